@@ -1,14 +1,343 @@
-pub fn add(left: usize, right: usize) -> usize {
-    left + right
+//! Derive macros for [`inquire`](https://docs.rs/inquire), removing the
+//! boilerplate of prompting for the variants of an enum or for each field of a
+//! configuration struct.
+//!
+//! The macros are re-exported by `inquire` behind its `derive` feature.
+//!
+//! - [`Selectable`] generates `select` and `multi_select` constructors listing
+//!   all variants of an enum;
+//! - [`Prompt`] generates a `prompt` function asking for each field of a struct
+//!   with a prompt chosen by its type.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, spanned::Spanned, Attribute, Data, DeriveInput, Error, Fields, LitStr,
+    Result, Type,
+};
+
+/// Derives `select` and `multi_select` constructors for an enum of unit
+/// variants, returning a `Select` or `MultiSelect` prompt listing all variants
+/// in declaration order.
+///
+/// Variants are displayed with their name, unless a label is set with
+/// `#[inquire(label = "...")]`. The enum does not need to implement `Display`.
+///
+/// # Example
+///
+/// ```
+/// use inquire::terminal::TestBackend;
+/// use inquire_derive::Selectable;
+///
+/// #[derive(Debug, PartialEq, Selectable)]
+/// enum Currency {
+///     #[inquire(label = "Brazilian real")]
+///     Brl,
+///     #[inquire(label = "US dollar")]
+///     Usd,
+///     Btc,
+/// }
+///
+/// let mut terminal = TestBackend::new(vec![]).with_text("us\n");
+/// let currency = Currency::select("Currency:").prompt_with_terminal(&mut terminal)?;
+///
+/// assert_eq!(Currency::Usd, currency);
+/// assert_eq!("> Currency: US dollar\n", terminal.last_frame().unwrap());
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[proc_macro_derive(Selectable, attributes(inquire))]
+pub fn derive_selectable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    selectable(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derives a `prompt` function for a struct with named fields, asking for each
+/// field in declaration order and returning the filled struct, along with a
+/// `prompt_with_terminal` variant.
+///
+/// The prompt of each field is chosen by its type:
+///
+/// - `String` fields are asked with a `Text` prompt;
+/// - `bool` fields are asked with a `Confirm` prompt;
+/// - fields marked with `#[inquire(select)]` are asked with the `select`
+///   constructor of their type, such as an enum deriving [`Selectable`];
+/// - any other field is asked with a `CustomType` prompt, requiring its type to
+///   implement `FromStr`, `ToString` and `Clone`.
+///
+/// The message of each prompt is the name of the field, e.g. `Max retries:`
+/// for `max_retries`, unless set with `#[inquire(message = "...")]`. A help
+/// message can be added with `#[inquire(help = "...")]`.
+///
+/// # Example
+///
+/// ```
+/// use inquire::terminal::TestBackend;
+/// use inquire_derive::{Prompt, Selectable};
+///
+/// #[derive(Debug, PartialEq, Selectable)]
+/// enum Level {
+///     Debug,
+///     Info,
+/// }
+///
+/// #[derive(Debug, PartialEq, Prompt)]
+/// struct Config {
+///     #[inquire(message = "Service name:")]
+///     name: String,
+///     max_retries: u32,
+///     verbose: bool,
+///     #[inquire(select, help = "Minimum level of the logged messages")]
+///     level: Level,
+/// }
+///
+/// let mut terminal = TestBackend::new(vec![]).with_text("api\n3\ny\ninf\n");
+/// let config = Config::prompt_with_terminal(&mut terminal)?;
+///
+/// assert_eq!(
+///     Config {
+///         name: String::from("api"),
+///         max_retries: 3,
+///         verbose: true,
+///         level: Level::Info,
+///     },
+///     config,
+/// );
+/// assert!(terminal.frames().contains(&String::from("> Max retries: 3\n")));
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[proc_macro_derive(Prompt, attributes(inquire))]
+pub fn derive_prompt(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    prompt(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn selectable(input: DeriveInput) -> Result<TokenStream2> {
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(Error::new(
+                input.span(),
+                "Selectable can only be derived for enums",
+            ))
+        }
+    };
+
+    if data.variants.is_empty() {
+        return Err(Error::new(
+            input.span(),
+            "Selectable can not be derived for enums without variants",
+        ));
+    }
+
+    let mut variants = vec![];
+    let mut labels = vec![];
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new(
+                variant.span(),
+                "Selectable can only be derived for enums of unit variants",
+            ));
+        }
+
+        let attributes = FieldAttributes::parse(&variant.attrs)?;
+        if attributes.message.is_some() || attributes.help.is_some() || attributes.select {
+            return Err(Error::new(
+                variant.span(),
+                "only the `label` attribute is supported on variants",
+            ));
+        }
+
+        variants.push(&variant.ident);
+        labels.push(
+            attributes
+                .label
+                .map(|label| label.value())
+                .unwrap_or_else(|| variant.ident.to_string()),
+        );
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Creates a `Select` prompt listing all variants.
+            pub fn select(message: &str) -> ::inquire::Select<'_, Self> {
+                ::inquire::Select::new_with_display(
+                    message,
+                    vec![#(Self::#variants),*],
+                    &|variant: &Self| match variant {
+                        #(Self::#variants => ::std::string::String::from(#labels),)*
+                    },
+                )
+            }
+
+            /// Creates a `MultiSelect` prompt listing all variants.
+            pub fn multi_select(message: &str) -> ::inquire::MultiSelect<'_, Self> {
+                ::inquire::MultiSelect::new_with_display(
+                    message,
+                    vec![#(Self::#variants),*],
+                    &|variant: &Self| match variant {
+                        #(Self::#variants => ::std::string::String::from(#labels),)*
+                    },
+                )
+            }
+        }
+    })
+}
+
+fn prompt(input: DeriveInput) -> Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new(
+                    input.span(),
+                    "Prompt can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new(
+                input.span(),
+                "Prompt can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut idents = vec![];
+    let mut prompts = vec![];
+    for field in fields {
+        let attributes = FieldAttributes::parse(&field.attrs)?;
+        if attributes.label.is_some() {
+            return Err(Error::new(
+                field.span(),
+                "the `label` attribute is only supported on enum variants",
+            ));
+        }
+
+        // named fields always have an identifier
+        let ident = field.ident.as_ref().unwrap();
+        let message = match attributes.message {
+            Some(message) => message.value(),
+            None => default_message(&ident.to_string()),
+        };
+        let ty = &field.ty;
+
+        let mut prompt = if attributes.select {
+            quote!(<#ty>::select(#message))
+        } else if is_type(ty, "String") {
+            quote!(::inquire::Text::new(#message))
+        } else if is_type(ty, "bool") {
+            quote!(::inquire::Confirm::new(#message))
+        } else {
+            quote!(::inquire::CustomType::<#ty>::new(#message))
+        };
+        if let Some(help) = attributes.help {
+            prompt = quote!(#prompt.with_help_message(#help));
+        }
+
+        idents.push(ident);
+        prompts.push(prompt);
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Prompts the user for each field, in declaration order.
+            pub fn prompt() -> ::inquire::error::InquireResult<Self> {
+                Ok(Self {
+                    #(#idents: #prompts.prompt()?,)*
+                })
+            }
+
+            /// Prompts the user for each field, in declaration order, through
+            /// the given terminal.
+            pub fn prompt_with_terminal<Term: ::inquire::terminal::Terminal>(
+                mut terminal: Term,
+            ) -> ::inquire::error::InquireResult<Self> {
+                Ok(Self {
+                    #(#idents: #prompts.prompt_with_terminal(&mut terminal)?,)*
+                })
+            }
+        }
+    })
+}
+
+/// Settings given in `#[inquire(...)]` attributes.
+#[derive(Default)]
+struct FieldAttributes {
+    message: Option<LitStr>,
+    help: Option<LitStr>,
+    label: Option<LitStr>,
+    select: bool,
+}
+
+impl FieldAttributes {
+    fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut attributes = Self::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("inquire")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("message") {
+                    attributes.message = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("help") {
+                    attributes.help = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("label") {
+                    attributes.label = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("select") {
+                    attributes.select = true;
+                } else {
+                    return Err(meta.error("unsupported inquire attribute"));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(attributes)
+    }
+}
+
+/// Whether the type is the given type, by the last segment of its path.
+fn is_type(ty: &Type, name: &str) -> bool {
+    match ty {
+        Type::Path(path) => matches!(
+            path.path.segments.last(),
+            Some(segment) if segment.ident == name && segment.arguments.is_empty()
+        ),
+        _ => false,
+    }
+}
+
+/// Message of the prompt of a field, e.g. `Max retries:` for `max_retries`.
+fn default_message(field: &str) -> String {
+    let words = field.trim_start_matches("r#").replace('_', " ");
+    let mut chars = words.trim().chars();
+
+    match chars.next() {
+        Some(first) => format!("{}{}:", first.to_uppercase(), chars.as_str()),
+        None => String::from(":"),
+    }
 }
 
 #[cfg(test)]
-mod tests {
-    use super::*;
+mod test {
+    use super::default_message;
 
     #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
+    fn default_messages_are_humanized_field_names() {
+        assert_eq!("Name:", default_message("name"));
+        assert_eq!("Max retries:", default_message("max_retries"));
+        assert_eq!("Type:", default_message("r#type"));
     }
 }
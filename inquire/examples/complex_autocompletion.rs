@@ -1,7 +1,7 @@
 use std::io::ErrorKind;
 
 use inquire::{
-    autocompletion::{Autocomplete, Replacement},
+    autocompletion::{Autocomplete, Replacement, Suggestion},
     CustomUserError, Text,
 };
 
@@ -116,10 +116,14 @@ impl FilePathCompleter {
 }
 
 impl Autocomplete for FilePathCompleter {
-    fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, CustomUserError> {
+    fn get_suggestions(&mut self, input: &str) -> Result<Vec<Suggestion>, CustomUserError> {
         self.update_input(input)?;
 
-        Ok(self.paths.clone())
+        Ok(self
+            .paths
+            .iter()
+            .map(|path| Suggestion::new(path.as_str()))
+            .collect())
     }
 
     fn get_completion(
@@ -1,4 +1,4 @@
-use inquire::{ui::RenderConfig, Confirm};
+use inquire::{ui::RenderConfig, Confirm, InterruptBehavior};
 
 fn main() {
     let ans = Confirm::new("Do you live in Brazil?")
@@ -20,6 +20,9 @@ fn main() {
         default: Some(false),
         placeholder: Some("si|no"),
         help_message: Some("It's alright if you're not"),
+        help_message_fn: None,
+        header: None,
+        footer: None,
         formatter: &|ans| match ans {
             true => "si".to_owned(),
             false => "no".to_owned(),
@@ -30,10 +33,17 @@ fn main() {
             _ => Err(()),
         },
         error_message: "Reply with 'si' or 'no'".into(),
+        instant: false,
         default_value_formatter: &|def| match def {
             true => String::from("si"),
             false => String::from("no"),
         },
+        interrupt_behavior: InterruptBehavior::default(),
+        event_listener: None,
+        handle: None,
+        timeout: None,
+        timeout_countdown: false,
+        refresh_interval: None,
         render_config: RenderConfig::default(),
     }
     .prompt()
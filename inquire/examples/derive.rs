@@ -0,0 +1,33 @@
+use inquire::{error::InquireResult, Prompt, Selectable};
+
+#[derive(Debug, Selectable)]
+enum Environment {
+    Development,
+    Staging,
+    Production,
+}
+
+#[derive(Debug, Prompt)]
+struct Deployment {
+    #[inquire(message = "Service name:")]
+    service: String,
+    replicas: u16,
+    #[inquire(select)]
+    environment: Environment,
+    #[inquire(message = "Run the migrations?")]
+    migrate: bool,
+}
+
+fn main() -> InquireResult<()> {
+    let deployment = Deployment::prompt()?;
+
+    println!(
+        "Deploying {} replicas of {} to {:?}",
+        deployment.replicas, deployment.service, deployment.environment
+    );
+    if deployment.migrate {
+        println!("Migrations will run first");
+    }
+
+    Ok(())
+}
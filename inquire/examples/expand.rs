@@ -0,0 +1,27 @@
+use inquire::Expand;
+
+fn main() {
+    let ans = Expand::new(
+        "Stage this hunk?",
+        vec![
+            ('y', "Stage this hunk"),
+            ('n', "Do not stage this hunk"),
+            (
+                'q',
+                "Quit; do not stage this hunk or any of the remaining ones",
+            ),
+            ('a', "Stage this hunk and all later hunks in the file"),
+            (
+                'd',
+                "Do not stage this hunk or any of the later hunks in the file",
+            ),
+        ],
+    )
+    .with_help_key('?')
+    .prompt();
+
+    match ans {
+        Ok(action) => println!("{}", action),
+        Err(_) => println!("Error with questionnaire, try again later"),
+    }
+}
@@ -0,0 +1,33 @@
+use inquire::{
+    error::InquireResult,
+    form::{Answers, Form},
+    validator::Validation,
+    CustomType, Text,
+};
+
+fn main() -> InquireResult<()> {
+    let mut answers = Form::new()
+        .add("name", Text::new("Name of the budget:"))
+        .add("min", CustomType::<u32>::new("Minimum amount:"))
+        .add("max", CustomType::<u32>::new("Maximum amount:"))
+        .with_validator(&["min", "max"], |answers: &Answers| {
+            let min: &u32 = answers.get("min").unwrap();
+            let max: &u32 = answers.get("max").unwrap();
+
+            match max > min {
+                true => Ok(Validation::Valid),
+                false => Ok(Validation::Invalid(
+                    "The maximum amount must be greater than the minimum amount".into(),
+                )),
+            }
+        })
+        .prompt()?;
+
+    let name: String = answers.take("name").unwrap();
+    let min: u32 = answers.take("min").unwrap();
+    let max: u32 = answers.take("max").unwrap();
+
+    println!("Budget '{name}' ranges from {min} to {max}");
+
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use inquire::{confirm, min_length, required, select, text};
+
+fn main() {
+    let name = text!(
+        "What's your name?",
+        placeholder = "Jane Doe",
+        validators = [required!(), min_length!(2)],
+    )
+    .prompt()
+    .unwrap();
+
+    let environment = select!("Where should we deploy?", ["dev", "staging", "prod"])
+        .prompt()
+        .unwrap();
+
+    let confirmed = confirm!("Are you sure?", default = false).prompt().unwrap();
+
+    if confirmed {
+        println!("Deploying to {environment} on behalf of {name}");
+    }
+}
@@ -6,7 +6,9 @@ fn main() {
         .with_display_mode(PasswordDisplayMode::Masked)
         .with_validator(min_length!(10))
         .with_formatter(&|_| String::from("Input received"))
-        .with_help_message("It is recommended to generate a new one only for this purpose")
+        .with_help_message(
+            "It is recommended to generate a new one only for this purpose, ctrl+r to cycle the display mode",
+        )
         .with_custom_confirmation_error_message("The keys don't match.")
         .prompt();
 
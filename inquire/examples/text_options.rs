@@ -1,4 +1,6 @@
-use inquire::{error::CustomUserError, length, required, ui::RenderConfig, Text};
+use inquire::{
+    error::CustomUserError, length, required, ui::RenderConfig, InterruptBehavior, Text,
+};
 
 fn main() {
     let answer = Text::new("What's your name?")
@@ -16,10 +18,26 @@ fn main() {
         default: None,
         placeholder: Some("Good"),
         help_message: None,
+        help_message_fn: None,
+        header: None,
+        footer: None,
         formatter: Text::DEFAULT_FORMATTER,
         validators: Vec::new(),
+        background_validators: Vec::new(),
         page_size: Text::DEFAULT_PAGE_SIZE,
+        ghost_text: false,
+        tab_cycles: false,
+        input_filter: None,
+        input_mask: None,
+        max_length: None,
+        length_counter: false,
         autocompleter: None,
+        interrupt_behavior: InterruptBehavior::default(),
+        event_listener: None,
+        handle: None,
+        timeout: None,
+        timeout_countdown: false,
+        refresh_interval: None,
         render_config: RenderConfig::default(),
     }
     .prompt()
@@ -0,0 +1,14 @@
+use chrono::NaiveTime;
+use inquire::TimeSelect;
+
+fn main() {
+    let ans = TimeSelect::new("What time does the meeting start?")
+        .with_default(NaiveTime::from_hms_opt(9, 30, 0).unwrap())
+        .with_twelve_hour_clock(true)
+        .prompt();
+
+    match ans {
+        Ok(time) => println!("Meeting scheduled at {}", time.format("%H:%M")),
+        Err(_) => println!("There was an error in the system."),
+    }
+}
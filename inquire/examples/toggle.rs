@@ -0,0 +1,13 @@
+use inquire::Toggle;
+
+fn main() {
+    let ans = Toggle::new("Deploy to production?")
+        .with_labels("Deploy", "Abort")
+        .prompt();
+
+    match ans {
+        Ok(true) => println!("Deploying..."),
+        Ok(false) => println!("Aborted"),
+        Err(_) => println!("Error with questionnaire, try again later"),
+    }
+}
@@ -0,0 +1,35 @@
+use inquire::{list_option::ListOption, validator::Validation, TreeMultiSelect, TreeNode};
+
+fn main() {
+    let permissions = vec![
+        TreeNode::new("repository").with_children(vec![
+            TreeNode::new("read"),
+            TreeNode::new("write"),
+            TreeNode::new("delete"),
+        ]),
+        TreeNode::new("issues").with_children(vec![
+            TreeNode::new("read"),
+            TreeNode::new("comment"),
+            TreeNode::new("close"),
+        ]),
+        TreeNode::new("admin"),
+    ];
+
+    let validator = |a: &[ListOption<&&str>]| {
+        if a.is_empty() {
+            return Ok(Validation::Invalid("Grant at least one permission".into()));
+        }
+
+        Ok(Validation::Valid)
+    };
+
+    let ans = TreeMultiSelect::new("Permissions:", permissions)
+        .with_expanded(true)
+        .with_validator(validator)
+        .raw_prompt();
+
+    match ans {
+        Ok(granted) => println!("Granted {} permissions", granted.len()),
+        Err(_) => println!("Error with questionnaire, try again later"),
+    }
+}
@@ -1,4 +1,8 @@
-use std::str::Chars;
+use std::{borrow::Cow, str::Chars};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::text::{grapheme_width, text_width, truncate_with_ellipsis};
 
 #[must_use]
 enum MatchResult<'a> {
@@ -119,6 +123,71 @@ impl AnsiStrippable for &str {
     }
 }
 
+/// Strips away the ANSI escape codes of the text, only allocating when it has any.
+pub fn ansi_stripped(text: &str) -> Cow<'_, str> {
+    match text.contains('\x1b') {
+        true => Cow::Owned(text.ansi_stripped_chars().collect()),
+        false => Cow::Borrowed(text),
+    }
+}
+
+/// Length in bytes of the ANSI escape code at the start of the text, if any.
+fn escape_code_len(text: &str) -> Option<usize> {
+    match Matcher::new(text.chars()).run() {
+        MatchResult::Matched { remaining_chars } => {
+            Some(text.len() - remaining_chars.as_str().len())
+        }
+        MatchResult::NotMatched => None,
+    }
+}
+
+/// Truncates the text to at most `width` columns like
+/// [`truncate_with_ellipsis`], without counting nor splitting its ANSI escape
+/// codes. The codes following the truncated text are kept, so that the styles
+/// it sets are still reset.
+pub fn ansi_truncate_to_width<'t>(text: &'t str, width: usize, ellipsis: &str) -> Cow<'t, str> {
+    if !text.contains('\x1b') {
+        return truncate_with_ellipsis(text, width, ellipsis);
+    }
+    if text_width(&ansi_stripped(text)) <= width {
+        return Cow::Borrowed(text);
+    }
+
+    let ellipsis = match text_width(ellipsis) <= width {
+        true => ellipsis,
+        false => "",
+    };
+    let available = width - text_width(ellipsis);
+    let mut truncated = String::with_capacity(text.len());
+    let mut used = 0;
+    let mut full = false;
+    let mut rest = text;
+
+    while !rest.is_empty() {
+        if let Some(len) = escape_code_len(rest) {
+            truncated.push_str(&rest[..len]);
+            rest = &rest[len..];
+            continue;
+        }
+
+        let visible = &rest[..rest.find('\x1b').unwrap_or(rest.len())];
+        for grapheme in visible.graphemes(true) {
+            if full || used + grapheme_width(grapheme) > available {
+                if !full {
+                    truncated.push_str(ellipsis);
+                }
+                full = true;
+                break;
+            }
+            used += grapheme_width(grapheme);
+            truncated.push_str(grapheme);
+        }
+        rest = &rest[visible.len()..];
+    }
+
+    Cow::Owned(truncated)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -142,6 +211,26 @@ mod tests {
         assert_stripped_eq!("\x1b[96", "");
     }
 
+    #[test]
+    fn truncation_keeps_escape_codes_whole() {
+        let text = "\x1b[31mred\x1b[0m \x1b[32mgreen\x1b[0m";
+
+        assert_eq!(text, ansi_truncate_to_width(text, 9, "…"));
+        assert_eq!(
+            "\x1b[31mred\x1b[0m \x1b[32mgr…\x1b[0m",
+            ansi_truncate_to_width(text, 7, "…")
+        );
+        assert_eq!(
+            "\x1b[31mre…\x1b[0m\x1b[32m\x1b[0m",
+            ansi_truncate_to_width(text, 3, "…")
+        );
+        assert_eq!("plai…", ansi_truncate_to_width("plain text", 5, "…"));
+        assert_eq!(
+            "\x1b[31mr..\x1b[0m\x1b[32m\x1b[0m",
+            ansi_truncate_to_width(text, 3, "..")
+        );
+    }
+
     #[test]
     // Please don't use ansi escapes like these!
     fn test_inconsistencies() {
@@ -8,9 +8,15 @@
 //! selection, if any. Then the developer may return a [Replacement] action
 //! where the current user text input is replaced or not by a provided string.
 //!
+//! Suggestions are [Suggestion] entries, which can carry a description rendered
+//! next to them and a replacement text inserted instead of the displayed value,
+//! like the completion menus of shells.
+//!
 //! Check the example files to see some usages, recommended are `expense_tracker.rs`
 //! and `complex_autocompletion.rs`.
 
+use std::fmt::{self, Display};
+
 use dyn_clone::DynClone;
 
 use crate::CustomUserError;
@@ -21,10 +27,89 @@ use crate::CustomUserError;
 /// `Some(String)` will replace the current text input with the `String` in `Some`.
 pub type Replacement = Option<String>;
 
+/// Suggestion displayed to the user while typing in a text input.
+///
+/// Besides its displayed `value`, a suggestion may have a `description`, rendered
+/// dimmed next to it, and a `replacement`, the text that is inserted in the input
+/// or submitted as the answer when the suggestion is chosen, instead of the value.
+///
+/// Suggestions can be created from strings, in which case they only have a value.
+///
+/// # Example
+///
+/// ```
+/// use inquire::autocompletion::Suggestion;
+///
+/// let suggestion = Suggestion::new("🇧🇷 Brazil")
+///     .with_description("South America")
+///     .with_replacement("BR");
+///
+/// assert_eq!("BR", suggestion.replacement_text());
+/// assert_eq!("Lisbon", Suggestion::from("Lisbon").replacement_text());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Suggestion {
+    /// Text displayed to the user.
+    pub value: String,
+
+    /// Short description rendered next to the value, if any.
+    pub description: Option<String>,
+
+    /// Text inserted or submitted when the suggestion is chosen, the value if
+    /// not set.
+    pub replacement: Option<String>,
+}
+
+impl Suggestion {
+    /// Creates a suggestion with the given value, without description nor replacement.
+    pub fn new<S: Into<String>>(value: S) -> Self {
+        Self {
+            value: value.into(),
+            description: None,
+            replacement: None,
+        }
+    }
+
+    /// Sets the description rendered next to the value.
+    pub fn with_description<S: Into<String>>(mut self, description: S) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the text inserted or submitted instead of the value.
+    pub fn with_replacement<S: Into<String>>(mut self, replacement: S) -> Self {
+        self.replacement = Some(replacement.into());
+        self
+    }
+
+    /// Text inserted or submitted when the suggestion is chosen.
+    pub fn replacement_text(&self) -> &str {
+        self.replacement.as_deref().unwrap_or(&self.value)
+    }
+}
+
+impl Display for Suggestion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.value)
+    }
+}
+
+impl From<String> for Suggestion {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&str> for Suggestion {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
 /// Mechanism to implement autocompletion features for text inputs. The `Autocomplete` trait has two provided methods: `get_suggestions` and `get_completion`.
 ///
-/// - `get_suggestions` is called whenever the user's text input is modified, e.g. a new letter is typed, returning a `Vec<String>`. The `Vec<String>` is the list of suggestions that the prompt displays to the user according to their text input. The user can then navigate through the list and if they submit while highlighting one of these suggestions, the suggestion is treated as the final answer.
-/// - `get_completion` is called whenever the user presses the autocompletion hotkey (`tab` by default), with the current text input and the text of the currently highlighted suggestion, if any, as parameters. This method should return whether any text replacement (an autocompletion) should be made. If the prompt receives a replacement to be made, it substitutes the current text input for the string received from the `get_completion` call.
+/// - `get_suggestions` is called whenever the user's text input is modified, e.g. a new letter is typed, returning a `Vec<Suggestion>`. The `Vec<Suggestion>` is the list of suggestions that the prompt displays to the user according to their text input. The user can then navigate through the list and if they submit while highlighting one of these suggestions, the [replacement text](Suggestion::replacement_text) of the suggestion is treated as the final answer.
+/// - `get_completion` is called whenever the user presses the autocompletion hotkey (`tab` by default), with the current text input and the replacement text of the currently highlighted suggestion, if any, as parameters. This method should return whether any text replacement (an autocompletion) should be made. If the prompt receives a replacement to be made, it substitutes the current text input for the string received from the `get_completion` call.
 ///
 /// For example, in the `complex_autocompletion.rs` example file, the `FilePathCompleter` scans the file system based on the current text input, storing a list of paths that match the current text input.
 ///
@@ -35,8 +120,8 @@ pub trait Autocomplete: DynClone {
     ///
     /// If the user presses the autocompletion hotkey (`tab` as default) with
     /// a suggestion highlighted, the user's text input will be replaced by the
-    /// content of the suggestion string.
-    fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, CustomUserError>;
+    /// [replacement text](Suggestion::replacement_text) of the suggestion.
+    fn get_suggestions(&mut self, input: &str) -> Result<Vec<Suggestion>, CustomUserError>;
 
     /// Standalone autocompletion that can be implemented based solely on the user's
     /// input.
@@ -69,7 +154,7 @@ impl Clone for Box<dyn Autocomplete> {
 pub struct NoAutoCompletion;
 
 impl Autocomplete for NoAutoCompletion {
-    fn get_suggestions(&mut self, _: &str) -> Result<Vec<String>, CustomUserError> {
+    fn get_suggestions(&mut self, _: &str) -> Result<Vec<Suggestion>, CustomUserError> {
         Ok(vec![])
     }
 
@@ -86,8 +171,8 @@ impl<F> Autocomplete for F
 where
     F: Fn(&str) -> Result<Vec<String>, CustomUserError> + Clone,
 {
-    fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, CustomUserError> {
-        (self)(input)
+    fn get_suggestions(&mut self, input: &str) -> Result<Vec<Suggestion>, CustomUserError> {
+        (self)(input).map(|suggestions| suggestions.into_iter().map(Suggestion::from).collect())
     }
 
     fn get_completion(
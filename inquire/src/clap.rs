@@ -0,0 +1,301 @@
+//! Integration with [clap](https://docs.rs/clap), prompting for the required
+//! arguments missing from the command line, so that a CLI defined once can be
+//! used both with flags and interactively.
+//!
+//! Requires the `clap` feature. Each missing argument is asked, in declaration
+//! order, with a prompt chosen by its definition:
+//!
+//! - arguments with possible values, such as `ValueEnum` types or booleans,
+//!   are asked with a [`Select`] prompt listing them;
+//! - any other argument is asked with a [`Text`] prompt, validated by the
+//!   value parser of the argument.
+//!
+//! The message of each prompt is the name of the argument, e.g. `Config file:`
+//! for `config_file`, and its help is displayed as the help message.
+//!
+//! The answers are then parsed along with the given arguments, so the returned
+//! matches behave exactly as if the user had passed them on the command line.
+//! Only the arguments of the top-level command are asked.
+//!
+//! Like [`Command::get_matches`], the functions of this module exit the
+//! process when the arguments are invalid or when the help or version were
+//! requested.
+//!
+//! # Example
+//!
+//! ```
+//! use clap::{value_parser, Arg, Command};
+//! use inquire::terminal::TestBackend;
+//!
+//! let command = Command::new("deploy")
+//!     .arg(Arg::new("service").required(true))
+//!     .arg(
+//!         Arg::new("replicas")
+//!             .long("replicas")
+//!             .required(true)
+//!             .value_parser(value_parser!(u16)),
+//!     );
+//!
+//! let mut terminal = TestBackend::new(vec![]).with_text("4\n");
+//! let matches =
+//!     inquire::clap::get_matches_from_with_terminal(command, ["deploy", "api"], &mut terminal)?;
+//!
+//! assert_eq!(Some("api"), matches.get_one::<String>("service").map(String::as_str));
+//! assert_eq!(Some(&4), matches.get_one::<u16>("replicas"));
+//! assert_eq!("> Replicas: 4\n", terminal.last_frame().unwrap());
+//! # Ok::<(), inquire::InquireError>(())
+//! ```
+
+use std::ffi::OsString;
+
+use ::clap::{Arg, ArgMatches, Command, CommandFactory, FromArgMatches};
+
+use crate::{
+    error::InquireResult,
+    terminal::{get_default_terminal, Terminal},
+    validator::Validation,
+    Select, Text,
+};
+
+/// Parses the arguments of the process with the given command, prompting for
+/// the required arguments that are missing.
+pub fn get_matches(command: Command) -> InquireResult<ArgMatches> {
+    get_matches_from(command, std::env::args_os())
+}
+
+/// Parses the given arguments with the given command, prompting for the
+/// required arguments that are missing. The first argument is the name of the
+/// binary.
+pub fn get_matches_from<I, T>(command: Command, args: I) -> InquireResult<ArgMatches>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<OsString> + Clone,
+{
+    get_matches_from_with_terminal(command, args, get_default_terminal()?)
+}
+
+/// Parses the given arguments with the given command, prompting for the
+/// required arguments that are missing through the given terminal, see
+/// [`get_matches_from`].
+pub fn get_matches_from_with_terminal<I, T, Term>(
+    mut command: Command,
+    args: I,
+    mut terminal: Term,
+) -> InquireResult<ArgMatches>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<OsString> + Clone,
+    Term: Terminal,
+{
+    command.build();
+
+    let args: Vec<OsString> = args.into_iter().map(Into::into).collect();
+    let given = command
+        .clone()
+        .mut_args(|arg| arg.required(false))
+        .try_get_matches_from(&args)
+        .unwrap_or_else(|err| err.exit());
+
+    let mut options = vec![];
+    let mut positionals = vec![];
+    for arg in command.get_arguments() {
+        if !arg.is_required_set()
+            || !arg.get_action().takes_values()
+            || given.value_source(arg.get_id().as_str()).is_some()
+        {
+            continue;
+        }
+
+        let answer = ask(arg, &mut terminal)?;
+
+        match (arg.get_long(), arg.get_short()) {
+            (Some(long), _) => options.push(OsString::from(format!("--{long}={answer}"))),
+            (None, Some(short)) => options.push(OsString::from(format!("-{short}={answer}"))),
+            (None, None) => positionals.push((arg.get_index().unwrap_or_default(), answer)),
+        }
+    }
+
+    if options.is_empty() && positionals.is_empty() {
+        return Ok(given);
+    }
+
+    positionals.sort_by_key(|(index, _)| *index);
+
+    let mut args = args.into_iter();
+    let mut filled: Vec<OsString> = args.next().into_iter().collect();
+    filled.extend(options);
+    filled.extend(args);
+    if !positionals.is_empty() && !filled.iter().any(|arg| arg == "--") {
+        filled.push(OsString::from("--"));
+    }
+    filled.extend(positionals.into_iter().map(|(_, answer)| answer.into()));
+
+    Ok(command
+        .try_get_matches_from(filled)
+        .unwrap_or_else(|err| err.exit()))
+}
+
+/// Parses the arguments of the process into `P`, usually a type deriving
+/// `clap::Parser`, prompting for the required arguments that are missing.
+pub fn parse<P: CommandFactory + FromArgMatches>() -> InquireResult<P> {
+    let matches = get_matches(P::command())?;
+
+    Ok(P::from_arg_matches(&matches).unwrap_or_else(|err| err.exit()))
+}
+
+/// Asks for the value of an argument.
+fn ask<Term: Terminal>(arg: &Arg, terminal: &mut Term) -> InquireResult<String> {
+    let message = message(arg.get_id().as_str());
+    let help = arg.get_help().map(ToString::to_string);
+
+    let possible_values: Vec<String> = arg
+        .get_possible_values()
+        .iter()
+        .filter(|value| !value.is_hide_set())
+        .map(|value| value.get_name().to_string())
+        .collect();
+
+    if !possible_values.is_empty() {
+        let mut prompt = Select::new(&message, possible_values);
+        if let Some(help) = &help {
+            prompt = prompt.with_help_message(help);
+        }
+
+        return prompt.prompt_with_terminal(terminal);
+    }
+
+    // The value parser of the argument is not public, so answers are validated
+    // by parsing them with a command made of a single positional argument.
+    let validation = Command::new("inquire").no_binary_name(true).arg(
+        Arg::new("value")
+            .required(true)
+            .allow_hyphen_values(true)
+            .value_parser(arg.get_value_parser().clone()),
+    );
+
+    let mut prompt = Text::new(&message).with_validator(move |input: &str| {
+        match validation.clone().try_get_matches_from([input]) {
+            Ok(_) => Ok(Validation::Valid),
+            Err(err) => Ok(Validation::Invalid(error_message(&err).into())),
+        }
+    });
+    if let Some(help) = &help {
+        prompt = prompt.with_help_message(help);
+    }
+
+    prompt.prompt_with_terminal(terminal)
+}
+
+/// First line of a clap error, without its `error: ` prefix.
+fn error_message(err: &::clap::Error) -> String {
+    let rendered = err.to_string();
+    let line = rendered.lines().next().unwrap_or_default();
+
+    line.trim_start_matches("error: ").to_string()
+}
+
+/// Message of the prompt of an argument, e.g. `Config file:` for
+/// `config_file` or `config-file`.
+fn message(id: &str) -> String {
+    let words = id.replace(['_', '-'], " ");
+    let mut chars = words.trim().chars();
+
+    match chars.next() {
+        Some(first) => format!("{}{}:", first.to_uppercase(), chars.as_str()),
+        None => String::from(":"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ::clap::{value_parser, Arg, ArgAction, Command};
+
+    use super::{get_matches_from_with_terminal, message};
+    use crate::terminal::TestBackend;
+
+    fn command() -> Command {
+        Command::new("deploy")
+            .arg(Arg::new("service").required(true))
+            .arg(Arg::new("target").required(true))
+            .arg(
+                Arg::new("replicas")
+                    .short('r')
+                    .required(true)
+                    .value_parser(value_parser!(u16)),
+            )
+            .arg(
+                Arg::new("environment")
+                    .long("environment")
+                    .required(true)
+                    .help("Where to deploy")
+                    .value_parser(["staging", "production"]),
+            )
+            .arg(
+                Arg::new("verbose")
+                    .long("verbose")
+                    .action(ArgAction::SetTrue),
+            )
+    }
+
+    #[test]
+    fn given_arguments_are_not_asked() {
+        let args = ["deploy", "api", "eu", "-r", "2", "--environment", "staging"];
+        let mut terminal = TestBackend::new(vec![]);
+        let matches = get_matches_from_with_terminal(command(), args, &mut terminal).unwrap();
+
+        assert!(terminal.frames().is_empty());
+        assert_eq!(Some(&2), matches.get_one::<u16>("replicas"));
+    }
+
+    #[test]
+    fn missing_arguments_are_asked_in_order() {
+        let mut terminal = TestBackend::new(vec![]).with_text("-eu\n3\nprod\n");
+        let matches =
+            get_matches_from_with_terminal(command(), ["deploy", "api"], &mut terminal).unwrap();
+
+        assert_eq!(
+            Some("api"),
+            matches.get_one::<String>("service").map(String::as_str)
+        );
+        assert_eq!(
+            Some("-eu"),
+            matches.get_one::<String>("target").map(String::as_str)
+        );
+        assert_eq!(Some(&3), matches.get_one::<u16>("replicas"));
+        assert_eq!(
+            Some("production"),
+            matches.get_one::<String>("environment").map(String::as_str)
+        );
+        assert_eq!(Some(&false), matches.get_one::<bool>("verbose"));
+        assert!(terminal
+            .frames()
+            .iter()
+            .any(|frame| frame.contains("[Where to deploy]")));
+    }
+
+    #[test]
+    fn answers_are_validated_by_the_value_parser() {
+        let mut terminal = TestBackend::new(vec![]).with_text("x\n");
+        terminal.push_key(crate::ui::Key::Backspace(crate::ui::KeyModifiers::NONE));
+        terminal.push_text("5\n");
+        let matches = get_matches_from_with_terminal(
+            command(),
+            ["deploy", "api", "eu", "--environment", "staging"],
+            &mut terminal,
+        )
+        .unwrap();
+
+        assert_eq!(Some(&5), matches.get_one::<u16>("replicas"));
+        assert!(terminal
+            .frames()
+            .iter()
+            .any(|frame| frame.contains("invalid value")));
+    }
+
+    #[test]
+    fn messages_are_humanized_ids() {
+        assert_eq!("Replicas:", message("replicas"));
+        assert_eq!("Config file:", message("config_file"));
+        assert_eq!("Config file:", message("config-file"));
+    }
+}
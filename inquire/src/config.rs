@@ -4,11 +4,48 @@ use std::sync::Mutex;
 
 use lazy_static::lazy_static;
 
-use crate::ui::RenderConfig;
+use crate::{
+    events::PromptEvent, status::StatusReporting, type_aliases::EventListener, ui::RenderConfig,
+};
 
 lazy_static! {
     static ref GLOBAL_RENDER_CONFIGURATION: Mutex<RenderConfig<'static>> =
         Mutex::new(RenderConfig::default());
+    static ref GLOBAL_INTERRUPT_BEHAVIOR: Mutex<InterruptBehavior> =
+        Mutex::new(InterruptBehavior::default());
+    static ref GLOBAL_STATUS_REPORTING: Mutex<StatusReporting> =
+        Mutex::new(StatusReporting::default());
+    static ref GLOBAL_OPTIONS: Mutex<GlobalOptions> = Mutex::new(GlobalOptions::default());
+    static ref GLOBAL_EVENT_LISTENER: Mutex<Option<GlobalEventListener>> = Mutex::new(None);
+}
+
+/// Event listener that can be shared by the prompts of all threads.
+type GlobalEventListener = &'static (dyn Fn(&PromptEvent<'_>) + Send + Sync);
+
+/// Behavior of prompts when the user presses Ctrl+C.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum InterruptBehavior {
+    /// Returns [`InquireError::OperationInterrupted`](crate::error::InquireError::OperationInterrupted).
+    ReturnError,
+
+    /// Cancels the prompt, just like pressing ESC.
+    Cancel,
+
+    /// Restores the terminal and raises SIGINT, leaving it to the process' signal
+    /// handler. If the handler returns,
+    /// [`InquireError::OperationInterrupted`](crate::error::InquireError::OperationInterrupted)
+    /// is returned.
+    #[cfg(unix)]
+    #[cfg_attr(docsrs, doc(cfg(unix)))]
+    RaiseSignal,
+}
+
+// Deriving it with `#[default]` requires Rust 1.62, above our MSRV.
+#[allow(clippy::derivable_impls)]
+impl Default for InterruptBehavior {
+    fn default() -> Self {
+        Self::ReturnError
+    }
 }
 
 pub fn get_configuration() -> RenderConfig<'static> {
@@ -22,6 +59,129 @@ pub fn set_global_render_config(config: RenderConfig<'static>) {
     *guard = config;
 }
 
+pub fn get_interrupt_behavior() -> InterruptBehavior {
+    *GLOBAL_INTERRUPT_BEHAVIOR.lock().unwrap()
+}
+
+/// Acquires a write lock to the global InterruptBehavior object
+/// and updates the inner value with the provided argument.
+///
+/// Prompts created afterwards use it by default.
+pub fn set_global_interrupt_behavior(behavior: InterruptBehavior) {
+    let mut guard = GLOBAL_INTERRUPT_BEHAVIOR.lock().unwrap();
+    *guard = behavior;
+}
+
+pub fn get_status_reporting() -> StatusReporting {
+    *GLOBAL_STATUS_REPORTING.lock().unwrap()
+}
+
+/// Acquires a write lock to the global StatusReporting object
+/// and updates the inner value with the provided argument.
+///
+/// Flows started afterwards update the terminal chrome accordingly, see the
+/// [`status`](crate::status) module.
+pub fn set_global_status_reporting(reporting: StatusReporting) {
+    let mut guard = GLOBAL_STATUS_REPORTING.lock().unwrap();
+    *guard = reporting;
+}
+
+pub fn get_event_listener() -> Option<EventListener<'static>> {
+    GLOBAL_EVENT_LISTENER
+        .lock()
+        .unwrap()
+        .map(|listener| listener as EventListener<'static>)
+}
+
+/// Acquires a write lock to the global event listener and updates it to the
+/// provided one, or removes it with `None`.
+///
+/// Prompts created afterwards notify it of their events by default, see the
+/// [`events`](crate::events) module.
+pub fn set_global_event_listener(listener: Option<GlobalEventListener>) {
+    let mut guard = GLOBAL_EVENT_LISTENER.lock().unwrap();
+    *guard = listener;
+}
+
+/// Translation of a built-in string to the global locale when the `i18n`
+/// feature is enabled, the string itself otherwise.
+pub(crate) fn translate(text: &str) -> &str {
+    #[cfg(feature = "i18n")]
+    return crate::i18n::get_locale().translate(text);
+
+    #[cfg(not(feature = "i18n"))]
+    text
+}
+
+/// Translation of a built-in template, such as `"The value should be {}"`,
+/// with its placeholder filled with the given value.
+pub(crate) fn translate_template(template: &str, value: impl std::fmt::Display) -> String {
+    translate(template).replacen("{}", &value.to_string(), 1)
+}
+
+/// Default values of the options shared by several prompts, applied to the
+/// prompts created after they are set with [`set_global_options`].
+///
+/// Each prompt can still override them with its own builder methods.
+///
+/// # Example
+///
+/// ```
+/// use inquire::{set_global_options, GlobalOptions};
+///
+/// set_global_options(GlobalOptions {
+///     page_size: 10,
+///     help_messages_enabled: false,
+///     ..GlobalOptions::default()
+/// });
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct GlobalOptions {
+    /// Number of options displayed at once by list prompts and suggestions.
+    pub page_size: usize,
+
+    /// Whether vim mode is enabled in `Select` and `MultiSelect` prompts.
+    /// `DateSelect` prompts have it enabled regardless.
+    pub vim_mode: bool,
+
+    /// Whether prompts display their default help messages. Help messages set
+    /// explicitly on a prompt are always displayed.
+    pub help_messages_enabled: bool,
+}
+
+impl GlobalOptions {
+    /// Help message of a prompt, given its default one.
+    pub(crate) fn help_message<'a>(&self, default: Option<&'a str>) -> Option<&'a str> {
+        match self.help_messages_enabled {
+            true => default.map(translate),
+            false => None,
+        }
+    }
+}
+
+impl Default for GlobalOptions {
+    fn default() -> Self {
+        Self {
+            page_size: DEFAULT_PAGE_SIZE,
+            vim_mode: DEFAULT_VIM_MODE,
+            help_messages_enabled: true,
+        }
+    }
+}
+
+pub fn get_global_options() -> GlobalOptions {
+    *GLOBAL_OPTIONS.lock().unwrap()
+}
+
+/// Acquires a write lock to the global GlobalOptions object
+/// and updates the inner value with the provided argument.
+///
+/// Prompts created afterwards use them by default.
+pub fn set_global_options(options: GlobalOptions) {
+    let mut guard = GLOBAL_OPTIONS.lock().unwrap();
+    *guard = options;
+}
+
 /// Default page size when displaying options to the user.
 pub const DEFAULT_PAGE_SIZE: usize = 7;
 
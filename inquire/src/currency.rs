@@ -0,0 +1,250 @@
+//! Locale-aware parsing and formatting of money amounts, backing the
+//! [`CustomType::currency`](crate::CustomType::currency) constructor.
+//!
+//! Requires the `rust_decimal` feature.
+//!
+//! ```
+//! use std::str::FromStr;
+//! use inquire::currency::CurrencyLocale;
+//! use rust_decimal::Decimal;
+//!
+//! let amount = CurrencyLocale::DeDe.parse("1.234,5 €").unwrap();
+//! assert_eq!(Decimal::from_str("1234.5").unwrap(), amount);
+//! assert_eq!("$1,234.50", CurrencyLocale::EnUs.format(amount));
+//! ```
+
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+
+/// Conventions used to write money amounts in a given locale: the currency
+/// symbol, the thousands and decimal separators and the number of decimals.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CurrencyLocale {
+    /// US dollars, e.g. `$1,234.56`.
+    EnUs,
+    /// Pound sterling, e.g. `£1,234.56`.
+    EnGb,
+    /// Euros with German conventions, e.g. `1.234,56 €`.
+    DeDe,
+    /// Euros with French conventions, e.g. `1 234,56 €`.
+    FrFr,
+    /// Brazilian reais, e.g. `R$ 1.234,56`.
+    PtBr,
+    /// Japanese yen, without decimals, e.g. `¥1,235`.
+    JaJp,
+}
+
+struct Conventions {
+    symbol: &'static str,
+    symbol_first: bool,
+    symbol_spaced: bool,
+    thousands_separator: char,
+    decimal_separator: char,
+    decimals: u32,
+}
+
+impl CurrencyLocale {
+    fn conventions(self) -> Conventions {
+        let (symbol, symbol_first, symbol_spaced) = match self {
+            Self::EnUs => ("$", true, false),
+            Self::EnGb => ("£", true, false),
+            Self::DeDe | Self::FrFr => ("€", false, true),
+            Self::PtBr => ("R$", true, true),
+            Self::JaJp => ("¥", true, false),
+        };
+        let (thousands_separator, decimal_separator) = match self {
+            Self::EnUs | Self::EnGb | Self::JaJp => (',', '.'),
+            Self::DeDe | Self::PtBr => ('.', ','),
+            Self::FrFr => ('\u{202f}', ','),
+        };
+
+        Conventions {
+            symbol,
+            symbol_first,
+            symbol_spaced,
+            thousands_separator,
+            decimal_separator,
+            decimals: match self {
+                Self::JaJp => 0,
+                _ => 2,
+            },
+        }
+    }
+
+    /// Parses an amount written with the conventions of the locale.
+    ///
+    /// The currency symbol is optional and thousands separators must group
+    /// digits by three, so that an amount written with the conventions of
+    /// another locale, such as `1.5` in `DeDe`, is rejected instead of being
+    /// silently misread. Amounts with more decimals than the currency has are
+    /// rejected as well.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use inquire::currency::CurrencyLocale;
+    /// use rust_decimal::Decimal;
+    ///
+    /// let parse = |input| CurrencyLocale::EnUs.parse(input);
+    ///
+    /// assert_eq!(Some(Decimal::from_str("1234.5").unwrap()), parse("$1,234.5"));
+    /// assert_eq!(Some(Decimal::from_str("-12").unwrap()), parse("-12"));
+    /// assert_eq!(None, parse("1,23.45"));
+    /// assert_eq!(None, parse("1.234"));
+    /// ```
+    pub fn parse(self, input: &str) -> Option<Decimal> {
+        let conventions = self.conventions();
+
+        let mut amount = input.trim();
+        let negative = amount.starts_with('-');
+        if negative {
+            amount = amount[1..].trim_start();
+        }
+        amount = amount
+            .strip_prefix(conventions.symbol)
+            .or_else(|| amount.strip_suffix(conventions.symbol))
+            .unwrap_or(amount)
+            .trim();
+
+        let (integer, fraction) = match amount.split_once(conventions.decimal_separator) {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (amount, None),
+        };
+
+        let is_digits = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+
+        // spaces are commonly typed in place of narrow no-break spaces
+        let groups: Vec<&str> = match conventions.thousands_separator {
+            ' ' | '\u{a0}' | '\u{202f}' => integer.split([' ', '\u{a0}', '\u{202f}']).collect(),
+            separator => integer.split(separator).collect(),
+        };
+        let grouped = groups.iter().enumerate().all(|(i, group)| {
+            let size_fits = match i {
+                _ if groups.len() == 1 => true,
+                0 => group.len() <= 3,
+                _ => group.len() == 3,
+            };
+            is_digits(group) && size_fits
+        });
+        if !grouped {
+            return None;
+        }
+
+        let mut number = groups.concat();
+        if let Some(fraction) = fraction {
+            if !is_digits(fraction) || fraction.len() > conventions.decimals as usize {
+                return None;
+            }
+            number.push('.');
+            number.push_str(fraction);
+        }
+
+        let value = Decimal::from_str(&number).ok()?;
+        Some(if negative { -value } else { value })
+    }
+
+    /// Formats an amount with the conventions of the locale, rounding it to
+    /// the decimals of the currency.
+    ///
+    /// ```
+    /// use inquire::currency::CurrencyLocale;
+    /// use rust_decimal::Decimal;
+    ///
+    /// let amount = Decimal::new(-123456789, 3);
+    ///
+    /// assert_eq!("-$123,456.79", CurrencyLocale::EnUs.format(amount));
+    /// assert_eq!("-123.456,79 €", CurrencyLocale::DeDe.format(amount));
+    /// assert_eq!("-¥123,457", CurrencyLocale::JaJp.format(amount));
+    /// ```
+    pub fn format(self, amount: Decimal) -> String {
+        let conventions = self.conventions();
+
+        let rounded = amount.round_dp(conventions.decimals);
+        let digits = format!("{:.*}", conventions.decimals as usize, rounded.abs());
+        let (integer, fraction) = match digits.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (digits.as_str(), None),
+        };
+
+        let mut number = String::new();
+        for (i, digit) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                number.push(conventions.thousands_separator);
+            }
+            number.push(digit);
+        }
+        if let Some(fraction) = fraction {
+            number.push(conventions.decimal_separator);
+            number.push_str(fraction);
+        }
+
+        let space = if conventions.symbol_spaced { " " } else { "" };
+        let sign = if rounded.is_sign_negative() && !rounded.is_zero() {
+            "-"
+        } else {
+            ""
+        };
+
+        match conventions.symbol_first {
+            true => format!("{sign}{}{space}{number}", conventions.symbol),
+            false => format!("{sign}{number}{space}{}", conventions.symbol),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use rust_decimal::Decimal;
+
+    use super::CurrencyLocale;
+
+    fn dec(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn amounts_follow_the_separators_of_the_locale() {
+        assert_eq!(
+            Some(dec("1234567.8")),
+            CurrencyLocale::EnGb.parse("£1,234,567.8")
+        );
+        assert_eq!(
+            Some(dec("1234.56")),
+            CurrencyLocale::DeDe.parse("1.234,56€")
+        );
+        assert_eq!(
+            Some(dec("1234.56")),
+            CurrencyLocale::FrFr.parse("1 234,56 €")
+        );
+        assert_eq!(
+            Some(dec("1234.56")),
+            CurrencyLocale::FrFr.parse("1\u{202f}234,56")
+        );
+        assert_eq!(Some(dec("-10")), CurrencyLocale::PtBr.parse("-R$ 10"));
+        assert_eq!(Some(dec("1500")), CurrencyLocale::JaJp.parse("¥1,500"));
+    }
+
+    #[test]
+    fn ambiguous_amounts_are_rejected() {
+        assert_eq!(None, CurrencyLocale::DeDe.parse("1234.56"));
+        assert_eq!(None, CurrencyLocale::EnUs.parse("1,2345"));
+        assert_eq!(None, CurrencyLocale::EnUs.parse("12.345"));
+        assert_eq!(None, CurrencyLocale::JaJp.parse("¥12.5"));
+        assert_eq!(None, CurrencyLocale::EnUs.parse("$"));
+        assert_eq!(None, CurrencyLocale::EnUs.parse("€12"));
+    }
+
+    #[test]
+    fn amounts_are_formatted_with_the_currency_symbol() {
+        assert_eq!("$0.00", CurrencyLocale::EnUs.format(Decimal::ZERO));
+        assert_eq!("£999.10", CurrencyLocale::EnGb.format(dec("999.1")));
+        assert_eq!(
+            "1\u{202f}000\u{202f}000,00 €",
+            CurrencyLocale::FrFr.format(dec("1000000"))
+        );
+        assert_eq!("R$ 1.234,57", CurrencyLocale::PtBr.format(dec("1234.567")));
+        assert_eq!("$0.00", CurrencyLocale::EnUs.format(dec("-0.001")));
+    }
+}
@@ -1,6 +1,6 @@
 use core::panic;
 
-use chrono::NaiveDate;
+use chrono::{Datelike, Duration, NaiveDate};
 
 pub fn get_current_date() -> NaiveDate {
     chrono::Local::now().date_naive()
@@ -10,6 +10,46 @@ pub fn get_start_date(month: chrono::Month, year: i32) -> NaiveDate {
     chrono::NaiveDate::from_ymd_opt(year, month.number_from_month(), 1).unwrap()
 }
 
+/// Monday of the calendar row containing the date, rows starting on
+/// `week_start`. Its ISO week is the one displayed for the row.
+pub fn get_row_monday(date: NaiveDate, week_start: chrono::Weekday) -> NaiveDate {
+    let offset =
+        (7 + date.weekday().num_days_from_monday() - week_start.num_days_from_monday()) % 7;
+    let row_start = date - Duration::days(i64::from(offset));
+
+    row_start
+        + Duration::days(i64::from(
+            (7 - row_start.weekday().num_days_from_monday()) % 7,
+        ))
+}
+
+/// Lowercase name of the month displayed in the calendar header, localized
+/// when the `i18n` feature is enabled.
+pub fn get_month_name(month: chrono::Month) -> String {
+    #[cfg(feature = "i18n")]
+    return crate::i18n::get_locale().month_name(month).to_owned();
+
+    #[cfg(not(feature = "i18n"))]
+    month.name().to_lowercase()
+}
+
+/// Two-letter lowercase abbreviation of the weekday displayed in the calendar
+/// header, localized when the `i18n` feature is enabled.
+pub fn get_weekday_abbreviation(weekday: chrono::Weekday) -> String {
+    #[cfg(feature = "i18n")]
+    return crate::i18n::get_locale()
+        .weekday_abbreviation(weekday)
+        .to_owned();
+
+    #[cfg(not(feature = "i18n"))]
+    {
+        let mut formatted = format!("{weekday}");
+        formatted.make_ascii_lowercase();
+        formatted.pop();
+        formatted
+    }
+}
+
 pub fn get_month(month: u32) -> chrono::Month {
     match month {
         1 => chrono::Month::January,
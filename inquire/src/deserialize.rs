@@ -0,0 +1,749 @@
+//! Filling in any [`Deserialize`] type, or a [`serde_json::Value`] template,
+//! through a sequence of prompts, one for each of its fields.
+//!
+//! Requires the `deserialize` feature. The prompt of each field is chosen by
+//! its type:
+//!
+//! - strings are asked with a [`Text`] prompt;
+//! - booleans are asked with a [`Confirm`] prompt;
+//! - numbers and characters are asked with a [`CustomType`] prompt of the
+//!   exact type of the field, e.g. a `u8` field does not accept `300`;
+//! - enums of unit variants are asked with a [`Select`] prompt listing their
+//!   variants;
+//! - nested structs are filled in field by field;
+//! - `Option` fields can be skipped by pressing ESC, leaving them as `None`.
+//!   Optional nested structs are preceded by a [`Confirm`] prompt asking
+//!   whether to fill them in.
+//!
+//! The message of each prompt is the name of the field, e.g. `Max retries:`
+//! for `max_retries`, prefixed by the names of its parent structs.
+//!
+//! Types whose structure is only known after reading the input, such as
+//! sequences, maps or untagged enums, are not supported and return
+//! [`InquireError::InvalidConfiguration`].
+//!
+//! # Example
+//!
+//! ```
+//! use inquire::{deserialize::from_prompts_with_terminal, terminal::TestBackend, ui::Key};
+//! use serde::Deserialize;
+//!
+//! #[derive(Debug, PartialEq, Deserialize)]
+//! enum Level {
+//!     Debug,
+//!     Info,
+//! }
+//!
+//! #[derive(Debug, PartialEq, Deserialize)]
+//! struct Config {
+//!     name: String,
+//!     max_retries: u32,
+//!     verbose: bool,
+//!     level: Level,
+//!     timeout: Option<u64>,
+//! }
+//!
+//! let mut terminal = TestBackend::new(vec![]).with_text("api\n3\ny\ninf\n");
+//! terminal.push_key(Key::Escape);
+//!
+//! let config: Config = from_prompts_with_terminal(&mut terminal)?;
+//!
+//! assert_eq!(
+//!     Config {
+//!         name: String::from("api"),
+//!         max_retries: 3,
+//!         verbose: true,
+//!         level: Level::Info,
+//!         timeout: None,
+//!     },
+//!     config,
+//! );
+//! assert!(terminal.frames().contains(&String::from("> Max retries: 3\n")));
+//! # Ok::<(), inquire::InquireError>(())
+//! ```
+
+use std::{fmt, str::FromStr};
+
+use serde::de::{
+    self, value::StrDeserializer, DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer,
+    MapAccess, VariantAccess, Visitor,
+};
+use serde_json::{Map, Number, Value};
+
+use crate::{
+    error::{InquireError, InquireResult},
+    terminal::{get_default_terminal, Terminal},
+    Confirm, CustomType, Select, Text,
+};
+
+#[cfg(doc)]
+use serde::Deserialize;
+
+/// Prompts the user for each field of `T`, in declaration order, and returns
+/// the filled value.
+pub fn from_prompts<T: DeserializeOwned>() -> InquireResult<T> {
+    from_prompts_with_terminal(get_default_terminal()?)
+}
+
+/// Prompts the user for each field of `T` through the given terminal, see
+/// [`from_prompts`].
+pub fn from_prompts_with_terminal<T, Term>(mut terminal: Term) -> InquireResult<T>
+where
+    T: DeserializeOwned,
+    Term: Terminal,
+{
+    let mut schema = Schema::Unknown;
+    T::deserialize(Probe {
+        schema: &mut schema,
+    })
+    .map_err(|err| InquireError::InvalidConfiguration(err.0))?;
+
+    let value = ask(&schema, "", false, &mut terminal)?.unwrap_or(Value::Null);
+
+    serde_json::from_value(value).map_err(|err| {
+        InquireError::InvalidConfiguration(format!("the answers do not match the type: {err}"))
+    })
+}
+
+/// Prompts the user for each field of a JSON object template, in the order
+/// they appear in the template, and returns the filled object.
+///
+/// The prompt of each field is chosen by its value in the template, which is
+/// also its default answer:
+///
+/// - strings, booleans and numbers are asked with a prompt of their type;
+/// - arrays of strings are asked with a [`Select`] prompt listing them;
+/// - nested objects are filled in field by field;
+/// - `null` fields are asked with a [`Text`] prompt that can be skipped by
+///   pressing ESC, leaving them as `null`.
+///
+/// ```
+/// use inquire::{deserialize::fill_template_with_terminal, terminal::TestBackend};
+/// use serde_json::json;
+///
+/// let template = json!({
+///     "name": "api",
+///     "port": 8080,
+///     "level": ["debug", "info"],
+/// });
+///
+/// let mut terminal = TestBackend::new(vec![]).with_text("\n\ninf\n");
+/// let answers = fill_template_with_terminal(&template, &mut terminal)?;
+///
+/// assert_eq!(json!({ "name": "api", "port": 8080, "level": "info" }), answers);
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+pub fn fill_template(template: &Value) -> InquireResult<Value> {
+    fill_template_with_terminal(template, get_default_terminal()?)
+}
+
+/// Prompts the user for each field of a JSON object template through the
+/// given terminal, see [`fill_template`].
+pub fn fill_template_with_terminal<Term: Terminal>(
+    template: &Value,
+    mut terminal: Term,
+) -> InquireResult<Value> {
+    let schema = Schema::from_template(template)?;
+
+    Ok(ask(&schema, "", false, &mut terminal)?.unwrap_or(Value::Null))
+}
+
+/// Structure of the value to be filled in, along with the default answers
+/// given by a template.
+#[derive(Debug, PartialEq)]
+enum Schema {
+    Unknown,
+    Unit,
+    Bool(Option<bool>),
+    Char,
+    String(Option<String>),
+    Number(NumberKind, Option<Number>),
+    Enum(Vec<String>),
+    Optional(Box<Schema>),
+    Struct(Vec<(String, Schema)>),
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum NumberKind {
+    I8,
+    I16,
+    I32,
+    I64,
+    U8,
+    U16,
+    U32,
+    U64,
+    F32,
+    F64,
+}
+
+impl Schema {
+    fn from_template(template: &Value) -> InquireResult<Self> {
+        Ok(match template {
+            Value::Null => Self::Optional(Box::new(Self::String(None))),
+            Value::Bool(default) => Self::Bool(Some(*default)),
+            Value::Number(default) => {
+                let kind = if default.is_u64() {
+                    NumberKind::U64
+                } else if default.is_i64() {
+                    NumberKind::I64
+                } else {
+                    NumberKind::F64
+                };
+
+                Self::Number(kind, Some(default.clone()))
+            }
+            Value::String(default) => Self::String(Some(default.clone())),
+            Value::Array(options) => {
+                let variants = options
+                    .iter()
+                    .map(|option| option.as_str().map(String::from))
+                    .collect::<Option<Vec<_>>>()
+                    .filter(|variants| !variants.is_empty())
+                    .ok_or_else(|| {
+                        InquireError::InvalidConfiguration(
+                            "template arrays must be non-empty lists of strings".into(),
+                        )
+                    })?;
+
+                Self::Enum(variants)
+            }
+            Value::Object(fields) => Self::Struct(
+                fields
+                    .iter()
+                    .map(|(name, value)| Ok((name.clone(), Self::from_template(value)?)))
+                    .collect::<InquireResult<_>>()?,
+            ),
+        })
+    }
+}
+
+/// Asks for the value of the given schema, returning `None` if the prompt was
+/// skippable and skipped.
+fn ask<Term: Terminal>(
+    schema: &Schema,
+    path: &str,
+    skippable: bool,
+    terminal: &mut Term,
+) -> InquireResult<Option<Value>> {
+    let message = message(path);
+
+    match schema {
+        Schema::Unknown | Schema::Unit => Ok(Some(Value::Null)),
+        Schema::Bool(default) => {
+            let mut prompt = Confirm::new(&message);
+            if let Some(default) = default {
+                prompt = prompt.with_default(*default);
+            }
+
+            skip(prompt.prompt_with_terminal(terminal), skippable).map(|a| a.map(Value::Bool))
+        }
+        Schema::Char => ask_custom::<char, _>(&message, None, skippable, terminal)
+            .map(|a| a.map(|c| Value::String(c.to_string()))),
+        Schema::String(default) => {
+            let mut prompt = Text::new(&message);
+            if let Some(default) = default {
+                prompt = prompt.with_default(default);
+            }
+
+            skip(prompt.prompt_with_terminal(terminal), skippable).map(|a| a.map(Value::String))
+        }
+        Schema::Number(kind, default) => {
+            let default = default.as_ref();
+
+            match kind {
+                NumberKind::I8 => ask_number::<i8, _>(&message, default, skippable, terminal),
+                NumberKind::I16 => ask_number::<i16, _>(&message, default, skippable, terminal),
+                NumberKind::I32 => ask_number::<i32, _>(&message, default, skippable, terminal),
+                NumberKind::I64 => ask_number::<i64, _>(&message, default, skippable, terminal),
+                NumberKind::U8 => ask_number::<u8, _>(&message, default, skippable, terminal),
+                NumberKind::U16 => ask_number::<u16, _>(&message, default, skippable, terminal),
+                NumberKind::U32 => ask_number::<u32, _>(&message, default, skippable, terminal),
+                NumberKind::U64 => ask_number::<u64, _>(&message, default, skippable, terminal),
+                NumberKind::F32 => ask_number::<f32, _>(&message, default, skippable, terminal),
+                NumberKind::F64 => ask_number::<f64, _>(&message, default, skippable, terminal),
+            }
+        }
+        Schema::Enum(variants) => skip(
+            Select::new(&message, variants.clone()).prompt_with_terminal(terminal),
+            skippable,
+        )
+        .map(|a| a.map(Value::String)),
+        Schema::Optional(inner) => match &**inner {
+            Schema::Struct(_) => {
+                let confirm = Confirm::new(&format!("Set {}?", label(path)))
+                    .with_default(false)
+                    .prompt_with_terminal(&mut *terminal)?;
+
+                match confirm {
+                    true => ask(inner, path, false, terminal),
+                    false => Ok(Some(Value::Null)),
+                }
+            }
+            _ => Ok(Some(
+                ask(inner, path, true, terminal)?.unwrap_or(Value::Null),
+            )),
+        },
+        Schema::Struct(fields) => {
+            let mut object = Map::new();
+
+            for (name, schema) in fields {
+                let path = match path {
+                    "" => name.clone(),
+                    parent => format!("{parent}_{name}"),
+                };
+                let value = ask(schema, &path, false, terminal)?.unwrap_or(Value::Null);
+
+                object.insert(name.clone(), value);
+            }
+
+            Ok(Some(Value::Object(object)))
+        }
+    }
+}
+
+fn ask_number<T, Term>(
+    message: &str,
+    default: Option<&Number>,
+    skippable: bool,
+    terminal: &mut Term,
+) -> InquireResult<Option<Value>>
+where
+    T: Clone + FromStr + ToString + Into<Value>,
+    Term: Terminal,
+{
+    let default = default.and_then(|default| default.to_string().parse::<T>().ok());
+
+    ask_custom::<T, _>(message, default, skippable, terminal).map(|a| a.map(Into::into))
+}
+
+fn ask_custom<T, Term>(
+    message: &str,
+    default: Option<T>,
+    skippable: bool,
+    terminal: &mut Term,
+) -> InquireResult<Option<T>>
+where
+    T: Clone + FromStr + ToString,
+    Term: Terminal,
+{
+    let mut prompt = CustomType::<T>::new(message);
+    if let Some(default) = default {
+        prompt = prompt.with_default(default);
+    }
+
+    skip(prompt.prompt_with_terminal(terminal), skippable)
+}
+
+/// Treats a canceled prompt as skipped, when allowed.
+fn skip<T>(answer: InquireResult<T>, skippable: bool) -> InquireResult<Option<T>> {
+    match answer {
+        Ok(answer) => Ok(Some(answer)),
+        Err(InquireError::OperationCanceled) if skippable => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Lowercase words of a field path, e.g. `database port` for `database_port`.
+fn label(path: &str) -> String {
+    path.trim_start_matches("r#")
+        .split(['_', '-'])
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Message of the prompt of a field path, e.g. `Database port:` for
+/// `database_port`.
+fn message(path: &str) -> String {
+    let label = label(path);
+    let mut chars = label.chars();
+
+    match chars.next() {
+        Some(first) => format!("{}{}:", first.to_uppercase(), chars.as_str()),
+        None => String::from("Value:"),
+    }
+}
+
+/// Error of the [`Probe`] deserializer, for types it can not describe.
+#[derive(Debug)]
+struct ProbeError(String);
+
+impl fmt::Display for ProbeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for ProbeError {}
+
+impl de::Error for ProbeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+fn unsupported(what: &str) -> ProbeError {
+    ProbeError(format!("{what} can not be filled in with prompts"))
+}
+
+/// Deserializer that records the structure of the type being deserialized,
+/// feeding it placeholder values.
+struct Probe<'s> {
+    schema: &'s mut Schema,
+}
+
+macro_rules! probe_number {
+    ($($method:ident, $visit:ident, $kind:ident, $zero:expr;)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ProbeError> {
+                *self.schema = Schema::Number(NumberKind::$kind, None);
+                visitor.$visit($zero)
+            }
+        )*
+    };
+}
+
+impl<'de, 's> de::Deserializer<'de> for Probe<'s> {
+    type Error = ProbeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, ProbeError> {
+        Err(unsupported("types without a fixed structure"))
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ProbeError> {
+        *self.schema = Schema::Bool(None);
+        visitor.visit_bool(false)
+    }
+
+    probe_number! {
+        deserialize_i8, visit_i8, I8, 0;
+        deserialize_i16, visit_i16, I16, 0;
+        deserialize_i32, visit_i32, I32, 0;
+        deserialize_i64, visit_i64, I64, 0;
+        deserialize_u8, visit_u8, U8, 0;
+        deserialize_u16, visit_u16, U16, 0;
+        deserialize_u32, visit_u32, U32, 0;
+        deserialize_u64, visit_u64, U64, 0;
+        deserialize_f32, visit_f32, F32, 0.0;
+        deserialize_f64, visit_f64, F64, 0.0;
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ProbeError> {
+        *self.schema = Schema::Char;
+        visitor.visit_char(' ')
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ProbeError> {
+        *self.schema = Schema::String(None);
+        visitor.visit_str("")
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ProbeError> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, _: V) -> Result<V::Value, ProbeError> {
+        Err(unsupported("byte arrays"))
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, _: V) -> Result<V::Value, ProbeError> {
+        Err(unsupported("byte arrays"))
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ProbeError> {
+        let mut inner = Schema::Unknown;
+        let value = visitor.visit_some(Probe { schema: &mut inner })?;
+
+        *self.schema = Schema::Optional(Box::new(inner));
+        Ok(value)
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ProbeError> {
+        *self.schema = Schema::Unit;
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, ProbeError> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, ProbeError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, _: V) -> Result<V::Value, ProbeError> {
+        Err(unsupported("sequences"))
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _: usize, _: V) -> Result<V::Value, ProbeError> {
+        Err(unsupported("tuples"))
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: usize,
+        _: V,
+    ) -> Result<V::Value, ProbeError> {
+        Err(unsupported("tuple structs"))
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, _: V) -> Result<V::Value, ProbeError> {
+        Err(unsupported("maps"))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ProbeError> {
+        let mut schemas = vec![];
+        let value = visitor.visit_map(ProbeFields {
+            fields,
+            schemas: &mut schemas,
+        })?;
+
+        *self.schema = Schema::Struct(schemas);
+        Ok(value)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ProbeError> {
+        let first = variants
+            .first()
+            .ok_or_else(|| unsupported("enums without variants"))?;
+
+        *self.schema = Schema::Enum(variants.iter().map(|v| String::from(*v)).collect());
+        visitor.visit_enum(ProbeVariant(first))
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ProbeError> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ProbeError> {
+        visitor.visit_unit()
+    }
+}
+
+/// Fields of a struct being probed, recording the structure of each.
+struct ProbeFields<'s> {
+    fields: &'static [&'static str],
+    schemas: &'s mut Vec<(String, Schema)>,
+}
+
+impl<'de, 's> MapAccess<'de> for ProbeFields<'s> {
+    type Error = ProbeError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, ProbeError> {
+        match self.fields.get(self.schemas.len()) {
+            Some(field) => {
+                let key: StrDeserializer<'_, ProbeError> = field.into_deserializer();
+                seed.deserialize(key).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<S: DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> Result<S::Value, ProbeError> {
+        let field = self.fields[self.schemas.len()];
+        let mut schema = Schema::Unknown;
+        let value = seed.deserialize(Probe {
+            schema: &mut schema,
+        })?;
+
+        self.schemas.push((String::from(field), schema));
+        Ok(value)
+    }
+}
+
+/// Variant of an enum being probed, only supporting unit variants.
+struct ProbeVariant(&'static str);
+
+impl<'de> EnumAccess<'de> for ProbeVariant {
+    type Error = ProbeError;
+    type Variant = Self;
+
+    fn variant_seed<S: DeserializeSeed<'de>>(
+        self,
+        seed: S,
+    ) -> Result<(S::Value, Self), ProbeError> {
+        let variant: StrDeserializer<'_, ProbeError> = self.0.into_deserializer();
+        seed.deserialize(variant).map(|value| (value, self))
+    }
+}
+
+impl<'de> VariantAccess<'de> for ProbeVariant {
+    type Error = ProbeError;
+
+    fn unit_variant(self) -> Result<(), ProbeError> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<S: DeserializeSeed<'de>>(self, _: S) -> Result<S::Value, ProbeError> {
+        Err(unsupported("enums with non-unit variants"))
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _: usize, _: V) -> Result<V::Value, ProbeError> {
+        Err(unsupported("enums with non-unit variants"))
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _: &'static [&'static str],
+        _: V,
+    ) -> Result<V::Value, ProbeError> {
+        Err(unsupported("enums with non-unit variants"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use super::{fill_template_with_terminal, from_prompts_with_terminal, message};
+    use crate::{
+        error::InquireError,
+        terminal::TestBackend,
+        ui::{Key, KeyModifiers},
+    };
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Database {
+        host: String,
+        port: u16,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Service {
+        name: String,
+        database: Database,
+        replica: Option<Database>,
+        #[serde(rename = "log-level")]
+        log_level: Option<char>,
+    }
+
+    #[test]
+    fn nested_structs_are_filled_field_by_field() {
+        let mut terminal =
+            TestBackend::new(vec![]).with_text("api\nlocalhost\n5432\ny\nreplica\n5433\nw\n");
+        let service: Service = from_prompts_with_terminal(&mut terminal).unwrap();
+
+        assert_eq!(
+            Service {
+                name: String::from("api"),
+                database: Database {
+                    host: String::from("localhost"),
+                    port: 5432,
+                },
+                replica: Some(Database {
+                    host: String::from("replica"),
+                    port: 5433,
+                }),
+                log_level: Some('w'),
+            },
+            service
+        );
+        assert!(terminal
+            .frames()
+            .contains(&String::from("> Database port: 5432\n")));
+        assert!(terminal
+            .frames()
+            .contains(&String::from("> Set replica? Yes\n")));
+        assert!(terminal
+            .frames()
+            .contains(&String::from("> Log level: w\n")));
+    }
+
+    #[test]
+    fn optional_fields_can_be_skipped() {
+        let mut terminal = TestBackend::new(vec![]).with_text("api\nlocalhost\n5432\n\n");
+        terminal.push_key(Key::Escape);
+
+        let service: Service = from_prompts_with_terminal(&mut terminal).unwrap();
+
+        assert_eq!(None, service.replica);
+        assert_eq!(None, service.log_level);
+    }
+
+    #[test]
+    fn numbers_are_parsed_as_the_field_type() {
+        let mut terminal = TestBackend::new(vec![]).with_text("localhost\n70000\n");
+        for _ in 0..5 {
+            terminal.push_key(Key::Backspace(KeyModifiers::NONE));
+        }
+        terminal.push_text("443\n");
+
+        let database: Database = from_prompts_with_terminal(&mut terminal).unwrap();
+
+        assert_eq!(443, database.port);
+    }
+
+    #[test]
+    fn unsupported_types_are_a_configuration_error() {
+        let mut terminal = TestBackend::new(vec![]);
+        let result = from_prompts_with_terminal::<Vec<String>, _>(&mut terminal);
+
+        assert!(matches!(result, Err(InquireError::InvalidConfiguration(_))));
+    }
+
+    #[test]
+    fn template_values_are_the_defaults() {
+        let template = json!({
+            "name": "api",
+            "debug": false,
+            "timeout": null,
+            "database": { "port": 5432 },
+        });
+
+        let mut terminal = TestBackend::new(vec![]).with_text("\ny\n8\n\n");
+        let answers = fill_template_with_terminal(&template, &mut terminal).unwrap();
+
+        assert_eq!(
+            json!({
+                "name": "api",
+                "debug": true,
+                "timeout": "8",
+                "database": { "port": 5432 },
+            }),
+            answers
+        );
+    }
+
+    #[test]
+    fn template_arrays_must_list_strings() {
+        let template = json!({ "level": [1, 2] });
+        let result = fill_template_with_terminal(&template, TestBackend::new(vec![]));
+
+        assert!(matches!(result, Err(InquireError::InvalidConfiguration(_))));
+    }
+
+    #[test]
+    fn messages_are_humanized_paths() {
+        assert_eq!("Value:", message(""));
+        assert_eq!("Max retries:", message("max_retries"));
+        assert_eq!("Database log level:", message("database_log-level"));
+    }
+}
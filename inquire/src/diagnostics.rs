@@ -0,0 +1,275 @@
+//! Diagnostics about the terminal state left behind by prompts.
+//!
+//! When a prompt finishes, successfully or not, the terminal is restored to its
+//! original state: the cursor is moved below the prompt and made visible again,
+//! and raw mode is disabled. These steps run while the prompt is being dropped,
+//! where errors can not be propagated to the caller, so they are recorded in a
+//! [`CleanupReport`] instead.
+//!
+//! Applications can inspect the report of the last prompt run on the current
+//! thread and, for example, suggest running `reset` when the terminal could not
+//! be restored.
+//!
+//! ```no_run
+//! use inquire::{diagnostics::last_cleanup_report, Text};
+//!
+//! let name = Text::new("What's your name?").prompt();
+//!
+//! if let Some(report) = last_cleanup_report() {
+//!     if !report.is_clean() {
+//!         eprintln!("The terminal might be in a broken state, try running `reset`.");
+//!     }
+//! }
+//! ```
+//!
+//! Cleanup also happens when a panic in user code, such as a validator or a
+//! formatter, unwinds through a prompt. However, the panic message is printed
+//! before that, while the terminal is still in raw mode. Calling
+//! [`install_panic_hook`] restores the terminal before the message is printed,
+//! which also covers applications built with `panic = "abort"`.
+//!
+//! ```no_run
+//! inquire::diagnostics::install_panic_hook();
+//!
+//! let name = inquire::Text::new("What's your name?")
+//!     .with_formatter(&|_| panic!("oops"))
+//!     .prompt();
+//! ```
+
+use std::{
+    cell::RefCell,
+    fmt::Display,
+    io, panic,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Once,
+    },
+};
+
+// Const thread-local initializers were stabilized on v1.59 which would require
+// us to bump the MSRV.
+thread_local! {
+    #[allow(clippy::missing_const_for_thread_local)]
+    static LAST_CLEANUP_REPORT: RefCell<Option<CleanupReport>> = RefCell::new(None);
+}
+
+/// Number of prompts currently holding the terminal, across all threads.
+static ACTIVE_PROMPTS: AtomicUsize = AtomicUsize::new(0);
+
+/// Step of the terminal cleanup performed when a prompt finishes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CleanupStep {
+    /// Moving the cursor to the end of the rendered prompt.
+    MoveCursor,
+    /// Making the cursor visible again.
+    ShowCursor,
+    /// Flushing pending output to the terminal.
+    Flush,
+    /// Disabling the terminal raw mode.
+    DisableRawMode,
+    /// Wrapping lines longer than the terminal width again, after a prompt
+    /// rendered in strict inline mode.
+    EnableLineWrap,
+    /// Switching back to the main screen, after a prompt rendered in
+    /// fullscreen that was not answered.
+    LeaveAlternateScreen,
+}
+
+impl Display for CleanupStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let step = match self {
+            Self::MoveCursor => "move cursor",
+            Self::ShowCursor => "show cursor",
+            Self::Flush => "flush output",
+            Self::DisableRawMode => "disable raw mode",
+            Self::EnableLineWrap => "enable line wrap",
+            Self::LeaveAlternateScreen => "leave alternate screen",
+        };
+
+        f.write_str(step)
+    }
+}
+
+/// Error that happened, and was suppressed, during a step of the terminal cleanup.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CleanupError {
+    /// Step that failed.
+    pub step: CleanupStep,
+    /// Kind of the underlying IO error.
+    pub kind: io::ErrorKind,
+    /// Message of the underlying IO error.
+    pub message: String,
+}
+
+impl Display for CleanupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "failed to {}: {}", self.step, self.message)
+    }
+}
+
+/// Report of the terminal cleanup performed when a prompt finishes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CleanupReport {
+    errors: Vec<CleanupError>,
+}
+
+impl CleanupReport {
+    /// Whether all cleanup steps succeeded.
+    pub fn is_clean(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Whether the cursor was made visible again.
+    ///
+    /// Terminals buffer their output, so the cursor is only visible again once
+    /// the output was flushed.
+    pub fn cursor_restored(&self) -> bool {
+        !self.failed(CleanupStep::ShowCursor) && !self.failed(CleanupStep::Flush)
+    }
+
+    /// Whether raw mode was disabled, or never enabled in the first place.
+    pub fn raw_mode_restored(&self) -> bool {
+        !self.failed(CleanupStep::DisableRawMode)
+    }
+
+    /// Errors suppressed during the cleanup, in the order they happened.
+    pub fn errors(&self) -> &[CleanupError] {
+        &self.errors
+    }
+
+    fn failed(&self, step: CleanupStep) -> bool {
+        self.errors.iter().any(|e| e.step == step)
+    }
+}
+
+/// Returns the report of the terminal cleanup of the last prompt run on the
+/// current thread, if any.
+pub fn last_cleanup_report() -> Option<CleanupReport> {
+    LAST_CLEANUP_REPORT.with(|report| report.borrow().clone())
+}
+
+/// Installs a panic hook that restores the terminal before the panic message
+/// is printed, if a prompt is running when the panic happens.
+///
+/// The previously installed hook is called afterwards, and calling this function
+/// more than once has no further effect.
+pub fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if ACTIVE_PROMPTS.load(Ordering::SeqCst) > 0 {
+                crate::terminal::restore_terminal();
+                eprintln!();
+            }
+            previous(info);
+        }));
+    });
+}
+
+/// Marks a prompt as holding the terminal.
+pub(crate) fn prompt_started() {
+    ACTIVE_PROMPTS.fetch_add(1, Ordering::SeqCst);
+}
+
+/// Marks a prompt as no longer holding the terminal.
+pub(crate) fn prompt_finished() {
+    ACTIVE_PROMPTS.fetch_sub(1, Ordering::SeqCst);
+}
+
+/// Starts a new cleanup report, replacing the previous one.
+pub(crate) fn begin_cleanup() {
+    LAST_CLEANUP_REPORT.with(|report| *report.borrow_mut() = Some(CleanupReport::default()));
+}
+
+/// Records the result of a cleanup step in the current report.
+pub(crate) fn record_cleanup(step: CleanupStep, result: io::Result<()>) {
+    if let Err(err) = result {
+        let error = CleanupError {
+            step,
+            kind: err.kind(),
+            message: err.to_string(),
+        };
+
+        LAST_CLEANUP_REPORT.with(|report| {
+            report
+                .borrow_mut()
+                .get_or_insert_with(CleanupReport::default)
+                .errors
+                .push(error)
+        });
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test {
+    use std::{
+        cell::Cell,
+        io::{self, Write},
+        rc::Rc,
+    };
+
+    use crossterm::event::KeyEvent;
+
+    use super::{last_cleanup_report, CleanupStep};
+    use crate::{
+        terminal::crossterm::CrosstermTerminal,
+        ui::{Backend, RenderConfig},
+    };
+
+    struct BreakableWriter {
+        broken: Rc<Cell<bool>>,
+    }
+
+    impl Write for BreakableWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            match self.broken.get() {
+                true => Err(io::Error::new(io::ErrorKind::BrokenPipe, "broken")),
+                false => Ok(buf.len()),
+            }
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn clean_drop_produces_clean_report() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+        let mut write: Vec<u8> = Vec::new();
+
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+        drop(backend);
+
+        let report = last_cleanup_report().unwrap();
+        assert!(report.is_clean());
+        assert!(report.cursor_restored());
+    }
+
+    #[test]
+    fn suppressed_drop_errors_are_reported() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+        let broken = Rc::new(Cell::new(false));
+        let mut write = BreakableWriter {
+            broken: broken.clone(),
+        };
+
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+        broken.set(true);
+        drop(backend);
+
+        let report = last_cleanup_report().unwrap();
+        assert!(!report.is_clean());
+        assert!(!report.cursor_restored());
+        assert!(report.raw_mode_restored());
+        assert_eq!(CleanupStep::Flush, report.errors()[0].step);
+        assert_eq!(io::ErrorKind::BrokenPipe, report.errors()[0].kind);
+    }
+}
@@ -31,17 +31,159 @@ pub enum InquireError {
 
     /// The operation was interrupted by the user after they
     /// pressed Ctrl+C.
-    ///
-    /// This error will be returned only when using `crossterm`
-    /// or `termion` as the terminal back-end. If using `console`,
-    /// pressing Ctrl+C will trigger SIGINT.
     #[error("Operation was interrupted by the user")]
     OperationInterrupted,
 
+    /// The user did not interact with a prompt configured with a timeout
+    /// before it expired, and the prompt had no default answer to submit.
+    #[error("Prompt timed out without an answer")]
+    Timeout,
+
     /// Error while executing IO operations.
     #[error("User-provided error: {0}")]
     Custom(#[from] CustomUserError),
 }
 
+impl InquireError {
+    /// Returns a suggestion to solve the error, meant to be shown to the user
+    /// alongside the error message.
+    ///
+    /// ```
+    /// use inquire::InquireError;
+    ///
+    /// assert!(InquireError::NotTTY.hint().unwrap().contains("interactive terminal"));
+    /// assert_eq!(None, InquireError::OperationCanceled.hint());
+    /// ```
+    pub fn hint(&self) -> Option<&'static str> {
+        match self {
+            Self::NotTTY => Some(
+                "run the program from an interactive terminal, the answers can not be read from a pipe or a file",
+            ),
+            Self::InvalidConfiguration(_) => {
+                Some("the prompt was misconfigured by the application, consider reporting it as a bug")
+            }
+            Self::Timeout => Some("answer the prompt before the time runs out"),
+            Self::IO(_)
+            | Self::OperationCanceled
+            | Self::OperationInterrupted
+            | Self::Custom(_) => None,
+        }
+    }
+
+    /// Identifier of the kind of error, such as `inquire::not_tty`.
+    #[cfg(feature = "miette")]
+    fn diagnostic_code(&self) -> &'static str {
+        match self {
+            Self::NotTTY => "inquire::not_tty",
+            Self::InvalidConfiguration(_) => "inquire::invalid_configuration",
+            Self::IO(_) => "inquire::io",
+            Self::OperationCanceled => "inquire::operation_canceled",
+            Self::OperationInterrupted => "inquire::operation_interrupted",
+            Self::Timeout => "inquire::timeout",
+            Self::Custom(_) => "inquire::custom",
+        }
+    }
+
+    /// Converts the error into an [`anyhow::Error`] whose message includes the
+    /// [hint](Self::hint), if any.
+    ///
+    /// Requires the `anyhow` feature.
+    ///
+    /// ```no_run
+    /// use inquire::{InquireError, Text};
+    ///
+    /// fn main() -> anyhow::Result<()> {
+    ///     let name = Text::new("What's your name?")
+    ///         .prompt()
+    ///         .map_err(InquireError::into_anyhow)?;
+    ///
+    ///     println!("Hello, {}!", name);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "anyhow")]
+    pub fn into_anyhow(self) -> anyhow::Error {
+        match self.hint() {
+            Some(hint) => anyhow::Error::new(HintedError { error: self, hint }),
+            None => anyhow::Error::new(self),
+        }
+    }
+}
+
+/// Error displayed along with its hint.
+#[cfg(feature = "anyhow")]
+#[derive(Debug)]
+struct HintedError {
+    error: InquireError,
+    hint: &'static str,
+}
+
+#[cfg(feature = "anyhow")]
+impl std::fmt::Display for HintedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\n\nhelp: {}", self.error, self.hint)
+    }
+}
+
+#[cfg(feature = "anyhow")]
+impl std::error::Error for HintedError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        std::error::Error::source(&self.error)
+    }
+}
+
+/// Diagnostic with a code and, when available, the [hint](InquireError::hint)
+/// as its help text.
+///
+/// Requires the `miette` feature.
+#[cfg(feature = "miette")]
+impl miette::Diagnostic for InquireError {
+    fn code<'a>(&'a self) -> Option<Box<dyn std::fmt::Display + 'a>> {
+        Some(Box::new(self.diagnostic_code()))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn std::fmt::Display + 'a>> {
+        self.hint()
+            .map(|hint| Box::new(hint) as Box<dyn std::fmt::Display>)
+    }
+}
+
 /// Result type where errors are of type [InquireError](crate::error::InquireError)
 pub type InquireResult<T> = Result<T, InquireError>;
+
+#[cfg(test)]
+#[cfg(any(feature = "anyhow", feature = "miette"))]
+mod test {
+    use super::InquireError;
+
+    #[test]
+    #[cfg(feature = "anyhow")]
+    fn anyhow_error_includes_hint() {
+        let error = InquireError::NotTTY.into_anyhow();
+        assert_eq!(
+            "The input device is not a TTY\n\nhelp: run the program from an interactive terminal, \
+             the answers can not be read from a pipe or a file",
+            error.to_string()
+        );
+
+        let error = InquireError::OperationCanceled.into_anyhow();
+        assert!(matches!(
+            error.downcast_ref(),
+            Some(InquireError::OperationCanceled)
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "miette")]
+    fn diagnostic_has_code_and_help() {
+        use miette::Diagnostic;
+
+        let error = InquireError::Timeout;
+        assert_eq!("inquire::timeout", error.code().unwrap().to_string());
+        assert_eq!(
+            Some("answer the prompt before the time runs out".to_owned()),
+            error.help().map(|help| help.to_string())
+        );
+        assert!(InquireError::OperationInterrupted.help().is_none());
+    }
+}
@@ -0,0 +1,112 @@
+//! Events emitted by prompts as the user interacts with them, so applications
+//! can log or trace their interaction flows.
+//!
+//! A listener is set on a prompt with its `with_event_listener` method, or on
+//! all prompts created afterwards with
+//! [`set_global_event_listener`](crate::set_global_event_listener).
+//!
+//! ```
+//! use std::cell::RefCell;
+//!
+//! use inquire::{events::{PromptEvent, PromptEventKind}, terminal::TestBackend, Text};
+//!
+//! let submitted = RefCell::new(vec![]);
+//! let listener = |event: &PromptEvent<'_>| {
+//!     if let PromptEventKind::Submitted(answer) = event.kind {
+//!         submitted.borrow_mut().push(format!("{} {}", event.message, answer));
+//!     }
+//! };
+//!
+//! let mut terminal = TestBackend::new(vec![]).with_text("Ferris\n");
+//! Text::new("Name:")
+//!     .with_event_listener(&listener)
+//!     .prompt_with_terminal(&mut terminal)?;
+//!
+//! assert_eq!(vec![String::from("Name: Ferris")], submitted.into_inner());
+//! # Ok::<(), inquire::InquireError>(())
+//! ```
+//!
+//! With the `tracing` feature, [`trace`] can be used as a listener to emit
+//! the events through [tracing](https://docs.rs/tracing).
+
+use crate::ui::Key;
+
+/// Event emitted by a prompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PromptEvent<'a> {
+    /// Message of the prompt emitting the event.
+    pub message: &'a str,
+
+    /// What happened.
+    pub kind: PromptEventKind<'a>,
+}
+
+/// What happened in a [`PromptEvent`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PromptEventKind<'a> {
+    /// The prompt was rendered to the terminal.
+    Rendered,
+
+    /// The user pressed a key.
+    KeyPressed(Key),
+
+    /// A submission was rejected, e.g. by a validator or because the input
+    /// could not be parsed.
+    ValidationFailed,
+
+    /// The prompt was answered, with the formatted answer as rendered to the
+    /// user.
+    Submitted(&'a str),
+
+    /// The user canceled the prompt.
+    Canceled,
+
+    /// The user interrupted the prompt with Ctrl+C.
+    Interrupted,
+
+    /// The timeout of the prompt expired before any interaction.
+    TimedOut,
+}
+
+impl<'a> PromptEventKind<'a> {
+    /// Name of the event, e.g. `key_pressed`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Rendered => "rendered",
+            Self::KeyPressed(_) => "key_pressed",
+            Self::ValidationFailed => "validation_failed",
+            Self::Submitted(_) => "submitted",
+            Self::Canceled => "canceled",
+            Self::Interrupted => "interrupted",
+            Self::TimedOut => "timed_out",
+        }
+    }
+}
+
+/// Event listener emitting the events through
+/// [tracing](https://docs.rs/tracing), with the `inquire` target.
+///
+/// Renders and key presses are emitted at the `TRACE` level and the other
+/// events at the `DEBUG` level. The pressed keys and submitted answers are
+/// never emitted, as they might be secrets typed into a password prompt.
+///
+/// ```
+/// inquire::set_global_event_listener(Some(&inquire::events::trace));
+/// # inquire::set_global_event_listener(None);
+/// ```
+#[cfg(feature = "tracing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tracing")))]
+pub fn trace(event: &PromptEvent<'_>) {
+    match event.kind {
+        PromptEventKind::Rendered | PromptEventKind::KeyPressed(_) => tracing::trace!(
+            target: "inquire",
+            prompt = event.message,
+            event = event.kind.name()
+        ),
+        _ => tracing::debug!(
+            target: "inquire",
+            prompt = event.message,
+            event = event.kind.name()
+        ),
+    }
+}
@@ -0,0 +1,170 @@
+//! Conventional process exit codes for the outcomes of prompts.
+//!
+//! Shells and scripts wrapping a program tell how it ended from its exit
+//! code: 130, i.e. 128 plus the number of SIGINT, means that the user
+//! interrupted it with Ctrl+C. [`ExitCodes`] maps prompt errors to such codes,
+//! and can terminate the process accordingly once the prompt restored the
+//! terminal.
+//!
+//! ```no_run
+//! use inquire::{exit::ExitCodes, Text};
+//!
+//! let exit_codes = ExitCodes::new().with_canceled(2);
+//!
+//! let name = exit_codes.unwrap_or_exit(Text::new("What's your name?").prompt());
+//! println!("Hello {}", name);
+//! ```
+
+#[cfg(unix)]
+use crate::terminal;
+use crate::{error::InquireResult, InquireError};
+
+/// Exit codes of the process for each kind of prompt error.
+///
+/// - [`InquireError::OperationInterrupted`]: 130 by default, as if the process
+///   was killed by SIGINT.
+/// - [`InquireError::OperationCanceled`]: 1 by default.
+/// - Any other error: 1 by default.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ExitCodes {
+    /// Exit code when the user canceled the prompt by pressing ESC.
+    pub canceled: i32,
+
+    /// Exit code when the user interrupted the prompt by pressing Ctrl+C.
+    pub interrupted: i32,
+
+    /// Exit code for any other error, such as IO errors.
+    pub failure: i32,
+
+    /// Whether the process is killed by SIGINT when the user interrupted the
+    /// prompt, instead of exiting with the `interrupted` code.
+    ///
+    /// Shells only stop running a script when one of its commands is killed by
+    /// SIGINT, not when it exits with code 130.
+    #[cfg(unix)]
+    #[cfg_attr(docsrs, doc(cfg(unix)))]
+    pub reraise_interrupt: bool,
+}
+
+impl ExitCodes {
+    /// Default exit code when the user canceled the prompt.
+    pub const DEFAULT_CANCELED: i32 = 1;
+
+    /// Default exit code when the user interrupted the prompt, 128 plus the
+    /// number of SIGINT.
+    pub const DEFAULT_INTERRUPTED: i32 = 130;
+
+    /// Default exit code for any other error.
+    pub const DEFAULT_FAILURE: i32 = 1;
+
+    /// Creates the exit codes with their default values, without re-raising
+    /// SIGINT.
+    pub fn new() -> Self {
+        Self {
+            canceled: Self::DEFAULT_CANCELED,
+            interrupted: Self::DEFAULT_INTERRUPTED,
+            failure: Self::DEFAULT_FAILURE,
+            #[cfg(unix)]
+            reraise_interrupt: false,
+        }
+    }
+
+    /// Sets the exit code when the user canceled the prompt.
+    pub fn with_canceled(mut self, code: i32) -> Self {
+        self.canceled = code;
+        self
+    }
+
+    /// Sets the exit code when the user interrupted the prompt.
+    pub fn with_interrupted(mut self, code: i32) -> Self {
+        self.interrupted = code;
+        self
+    }
+
+    /// Sets the exit code for any other error.
+    pub fn with_failure(mut self, code: i32) -> Self {
+        self.failure = code;
+        self
+    }
+
+    /// Sets whether the process is killed by SIGINT when the user interrupted
+    /// the prompt.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::{exit::ExitCodes, Text};
+    ///
+    /// let exit_codes = ExitCodes::new().with_reraise_interrupt(true);
+    ///
+    /// let name = exit_codes.unwrap_or_exit(Text::new("What's your name?").prompt());
+    /// println!("Hello {}", name);
+    /// ```
+    #[cfg(unix)]
+    #[cfg_attr(docsrs, doc(cfg(unix)))]
+    pub fn with_reraise_interrupt(mut self, reraise_interrupt: bool) -> Self {
+        self.reraise_interrupt = reraise_interrupt;
+        self
+    }
+
+    /// Exit code for the given error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inquire::{exit::ExitCodes, InquireError};
+    ///
+    /// let exit_codes = ExitCodes::new().with_canceled(2);
+    ///
+    /// assert_eq!(2, exit_codes.code(&InquireError::OperationCanceled));
+    /// assert_eq!(130, exit_codes.code(&InquireError::OperationInterrupted));
+    /// assert_eq!(1, exit_codes.code(&InquireError::NotTTY));
+    /// ```
+    pub fn code(&self, error: &InquireError) -> i32 {
+        match error {
+            InquireError::OperationCanceled => self.canceled,
+            InquireError::OperationInterrupted => self.interrupted,
+            _ => self.failure,
+        }
+    }
+
+    /// Terminates the process with the exit code of the given error.
+    ///
+    /// Errors other than cancellations and interruptions are printed to stderr
+    /// first, along with their [hint](InquireError::hint). When the user
+    /// interrupted the prompt and `reraise_interrupt` is set on UNIX systems,
+    /// the process is killed by SIGINT instead.
+    pub fn exit(&self, error: &InquireError) -> ! {
+        match error {
+            #[cfg(unix)]
+            InquireError::OperationInterrupted if self.reraise_interrupt => {
+                terminal::restore_terminal();
+                terminal::reraise_interrupt_signal();
+            }
+            InquireError::OperationCanceled | InquireError::OperationInterrupted => {}
+            error => {
+                eprintln!("Error: {}", error);
+                if let Some(hint) = error.hint() {
+                    eprintln!("Hint: {}", hint);
+                }
+            }
+        }
+
+        std::process::exit(self.code(error))
+    }
+
+    /// Returns the answer of the prompt, or terminates the process with the
+    /// exit code of its error, see [`exit`](Self::exit).
+    pub fn unwrap_or_exit<T>(&self, result: InquireResult<T>) -> T {
+        match result {
+            Ok(answer) => answer,
+            Err(error) => self.exit(&error),
+        }
+    }
+}
+
+impl Default for ExitCodes {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,281 @@
+//! Evaluation of simple arithmetic expressions typed into numeric prompts.
+//!
+//! Expressions support the `+`, `-`, `*` and `/` operators, parentheses,
+//! unary signs and decimal numbers. A `%` after a value divides it by 100, and
+//! `of` multiplies like `*`, so that `30% of 200` evaluates to `60`.
+//! Parentheses can be nested up to [`MAX_NESTING_DEPTH`] levels deep.
+//!
+//! Requires the `expressions` feature.
+//!
+//! ```
+//! use inquire::expression::evaluate;
+//!
+//! assert_eq!(Ok(8192.0), evaluate("1024*8"));
+//! assert_eq!(Ok(60.0), evaluate("30% of 200"));
+//! assert_eq!(Ok(-4.5), evaluate("-(1 + 2) * 1.5"));
+//! assert!(evaluate("1 / 0").is_err());
+//! ```
+
+use std::{iter::Peekable, str::CharIndices};
+
+use thiserror::Error;
+
+/// Reason why an expression could not be evaluated.
+#[derive(Error, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExpressionError {
+    /// The expression contains an unexpected character, at the given position
+    /// counted in characters.
+    #[error("Unexpected character at position {0}")]
+    UnexpectedCharacter(usize),
+
+    /// The expression ended before it was complete, such as `2 *`.
+    #[error("Unexpected end of expression")]
+    UnexpectedEnd,
+
+    /// The expression divides by zero.
+    #[error("Division by zero")]
+    DivisionByZero,
+
+    /// The result is too large to be represented.
+    #[error("Result is out of range")]
+    OutOfRange,
+
+    /// The expression nests parentheses deeper than
+    /// [`MAX_NESTING_DEPTH`], at the given position counted in characters.
+    #[error("Parentheses nested too deeply at position {0}")]
+    TooDeeplyNested(usize),
+}
+
+/// Maximum number of nested parentheses in an expression, past which
+/// evaluating it would risk overflowing the stack.
+pub const MAX_NESTING_DEPTH: usize = 256;
+
+/// Evaluates the arithmetic expression, see the [module documentation](self)
+/// for the supported syntax.
+pub fn evaluate(expression: &str) -> Result<f64, ExpressionError> {
+    let mut parser = Parser {
+        chars: expression.char_indices().peekable(),
+        expression,
+        depth: 0,
+    };
+
+    let value = parser.sum()?;
+    match parser.peek() {
+        Some((position, _)) => Err(ExpressionError::UnexpectedCharacter(position)),
+        None if value.is_finite() => Ok(value),
+        None => Err(ExpressionError::OutOfRange),
+    }
+}
+
+/// Formats a value resulting from an expression, without a fractional part
+/// when it is an integer.
+pub(crate) fn format_value(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{}", value as i64)
+    } else {
+        format!("{}", value)
+    }
+}
+
+struct Parser<'e> {
+    chars: Peekable<CharIndices<'e>>,
+    expression: &'e str,
+    depth: usize,
+}
+
+impl<'e> Parser<'e> {
+    /// Next non-whitespace character, along with its position in characters.
+    fn peek(&mut self) -> Option<(usize, char)> {
+        while let Some((_, c)) = self.chars.peek() {
+            if !c.is_whitespace() {
+                break;
+            }
+            self.chars.next();
+        }
+
+        let expression = self.expression;
+        self.chars
+            .peek()
+            .map(|(offset, c)| (expression[..*offset].chars().count(), *c))
+    }
+
+    fn sum(&mut self) -> Result<f64, ExpressionError> {
+        let mut value = self.product()?;
+
+        loop {
+            match self.peek() {
+                Some((_, '+')) => {
+                    self.chars.next();
+                    value += self.product()?;
+                }
+                Some((_, '-')) => {
+                    self.chars.next();
+                    value -= self.product()?;
+                }
+                _ => return Ok(value),
+            }
+        }
+    }
+
+    fn product(&mut self) -> Result<f64, ExpressionError> {
+        let mut value = self.unary()?;
+
+        loop {
+            match self.peek() {
+                Some((_, '*')) => {
+                    self.chars.next();
+                    value *= self.unary()?;
+                }
+                Some((_, '/')) => {
+                    self.chars.next();
+                    let divisor = self.unary()?;
+                    if divisor == 0.0 {
+                        return Err(ExpressionError::DivisionByZero);
+                    }
+                    value /= divisor;
+                }
+                Some((position, 'o')) => {
+                    self.chars.next();
+                    match self.chars.next() {
+                        Some((_, 'f')) => value *= self.unary()?,
+                        _ => return Err(ExpressionError::UnexpectedCharacter(position)),
+                    }
+                }
+                _ => return Ok(value),
+            }
+        }
+    }
+
+    fn unary(&mut self) -> Result<f64, ExpressionError> {
+        let mut negative = false;
+        loop {
+            match self.peek() {
+                Some((_, '-')) => negative = !negative,
+                Some((_, '+')) => {}
+                _ => break,
+            }
+            self.chars.next();
+        }
+
+        let value = self.percentage()?;
+        Ok(if negative { -value } else { value })
+    }
+
+    fn percentage(&mut self) -> Result<f64, ExpressionError> {
+        let mut value = self.primary()?;
+
+        while let Some((_, '%')) = self.peek() {
+            self.chars.next();
+            value /= 100.0;
+        }
+
+        Ok(value)
+    }
+
+    fn primary(&mut self) -> Result<f64, ExpressionError> {
+        match self.peek() {
+            Some((position, '(')) => {
+                if self.depth == MAX_NESTING_DEPTH {
+                    return Err(ExpressionError::TooDeeplyNested(position));
+                }
+
+                self.chars.next();
+                self.depth += 1;
+                let value = self.sum()?;
+                self.depth -= 1;
+                match self.peek() {
+                    Some((_, ')')) => {
+                        self.chars.next();
+                        Ok(value)
+                    }
+                    Some((position, _)) => Err(ExpressionError::UnexpectedCharacter(position)),
+                    None => Err(ExpressionError::UnexpectedEnd),
+                }
+            }
+            Some((position, c)) if c.is_ascii_digit() || c == '.' => {
+                let mut number = String::new();
+                while let Some((_, c)) = self.chars.peek() {
+                    if !c.is_ascii_digit() && *c != '.' {
+                        break;
+                    }
+                    number.push(*c);
+                    self.chars.next();
+                }
+
+                number
+                    .parse()
+                    .map_err(|_| ExpressionError::UnexpectedCharacter(position))
+            }
+            Some((position, _)) => Err(ExpressionError::UnexpectedCharacter(position)),
+            None => Err(ExpressionError::UnexpectedEnd),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{evaluate, format_value, ExpressionError, MAX_NESTING_DEPTH};
+
+    #[test]
+    fn operators_follow_precedence() {
+        assert_eq!(Ok(14.0), evaluate("2 + 3 * 4"));
+        assert_eq!(Ok(20.0), evaluate("(2 + 3) * 4"));
+        assert_eq!(Ok(1.0), evaluate("10 - 6 - 3"));
+        assert_eq!(Ok(2.5), evaluate("10 / 2 / 2"));
+        assert_eq!(Ok(-6.0), evaluate("-2 * +3"));
+        assert_eq!(Ok(2.0), evaluate("--+-(-2)"));
+    }
+
+    #[test]
+    fn deep_nesting_does_not_overflow_the_stack() {
+        let signs = "-".repeat(100_000) + "1";
+        assert_eq!(Ok(1.0), evaluate(&signs));
+
+        let nested = |depth| "(".repeat(depth) + "1" + &")".repeat(depth);
+        assert_eq!(Ok(1.0), evaluate(&nested(MAX_NESTING_DEPTH)));
+        assert_eq!(
+            Err(ExpressionError::TooDeeplyNested(MAX_NESTING_DEPTH)),
+            evaluate(&nested(MAX_NESTING_DEPTH + 1))
+        );
+        assert_eq!(
+            Err(ExpressionError::TooDeeplyNested(MAX_NESTING_DEPTH)),
+            evaluate(&"(".repeat(100_000))
+        );
+    }
+
+    #[test]
+    fn percentages_divide_by_one_hundred() {
+        assert_eq!(Ok(0.3), evaluate("30%"));
+        assert_eq!(Ok(60.0), evaluate("30% of 200"));
+        assert_eq!(Ok(15.0), evaluate("50% of (10 + 20)"));
+    }
+
+    #[test]
+    fn invalid_expressions_are_rejected() {
+        assert_eq!(Err(ExpressionError::UnexpectedEnd), evaluate("2 *"));
+        assert_eq!(Err(ExpressionError::UnexpectedEnd), evaluate("(1 + 2"));
+        assert_eq!(
+            Err(ExpressionError::UnexpectedCharacter(4)),
+            evaluate("2 * x")
+        );
+        assert_eq!(
+            Err(ExpressionError::UnexpectedCharacter(2)),
+            evaluate("1 ox 2")
+        );
+        assert_eq!(
+            Err(ExpressionError::UnexpectedCharacter(0)),
+            evaluate("1.2.3")
+        );
+        assert_eq!(
+            Err(ExpressionError::DivisionByZero),
+            evaluate("1 / (2 - 2)")
+        );
+    }
+
+    #[test]
+    fn integer_values_are_formatted_without_fraction() {
+        assert_eq!("8192", format_value(8192.0));
+        assert_eq!("-3", format_value(-3.0));
+        assert_eq!("2.5", format_value(2.5));
+    }
+}
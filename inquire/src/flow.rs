@@ -0,0 +1,168 @@
+//! Abort propagation for sequences of prompts.
+//!
+//! When a user presses Ctrl+C in the middle of a multi-prompt flow, the usual
+//! expectation is that the whole flow stops, not only the current prompt. A
+//! [`FlowController`] runs each prompt of a flow and, once one of them is
+//! interrupted, skips all subsequent prompts without rendering them. The flow
+//! then ends with a single [`InquireError::OperationInterrupted`], and the
+//! controller keeps a record of the answers collected before the interruption.
+//!
+//! ```no_run
+//! use inquire::{flow::FlowController, Confirm, Text};
+//!
+//! let mut flow = FlowController::new();
+//!
+//! let name = flow.ask("name", || Text::new("Name:").prompt()).ok();
+//! let email = flow.ask("email", || Text::new("Email:").prompt()).ok();
+//! let subscribe = flow
+//!     .ask("subscribe", || Confirm::new("Subscribe?").prompt())
+//!     .unwrap_or(false);
+//!
+//! if let Some(key) = flow.interrupted_at() {
+//!     eprintln!("Interrupted at '{}', answered: {:?}", key, flow.collected());
+//! }
+//! flow.finish()?;
+//! # Ok::<(), inquire::InquireError>(())
+//! ```
+//!
+//! [`Form`](crate::form::Form)s are run through a controller as well, see
+//! [`Form::prompt_in`](crate::form::Form::prompt_in).
+
+use crate::error::{InquireError, InquireResult};
+
+/// Runs the prompts of a flow, skipping all of them once one is interrupted.
+///
+/// Prompts are identified by keys, used to record which ones were answered,
+/// which one was interrupted and which ones were skipped afterwards.
+#[derive(Clone, Debug, Default)]
+pub struct FlowController {
+    collected: Vec<String>,
+    interrupted_at: Option<String>,
+    skipped: Vec<String>,
+}
+
+impl FlowController {
+    /// Creates a controller for a new flow.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs the prompt identified by `key`, unless the flow was interrupted.
+    ///
+    /// When an earlier prompt was interrupted, `prompt` is not called and
+    /// [`InquireError::OperationInterrupted`] is returned right away. Other
+    /// errors, such as cancellations, are returned as is and do not affect
+    /// subsequent prompts.
+    pub fn ask<T, F>(&mut self, key: &str, prompt: F) -> InquireResult<T>
+    where
+        F: FnOnce() -> InquireResult<T>,
+    {
+        if self.is_interrupted() {
+            push_unique(&mut self.skipped, key);
+            return Err(InquireError::OperationInterrupted);
+        }
+
+        let result = prompt();
+        match &result {
+            Ok(_) => push_unique(&mut self.collected, key),
+            Err(InquireError::OperationInterrupted) => {
+                self.interrupted_at = Some(key.to_owned());
+            }
+            Err(_) => {}
+        }
+
+        result
+    }
+
+    /// Whether a prompt of the flow was interrupted.
+    pub fn is_interrupted(&self) -> bool {
+        self.interrupted_at.is_some()
+    }
+
+    /// Key of the prompt that was interrupted, if any.
+    pub fn interrupted_at(&self) -> Option<&str> {
+        self.interrupted_at.as_deref()
+    }
+
+    /// Keys of the prompts answered so far, in the order they were first answered.
+    pub fn collected(&self) -> &[String] {
+        &self.collected
+    }
+
+    /// Keys of the prompts skipped because of the interruption, in order.
+    pub fn skipped(&self) -> &[String] {
+        &self.skipped
+    }
+
+    /// Ends the flow, returning [`InquireError::OperationInterrupted`] if any of
+    /// its prompts was interrupted.
+    pub fn finish(self) -> InquireResult<()> {
+        match self.is_interrupted() {
+            true => Err(InquireError::OperationInterrupted),
+            false => Ok(()),
+        }
+    }
+}
+
+fn push_unique(keys: &mut Vec<String>, key: &str) {
+    if !keys.iter().any(|k| k == key) {
+        keys.push(key.to_owned());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::Cell;
+
+    use super::FlowController;
+    use crate::InquireError;
+
+    #[test]
+    fn prompts_after_interruption_are_skipped() {
+        let calls = Cell::new(0);
+        let mut flow = FlowController::new();
+
+        let answer = |value: u32| {
+            calls.set(calls.get() + 1);
+            Ok(value)
+        };
+
+        assert_eq!(1, flow.ask("first", || answer(1)).unwrap());
+        assert!(matches!(
+            flow.ask("second", || -> Result<u32, _> {
+                Err(InquireError::OperationInterrupted)
+            }),
+            Err(InquireError::OperationInterrupted)
+        ));
+        assert!(matches!(
+            flow.ask("third", || answer(3)),
+            Err(InquireError::OperationInterrupted)
+        ));
+
+        assert_eq!(1, calls.get());
+        assert_eq!(Some("second"), flow.interrupted_at());
+        assert_eq!(vec!["first"], flow.collected());
+        assert_eq!(vec!["third"], flow.skipped());
+        assert!(matches!(
+            flow.finish(),
+            Err(InquireError::OperationInterrupted)
+        ));
+    }
+
+    #[test]
+    fn cancellations_do_not_stop_the_flow() {
+        let mut flow = FlowController::new();
+
+        assert!(matches!(
+            flow.ask("first", || -> Result<u32, _> {
+                Err(InquireError::OperationCanceled)
+            }),
+            Err(InquireError::OperationCanceled)
+        ));
+        assert_eq!(2, flow.ask("second", || Ok(2)).unwrap());
+
+        assert!(!flow.is_interrupted());
+        assert_eq!(vec!["second"], flow.collected());
+        assert!(flow.finish().is_ok());
+    }
+}
@@ -0,0 +1,828 @@
+//! Form API, used to ask a sequence of questions as a single flow.
+//!
+//! A [`Form`] is a list of keyed questions that are asked one after the other.
+//! Once every question has been answered, the validators registered in the form
+//! are run over the whole answer set. When a validator fails, its error message
+//! is displayed and the user is taken back to the offending questions, which are
+//! pre-filled with their previous answers whenever the prompt supports it.
+//!
+//! Questions can also be built from the answers given so far, see [`Form::add_with`],
+//! which allows validators of later prompts to depend on earlier answers.
+//!
+//! When a question is interrupted with Ctrl+C, the remaining questions are skipped
+//! and the form returns [`InquireError::OperationInterrupted`]. To keep the answers
+//! given before that, run the form with [`Form::prompt_in`], which returns them in a
+//! [`FormError`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use inquire::{form::Form, validator::Validation, CustomType};
+//!
+//! let mut answers = Form::new()
+//!     .add("min", CustomType::<u32>::new("Minimum amount:"))
+//!     .add("max", CustomType::<u32>::new("Maximum amount:"))
+//!     .with_validator(&["max"], |answers: &inquire::form::Answers| {
+//!         let min: &u32 = answers.get("min").unwrap();
+//!         let max: &u32 = answers.get("max").unwrap();
+//!
+//!         match max > min {
+//!             true => Ok(Validation::Valid),
+//!             false => Ok(Validation::Invalid(
+//!                 "The maximum amount must be greater than the minimum".into(),
+//!             )),
+//!         }
+//!     })
+//!     .prompt()?;
+//!
+//! let min: u32 = answers.take("min").unwrap();
+//! let max: u32 = answers.take("max").unwrap();
+//! # Ok::<(), inquire::InquireError>(())
+//! ```
+
+use std::{any::Any, fmt::Debug, str::FromStr};
+
+use dyn_clone::DynClone;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    flow::FlowController,
+    status::FlowStatus,
+    terminal::get_default_terminal,
+    ui::{Backend, CommonBackend},
+    validator::{ErrorMessage, FormValidator, Validation},
+    Confirm, CustomType, MultiSelect, Password, Select, Text,
+};
+
+#[cfg(feature = "date")]
+use crate::DateSelect;
+#[cfg(feature = "editor")]
+use crate::Editor;
+
+/// Prompt that can be asked as part of a [`Form`].
+///
+/// This trait is implemented for all prompts of this crate, and can be implemented
+/// for custom prompts as well.
+pub trait Question: Clone {
+    /// Type of the answer returned by the question.
+    type Answer: Any;
+
+    /// Prompts the user for an answer.
+    ///
+    /// When the question is asked again, for example after a form validator
+    /// rejected the answer set, `previous` contains the last answer given to it
+    /// so the prompt can be pre-filled with it.
+    fn ask(&self, previous: Option<&Self::Answer>) -> InquireResult<Self::Answer>;
+}
+
+impl<'a> Question for Text<'a> {
+    type Answer = String;
+
+    fn ask(&self, previous: Option<&String>) -> InquireResult<String> {
+        let mut text: Text<'_> = self.clone();
+        if let Some(previous) = previous {
+            text.initial_value = Some(previous);
+        }
+
+        text.prompt()
+    }
+}
+
+impl<'a> Question for Password<'a> {
+    type Answer = String;
+
+    fn ask(&self, _previous: Option<&String>) -> InquireResult<String> {
+        // previous passwords are intentionally not pre-filled.
+        self.clone().prompt()
+    }
+}
+
+impl<'a> Question for Confirm<'a> {
+    type Answer = bool;
+
+    fn ask(&self, previous: Option<&bool>) -> InquireResult<bool> {
+        let mut confirm = self.clone();
+        if let Some(previous) = previous {
+            confirm.default = Some(*previous);
+        }
+
+        confirm.prompt()
+    }
+}
+
+impl<'a, T> Question for CustomType<'a, T>
+where
+    T: Clone + FromStr + ToString + Any,
+{
+    type Answer = T;
+
+    fn ask(&self, previous: Option<&T>) -> InquireResult<T> {
+        let mut custom_type = self.clone();
+        if let Some(previous) = previous {
+            custom_type.default = Some(previous.clone());
+        }
+
+        custom_type.prompt()
+    }
+}
+
+impl<'a, T> Question for Select<'a, T>
+where
+    T: Clone + PartialEq + Any,
+{
+    type Answer = T;
+
+    fn ask(&self, previous: Option<&T>) -> InquireResult<T> {
+        let mut select = self.clone();
+        if let Some(index) = previous.and_then(|p| select.options.iter().position(|o| o == p)) {
+            select.starting_cursor = index;
+        }
+
+        select.prompt()
+    }
+}
+
+impl<'a, T> Question for MultiSelect<'a, T>
+where
+    T: Clone + PartialEq + Any,
+{
+    type Answer = Vec<T>;
+
+    fn ask(&self, previous: Option<&Vec<T>>) -> InquireResult<Vec<T>> {
+        let mut multi_select = self.clone();
+        if let Some(previous) = previous {
+            multi_select = multi_select.with_default_values(previous);
+        }
+
+        multi_select.prompt()
+    }
+}
+
+#[cfg(feature = "date")]
+impl<'a> Question for DateSelect<'a> {
+    type Answer = chrono::NaiveDate;
+
+    fn ask(&self, previous: Option<&chrono::NaiveDate>) -> InquireResult<chrono::NaiveDate> {
+        let mut date_select = self.clone();
+        if let Some(previous) = previous {
+            date_select.starting_date = *previous;
+        }
+
+        date_select.prompt()
+    }
+}
+
+#[cfg(feature = "editor")]
+impl<'a> Question for Editor<'a> {
+    type Answer = String;
+
+    fn ask(&self, previous: Option<&String>) -> InquireResult<String> {
+        let mut editor: Editor<'_> = self.clone();
+        if let Some(previous) = previous {
+            editor.predefined_text = Some(previous);
+        }
+
+        editor.prompt()
+    }
+}
+
+/// Type-erased version of [`Question`], so questions with different answer
+/// types can be stored in the same form.
+trait ErasedQuestion: DynClone {
+    fn ask(&self, answers: &Answers, previous: Option<&dyn Any>) -> InquireResult<Box<dyn Any>>;
+}
+
+impl<Q> ErasedQuestion for Q
+where
+    Q: Question,
+{
+    fn ask(&self, _answers: &Answers, previous: Option<&dyn Any>) -> InquireResult<Box<dyn Any>> {
+        let previous = previous.and_then(|p| p.downcast_ref::<Q::Answer>());
+        let answer = Question::ask(self, previous)?;
+
+        Ok(Box::new(answer))
+    }
+}
+
+/// Question built right before being asked, from the answers given so far.
+#[derive(Clone)]
+struct ContextualQuestion<F> {
+    builder: F,
+}
+
+impl<F, Q> ErasedQuestion for ContextualQuestion<F>
+where
+    F: Fn(&Answers) -> Q + Clone,
+    Q: Question,
+{
+    fn ask(&self, answers: &Answers, previous: Option<&dyn Any>) -> InquireResult<Box<dyn Any>> {
+        let question = (self.builder)(answers);
+        ErasedQuestion::ask(&question, answers, previous)
+    }
+}
+
+impl<'a> Clone for Box<dyn ErasedQuestion + 'a> {
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+/// Set of answers given to the questions of a [`Form`], indexed by the
+/// question keys.
+#[derive(Default)]
+pub struct Answers {
+    entries: Vec<(String, Box<dyn Any>)>,
+}
+
+impl Answers {
+    /// Returns a reference to the answer of the question with the given key.
+    ///
+    /// Returns `None` if the question was not answered or if its answer is
+    /// not of type `T`.
+    pub fn get<T: Any>(&self, key: &str) -> Option<&T> {
+        self.get_raw(key).and_then(|answer| answer.downcast_ref())
+    }
+
+    /// Removes the answer of the question with the given key from the set,
+    /// returning it.
+    ///
+    /// Returns `None` if the question was not answered or if its answer is
+    /// not of type `T`, in which case the set is left untouched.
+    pub fn take<T: Any>(&mut self, key: &str) -> Option<T> {
+        let index = self.position(key)?;
+        if !self.entries[index].1.is::<T>() {
+            return None;
+        }
+
+        let (_, answer) = self.entries.remove(index);
+        answer.downcast().ok().map(|answer| *answer)
+    }
+
+    /// Returns whether the question with the given key has been answered.
+    pub fn contains(&self, key: &str) -> bool {
+        self.position(key).is_some()
+    }
+
+    /// Returns the keys of all answered questions, in the order they were asked.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|(key, _)| key.as_str())
+    }
+
+    /// Returns the amount of answers in the set.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether the set contains no answers.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn position(&self, key: &str) -> Option<usize> {
+        self.entries.iter().position(|(k, _)| k == key)
+    }
+
+    fn get_raw(&self, key: &str) -> Option<&dyn Any> {
+        self.position(key).map(|index| &*self.entries[index].1)
+    }
+
+    fn insert(&mut self, key: &str, answer: Box<dyn Any>) {
+        match self.position(key) {
+            Some(index) => self.entries[index].1 = answer,
+            None => self.entries.push((key.to_string(), answer)),
+        }
+    }
+}
+
+impl Debug for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Answers")
+            .field("keys", &self.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// Error that stopped a [`Form`], along with the answers given before it occurred.
+///
+/// Returned by [`Form::prompt_in`], so that an interrupted or canceled form can be
+/// inspected or resumed later on. Converts into the underlying [`InquireError`].
+#[derive(Debug)]
+pub struct FormError {
+    /// Error that stopped the form.
+    pub error: InquireError,
+    /// Answers given to the questions asked before the form was stopped.
+    pub answers: Answers,
+}
+
+impl FormError {
+    fn new(error: InquireError) -> Self {
+        Self {
+            error,
+            answers: Answers::default(),
+        }
+    }
+}
+
+impl std::fmt::Display for FormError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.error, f)
+    }
+}
+
+impl std::error::Error for FormError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<FormError> for InquireError {
+    fn from(err: FormError) -> Self {
+        err.error
+    }
+}
+
+#[derive(Clone)]
+struct FormValidatorEntry {
+    keys: Vec<String>,
+    validator: Box<dyn FormValidator>,
+}
+
+/// Sequence of keyed questions asked as a single flow, with support for
+/// validators that run over the whole answer set.
+///
+/// Check the [module documentation](crate::form) for an example.
+#[derive(Clone, Default)]
+pub struct Form<'a> {
+    questions: Vec<(String, Box<dyn ErasedQuestion + 'a>)>,
+    validators: Vec<FormValidatorEntry>,
+    status: Option<String>,
+}
+
+impl<'a> Form<'a> {
+    /// Creates an empty [Form].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a question to the end of the form, identified by the given key.
+    ///
+    /// If a question with the same key already exists, it is replaced.
+    pub fn add<Q>(self, key: &str, question: Q) -> Self
+    where
+        Q: Question + 'a,
+    {
+        self.add_erased(key, Box::new(question))
+    }
+
+    /// Adds a question to the end of the form, identified by the given key, that
+    /// is built right before being asked with the answers given so far as its context.
+    ///
+    /// This allows prompts to depend on earlier answers, for example to set a
+    /// validator that checks a password is different from a previously given
+    /// username:
+    ///
+    /// ```no_run
+    /// use inquire::{form::{Answers, Form}, validator::Validation, Password, Text};
+    ///
+    /// let answers = Form::new()
+    ///     .add("username", Text::new("Username:"))
+    ///     .add_with("password", |ctx: &Answers| {
+    ///         let username = ctx.get::<String>("username").cloned().unwrap_or_default();
+    ///
+    ///         Password::new("Password:").with_validator(move |input: &str| {
+    ///             match input == username {
+    ///                 true => Ok(Validation::Invalid("Password can not be the username".into())),
+    ///                 false => Ok(Validation::Valid),
+    ///             }
+    ///         })
+    ///     })
+    ///     .prompt()?;
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    ///
+    /// If a question with the same key already exists, it is replaced.
+    pub fn add_with<Q, F>(self, key: &str, builder: F) -> Self
+    where
+        F: Fn(&Answers) -> Q + Clone + 'a,
+        Q: Question,
+    {
+        self.add_erased(key, Box::new(ContextualQuestion { builder }))
+    }
+
+    fn add_erased(mut self, key: &str, question: Box<dyn ErasedQuestion + 'a>) -> Self {
+        match self.questions.iter().position(|(k, _)| k == key) {
+            Some(index) => self.questions[index].1 = question,
+            None => self.questions.push((key.to_string(), question)),
+        }
+
+        self
+    }
+
+    /// Adds a validator that runs over the whole answer set once all questions
+    /// have been answered.
+    ///
+    /// `keys` identifies the questions the validator refers to. When the validator
+    /// fails, those questions are asked again, in form order, pre-filled with their
+    /// previous answers. If no keys are given, the whole form is asked again.
+    ///
+    /// Validators are run in the order they were added, and the first failure
+    /// is the one reported to the user.
+    pub fn with_validator<V>(mut self, keys: &[&str], validator: V) -> Self
+    where
+        V: FormValidator + 'static,
+    {
+        self.validators.push(FormValidatorEntry {
+            keys: keys.iter().map(|k| k.to_string()).collect(),
+            validator: Box::new(validator),
+        });
+        self
+    }
+
+    /// Reports the progress of the form in the terminal title and progress
+    /// indicator under the given name, e.g. `Setup 3/7 — networking` where
+    /// `networking` is the key of the current question.
+    ///
+    /// Only takes effect when enabled globally, see the [`status`](crate::status) module.
+    pub fn with_status(mut self, name: &str) -> Self {
+        self.status = Some(name.to_owned());
+        self
+    }
+
+    /// Asks all questions of the form, returning the answer set once it passes
+    /// all registered validators.
+    ///
+    /// If a validator references a key that is not part of the form, this method
+    /// returns an [`InquireError::InvalidConfiguration`] error before asking anything.
+    pub fn prompt(self) -> InquireResult<Answers> {
+        Ok(self.prompt_in(&mut FlowController::new())?)
+    }
+
+    /// Asks all questions of the form as part of a larger flow, identifying them
+    /// in the [`FlowController`] by their keys.
+    ///
+    /// If a question is interrupted, or the flow already was before the form
+    /// started, the remaining questions are skipped and this method returns
+    /// [`InquireError::OperationInterrupted`]. The controller then records which
+    /// questions were answered, interrupted and skipped.
+    ///
+    /// Errors are returned as a [`FormError`], carrying the answers given before
+    /// the form was stopped:
+    ///
+    /// ```no_run
+    /// use inquire::{flow::FlowController, form::Form, Text};
+    ///
+    /// let result = Form::new()
+    ///     .add("name", Text::new("Name:"))
+    ///     .add("email", Text::new("Email:"))
+    ///     .prompt_in(&mut FlowController::new());
+    ///
+    /// if let Err(err) = &result {
+    ///     let name: Option<&String> = err.answers.get("name");
+    ///     eprintln!("Stopped after name {:?}: {}", name, err.error);
+    /// }
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn prompt_in(self, flow: &mut FlowController) -> Result<Answers, FormError> {
+        self.prompt_with(flow, render_form_error)
+    }
+
+    /// Parses the provided questions and asks them in order.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the form - by pressing ESC in any question - is considered normal behavior.
+    /// In this case, it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does complete the form, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<Answers>> {
+        match self.prompt() {
+            Ok(answers) => Ok(Some(answers)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub(crate) fn prompt_with<F>(
+        self,
+        flow: &mut FlowController,
+        mut report_error: F,
+    ) -> Result<Answers, FormError>
+    where
+        F: FnMut(&ErrorMessage) -> InquireResult<()>,
+    {
+        self.check_validator_keys().map_err(FormError::new)?;
+
+        let mut answers = Answers::default();
+        let mut pending: Vec<usize> = (0..self.questions.len()).collect();
+        let status = self
+            .status
+            .as_ref()
+            .map(|name| FlowStatus::new(name.as_str(), self.questions.len()));
+
+        loop {
+            for &index in &pending {
+                let (key, question) = &self.questions[index];
+                if let (Some(status), false) = (&status, flow.is_interrupted()) {
+                    status.step(index + 1, key);
+                }
+
+                // after an interruption, the remaining questions are recorded as skipped
+                match flow.ask(key, || question.ask(&answers, answers.get_raw(key))) {
+                    Ok(answer) => answers.insert(key, answer),
+                    Err(InquireError::OperationInterrupted) => {}
+                    Err(error) => return Err(FormError { error, answers }),
+                }
+            }
+
+            if flow.is_interrupted() {
+                let error = InquireError::OperationInterrupted;
+                return Err(FormError { error, answers });
+            }
+
+            let failure = match self.first_failure(&answers) {
+                Ok(failure) => failure,
+                Err(error) => return Err(FormError { error, answers }),
+            };
+
+            match failure {
+                None => return Ok(answers),
+                Some((keys, message)) => {
+                    if let Err(error) = report_error(&message) {
+                        return Err(FormError { error, answers });
+                    }
+                    pending = self.question_indexes(keys);
+                }
+            }
+        }
+    }
+
+    fn check_validator_keys(&self) -> InquireResult<()> {
+        for key in self.validators.iter().flat_map(|v| &v.keys) {
+            if !self.questions.iter().any(|(k, _)| k == key) {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Form validator references unknown question key '{}'",
+                    key
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn first_failure(&self, answers: &Answers) -> InquireResult<Option<(&[String], ErrorMessage)>> {
+        for entry in &self.validators {
+            if let Validation::Invalid(message) = entry.validator.validate(answers)? {
+                return Ok(Some((&entry.keys, message)));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn question_indexes(&self, keys: &[String]) -> Vec<usize> {
+        self.questions
+            .iter()
+            .enumerate()
+            .filter(|(_, (key, _))| keys.is_empty() || keys.contains(key))
+            .map(|(index, _)| index)
+            .collect()
+    }
+}
+
+fn render_form_error(message: &ErrorMessage) -> InquireResult<()> {
+    let terminal = get_default_terminal()?;
+    let mut backend = Backend::new(terminal, get_configuration())?;
+
+    backend.frame_setup()?;
+    backend.render_error_message(message)?;
+    backend.frame_finish()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::RefCell, collections::VecDeque, rc::Rc};
+
+    use super::{Answers, Form, FormError, Question};
+    use crate::{
+        error::InquireResult,
+        flow::FlowController,
+        validator::{ErrorMessage, Validation},
+        InquireError,
+    };
+
+    /// Question that pops its answers from a shared queue, recording the
+    /// previous answers it was asked with.
+    #[derive(Clone)]
+    struct QueuedQuestion {
+        answers: Rc<RefCell<VecDeque<u32>>>,
+        previous: Rc<RefCell<Vec<Option<u32>>>>,
+    }
+
+    impl QueuedQuestion {
+        fn new(answers: &[u32]) -> Self {
+            Self {
+                answers: Rc::new(RefCell::new(answers.iter().copied().collect())),
+                previous: Rc::new(RefCell::new(vec![])),
+            }
+        }
+    }
+
+    impl Question for QueuedQuestion {
+        type Answer = u32;
+
+        fn ask(&self, previous: Option<&u32>) -> InquireResult<u32> {
+            self.previous.borrow_mut().push(previous.copied());
+            Ok(self.answers.borrow_mut().pop_front().unwrap())
+        }
+    }
+
+    fn max_greater_than_min(answers: &Answers) -> Result<Validation, crate::CustomUserError> {
+        let min: &u32 = answers.get("min").unwrap();
+        let max: &u32 = answers.get("max").unwrap();
+
+        match max > min {
+            true => Ok(Validation::Valid),
+            false => Ok(Validation::Invalid("max must be greater than min".into())),
+        }
+    }
+
+    #[test]
+    fn answers_are_returned_by_key() {
+        let mut answers = Form::new()
+            .add("min", QueuedQuestion::new(&[1]))
+            .add("max", QueuedQuestion::new(&[2]))
+            .with_validator(&["max"], max_greater_than_min)
+            .prompt_with(&mut FlowController::new(), |_| panic!("no error expected"))
+            .unwrap();
+
+        assert_eq!(vec!["min", "max"], answers.keys().collect::<Vec<_>>());
+        assert_eq!(None, answers.get::<String>("min"));
+        assert_eq!(Some(1), answers.take::<u32>("min"));
+        assert_eq!(Some(&2), answers.get::<u32>("max"));
+        assert!(!answers.contains("min"));
+    }
+
+    #[test]
+    fn failing_validator_asks_offending_questions_again() {
+        let min = QueuedQuestion::new(&[5]);
+        let max = QueuedQuestion::new(&[3, 4, 8]);
+        let mut errors = vec![];
+
+        let answers = Form::new()
+            .add("min", min.clone())
+            .add("max", max.clone())
+            .with_validator(&["max"], max_greater_than_min)
+            .prompt_with(&mut FlowController::new(), |message| {
+                errors.push(message.clone());
+                Ok(())
+            })
+            .unwrap();
+
+        assert_eq!(Some(&8), answers.get::<u32>("max"));
+        assert_eq!(vec![None], *min.previous.borrow());
+        assert_eq!(vec![None, Some(3), Some(4)], *max.previous.borrow());
+        assert_eq!(
+            vec![ErrorMessage::Custom("max must be greater than min".into()); 2],
+            errors
+        );
+    }
+
+    #[test]
+    fn validator_without_keys_asks_whole_form_again() {
+        let min = QueuedQuestion::new(&[5, 1]);
+        let max = QueuedQuestion::new(&[3, 3]);
+
+        let answers = Form::new()
+            .add("min", min.clone())
+            .add("max", max.clone())
+            .with_validator(&[], max_greater_than_min)
+            .prompt_with(&mut FlowController::new(), |_| Ok(()))
+            .unwrap();
+
+        assert_eq!(Some(&1), answers.get::<u32>("min"));
+        assert_eq!(vec![None, Some(5)], *min.previous.borrow());
+        assert_eq!(vec![None, Some(3)], *max.previous.borrow());
+    }
+
+    #[test]
+    fn contextual_questions_receive_earlier_answers() {
+        let seen = Rc::new(RefCell::new(vec![]));
+        let seen_by_builder = seen.clone();
+
+        let answers = Form::new()
+            .add("min", QueuedQuestion::new(&[5, 2]))
+            .add_with("max", move |ctx: &Answers| {
+                seen_by_builder
+                    .borrow_mut()
+                    .push(ctx.get::<u32>("min").copied());
+                QueuedQuestion::new(&[4])
+            })
+            .with_validator(&["min", "max"], max_greater_than_min)
+            .prompt_with(&mut FlowController::new(), |_| Ok(()))
+            .unwrap();
+
+        assert_eq!(Some(&4), answers.get::<u32>("max"));
+        assert_eq!(vec![Some(5), Some(2)], *seen.borrow());
+    }
+
+    #[test]
+    fn unknown_validator_key_is_invalid_configuration() {
+        let result = Form::new()
+            .add("min", QueuedQuestion::new(&[1]))
+            .with_validator(&["max"], max_greater_than_min)
+            .prompt_with(&mut FlowController::new(), |_| Ok(()));
+
+        assert!(matches!(
+            result,
+            Err(FormError {
+                error: crate::InquireError::InvalidConfiguration(_),
+                ..
+            })
+        ));
+    }
+
+    /// Question that is always interrupted.
+    #[derive(Clone)]
+    struct InterruptedQuestion;
+
+    impl Question for InterruptedQuestion {
+        type Answer = u32;
+
+        fn ask(&self, _previous: Option<&u32>) -> InquireResult<u32> {
+            Err(InquireError::OperationInterrupted)
+        }
+    }
+
+    #[test]
+    fn interruption_skips_remaining_questions() {
+        let max = QueuedQuestion::new(&[1]);
+        let mut flow = FlowController::new();
+
+        let result = Form::new()
+            .add("min", QueuedQuestion::new(&[1]))
+            .add("step", InterruptedQuestion)
+            .add("max", max.clone())
+            .with_validator(&["max"], max_greater_than_min)
+            .prompt_with(&mut flow, |_| panic!("validators must not run"));
+
+        let err = result.unwrap_err();
+        assert!(matches!(err.error, InquireError::OperationInterrupted));
+        assert_eq!(vec!["min"], err.answers.keys().collect::<Vec<_>>());
+        assert_eq!(Some(&1), err.answers.get::<u32>("min"));
+        assert!(max.previous.borrow().is_empty());
+        assert_eq!(vec!["min"], flow.collected());
+        assert_eq!(Some("step"), flow.interrupted_at());
+        assert_eq!(vec!["max"], flow.skipped());
+    }
+
+    #[test]
+    fn interrupted_flow_skips_whole_form() {
+        let min = QueuedQuestion::new(&[1]);
+        let mut flow = FlowController::new();
+        let _ = flow.ask("before", || -> InquireResult<()> {
+            Err(InquireError::OperationInterrupted)
+        });
+
+        let result = Form::new()
+            .add("min", min.clone())
+            .prompt_with(&mut flow, |_| Ok(()));
+
+        let err = result.unwrap_err();
+        assert!(matches!(err.error, InquireError::OperationInterrupted));
+        assert!(err.answers.is_empty());
+        assert!(min.previous.borrow().is_empty());
+        assert_eq!(vec!["min"], flow.skipped());
+    }
+
+    /// Question that is always canceled.
+    #[derive(Clone)]
+    struct CanceledQuestion;
+
+    impl Question for CanceledQuestion {
+        type Answer = u32;
+
+        fn ask(&self, _previous: Option<&u32>) -> InquireResult<u32> {
+            Err(InquireError::OperationCanceled)
+        }
+    }
+
+    #[test]
+    fn cancellation_returns_answers_given_so_far() {
+        let err = Form::new()
+            .add("min", QueuedQuestion::new(&[1]))
+            .add("step", CanceledQuestion)
+            .add("max", QueuedQuestion::new(&[2]))
+            .prompt_with(&mut FlowController::new(), |_| Ok(()))
+            .unwrap_err();
+
+        assert!(matches!(err.error, InquireError::OperationCanceled));
+        assert_eq!(vec!["min"], err.answers.keys().collect::<Vec<_>>());
+        assert!(matches!(
+            InquireError::from(err),
+            InquireError::OperationCanceled
+        ));
+    }
+}
@@ -46,7 +46,7 @@
 //! ? What's your name? My name is Mikael
 //! ```
 
-use crate::list_option::ListOption;
+use crate::{config::translate, list_option::ListOption};
 
 /// Type alias for formatters that receive a string slice as the input,
 /// required by [Text](crate::Text) and [Password](crate::Password) for example.
@@ -148,8 +148,6 @@ pub type MultiOptionFormatter<'a, T> = &'a dyn Fn(&[ListOption<&T>]) -> String;
 /// ```
 pub type CustomTypeFormatter<'a, T> = &'a dyn Fn(T) -> String;
 
-#[cfg(feature = "date")]
-
 /// Type alias for formatters used in [`DateSelect`](crate::DateSelect) prompts.
 ///
 /// Formatters receive the user input and return a [String] to be displayed
@@ -168,8 +166,30 @@ pub type CustomTypeFormatter<'a, T> = &'a dyn Fn(T) -> String;
 ///     formatter(NaiveDate::from_ymd(2021, 7, 25)),
 /// );
 /// ```
+#[cfg(feature = "date")]
 pub type DateFormatter<'a> = &'a dyn Fn(chrono::NaiveDate) -> String;
 
+/// Type alias for formatters used in [`TimeSelect`](crate::TimeSelect) prompts.
+///
+/// Formatters receive the user input and return a [String] to be displayed
+/// to the user as the final answer.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveTime;
+/// use inquire::formatter::TimeFormatter;
+///
+/// let formatter: TimeFormatter = &|val| val.format("%Hh%M").to_string();
+///
+/// assert_eq!(
+///     String::from("09h30"),
+///     formatter(NaiveTime::from_hms_opt(9, 30, 0).unwrap()),
+/// );
+/// ```
+#[cfg(feature = "date")]
+pub type TimeFormatter<'a> = &'a dyn Fn(chrono::NaiveTime) -> String;
+
 /// String formatter used by default in inputs that return a `String` as input.
 /// Its behavior is to just echo the received input.
 ///
@@ -185,7 +205,8 @@ pub type DateFormatter<'a> = &'a dyn Fn(chrono::NaiveDate) -> String;
 pub const DEFAULT_STRING_FORMATTER: StringFormatter = &|val| String::from(val);
 
 /// String formatter used by default in [Confirm](crate::Confirm) prompts.
-/// Translates `bool` to `"Yes"` and `false` to `"No"`.
+/// Translates `bool` to `"Yes"` and `false` to `"No"`, or their translation
+/// in the locale selected with the `i18n` feature.
 ///
 /// # Examples
 ///
@@ -198,9 +219,9 @@ pub const DEFAULT_STRING_FORMATTER: StringFormatter = &|val| String::from(val);
 /// ```
 pub const DEFAULT_BOOL_FORMATTER: BoolFormatter = &|ans| {
     if ans {
-        String::from("Yes")
+        String::from(translate("Yes"))
     } else {
-        String::from("No")
+        String::from(translate("No"))
     }
 };
 
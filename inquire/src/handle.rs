@@ -0,0 +1,66 @@
+//! Aborting prompts from outside of them, e.g. from another thread, a Ctrl+C
+//! handler or when the application receives a shutdown signal.
+//!
+//! A [`PromptHandle`] is given to prompts with their `with_handle` method.
+//! Once [aborted](PromptHandle::abort), the prompts using it are canceled as
+//! if the user pressed ESC: the terminal is restored and the prompt returns
+//! [`InquireError::OperationCanceled`].
+//!
+//! ```no_run
+//! use std::{thread, time::Duration};
+//!
+//! use inquire::{handle::PromptHandle, InquireError, Text};
+//!
+//! let handle = PromptHandle::new();
+//!
+//! let shutdown = handle.clone();
+//! thread::spawn(move || {
+//!     thread::sleep(Duration::from_secs(30));
+//!     shutdown.abort();
+//! });
+//!
+//! match Text::new("Name:").with_handle(&handle).prompt() {
+//!     Ok(name) => println!("Hello {}", name),
+//!     Err(InquireError::OperationCanceled) => println!("Too late!"),
+//!     Err(err) => return Err(err),
+//! }
+//! # Ok::<(), InquireError>(())
+//! ```
+//!
+//! [`InquireError::OperationCanceled`]: crate::InquireError::OperationCanceled
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+/// Handle aborting the prompts using it, shared by cloning it.
+///
+/// Aborting is final: prompts started with an aborted handle are canceled
+/// right away, so that a shutdown signal also stops the rest of a flow of
+/// prompts.
+///
+/// Prompts check their handle after each key press, and while the user is
+/// idle with terminals able to wait for keys with a timeout, such as the
+/// crossterm or termion ones.
+#[derive(Clone, Debug, Default)]
+pub struct PromptHandle {
+    aborted: Arc<AtomicBool>,
+}
+
+impl PromptHandle {
+    /// Creates a handle that is not aborted.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Aborts the prompts using this handle or any of its clones.
+    pub fn abort(&self) {
+        self.aborted.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether the handle was aborted.
+    pub fn is_aborted(&self) -> bool {
+        self.aborted.load(Ordering::SeqCst)
+    }
+}
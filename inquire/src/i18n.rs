@@ -0,0 +1,399 @@
+//! Translations of the built-in strings of the prompts, such as the default
+//! help messages and validator error messages, along with the month and
+//! weekday names of the [`DateSelect`](crate::DateSelect) calendar.
+//!
+//! Requires the `i18n` feature. The locale is selected globally with
+//! [`set_locale`], applying to the prompts and validators created afterwards.
+//! Strings set explicitly by the caller, such as a custom help message, are
+//! never translated.
+//!
+//! ```
+//! use inquire::{i18n::Locale, set_locale};
+//!
+//! set_locale("de-DE")?;
+//!
+//! assert_eq!(Some(Locale::DeDe), Locale::from_tag("de_DE"));
+//! assert_eq!("Eine Antwort ist erforderlich.", Locale::DeDe.translate("A response is required."));
+//! # inquire::set_locale("en-US")?;
+//! # Ok::<(), inquire::InquireError>(())
+//! ```
+
+use std::sync::Mutex;
+
+use lazy_static::lazy_static;
+
+use crate::error::{InquireError, InquireResult};
+
+lazy_static! {
+    static ref GLOBAL_LOCALE: Mutex<Locale> = Mutex::new(Locale::default());
+}
+
+/// Locales whose translations are built into the library.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Locale {
+    /// American English, the language of the strings in the source code.
+    EnUs,
+    /// German.
+    DeDe,
+    /// French.
+    FrFr,
+    /// Spanish.
+    EsEs,
+    /// Brazilian Portuguese.
+    PtBr,
+}
+
+// Deriving it with `#[default]` requires Rust 1.62, above our MSRV.
+#[allow(clippy::derivable_impls)]
+impl Default for Locale {
+    fn default() -> Self {
+        Self::EnUs
+    }
+}
+
+impl Locale {
+    /// Locale identified by the given language tag, such as `de-DE`, `de_DE`
+    /// or just `de`, case-insensitively. Returns `None` if the language has no
+    /// built-in translations.
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let language = tag.split(['-', '_']).next().unwrap_or_default();
+
+        match language.to_ascii_lowercase().as_str() {
+            "en" => Some(Self::EnUs),
+            "de" => Some(Self::DeDe),
+            "fr" => Some(Self::FrFr),
+            "es" => Some(Self::EsEs),
+            "pt" => Some(Self::PtBr),
+            _ => None,
+        }
+    }
+
+    /// Translation of one of the built-in strings, or the string itself when
+    /// it has no translation in this locale.
+    pub fn translate(self, text: &str) -> &str {
+        TRANSLATIONS
+            .iter()
+            .find(|entry| entry[0] == text)
+            .map(|entry| match self {
+                Self::EnUs => entry[0],
+                Self::DeDe => entry[1],
+                Self::FrFr => entry[2],
+                Self::EsEs => entry[3],
+                Self::PtBr => entry[4],
+            })
+            .unwrap_or(text)
+    }
+
+    /// Name of the month, in lowercase as displayed in the calendar header.
+    #[cfg(feature = "date")]
+    pub fn month_name(self, month: chrono::Month) -> &'static str {
+        let index = month.number_from_month() as usize - 1;
+
+        match self {
+            Self::EnUs => EN_MONTHS[index],
+            Self::DeDe => DE_MONTHS[index],
+            Self::FrFr => FR_MONTHS[index],
+            Self::EsEs => ES_MONTHS[index],
+            Self::PtBr => PT_MONTHS[index],
+        }
+    }
+
+    /// Two-letter abbreviation of the weekday, in lowercase as displayed in
+    /// the calendar header.
+    #[cfg(feature = "date")]
+    pub fn weekday_abbreviation(self, weekday: chrono::Weekday) -> &'static str {
+        let index = weekday.num_days_from_monday() as usize;
+
+        match self {
+            Self::EnUs => ["mo", "tu", "we", "th", "fr", "sa", "su"][index],
+            Self::DeDe => ["mo", "di", "mi", "do", "fr", "sa", "so"][index],
+            Self::FrFr => ["lu", "ma", "me", "je", "ve", "sa", "di"][index],
+            Self::EsEs => ["lu", "ma", "mi", "ju", "vi", "sá", "do"][index],
+            Self::PtBr => ["se", "te", "qa", "qi", "sx", "sá", "do"][index],
+        }
+    }
+}
+
+/// Returns the locale used by the prompts created from now on.
+pub fn get_locale() -> Locale {
+    *GLOBAL_LOCALE.lock().unwrap()
+}
+
+/// Acquires a write lock to the global locale and updates it to the one
+/// identified by the given language tag, such as `de-DE`.
+///
+/// Prompts and validators created afterwards display their built-in strings
+/// in this locale. Returns [`InquireError::InvalidConfiguration`] if the
+/// language has no built-in translations, see [`Locale::from_tag`].
+pub fn set_locale(tag: &str) -> InquireResult<()> {
+    let locale = Locale::from_tag(tag).ok_or_else(|| {
+        InquireError::InvalidConfiguration(format!("locale {tag:?} is not supported"))
+    })?;
+
+    let mut guard = GLOBAL_LOCALE.lock().unwrap();
+    *guard = locale;
+
+    Ok(())
+}
+
+#[cfg(feature = "date")]
+const EN_MONTHS: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+#[cfg(feature = "date")]
+const DE_MONTHS: [&str; 12] = [
+    "januar",
+    "februar",
+    "märz",
+    "april",
+    "mai",
+    "juni",
+    "juli",
+    "august",
+    "september",
+    "oktober",
+    "november",
+    "dezember",
+];
+
+#[cfg(feature = "date")]
+const FR_MONTHS: [&str; 12] = [
+    "janvier",
+    "février",
+    "mars",
+    "avril",
+    "mai",
+    "juin",
+    "juillet",
+    "août",
+    "septembre",
+    "octobre",
+    "novembre",
+    "décembre",
+];
+
+#[cfg(feature = "date")]
+const ES_MONTHS: [&str; 12] = [
+    "enero",
+    "febrero",
+    "marzo",
+    "abril",
+    "mayo",
+    "junio",
+    "julio",
+    "agosto",
+    "septiembre",
+    "octubre",
+    "noviembre",
+    "diciembre",
+];
+
+#[cfg(feature = "date")]
+const PT_MONTHS: [&str; 12] = [
+    "janeiro",
+    "fevereiro",
+    "março",
+    "abril",
+    "maio",
+    "junho",
+    "julho",
+    "agosto",
+    "setembro",
+    "outubro",
+    "novembro",
+    "dezembro",
+];
+
+/// Built-in strings along with their translations, in the order of the
+/// [`Locale`] variants. Templates have a single `{}` placeholder.
+const TRANSLATIONS: &[[&str; 5]] = &[
+    [
+        "↑↓ to move, enter to select, type to filter",
+        "↑↓ zum Bewegen, Enter zum Auswählen, tippen zum Filtern",
+        "↑↓ pour naviguer, entrée pour choisir, tapez pour filtrer",
+        "↑↓ para moverse, intro para elegir, escriba para filtrar",
+        "↑↓ para mover, enter para selecionar, digite para filtrar",
+    ],
+    [
+        "↑↓ to move, space to select one, → to all, ← to none, type to filter",
+        "↑↓ zum Bewegen, Leertaste wählt eine, → alle, ← keine, tippen zum Filtern",
+        "↑↓ pour naviguer, espace pour en choisir une, → toutes, ← aucune, tapez pour filtrer",
+        "↑↓ para moverse, espacio para elegir una, → todas, ← ninguna, escriba para filtrar",
+        "↑↓ para mover, espaço para selecionar uma, → todas, ← nenhuma, digite para filtrar",
+    ],
+    [
+        "↑↓ to move, tab to autocomplete, enter to submit",
+        "↑↓ zum Bewegen, Tab zum Vervollständigen, Enter zum Bestätigen",
+        "↑↓ pour naviguer, tab pour compléter, entrée pour valider",
+        "↑↓ para moverse, tab para autocompletar, intro para enviar",
+        "↑↓ para mover, tab para completar, enter para enviar",
+    ],
+    [
+        "arrows to move, with ctrl to move months and years, enter to select",
+        "Pfeiltasten zum Bewegen, mit Strg Monate und Jahre, Enter zum Auswählen",
+        "flèches pour naviguer, avec ctrl par mois et années, entrée pour choisir",
+        "flechas para moverse, con ctrl por meses y años, intro para elegir",
+        "setas para mover, com ctrl por meses e anos, enter para selecionar",
+    ],
+    [
+        "←→ to move, ↑↓ to change, type to set, enter to select",
+        "←→ zum Bewegen, ↑↓ zum Ändern, tippen zum Setzen, Enter zum Auswählen",
+        "←→ pour naviguer, ↑↓ pour modifier, tapez pour saisir, entrée pour choisir",
+        "←→ para moverse, ↑↓ para cambiar, escriba para fijar, intro para elegir",
+        "←→ para mover, ↑↓ para alterar, digite para definir, enter para selecionar",
+    ],
+    [
+        "←→ to switch, enter to select",
+        "←→ zum Wechseln, Enter zum Auswählen",
+        "←→ pour basculer, entrée pour choisir",
+        "←→ para cambiar, intro para elegir",
+        "←→ para alternar, enter para selecionar",
+    ],
+    ["Yes", "Ja", "Oui", "Sí", "Sim"],
+    ["No", "Nein", "Non", "No", "Não"],
+    [
+        "Invalid answer, try typing 'y' for yes or 'n' for no",
+        "Ungültige Antwort, tippe 'j' für ja oder 'n' für nein",
+        "Réponse invalide, tapez 'o' pour oui ou 'n' pour non",
+        "Respuesta inválida, escriba 's' para sí o 'n' para no",
+        "Resposta inválida, digite 's' para sim ou 'n' para não",
+    ],
+    [
+        "Confirmation:",
+        "Bestätigung:",
+        "Confirmation :",
+        "Confirmación:",
+        "Confirmação:",
+    ],
+    [
+        "The answers don't match.",
+        "Die Antworten stimmen nicht überein.",
+        "Les réponses ne correspondent pas.",
+        "Las respuestas no coinciden.",
+        "As respostas não coincidem.",
+    ],
+    [
+        "Invalid date",
+        "Ungültiges Datum",
+        "Date invalide",
+        "Fecha inválida",
+        "Data inválida",
+    ],
+    [
+        "Type the full date to jump to, e.g. {}",
+        "Gib das vollständige Datum ein, um dorthin zu springen, z. B. {}",
+        "Saisissez la date complète pour y aller, p. ex. {}",
+        "Escriba la fecha completa para saltar a ella, p. ej. {}",
+        "Digite a data completa para ir até ela, p. ex. {}",
+    ],
+    [
+        "The input is incomplete",
+        "Die Eingabe ist unvollständig",
+        "La saisie est incomplète",
+        "La entrada está incompleta",
+        "A entrada está incompleta",
+    ],
+    [
+        "Invalid input",
+        "Ungültige Eingabe",
+        "Saisie invalide",
+        "Entrada inválida",
+        "Entrada inválida",
+    ],
+    [
+        "A response is required.",
+        "Eine Antwort ist erforderlich.",
+        "Une réponse est requise.",
+        "Se requiere una respuesta.",
+        "Uma resposta é obrigatória.",
+    ],
+    [
+        "The length of the response should be at most {}",
+        "Die Antwort sollte höchstens {} Zeichen lang sein",
+        "La réponse devrait faire au plus {} caractères",
+        "La respuesta debería tener como máximo {} caracteres",
+        "A resposta deveria ter no máximo {} caracteres",
+    ],
+    [
+        "The length of the response should be at least {}",
+        "Die Antwort sollte mindestens {} Zeichen lang sein",
+        "La réponse devrait faire au moins {} caractères",
+        "La respuesta debería tener como mínimo {} caracteres",
+        "A resposta deveria ter no mínimo {} caracteres",
+    ],
+    [
+        "The length of the response should be {}",
+        "Die Antwort sollte {} Zeichen lang sein",
+        "La réponse devrait faire {} caractères",
+        "La respuesta debería tener {} caracteres",
+        "A resposta deveria ter {} caracteres",
+    ],
+];
+
+#[cfg(test)]
+mod test {
+    use crate::parser::parse_bool;
+
+    use super::{Locale, TRANSLATIONS};
+
+    #[test]
+    fn tags_are_matched_by_language() {
+        assert_eq!(Some(Locale::DeDe), Locale::from_tag("de-DE"));
+        assert_eq!(Some(Locale::DeDe), Locale::from_tag("de_AT"));
+        assert_eq!(Some(Locale::FrFr), Locale::from_tag("FR"));
+        assert_eq!(Some(Locale::PtBr), Locale::from_tag("pt-PT"));
+        assert_eq!(None, Locale::from_tag("ja-JP"));
+        assert_eq!(None, Locale::from_tag(""));
+    }
+
+    #[test]
+    fn unknown_strings_are_not_translated() {
+        assert_eq!("Custom message", Locale::DeDe.translate("Custom message"));
+        assert_eq!("Yes", Locale::EnUs.translate("Yes"));
+        assert_eq!("Ja", Locale::DeDe.translate("Yes"));
+    }
+
+    #[test]
+    fn bool_answers_accept_translated_words() {
+        let locale = Locale::DeDe;
+        let parse = |ans| parse_bool(ans, locale.translate("Yes"), locale.translate("No"));
+
+        assert_eq!(Ok(true), parse("Ja"));
+        assert_eq!(Ok(true), parse("j"));
+        assert_eq!(Ok(true), parse("yes"));
+        assert_eq!(Ok(false), parse("NEIN"));
+        assert_eq!(Ok(false), parse("n"));
+        assert_eq!(Err(()), parse("jein"));
+
+        let locale = Locale::PtBr;
+        let parse = |ans| parse_bool(ans, locale.translate("Yes"), locale.translate("No"));
+
+        assert_eq!(Ok(true), parse("sim"));
+        assert_eq!(Ok(true), parse("S"));
+        assert_eq!(Ok(false), parse("não"));
+        assert_eq!(Err(()), parse("si"));
+    }
+
+    #[test]
+    fn templates_keep_their_placeholder() {
+        for entry in TRANSLATIONS {
+            let placeholders = entry[0].matches("{}").count();
+
+            for translation in entry {
+                assert_eq!(placeholders, translation.matches("{}").count());
+            }
+        }
+    }
+}
@@ -16,6 +16,10 @@ pub enum InputAction {
     /// Writes a character to the content, according to the current cursor
     /// position.
     Write(char),
+    /// Reverts the last change made to the content.
+    Undo,
+    /// Reapplies the last change reverted by `Undo`.
+    Redo,
 }
 
 impl InputAction {
@@ -33,7 +37,22 @@ impl InnerAction<()> for InputAction {
         Self: Sized,
     {
         let action = match key {
-            Key::Backspace => Self::Delete(Magnitude::Char, LineDirection::Left),
+            Key::Backspace(m) if m.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => {
+                Self::Delete(Magnitude::Word, LineDirection::Left)
+            }
+            Key::Backspace(_) => Self::Delete(Magnitude::Char, LineDirection::Left),
+            Key::Char('w', KeyModifiers::CONTROL) => {
+                Self::Delete(Magnitude::Word, LineDirection::Left)
+            }
+            Key::Char('d', KeyModifiers::ALT) => {
+                Self::Delete(Magnitude::Word, LineDirection::Right)
+            }
+            Key::Char('u', KeyModifiers::CONTROL) => {
+                Self::Delete(Magnitude::Line, LineDirection::Left)
+            }
+            Key::Char('k', KeyModifiers::CONTROL) => {
+                Self::Delete(Magnitude::Line, LineDirection::Right)
+            }
             Key::Char('h', m) if m.contains(KeyModifiers::CONTROL) => {
                 // Ctrl+Backspace is tricky, we don't want to handle a Ctrl+H
                 // but also don't want ctrl+h to simply write h.
@@ -58,6 +77,18 @@ impl InnerAction<()> for InputAction {
             }
             Key::Right(_) => Self::MoveCursor(Magnitude::Char, LineDirection::Right),
 
+            Key::Char('z', KeyModifiers::CONTROL) | Key::Char('_' | '7', KeyModifiers::CONTROL) => {
+                Self::Undo
+            }
+            Key::Char('y', KeyModifiers::CONTROL) => Self::Redo,
+
+            Key::Char('b', KeyModifiers::ALT) => {
+                Self::MoveCursor(Magnitude::Word, LineDirection::Left)
+            }
+            Key::Char('f', KeyModifiers::ALT) => {
+                Self::MoveCursor(Magnitude::Word, LineDirection::Right)
+            }
+
             Key::Char(c, _) => Self::Write(c),
             _ => return None,
         };
@@ -0,0 +1,93 @@
+use super::{Input, InputActionResult};
+use crate::InputAction;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Slot {
+    Digit,
+    Letter,
+    HexDigit,
+    Any,
+    Literal(char),
+}
+
+impl Slot {
+    fn accepts(self, c: char) -> bool {
+        match self {
+            Slot::Digit => c.is_ascii_digit(),
+            Slot::Letter => c.is_alphabetic(),
+            Slot::HexDigit => c.is_ascii_hexdigit(),
+            Slot::Any => true,
+            Slot::Literal(l) => l == c,
+        }
+    }
+}
+
+/// Template restricting the characters accepted at each position of an input.
+///
+/// `#` accepts a digit, `A` a letter, `H` a hexadecimal digit and `*` any
+/// character. Every other character is a literal separator, inserted
+/// automatically when the user types the character of the next slot. A
+/// backslash turns the following character into a literal, e.g. `\#HHHHHH`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputMask {
+    slots: Vec<Slot>,
+}
+
+impl InputMask {
+    pub fn new(template: &str) -> Self {
+        let mut slots = vec![];
+        let mut chars = template.chars();
+
+        while let Some(c) = chars.next() {
+            let slot = match c {
+                '#' => Slot::Digit,
+                'A' => Slot::Letter,
+                'H' => Slot::HexDigit,
+                '*' => Slot::Any,
+                '\\' => Slot::Literal(chars.next().unwrap_or('\\')),
+                c => Slot::Literal(c),
+            };
+            slots.push(slot);
+        }
+
+        Self { slots }
+    }
+
+    /// Whether the content fills the beginning of the mask.
+    pub fn matches_prefix(&self, content: &str) -> bool {
+        content.chars().count() <= self.slots.len()
+            && content
+                .chars()
+                .zip(self.slots.iter())
+                .all(|(c, slot)| slot.accepts(c))
+    }
+
+    /// Whether the content fills the whole mask.
+    pub fn is_complete(&self, content: &str) -> bool {
+        content.chars().count() == self.slots.len() && self.matches_prefix(content)
+    }
+
+    /// Writes a character to the input, first inserting the literal
+    /// separators at the cursor position when needed. The input is left
+    /// untouched if the character does not fit the mask.
+    pub fn write(&self, input: &mut Input, c: char) -> InputActionResult {
+        let mut candidate = input.clone();
+
+        loop {
+            let mut attempt = candidate.clone();
+            let result = attempt.handle(InputAction::Write(c));
+            if result == InputActionResult::ContentChanged && self.matches_prefix(attempt.content())
+            {
+                *input = attempt;
+                return InputActionResult::ContentChanged;
+            }
+
+            match self.slots.get(candidate.cursor()) {
+                Some(Slot::Literal(l))
+                    if candidate.handle(InputAction::Write(*l))
+                        == InputActionResult::ContentChanged => {}
+                _ => return InputActionResult::Clean,
+            }
+        }
+    }
+}
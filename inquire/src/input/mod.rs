@@ -1,4 +1,5 @@
 pub mod action;
+mod mask;
 #[cfg(test)]
 mod test;
 
@@ -6,6 +7,8 @@ use unicode_segmentation::UnicodeSegmentation;
 
 use crate::InputAction;
 
+pub use mask::InputMask;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Magnitude {
     Char,
@@ -23,12 +26,26 @@ fn is_alphanumeric(grapheme: &str) -> bool {
     grapheme.unicode_words().count() > 0
 }
 
+/// Content and cursor position of an input at a given point in time.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Snapshot {
+    content: String,
+    cursor: usize,
+}
+
 #[derive(Clone, Debug)]
 pub struct Input {
     content: String,
     placeholder: Option<String>,
     cursor: usize,
     length: usize,
+    max_length: Option<usize>,
+    length_counter: bool,
+    undo_stack: Vec<Snapshot>,
+    redo_stack: Vec<Snapshot>,
+    /// Whether the next written character belongs to the same undo step as
+    /// the previous one, so that a whole word is undone at once.
+    coalesce_writes: bool,
 }
 
 impl Input {
@@ -38,6 +55,11 @@ impl Input {
             placeholder: None,
             cursor: 0,
             length: 0,
+            max_length: None,
+            length_counter: false,
+            undo_stack: vec![],
+            redo_stack: vec![],
+            coalesce_writes: false,
         }
     }
 
@@ -54,6 +76,11 @@ impl Input {
             placeholder: None,
             length: len,
             cursor: len,
+            max_length: None,
+            length_counter: false,
+            undo_stack: vec![],
+            redo_stack: vec![],
+            coalesce_writes: false,
         }
     }
 
@@ -62,6 +89,23 @@ impl Input {
         self
     }
 
+    pub fn with_max_length(mut self, max_length: Option<usize>) -> Self {
+        self.max_length = max_length;
+        self
+    }
+
+    pub fn with_length_counter(mut self, length_counter: bool) -> Self {
+        self.length_counter = length_counter;
+        self
+    }
+
+    /// Copies the maximum length and counter settings of another input, used
+    /// when rendering a masked copy of it.
+    pub fn with_limits_of(self, other: &Input) -> Self {
+        self.with_max_length(other.max_length)
+            .with_length_counter(other.length_counter)
+    }
+
     pub fn with_cursor(mut self, cursor: usize) -> Self {
         assert!(
             cursor <= self.length,
@@ -82,8 +126,55 @@ impl Input {
         self.placeholder.as_deref()
     }
 
+    /// Current length and maximum length of the input, when the counter is enabled.
+    pub fn length_counter(&self) -> Option<(usize, usize)> {
+        match (self.length_counter, self.max_length) {
+            (true, Some(max_length)) => Some((self.length, max_length)),
+            _ => None,
+        }
+    }
+
+    /// Longest prefix of the text fitting in the maximum length of the input.
+    pub fn truncate_to_max_length<'t>(&self, text: &'t str) -> &'t str {
+        match self.max_length {
+            Some(max_length) => match text.grapheme_indices(true).nth(max_length) {
+                Some((end, _)) => &text[..end],
+                None => text,
+            },
+            None => text,
+        }
+    }
+
+    /// Replaces the content of the input, moving the cursor to its end.
+    ///
+    /// The replacement can be undone and is truncated to the maximum length.
+    pub fn set_content<S>(&mut self, content: S)
+    where
+        S: Into<String>,
+    {
+        let mut content = content.into();
+        content.truncate(self.truncate_to_max_length(&content).len());
+        if content == self.content {
+            return;
+        }
+
+        self.undo_stack.push(self.snapshot());
+        self.redo_stack.clear();
+        self.restore(Snapshot {
+            cursor: usize::MAX,
+            content,
+        });
+    }
+
     pub fn handle(&mut self, action: InputAction) -> InputActionResult {
-        match action {
+        let is_word_char = matches!(action, InputAction::Write(c) if !c.is_whitespace());
+        let snapshot = match action {
+            InputAction::Write(_) if is_word_char && self.coalesce_writes => None,
+            InputAction::Write(_) | InputAction::Delete(_, _) => Some(self.snapshot()),
+            _ => None,
+        };
+
+        let result = match action {
             InputAction::MoveCursor(mag, dir) => match dir {
                 LineDirection::Left => self.move_left(mag),
                 LineDirection::Right => self.move_right(mag),
@@ -93,6 +184,56 @@ impl Input {
                 LineDirection::Right => self.forwards_delete(mag),
             },
             InputAction::Write(c) => self.insert(c),
+            InputAction::Undo => return self.undo(),
+            InputAction::Redo => return self.redo(),
+        };
+
+        match result {
+            InputActionResult::ContentChanged => {
+                self.undo_stack.extend(snapshot);
+                self.redo_stack.clear();
+                self.coalesce_writes = is_word_char;
+            }
+            InputActionResult::PositionChanged => self.coalesce_writes = false,
+            InputActionResult::Clean => {}
+        }
+
+        result
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            content: self.content.clone(),
+            cursor: self.cursor,
+        }
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.content = snapshot.content;
+        self.update_length();
+        self.cursor = snapshot.cursor.min(self.length);
+        self.coalesce_writes = false;
+    }
+
+    fn undo(&mut self) -> InputActionResult {
+        match self.undo_stack.pop() {
+            Some(snapshot) => {
+                self.redo_stack.push(self.snapshot());
+                self.restore(snapshot);
+                InputActionResult::ContentChanged
+            }
+            None => InputActionResult::Clean,
+        }
+    }
+
+    fn redo(&mut self) -> InputActionResult {
+        match self.redo_stack.pop() {
+            Some(snapshot) => {
+                self.undo_stack.push(self.snapshot());
+                self.restore(snapshot);
+                InputActionResult::ContentChanged
+            }
+            None => InputActionResult::Clean,
         }
     }
 
@@ -203,6 +344,10 @@ impl Input {
     }
 
     fn insert(&mut self, c: char) -> InputActionResult {
+        if matches!(self.max_length, Some(max_length) if self.length >= max_length) {
+            return InputActionResult::Clean;
+        }
+
         let at = self.cursor;
 
         if at >= self.length {
@@ -2,8 +2,9 @@ use unicode_segmentation::UnicodeSegmentation;
 
 use super::Input;
 use crate::{
-    input::{InputActionResult, LineDirection, Magnitude},
-    InputAction,
+    input::{InputActionResult, InputMask, LineDirection, Magnitude},
+    ui::{Key, KeyModifiers},
+    InnerAction, InputAction,
 };
 
 #[test]
@@ -96,3 +97,212 @@ fn regression_issue_5() {
         assert!(input.content.find(vs16).is_some());
     }
 }
+
+#[test]
+fn readline_keybindings() {
+    let assert = |key, expected| {
+        assert_eq!(Some(expected), InputAction::from_key(key, &()), "{:?}", key);
+    };
+
+    assert(
+        Key::Char('w', KeyModifiers::CONTROL),
+        InputAction::Delete(Magnitude::Word, LineDirection::Left),
+    );
+    assert(
+        Key::Backspace(KeyModifiers::ALT),
+        InputAction::Delete(Magnitude::Word, LineDirection::Left),
+    );
+    assert(
+        Key::Char('d', KeyModifiers::ALT),
+        InputAction::Delete(Magnitude::Word, LineDirection::Right),
+    );
+    assert(
+        Key::Char('u', KeyModifiers::CONTROL),
+        InputAction::Delete(Magnitude::Line, LineDirection::Left),
+    );
+    assert(
+        Key::Char('k', KeyModifiers::CONTROL),
+        InputAction::Delete(Magnitude::Line, LineDirection::Right),
+    );
+    assert(
+        Key::Left(KeyModifiers::CONTROL),
+        InputAction::MoveCursor(Magnitude::Word, LineDirection::Left),
+    );
+    assert(
+        Key::Right(KeyModifiers::CONTROL),
+        InputAction::MoveCursor(Magnitude::Word, LineDirection::Right),
+    );
+    assert(
+        Key::Backspace(KeyModifiers::NONE),
+        InputAction::Delete(Magnitude::Char, LineDirection::Left),
+    );
+}
+
+#[test]
+fn word_deletion_respects_graphemes() {
+    let mut input = Input::new_with("héllo wörld ♥️ añejo");
+
+    let result = input.handle(InputAction::Delete(Magnitude::Word, LineDirection::Left));
+    assert_eq!(InputActionResult::ContentChanged, result);
+    assert_eq!("héllo wörld ♥️ ", input.content());
+    assert_eq!(14, input.cursor());
+
+    input.handle(InputAction::Delete(Magnitude::Word, LineDirection::Left));
+    assert_eq!("héllo ", input.content());
+    assert_eq!(6, input.cursor());
+
+    input.handle(InputAction::MoveCursor(
+        Magnitude::Line,
+        LineDirection::Left,
+    ));
+    input.handle(InputAction::Delete(Magnitude::Word, LineDirection::Right));
+    assert_eq!(" ", input.content());
+    assert_eq!(0, input.cursor());
+}
+
+#[test]
+fn kill_to_line_boundaries() {
+    let mut input = Input::new_with("añejo wörld").with_cursor(5);
+
+    input.handle(InputAction::Delete(Magnitude::Line, LineDirection::Right));
+    assert_eq!("añejo", input.content());
+    assert_eq!(5, input.cursor());
+
+    input.handle(InputAction::MoveCursor(
+        Magnitude::Char,
+        LineDirection::Left,
+    ));
+    input.handle(InputAction::Delete(Magnitude::Line, LineDirection::Left));
+    assert_eq!("o", input.content());
+    assert_eq!(0, input.cursor());
+}
+
+#[test]
+fn undo_reverts_whole_words() {
+    let mut input = Input::new();
+    for c in "hello big world".chars() {
+        input.handle(InputAction::Write(c));
+    }
+
+    assert_eq!(
+        InputActionResult::ContentChanged,
+        input.handle(InputAction::Undo)
+    );
+    assert_eq!("hello big ", input.content());
+    assert_eq!(10, input.cursor());
+
+    input.handle(InputAction::Undo);
+    assert_eq!("hello big", input.content());
+
+    input.handle(InputAction::Undo);
+    assert_eq!("hello ", input.content());
+
+    input.handle(InputAction::Redo);
+    input.handle(InputAction::Redo);
+    assert_eq!("hello big ", input.content());
+}
+
+#[test]
+fn undo_restores_deletions_and_cursor() {
+    let mut input = Input::new_with("añejo wörld").with_cursor(5);
+
+    input.handle(InputAction::Delete(Magnitude::Line, LineDirection::Left));
+    input.handle(InputAction::Delete(Magnitude::Char, LineDirection::Right));
+    assert_eq!("wörld", input.content());
+
+    input.handle(InputAction::Undo);
+    assert_eq!(" wörld", input.content());
+    assert_eq!(0, input.cursor());
+
+    input.handle(InputAction::Undo);
+    assert_eq!("añejo wörld", input.content());
+    assert_eq!(5, input.cursor());
+
+    assert_eq!(InputActionResult::Clean, input.handle(InputAction::Undo));
+}
+
+#[test]
+fn new_edits_discard_redo_history() {
+    let mut input = Input::new_with("abc");
+
+    input.handle(InputAction::Delete(Magnitude::Char, LineDirection::Left));
+    input.handle(InputAction::Undo);
+    input.handle(InputAction::Write('d'));
+
+    assert_eq!(InputActionResult::Clean, input.handle(InputAction::Redo));
+    assert_eq!("abcd", input.content());
+}
+
+#[test]
+fn moving_the_cursor_splits_undo_steps() {
+    let mut input = Input::new_with("ac").with_cursor(1);
+
+    input.handle(InputAction::Write('b'));
+    input.handle(InputAction::MoveCursor(
+        Magnitude::Line,
+        LineDirection::Right,
+    ));
+    input.handle(InputAction::Write('d'));
+    input.handle(InputAction::Undo);
+
+    assert_eq!("abc", input.content());
+}
+
+#[test]
+fn mask_inserts_separators_and_rejects_invalid_characters() {
+    let mask = InputMask::new("####-##-##");
+    let mut input = Input::new();
+
+    for c in "2024a1x231".chars() {
+        mask.write(&mut input, c);
+    }
+
+    assert_eq!("2024-12-31", input.content());
+    assert!(mask.is_complete(input.content()));
+    assert_eq!(InputActionResult::Clean, mask.write(&mut input, '1'));
+}
+
+#[test]
+fn mask_accepts_typed_separators_and_escapes() {
+    let mask = InputMask::new("\\#HHH");
+    let mut input = Input::new();
+
+    assert_eq!(
+        InputActionResult::ContentChanged,
+        mask.write(&mut input, '#')
+    );
+    for c in "fgA0".chars() {
+        mask.write(&mut input, c);
+    }
+
+    assert_eq!("#fA0", input.content());
+    assert!(mask.is_complete(input.content()));
+    assert!(!mask.is_complete("#fA"));
+    assert!(mask.matches_prefix("#fA"));
+}
+
+#[test]
+fn max_length_blocks_writes() {
+    let mut input = Input::new_with("añej").with_max_length(Some(5));
+
+    assert_eq!(
+        InputActionResult::ContentChanged,
+        input.handle(InputAction::Write('o'))
+    );
+    assert_eq!(
+        InputActionResult::Clean,
+        input.handle(InputAction::Write('s'))
+    );
+    assert_eq!("añejo", input.content());
+
+    input.set_content("añ");
+    assert_eq!("añ", input.content());
+    assert_eq!(2, input.cursor());
+
+    input.handle(InputAction::Undo);
+    assert_eq!("añejo", input.content());
+
+    input.set_content("añejos");
+    assert_eq!("añejo", input.content());
+    assert_eq!(5, input.cursor());
+}
@@ -6,6 +6,7 @@
 //! - [`Text`] to get text input from the user, with _built-in autocompletion support_;
 //! - [`Editor`]* to get longer text inputs by opening a text editor for the user;
 //! - [`DateSelect`]* to get a date input from the user, selected via an _interactive calendar_;
+//! - [`TimeSelect`]* to get a time of the day from the user, adjusting its hours and minutes;
 //! - [`Select`] to ask the user to select one option from a given list;
 //! - [`MultiSelect`] to ask the user to select an arbitrary number of options from a given list;
 //! - [`Confirm`] for simple yes/no confirmation prompts;
@@ -31,6 +32,42 @@
 //!
 //! \* Date-related features are available by enabling the `date` feature.
 //!
+//! On UNIX systems, enabling the `suspend` feature makes `Ctrl+Z` suspend the
+//! application while a prompt is running, restoring the terminal beforehand and
+//! rendering the prompt again once the application is resumed.
+//!
+//! With the `expressions` feature, numeric [`CustomType`] prompts can evaluate
+//! simple arithmetic expressions such as `1024*8`, see
+//! [`CustomType::with_expressions`].
+//!
+//! The `i18n` feature translates the built-in strings of the prompts, such as
+//! help messages, validator errors and the calendar of [`DateSelect`], to the
+//! locale selected with `set_locale("de-DE")`, see the `i18n` module.
+//!
+//! The `derive` feature re-exports the `Selectable` derive macro, generating a
+//! `select` constructor listing all variants of an enum, and the `Prompt`
+//! derive macro, generating a `prompt` function asking for each field of a
+//! struct with a prompt chosen by its type.
+//!
+//! The `clap` feature prompts for the required arguments missing from the
+//! command line of a clap `Command`, see the `clap` module.
+//!
+//! The `deserialize` feature fills in any serde `Deserialize` type, or a
+//! `serde_json::Value` template, with a prompt for each of its fields, see the
+//! `deserialize` module.
+//!
+//! Prompts notify their event listener of the user interactions, see the
+//! `events` module. The `tracing` feature adds `events::trace`, emitting them
+//! through tracing.
+//!
+//! Prompts can be aborted from another thread or a shutdown signal with a
+//! [`PromptHandle`], see the `handle` module.
+//!
+//! Errors can be reported with a hint on how to solve them, see [`InquireError::hint`].
+//! The `miette` feature implements `miette::Diagnostic` for [`InquireError`], and
+//! the `anyhow` feature adds [`InquireError::into_anyhow`], both including the hint
+//! in the reported error.
+//!
 //! # Simple Example
 //!
 //! ```rust no_run
@@ -56,6 +93,7 @@
 //!
 //! [`Text`]: crate::Text
 //! [`DateSelect`]: crate::DateSelect
+//! [`TimeSelect`]: crate::TimeSelect
 //! [`Select`]: crate::Select
 //! [`MultiSelect`]: crate::MultiSelect
 //! [`Confirm`]: crate::Confirm
@@ -68,23 +106,64 @@
 #![allow(clippy::bool_to_int_with_if)]
 mod ansi;
 pub mod autocompletion;
+#[cfg(feature = "clap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "clap")))]
+pub mod clap;
 mod config;
+#[cfg(feature = "rust_decimal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rust_decimal")))]
+pub mod currency;
 #[cfg(feature = "date")]
 mod date_utils;
+#[cfg(feature = "deserialize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "deserialize")))]
+pub mod deserialize;
+pub mod diagnostics;
 pub mod error;
+pub mod events;
+pub mod exit;
+#[cfg(feature = "expressions")]
+#[cfg_attr(docsrs, doc(cfg(feature = "expressions")))]
+pub mod expression;
+pub mod flow;
+pub mod form;
 pub mod formatter;
+pub mod handle;
+#[cfg(feature = "i18n")]
+#[cfg_attr(docsrs, doc(cfg(feature = "i18n")))]
+pub mod i18n;
 mod input;
 pub mod list_option;
+mod macros;
+pub mod network;
 pub mod parser;
 mod prompts;
-mod terminal;
+pub mod record;
+pub mod report;
+pub mod status;
+pub mod store;
+pub mod terminal;
+pub mod text;
 pub mod type_aliases;
 pub mod ui;
+#[cfg(feature = "units")]
+#[cfg_attr(docsrs, doc(cfg(feature = "units")))]
+pub mod units;
 mod utils;
 pub mod validator;
 
 pub use crate::autocompletion::Autocomplete;
-pub use crate::config::set_global_render_config;
+pub use crate::config::{
+    set_global_event_listener, set_global_interrupt_behavior, set_global_options,
+    set_global_render_config, set_global_status_reporting, GlobalOptions, InterruptBehavior,
+};
 pub use crate::error::{CustomUserError, InquireError};
+pub use crate::handle::PromptHandle;
+#[cfg(feature = "i18n")]
+pub use crate::i18n::set_locale;
 pub use crate::input::action::*;
 pub use crate::prompts::*;
+pub use crate::report::PromptReport;
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use inquire_derive::{Prompt, Selectable};
@@ -62,3 +62,27 @@ where
         self.value.fmt(f)
     }
 }
+
+/// Answer of a [Select](crate::Select) allowing the user to create a new
+/// option from the filter input, see
+/// [`Select::new_or_create`](crate::Select::new_or_create).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Choice<T> {
+    /// One of the options given to the prompt.
+    Existing(T),
+
+    /// Option created from the text typed by the user.
+    New(String),
+}
+
+impl<T> fmt::Display for Choice<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Existing(value) => value.fmt(f),
+            Self::New(text) => text.fmt(f),
+        }
+    }
+}
@@ -0,0 +1,262 @@
+//! Declarative shorthands for building prompts.
+//!
+//! Each macro expands to the builder chain of its prompt: the first arguments
+//! are the ones taken by the prompt's `new` method, followed by any number of
+//! `option = value` pairs, each one calling the `with_option` method of the
+//! builder. Options not supported by a prompt fail to compile, just like calling
+//! a missing method would.
+
+/// Applies `option = value` pairs to a prompt builder. Used by the prompt macros.
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "macros")]
+macro_rules! __prompt_options {
+    ($prompt:expr $(,)?) => {
+        $prompt
+    };
+
+    ($prompt:expr, validators = [$($validator:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::__prompt_options!($prompt $(.with_validator($validator))* $(, $($rest)*)?)
+    };
+
+    ($prompt:expr, $option:ident = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__prompt_options!(
+            $crate::__prompt_option!($prompt, $option, $value)
+            $(, $($rest)*)?
+        )
+    };
+}
+
+/// Maps a single option to its builder method. Used by the prompt macros.
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "macros")]
+macro_rules! __prompt_option {
+    ($prompt:expr, default, $value:expr) => {
+        $prompt.with_default($value)
+    };
+    ($prompt:expr, default_values, $value:expr) => {
+        $prompt.with_default_values($value)
+    };
+    ($prompt:expr, initial_value, $value:expr) => {
+        $prompt.with_initial_value($value)
+    };
+    ($prompt:expr, placeholder, $value:expr) => {
+        $prompt.with_placeholder($value)
+    };
+    ($prompt:expr, help_message, $value:expr) => {
+        $prompt.with_help_message($value)
+    };
+    ($prompt:expr, formatter, $value:expr) => {
+        $prompt.with_formatter($value)
+    };
+    ($prompt:expr, parser, $value:expr) => {
+        $prompt.with_parser($value)
+    };
+    ($prompt:expr, error_message, $value:expr) => {
+        $prompt.with_error_message($value)
+    };
+    ($prompt:expr, validator, $value:expr) => {
+        $prompt.with_validator($value)
+    };
+    ($prompt:expr, validators, $value:expr) => {
+        $prompt.with_validators(&$value)
+    };
+    ($prompt:expr, background_validator, $value:expr) => {
+        $prompt.with_background_validator($value)
+    };
+    ($prompt:expr, autocomplete, $value:expr) => {
+        $prompt.with_autocomplete($value)
+    };
+    ($prompt:expr, filter, $value:expr) => {
+        $prompt.with_filter($value)
+    };
+    ($prompt:expr, keep_filter, $value:expr) => {
+        $prompt.with_keep_filter($value)
+    };
+    ($prompt:expr, page_size, $value:expr) => {
+        $prompt.with_page_size($value)
+    };
+    ($prompt:expr, starting_cursor, $value:expr) => {
+        $prompt.with_starting_cursor($value)
+    };
+    ($prompt:expr, search_only_threshold, $value:expr) => {
+        $prompt.with_search_only_threshold($value)
+    };
+    ($prompt:expr, search_min_input_length, $value:expr) => {
+        $prompt.with_search_min_input_length($value)
+    };
+    ($prompt:expr, vim_mode, $value:expr) => {
+        $prompt.with_vim_mode($value)
+    };
+    ($prompt:expr, display_mode, $value:expr) => {
+        $prompt.with_display_mode($value)
+    };
+    ($prompt:expr, interrupt_behavior, $value:expr) => {
+        $prompt.with_interrupt_behavior($value)
+    };
+    ($prompt:expr, timeout, $value:expr) => {
+        $prompt.with_timeout($value)
+    };
+    ($prompt:expr, timeout_countdown, $value:expr) => {
+        $prompt.with_timeout_countdown($value)
+    };
+    ($prompt:expr, render_config, $value:expr) => {
+        $prompt.with_render_config($value)
+    };
+    ($prompt:expr, $option:ident, $value:expr) => {
+        compile_error!(concat!("unknown prompt option `", stringify!($option), "`"))
+    };
+}
+
+/// Shorthand for building a [`Text`](crate::Text) prompt.
+///
+/// # Arguments
+///
+/// * `$message` - Message of the prompt.
+/// * `$option = $value` - optional - Options of the prompt, each one calling the
+///   `with_$option` method of the builder. `validators` accepts a list of validators.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{min_length, required, text};
+///
+/// let prompt = text!(
+///     "What's your name?",
+///     default = "Jane",
+///     help_message = "First name only",
+///     validators = [required!(), min_length!(2)],
+/// );
+///
+/// assert_eq!(Some("Jane"), prompt.default);
+/// assert_eq!(2, prompt.validators.len());
+/// ```
+#[macro_export]
+#[cfg(feature = "macros")]
+macro_rules! text {
+    ($message:expr $(, $($options:tt)*)?) => {
+        $crate::__prompt_options!($crate::Text::new($message) $(, $($options)*)?)
+    };
+}
+
+/// Shorthand for building a [`Password`](crate::Password) prompt.
+///
+/// # Arguments
+///
+/// * `$message` - Message of the prompt.
+/// * `$option = $value` - optional - Options of the prompt, each one calling the
+///   `with_$option` method of the builder. `validators` accepts a list of validators.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{password, PasswordDisplayMode};
+///
+/// let prompt = password!("Password:", display_mode = PasswordDisplayMode::Masked);
+///
+/// assert_eq!(PasswordDisplayMode::Masked, prompt.display_mode);
+/// ```
+#[macro_export]
+#[cfg(feature = "macros")]
+macro_rules! password {
+    ($message:expr $(, $($options:tt)*)?) => {
+        $crate::__prompt_options!($crate::Password::new($message) $(, $($options)*)?)
+    };
+}
+
+/// Shorthand for building a [`Confirm`](crate::Confirm) prompt.
+///
+/// # Arguments
+///
+/// * `$message` - Message of the prompt.
+/// * `$option = $value` - optional - Options of the prompt, each one calling the
+///   `with_$option` method of the builder.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::confirm;
+///
+/// let prompt = confirm!("Continue?", default = true);
+///
+/// assert_eq!(Some(true), prompt.default);
+/// ```
+#[macro_export]
+#[cfg(feature = "macros")]
+macro_rules! confirm {
+    ($message:expr $(, $($options:tt)*)?) => {
+        $crate::__prompt_options!($crate::Confirm::new($message) $(, $($options)*)?)
+    };
+}
+
+/// Shorthand for building a [`Select`](crate::Select) prompt.
+///
+/// # Arguments
+///
+/// * `$message` - Message of the prompt.
+/// * `$options` - List of options, either as a `Vec` or as an array literal.
+/// * `$option = $value` - optional - Options of the prompt, each one calling the
+///   `with_$option` method of the builder.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::select;
+///
+/// let prompt = select!("Environment:", ["dev", "staging", "prod"], starting_cursor = 2);
+///
+/// assert_eq!(vec!["dev", "staging", "prod"], prompt.options);
+/// assert_eq!(2, prompt.starting_cursor);
+/// ```
+#[macro_export]
+#[cfg(feature = "macros")]
+macro_rules! select {
+    ($message:expr, [$($option:expr),* $(,)?] $(, $($options:tt)*)?) => {
+        $crate::__prompt_options!(
+            $crate::Select::new($message, vec![$($option),*])
+            $(, $($options)*)?
+        )
+    };
+
+    ($message:expr, $list:expr $(, $($options:tt)*)?) => {
+        $crate::__prompt_options!($crate::Select::new($message, $list) $(, $($options)*)?)
+    };
+}
+
+/// Shorthand for building a [`MultiSelect`](crate::MultiSelect) prompt.
+///
+/// # Arguments
+///
+/// * `$message` - Message of the prompt.
+/// * `$options` - List of options, either as a `Vec` or as an array literal.
+/// * `$option = $value` - optional - Options of the prompt, each one calling the
+///   `with_$option` method of the builder.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::multi_select;
+///
+/// let prompt = multi_select!(
+///     "Toppings:",
+///     ["cheese", "olives", "onions"],
+///     default_values = &["cheese"],
+/// );
+///
+/// assert_eq!(Some(vec![0]), prompt.default);
+/// ```
+#[macro_export]
+#[cfg(feature = "macros")]
+macro_rules! multi_select {
+    ($message:expr, [$($option:expr),* $(,)?] $(, $($options:tt)*)?) => {
+        $crate::__prompt_options!(
+            $crate::MultiSelect::new($message, vec![$($option),*])
+            $(, $($options)*)?
+        )
+    };
+
+    ($message:expr, $list:expr $(, $($options:tt)*)?) => {
+        $crate::__prompt_options!($crate::MultiSelect::new($message, $list) $(, $($options)*)?)
+    };
+}
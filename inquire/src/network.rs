@@ -0,0 +1,274 @@
+//! Network address types and helpers backing the
+//! [`CustomType::new_ip`](crate::CustomType::new_ip) and
+//! [`CustomType::new_cidr`](crate::CustomType::new_cidr) constructors.
+//!
+//! ```
+//! use inquire::network::Cidr;
+//!
+//! let cidr: Cidr = "192.168.1.17/24".parse().unwrap();
+//! assert_eq!(24, cidr.prefix_len());
+//! assert_eq!("192.168.1.0/24", cidr.network().to_string());
+//! assert!("10.0.0.1/33".parse::<Cidr>().is_err());
+//! ```
+
+use std::{
+    fmt,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    str::FromStr,
+};
+
+use thiserror::Error;
+
+/// Reason why a [`Cidr`] could not be parsed.
+#[derive(Error, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CidrParseError {
+    /// The input is missing the `/` separating the address from the prefix length.
+    #[error("Missing prefix length")]
+    MissingPrefixLength,
+
+    /// The part before the `/` is not a valid IPv4 or IPv6 address.
+    #[error("Invalid address")]
+    InvalidAddress,
+
+    /// The prefix length is not a number or is longer than the address, 32
+    /// bits for IPv4 and 128 bits for IPv6.
+    #[error("Invalid prefix length")]
+    InvalidPrefixLength,
+}
+
+/// IP address along with a prefix length, written in CIDR notation such as
+/// `10.0.0.0/8` or `2001:db8::/32`.
+///
+/// The address may have bits set after the prefix, as in `192.168.1.17/24`
+/// which describes an interface address. Use [`network`](Self::network) to
+/// get the address of the network itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cidr {
+    address: IpAddr,
+    prefix_len: u8,
+}
+
+impl Cidr {
+    /// Creates a [Cidr], returning `None` when the prefix length is longer
+    /// than the address.
+    pub fn new(address: IpAddr, prefix_len: u8) -> Option<Self> {
+        let max_len = match address {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        };
+
+        match prefix_len <= max_len {
+            true => Some(Self {
+                address,
+                prefix_len,
+            }),
+            false => None,
+        }
+    }
+
+    /// Address, as typed by the user.
+    pub fn address(&self) -> IpAddr {
+        self.address
+    }
+
+    /// Number of leading bits of the address describing the network.
+    pub fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    /// Same [Cidr] with every bit after the prefix cleared, e.g. `10.1.2.0/24`
+    /// for `10.1.2.3/24`.
+    pub fn network(&self) -> Self {
+        let address = match self.address {
+            IpAddr::V4(address) => {
+                let mask = u32::MAX.checked_shl(32 - u32::from(self.prefix_len));
+                let bits = u32::from(address) & mask.unwrap_or(0);
+                IpAddr::V4(Ipv4Addr::from(bits))
+            }
+            IpAddr::V6(address) => {
+                let mask = u128::MAX.checked_shl(128 - u32::from(self.prefix_len));
+                let bits = u128::from(address) & mask.unwrap_or(0);
+                IpAddr::V6(Ipv6Addr::from(bits))
+            }
+        };
+
+        Self {
+            address,
+            prefix_len: self.prefix_len,
+        }
+    }
+
+    /// Whether the address belongs to the network.
+    ///
+    /// ```
+    /// use inquire::network::Cidr;
+    ///
+    /// let cidr: Cidr = "10.0.0.0/8".parse().unwrap();
+    /// assert!(cidr.contains("10.20.30.40".parse().unwrap()));
+    /// assert!(!cidr.contains("11.0.0.1".parse().unwrap()));
+    /// ```
+    pub fn contains(&self, address: IpAddr) -> bool {
+        Cidr::new(address, self.prefix_len)
+            .map(|other| other.network().address == self.network().address)
+            .unwrap_or(false)
+    }
+}
+
+impl FromStr for Cidr {
+    type Err = CidrParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (address, prefix_len) = s
+            .trim()
+            .split_once('/')
+            .ok_or(CidrParseError::MissingPrefixLength)?;
+
+        let address = address
+            .parse::<IpAddr>()
+            .map_err(|_| CidrParseError::InvalidAddress)?;
+
+        // `u8::from_str` also accepts a `+` sign and leading zeros, which are
+        // not valid in CIDR notation
+        if !prefix_len.bytes().all(|b| b.is_ascii_digit())
+            || (prefix_len.len() > 1 && prefix_len.starts_with('0'))
+        {
+            return Err(CidrParseError::InvalidPrefixLength);
+        }
+
+        prefix_len
+            .parse::<u8>()
+            .ok()
+            .and_then(|prefix_len| Cidr::new(address, prefix_len))
+            .ok_or(CidrParseError::InvalidPrefixLength)
+    }
+}
+
+impl fmt::Display for Cidr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.address, self.prefix_len)
+    }
+}
+
+/// Addresses of the network interfaces of the machine, loopback addresses
+/// excluded, IPv4 addresses first.
+///
+/// Only supported on UNIX systems, an empty list is returned elsewhere or when
+/// the interfaces can not be listed.
+///
+/// Requires the `local-addresses` feature.
+#[cfg(feature = "local-addresses")]
+#[cfg_attr(docsrs, doc(cfg(feature = "local-addresses")))]
+pub fn local_addresses() -> Vec<IpAddr> {
+    let mut addresses: Vec<IpAddr> = vec![];
+    for address in interface_addresses() {
+        if !address.is_loopback() && !addresses.contains(&address) {
+            addresses.push(address);
+        }
+    }
+
+    addresses.sort_by_key(IpAddr::is_ipv6);
+    addresses
+}
+
+#[cfg(all(feature = "local-addresses", unix))]
+fn interface_addresses() -> Vec<IpAddr> {
+    let mut addresses = vec![];
+
+    let mut head: *mut libc::ifaddrs = std::ptr::null_mut();
+    // SAFETY: the list allocated by getifaddrs is only read while it is alive
+    // and freed exactly once.
+    unsafe {
+        if libc::getifaddrs(&mut head) != 0 {
+            return addresses;
+        }
+
+        let mut current = head;
+        while let Some(ifaddr) = current.as_ref() {
+            if let Some(addr) = ifaddr.ifa_addr.as_ref() {
+                match i32::from(addr.sa_family) {
+                    libc::AF_INET => {
+                        let addr = &*(ifaddr.ifa_addr as *const libc::sockaddr_in);
+                        let bits = u32::from_be(addr.sin_addr.s_addr);
+                        addresses.push(IpAddr::V4(Ipv4Addr::from(bits)));
+                    }
+                    libc::AF_INET6 => {
+                        let addr = &*(ifaddr.ifa_addr as *const libc::sockaddr_in6);
+                        addresses.push(IpAddr::V6(Ipv6Addr::from(addr.sin6_addr.s6_addr)));
+                    }
+                    _ => {}
+                }
+            }
+            current = ifaddr.ifa_next;
+        }
+
+        libc::freeifaddrs(head);
+    }
+
+    addresses
+}
+
+#[cfg(all(feature = "local-addresses", not(unix)))]
+fn interface_addresses() -> Vec<IpAddr> {
+    vec![]
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::IpAddr;
+
+    use super::{Cidr, CidrParseError};
+
+    #[test]
+    fn cidrs_are_parsed_and_validated() {
+        let cidr: Cidr = " 2001:db8::1/32 ".parse().unwrap();
+        assert_eq!("2001:db8::1".parse::<IpAddr>().unwrap(), cidr.address());
+        assert_eq!(32, cidr.prefix_len());
+
+        assert_eq!(
+            Err(CidrParseError::MissingPrefixLength),
+            "10.0.0.1".parse::<Cidr>()
+        );
+        assert_eq!(
+            Err(CidrParseError::InvalidAddress),
+            "10.0.0/8".parse::<Cidr>()
+        );
+        assert_eq!(
+            Err(CidrParseError::InvalidPrefixLength),
+            "10.0.0.0/".parse::<Cidr>()
+        );
+        assert_eq!(
+            Err(CidrParseError::InvalidPrefixLength),
+            "::/129".parse::<Cidr>()
+        );
+        assert_eq!(
+            Err(CidrParseError::InvalidPrefixLength),
+            "10.0.0.1/+8".parse::<Cidr>()
+        );
+        assert_eq!(
+            Err(CidrParseError::InvalidPrefixLength),
+            "10.0.0.1/08".parse::<Cidr>()
+        );
+        assert_eq!(
+            Err(CidrParseError::InvalidPrefixLength),
+            "10.0.0.1/ 8".parse::<Cidr>()
+        );
+    }
+
+    #[test]
+    fn network_clears_host_bits() {
+        let network = |input: &str| input.parse::<Cidr>().unwrap().network().to_string();
+
+        assert_eq!("0.0.0.0/0", network("192.168.1.1/0"));
+        assert_eq!("172.16.0.0/12", network("172.20.1.1/12"));
+        assert_eq!("10.1.2.3/32", network("10.1.2.3/32"));
+        assert_eq!("2001:db8::/48", network("2001:db8::ff/48"));
+    }
+
+    #[test]
+    #[cfg(feature = "local-addresses")]
+    fn local_addresses_skip_loopback_interfaces() {
+        let addresses = super::local_addresses();
+
+        assert!(addresses.iter().all(|address| !address.is_loopback()));
+    }
+}
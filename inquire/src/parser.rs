@@ -6,6 +6,8 @@
 //! a successful result ([Ok]) containing the parsed value or an empty [Err]
 //! if a value could not be parsed.
 
+use crate::config::translate;
+
 /// Type alias for parsers used in [Confirm](crate::Confirm) prompts.
 ///
 /// [`BoolParser`]s receive the user input to a given prompt and return either
@@ -53,19 +55,30 @@ pub type BoolParser<'a> = &'a dyn Fn(&str) -> Result<bool, ()>;
 pub type CustomTypeParser<'a, T> = &'a dyn Fn(&str) -> Result<T, ()>;
 
 /// Bool formatter used  by default in [Confirm](crate::Confirm) prompts.
-pub const DEFAULT_BOOL_PARSER: BoolParser = &|ans| {
-    if ans.len() > 3 {
-        return Err(());
-    }
+///
+/// Accepts `y`, `yes`, `n` and `no`, case-insensitively. With the `i18n`
+/// feature, the words for yes and no of the selected locale and their first
+/// letter are accepted as well, e.g. `j` and `nein` in German.
+pub const DEFAULT_BOOL_PARSER: BoolParser =
+    &|ans| parse_bool(ans, translate("Yes"), translate("No"));
 
+/// Parses the answer to a yes/no question, accepting the English words, the
+/// given ones and their first letters.
+pub(crate) fn parse_bool(ans: &str, yes: &str, no: &str) -> Result<bool, ()> {
     let ans = ans.to_lowercase();
+    let matches = |word: &str| {
+        let word = word.to_lowercase();
+        ans == word || (ans.chars().count() == 1 && word.starts_with(ans.as_str()))
+    };
 
     match ans.as_str() {
         "y" | "yes" => Ok(true),
         "n" | "no" => Ok(false),
+        _ if matches(yes) => Ok(true),
+        _ if matches(no) => Ok(false),
         _ => Err(()),
     }
-};
+}
 
 #[macro_export]
 #[cfg(feature = "macros")]
@@ -22,6 +22,10 @@ where
     Cancel,
     /// Interrupts the prompt execution without a graceful shutdown.
     Interrupt,
+    /// Suspends the process, resuming the prompt once the process continues.
+    #[cfg(all(unix, feature = "suspend"))]
+    #[cfg_attr(docsrs, doc(cfg(all(unix, feature = "suspend"))))]
+    Suspend,
     /// Specialized actions according to the prompt type.
     Inner(I),
 }
@@ -39,6 +43,8 @@ where
             Key::Enter => Some(Action::Submit),
             Key::Escape => Some(Action::Cancel),
             Key::Char('c', KeyModifiers::CONTROL) => Some(Action::Interrupt),
+            #[cfg(all(unix, feature = "suspend"))]
+            Key::Char('z', KeyModifiers::CONTROL) => Some(Action::Suspend),
             key => I::from_key(key, config).map(Action::Inner),
         }
     }
@@ -0,0 +1,101 @@
+use std::{
+    sync::{
+        mpsc::{self, Receiver, RecvTimeoutError},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    error::{CustomUserError, InquireResult},
+    validator::{BackgroundStringValidator, CancellationToken, Validation},
+    InquireError,
+};
+
+const SPINNER_FRAMES: &[char] = &['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+/// Background validation of a submitted answer, canceled once dropped.
+pub(crate) struct PendingValidation<T> {
+    answer: T,
+    started: Instant,
+    cancellation: CancellationToken,
+    result: Receiver<Result<Validation, CustomUserError>>,
+}
+
+impl<T> PendingValidation<T> {
+    /// Runs the validators over the input on a worker thread, in order, until
+    /// one of them does not accept it. `answer` is returned once all do.
+    pub fn start(
+        validators: &[Arc<dyn BackgroundStringValidator>],
+        input: &str,
+        answer: T,
+    ) -> Self {
+        let (sender, result) = mpsc::channel();
+        let cancellation = CancellationToken::new();
+
+        let validators = validators.to_vec();
+        let input = input.to_owned();
+        let token = cancellation.clone();
+        thread::spawn(move || {
+            let mut outcome = Ok(Validation::Valid);
+            for validator in &validators {
+                if token.is_canceled() {
+                    return;
+                }
+
+                outcome = validator.validate(&input, &token);
+                if !matches!(outcome, Ok(Validation::Valid)) {
+                    break;
+                }
+            }
+
+            // The prompt might be gone already, in which case nobody cares about the result.
+            let _ = sender.send(outcome);
+        });
+
+        Self {
+            answer,
+            started: Instant::now(),
+            cancellation,
+            result,
+        }
+    }
+
+    /// Waits up to `timeout` for the validation to end, returning `None` if it
+    /// is still running.
+    pub fn poll(&self, timeout: Duration) -> Option<InquireResult<Validation>> {
+        match self.result.recv_timeout(timeout) {
+            Ok(Ok(validation)) => Some(Ok(validation)),
+            Ok(Err(err)) => Some(Err(InquireError::Custom(err))),
+            Err(RecvTimeoutError::Timeout) => None,
+            Err(RecvTimeoutError::Disconnected) => Some(Err(InquireError::Custom(
+                "background validation stopped without a result".into(),
+            ))),
+        }
+    }
+
+    /// Answer being validated.
+    pub fn answer(&self) -> &T {
+        &self.answer
+    }
+
+    /// Help message rendered while the validation runs, with a spinner and
+    /// the elapsed time.
+    pub fn status_message(&self) -> String {
+        let elapsed = self.started.elapsed();
+        let frame = SPINNER_FRAMES[(elapsed.as_millis() / 100) as usize % SPINNER_FRAMES.len()];
+
+        format!(
+            "{} Validating... {:.1}s (esc to cancel)",
+            frame,
+            elapsed.as_secs_f64()
+        )
+    }
+}
+
+impl<T> Drop for PendingValidation<T> {
+    fn drop(&mut self) {
+        self.cancellation.cancel();
+    }
+}
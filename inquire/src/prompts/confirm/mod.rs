@@ -2,13 +2,20 @@ mod action;
 
 pub use action::*;
 
+use std::time::Duration;
+
 use crate::{
-    config::get_configuration,
+    config::{
+        get_configuration, get_event_listener, get_interrupt_behavior, translate, InterruptBehavior,
+    },
     error::{InquireError, InquireResult},
     formatter::{BoolFormatter, DEFAULT_BOOL_FORMATTER},
+    handle::PromptHandle,
     parser::{BoolParser, DEFAULT_BOOL_PARSER},
+    report::PromptReport,
     terminal::{get_default_terminal, Terminal},
-    ui::{Backend, RenderConfig},
+    type_aliases::{EventListener, HelpMessageFn},
+    ui::{Backend, Block, FinalRender, RenderConfig},
     CustomType,
 };
 
@@ -32,11 +39,13 @@ use crate::{
 /// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
 ///   - Formats `true` to "Yes" and `false` to "No", by default.
 /// - **Parser**: Custom parser for user inputs.
-///   - The default `bool` parser returns `true` if the input is either `"y"` or `"yes"`, in a case-insensitive comparison. Similarly, the parser returns `false` if the input is either `"n"` or `"no"`.
+///   - The default `bool` parser returns `true` if the input is either `"y"` or `"yes"`, in a case-insensitive comparison. Similarly, the parser returns `false` if the input is either `"n"` or `"no"`. With the `i18n` feature, the words for yes and no of the selected locale and their first letter are accepted as well.
 /// - **Default value formatter**: Function that formats how the default value is displayed to the user.
 ///   - By default, displays "y/n" with the default value capitalized, e.g. "y/N".
 /// - **Error message**: Error message to display when a value could not be parsed from the input.
 ///   - Set to "Invalid answer, try typing 'y' for yes or 'n' for no" by default.
+/// - **Instant**: Whether the answer is submitted as soon as the user types `y` or `n`, without pressing enter.
+///   - Disabled by default.
 ///
 /// # Example
 ///
@@ -70,6 +79,18 @@ pub struct Confirm<'a> {
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
+    /// Function computing the help message from the current state of the
+    /// prompt, replacing [`help_message`](Self::help_message) when set.
+    ///
+    /// See [`with_help_message_fn`](Self::with_help_message_fn) for more details.
+    pub help_message_fn: Option<HelpMessageFn<'a>>,
+
+    /// Content rendered above the prompt.
+    pub header: Option<Block>,
+
+    /// Content rendered below the prompt.
+    pub footer: Option<Block>,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: BoolFormatter<'a>,
 
@@ -82,6 +103,39 @@ pub struct Confirm<'a> {
     /// Error message displayed when a value could not be parsed from input.
     pub error_message: String,
 
+    /// Whether the answer is submitted as soon as the input can be parsed,
+    /// without waiting for the user to press enter.
+    ///
+    /// See [`with_instant`](Self::with_instant) for more details.
+    pub instant: bool,
+
+    /// What happens when the user presses Ctrl+C.
+    pub interrupt_behavior: InterruptBehavior,
+
+    /// Listener notified of the events of the prompt, such as key presses and
+    /// submissions.
+    ///
+    /// See [`with_event_listener`](Self::with_event_listener) for more details.
+    pub event_listener: Option<EventListener<'a>>,
+
+    /// Handle aborting the prompt from outside of it, if any.
+    ///
+    /// See [`with_handle`](Self::with_handle) for more details.
+    pub handle: Option<&'a PromptHandle>,
+
+    /// Time to wait for the user to start interacting with the prompt.
+    ///
+    /// See [`with_timeout`](Self::with_timeout) for more details.
+    pub timeout: Option<Duration>,
+
+    /// Whether to render the time left before the timeout expires.
+    pub timeout_countdown: bool,
+
+    /// Interval at which the prompt is re-rendered while no key is pressed.
+    ///
+    /// See [`with_refresh_interval`](Self::with_refresh_interval) for more details.
+    pub refresh_interval: Option<Duration>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -118,10 +172,20 @@ impl<'a> Confirm<'a> {
             default: None,
             placeholder: None,
             help_message: None,
+            help_message_fn: None,
+            header: None,
+            footer: None,
             formatter: Self::DEFAULT_FORMATTER,
             parser: Self::DEFAULT_PARSER,
             default_value_formatter: Self::DEFAULT_DEFAULT_VALUE_FORMATTER,
-            error_message: String::from(Self::DEFAULT_ERROR_MESSAGE),
+            error_message: String::from(translate(Self::DEFAULT_ERROR_MESSAGE)),
+            instant: false,
+            interrupt_behavior: get_interrupt_behavior(),
+            event_listener: get_event_listener(),
+            handle: None,
+            timeout: None,
+            timeout_countdown: false,
+            refresh_interval: None,
             render_config: get_configuration(),
         }
     }
@@ -144,6 +208,30 @@ impl<'a> Confirm<'a> {
         self
     }
 
+    /// Sets a function computing the help message from the current state of the
+    /// prompt, called whenever it is rendered. It takes precedence over the
+    /// static help message, and returning `None` hides the help line.
+    ///
+    /// See [`PromptState`](crate::PromptState) for the state given to the function.
+    pub fn with_help_message_fn(mut self, help_message_fn: HelpMessageFn<'a>) -> Self {
+        self.help_message_fn = Some(help_message_fn);
+        self
+    }
+
+    /// Sets the content rendered above the prompt, such as the current git
+    /// branch. It is redrawn and cleared along with the prompt, see [`Block`].
+    pub fn with_header<H: Into<Block>>(mut self, header: H) -> Self {
+        self.header = Some(header.into());
+        self
+    }
+
+    /// Sets the content rendered below the prompt. It is redrawn and cleared
+    /// along with the prompt, see [`Block`].
+    pub fn with_footer<F: Into<Block>>(mut self, footer: F) -> Self {
+        self.footer = Some(footer.into());
+        self
+    }
+
     /// Sets the formatter.
     pub fn with_formatter(mut self, formatter: BoolFormatter<'a>) -> Self {
         self.formatter = formatter;
@@ -168,6 +256,82 @@ impl<'a> Confirm<'a> {
         self
     }
 
+    /// Sets whether the answer is submitted as soon as the user types a key the
+    /// parser accepts, e.g. `y` or `n` with the default parser, without waiting
+    /// for the user to press enter. The answer is still rendered before the
+    /// prompt ends.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::Confirm;
+    ///
+    /// let proceed = Confirm::new("Install the dependencies?")
+    ///     .with_instant(true)
+    ///     .prompt()?;
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn with_instant(mut self, instant: bool) -> Self {
+        self.instant = instant;
+        self
+    }
+
+    /// Sets what happens when the user presses Ctrl+C, overriding the global
+    /// setting.
+    pub fn with_interrupt_behavior(mut self, interrupt_behavior: InterruptBehavior) -> Self {
+        self.interrupt_behavior = interrupt_behavior;
+        self
+    }
+
+    /// Sets a listener notified of the events of the prompt, such as renders,
+    /// key presses and submissions, overriding the global listener.
+    ///
+    /// See the [`events`](crate::events) module for more details.
+    pub fn with_event_listener(mut self, listener: EventListener<'a>) -> Self {
+        self.event_listener = Some(listener);
+        self
+    }
+
+    /// Sets a handle aborting the prompt from outside of it, e.g. from another
+    /// thread or a Ctrl+C handler, canceling it as if the user pressed ESC.
+    ///
+    /// See the [`handle`](crate::handle) module for more details.
+    pub fn with_handle(mut self, handle: &'a PromptHandle) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets a timeout for the user to start interacting with the prompt.
+    ///
+    /// If no key is pressed before it expires, the default value is submitted, or
+    /// [`InquireError::Timeout`] is returned when there is none.
+    /// Once a key is pressed, the prompt waits for the user indefinitely.
+    ///
+    /// Timeouts are supported by the `crossterm` and `termion` backends, the
+    /// `console` backend always waits for the first key press.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether to render the time left before the timeout expires below the prompt.
+    pub fn with_timeout_countdown(mut self, countdown: bool) -> Self {
+        self.timeout_countdown = countdown;
+        self
+    }
+
+    /// Sets an interval at which the prompt is re-rendered even when no key is
+    /// pressed, e.g. for content computed at render time, such as a dynamic
+    /// help message showing the current time, to stay up to date.
+    ///
+    /// Requires a terminal able to wait for keys with a timeout, such as the
+    /// crossterm or termion ones. Other terminals only re-render the prompt
+    /// after key presses.
+    pub fn with_refresh_interval(mut self, interval: Duration) -> Self {
+        self.refresh_interval = Some(interval);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -181,6 +345,25 @@ impl<'a> Confirm<'a> {
         self
     }
 
+    /// Sets how the prompt is rendered once answered, e.g. [`FinalRender::None`]
+    /// for the prompt to disappear. This is a shorthand for setting the
+    /// [`final_render`](RenderConfig::final_render) of the render config, so it
+    /// must be called after [`with_render_config`](Self::with_render_config).
+    pub fn with_final_render(mut self, final_render: FinalRender) -> Self {
+        self.render_config.final_render = final_render;
+        self
+    }
+
+    /// Sets whether the prompt is rendered in fullscreen, on the alternate
+    /// screen buffer, leaving only its final rendering in the original screen.
+    /// This is a shorthand for setting the
+    /// [`fullscreen`](RenderConfig::fullscreen) flag of the render config, so
+    /// it must be called after [`with_render_config`](Self::with_render_config).
+    pub fn with_fullscreen(mut self, fullscreen: bool) -> Self {
+        self.render_config.fullscreen = fullscreen;
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -201,11 +384,32 @@ impl<'a> Confirm<'a> {
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     pub fn prompt(self) -> InquireResult<bool> {
-        let terminal = get_default_terminal()?;
+        self.prompt_with_terminal(get_default_terminal()?)
+    }
+
+    /// Prompts the user through the given terminal instead of the default
+    /// terminal of the enabled backend feature, see [`Terminal`].
+    pub fn prompt_with_terminal<Term: Terminal>(self, terminal: Term) -> InquireResult<bool> {
         let mut backend = Backend::new(terminal, self.render_config)?;
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Same as [`prompt`](Self::prompt), also returning a [`PromptReport`]
+    /// describing how the user interacted with the prompt.
+    pub fn prompt_with_report(self) -> InquireResult<(bool, PromptReport)> {
+        self.prompt_with_report_and_terminal(get_default_terminal()?)
+    }
+
+    /// Same as [`prompt_with_terminal`](Self::prompt_with_terminal), also
+    /// returning a [`PromptReport`] describing how the user interacted with
+    /// the prompt.
+    pub fn prompt_with_report_and_terminal<Term: Terminal>(
+        self,
+        terminal: Term,
+    ) -> InquireResult<(bool, PromptReport)> {
+        CustomType::from(self).prompt_with_report_and_terminal(terminal)
+    }
+
     pub(crate) fn prompt_with_backend<T: Terminal>(
         self,
         backend: &mut Backend<'a, T>,
@@ -228,10 +432,22 @@ impl<'a> From<Confirm<'a>> for CustomType<'a, bool> {
             default_value_formatter: co.default_value_formatter,
             placeholder: co.placeholder,
             help_message: co.help_message,
+            help_message_fn: co.help_message_fn,
+            header: co.header,
+            footer: co.footer,
             formatter: co.formatter,
             parser: co.parser,
             validators: vec![],
+            background_validators: vec![],
             error_message: co.error_message,
+            expressions: false,
+            instant: co.instant,
+            interrupt_behavior: co.interrupt_behavior,
+            event_listener: co.event_listener,
+            handle: co.handle,
+            timeout: co.timeout,
+            timeout_countdown: co.timeout_countdown,
+            refresh_interval: co.refresh_interval,
             render_config: co.render_config,
         }
     }
@@ -1,10 +1,26 @@
-use crate::CustomType;
+use std::time::Duration;
+
+use crate::{config::InterruptBehavior, prompts::prompt::Timeout, CustomType};
 
 /// Configuration settings used in the execution of a CustomTypePrompt.
-pub struct CustomTypeConfig {}
+pub struct CustomTypeConfig {
+    /// What happens when the user presses Ctrl+C.
+    pub interrupt_behavior: InterruptBehavior,
+    /// Timeout applied until the first key press.
+    pub timeout: Option<Timeout>,
+    /// Interval at which the prompt is re-rendered without key presses.
+    pub refresh_interval: Option<Duration>,
+    /// Whether the answer is submitted as soon as the input can be parsed.
+    pub instant: bool,
+}
 
 impl<T> From<&CustomType<'_, T>> for CustomTypeConfig {
-    fn from(_value: &CustomType<'_, T>) -> Self {
-        Self {}
+    fn from(value: &CustomType<'_, T>) -> Self {
+        Self {
+            interrupt_behavior: value.interrupt_behavior,
+            timeout: Timeout::new(value.timeout, value.timeout_countdown),
+            refresh_interval: value.refresh_interval,
+            instant: value.instant,
+        }
     }
 }
@@ -1,22 +1,36 @@
 mod action;
 mod config;
 mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
 
 pub use action::*;
 
-use std::str::FromStr;
+use std::{net::IpAddr, str::FromStr, sync::Arc, time::Duration};
 
 use crate::{
-    config::get_configuration,
+    config::{
+        get_configuration, get_event_listener, get_interrupt_behavior, translate, InterruptBehavior,
+    },
     error::{InquireError, InquireResult},
     formatter::CustomTypeFormatter,
+    handle::PromptHandle,
+    network::Cidr,
     parser::CustomTypeParser,
     prompts::prompt::Prompt,
-    terminal::get_default_terminal,
-    ui::{Backend, CustomTypeBackend, RenderConfig},
-    validator::CustomTypeValidator,
+    report::PromptReport,
+    terminal::{get_default_terminal, Terminal},
+    type_aliases::{EventListener, HelpMessageFn},
+    ui::{Backend, Block, CustomTypeBackend, FinalRender, Redaction, RenderConfig},
+    validator::{BackgroundStringValidator, CustomTypeValidator},
 };
 
+#[cfg(feature = "rust_decimal")]
+use crate::currency::CurrencyLocale;
+#[cfg(feature = "units")]
+use crate::units;
+
 use self::prompt::CustomTypePrompt;
 
 /// Generic prompt suitable for when you need to parse the user input into a specific type, for example an `f64` or a `rust_decimal`, maybe even an `uuid`.
@@ -38,7 +52,7 @@ use self::prompt::CustomTypePrompt;
 /// If your type `T` does not satisfy these constraints, you can always manually instantiate the entire struct yourself like this:
 ///
 /// ```no_run
-/// use inquire::{CustomType, ui::RenderConfig};
+/// use inquire::{CustomType, InterruptBehavior, ui::RenderConfig};
 ///
 /// let amount_prompt: CustomType<f64> = CustomType {
 ///     message: "How much is your travel going to cost?",
@@ -46,13 +60,25 @@ use self::prompt::CustomTypePrompt;
 ///     default_value_formatter: &|i| format!("${:.2}", i),
 ///     default: None,
 ///     validators: vec![],
+///     background_validators: vec![],
 ///     placeholder: Some("123.45"),
 ///     error_message: "Please type a valid number.".into(),
+///     expressions: false,
+///     instant: false,
 ///     help_message: "Do not use currency and the number should use dots as the decimal separator.".into(),
+///     help_message_fn: None,
+///     header: None,
+///     footer: None,
 ///     parser: &|i| match i.parse::<f64>() {
 ///         Ok(val) => Ok(val),
 ///         Err(_) => Err(()),
 ///     },
+///     interrupt_behavior: InterruptBehavior::default(),
+///     event_listener: None,
+///     handle: None,
+///     timeout: None,
+///     timeout_countdown: false,
+///     refresh_interval: None,
 ///     render_config: RenderConfig::default(),
 /// };
 /// ```
@@ -89,6 +115,18 @@ pub struct CustomType<'a, T> {
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
+    /// Function computing the help message from the current state of the
+    /// prompt, replacing [`help_message`](Self::help_message) when set.
+    ///
+    /// See [`with_help_message_fn`](Self::with_help_message_fn) for more details.
+    pub help_message_fn: Option<HelpMessageFn<'a>>,
+
+    /// Content rendered above the prompt.
+    pub header: Option<Block>,
+
+    /// Content rendered below the prompt.
+    pub footer: Option<Block>,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: CustomTypeFormatter<'a, T>,
 
@@ -106,9 +144,54 @@ pub struct CustomType<'a, T> {
     /// The possible error is displayed to the user one line above the prompt.
     pub validators: Vec<Box<dyn CustomTypeValidator<T>>>,
 
+    /// Collection of validators to run on a worker thread over the input once
+    /// its parsed value passes all [`validators`](Self::validators).
+    ///
+    /// See [`with_background_validator`](Self::with_background_validator) for more details.
+    pub background_validators: Vec<Arc<dyn BackgroundStringValidator>>,
+
     /// Error message displayed when value could not be parsed from input.
     pub error_message: String,
 
+    /// Whether arithmetic expressions typed by the user are evaluated. Only has
+    /// an effect when the `expressions` feature is enabled.
+    ///
+    /// See [`with_expressions`](Self::with_expressions) for more details.
+    pub expressions: bool,
+
+    /// Whether the answer is submitted as soon as the input can be parsed,
+    /// without waiting for the user to press enter.
+    ///
+    /// See [`with_instant`](Self::with_instant) for more details.
+    pub instant: bool,
+
+    /// What happens when the user presses Ctrl+C.
+    pub interrupt_behavior: InterruptBehavior,
+
+    /// Listener notified of the events of the prompt, such as key presses and
+    /// submissions.
+    ///
+    /// See [`with_event_listener`](Self::with_event_listener) for more details.
+    pub event_listener: Option<EventListener<'a>>,
+
+    /// Handle aborting the prompt from outside of it, if any.
+    ///
+    /// See [`with_handle`](Self::with_handle) for more details.
+    pub handle: Option<&'a PromptHandle>,
+
+    /// Time to wait for the user to start interacting with the prompt.
+    ///
+    /// See [`with_timeout`](Self::with_timeout) for more details.
+    pub timeout: Option<Duration>,
+
+    /// Whether to render the time left before the timeout expires.
+    pub timeout_countdown: bool,
+
+    /// Interval at which the prompt is re-rendered while no key is pressed.
+    ///
+    /// See [`with_refresh_interval`](Self::with_refresh_interval) for more details.
+    pub refresh_interval: Option<Duration>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -137,11 +220,23 @@ where
             default: None,
             placeholder: None,
             help_message: None,
+            help_message_fn: None,
+            header: None,
+            footer: None,
             formatter: &|val| val.to_string(),
             default_value_formatter: &|val| val.to_string(),
             parser: &|a| a.parse::<T>().map_err(|_| ()),
             validators: Self::DEFAULT_VALIDATORS,
-            error_message: "Invalid input".into(),
+            background_validators: vec![],
+            error_message: translate("Invalid input").into(),
+            expressions: false,
+            instant: false,
+            interrupt_behavior: get_interrupt_behavior(),
+            event_listener: get_event_listener(),
+            handle: None,
+            timeout: None,
+            timeout_countdown: false,
+            refresh_interval: None,
             render_config: get_configuration(),
         }
     }
@@ -164,12 +259,45 @@ where
         self
     }
 
+    /// Sets a function computing the help message from the current state of the
+    /// prompt, called whenever it is rendered. It takes precedence over the
+    /// static help message, and returning `None` hides the help line.
+    ///
+    /// See [`PromptState`](crate::PromptState) for the state given to the function.
+    pub fn with_help_message_fn(mut self, help_message_fn: HelpMessageFn<'a>) -> Self {
+        self.help_message_fn = Some(help_message_fn);
+        self
+    }
+
+    /// Sets the content rendered above the prompt, such as the current git
+    /// branch. It is redrawn and cleared along with the prompt, see [`Block`].
+    pub fn with_header<H: Into<Block>>(mut self, header: H) -> Self {
+        self.header = Some(header.into());
+        self
+    }
+
+    /// Sets the content rendered below the prompt. It is redrawn and cleared
+    /// along with the prompt, see [`Block`].
+    pub fn with_footer<F: Into<Block>>(mut self, footer: F) -> Self {
+        self.footer = Some(footer.into());
+        self
+    }
+
     /// Sets the formatter
     pub fn with_formatter(mut self, formatter: CustomTypeFormatter<'a, T>) -> Self {
         self.formatter = formatter;
         self
     }
 
+    /// Sets both the formatter and the default value formatter, so that values
+    /// typed with units, such as `10 MiB`, are rendered the same way as the
+    /// answer and as the default value.
+    pub fn with_unit_formatter(mut self, formatter: CustomTypeFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self.default_value_formatter = formatter;
+        self
+    }
+
     /// Sets the formatter for default values.
     ///
     /// Useful for example when you want to format a default `true` to the string "Y/n", common in confirmation prompts,
@@ -216,18 +344,117 @@ where
     /// The possible error is displayed to the user one line above the prompt.
     pub fn with_validators(mut self, validators: &[Box<dyn CustomTypeValidator<T>>]) -> Self {
         for validator in validators {
-            #[allow(clippy::clone_double_ref)]
             self.validators.push(validator.clone());
         }
         self
     }
 
+    /// Adds a validator that runs on a worker thread over the text typed by the
+    /// user, meant for checks that take noticeable time, such as looking up the
+    /// value over the network.
+    ///
+    /// Background validators run in the order they are added, once the input is
+    /// parsed and its value passes all regular validators. They are skipped when
+    /// the default value is submitted. While they run, a spinner with the elapsed
+    /// time is rendered and the user can press ESC to cancel the validation and
+    /// go back to editing the input, as with
+    /// [`Text::with_background_validator`](crate::Text::with_background_validator).
+    pub fn with_background_validator<V>(mut self, validator: V) -> Self
+    where
+        V: BackgroundStringValidator + 'static,
+    {
+        self.background_validators.push(Arc::new(validator));
+        self
+    }
+
     /// Sets a custom error message displayed when a submission could not be parsed to a value.
     pub fn with_error_message(mut self, error_message: &'a str) -> Self {
         self.error_message = String::from(error_message);
         self
     }
 
+    /// Sets whether arithmetic expressions, such as `1024*8` or `30% of 200`, are
+    /// evaluated when the user submits them. See [`expression`](crate::expression)
+    /// for the supported syntax.
+    ///
+    /// When the input can not be parsed but evaluates to a value the parser
+    /// accepts, the input is replaced by the computed value instead of being
+    /// submitted, so that the user can confirm it by submitting again.
+    ///
+    /// Requires the `expressions` feature.
+    #[cfg(feature = "expressions")]
+    pub fn with_expressions(mut self, expressions: bool) -> Self {
+        self.expressions = expressions;
+        self
+    }
+
+    /// Sets whether the answer is submitted as soon as the input can be parsed
+    /// and passes the validators, without waiting for the user to press enter.
+    ///
+    /// Only suitable for types whose valid inputs are never the beginning of
+    /// other valid inputs, e.g. single characters: with an `u32` the user could
+    /// not type `12` since `1` would be submitted right away.
+    pub fn with_instant(mut self, instant: bool) -> Self {
+        self.instant = instant;
+        self
+    }
+
+    /// Sets what happens when the user presses Ctrl+C, overriding the global
+    /// setting.
+    pub fn with_interrupt_behavior(mut self, interrupt_behavior: InterruptBehavior) -> Self {
+        self.interrupt_behavior = interrupt_behavior;
+        self
+    }
+
+    /// Sets a listener notified of the events of the prompt, such as renders,
+    /// key presses and submissions, overriding the global listener.
+    ///
+    /// See the [`events`](crate::events) module for more details.
+    pub fn with_event_listener(mut self, listener: EventListener<'a>) -> Self {
+        self.event_listener = Some(listener);
+        self
+    }
+
+    /// Sets a handle aborting the prompt from outside of it, e.g. from another
+    /// thread or a Ctrl+C handler, canceling it as if the user pressed ESC.
+    ///
+    /// See the [`handle`](crate::handle) module for more details.
+    pub fn with_handle(mut self, handle: &'a PromptHandle) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets a timeout for the user to start interacting with the prompt.
+    ///
+    /// If no key is pressed before it expires, the default value is submitted, or
+    /// [`InquireError::Timeout`] is returned when there is none.
+    /// Once a key is pressed, the prompt waits for the user indefinitely.
+    ///
+    /// Timeouts are supported by the `crossterm` and `termion` backends, the
+    /// `console` backend always waits for the first key press.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether to render the time left before the timeout expires below the prompt.
+    pub fn with_timeout_countdown(mut self, countdown: bool) -> Self {
+        self.timeout_countdown = countdown;
+        self
+    }
+
+    /// Sets an interval at which the prompt is re-rendered even when no key is
+    /// pressed, e.g. for content computed at render time, such as a dynamic
+    /// help message showing the current time, to stay up to date.
+    ///
+    /// Requires a terminal able to wait for keys with a timeout, such as the
+    /// crossterm or termion ones. Other terminals only re-render the prompt
+    /// after key presses.
+    pub fn with_refresh_interval(mut self, interval: Duration) -> Self {
+        self.refresh_interval = Some(interval);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -241,6 +468,35 @@ where
         self
     }
 
+    /// Sets how the prompt is rendered once answered, e.g. [`FinalRender::None`]
+    /// for the prompt to disappear. This is a shorthand for setting the
+    /// [`final_render`](RenderConfig::final_render) of the render config, so it
+    /// must be called after [`with_render_config`](Self::with_render_config).
+    pub fn with_final_render(mut self, final_render: FinalRender) -> Self {
+        self.render_config.final_render = final_render;
+        self
+    }
+
+    /// Sets how the answer is redacted once the prompt is answered, e.g.
+    /// [`Redaction::Masked`] for secrets to stay out of the scrollback. This is
+    /// a shorthand for setting the [`answer_redaction`](RenderConfig::answer_redaction)
+    /// of the render config, so it must be called after
+    /// [`with_render_config`](Self::with_render_config).
+    pub fn with_answer_redaction(mut self, answer_redaction: Redaction) -> Self {
+        self.render_config.answer_redaction = answer_redaction;
+        self
+    }
+
+    /// Sets whether the prompt is rendered in fullscreen, on the alternate
+    /// screen buffer, leaving only its final rendering in the original screen.
+    /// This is a shorthand for setting the
+    /// [`fullscreen`](RenderConfig::fullscreen) flag of the render config, so
+    /// it must be called after [`with_render_config`](Self::with_render_config).
+    pub fn with_fullscreen(mut self, fullscreen: bool) -> Self {
+        self.render_config.fullscreen = fullscreen;
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -261,11 +517,33 @@ where
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     pub fn prompt(self) -> InquireResult<T> {
-        let terminal = get_default_terminal()?;
+        self.prompt_with_terminal(get_default_terminal()?)
+    }
+
+    /// Prompts the user through the given terminal instead of the default
+    /// terminal of the enabled backend feature, see [`Terminal`].
+    pub fn prompt_with_terminal<Term: Terminal>(self, terminal: Term) -> InquireResult<T> {
         let mut backend = Backend::new(terminal, self.render_config)?;
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Same as [`prompt`](Self::prompt), also returning a [`PromptReport`]
+    /// describing how the user interacted with the prompt.
+    pub fn prompt_with_report(self) -> InquireResult<(T, PromptReport)> {
+        self.prompt_with_report_and_terminal(get_default_terminal()?)
+    }
+
+    /// Same as [`prompt_with_terminal`](Self::prompt_with_terminal), also
+    /// returning a [`PromptReport`] describing how the user interacted with
+    /// the prompt.
+    pub fn prompt_with_report_and_terminal<Term: Terminal>(
+        self,
+        terminal: Term,
+    ) -> InquireResult<(T, PromptReport)> {
+        let mut backend = Backend::new(terminal, self.render_config)?;
+        CustomTypePrompt::from(self).prompt_with_report(&mut backend)
+    }
+
     pub(crate) fn prompt_with_backend<B: CustomTypeBackend>(
         self,
         backend: &mut B,
@@ -273,3 +551,155 @@ where
         CustomTypePrompt::from(self).prompt(backend)
     }
 }
+
+impl<'a> CustomType<'a, IpAddr> {
+    /// Creates a [CustomType] parsing IPv4 and IPv6 addresses.
+    pub fn new_ip(message: &'a str) -> Self {
+        Self::new(message).with_error_message("Please type a valid IP address")
+    }
+
+    /// Suggests the first address of the network interfaces of the machine,
+    /// see [`local_addresses`](crate::network::local_addresses), as default
+    /// value. The default is left untouched when there is none.
+    ///
+    /// Requires the `local-addresses` feature.
+    #[cfg(feature = "local-addresses")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "local-addresses")))]
+    pub fn with_local_address_default(mut self) -> Self {
+        if let Some(address) = crate::network::local_addresses().first() {
+            self.default = Some(*address);
+        }
+        self
+    }
+}
+
+impl<'a> CustomType<'a, Cidr> {
+    /// Creates a [CustomType] parsing IPv4 and IPv6 addresses written in CIDR
+    /// notation, such as `10.0.0.0/8`, into a [Cidr].
+    pub fn new_cidr(message: &'a str) -> Self {
+        Self::new(message)
+            .with_error_message("Please type an address in CIDR notation, such as 10.0.0.0/8")
+    }
+}
+
+#[cfg(any(feature = "units", feature = "rust_decimal"))]
+impl<'a, T> CustomType<'a, T>
+where
+    T: Clone,
+{
+    fn from_parts(
+        message: &'a str,
+        parser: CustomTypeParser<'a, T>,
+        formatter: CustomTypeFormatter<'a, T>,
+        error_message: &str,
+    ) -> Self {
+        Self {
+            message,
+            default: None,
+            placeholder: None,
+            help_message: None,
+            help_message_fn: None,
+            header: None,
+            footer: None,
+            formatter,
+            default_value_formatter: formatter,
+            parser,
+            validators: Self::DEFAULT_VALIDATORS,
+            background_validators: vec![],
+            error_message: error_message.into(),
+            expressions: false,
+            instant: false,
+            interrupt_behavior: get_interrupt_behavior(),
+            event_listener: get_event_listener(),
+            handle: None,
+            timeout: None,
+            timeout_countdown: false,
+            refresh_interval: None,
+            render_config: get_configuration(),
+        }
+    }
+}
+
+#[cfg(feature = "units")]
+#[cfg_attr(docsrs, doc(cfg(feature = "units")))]
+impl<'a> CustomType<'a, Duration> {
+    /// Creates a [CustomType] parsing durations such as `1h30m`, `90s` or
+    /// `1.5d`, see [`parse_duration`](crate::units::parse_duration).
+    ///
+    /// Requires the `units` feature.
+    pub fn duration(message: &'a str) -> Self {
+        Self::from_parts(
+            message,
+            &|input| units::parse_duration(input).ok_or(()),
+            &units::format_duration,
+            "Please type a duration, such as 1h30m",
+        )
+    }
+}
+
+#[cfg(feature = "units")]
+#[cfg_attr(docsrs, doc(cfg(feature = "units")))]
+impl<'a> CustomType<'a, u64> {
+    /// Creates a [CustomType] parsing byte sizes such as `512`, `1.5 GB` or
+    /// `10MiB` into a number of bytes, see [`parse_byte_size`](crate::units::parse_byte_size).
+    ///
+    /// Requires the `units` feature.
+    pub fn bytesize(message: &'a str) -> Self {
+        Self::from_parts(
+            message,
+            &|input| units::parse_byte_size(input).ok_or(()),
+            &units::format_byte_size,
+            "Please type a size, such as 10MiB",
+        )
+    }
+}
+
+#[cfg(feature = "units")]
+#[cfg_attr(docsrs, doc(cfg(feature = "units")))]
+impl<'a> CustomType<'a, f64> {
+    /// Creates a [CustomType] parsing percentages such as `50%` into a ratio,
+    /// `0.5`, see [`parse_percentage`](crate::units::parse_percentage).
+    ///
+    /// Requires the `units` feature.
+    pub fn percentage(message: &'a str) -> Self {
+        Self::from_parts(
+            message,
+            &|input| units::parse_percentage(input).ok_or(()),
+            &units::format_percentage,
+            "Please type a percentage, such as 12.5%",
+        )
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rust_decimal")))]
+impl<'a> CustomType<'a, rust_decimal::Decimal> {
+    /// Creates a [CustomType] parsing money amounts written with the conventions
+    /// of the given locale, such as `$1,234.56` or `1.234,56 €`, and formatting
+    /// the answer with its currency symbol. See [`CurrencyLocale::parse`] for
+    /// the accepted inputs.
+    ///
+    /// Requires the `rust_decimal` feature.
+    ///
+    /// ```no_run
+    /// use inquire::{currency::CurrencyLocale, CustomType};
+    ///
+    /// let amount = CustomType::currency("Price:", CurrencyLocale::DeDe).prompt();
+    /// ```
+    pub fn currency(message: &'a str, locale: CurrencyLocale) -> Self {
+        macro_rules! with_locale {
+            ($($locale:ident),*) => {
+                match locale {
+                    $(CurrencyLocale::$locale => Self::from_parts(
+                        message,
+                        &|input| CurrencyLocale::$locale.parse(input).ok_or(()),
+                        &|amount| CurrencyLocale::$locale.format(amount),
+                        "Please type a valid amount",
+                    ),)*
+                }
+            };
+        }
+
+        with_locale!(EnUs, EnGb, DeDe, FrFr, PtBr, JaJp)
+    }
+}
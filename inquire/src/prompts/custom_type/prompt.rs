@@ -1,11 +1,20 @@
+use std::{sync::Arc, time::Duration};
+
 use crate::{
+    config::InterruptBehavior,
     error::InquireResult,
     formatter::CustomTypeFormatter,
-    input::Input,
+    handle::PromptHandle,
+    input::{Input, InputActionResult},
     parser::CustomTypeParser,
-    prompts::prompt::{ActionResult, Prompt},
-    ui::CustomTypeBackend,
-    validator::{CustomTypeValidator, ErrorMessage, Validation},
+    prompts::{
+        background::PendingValidation,
+        prompt::{ActionResult, Prompt, Timeout},
+        state::{render_help_message, PromptState},
+    },
+    type_aliases::{EventListener, HelpMessageFn},
+    ui::{Block, CustomTypeBackend},
+    validator::{BackgroundStringValidator, CustomTypeValidator, ErrorMessage, Validation},
     CustomType, InquireError,
 };
 
@@ -14,15 +23,24 @@ use super::{action::CustomTypePromptAction, config::CustomTypeConfig};
 pub struct CustomTypePrompt<'a, T> {
     message: &'a str,
     config: CustomTypeConfig,
+    event_listener: Option<EventListener<'a>>,
+    handle: Option<&'a PromptHandle>,
     error: Option<ErrorMessage>,
     help_message: Option<&'a str>,
+    help_message_fn: Option<HelpMessageFn<'a>>,
+    header: Option<Block>,
+    footer: Option<Block>,
     default: Option<T>,
     input: Input,
     formatter: CustomTypeFormatter<'a, T>,
     default_value_formatter: CustomTypeFormatter<'a, T>,
     validators: Vec<Box<dyn CustomTypeValidator<T>>>,
+    background_validators: Vec<Arc<dyn BackgroundStringValidator>>,
+    pending_validation: Option<PendingValidation<T>>,
     parser: CustomTypeParser<'a, T>,
     error_message: String,
+    #[cfg(feature = "expressions")]
+    expressions: bool,
 }
 
 impl<'a, T> From<CustomType<'a, T>> for CustomTypePrompt<'a, T>
@@ -33,18 +51,27 @@ where
         Self {
             message: co.message,
             config: (&co).into(),
+            event_listener: co.event_listener,
+            handle: co.handle,
             error: None,
             default: co.default,
             help_message: co.help_message,
+            help_message_fn: co.help_message_fn,
+            header: co.header,
+            footer: co.footer,
             formatter: co.formatter,
             default_value_formatter: co.default_value_formatter,
             validators: co.validators,
+            background_validators: co.background_validators,
+            pending_validation: None,
             parser: co.parser,
             input: co
                 .placeholder
                 .map(|p| Input::new().with_placeholder(p))
                 .unwrap_or_else(Input::new),
             error_message: co.error_message,
+            #[cfg(feature = "expressions")]
+            expressions: co.expressions,
         }
     }
 }
@@ -65,6 +92,21 @@ where
         Ok(Validation::Valid)
     }
 
+    /// Value of the arithmetic expression in the input, if expressions are enabled
+    /// and the input is an expression whose value the parser accepts.
+    #[cfg(feature = "expressions")]
+    fn evaluate_expression(&self) -> Option<String> {
+        let content = self.input.content();
+        if !self.expressions || content.is_empty() || (self.parser)(content).is_ok() {
+            return None;
+        }
+
+        let value = crate::expression::evaluate(content).ok()?;
+        let value = crate::expression::format_value(value);
+
+        (self.parser)(&value).ok().map(|_| value)
+    }
+
     fn get_final_answer(&self) -> Result<T, String> {
         match &self.default {
             Some(val) if self.input.content().is_empty() => return Ok(val.clone()),
@@ -76,6 +118,15 @@ where
             Err(_) => Err(self.error_message.clone()),
         }
     }
+
+    fn state(&self) -> PromptState<'_> {
+        PromptState {
+            input: self.input.content(),
+            answer: self.get_final_answer().ok().map(self.formatter),
+            options: 0,
+            selected: 0,
+        }
+    }
 }
 
 impl<'a, B, T> Prompt<B, CustomTypeConfig, CustomTypePromptAction, T> for CustomTypePrompt<'a, T>
@@ -91,13 +142,103 @@ where
         &self.config
     }
 
+    fn interrupt_behavior(&self) -> InterruptBehavior {
+        self.config.interrupt_behavior
+    }
+
+    fn event_listener(&self) -> Option<EventListener<'_>> {
+        self.event_listener
+    }
+
+    fn prompt_handle(&self) -> Option<&PromptHandle> {
+        self.handle
+    }
+
+    fn header(&self) -> Option<&Block> {
+        self.header.as_ref()
+    }
+
+    fn footer(&self) -> Option<&Block> {
+        self.footer.as_ref()
+    }
+
+    fn timeout(&self) -> Option<Timeout> {
+        self.config.timeout
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        self.config.refresh_interval
+    }
+
+    fn submit_on_timeout(&mut self) -> InquireResult<Option<T>> {
+        match self.default {
+            Some(_) => Prompt::<B, _, _, _>::submit(self),
+            None => Ok(None),
+        }
+    }
+
     fn format_answer(&self, answer: &T) -> String {
         (self.formatter)((*answer).clone())
     }
 
+    fn is_default_answer(&self) -> bool {
+        self.default.is_some() && self.input.content().is_empty()
+    }
+
+    fn is_busy(&self) -> bool {
+        self.pending_validation.is_some()
+    }
+
+    fn poll_background(&mut self, timeout: Duration) -> InquireResult<Option<T>> {
+        let validation = match self
+            .pending_validation
+            .as_ref()
+            .and_then(|p| p.poll(timeout))
+        {
+            Some(validation) => validation,
+            None => return Ok(None),
+        };
+
+        let pending = self.pending_validation.take().unwrap();
+        match validation? {
+            Validation::Valid => Ok(Some(pending.answer().clone())),
+            Validation::Invalid(msg) => {
+                self.error = Some(msg);
+                Ok(None)
+            }
+        }
+    }
+
+    fn pre_cancel(&mut self) -> InquireResult<bool> {
+        // Canceling a background validation goes back to editing the answer.
+        Ok(self.pending_validation.take().is_none())
+    }
+
     fn submit(&mut self) -> InquireResult<Option<T>> {
+        #[cfg(feature = "expressions")]
+        if let Some(value) = self.evaluate_expression() {
+            let mut input = Input::new_with(value);
+            if let Some(placeholder) = self.input.placeholder() {
+                input = input.with_placeholder(placeholder);
+            }
+            self.input = input;
+            self.error = None;
+            return Ok(None);
+        }
+
         let answer = match self.get_final_answer() {
             Ok(answer) => match self.validate_current_answer(&answer)? {
+                Validation::Valid
+                    if !self.background_validators.is_empty() && !self.input.is_empty() =>
+                {
+                    self.error = None;
+                    self.pending_validation = Some(PendingValidation::start(
+                        &self.background_validators,
+                        self.input.content(),
+                        answer,
+                    ));
+                    None
+                }
                 Validation::Valid => Some(answer),
                 Validation::Invalid(msg) => {
                     self.error = Some(msg);
@@ -116,7 +257,16 @@ where
     fn handle(&mut self, action: CustomTypePromptAction) -> InquireResult<ActionResult> {
         let result = match action {
             CustomTypePromptAction::ValueInput(input_action) => {
-                self.input.handle(input_action).into()
+                let result = self.input.handle(input_action);
+
+                match result {
+                    InputActionResult::ContentChanged
+                        if self.config.instant && (self.parser)(self.input.content()).is_ok() =>
+                    {
+                        ActionResult::Submit
+                    }
+                    result => result.into(),
+                }
             }
         };
 
@@ -138,8 +288,11 @@ where
 
         backend.render_prompt(prompt, default_message.as_deref(), &self.input)?;
 
-        if let Some(message) = self.help_message {
-            backend.render_help_message(message)?;
+        match &self.pending_validation {
+            Some(pending) => backend.render_help_message(&pending.status_message())?,
+            None => render_help_message(backend, self.help_message, self.help_message_fn, || {
+                self.state()
+            })?,
         }
 
         Ok(())
@@ -0,0 +1,252 @@
+use crate::{
+    terminal::{crossterm::CrosstermTerminal, TestBackend},
+    ui::{Backend, Key, KeyModifiers, RenderConfig},
+    validator::{CancellationToken, Validation},
+    Confirm, CustomType,
+};
+use crossterm::event::{KeyCode, KeyEvent};
+
+fn keys(input: &str) -> Vec<KeyEvent> {
+    input
+        .chars()
+        .map(|c| match c {
+            '\n' => KeyCode::Enter,
+            c => KeyCode::Char(c),
+        })
+        .map(KeyEvent::from)
+        .collect()
+}
+
+#[test]
+fn input_is_parsed_on_submit() {
+    let read = keys("42\n");
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = CustomType::<u32>::new("Question")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(42, ans);
+}
+
+#[test]
+fn background_validation_rejects_answer() {
+    let read: Vec<KeyEvent> = keys("8080\n")
+        .into_iter()
+        .chain(vec![KeyEvent::from(KeyCode::Backspace); 2])
+        .chain(keys("81\n"))
+        .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+    let ans = CustomType::<u16>::new("Port:")
+        .with_background_validator(|input: &str, _: &CancellationToken| match input {
+            "8080" => Ok(Validation::Invalid("Port already in use".into())),
+            _ => Ok(Validation::Valid),
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    assert_eq!(8081, ans);
+    drop(backend);
+
+    let output = String::from_utf8(write).unwrap();
+    assert!(output.contains("Validating..."));
+    assert!(output.contains("Port already in use"));
+}
+
+#[test]
+#[cfg(feature = "expressions")]
+fn expressions_are_echoed_before_submitting() {
+    let read = keys("1024*8\n\n");
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+    let ans = CustomType::<u32>::new("Question")
+        .with_expressions(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    assert_eq!(8192, ans);
+    drop(backend);
+
+    let output = String::from_utf8(write).unwrap();
+    assert!(output.contains("? Question 8192"));
+}
+
+#[test]
+#[cfg(feature = "expressions")]
+fn expressions_with_unparseable_values_are_rejected() {
+    // 2.5 is not a valid u32, so the expression is not echoed and the error
+    // message is displayed, then the input is fixed
+    let read: Vec<KeyEvent> = keys("5/2\n")
+        .into_iter()
+        .chain(vec![KeyEvent::from(KeyCode::Backspace); 3])
+        .chain(keys("5\n"))
+        .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+    let ans = CustomType::<u32>::new("Question")
+        .with_expressions(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    assert_eq!(5, ans);
+    drop(backend);
+
+    let output = String::from_utf8(write).unwrap();
+    assert!(output.contains("Invalid input"));
+}
+
+#[test]
+fn placeholder_is_dimmed_and_cleared_on_first_keystroke() {
+    let mut terminal = TestBackend::new(vec![]).with_text("7\n");
+
+    let ans = CustomType::<u32>::new("Age:")
+        .with_placeholder("in years")
+        .with_default(18)
+        .with_render_config(RenderConfig::default_colored())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+    assert_eq!(7, ans);
+
+    let frames = terminal.styled_frames();
+    assert_eq!(
+        "<fg=LightGreen>?</> Age: (18) <fg=DarkGrey>in years</> \n",
+        frames[0].annotated()
+    );
+    assert_eq!("? Age: (18) 7 \n", frames[1].text());
+    assert_eq!("> Age: 7\n", terminal.last_frame().unwrap());
+}
+
+#[test]
+fn instant_confirm_submits_on_key_press() {
+    let mut terminal = TestBackend::new(vec![]).with_text("x");
+    terminal.push_key(Key::Backspace(KeyModifiers::NONE));
+    terminal.push_text("n");
+    let ans = Confirm::new("Continue?")
+        .with_instant(true)
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert!(!ans);
+    assert_eq!(0, terminal.remaining_keys().count());
+    assert_eq!(
+        vec![
+            "? Continue?  \n",
+            "? Continue? x \n",
+            "? Continue?  \n",
+            "? Continue? No\n",
+        ],
+        terminal.frames()
+    );
+}
+
+#[test]
+fn instant_answers_are_validated() {
+    let mut terminal = TestBackend::new(vec![]).with_text("0");
+    terminal.push_key(Key::Backspace(KeyModifiers::NONE));
+    terminal.push_text("7");
+
+    let ans = CustomType::<u32>::new("Question")
+        .with_instant(true)
+        .with_validator(|val: &u32| match *val > 0 {
+            true => Ok(Validation::Valid),
+            false => Ok(Validation::Invalid("Zero is not allowed".into())),
+        })
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(7, ans);
+    assert!(terminal.frames()[1].contains("Zero is not allowed"));
+}
+
+#[test]
+#[cfg(feature = "units")]
+fn bytesize_parses_and_formats_units() {
+    let mut terminal = TestBackend::new(vec![]).with_text("1.5 gib\n");
+
+    let ans = CustomType::bytesize("Size:")
+        .with_default(1024)
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(1_610_612_736, ans);
+    assert!(terminal.frames()[0].starts_with("? Size: (1 KiB) "));
+    assert_eq!("? Size: 1.5 GiB\n", terminal.last_frame().unwrap());
+}
+
+#[test]
+#[cfg(feature = "rust_decimal")]
+fn currency_parses_amounts_of_the_locale() {
+    use crate::currency::CurrencyLocale;
+
+    let mut terminal = TestBackend::new(vec![]).with_text("1.234,5\n");
+
+    let ans = CustomType::currency("Price:", CurrencyLocale::DeDe)
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(rust_decimal::Decimal::new(12345, 1), ans);
+    assert_eq!("? Price: 1.234,50 €\n", terminal.last_frame().unwrap());
+}
+
+#[test]
+fn new_cidr_rejects_invalid_networks() {
+    let mut terminal = TestBackend::new(vec![]).with_text("10.0.0.0/33\n");
+    for _ in 0..2 {
+        terminal.push_key(Key::Backspace(KeyModifiers::NONE));
+    }
+    terminal.push_text("8\n");
+
+    let ans = CustomType::new_cidr("Subnet:")
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("10.0.0.0/8".parse::<crate::network::Cidr>().unwrap(), ans);
+    assert!(terminal.frames().iter().any(|frame| frame.starts_with(
+        "# Please type an address in CIDR notation, such as 10.0.0.0/8\n? Subnet: 10.0.0.0/3 "
+    )));
+}
+
+#[test]
+fn help_message_fn_previews_the_parsed_value() {
+    let mut terminal = TestBackend::new(vec![]).with_text("2x\n");
+    terminal.push_key(Key::Backspace(KeyModifiers::NONE));
+    terminal.push_text("\n");
+
+    let ans = CustomType::<u32>::new("Replicas:")
+        .with_formatter(&|value| format!("{value} replicas"))
+        .with_help_message_fn(&|state| match &state.answer {
+            Some(answer) => Some(format!("Deploying {answer}")),
+            None => Some(format!("{:?} is not a number", state.input)),
+        })
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(2, ans);
+    assert_eq!(
+        "? Replicas: 2 \n[Deploying 2 replicas]\n",
+        terminal.frames()[1]
+    );
+    assert_eq!(
+        "? Replicas: 2x \n[\"2x\" is not a number]\n",
+        terminal.frames()[2]
+    );
+}
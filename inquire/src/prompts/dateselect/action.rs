@@ -1,6 +1,6 @@
 use crate::{
     ui::{Key, KeyModifiers},
-    InnerAction,
+    InnerAction, InputAction,
 };
 
 use super::config::DateSelectConfig;
@@ -25,6 +25,8 @@ pub enum DateSelectPromptAction {
     GoToPrevYear,
     /// Move day cursor to the next year.
     GoToNextYear,
+    /// Action on the input of the date to jump to, e.g. typing `1985-03-12`.
+    JumpInput(InputAction),
 }
 
 impl InnerAction<DateSelectConfig> for DateSelectPromptAction {
@@ -52,6 +54,14 @@ impl InnerAction<DateSelectConfig> for DateSelectPromptAction {
             Key::Right(KeyModifiers::CONTROL) => Self::GoToNextMonth,
             Key::Up(KeyModifiers::CONTROL) => Self::GoToPrevYear,
             Key::Down(KeyModifiers::CONTROL) => Self::GoToNextYear,
+            Key::PageUp(KeyModifiers::NONE) => Self::GoToPrevMonth,
+            Key::PageDown(KeyModifiers::NONE) => Self::GoToNextMonth,
+            Key::PageUp(KeyModifiers::CONTROL) => Self::GoToPrevYear,
+            Key::PageDown(KeyModifiers::CONTROL) => Self::GoToNextYear,
+            Key::Char(c, KeyModifiers::NONE) if c.is_ascii_digit() || c == '-' => {
+                Self::JumpInput(InputAction::Write(c))
+            }
+            key @ Key::Backspace(_) => Self::JumpInput(InputAction::from_key(key, &())?),
             _ => return None,
         };
 
@@ -1,6 +1,8 @@
+use std::time::Duration;
+
 use chrono::NaiveDate;
 
-use crate::DateSelect;
+use crate::{config::InterruptBehavior, prompts::prompt::Timeout, DateSelect};
 
 /// Configuration settings used in the execution of a DateSelectPrompt.
 #[derive(Copy, Clone, Debug)]
@@ -14,8 +16,23 @@ pub struct DateSelectConfig {
     /// Max date allowed to be selected.
     pub max_date: Option<NaiveDate>,
 
+    /// Function deciding whether a date can not be selected.
+    pub disabled_dates: Option<fn(NaiveDate) -> bool>,
+
     /// Weekday to start the week on.
     pub week_start: chrono::Weekday,
+
+    /// What happens when the user presses Ctrl+C.
+    pub interrupt_behavior: InterruptBehavior,
+
+    /// Timeout applied until the first key press.
+    pub timeout: Option<Timeout>,
+    /// Interval at which the prompt is re-rendered without key presses.
+    pub refresh_interval: Option<Duration>,
+
+    /// Whether the whole week of the selected date is selected, see
+    /// [`DateSelect::prompt_week`].
+    pub week_selection: bool,
 }
 
 impl From<&DateSelect<'_>> for DateSelectConfig {
@@ -24,7 +41,12 @@ impl From<&DateSelect<'_>> for DateSelectConfig {
             vim_mode: value.vim_mode,
             min_date: value.min_date,
             max_date: value.max_date,
+            disabled_dates: value.disabled_dates,
             week_start: value.week_start,
+            interrupt_behavior: value.interrupt_behavior,
+            timeout: Timeout::new(value.timeout, value.timeout_countdown),
+            refresh_interval: value.refresh_interval,
+            week_selection: false,
         }
     }
 }
@@ -7,21 +7,37 @@ mod test;
 
 pub use action::*;
 
-use chrono::NaiveDate;
+use std::time::Duration;
+
+use chrono::{Datelike, IsoWeek, NaiveDate};
 
 use crate::{
-    config::get_configuration,
-    date_utils::get_current_date,
+    config::{
+        get_configuration, get_event_listener, get_global_options, get_interrupt_behavior,
+        InterruptBehavior,
+    },
+    date_utils::{get_current_date, get_row_monday},
     error::{InquireError, InquireResult},
     formatter::{self, DateFormatter},
+    handle::PromptHandle,
     prompts::prompt::Prompt,
+    report::PromptReport,
     terminal::{get_default_terminal, Terminal},
-    ui::{Backend, RenderConfig},
+    type_aliases::{EventListener, HelpMessageFn},
+    ui::{Backend, Block, FinalRender, RenderConfig},
     validator::DateValidator,
 };
 
 use self::prompt::DateSelectPrompt;
 
+#[cfg(feature = "chrono-tz")]
+use chrono::{DateTime, TimeZone};
+#[cfg(feature = "chrono-tz")]
+use chrono_tz::{Tz, TZ_VARIANTS};
+
+#[cfg(feature = "chrono-tz")]
+use crate::Select;
+
 /// Prompt that allows user to select a date (time not supported) from an interactive calendar. Available via the `date` feature.
 ///
 /// By default, the initial selected date is the current date. The user can navigate through the calendar by pressing the keyboard arrows. If the user also presses the control key along with the arrows, the user will be able to "fast-forward" to previous or next months or years.
@@ -31,9 +47,14 @@ use self::prompt::DateSelectPrompt;
 /// - Analogously, right arrow does the same, but moving to the next day or month.
 /// - Up arrow moves to the day above to the one selected, basically a week before the selected date. When pressed with `ctrl`, it moves to the previous year.
 /// - Analogously, the down arrow moves to a week later or a year later.
+/// - Page up and page down move to the previous or next month, and to the previous or next year when pressed with `ctrl`.
+/// - Typing a date such as `1985-03-12` moves straight to it, the dashes being inserted automatically. Pressing escape clears a partially typed date.
 ///
 /// Finally, the user selects a date by pressing the space or enter keys.
 ///
+/// Time-tracking flows can select a whole week instead with [`prompt_week`](DateSelect::prompt_week),
+/// which highlights the row of the selected date and returns its ISO week.
+///
 /// `DateSelect` prompts provide several options of configuration:
 ///
 /// - **Prompt message**: Required when creating the prompt.
@@ -44,6 +65,7 @@ use self::prompt::DateSelectPrompt;
 /// - **Validators**: Custom validators to the user's selected date, displaying an error message if the date does not pass the requirements.
 /// - **Week start**: Which day of the week should be displayed in the first column of the calendar, Sunday by default.
 /// - **Min and max date**: Inclusive boundaries of allowed dates in the interactive calendar. If any boundary is set, the user will not be able to move past them, consequently not being able to select any dates out of the allowed range.
+/// - **Disabled dates**: Function deciding whether a specific date, such as a weekend or a holiday, can not be selected. Disabled dates are greyed out and skipped by the cursor.
 ///
 /// # Example
 ///
@@ -81,9 +103,26 @@ pub struct DateSelect<'a> {
     /// Max date allowed to be selected.
     pub max_date: Option<NaiveDate>,
 
+    /// Function deciding whether a date can not be selected.
+    ///
+    /// See [`with_disabled_dates`](Self::with_disabled_dates) for more details.
+    pub disabled_dates: Option<fn(NaiveDate) -> bool>,
+
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
+    /// Function computing the help message from the current state of the
+    /// prompt, replacing [`help_message`](Self::help_message) when set.
+    ///
+    /// See [`with_help_message_fn`](Self::with_help_message_fn) for more details.
+    pub help_message_fn: Option<HelpMessageFn<'a>>,
+
+    /// Content rendered above the prompt.
+    pub header: Option<Block>,
+
+    /// Content rendered below the prompt.
+    pub footer: Option<Block>,
+
     /// Whether vim mode is enabled. When enabled, the user can
     /// navigate through the options using hjkl.
     pub vim_mode: bool,
@@ -99,6 +138,33 @@ pub struct DateSelect<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub validators: Vec<Box<dyn DateValidator>>,
 
+    /// What happens when the user presses Ctrl+C.
+    pub interrupt_behavior: InterruptBehavior,
+
+    /// Listener notified of the events of the prompt, such as key presses and
+    /// submissions.
+    ///
+    /// See [`with_event_listener`](Self::with_event_listener) for more details.
+    pub event_listener: Option<EventListener<'a>>,
+
+    /// Handle aborting the prompt from outside of it, if any.
+    ///
+    /// See [`with_handle`](Self::with_handle) for more details.
+    pub handle: Option<&'a PromptHandle>,
+
+    /// Time to wait for the user to start interacting with the prompt.
+    ///
+    /// See [`with_timeout`](Self::with_timeout) for more details.
+    pub timeout: Option<Duration>,
+
+    /// Whether to render the time left before the timeout expires.
+    pub timeout_countdown: bool,
+
+    /// Interval at which the prompt is re-rendered while no key is pressed.
+    ///
+    /// See [`with_refresh_interval`](Self::with_refresh_interval) for more details.
+    pub refresh_interval: Option<Duration>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -127,6 +193,9 @@ impl<'a> DateSelect<'a> {
     /// Default week start.
     pub const DEFAULT_WEEK_START: chrono::Weekday = chrono::Weekday::Sun;
 
+    /// Message of the time zone prompt of [`prompt_zoned`](Self::prompt_zoned).
+    pub const TIMEZONE_MESSAGE: &'static str = "Time zone:";
+
     /// Default min date.
     pub const DEFAULT_MIN_DATE: Option<NaiveDate> = None;
 
@@ -135,16 +204,28 @@ impl<'a> DateSelect<'a> {
 
     /// Creates a [DateSelect] with the provided message, along with default configuration values.
     pub fn new(message: &'a str) -> Self {
+        let global = get_global_options();
+
         Self {
             message,
             starting_date: get_current_date(),
             min_date: Self::DEFAULT_MIN_DATE,
             max_date: Self::DEFAULT_MAX_DATE,
-            help_message: Self::DEFAULT_HELP_MESSAGE,
+            disabled_dates: None,
+            help_message: global.help_message(Self::DEFAULT_HELP_MESSAGE),
+            help_message_fn: None,
+            header: None,
+            footer: None,
             vim_mode: Self::DEFAULT_VIM_MODE,
             formatter: Self::DEFAULT_FORMATTER,
             validators: Self::DEFAULT_VALIDATORS,
             week_start: Self::DEFAULT_WEEK_START,
+            interrupt_behavior: get_interrupt_behavior(),
+            event_listener: get_event_listener(),
+            handle: None,
+            timeout: None,
+            timeout_countdown: false,
+            refresh_interval: None,
             render_config: get_configuration(),
         }
     }
@@ -155,6 +236,30 @@ impl<'a> DateSelect<'a> {
         self
     }
 
+    /// Sets a function computing the help message from the current state of the
+    /// prompt, called whenever it is rendered. It takes precedence over the
+    /// static help message, and returning `None` hides the help line.
+    ///
+    /// See [`PromptState`](crate::PromptState) for the state given to the function.
+    pub fn with_help_message_fn(mut self, help_message_fn: HelpMessageFn<'a>) -> Self {
+        self.help_message_fn = Some(help_message_fn);
+        self
+    }
+
+    /// Sets the content rendered above the prompt, such as the current git
+    /// branch. It is redrawn and cleared along with the prompt, see [`Block`].
+    pub fn with_header<H: Into<Block>>(mut self, header: H) -> Self {
+        self.header = Some(header.into());
+        self
+    }
+
+    /// Sets the content rendered below the prompt. It is redrawn and cleared
+    /// along with the prompt, see [`Block`].
+    pub fn with_footer<F: Into<Block>>(mut self, footer: F) -> Self {
+        self.footer = Some(footer.into());
+        self
+    }
+
     /// Removes the set help message.
     pub fn without_help_message(mut self) -> Self {
         self.help_message = None;
@@ -184,6 +289,27 @@ impl<'a> DateSelect<'a> {
         self
     }
 
+    /// Sets a function deciding whether a date can not be selected, such as
+    /// weekends, holidays or already booked days.
+    ///
+    /// Disabled dates are rendered like the dates out of the min/max range,
+    /// and the cursor skips them, moving on to the closest available date in
+    /// the direction it was moving. A disabled starting date is replaced by
+    /// the next available date.
+    ///
+    /// ```no_run
+    /// use chrono::{Datelike, NaiveDate, Weekday};
+    /// use inquire::DateSelect;
+    ///
+    /// let date = DateSelect::new("Delivery date:")
+    ///     .with_disabled_dates(|date| matches!(date.weekday(), Weekday::Sat | Weekday::Sun))
+    ///     .prompt();
+    /// ```
+    pub fn with_disabled_dates(mut self, disabled_dates: fn(NaiveDate) -> bool) -> Self {
+        self.disabled_dates = Some(disabled_dates);
+        self
+    }
+
     /// Sets the starting date. Equivalent to [DateSelect::with_default](DateSelect::with_default).
     pub fn with_starting_date(mut self, starting_date: NaiveDate) -> Self {
         self.starting_date = starting_date;
@@ -238,6 +364,62 @@ impl<'a> DateSelect<'a> {
         self
     }
 
+    /// Sets what happens when the user presses Ctrl+C, overriding the global
+    /// setting.
+    pub fn with_interrupt_behavior(mut self, interrupt_behavior: InterruptBehavior) -> Self {
+        self.interrupt_behavior = interrupt_behavior;
+        self
+    }
+
+    /// Sets a listener notified of the events of the prompt, such as renders,
+    /// key presses and submissions, overriding the global listener.
+    ///
+    /// See the [`events`](crate::events) module for more details.
+    pub fn with_event_listener(mut self, listener: EventListener<'a>) -> Self {
+        self.event_listener = Some(listener);
+        self
+    }
+
+    /// Sets a handle aborting the prompt from outside of it, e.g. from another
+    /// thread or a Ctrl+C handler, canceling it as if the user pressed ESC.
+    ///
+    /// See the [`handle`](crate::handle) module for more details.
+    pub fn with_handle(mut self, handle: &'a PromptHandle) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets a timeout for the user to start interacting with the prompt.
+    ///
+    /// If no key is pressed before it expires, the highlighted date is submitted, or
+    /// [`InquireError::Timeout`] is returned when it does not pass validation.
+    /// Once a key is pressed, the prompt waits for the user indefinitely.
+    ///
+    /// Timeouts are supported by the `crossterm` and `termion` backends, the
+    /// `console` backend always waits for the first key press.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether to render the time left before the timeout expires below the prompt.
+    pub fn with_timeout_countdown(mut self, countdown: bool) -> Self {
+        self.timeout_countdown = countdown;
+        self
+    }
+
+    /// Sets an interval at which the prompt is re-rendered even when no key is
+    /// pressed, e.g. for content computed at render time, such as a dynamic
+    /// help message showing the current time, to stay up to date.
+    ///
+    /// Requires a terminal able to wait for keys with a timeout, such as the
+    /// crossterm or termion ones. Other terminals only re-render the prompt
+    /// after key presses.
+    pub fn with_refresh_interval(mut self, interval: Duration) -> Self {
+        self.refresh_interval = Some(interval);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -251,6 +433,25 @@ impl<'a> DateSelect<'a> {
         self
     }
 
+    /// Sets how the prompt is rendered once answered, e.g. [`FinalRender::None`]
+    /// for the prompt to disappear. This is a shorthand for setting the
+    /// [`final_render`](RenderConfig::final_render) of the render config, so it
+    /// must be called after [`with_render_config`](Self::with_render_config).
+    pub fn with_final_render(mut self, final_render: FinalRender) -> Self {
+        self.render_config.final_render = final_render;
+        self
+    }
+
+    /// Sets whether the prompt is rendered in fullscreen, on the alternate
+    /// screen buffer, leaving only its final rendering in the original screen.
+    /// This is a shorthand for setting the
+    /// [`fullscreen`](RenderConfig::fullscreen) flag of the render config, so
+    /// it must be called after [`with_render_config`](Self::with_render_config).
+    pub fn with_fullscreen(mut self, fullscreen: bool) -> Self {
+        self.render_config.fullscreen = fullscreen;
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -271,15 +472,164 @@ impl<'a> DateSelect<'a> {
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     pub fn prompt(self) -> InquireResult<NaiveDate> {
-        let terminal = get_default_terminal()?;
+        self.prompt_with_terminal(get_default_terminal()?)
+    }
+
+    /// Prompts the user through the given terminal instead of the default
+    /// terminal of the enabled backend feature, see [`Terminal`].
+    pub fn prompt_with_terminal<Term: Terminal>(self, terminal: Term) -> InquireResult<NaiveDate> {
         let mut backend = Backend::new(terminal, self.render_config)?;
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Same as [`prompt`](Self::prompt), also returning a [`PromptReport`]
+    /// describing how the user interacted with the prompt.
+    pub fn prompt_with_report(self) -> InquireResult<(NaiveDate, PromptReport)> {
+        self.prompt_with_report_and_terminal(get_default_terminal()?)
+    }
+
+    /// Same as [`prompt_with_terminal`](Self::prompt_with_terminal), also
+    /// returning a [`PromptReport`] describing how the user interacted with
+    /// the prompt.
+    pub fn prompt_with_report_and_terminal<Term: Terminal>(
+        self,
+        terminal: Term,
+    ) -> InquireResult<(NaiveDate, PromptReport)> {
+        let mut backend = Backend::new(terminal, self.render_config)?;
+        DateSelectPrompt::new(self)?.prompt_with_report(&mut backend)
+    }
+
     pub(crate) fn prompt_with_backend<T: Terminal>(
         self,
         backend: &mut Backend<'a, T>,
     ) -> InquireResult<NaiveDate> {
         DateSelectPrompt::new(self)?.prompt(backend)
     }
+
+    /// Same as [`prompt_skippable`](Self::prompt_skippable), selecting a whole
+    /// week, see [`prompt_week`](Self::prompt_week).
+    pub fn prompt_week_skippable(self) -> InquireResult<Option<IsoWeek>> {
+        match self.prompt_week() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Prompts the user for a whole week instead of a single day, returning
+    /// its ISO week, e.g. `2021-W31`.
+    ///
+    /// The user navigates through the calendar as usual, with the row of the
+    /// selected date highlighted. Its ISO week is the one of the monday of the
+    /// row, which matches the ISO week of every date in the row when the week
+    /// starts on monday. Validators receive the selected date, and the answer
+    /// is rendered as `2021-W31` in place of the output of the formatter.
+    ///
+    /// Week numbers can be displayed on the left of the calendar with
+    /// [`CalendarRenderConfig::with_week_numbers`](crate::ui::calendar::CalendarRenderConfig::with_week_numbers).
+    pub fn prompt_week(self) -> InquireResult<IsoWeek> {
+        self.prompt_week_with_terminal(get_default_terminal()?)
+    }
+
+    /// Same as [`prompt_week`](Self::prompt_week), through the given terminal,
+    /// see [`Terminal`].
+    pub fn prompt_week_with_terminal<Term: Terminal>(
+        self,
+        terminal: Term,
+    ) -> InquireResult<IsoWeek> {
+        let mut backend = Backend::new(terminal, self.render_config)?;
+        self.prompt_week_with_backend(&mut backend)
+    }
+
+    /// Same as [`prompt_skippable`](Self::prompt_skippable), also asking for
+    /// a time zone, see [`prompt_zoned`](Self::prompt_zoned).
+    #[cfg(feature = "chrono-tz")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono-tz")))]
+    pub fn prompt_zoned_skippable(self) -> InquireResult<Option<DateTime<Tz>>> {
+        match self.prompt_zoned() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Prompts the user for a date and then for the time zone it is in,
+    /// returning the start of the selected day in that time zone.
+    ///
+    /// The time zone is picked from a searchable list of the IANA time zones,
+    /// such as `Europe/Paris`, initially highlighting the one set in the `TZ`
+    /// environment variable, or UTC. When the day does not start at midnight
+    /// because of a daylight saving time transition, the first valid time of
+    /// the day is returned.
+    ///
+    /// Requires the `chrono-tz` feature.
+    ///
+    /// ```no_run
+    /// use inquire::DateSelect;
+    ///
+    /// let meeting = DateSelect::new("Meeting date:").prompt_zoned();
+    /// ```
+    #[cfg(feature = "chrono-tz")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono-tz")))]
+    pub fn prompt_zoned(self) -> InquireResult<DateTime<Tz>> {
+        self.prompt_zoned_with_terminal(get_default_terminal()?)
+    }
+
+    /// Same as [`prompt_zoned`](Self::prompt_zoned), through the given
+    /// terminal, see [`Terminal`].
+    #[cfg(feature = "chrono-tz")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono-tz")))]
+    pub fn prompt_zoned_with_terminal<Term: Terminal>(
+        self,
+        mut terminal: Term,
+    ) -> InquireResult<DateTime<Tz>> {
+        let render_config = self.render_config;
+        let interrupt_behavior = self.interrupt_behavior;
+
+        let date = self.prompt_with_terminal(&mut terminal)?;
+
+        let default_timezone = std::env::var("TZ")
+            .ok()
+            .and_then(|tz| tz.parse::<Tz>().ok())
+            .unwrap_or(Tz::UTC);
+        let starting_cursor = TZ_VARIANTS
+            .iter()
+            .position(|tz| *tz == default_timezone)
+            .unwrap_or_default();
+
+        let timezone = Select::new(Self::TIMEZONE_MESSAGE, TZ_VARIANTS.to_vec())
+            .with_starting_cursor(starting_cursor)
+            .with_interrupt_behavior(interrupt_behavior)
+            .with_render_config(render_config)
+            .prompt_with_terminal(&mut terminal)?;
+
+        Ok(start_of_day(date, timezone))
+    }
+
+    pub(crate) fn prompt_week_with_backend<T: Terminal>(
+        self,
+        backend: &mut Backend<'a, T>,
+    ) -> InquireResult<IsoWeek> {
+        let week_start = self.week_start;
+        let date = DateSelectPrompt::new(self)?
+            .with_week_selection()
+            .prompt(backend)?;
+
+        Ok(get_row_monday(date, week_start).iso_week())
+    }
+}
+
+/// First valid time of the day in the time zone, midnight unless a daylight
+/// saving time transition skips it.
+#[cfg(feature = "chrono-tz")]
+fn start_of_day(date: NaiveDate, timezone: Tz) -> DateTime<Tz> {
+    let midnight = date.and_time(chrono::NaiveTime::MIN);
+
+    (0..=24)
+        .filter_map(|hours| {
+            let time = midnight + chrono::Duration::hours(hours);
+            timezone.from_local_datetime(&time).earliest()
+        })
+        .next()
+        .unwrap_or_else(|| timezone.from_utc_datetime(&midnight))
 }
@@ -6,24 +6,44 @@ use std::{
 use chrono::{Datelike, Duration, NaiveDate};
 
 use crate::{
-    date_utils::{get_current_date, get_month},
+    config::{translate, translate_template, InterruptBehavior},
+    date_utils::{get_current_date, get_month, get_row_monday},
     error::InquireResult,
     formatter::DateFormatter,
-    prompts::prompt::{ActionResult, Prompt},
-    ui::date::DateSelectBackend,
+    handle::PromptHandle,
+    input::{Input, InputActionResult, InputMask},
+    prompts::{
+        prompt::{ActionResult, Prompt, Timeout},
+        state::{render_help_message, PromptState},
+    },
+    type_aliases::{EventListener, HelpMessageFn},
+    ui::{date::DateSelectBackend, Block},
     validator::{DateValidator, ErrorMessage, Validation},
-    DateSelect, InquireError,
+    DateSelect, InputAction, InquireError,
 };
 
 use super::{action::DateSelectPromptAction, config::DateSelectConfig};
 
+/// Template of the date typed to jump to.
+const JUMP_INPUT_MASK: &str = "####-##-##";
+
+/// Max number of consecutive disabled dates skipped when looking for an
+/// available date.
+const MAX_SKIPPED_DAYS: i64 = 366;
+
 pub struct DateSelectPrompt<'a> {
     message: &'a str,
     config: DateSelectConfig,
+    event_listener: Option<EventListener<'a>>,
+    handle: Option<&'a PromptHandle>,
     current_date: NaiveDate,
     help_message: Option<&'a str>,
+    help_message_fn: Option<HelpMessageFn<'a>>,
+    header: Option<Block>,
+    footer: Option<Block>,
     formatter: DateFormatter<'a>,
     validators: Vec<Box<dyn DateValidator>>,
+    jump_input: Input,
     error: Option<ErrorMessage>,
 }
 
@@ -44,15 +64,38 @@ impl<'a> DateSelectPrompt<'a> {
             }
         }
 
-        Ok(Self {
+        let mut prompt = Self {
             message: so.message,
             current_date: so.starting_date,
             config: (&so).into(),
+            event_listener: so.event_listener,
+            handle: so.handle,
             help_message: so.help_message,
+            help_message_fn: so.help_message_fn,
+            header: so.header,
+            footer: so.footer,
             formatter: so.formatter,
             validators: so.validators,
+            jump_input: Input::new(),
             error: None,
-        })
+        };
+
+        let starting_date = prompt.current_date;
+        prompt.current_date = prompt
+            .closest_available_date(starting_date, 1)
+            .or_else(|| prompt.closest_available_date(starting_date, -1))
+            .ok_or_else(|| {
+                InquireError::InvalidConfiguration(
+                    "No date around the starting date can be selected".into(),
+                )
+            })?;
+
+        Ok(prompt)
+    }
+
+    pub fn with_week_selection(mut self) -> Self {
+        self.config.week_selection = true;
+        self
     }
 
     fn shift_date(&mut self, duration: chrono::Duration) -> ActionResult {
@@ -84,16 +127,70 @@ impl<'a> DateSelectPrompt<'a> {
     }
 
     fn update_date(&mut self, new_date: NaiveDate) -> ActionResult {
+        let mut new_date = new_date;
+        if let Some(min_date) = self.config.min_date {
+            new_date = max(new_date, min_date);
+        }
+        if let Some(max_date) = self.config.max_date {
+            new_date = min(new_date, max_date);
+        }
+
+        let step = if new_date < self.current_date { -1 } else { 1 };
+        let new_date = match self.closest_available_date(new_date, step) {
+            Some(date) => date,
+            None => return ActionResult::Clean,
+        };
+
         if self.current_date == new_date {
             return ActionResult::Clean;
         }
 
         self.current_date = new_date;
-        if let Some(min_date) = self.config.min_date {
-            self.current_date = max(self.current_date, min_date);
+        ActionResult::NeedsRedraw
+    }
+
+    fn is_in_range(&self, date: NaiveDate) -> bool {
+        let after_min = !matches!(self.config.min_date, Some(min_date) if date < min_date);
+        let before_max = !matches!(self.config.max_date, Some(max_date) if date > max_date);
+
+        after_min && before_max
+    }
+
+    fn is_available(&self, date: NaiveDate) -> bool {
+        let disabled = matches!(self.config.disabled_dates, Some(disabled) if disabled(date));
+
+        self.is_in_range(date) && !disabled
+    }
+
+    /// Closest date from the given one, itself included, which is not out of
+    /// the min/max range nor disabled, looking in the direction of `step`.
+    fn closest_available_date(&self, date: NaiveDate, step: i64) -> Option<NaiveDate> {
+        (0..MAX_SKIPPED_DAYS)
+            .map_while(|days| date.checked_add_signed(Duration::days(days * step)))
+            .take_while(|date| self.is_in_range(*date))
+            .find(|date| self.is_available(*date))
+    }
+
+    fn handle_jump_input(&mut self, action: InputAction) -> ActionResult {
+        let result = match action {
+            InputAction::Write(c) => InputMask::new(JUMP_INPUT_MASK).write(&mut self.jump_input, c),
+            action => self.jump_input.handle(action),
+        };
+        if result != InputActionResult::ContentChanged {
+            return result.into();
         }
-        if let Some(max_date) = self.config.max_date {
-            self.current_date = min(self.current_date, max_date);
+
+        if InputMask::new(JUMP_INPUT_MASK).is_complete(self.jump_input.content()) {
+            let date = NaiveDate::parse_from_str(self.jump_input.content(), "%Y-%m-%d");
+            self.jump_input.clear();
+
+            match date {
+                Ok(date) => {
+                    self.error = None;
+                    self.update_date(date);
+                }
+                Err(_) => self.error = Some(ErrorMessage::Custom(translate("Invalid date").into())),
+            }
         }
 
         ActionResult::NeedsRedraw
@@ -125,14 +222,69 @@ where
     }
 
     fn format_answer(&self, answer: &NaiveDate) -> String {
-        (self.formatter)(*answer)
+        match self.config.week_selection {
+            true => {
+                let week = get_row_monday(*answer, self.config.week_start).iso_week();
+                format!("{}-W{:02}", week.year(), week.week())
+            }
+            false => (self.formatter)(*answer),
+        }
     }
 
     fn config(&self) -> &DateSelectConfig {
         &self.config
     }
 
+    fn interrupt_behavior(&self) -> InterruptBehavior {
+        self.config.interrupt_behavior
+    }
+
+    fn event_listener(&self) -> Option<EventListener<'_>> {
+        self.event_listener
+    }
+
+    fn prompt_handle(&self) -> Option<&PromptHandle> {
+        self.handle
+    }
+
+    fn header(&self) -> Option<&Block> {
+        self.header.as_ref()
+    }
+
+    fn footer(&self) -> Option<&Block> {
+        self.footer.as_ref()
+    }
+
+    fn timeout(&self) -> Option<Timeout> {
+        self.config.timeout
+    }
+
+    fn refresh_interval(&self) -> Option<std::time::Duration> {
+        self.config.refresh_interval
+    }
+
+    fn submit_on_timeout(&mut self) -> InquireResult<Option<NaiveDate>> {
+        Prompt::<B, _, _, _>::submit(self)
+    }
+
+    fn pre_cancel(&mut self) -> InquireResult<bool> {
+        if self.jump_input.is_empty() {
+            return Ok(true);
+        }
+
+        self.jump_input.clear();
+        Ok(false)
+    }
+
     fn submit(&mut self) -> InquireResult<Option<NaiveDate>> {
+        if !self.jump_input.is_empty() {
+            self.error = Some(ErrorMessage::Custom(translate_template(
+                "Type the full date to jump to, e.g. {}",
+                self.current_date.format("%Y-%m-%d"),
+            )));
+            return Ok(None);
+        }
+
         let answer = match self.validate_current_answer()? {
             Validation::Valid => Some(self.cur_answer()),
             Validation::Invalid(msg) => {
@@ -154,6 +306,7 @@ where
             DateSelectPromptAction::GoToNextYear => self.shift_months(12),
             DateSelectPromptAction::GoToPrevMonth => self.shift_months(-1),
             DateSelectPromptAction::GoToNextMonth => self.shift_months(1),
+            DateSelectPromptAction::JumpInput(action) => self.handle_jump_input(action),
         };
 
         Ok(result)
@@ -166,7 +319,7 @@ where
             backend.render_error_message(err)?;
         }
 
-        backend.render_calendar_prompt(prompt)?;
+        backend.render_calendar_prompt(prompt, &self.jump_input)?;
 
         backend.render_calendar(
             get_month(self.current_date.month()),
@@ -176,11 +329,20 @@ where
             self.current_date,
             self.config.min_date,
             self.config.max_date,
+            self.config.disabled_dates,
+            self.config.week_selection,
         )?;
 
-        if let Some(help_message) = self.help_message {
-            backend.render_help_message(help_message)?;
-        }
+        render_help_message(backend, self.help_message, self.help_message_fn, || {
+            PromptState {
+                input: self.jump_input.content(),
+                answer: Some(Prompt::<B, _, _, _>::format_answer(
+                    self,
+                    &self.current_date,
+                )),
+                ..PromptState::default()
+            }
+        })?;
 
         Ok(())
     }
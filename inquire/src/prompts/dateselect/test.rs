@@ -1,11 +1,11 @@
 use crate::{
     date_utils::get_current_date,
-    terminal::crossterm::CrosstermTerminal,
-    ui::{Backend, RenderConfig},
+    terminal::{crossterm::CrosstermTerminal, TestBackend},
+    ui::{calendar::CalendarRenderConfig, Backend, Key, KeyModifiers, RenderConfig},
     validator::Validation,
     DateSelect,
 };
-use chrono::NaiveDate;
+use chrono::{Datelike, NaiveDate, Weekday};
 use crossterm::event::{KeyCode, KeyEvent};
 
 fn default<'a>() -> DateSelect<'a> {
@@ -74,3 +74,203 @@ fn closure_validator() {
 
     assert_eq!(today_date.pred_opt().unwrap(), ans);
 }
+
+#[test]
+fn prompt_week_returns_the_iso_week_of_the_selected_row() {
+    let mut terminal = TestBackend::new(vec![]);
+    terminal.push_key(Key::Down(KeyModifiers::NONE));
+    terminal.push_key(Key::Enter);
+
+    let week = DateSelect::new("Week:")
+        .with_starting_date(NaiveDate::from_ymd_opt(2021, 8, 4).unwrap())
+        .with_week_start(Weekday::Mon)
+        .prompt_week_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(
+        NaiveDate::from_ymd_opt(2021, 8, 11).unwrap().iso_week(),
+        week
+    );
+    assert!(terminal.last_frame().unwrap().contains("Week: 2021-W32\n"));
+}
+
+#[test]
+fn prompt_week_uses_the_monday_of_sunday_started_rows() {
+    let mut terminal = TestBackend::new(vec![]);
+    terminal.push_key(Key::Enter);
+
+    // sunday, august 1st belongs to the 30th ISO week, but is displayed in the
+    // row of monday, august 2nd
+    let week = DateSelect::new("Week:")
+        .with_starting_date(NaiveDate::from_ymd_opt(2021, 8, 1).unwrap())
+        .prompt_week_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(31, week.week());
+}
+
+#[test]
+fn week_numbers_are_rendered_on_the_left() {
+    let mut terminal = TestBackend::new(vec![]);
+    terminal.push_key(Key::Enter);
+
+    let render_config = RenderConfig::empty()
+        .with_calendar_config(CalendarRenderConfig::empty().with_week_numbers(true));
+
+    DateSelect::new("Date:")
+        .with_starting_date(NaiveDate::from_ymd_opt(2021, 8, 4).unwrap())
+        .with_week_start(Weekday::Mon)
+        .with_render_config(render_config)
+        .without_help_message()
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(
+        concat!(
+            "? Date:\n",
+            ">        august 2021     \n",
+            "> wk mo tu we th fr sa su\n",
+            "> 30 26 27 28 29 30 31  1\n",
+            "> 31  2  3  4  5  6  7  8\n",
+            "> 32  9 10 11 12 13 14 15\n",
+            "> 33 16 17 18 19 20 21 22\n",
+            "> 34 23 24 25 26 27 28 29\n",
+            "> 35 30 31  1  2  3  4  5\n",
+        ),
+        terminal.frames()[0]
+    );
+}
+
+fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+#[test]
+fn cursor_skips_disabled_dates() {
+    let mut terminal = TestBackend::new(vec![]);
+    terminal.push_key(Key::Right(KeyModifiers::NONE));
+    terminal.push_key(Key::Left(KeyModifiers::NONE));
+    terminal.push_key(Key::Right(KeyModifiers::NONE));
+    terminal.push_key(Key::Enter);
+
+    // friday, august 6th
+    let ans = DateSelect::new("Date:")
+        .with_starting_date(NaiveDate::from_ymd_opt(2021, 8, 6).unwrap())
+        .with_disabled_dates(is_weekend)
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(NaiveDate::from_ymd_opt(2021, 8, 9).unwrap(), ans);
+}
+
+#[test]
+fn disabled_starting_date_is_replaced_by_the_next_available_one() {
+    let mut terminal = TestBackend::new(vec![]);
+    terminal.push_key(Key::Enter);
+
+    // saturday, august 7th
+    let ans = DateSelect::new("Date:")
+        .with_starting_date(NaiveDate::from_ymd_opt(2021, 8, 7).unwrap())
+        .with_disabled_dates(is_weekend)
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(NaiveDate::from_ymd_opt(2021, 8, 9).unwrap(), ans);
+}
+
+#[test]
+fn cursor_stays_when_every_date_ahead_is_disabled() {
+    let mut terminal = TestBackend::new(vec![]);
+    terminal.push_key(Key::Right(KeyModifiers::NONE));
+    terminal.push_key(Key::Enter);
+
+    let ans = DateSelect::new("Date:")
+        .with_starting_date(NaiveDate::from_ymd_opt(2021, 8, 6).unwrap())
+        .with_max_date(NaiveDate::from_ymd_opt(2021, 8, 8).unwrap())
+        .with_disabled_dates(is_weekend)
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(NaiveDate::from_ymd_opt(2021, 8, 6).unwrap(), ans);
+}
+
+#[test]
+fn page_keys_jump_by_month_and_year() {
+    let mut terminal = TestBackend::new(vec![]);
+    terminal.push_key(Key::PageDown(KeyModifiers::NONE));
+    terminal.push_key(Key::PageDown(KeyModifiers::NONE));
+    terminal.push_key(Key::PageUp(KeyModifiers::CONTROL));
+    terminal.push_key(Key::Enter);
+
+    let ans = DateSelect::new("Date:")
+        .with_starting_date(NaiveDate::from_ymd_opt(2021, 8, 4).unwrap())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(NaiveDate::from_ymd_opt(2020, 10, 4).unwrap(), ans);
+}
+
+#[test]
+fn typing_a_date_jumps_to_it() {
+    let mut terminal = TestBackend::new(vec![]).with_text("19850312\n");
+
+    let ans = DateSelect::new("Birthdate:")
+        .with_starting_date(NaiveDate::from_ymd_opt(2021, 8, 4).unwrap())
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(NaiveDate::from_ymd_opt(1985, 3, 12).unwrap(), ans);
+    assert!(terminal.frames()[7].starts_with("? Birthdate: 1985-03-1 \n"));
+    assert!(terminal.frames()[8].starts_with("? Birthdate:\n>      march 1985"));
+}
+
+#[test]
+fn invalid_typed_dates_are_reported_and_cleared() {
+    let mut terminal = TestBackend::new(vec![]).with_text("20210231");
+    terminal.push_text("2021");
+    terminal.push_key(Key::Escape);
+    terminal.push_key(Key::Enter);
+
+    let ans = DateSelect::new("Date:")
+        .with_starting_date(NaiveDate::from_ymd_opt(2021, 8, 4).unwrap())
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(NaiveDate::from_ymd_opt(2021, 8, 4).unwrap(), ans);
+    assert!(terminal.frames()[8].starts_with("# Invalid date\n? Date:\n"));
+    assert!(terminal.frames()[12].starts_with("# Invalid date\n? Date: 2021 \n"));
+    assert!(terminal.frames()[13].starts_with("# Invalid date\n? Date:\n"));
+}
+
+#[test]
+#[cfg(feature = "chrono-tz")]
+fn prompt_zoned_returns_the_start_of_the_day_in_the_selected_timezone() {
+    let mut terminal = TestBackend::new(vec![]).with_text("\nparis\n");
+
+    let ans = DateSelect::new("Date:")
+        .with_starting_date(NaiveDate::from_ymd_opt(2021, 8, 4).unwrap())
+        .with_render_config(RenderConfig::empty())
+        .prompt_zoned_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("2021-08-04T00:00:00+02:00", ans.to_rfc3339());
+    assert_eq!(chrono_tz::Europe::Paris, ans.timezone());
+    assert_eq!(
+        Some("? Time zone: Europe/Paris\n"),
+        terminal.last_frame().as_deref()
+    );
+}
+
+#[test]
+#[cfg(feature = "chrono-tz")]
+fn days_skipping_midnight_start_at_the_first_valid_time() {
+    // daylight saving time started at midnight in Brazil in 2018
+    let ans = super::start_of_day(
+        NaiveDate::from_ymd_opt(2018, 11, 4).unwrap(),
+        chrono_tz::America::Sao_Paulo,
+    );
+
+    assert_eq!("2018-11-04T01:00:00-02:00", ans.to_rfc3339());
+}
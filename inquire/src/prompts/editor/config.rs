@@ -1,5 +1,5 @@
-use crate::Editor;
-use std::ffi::OsStr;
+use crate::{config::InterruptBehavior, prompts::prompt::Timeout, Editor};
+use std::{ffi::OsStr, time::Duration};
 
 /// Configuration settings used in the execution of an EditorPrompt.
 #[derive(Copy, Clone, Debug)]
@@ -8,6 +8,12 @@ pub struct EditorConfig<'a> {
     pub editor_command: &'a OsStr,
     /// The arguments to pass to the editor command.
     pub editor_command_args: &'a [&'a OsStr],
+    /// What happens when the user presses Ctrl+C.
+    pub interrupt_behavior: InterruptBehavior,
+    /// Timeout applied until the first key press.
+    pub timeout: Option<Timeout>,
+    /// Interval at which the prompt is re-rendered without key presses.
+    pub refresh_interval: Option<Duration>,
 }
 
 impl<'a> From<&Editor<'a>> for EditorConfig<'a> {
@@ -15,6 +21,9 @@ impl<'a> From<&Editor<'a>> for EditorConfig<'a> {
         Self {
             editor_command: value.editor_command,
             editor_command_args: value.editor_command_args,
+            interrupt_behavior: value.interrupt_behavior,
+            timeout: Timeout::new(value.timeout, value.timeout_countdown),
+            refresh_interval: value.refresh_interval,
         }
     }
 }
@@ -7,16 +7,21 @@ pub use action::*;
 use std::{
     env,
     ffi::{OsStr, OsString},
+    time::Duration,
 };
 
 use lazy_static::lazy_static;
 
 use crate::{
+    config::{get_event_listener, get_global_options, get_interrupt_behavior, InterruptBehavior},
     error::{InquireError, InquireResult},
     formatter::StringFormatter,
+    handle::PromptHandle,
     prompts::prompt::Prompt,
-    terminal::get_default_terminal,
-    ui::{Backend, EditorBackend, RenderConfig},
+    report::PromptReport,
+    terminal::{get_default_terminal, Terminal},
+    type_aliases::{EventListener, HelpMessageFn},
+    ui::{Backend, Block, EditorBackend, FinalRender, Redaction, RenderConfig},
     validator::StringValidator,
 };
 
@@ -70,6 +75,18 @@ pub struct Editor<'a> {
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
+    /// Function computing the help message from the current state of the
+    /// prompt, replacing [`help_message`](Self::help_message) when set.
+    ///
+    /// See [`with_help_message_fn`](Self::with_help_message_fn) for more details.
+    pub help_message_fn: Option<HelpMessageFn<'a>>,
+
+    /// Content rendered above the prompt.
+    pub header: Option<Block>,
+
+    /// Content rendered below the prompt.
+    pub footer: Option<Block>,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: StringFormatter<'a>,
 
@@ -81,6 +98,33 @@ pub struct Editor<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub validators: Vec<Box<dyn StringValidator>>,
 
+    /// What happens when the user presses Ctrl+C.
+    pub interrupt_behavior: InterruptBehavior,
+
+    /// Listener notified of the events of the prompt, such as key presses and
+    /// submissions.
+    ///
+    /// See [`with_event_listener`](Self::with_event_listener) for more details.
+    pub event_listener: Option<EventListener<'a>>,
+
+    /// Handle aborting the prompt from outside of it, if any.
+    ///
+    /// See [`with_handle`](Self::with_handle) for more details.
+    pub handle: Option<&'a PromptHandle>,
+
+    /// Time to wait for the user to start interacting with the prompt.
+    ///
+    /// See [`with_timeout`](Self::with_timeout) for more details.
+    pub timeout: Option<Duration>,
+
+    /// Whether to render the time left before the timeout expires.
+    pub timeout_countdown: bool,
+
+    /// Interval at which the prompt is re-rendered while no key is pressed.
+    ///
+    /// See [`with_refresh_interval`](Self::with_refresh_interval) for more details.
+    pub refresh_interval: Option<Duration>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -104,15 +148,26 @@ impl<'a> Editor<'a> {
 
     /// Creates a [Editor] with the provided message and default options.
     pub fn new(message: &'a str) -> Self {
+        let global = get_global_options();
+
         Self {
             message,
             editor_command: &DEFAULT_EDITOR,
             editor_command_args: &[],
             file_extension: ".txt",
             predefined_text: None,
-            help_message: Self::DEFAULT_HELP_MESSAGE,
+            help_message: global.help_message(Self::DEFAULT_HELP_MESSAGE),
+            help_message_fn: None,
+            header: None,
+            footer: None,
             validators: Self::DEFAULT_VALIDATORS,
             formatter: Self::DEFAULT_FORMATTER,
+            interrupt_behavior: get_interrupt_behavior(),
+            event_listener: get_event_listener(),
+            handle: None,
+            timeout: None,
+            timeout_countdown: false,
+            refresh_interval: None,
             render_config: RenderConfig::default(),
         }
     }
@@ -123,6 +178,30 @@ impl<'a> Editor<'a> {
         self
     }
 
+    /// Sets a function computing the help message from the current state of the
+    /// prompt, called whenever it is rendered. It takes precedence over the
+    /// static help message, and returning `None` hides the help line.
+    ///
+    /// See [`PromptState`](crate::PromptState) for the state given to the function.
+    pub fn with_help_message_fn(mut self, help_message_fn: HelpMessageFn<'a>) -> Self {
+        self.help_message_fn = Some(help_message_fn);
+        self
+    }
+
+    /// Sets the content rendered above the prompt, such as the current git
+    /// branch. It is redrawn and cleared along with the prompt, see [`Block`].
+    pub fn with_header<H: Into<Block>>(mut self, header: H) -> Self {
+        self.header = Some(header.into());
+        self
+    }
+
+    /// Sets the content rendered below the prompt. It is redrawn and cleared
+    /// along with the prompt, see [`Block`].
+    pub fn with_footer<F: Into<Block>>(mut self, footer: F) -> Self {
+        self.footer = Some(footer.into());
+        self
+    }
+
     /// Sets the predefined text to be written into the temporary file.
     pub fn with_predefined_text(mut self, text: &'a str) -> Self {
         self.predefined_text = Some(text);
@@ -185,12 +264,66 @@ impl<'a> Editor<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub fn with_validators(mut self, validators: &[Box<dyn StringValidator>]) -> Self {
         for validator in validators {
-            #[allow(clippy::clone_double_ref)]
             self.validators.push(validator.clone());
         }
         self
     }
 
+    /// Sets what happens when the user presses Ctrl+C, overriding the global
+    /// setting.
+    pub fn with_interrupt_behavior(mut self, interrupt_behavior: InterruptBehavior) -> Self {
+        self.interrupt_behavior = interrupt_behavior;
+        self
+    }
+
+    /// Sets a listener notified of the events of the prompt, such as renders,
+    /// key presses and submissions, overriding the global listener.
+    ///
+    /// See the [`events`](crate::events) module for more details.
+    pub fn with_event_listener(mut self, listener: EventListener<'a>) -> Self {
+        self.event_listener = Some(listener);
+        self
+    }
+
+    /// Sets a handle aborting the prompt from outside of it, e.g. from another
+    /// thread or a Ctrl+C handler, canceling it as if the user pressed ESC.
+    ///
+    /// See the [`handle`](crate::handle) module for more details.
+    pub fn with_handle(mut self, handle: &'a PromptHandle) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets a timeout for the user to start interacting with the prompt.
+    ///
+    /// If no key is pressed before it expires, [`InquireError::Timeout`] is returned.
+    /// Once a key is pressed, the prompt waits for the user indefinitely.
+    ///
+    /// Timeouts are supported by the `crossterm` and `termion` backends, the
+    /// `console` backend always waits for the first key press.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether to render the time left before the timeout expires below the prompt.
+    pub fn with_timeout_countdown(mut self, countdown: bool) -> Self {
+        self.timeout_countdown = countdown;
+        self
+    }
+
+    /// Sets an interval at which the prompt is re-rendered even when no key is
+    /// pressed, e.g. for content computed at render time, such as a dynamic
+    /// help message showing the current time, to stay up to date.
+    ///
+    /// Requires a terminal able to wait for keys with a timeout, such as the
+    /// crossterm or termion ones. Other terminals only re-render the prompt
+    /// after key presses.
+    pub fn with_refresh_interval(mut self, interval: Duration) -> Self {
+        self.refresh_interval = Some(interval);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -204,6 +337,35 @@ impl<'a> Editor<'a> {
         self
     }
 
+    /// Sets how the prompt is rendered once answered, e.g. [`FinalRender::None`]
+    /// for the prompt to disappear. This is a shorthand for setting the
+    /// [`final_render`](RenderConfig::final_render) of the render config, so it
+    /// must be called after [`with_render_config`](Self::with_render_config).
+    pub fn with_final_render(mut self, final_render: FinalRender) -> Self {
+        self.render_config.final_render = final_render;
+        self
+    }
+
+    /// Sets how the answer is redacted once the prompt is answered, e.g.
+    /// [`Redaction::Masked`] for secrets to stay out of the scrollback. This is
+    /// a shorthand for setting the [`answer_redaction`](RenderConfig::answer_redaction)
+    /// of the render config, so it must be called after
+    /// [`with_render_config`](Self::with_render_config).
+    pub fn with_answer_redaction(mut self, answer_redaction: Redaction) -> Self {
+        self.render_config.answer_redaction = answer_redaction;
+        self
+    }
+
+    /// Sets whether the prompt is rendered in fullscreen, on the alternate
+    /// screen buffer, leaving only its final rendering in the original screen.
+    /// This is a shorthand for setting the
+    /// [`fullscreen`](RenderConfig::fullscreen) flag of the render config, so
+    /// it must be called after [`with_render_config`](Self::with_render_config).
+    pub fn with_fullscreen(mut self, fullscreen: bool) -> Self {
+        self.render_config.fullscreen = fullscreen;
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -224,11 +386,33 @@ impl<'a> Editor<'a> {
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     pub fn prompt(self) -> InquireResult<String> {
-        let terminal = get_default_terminal()?;
+        self.prompt_with_terminal(get_default_terminal()?)
+    }
+
+    /// Prompts the user through the given terminal instead of the default
+    /// terminal of the enabled backend feature, see [`Terminal`].
+    pub fn prompt_with_terminal<Term: Terminal>(self, terminal: Term) -> InquireResult<String> {
         let mut backend = Backend::new(terminal, self.render_config)?;
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Same as [`prompt`](Self::prompt), also returning a [`PromptReport`]
+    /// describing how the user interacted with the prompt.
+    pub fn prompt_with_report(self) -> InquireResult<(String, PromptReport)> {
+        self.prompt_with_report_and_terminal(get_default_terminal()?)
+    }
+
+    /// Same as [`prompt_with_terminal`](Self::prompt_with_terminal), also
+    /// returning a [`PromptReport`] describing how the user interacted with
+    /// the prompt.
+    pub fn prompt_with_report_and_terminal<Term: Terminal>(
+        self,
+        terminal: Term,
+    ) -> InquireResult<(String, PromptReport)> {
+        let mut backend = Backend::new(terminal, self.render_config)?;
+        EditorPrompt::new(self)?.prompt_with_report(&mut backend)
+    }
+
     pub(crate) fn prompt_with_backend<B: EditorBackend>(
         self,
         backend: &mut B,
@@ -3,15 +3,22 @@ use std::{
     io::{Read, Write},
     path::Path,
     process,
+    time::Duration,
 };
 
 use tempfile::NamedTempFile;
 
 use crate::{
+    config::InterruptBehavior,
     error::InquireResult,
     formatter::StringFormatter,
-    prompts::prompt::{ActionResult, Prompt},
-    ui::EditorBackend,
+    handle::PromptHandle,
+    prompts::{
+        prompt::{ActionResult, Prompt, Timeout},
+        state::{render_help_message, PromptState},
+    },
+    type_aliases::{EventListener, HelpMessageFn},
+    ui::{Block, EditorBackend},
     validator::{ErrorMessage, StringValidator, Validation},
     Editor, InquireError,
 };
@@ -21,7 +28,12 @@ use super::{action::EditorPromptAction, config::EditorConfig};
 pub struct EditorPrompt<'a> {
     message: &'a str,
     config: EditorConfig<'a>,
+    event_listener: Option<EventListener<'a>>,
+    handle: Option<&'a PromptHandle>,
     help_message: Option<&'a str>,
+    help_message_fn: Option<HelpMessageFn<'a>>,
+    header: Option<Block>,
+    footer: Option<Block>,
     formatter: StringFormatter<'a>,
     validators: Vec<Box<dyn StringValidator>>,
     error: Option<ErrorMessage>,
@@ -39,7 +51,12 @@ impl<'a> EditorPrompt<'a> {
         Ok(Self {
             message: so.message,
             config: (&so).into(),
+            event_listener: so.event_listener,
+            handle: so.handle,
             help_message: so.help_message,
+            help_message_fn: so.help_message_fn,
+            header: so.header,
+            footer: so.footer,
             formatter: so.formatter,
             validators: so.validators,
             error: None,
@@ -112,6 +129,34 @@ where
         &self.config
     }
 
+    fn interrupt_behavior(&self) -> InterruptBehavior {
+        self.config.interrupt_behavior
+    }
+
+    fn event_listener(&self) -> Option<EventListener<'_>> {
+        self.event_listener
+    }
+
+    fn prompt_handle(&self) -> Option<&PromptHandle> {
+        self.handle
+    }
+
+    fn header(&self) -> Option<&Block> {
+        self.header.as_ref()
+    }
+
+    fn footer(&self) -> Option<&Block> {
+        self.footer.as_ref()
+    }
+
+    fn timeout(&self) -> Option<Timeout> {
+        self.config.timeout
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        self.config.refresh_interval
+    }
+
     fn format_answer(&self, answer: &String) -> String {
         (self.formatter)(answer)
     }
@@ -152,9 +197,12 @@ where
 
         backend.render_prompt(prompt, editor_name)?;
 
-        if let Some(message) = self.help_message {
-            backend.render_help_message(message)?;
-        }
+        render_help_message(
+            backend,
+            self.help_message,
+            self.help_message_fn,
+            PromptState::default,
+        )?;
 
         Ok(())
     }
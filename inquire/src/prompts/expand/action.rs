@@ -0,0 +1,32 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction,
+};
+
+use super::config::ExpandConfig;
+
+/// Set of actions for an ExpandPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExpandPromptAction {
+    /// Key pressed by the user, either the key of a choice or the help key.
+    KeyPress(char),
+    /// Moves the cursor to the option above. In the expanded list only.
+    MoveUp,
+    /// Moves the cursor to the option below. In the expanded list only.
+    MoveDown,
+}
+
+impl InnerAction<ExpandConfig> for ExpandPromptAction {
+    fn from_key(key: Key, _config: &ExpandConfig) -> Option<Self> {
+        let action = match key {
+            Key::Up(KeyModifiers::NONE) => Self::MoveUp,
+            Key::Down(KeyModifiers::NONE) => Self::MoveDown,
+            Key::Char(c, m) if !m.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => {
+                Self::KeyPress(c)
+            }
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
@@ -0,0 +1,27 @@
+use std::time::Duration;
+
+use crate::{config::InterruptBehavior, prompts::prompt::Timeout, Expand};
+
+/// Configuration settings used in the execution of an ExpandPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct ExpandConfig {
+    /// Page size of the expanded list of options.
+    pub page_size: usize,
+    /// What happens when the user presses Ctrl+C.
+    pub interrupt_behavior: InterruptBehavior,
+    /// Timeout applied until the first key press.
+    pub timeout: Option<Timeout>,
+    /// Interval at which the prompt is re-rendered without key presses.
+    pub refresh_interval: Option<Duration>,
+}
+
+impl<T> From<&Expand<'_, T>> for ExpandConfig {
+    fn from(value: &Expand<'_, T>) -> Self {
+        Self {
+            page_size: value.page_size,
+            interrupt_behavior: value.interrupt_behavior,
+            timeout: Timeout::new(value.timeout, value.timeout_countdown),
+            refresh_interval: value.refresh_interval,
+        }
+    }
+}
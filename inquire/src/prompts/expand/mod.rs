@@ -0,0 +1,425 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+
+use std::{fmt::Display, time::Duration};
+
+use crate::{
+    config::{
+        get_configuration, get_event_listener, get_global_options, get_interrupt_behavior,
+        InterruptBehavior,
+    },
+    error::{InquireError, InquireResult},
+    formatter::OptionFormatter,
+    handle::PromptHandle,
+    list_option::ListOption,
+    prompts::prompt::Prompt,
+    report::PromptReport,
+    terminal::{get_default_terminal, Terminal},
+    type_aliases::{EventListener, HelpMessageFn},
+    ui::{Backend, Block, ExpandBackend, FinalRender, RenderConfig},
+};
+
+use self::prompt::ExpandPrompt;
+
+/// Prompt to choose one among a few options by pressing a single key, like the
+/// `expand` prompt of inquirer.js or the hunk prompts of `git add -p`.
+///
+/// Each option is assigned a key. The prompt only displays the available keys
+/// next to the message, e.g. `(y,n,q,a,d,h)`, and pressing the key of an option
+/// immediately selects and submits it. Pressing the help key, `h` by default,
+/// expands the full list of options along with their keys, in which the user
+/// can also move with the arrow keys and submit the highlighted option by
+/// pressing enter.
+///
+/// Keys are case-sensitive, so `y` and `Y` can be assigned to different options.
+/// The prompt fails with an [`InquireError::InvalidConfiguration`] error when
+/// there are no options, when two options have the same key or when an option
+/// has the help key.
+///
+/// This prompt provides several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Options list**: Options displayed to the user, each with its key. Must be **non-empty**.
+/// - **Help key**: Key that expands the full list of options, `h` by default.
+/// - **Expanded**: Whether the full list of options is displayed from the start. Disabled by default.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the selected option string value by default.
+/// - **Page size**: Number of options displayed at once in the expanded list, 7 by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::Expand;
+///
+/// let action = Expand::new(
+///     "Stage this hunk?",
+///     vec![
+///         ('y', "Stage this hunk"),
+///         ('n', "Do not stage this hunk"),
+///         ('q', "Quit"),
+///         ('a', "Stage this hunk and all later hunks in the file"),
+///         ('d', "Do not stage this hunk or any of the later hunks in the file"),
+///     ],
+/// )
+/// .with_help_key('?')
+/// .prompt()?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+///
+/// [`InquireError::InvalidConfiguration`]: crate::error::InquireError::InvalidConfiguration
+#[derive(Clone)]
+pub struct Expand<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Options displayed to the user.
+    pub options: Vec<T>,
+
+    /// Keys that select the option at the same index when pressed.
+    pub keys: Vec<char>,
+
+    /// Key that expands the full list of options.
+    pub help_key: char,
+
+    /// Whether the full list of options is displayed from the start.
+    pub expanded: bool,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function computing the help message from the current state of the
+    /// prompt, replacing [`help_message`](Self::help_message) when set.
+    ///
+    /// See [`with_help_message_fn`](Self::with_help_message_fn) for more details.
+    pub help_message_fn: Option<HelpMessageFn<'a>>,
+
+    /// Content rendered above the prompt.
+    pub header: Option<Block>,
+
+    /// Content rendered below the prompt.
+    pub footer: Option<Block>,
+
+    /// Page size of the expanded list of options.
+    pub page_size: usize,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: OptionFormatter<'a, T>,
+
+    /// What happens when the user presses Ctrl+C.
+    pub interrupt_behavior: InterruptBehavior,
+
+    /// Listener notified of the events of the prompt, such as key presses and
+    /// submissions.
+    ///
+    /// See [`with_event_listener`](Self::with_event_listener) for more details.
+    pub event_listener: Option<EventListener<'a>>,
+
+    /// Handle aborting the prompt from outside of it, if any.
+    ///
+    /// See [`with_handle`](Self::with_handle) for more details.
+    pub handle: Option<&'a PromptHandle>,
+
+    /// Time to wait for the user to start interacting with the prompt.
+    ///
+    /// See [`with_timeout`](Self::with_timeout) for more details.
+    pub timeout: Option<Duration>,
+
+    /// Whether to render the time left before the timeout expires.
+    pub timeout_countdown: bool,
+
+    /// Interval at which the prompt is re-rendered while no key is pressed.
+    ///
+    /// See [`with_refresh_interval`](Self::with_refresh_interval) for more details.
+    pub refresh_interval: Option<Duration>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still suport NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> Expand<'a, T>
+where
+    T: Display,
+{
+    /// String formatter used by default in [Expand](crate::Expand) prompts.
+    /// Simply prints the string value contained in the selected option.
+    pub const DEFAULT_FORMATTER: OptionFormatter<'a, T> = &|ans| ans.to_string();
+
+    /// Default key that expands the full list of options.
+    pub const DEFAULT_HELP_KEY: char = 'h';
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> = None;
+
+    /// Creates an [Expand] with the provided message and options, each given
+    /// along with its key, and default configuration values.
+    pub fn new(message: &'a str, options: Vec<(char, T)>) -> Self {
+        let global = get_global_options();
+        let (keys, options) = options.into_iter().unzip();
+
+        Self {
+            message,
+            options,
+            keys,
+            help_key: Self::DEFAULT_HELP_KEY,
+            expanded: false,
+            help_message: global.help_message(Self::DEFAULT_HELP_MESSAGE),
+            help_message_fn: None,
+            header: None,
+            footer: None,
+            page_size: global.page_size,
+            formatter: Self::DEFAULT_FORMATTER,
+            interrupt_behavior: get_interrupt_behavior(),
+            event_listener: get_event_listener(),
+            handle: None,
+            timeout: None,
+            timeout_countdown: false,
+            refresh_interval: None,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the key that expands the full list of options.
+    pub fn with_help_key(mut self, help_key: char) -> Self {
+        self.help_key = help_key;
+        self
+    }
+
+    /// Sets whether the full list of options is displayed from the start.
+    pub fn with_expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Sets a function computing the help message from the current state of the
+    /// prompt, called whenever it is rendered. It takes precedence over the
+    /// static help message, and returning `None` hides the help line.
+    ///
+    /// See [`PromptState`](crate::PromptState) for the state given to the function.
+    pub fn with_help_message_fn(mut self, help_message_fn: HelpMessageFn<'a>) -> Self {
+        self.help_message_fn = Some(help_message_fn);
+        self
+    }
+
+    /// Sets the content rendered above the prompt, such as the current git
+    /// branch. It is redrawn and cleared along with the prompt, see [`Block`].
+    pub fn with_header<H: Into<Block>>(mut self, header: H) -> Self {
+        self.header = Some(header.into());
+        self
+    }
+
+    /// Sets the content rendered below the prompt. It is redrawn and cleared
+    /// along with the prompt, see [`Block`].
+    pub fn with_footer<F: Into<Block>>(mut self, footer: F) -> Self {
+        self.footer = Some(footer.into());
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size of the expanded list of options.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: OptionFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets what happens when the user presses Ctrl+C, overriding the global
+    /// setting.
+    pub fn with_interrupt_behavior(mut self, interrupt_behavior: InterruptBehavior) -> Self {
+        self.interrupt_behavior = interrupt_behavior;
+        self
+    }
+
+    /// Sets a listener notified of the events of the prompt, such as renders,
+    /// key presses and submissions, overriding the global listener.
+    ///
+    /// See the [`events`](crate::events) module for more details.
+    pub fn with_event_listener(mut self, listener: EventListener<'a>) -> Self {
+        self.event_listener = Some(listener);
+        self
+    }
+
+    /// Sets a handle aborting the prompt from outside of it, e.g. from another
+    /// thread or a Ctrl+C handler, canceling it as if the user pressed ESC.
+    ///
+    /// See the [`handle`](crate::handle) module for more details.
+    pub fn with_handle(mut self, handle: &'a PromptHandle) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets a timeout for the user to start interacting with the prompt.
+    ///
+    /// If no key is pressed before it expires, [`InquireError::Timeout`] is returned.
+    /// Once a key is pressed, the prompt waits for the user indefinitely.
+    ///
+    /// Timeouts are supported by the `crossterm` and `termion` backends, the
+    /// `console` backend always waits for the first key press.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether to render the time left before the timeout expires below the prompt.
+    pub fn with_timeout_countdown(mut self, countdown: bool) -> Self {
+        self.timeout_countdown = countdown;
+        self
+    }
+
+    /// Sets an interval at which the prompt is re-rendered even when no key is
+    /// pressed, e.g. for content computed at render time, such as a dynamic
+    /// help message showing the current time, to stay up to date.
+    ///
+    /// Requires a terminal able to wait for keys with a timeout, such as the
+    /// crossterm or termion ones. Other terminals only re-render the prompt
+    /// after key presses.
+    pub fn with_refresh_interval(mut self, interval: Duration) -> Self {
+        self.refresh_interval = Some(interval);
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still suport NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Sets how the prompt is rendered once answered, e.g. [`FinalRender::None`]
+    /// for the prompt to disappear. This is a shorthand for setting the
+    /// [`final_render`](RenderConfig::final_render) of the render config, so it
+    /// must be called after [`with_render_config`](Self::with_render_config).
+    pub fn with_final_render(mut self, final_render: FinalRender) -> Self {
+        self.render_config.final_render = final_render;
+        self
+    }
+
+    /// Sets whether the prompt is rendered in fullscreen, on the alternate
+    /// screen buffer, leaving only its final rendering in the original screen.
+    /// This is a shorthand for setting the
+    /// [`fullscreen`](RenderConfig::fullscreen) flag of the render config, so
+    /// it must be called after [`with_render_config`](Self::with_render_config).
+    pub fn with_fullscreen(mut self, fullscreen: bool) -> Self {
+        self.render_config.fullscreen = fullscreen;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the owned object selected by the user.
+    pub fn prompt(self) -> InquireResult<T> {
+        self.raw_prompt().map(|op| op.value)
+    }
+
+    /// Prompts the user through the given terminal instead of the default
+    /// terminal of the enabled backend feature, see [`Terminal`].
+    pub fn prompt_with_terminal<Term: Terminal>(self, terminal: Term) -> InquireResult<T> {
+        self.raw_prompt_with_terminal(terminal).map(|op| op.value)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<T>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns a [`ListOption`](crate::list_option::ListOption) containing
+    /// the index of the selection and the owned object selected by the user.
+    pub fn raw_prompt(self) -> InquireResult<ListOption<T>> {
+        self.raw_prompt_with_terminal(get_default_terminal()?)
+    }
+
+    /// Prompts the user through the given terminal instead of the default
+    /// terminal of the enabled backend feature, see [`Terminal`].
+    ///
+    /// Returns the same as [`raw_prompt`](Self::raw_prompt).
+    pub fn raw_prompt_with_terminal<Term: Terminal>(
+        self,
+        terminal: Term,
+    ) -> InquireResult<ListOption<T>> {
+        let mut backend = Backend::new(terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    /// Same as [`prompt`](Self::prompt), also returning a [`PromptReport`]
+    /// describing how the user interacted with the prompt.
+    pub fn prompt_with_report(self) -> InquireResult<(T, PromptReport)> {
+        self.prompt_with_report_and_terminal(get_default_terminal()?)
+    }
+
+    /// Same as [`prompt_with_terminal`](Self::prompt_with_terminal), also
+    /// returning a [`PromptReport`] describing how the user interacted with
+    /// the prompt.
+    pub fn prompt_with_report_and_terminal<Term: Terminal>(
+        self,
+        terminal: Term,
+    ) -> InquireResult<(T, PromptReport)> {
+        self.raw_prompt_with_report(terminal)
+            .map(|(answer, report)| (answer.value, report))
+    }
+
+    fn raw_prompt_with_report<Term: Terminal>(
+        self,
+        terminal: Term,
+    ) -> InquireResult<(ListOption<T>, PromptReport)> {
+        let mut backend = Backend::new(terminal, self.render_config)?;
+        ExpandPrompt::new(self)?.prompt_with_report(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: ExpandBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<ListOption<T>> {
+        ExpandPrompt::new(self)?.prompt(backend)
+    }
+}
@@ -0,0 +1,253 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::{
+    config::InterruptBehavior,
+    error::InquireResult,
+    formatter::OptionFormatter,
+    handle::PromptHandle,
+    list_option::ListOption,
+    prompts::{
+        prompt::{ActionResult, Prompt, Timeout},
+        state::{render_help_message, PromptState},
+    },
+    type_aliases::{EventListener, HelpMessageFn},
+    ui::{Block, ExpandBackend},
+    utils::paginate,
+    validator::ErrorMessage,
+    Expand, InquireError,
+};
+
+use super::{action::ExpandPromptAction, config::ExpandConfig};
+
+pub struct ExpandPrompt<'a, T> {
+    message: &'a str,
+    config: ExpandConfig,
+    event_listener: Option<EventListener<'a>>,
+    handle: Option<&'a PromptHandle>,
+    options: Vec<T>,
+    keys: Vec<char>,
+    labels: Vec<String>,
+    help_key: char,
+    expanded: bool,
+    cursor_index: usize,
+    chosen: Option<usize>,
+    error: Option<ErrorMessage>,
+    help_message: Option<&'a str>,
+    help_message_fn: Option<HelpMessageFn<'a>>,
+    header: Option<Block>,
+    footer: Option<Block>,
+    formatter: OptionFormatter<'a, T>,
+}
+
+impl<'a, T> ExpandPrompt<'a, T>
+where
+    T: Display,
+{
+    pub fn new(eo: Expand<'a, T>) -> InquireResult<Self> {
+        if eo.options.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Available options can not be empty".into(),
+            ));
+        }
+
+        if eo.keys.len() != eo.options.len() {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Got {} keys for {} options",
+                eo.keys.len(),
+                eo.options.len()
+            )));
+        }
+
+        for (i, key) in eo.keys.iter().enumerate() {
+            if *key == eo.help_key || eo.keys[..i].contains(key) {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Key `{}` is assigned more than once",
+                    key
+                )));
+            }
+        }
+
+        let labels = eo
+            .keys
+            .iter()
+            .zip(&eo.options)
+            .map(|(key, option)| format!("{}) {}", key, option))
+            .collect();
+
+        Ok(Self {
+            message: eo.message,
+            config: (&eo).into(),
+            event_listener: eo.event_listener,
+            handle: eo.handle,
+            options: eo.options,
+            keys: eo.keys,
+            labels,
+            help_key: eo.help_key,
+            expanded: eo.expanded,
+            cursor_index: 0,
+            chosen: None,
+            error: None,
+            help_message: eo.help_message,
+            help_message_fn: eo.help_message_fn,
+            header: eo.header,
+            footer: eo.footer,
+            formatter: eo.formatter,
+        })
+    }
+
+    fn invalid_key_error(&self) -> ErrorMessage {
+        ErrorMessage::Custom(format!(
+            "Press the key of an option, or {} to list all options",
+            self.help_key
+        ))
+    }
+
+    fn move_cursor(&mut self, forward: bool) -> ActionResult {
+        if !self.expanded {
+            return ActionResult::Clean;
+        }
+
+        let len = self.options.len();
+        self.cursor_index = match forward {
+            true => (self.cursor_index + 1) % len,
+            false => (self.cursor_index + len - 1) % len,
+        };
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn state(&self) -> PromptState<'_> {
+        let index = match (self.chosen, self.expanded) {
+            (Some(index), _) => Some(index),
+            (None, true) => Some(self.cursor_index),
+            (None, false) => None,
+        };
+        let answer =
+            index.map(|index| (self.formatter)(ListOption::new(index, &self.options[index])));
+
+        PromptState {
+            answer,
+            options: self.options.len(),
+            ..PromptState::default()
+        }
+    }
+}
+
+impl<'a, B, T> Prompt<B, ExpandConfig, ExpandPromptAction, ListOption<T>> for ExpandPrompt<'a, T>
+where
+    B: ExpandBackend,
+    T: Display,
+{
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &ExpandConfig {
+        &self.config
+    }
+
+    fn interrupt_behavior(&self) -> InterruptBehavior {
+        self.config.interrupt_behavior
+    }
+
+    fn event_listener(&self) -> Option<EventListener<'_>> {
+        self.event_listener
+    }
+
+    fn prompt_handle(&self) -> Option<&PromptHandle> {
+        self.handle
+    }
+
+    fn header(&self) -> Option<&Block> {
+        self.header.as_ref()
+    }
+
+    fn footer(&self) -> Option<&Block> {
+        self.footer.as_ref()
+    }
+
+    fn timeout(&self) -> Option<Timeout> {
+        self.config.timeout
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        self.config.refresh_interval
+    }
+
+    fn format_answer(&self, answer: &ListOption<T>) -> String {
+        (self.formatter)(answer.as_ref())
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<ListOption<T>>> {
+        let index = match (self.chosen, self.expanded) {
+            (Some(index), _) => index,
+            (None, true) => self.cursor_index,
+            (None, false) => {
+                self.error = Some(self.invalid_key_error());
+                return Ok(None);
+            }
+        };
+        let value = self.options.swap_remove(index);
+
+        Ok(Some(ListOption::new(index, value)))
+    }
+
+    fn handle(&mut self, action: ExpandPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            ExpandPromptAction::MoveUp => self.move_cursor(false),
+            ExpandPromptAction::MoveDown => self.move_cursor(true),
+            ExpandPromptAction::KeyPress(c) if c == self.help_key => {
+                self.error = None;
+                self.expanded = true;
+                ActionResult::NeedsRedraw
+            }
+            ExpandPromptAction::KeyPress(c) => match self.keys.iter().position(|key| *key == c) {
+                Some(index) => {
+                    self.chosen = Some(index);
+                    ActionResult::Submit
+                }
+                None => {
+                    self.error = Some(self.invalid_key_error());
+                    ActionResult::NeedsRedraw
+                }
+            },
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut B) -> InquireResult<()> {
+        if let Some(error) = &self.error {
+            backend.render_error_message(error)?;
+        }
+
+        let keys: String = self
+            .keys
+            .iter()
+            .chain(std::iter::once(&self.help_key))
+            .map(char::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+        backend.render_expand_prompt(self.message, &keys)?;
+
+        if self.expanded {
+            let choices = self
+                .labels
+                .iter()
+                .enumerate()
+                .map(|(i, label)| ListOption::new(i, label))
+                .collect::<Vec<ListOption<&String>>>();
+
+            let page_size = backend.fit_page_size(self.config.page_size);
+            let page = paginate(page_size, &choices, Some(self.cursor_index));
+
+            backend.render_expanded_options(page)?;
+        }
+
+        render_help_message(backend, self.help_message, self.help_message_fn, || {
+            self.state()
+        })?;
+
+        Ok(())
+    }
+}
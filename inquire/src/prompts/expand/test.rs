@@ -0,0 +1,64 @@
+use crate::{
+    terminal::TestBackend,
+    ui::{Key, KeyModifiers, RenderConfig},
+    Expand, InquireError,
+};
+
+fn hunk_prompt<'a>() -> Expand<'a, &'a str> {
+    Expand::new(
+        "Stage this hunk?",
+        vec![('y', "Yes"), ('n', "No"), ('q', "Quit")],
+    )
+    .with_render_config(RenderConfig::empty())
+}
+
+#[test]
+fn key_of_an_option_submits_it() {
+    let mut terminal = TestBackend::new(vec![]).with_text("n");
+
+    let ans = hunk_prompt().prompt_with_terminal(&mut terminal).unwrap();
+
+    assert_eq!("No", ans);
+    assert_eq!(
+        vec!["? Stage this hunk? (y,n,q,h)\n", "? Stage this hunk? No\n"],
+        terminal.frames()
+    );
+}
+
+#[test]
+fn help_key_expands_the_options() {
+    let mut terminal = TestBackend::new(vec![]).with_text("?");
+    terminal.push_key(Key::Down(KeyModifiers::NONE));
+    terminal.push_key(Key::Enter);
+
+    let ans = hunk_prompt()
+        .with_help_key('?')
+        .raw_prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(1, ans.index);
+    assert_eq!(
+        "? Stage this hunk? (y,n,q,?)\n> y) Yes\n  n) No\n  q) Quit\n",
+        terminal.frames()[1]
+    );
+}
+
+#[test]
+fn unknown_keys_are_rejected() {
+    let mut terminal = TestBackend::new(vec![Key::Enter]).with_text("xq");
+
+    let ans = hunk_prompt().prompt_with_terminal(&mut terminal).unwrap();
+
+    assert_eq!("Quit", ans);
+    assert!(terminal.frames()[1].contains("Press the key of an option, or h to list all options"));
+}
+
+#[test]
+fn keys_must_be_unique() {
+    let mut terminal = TestBackend::new(vec![]);
+
+    let ans = Expand::new("Continue?", vec![('y', "Yes"), ('h', "Help me")])
+        .prompt_with_terminal(&mut terminal);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
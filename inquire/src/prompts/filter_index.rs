@@ -0,0 +1,162 @@
+use std::cmp::Reverse;
+
+use crate::{
+    ansi::ansi_stripped,
+    type_aliases::{Filter, Scorer},
+};
+
+/// Function matching the options against the filter input.
+pub(crate) enum Matcher<'a, T> {
+    /// Keeps the matching options in their order.
+    Filter(Filter<'a, T>),
+    /// Orders the matching options by descending score, ties keeping their
+    /// original order.
+    Scorer(Scorer<'a, T>),
+}
+
+impl<'a, T> Matcher<'a, T> {
+    pub fn new(filter: Filter<'a, T>, scorer: Option<Scorer<'a, T>>) -> Self {
+        match scorer {
+            Some(scorer) => Self::Scorer(scorer),
+            None => Self::Filter(filter),
+        }
+    }
+}
+
+/// Filtering state of a list prompt, kept across key presses to avoid running
+/// the filter over every option each time the input changes.
+///
+/// When incremental, an input extending the previous one is only matched
+/// against the options that matched the previous input. Any other change, such
+/// as a deletion, matches all options again.
+///
+/// When caching lowercase values, the filter receives the input and the string
+/// values of the options already lowercased, the latter being computed once
+/// instead of on each key press.
+///
+/// When the string values of the options contain ANSI escape codes, the filter
+/// receives them without the codes, so that typing `m` does not match the
+/// `\x1b[31m` code of every red option.
+pub(crate) struct FilterIndex {
+    incremental: bool,
+    lowercase: bool,
+    ansi_labels: bool,
+    /// String values of the options as given to the filter, when they differ
+    /// from the displayed ones.
+    search_options: Option<Vec<String>>,
+    last_input: Option<String>,
+}
+
+impl FilterIndex {
+    pub fn new(
+        incremental: bool,
+        lowercase_cache: bool,
+        ansi_labels: bool,
+        string_options: &[String],
+    ) -> Self {
+        let mut index = Self {
+            incremental,
+            lowercase: lowercase_cache,
+            ansi_labels,
+            search_options: None,
+            last_input: None,
+        };
+        if lowercase_cache || ansi_labels {
+            let search_options = string_options
+                .iter()
+                .map(|s| index.search_text(s))
+                .collect();
+            index.search_options = Some(search_options);
+        }
+
+        index
+    }
+
+    fn search_text(&self, string_option: &str) -> String {
+        let text = match self.ansi_labels {
+            true => ansi_stripped(string_option),
+            false => string_option.into(),
+        };
+
+        match self.lowercase {
+            true => text.to_lowercase(),
+            false => text.into_owned(),
+        }
+    }
+
+    /// Registers the string value of an option appended to the list.
+    pub fn push(&mut self, string_option: &str) {
+        if self.search_options.is_some() {
+            let search_text = self.search_text(string_option);
+            if let Some(search_options) = &mut self.search_options {
+                search_options.push(search_text);
+            }
+        }
+        self.invalidate();
+    }
+
+    /// Forgets the last matched input, so that the next call to
+    /// [`matches`](Self::matches) goes through all options. To be called when
+    /// the result of the last call is not the current list of options anymore.
+    pub fn invalidate(&mut self) {
+        self.last_input = None;
+    }
+
+    /// Returns the indices of the options matching the input. Scored options
+    /// are ordered by descending score, and filtered ones are in the order of
+    /// `previous` when narrowing it, or of the options otherwise.
+    ///
+    /// `previous` must be the result of the last call, and `candidate` tells
+    /// whether an option can be matched at all, e.g. when quick filters are
+    /// active.
+    pub fn matches<T>(
+        &mut self,
+        input: &str,
+        options: &[T],
+        string_options: &[String],
+        matcher: Matcher<'_, T>,
+        previous: &[usize],
+        candidate: impl Fn(usize, &T) -> bool,
+    ) -> Vec<usize> {
+        let narrowing = self.incremental
+            && matches!(&self.last_input, Some(last) if input.starts_with(last.as_str()));
+        self.last_input = Some(input.to_owned());
+
+        let lowercase_input;
+        let input = match self.lowercase {
+            true => {
+                lowercase_input = input.to_lowercase();
+                lowercase_input.as_str()
+            }
+            false => input,
+        };
+        let string_options = match &self.search_options {
+            Some(search_options) => search_options.as_slice(),
+            None => string_options,
+        };
+
+        let indices: Box<dyn Iterator<Item = usize>> = match narrowing {
+            true => Box::new(previous.iter().copied()),
+            false => Box::new(0..options.len()),
+        };
+        let indices = indices.filter(|&i| candidate(i, &options[i]));
+
+        match matcher {
+            _ if input.is_empty() => indices.collect(),
+            Matcher::Filter(filter) => indices
+                .filter(|&i| filter(input, &options[i], &string_options[i], i))
+                .collect(),
+            Matcher::Scorer(scorer) => {
+                let mut scored: Vec<(Reverse<i64>, usize)> = indices
+                    .filter_map(|i| {
+                        scorer(input, &options[i], &string_options[i], i)
+                            .map(|score| (Reverse(score), i))
+                    })
+                    .collect();
+                scored.sort_unstable();
+
+                scored.into_iter().map(|(_, i)| i).collect()
+            }
+        }
+    }
+}
@@ -1,16 +1,25 @@
 mod action;
+mod background;
 mod confirm;
 mod custom_type;
 #[cfg(feature = "date")]
 mod dateselect;
 #[cfg(feature = "editor")]
 mod editor;
+mod expand;
+mod filter_index;
 mod multiselect;
 mod one_liners;
 mod password;
 mod prompt;
 mod select;
+mod state;
+mod table;
 mod text;
+#[cfg(feature = "date")]
+mod timeselect;
+mod toggle;
+mod treemultiselect;
 
 pub use action::*;
 pub use confirm::*;
@@ -19,9 +28,16 @@ pub use custom_type::*;
 pub use dateselect::*;
 #[cfg(feature = "editor")]
 pub use editor::*;
+pub use expand::*;
 pub use multiselect::*;
 #[cfg(feature = "one-liners")]
 pub use one_liners::*;
 pub use password::*;
 pub use select::*;
+pub use state::PromptState;
+pub use table::*;
 pub use text::*;
+#[cfg(feature = "date")]
+pub use timeselect::*;
+pub use toggle::*;
+pub use treemultiselect::*;
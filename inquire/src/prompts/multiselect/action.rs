@@ -28,6 +28,13 @@ pub enum MultiSelectPromptAction {
     SelectAll,
     /// Deselects all options.
     ClearSelections,
+    /// Toggles the quick filter at the given index.
+    ToggleQuickFilter(usize),
+    /// Sorts the options by the column at the given index, or reverses the
+    /// order if they are already sorted by it. In table mode only.
+    SortByColumn(usize),
+    /// Restores the original order of the options. In table mode only.
+    ClearSort,
 }
 
 impl InnerAction<MultiSelectConfig> for MultiSelectPromptAction {
@@ -46,13 +53,24 @@ impl InnerAction<MultiSelectConfig> for MultiSelectPromptAction {
 
         let action = match key {
             Key::Up(KeyModifiers::NONE) => Self::MoveUp,
-            Key::PageUp => Self::PageUp,
+            Key::PageUp(_) => Self::PageUp,
             Key::Home => Self::MoveToStart,
 
             Key::Down(KeyModifiers::NONE) => Self::MoveDown,
-            Key::PageDown => Self::PageDown,
+            Key::PageDown(_) => Self::PageDown,
             Key::End => Self::MoveToEnd,
 
+            Key::Char(c @ '1'..='9', KeyModifiers::NONE)
+                if (c as usize - '1' as usize) < config.quick_filter_count =>
+            {
+                Self::ToggleQuickFilter(c as usize - '1' as usize)
+            }
+            Key::Char('0', KeyModifiers::ALT) if config.table_columns > 0 => Self::ClearSort,
+            Key::Char(c @ '1'..='9', KeyModifiers::ALT)
+                if (c as usize - '1' as usize) < config.table_columns =>
+            {
+                Self::SortByColumn(c as usize - '1' as usize)
+            }
             Key::Char(' ', KeyModifiers::NONE) => Self::ToggleCurrentOption,
             Key::Right(KeyModifiers::NONE) => Self::SelectAll,
             Key::Left(KeyModifiers::NONE) => Self::ClearSelections,
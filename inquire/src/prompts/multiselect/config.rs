@@ -1,4 +1,6 @@
-use crate::MultiSelect;
+use std::time::Duration;
+
+use crate::{config::InterruptBehavior, prompts::prompt::Timeout, MultiSelect};
 
 /// Configuration settings used in the execution of a MultiSelectPrompt.
 #[derive(Copy, Clone, Debug)]
@@ -7,8 +9,30 @@ pub struct MultiSelectConfig {
     pub vim_mode: bool,
     /// Page size of the list of options.
     pub page_size: usize,
+    /// Number of options kept visible around the cursor, if any.
+    pub scroll_margin: Option<usize>,
+    /// Whether the cursor wraps around the ends of the list.
+    pub wrap_around: bool,
+    /// Whether the position of the cursor is rendered below the options.
+    pub position_indicator: bool,
+    /// Whether a summary of the checked options is rendered below them.
+    pub selection_summary: bool,
     /// Whether to keep the filter text when an option is selected.
     pub keep_filter: bool,
+    /// Whether the answer lists the options in the order they were checked.
+    pub selection_order_output: bool,
+    /// Number of quick filters toggled with the number keys.
+    pub quick_filter_count: usize,
+    /// Number of columns the options are laid out in, 0 when not in table mode.
+    pub table_columns: usize,
+    /// Whether the header row of the table is rendered above the options.
+    pub table_header: bool,
+    /// What happens when the user presses Ctrl+C.
+    pub interrupt_behavior: InterruptBehavior,
+    /// Timeout applied until the first key press.
+    pub timeout: Option<Timeout>,
+    /// Interval at which the prompt is re-rendered without key presses.
+    pub refresh_interval: Option<Duration>,
 }
 
 impl<T> From<&MultiSelect<'_, T>> for MultiSelectConfig {
@@ -16,7 +40,18 @@ impl<T> From<&MultiSelect<'_, T>> for MultiSelectConfig {
         Self {
             vim_mode: value.vim_mode,
             page_size: value.page_size,
+            scroll_margin: value.scroll_margin,
+            wrap_around: value.wrap_around,
+            position_indicator: value.position_indicator,
+            selection_summary: value.selection_summary,
             keep_filter: value.keep_filter,
+            selection_order_output: value.selection_order_output,
+            quick_filter_count: value.quick_filters.len(),
+            table_columns: value.columns.as_ref().map_or(0, |c| c.headers.len()),
+            table_header: value.table_header,
+            interrupt_behavior: value.interrupt_behavior,
+            timeout: Timeout::new(value.timeout, value.timeout_countdown),
+            refresh_interval: value.refresh_interval,
         }
     }
 }
@@ -7,27 +7,52 @@ mod test;
 
 pub use action::*;
 
-use std::fmt::Display;
+use std::{fmt::Display, sync::mpsc::Receiver, time::Duration};
 
 use crate::{
-    config::get_configuration,
+    config::{
+        get_configuration, get_event_listener, get_global_options, get_interrupt_behavior,
+        InterruptBehavior,
+    },
     error::{InquireError, InquireResult},
     formatter::MultiOptionFormatter,
+    handle::PromptHandle,
     list_option::ListOption,
-    prompts::prompt::Prompt,
-    terminal::get_default_terminal,
-    type_aliases::Filter,
-    ui::{Backend, MultiSelectBackend, RenderConfig},
+    prompts::{prompt::Prompt, TableColumns},
+    report::PromptReport,
+    terminal::{get_default_terminal, Terminal},
+    type_aliases::{Displayer, EventListener, Filter, Grouper, HelpMessageFn, Scorer},
+    ui::{Backend, Block, FinalRender, MultiSelectBackend, RenderConfig},
     validator::MultiOptionValidator,
 };
 
 use self::prompt::MultiSelectPrompt;
 
+/// Predefined filter of a [MultiSelect] prompt, toggled by the user with a number key.
+///
+/// Options are only displayed when they satisfy all active quick filters, in
+/// addition to the filter typed by the user.
+pub struct QuickFilter<'a, T> {
+    /// Label of the filter, rendered in the row of chips below the prompt.
+    pub label: &'a str,
+
+    /// Function that returns whether an option is displayed while the filter is active.
+    pub predicate: &'a dyn Fn(&T) -> bool,
+}
+
+impl<'a, T> Clone for QuickFilter<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for QuickFilter<'a, T> {}
+
 /// Prompt suitable for when you need the user to select many options (including none if applicable) among a list of them.
 ///
 /// The user can select (or deselect) the current highlighted option by pressing space, clean all selections by pressing the left arrow and select all options by pressing the right arrow.
 ///
-/// This prompt requires a prompt message and a **non-empty** `Vec` of options to be displayed to the user. The options can be of any type as long as they implement the `Display` trait. It is required that the `Vec` is moved to the prompt, as the prompt will return the ownership of the `Vec` after the user submits, with only the selected options inside it.
+/// This prompt requires a prompt message and a **non-empty** `Vec` of options to be displayed to the user. The options can be of any type as long as they implement the `Display` trait, or are rendered by a function given to [`new_with_display`](MultiSelect::new_with_display). It is required that the `Vec` is moved to the prompt, as the prompt will return the ownership of the `Vec` after the user submits, with only the selected options inside it.
 /// - If the list is empty, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
 ///
 /// The options are paginated in order to provide a smooth experience to the user, with the default page size being 7. The user can move from the options and the pages will be updated accordingly, including moving from the last to the first options (or vice-versa).
@@ -36,7 +61,7 @@ use self::prompt::MultiSelectPrompt;
 ///
 /// - **Prompt message**: Required when creating the prompt.
 /// - **Options list**: Options displayed to the user. Must be **non-empty**.
-/// - **Default selections**: Options that are selected by default when the prompt is first rendered. The user can unselect them. If any of the indices is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Default selections**: Options that are selected by default when the prompt is first rendered. The user can unselect them. They can be set by index or by value. If any of the indices is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
 /// - **Starting cursor**: Index of the cursor when the prompt is first rendered. Default is 0 (first option). If the index is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
 /// - **Help message**: Message displayed at the line below the prompt.
 /// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
@@ -47,6 +72,11 @@ use self::prompt::MultiSelectPrompt;
 /// - **Display option indexes**: On long lists, it might be helpful to display the indexes of the options to the user. Via the `RenderConfig`, you can set the display mode of the indexes as a prefix of an option. The default configuration is `None`, to not render any index when displaying the options.
 /// - **Filter function**: Function that defines if an option is displayed or not based on the current filter input.
 /// - **Keep filter flag**: Whether the current filter input should be cleared or not after a selection is made. Defaults to true.
+/// - **Selection summary**: Whether a single-line summary of the checked options, e.g. `3 selected: a, b, c`, is rendered below the options. Defaults to false.
+/// - **Group by**: Function returning the group of each option, rendering consecutive options of the same group under a header toggling all of them.
+/// - **Selection order output**: Whether the selected options are returned in the order in which the user checked them instead of the order of the list. Defaults to false.
+/// - **Quick filters**: Predefined filters rendered as a row of chips below the prompt, toggled with the number keys `1` to `9`. Active quick filters constrain the displayed options on top of the typed filter.
+/// - **Table columns**: Columns in which the options are laid out as a table, with an optional header row. The user can sort the rows by a column at runtime, see [`TableColumns`].
 ///
 /// # Example
 ///
@@ -62,14 +92,44 @@ pub struct MultiSelect<'a, T> {
     pub options: Vec<T>,
 
     /// Default indexes of options to be selected from the start.
-    pub default: Option<&'a [usize]>,
+    pub default: Option<Vec<usize>>,
 
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
+    /// Function computing the help message from the current state of the
+    /// prompt, replacing [`help_message`](Self::help_message) when set.
+    ///
+    /// See [`with_help_message_fn`](Self::with_help_message_fn) for more details.
+    pub help_message_fn: Option<HelpMessageFn<'a>>,
+
+    /// Content rendered above the prompt.
+    pub header: Option<Block>,
+
+    /// Content rendered below the prompt.
+    pub footer: Option<Block>,
+
     /// Page size of the options displayed to the user.
     pub page_size: usize,
 
+    /// Number of options kept visible between the highlighted option and the
+    /// edges of the page, if any.
+    ///
+    /// See [`with_scroll_margin`](Self::with_scroll_margin) for more details.
+    pub scroll_margin: Option<usize>,
+
+    /// Whether moving past the last option highlights the first one, and
+    /// vice-versa.
+    pub wrap_around: bool,
+
+    /// Whether the position of the highlighted option, e.g. `3/120`, is
+    /// rendered below the options.
+    pub position_indicator: bool,
+
+    /// Whether a summary of the checked options, e.g. `3 selected: a, b, c`,
+    /// is rendered below the options.
+    pub selection_summary: bool,
+
     /// Whether vim mode is enabled. When enabled, the user can
     /// navigate through the options using hjkl.
     pub vim_mode: bool,
@@ -77,21 +137,105 @@ pub struct MultiSelect<'a, T> {
     /// Starting cursor index of the selection.
     pub starting_cursor: usize,
 
+    /// Function that renders each option as the text displayed to the user,
+    /// also matched by the filter.
+    pub display: Displayer<'a, T>,
+
     /// Function called with the current user input to filter the provided
     /// options.
     pub filter: Filter<'a, T>,
 
+    /// Function called with the current user input to filter the provided
+    /// options and order them by relevance, used instead of the filter when set.
+    ///
+    /// See [`with_scorer`](Self::with_scorer) for more details.
+    pub scorer: Option<Scorer<'a, T>>,
+
+    /// Whether the options matching the previous input are the only ones
+    /// matched again when the user appends characters to it.
+    ///
+    /// See [`with_incremental_filtering`](Self::with_incremental_filtering) for more details.
+    pub incremental_filtering: bool,
+
+    /// Whether the filter is given the lowercased input and string values of
+    /// the options, the latter being computed once when the prompt starts.
+    ///
+    /// See [`with_lowercase_cache`](Self::with_lowercase_cache) for more details.
+    pub lowercase_cache: bool,
+
+    /// Message rendered in place of the options when the filter input matches
+    /// none of them, if any.
+    ///
+    /// See [`with_no_matches_message`](Self::with_no_matches_message) for more details.
+    pub no_matches_message: Option<&'a str>,
+
     /// Whether the current filter typed by the user is kept or cleaned after a selection is made.
     pub keep_filter: bool,
 
+    /// Whether the selected options are returned in the order in which the
+    /// user checked them, instead of the order of the list.
+    pub selection_order_output: bool,
+
+    /// Quick filters that the user can toggle with the number keys, in order.
+    pub quick_filters: Vec<QuickFilter<'a, T>>,
+
+    /// Columns in which the options are laid out as a table, if any.
+    pub columns: Option<TableColumns<'a, T>>,
+
+    /// Whether the header row of the table is rendered above the options, when
+    /// laid out as a table.
+    pub table_header: bool,
+
+    /// Function that assigns each option to a group. Consecutive options
+    /// in the same group are rendered below a header with the group name.
+    ///
+    /// See [`with_group_by`](Self::with_group_by) for more details.
+    pub group_by: Option<Grouper<'a, T>>,
+
+    /// Channel through which options are added while the prompt is open, if
+    /// any.
+    ///
+    /// See [`with_option_receiver`](Self::with_option_receiver) for more details.
+    pub option_receiver: Option<&'a Receiver<T>>,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
-    pub formatter: MultiOptionFormatter<'a, T>,
+    ///
+    /// When not set, the selected options are printed as they are displayed in
+    /// the list, separated by commas.
+    pub formatter: Option<MultiOptionFormatter<'a, T>>,
 
     /// Validator to apply to the user input.
     ///
     /// In case of error, the message is displayed one line above the prompt.
     pub validator: Option<Box<dyn MultiOptionValidator<T>>>,
 
+    /// What happens when the user presses Ctrl+C.
+    pub interrupt_behavior: InterruptBehavior,
+
+    /// Listener notified of the events of the prompt, such as key presses and
+    /// submissions.
+    ///
+    /// See [`with_event_listener`](Self::with_event_listener) for more details.
+    pub event_listener: Option<EventListener<'a>>,
+
+    /// Handle aborting the prompt from outside of it, if any.
+    ///
+    /// See [`with_handle`](Self::with_handle) for more details.
+    pub handle: Option<&'a PromptHandle>,
+
+    /// Time to wait for the user to start interacting with the prompt.
+    ///
+    /// See [`with_timeout`](Self::with_timeout) for more details.
+    pub timeout: Option<Duration>,
+
+    /// Whether to render the time left before the timeout expires.
+    pub timeout_countdown: bool,
+
+    /// Interval at which the prompt is re-rendered while no key is pressed.
+    ///
+    /// See [`with_refresh_interval`](Self::with_refresh_interval) for more details.
+    pub refresh_interval: Option<Duration>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -134,6 +278,17 @@ where
             .join(", ")
     };
 
+    /// Function used by default to render the options, which simply prints
+    /// their string value.
+    pub const DEFAULT_DISPLAY: Displayer<'a, T> = &|option| option.to_string();
+
+    /// Creates a [MultiSelect] with the provided message and options, along with default configuration values.
+    pub fn new(message: &'a str, options: Vec<T>) -> Self {
+        Self::new_with_display(message, options, Self::DEFAULT_DISPLAY)
+    }
+}
+
+impl<'a, T> MultiSelect<'a, T> {
     /// Default filter function, which checks if the current filter value is a substring of the option value.
     /// If it is, the option is displayed.
     ///
@@ -158,9 +313,9 @@ where
     /// assert_eq!(true,  filter("sa", &"San Jose",      "San Jose",     12));
     /// ```
     pub const DEFAULT_FILTER: Filter<'a, T> = &|filter, _, string_value, _| -> bool {
-        let filter = filter.to_lowercase();
+        let filter = crate::utils::to_lowercase(filter);
 
-        string_value.to_lowercase().contains(&filter)
+        crate::utils::to_lowercase(string_value).contains(filter.as_ref())
     };
 
     /// Default page size, equal to the global default page size [config::DEFAULT_PAGE_SIZE]
@@ -172,27 +327,93 @@ where
     /// Default starting cursor index.
     pub const DEFAULT_STARTING_CURSOR: usize = 0;
 
+    /// Default value of wrap around.
+    pub const DEFAULT_WRAP_AROUND: bool = true;
+
+    /// Default value of the position indicator.
+    pub const DEFAULT_POSITION_INDICATOR: bool = false;
+
+    /// Default value of the table header.
+    pub const DEFAULT_TABLE_HEADER: bool = true;
+
+    /// Default value of the selection summary.
+    pub const DEFAULT_SELECTION_SUMMARY: bool = false;
+
     /// Default behavior of keeping or cleaning the current filter value.
     pub const DEFAULT_KEEP_FILTER: bool = true;
 
+    /// Default value of the selection order output.
+    pub const DEFAULT_SELECTION_ORDER_OUTPUT: bool = false;
+
+    /// Default value of incremental filtering.
+    pub const DEFAULT_INCREMENTAL_FILTERING: bool = false;
+
+    /// Default value of the lowercase cache.
+    pub const DEFAULT_LOWERCASE_CACHE: bool = false;
+
     /// Default help message.
     pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
         Some("↑↓ to move, space to select one, → to all, ← to none, type to filter");
 
-    /// Creates a [MultiSelect] with the provided message and options, along with default configuration values.
-    pub fn new(message: &'a str, options: Vec<T>) -> Self {
+    /// Creates a [MultiSelect] over options that do not implement [`Display`],
+    /// each option being rendered by the given function instead.
+    ///
+    /// The rendered text is also the one matched by the filter and, unless a
+    /// formatter is set, printed as the final answer.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::PathBuf;
+    /// use inquire::MultiSelect;
+    ///
+    /// let paths = vec![PathBuf::from("Cargo.toml"), PathBuf::from("src/lib.rs")];
+    ///
+    /// let paths = MultiSelect::new_with_display("Files to stage:", paths, &|path| {
+    ///     path.display().to_string()
+    /// })
+    /// .prompt()?;
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn new_with_display(message: &'a str, options: Vec<T>, display: Displayer<'a, T>) -> Self {
+        let global = get_global_options();
+
         Self {
             message,
             options,
             default: None,
-            help_message: Self::DEFAULT_HELP_MESSAGE,
-            page_size: Self::DEFAULT_PAGE_SIZE,
-            vim_mode: Self::DEFAULT_VIM_MODE,
+            help_message: global.help_message(Self::DEFAULT_HELP_MESSAGE),
+            help_message_fn: None,
+            header: None,
+            footer: None,
+            page_size: global.page_size,
+            scroll_margin: None,
+            wrap_around: Self::DEFAULT_WRAP_AROUND,
+            position_indicator: Self::DEFAULT_POSITION_INDICATOR,
+            selection_summary: Self::DEFAULT_SELECTION_SUMMARY,
+            vim_mode: global.vim_mode,
             starting_cursor: Self::DEFAULT_STARTING_CURSOR,
             keep_filter: Self::DEFAULT_KEEP_FILTER,
+            selection_order_output: Self::DEFAULT_SELECTION_ORDER_OUTPUT,
+            display,
             filter: Self::DEFAULT_FILTER,
-            formatter: Self::DEFAULT_FORMATTER,
+            scorer: None,
+            incremental_filtering: Self::DEFAULT_INCREMENTAL_FILTERING,
+            lowercase_cache: Self::DEFAULT_LOWERCASE_CACHE,
+            no_matches_message: None,
+            quick_filters: vec![],
+            columns: None,
+            table_header: Self::DEFAULT_TABLE_HEADER,
+            group_by: None,
+            option_receiver: None,
+            formatter: None,
             validator: None,
+            interrupt_behavior: get_interrupt_behavior(),
+            event_listener: get_event_listener(),
+            handle: None,
+            timeout: None,
+            timeout_countdown: false,
+            refresh_interval: None,
             render_config: get_configuration(),
         }
     }
@@ -203,6 +424,30 @@ where
         self
     }
 
+    /// Sets a function computing the help message from the current state of the
+    /// prompt, called whenever it is rendered. It takes precedence over the
+    /// static help message, and returning `None` hides the help line.
+    ///
+    /// See [`PromptState`](crate::PromptState) for the state given to the function.
+    pub fn with_help_message_fn(mut self, help_message_fn: HelpMessageFn<'a>) -> Self {
+        self.help_message_fn = Some(help_message_fn);
+        self
+    }
+
+    /// Sets the content rendered above the prompt, such as the current git
+    /// branch. It is redrawn and cleared along with the prompt, see [`Block`].
+    pub fn with_header<H: Into<Block>>(mut self, header: H) -> Self {
+        self.header = Some(header.into());
+        self
+    }
+
+    /// Sets the content rendered below the prompt. It is redrawn and cleared
+    /// along with the prompt, see [`Block`].
+    pub fn with_footer<F: Into<Block>>(mut self, footer: F) -> Self {
+        self.footer = Some(footer.into());
+        self
+    }
+
     /// Removes the set help message.
     pub fn without_help_message(mut self) -> Self {
         self.help_message = None;
@@ -215,6 +460,59 @@ where
         self
     }
 
+    /// Sets the number of options kept visible between the highlighted option
+    /// and the edges of the page: the page only scrolls once the cursor gets
+    /// closer than that to them. The margin is capped to half of the page.
+    ///
+    /// When not set, the highlighted option is kept in the middle of the page.
+    pub fn with_scroll_margin(mut self, scroll_margin: usize) -> Self {
+        self.scroll_margin = Some(scroll_margin);
+        self
+    }
+
+    /// Sets whether moving past the last option highlights the first one, and
+    /// vice-versa. When disabled, the cursor stops at the ends of the list.
+    pub fn with_wrap_around(mut self, wrap_around: bool) -> Self {
+        self.wrap_around = wrap_around;
+        self
+    }
+
+    /// Sets whether the position of the highlighted option among the displayed
+    /// ones, e.g. `3/120`, is rendered below the options.
+    pub fn with_position_indicator(mut self, position_indicator: bool) -> Self {
+        self.position_indicator = position_indicator;
+        self
+    }
+
+    /// Sets whether a summary of the checked options, e.g. `3 selected: a, b, c`,
+    /// is rendered below the options while at least one is checked, so that
+    /// users scrolling a long list can always see what they picked. The summary
+    /// is collapsed into a single line, truncated with an ellipsis when it does
+    /// not fit the terminal, and styled with
+    /// [`RenderConfig::selection_summary`](crate::ui::RenderConfig::selection_summary).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inquire::{terminal::TestBackend, ui::RenderConfig, MultiSelect};
+    ///
+    /// let mut terminal = TestBackend::new(vec![]).with_text(" \n");
+    /// let fruits = MultiSelect::new("Fruits:", vec!["apple", "banana", "cherry"])
+    ///     .with_default(&[2])
+    ///     .with_selection_summary(true)
+    ///     .without_help_message()
+    ///     .with_render_config(RenderConfig::empty())
+    ///     .prompt_with_terminal(&mut terminal)?;
+    ///
+    /// assert_eq!(vec!["apple", "cherry"], fruits);
+    /// assert!(terminal.frames()[1].ends_with("\n2 selected: apple, cherry\n"));
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn with_selection_summary(mut self, selection_summary: bool) -> Self {
+        self.selection_summary = selection_summary;
+        self
+    }
+
     /// Enables or disables vim_mode.
     pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
         self.vim_mode = vim_mode;
@@ -227,15 +525,251 @@ where
         self
     }
 
+    /// Sets whether the selected options are returned in the order in which
+    /// the user checked them, e.g. to pick steps to run in order, instead of
+    /// the order of the list. Default selections come first, in the given
+    /// order, and selecting all the options checks them in the displayed order.
+    ///
+    /// The validator and the formatter are given the options in the same order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inquire::{terminal::TestBackend, ui::{Key, KeyModifiers}, MultiSelect};
+    ///
+    /// let down = Key::Down(KeyModifiers::NONE);
+    /// let mut terminal = TestBackend::new(vec![down, down]).with_text(" ");
+    /// terminal.push_key(Key::Up(KeyModifiers::NONE));
+    /// let mut terminal = terminal.with_text(" \n");
+    /// let steps = MultiSelect::new("Steps:", vec!["build", "test", "deploy"])
+    ///     .with_selection_order_output(true)
+    ///     .prompt_with_terminal(&mut terminal)?;
+    ///
+    /// assert_eq!(vec!["deploy", "test"], steps);
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn with_selection_order_output(mut self, selection_order_output: bool) -> Self {
+        self.selection_order_output = selection_order_output;
+        self
+    }
+
     /// Sets the filter function.
     pub fn with_filter(mut self, filter: Filter<'a, T>) -> Self {
         self.filter = filter;
         self
     }
 
+    /// Sets the scoring function, filtering the options like a filter function
+    /// and displaying the matching ones by descending score, see [`Scorer`].
+    /// It takes precedence over the filter function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inquire::{terminal::TestBackend, MultiSelect};
+    ///
+    /// let mut terminal = TestBackend::new(vec![]).with_text("de \n");
+    /// let ans = MultiSelect::new("Command:", vec!["undeploy", "describe", "deploy"])
+    ///     .with_scorer(&|input, _, value, _| {
+    ///         // Prefix matches first, shorter commands first.
+    ///         let position = value.find(input)? as i64;
+    ///         Some(-position * 100 - value.len() as i64)
+    ///     })
+    ///     .prompt_with_terminal(&mut terminal)?;
+    ///
+    /// assert_eq!(vec!["deploy"], ans);
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn with_scorer(mut self, scorer: Scorer<'a, T>) -> Self {
+        self.scorer = Some(scorer);
+        self
+    }
+
+    /// Enables or disables incremental filtering, which speeds up filtering
+    /// tens of thousands of options.
+    ///
+    /// When enabled, typing characters at the end of the filter input only
+    /// matches the options that matched the input before them, instead of all
+    /// the options. Any other change to the input, such as a deletion, matches
+    /// all the options again.
+    ///
+    /// This is only correct for filters that never match an option an input
+    /// does not match when given a longer input starting with it, which is the
+    /// case of the [default filter](Self::DEFAULT_FILTER) and of most
+    /// substring or prefix matching filters.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::MultiSelect;
+    ///
+    /// let words: Vec<String> = (0..50_000).map(|i| format!("word-{}", i)).collect();
+    ///
+    /// let words = MultiSelect::new("Words:", words)
+    ///     .with_incremental_filtering(true)
+    ///     .with_lowercase_cache(true)
+    ///     .prompt()?;
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn with_incremental_filtering(mut self, incremental_filtering: bool) -> Self {
+        self.incremental_filtering = incremental_filtering;
+        self
+    }
+
+    /// Enables or disables the lowercase cache, computing the lowercased
+    /// string values of the options once when the prompt starts instead of
+    /// lowercasing them each time the filter input changes.
+    ///
+    /// When enabled, the filter is given the lowercased filter input and string
+    /// value of each option, so it can match them as they are. Options still
+    /// keep their original case when displayed.
+    pub fn with_lowercase_cache(mut self, lowercase_cache: bool) -> Self {
+        self.lowercase_cache = lowercase_cache;
+        self
+    }
+
+    /// Sets the message rendered in place of the options when the filter input
+    /// matches none of them, instead of rendering nothing. Occurrences of
+    /// `{filter}` in the message are replaced by the filter input.
+    ///
+    /// The message is styled with
+    /// [`RenderConfig::no_matches_message`](crate::ui::RenderConfig::no_matches_message).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inquire::{terminal::TestBackend, ui::RenderConfig, MultiSelect};
+    ///
+    /// let mut terminal = TestBackend::new(vec![]).with_text("qa");
+    /// let ans = MultiSelect::new("Environment:", vec!["staging", "production"])
+    ///     .with_no_matches_message("No environments match '{filter}'")
+    ///     .without_help_message()
+    ///     .with_render_config(RenderConfig::empty())
+    ///     .prompt_with_terminal(&mut terminal);
+    ///
+    /// assert_eq!(
+    ///     "? Environment: qa \nNo environments match 'qa'\n",
+    ///     terminal.last_frame().unwrap()
+    /// );
+    /// # let _ = ans;
+    /// ```
+    pub fn with_no_matches_message(mut self, message: &'a str) -> Self {
+        self.no_matches_message = Some(message);
+        self
+    }
+
+    /// Sets the function used to group options. Consecutive options in the
+    /// same group are rendered indented below a header with the group name,
+    /// so options are expected to be already sorted by group.
+    ///
+    /// Group headers can be highlighted like options: toggling one checks all
+    /// the displayed options of the group, or unchecks them if they are all
+    /// checked already. Their checkbox is rendered as
+    /// [partially selected](crate::ui::RenderConfig::partially_selected_checkbox)
+    /// when only some of them are checked.
+    ///
+    /// The cursor starts on the first option, below its group header. Options
+    /// can not be both grouped and laid out as a table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inquire::{terminal::TestBackend, ui::{Key, KeyModifiers, RenderConfig}, MultiSelect};
+    ///
+    /// let mut terminal = TestBackend::new(vec![Key::Up(KeyModifiers::NONE)]).with_text(" \n");
+    /// let food = MultiSelect::new("Food:", vec!["fruits/apple", "fruits/kiwi", "vegetables/leek"])
+    ///     .with_group_by(&|option| option.split('/').next().unwrap_or_default().to_string())
+    ///     .without_help_message()
+    ///     .with_render_config(RenderConfig::empty())
+    ///     .prompt_with_terminal(&mut terminal)?;
+    ///
+    /// assert_eq!(vec!["fruits/apple", "fruits/kiwi"], food);
+    /// assert_eq!(
+    ///     "? Food:  \n  [ ] fruits\n>   [ ] fruits/apple\n    [ ] fruits/kiwi\n  [ ] vegetables\n    [ ] vegetables/leek\n",
+    ///     terminal.frames()[0]
+    /// );
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn with_group_by(mut self, group_by: Grouper<'a, T>) -> Self {
+        self.group_by = Some(group_by);
+        self
+    }
+
+    /// Adds a quick filter, toggled with the number key matching its position,
+    /// starting from `1`.
+    ///
+    /// Up to 9 quick filters are supported. While quick filters are set, their
+    /// number keys toggle them instead of being typed into the filter input.
+    pub fn with_quick_filter(mut self, label: &'a str, predicate: &'a dyn Fn(&T) -> bool) -> Self {
+        self.quick_filters.push(QuickFilter { label, predicate });
+        self
+    }
+
+    /// Lays out the options as a table with the given column titles, each option
+    /// split into one cell per column by the `cells` function.
+    ///
+    /// The user can sort the rows by the n-th column by pressing Alt+n, and
+    /// restore the original order by pressing Alt+0. See [`TableColumns`].
+    ///
+    /// The filter input is matched against the cells of each option, separated
+    /// by spaces, instead of its string value.
+    pub fn with_columns(
+        mut self,
+        headers: Vec<&'a str>,
+        cells: &'a dyn Fn(&T) -> Vec<String>,
+    ) -> Self {
+        self.columns = Some(TableColumns { headers, cells });
+        self
+    }
+
+    /// Sets whether the header row of the table, with the column titles and
+    /// the sort indicator, is rendered above the options when they are laid
+    /// out as a table. The rows can still be sorted without it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inquire::{terminal::TestBackend, ui::RenderConfig, MultiSelect};
+    ///
+    /// let pods = vec![("api-7d9f", "Running", 3), ("worker-5c2a", "Pending", 0)];
+    ///
+    /// let mut terminal = TestBackend::new(vec![]).with_text("\n");
+    /// MultiSelect::new_with_display("Pods:", pods, &|(name, _, _)| name.to_string())
+    ///     .with_columns(vec!["NAME", "STATUS", "RESTARTS"], &|(name, status, restarts)| {
+    ///         vec![name.to_string(), status.to_string(), restarts.to_string()]
+    ///     })
+    ///     .with_table_header(false)
+    ///     .without_help_message()
+    ///     .with_render_config(RenderConfig::empty())
+    ///     .raw_prompt_with_terminal(&mut terminal)?;
+    ///
+    /// assert!(!terminal.frames()[0].contains("NAME"));
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn with_table_header(mut self, table_header: bool) -> Self {
+        self.table_header = table_header;
+        self
+    }
+
+    /// Sets a channel through which options are added while the prompt is
+    /// open, such as results streaming in from a network scan. Received options
+    /// are appended to the list, keeping the filter input, the highlighted
+    /// option and the checked ones.
+    ///
+    /// The list of options can then start empty. Options can not be received
+    /// when laid out as a table.
+    ///
+    /// Options are received after each key press, and while the user is idle
+    /// with terminals able to wait for keys with a timeout, such as the
+    /// crossterm or termion ones.
+    pub fn with_option_receiver(mut self, receiver: &'a Receiver<T>) -> Self {
+        self.option_receiver = Some(receiver);
+        self
+    }
+
     /// Sets the formatter.
     pub fn with_formatter(mut self, formatter: MultiOptionFormatter<'a, T>) -> Self {
-        self.formatter = formatter;
+        self.formatter = Some(formatter);
         self
     }
 
@@ -254,6 +788,35 @@ where
 
     /// Sets the indexes to be selected by the default.
     pub fn with_default(mut self, default: &'a [usize]) -> Self {
+        self.default = Some(default.to_vec());
+        self
+    }
+
+    /// Sets the options to be selected by default, matching them by value
+    /// against the options currently set in the prompt.
+    ///
+    /// Values that are not present in the options list are ignored.
+    pub fn with_default_values(self, values: &[T]) -> Self
+    where
+        T: PartialEq,
+    {
+        self.with_default_by(|option| values.contains(option))
+    }
+
+    /// Sets the options to be selected by default, selecting all options
+    /// currently set in the prompt for which the predicate returns `true`.
+    pub fn with_default_by<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&T) -> bool,
+    {
+        let default = self
+            .options
+            .iter()
+            .enumerate()
+            .filter(|(_, option)| predicate(option))
+            .map(|(idx, _)| idx)
+            .collect();
+
         self.default = Some(default);
         self
     }
@@ -264,6 +827,62 @@ where
         self
     }
 
+    /// Sets what happens when the user presses Ctrl+C, overriding the global
+    /// setting.
+    pub fn with_interrupt_behavior(mut self, interrupt_behavior: InterruptBehavior) -> Self {
+        self.interrupt_behavior = interrupt_behavior;
+        self
+    }
+
+    /// Sets a listener notified of the events of the prompt, such as renders,
+    /// key presses and submissions, overriding the global listener.
+    ///
+    /// See the [`events`](crate::events) module for more details.
+    pub fn with_event_listener(mut self, listener: EventListener<'a>) -> Self {
+        self.event_listener = Some(listener);
+        self
+    }
+
+    /// Sets a handle aborting the prompt from outside of it, e.g. from another
+    /// thread or a Ctrl+C handler, canceling it as if the user pressed ESC.
+    ///
+    /// See the [`handle`](crate::handle) module for more details.
+    pub fn with_handle(mut self, handle: &'a PromptHandle) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets a timeout for the user to start interacting with the prompt.
+    ///
+    /// If no key is pressed before it expires, the current selection is submitted, or
+    /// [`InquireError::Timeout`] is returned when it does not pass validation.
+    /// Once a key is pressed, the prompt waits for the user indefinitely.
+    ///
+    /// Timeouts are supported by the `crossterm` and `termion` backends, the
+    /// `console` backend always waits for the first key press.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether to render the time left before the timeout expires below the prompt.
+    pub fn with_timeout_countdown(mut self, countdown: bool) -> Self {
+        self.timeout_countdown = countdown;
+        self
+    }
+
+    /// Sets an interval at which the prompt is re-rendered even when no key is
+    /// pressed, e.g. for content computed at render time, such as a dynamic
+    /// help message showing the current time, to stay up to date.
+    ///
+    /// Requires a terminal able to wait for keys with a timeout, such as the
+    /// crossterm or termion ones. Other terminals only re-render the prompt
+    /// after key presses.
+    pub fn with_refresh_interval(mut self, interval: Duration) -> Self {
+        self.refresh_interval = Some(interval);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -277,6 +896,36 @@ where
         self
     }
 
+    /// Sets how the prompt is rendered once answered, e.g. [`FinalRender::None`]
+    /// for the prompt to disappear. This is a shorthand for setting the
+    /// [`final_render`](RenderConfig::final_render) of the render config, so it
+    /// must be called after [`with_render_config`](Self::with_render_config).
+    pub fn with_final_render(mut self, final_render: FinalRender) -> Self {
+        self.render_config.final_render = final_render;
+        self
+    }
+
+    /// Sets whether the prompt is rendered in fullscreen, on the alternate
+    /// screen buffer, leaving only its final rendering in the original screen.
+    /// This is a shorthand for setting the
+    /// [`fullscreen`](RenderConfig::fullscreen) flag of the render config, so
+    /// it must be called after [`with_render_config`](Self::with_render_config).
+    pub fn with_fullscreen(mut self, fullscreen: bool) -> Self {
+        self.render_config.fullscreen = fullscreen;
+        self
+    }
+
+    /// Sets whether the string values of the options may contain ANSI escape
+    /// codes, e.g. colored with `owo-colors`. The codes are passed through to
+    /// the terminal, while left out when measuring and filtering the options.
+    /// This is a shorthand for setting the
+    /// [`ansi_labels`](RenderConfig::ansi_labels) flag of the render config,
+    /// so it must be called after [`with_render_config`](Self::with_render_config).
+    pub fn with_ansi_labels(mut self, ansi_labels: bool) -> Self {
+        self.render_config.ansi_labels = ansi_labels;
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -305,6 +954,13 @@ where
             .map(|op| op.into_iter().map(|o| o.value).collect())
     }
 
+    /// Prompts the user through the given terminal instead of the default
+    /// terminal of the enabled backend feature, see [`Terminal`].
+    pub fn prompt_with_terminal<Term: Terminal>(self, terminal: Term) -> InquireResult<Vec<T>> {
+        self.raw_prompt_with_terminal(terminal)
+            .map(|op| op.into_iter().map(|o| o.value).collect())
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -331,11 +987,46 @@ where
     /// Returns a [`ListOption`](crate::list_option::ListOption) containing
     /// the index of the selection and the owned object selected by the user.
     pub fn raw_prompt(self) -> InquireResult<Vec<ListOption<T>>> {
-        let terminal = get_default_terminal()?;
+        self.raw_prompt_with_terminal(get_default_terminal()?)
+    }
+
+    /// Prompts the user through the given terminal instead of the default
+    /// terminal of the enabled backend feature, see [`Terminal`].
+    ///
+    /// Returns the same as [`raw_prompt`](Self::raw_prompt).
+    pub fn raw_prompt_with_terminal<Term: Terminal>(
+        self,
+        terminal: Term,
+    ) -> InquireResult<Vec<ListOption<T>>> {
         let mut backend = Backend::new(terminal, self.render_config)?;
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Same as [`prompt`](Self::prompt), also returning a [`PromptReport`]
+    /// describing how the user interacted with the prompt.
+    pub fn prompt_with_report(self) -> InquireResult<(Vec<T>, PromptReport)> {
+        self.prompt_with_report_and_terminal(get_default_terminal()?)
+    }
+
+    /// Same as [`prompt_with_terminal`](Self::prompt_with_terminal), also
+    /// returning a [`PromptReport`] describing how the user interacted with
+    /// the prompt.
+    pub fn prompt_with_report_and_terminal<Term: Terminal>(
+        self,
+        terminal: Term,
+    ) -> InquireResult<(Vec<T>, PromptReport)> {
+        self.raw_prompt_with_report(terminal)
+            .map(|(answer, report)| (answer.into_iter().map(|o| o.value).collect(), report))
+    }
+
+    fn raw_prompt_with_report<Term: Terminal>(
+        self,
+        terminal: Term,
+    ) -> InquireResult<(Vec<ListOption<T>>, PromptReport)> {
+        let mut backend = Backend::new(terminal, self.render_config)?;
+        MultiSelectPrompt::new(self)?.prompt_with_report(&mut backend)
+    }
+
     pub(crate) fn prompt_with_backend<B: MultiSelectBackend>(
         self,
         backend: &mut B,
@@ -343,3 +1034,48 @@ where
         MultiSelectPrompt::new(self)?.prompt(backend)
     }
 }
+
+impl<'a, K, V> MultiSelect<'a, (K, V)>
+where
+    V: Display,
+{
+    const MAP_DISPLAY: Displayer<'a, (K, V)> = &|(_, value)| value.to_string();
+
+    /// Creates a [MultiSelect] over the entries of a map, such as a `BTreeMap`
+    /// or an `IndexMap`, or any other sequence of key-value pairs. The values
+    /// are displayed to the user, in iteration order, and the selected entries
+    /// are returned, their keys alone with [`prompt_keys`](Self::prompt_keys).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::collections::BTreeMap;
+    /// use inquire::MultiSelect;
+    ///
+    /// let features = BTreeMap::from([("date", "Date prompts"), ("editor", "Editor prompt")]);
+    ///
+    /// let features: Vec<&str> = MultiSelect::from_map("Features:", features).prompt_keys()?;
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn from_map<M>(message: &'a str, map: M) -> Self
+    where
+        M: IntoIterator<Item = (K, V)>,
+    {
+        Self::new_with_display(message, map.into_iter().collect(), Self::MAP_DISPLAY)
+    }
+}
+
+impl<'a, K, V> MultiSelect<'a, (K, V)> {
+    /// Prompts the user to select entries, returning their keys.
+    pub fn prompt_keys(self) -> InquireResult<Vec<K>> {
+        self.prompt()
+            .map(|answer| answer.into_iter().map(|(key, _)| key).collect())
+    }
+
+    /// Prompts the user to select entries, returning their keys, or `None` if
+    /// the user cancels the prompt. See [`prompt_skippable`](Self::prompt_skippable).
+    pub fn prompt_keys_skippable(self) -> InquireResult<Option<Vec<K>>> {
+        self.prompt_skippable()
+            .map(|answer| answer.map(|answer| answer.into_iter().map(|(key, _)| key).collect()))
+    }
+}
@@ -1,47 +1,93 @@
-use std::{collections::BTreeSet, fmt::Display};
+use std::{cell::Cell, collections::BTreeSet, fmt::Display, sync::mpsc::Receiver, time::Duration};
 
 use crate::{
+    config::InterruptBehavior,
     error::InquireResult,
     formatter::MultiOptionFormatter,
+    handle::PromptHandle,
     input::{Input, InputActionResult},
     list_option::ListOption,
-    prompts::prompt::{ActionResult, Prompt},
-    type_aliases::Filter,
-    ui::MultiSelectBackend,
-    utils::paginate,
+    prompts::{
+        filter_index::{FilterIndex, Matcher},
+        prompt::{ActionResult, Prompt, Timeout},
+        state::{render_help_message, PromptState},
+        table::Table,
+    },
+    type_aliases::{Displayer, EventListener, Filter, Grouper, HelpMessageFn, Scorer},
+    ui::{Block, CheckState, CheckboxRow, MultiSelectBackend},
+    utils::{paginate, paginate_with_margin, Page},
     validator::{ErrorMessage, MultiOptionValidator, Validation},
-    InquireError, MultiSelect,
+    InquireError, MultiSelect, QuickFilter,
 };
 
 use super::{action::MultiSelectPromptAction, config::MultiSelectConfig};
 
+/// Row of the displayed list, a group header or an option.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Row {
+    /// Header of the group of the option at the given index, followed by the
+    /// rows of the options of the group.
+    Group(usize),
+    Option(usize),
+}
+
 pub struct MultiSelectPrompt<'a, T> {
     message: &'a str,
     config: MultiSelectConfig,
+    event_listener: Option<EventListener<'a>>,
+    handle: Option<&'a PromptHandle>,
     options: Vec<T>,
     string_options: Vec<String>,
     help_message: Option<&'a str>,
+    help_message_fn: Option<HelpMessageFn<'a>>,
+    header: Option<Block>,
+    footer: Option<Block>,
     cursor_index: usize,
+    page_start: Cell<usize>,
     checked: BTreeSet<usize>,
+    checked_order: Vec<usize>,
     input: Input,
     filtered_options: Vec<usize>,
+    rows: Vec<Row>,
+    group_by: Option<Grouper<'a, T>>,
+    groups: Vec<String>,
+    option_receiver: Option<&'a Receiver<T>>,
+    display: Displayer<'a, T>,
     filter: Filter<'a, T>,
-    formatter: MultiOptionFormatter<'a, T>,
+    scorer: Option<Scorer<'a, T>>,
+    no_matches_message: Option<&'a str>,
+    filter_index: FilterIndex,
+    quick_filters: Vec<QuickFilter<'a, T>>,
+    active_quick_filters: BTreeSet<usize>,
+    table: Option<Table<'a>>,
+    formatter: Option<MultiOptionFormatter<'a, T>>,
     validator: Option<Box<dyn MultiOptionValidator<T>>>,
     error: Option<ErrorMessage>,
 }
 
-impl<'a, T> MultiSelectPrompt<'a, T>
-where
-    T: Display,
-{
+impl<'a, T> MultiSelectPrompt<'a, T> {
     pub fn new(mso: MultiSelect<'a, T>) -> InquireResult<Self> {
-        if mso.options.is_empty() {
+        if mso.options.is_empty() && mso.option_receiver.is_none() {
             return Err(InquireError::InvalidConfiguration(
                 "Available options can not be empty".into(),
             ));
         }
-        if let Some(default) = mso.default {
+        if mso.option_receiver.is_some() && mso.columns.is_some() {
+            return Err(InquireError::InvalidConfiguration(
+                "Options laid out as a table can not be received while the prompt is open".into(),
+            ));
+        }
+        if mso.group_by.is_some() && mso.columns.is_some() {
+            return Err(InquireError::InvalidConfiguration(
+                "Options laid out as a table can not be grouped".into(),
+            ));
+        }
+        if mso.quick_filters.len() > 9 {
+            return Err(InquireError::InvalidConfiguration(
+                "At most 9 quick filters are supported".into(),
+            ));
+        }
+        if let Some(default) = &mso.default {
             for i in default {
                 if i >= &mso.options.len() {
                     return Err(InquireError::InvalidConfiguration(format!(
@@ -53,39 +99,229 @@ where
             }
         }
 
-        let string_options = mso.options.iter().map(T::to_string).collect();
+        let table = match &mso.columns {
+            Some(columns) => Some(Table::new(columns, &mso.options)?),
+            None => None,
+        };
+        let string_options: Vec<String> = match &table {
+            Some(table) => (0..mso.options.len())
+                .map(|i| table.search_text(i))
+                .collect(),
+            None => mso.options.iter().map(mso.display).collect(),
+        };
         let filtered_options = (0..mso.options.len()).collect();
-        let checked_options = mso
-            .default
-            .map_or_else(BTreeSet::new, |d| d.iter().cloned().collect());
+        let filter_index = FilterIndex::new(
+            mso.incremental_filtering,
+            mso.lowercase_cache,
+            mso.render_config.ansi_labels,
+            &string_options,
+        );
+        let groups = match mso.group_by {
+            Some(group_by) => mso.options.iter().map(group_by).collect(),
+            None => vec![],
+        };
+        let mut checked_options = BTreeSet::new();
+        let mut checked_order = vec![];
+        for &i in mso.default.iter().flatten() {
+            if checked_options.insert(i) {
+                checked_order.push(i);
+            }
+        }
 
-        Ok(Self {
+        let mut prompt = Self {
             message: mso.message,
             config: (&mso).into(),
+            event_listener: mso.event_listener,
+            handle: mso.handle,
             options: mso.options,
             string_options,
             filtered_options,
+            rows: vec![],
+            group_by: mso.group_by,
+            groups,
+            option_receiver: mso.option_receiver,
             help_message: mso.help_message,
+            help_message_fn: mso.help_message_fn,
+            header: mso.header,
+            footer: mso.footer,
             cursor_index: mso.starting_cursor,
+            page_start: Cell::new(0),
             input: Input::new(),
+            display: mso.display,
             filter: mso.filter,
+            scorer: mso.scorer,
+            no_matches_message: mso.no_matches_message,
+            filter_index,
+            quick_filters: mso.quick_filters,
+            active_quick_filters: BTreeSet::new(),
+            table,
             formatter: mso.formatter,
             validator: mso.validator,
             error: None,
             checked: checked_options,
+            checked_order,
+        };
+
+        prompt.compute_rows();
+        if let Some(position) = prompt.position_of(Row::Option(mso.starting_cursor)) {
+            prompt.cursor_index = position;
+        }
+
+        Ok(prompt)
+    }
+
+    /// Lays out the filtered options in rows, adding a group header before
+    /// each run of consecutive options of the same group.
+    fn compute_rows(&mut self) {
+        self.rows.clear();
+        let mut last_group = None;
+
+        for &i in &self.filtered_options {
+            if let Some(group) = self.groups.get(i) {
+                if last_group != Some(group) {
+                    self.rows.push(Row::Group(i));
+                    last_group = Some(group);
+                }
+            }
+            self.rows.push(Row::Option(i));
+        }
+    }
+
+    fn position_of(&self, row: Row) -> Option<usize> {
+        self.rows.iter().position(|r| *r == row)
+    }
+
+    /// Moves the cursor back to the given row, if it is still displayed.
+    fn restore_highlighted_row(&mut self, row: Option<Row>) {
+        if let Some(position) = row.and_then(|row| self.position_of(row)) {
+            self.cursor_index = position;
+        }
+    }
+
+    /// Indices of the options of the group whose header is at the given row.
+    fn group_members(&self, header: usize) -> impl Iterator<Item = usize> + '_ {
+        self.rows[header + 1..].iter().map_while(|row| match row {
+            Row::Option(i) => Some(*i),
+            Row::Group(_) => None,
         })
     }
 
-    fn filter_options(&self) -> Vec<usize> {
-        self.options
-            .iter()
-            .enumerate()
-            .filter_map(|(i, opt)| match self.input.content() {
-                val if val.is_empty() => Some(i),
-                val if (self.filter)(val, opt, self.string_options.get(i).unwrap(), i) => Some(i),
-                _ => None,
-            })
-            .collect()
+    fn check_state(&self, position: usize) -> CheckState {
+        match self.rows[position] {
+            Row::Option(i) if self.checked.contains(&i) => CheckState::Checked,
+            Row::Option(_) => CheckState::Unchecked,
+            Row::Group(_) => {
+                let (checked, total) =
+                    self.group_members(position)
+                        .fold((0, 0), |(checked, total), i| {
+                            (checked + usize::from(self.checked.contains(&i)), total + 1)
+                        });
+
+                match checked {
+                    0 => CheckState::Unchecked,
+                    checked if checked == total => CheckState::Checked,
+                    _ => CheckState::Partial,
+                }
+            }
+        }
+    }
+
+    /// Appends the options received since the last call, keeping the
+    /// highlighted option. Returns whether any option was received.
+    fn receive_options(&mut self) -> bool {
+        let received: Vec<T> = match self.option_receiver {
+            Some(receiver) => receiver.try_iter().collect(),
+            None => return false,
+        };
+
+        if received.is_empty() {
+            return false;
+        }
+
+        let highlighted = self.rows.get(self.cursor_index).copied();
+
+        for option in received {
+            let string_option = (self.display)(&option);
+            self.filter_index.push(&string_option);
+            self.string_options.push(string_option);
+            if let Some(group_by) = self.group_by {
+                self.groups.push(group_by(&option));
+            }
+            self.options.push(option);
+        }
+
+        self.filtered_options = self.filter_options();
+        self.compute_rows();
+        self.restore_highlighted_row(highlighted);
+
+        true
+    }
+
+    fn filter_options(&mut self) -> Vec<usize> {
+        let active_quick_filters = &self.active_quick_filters;
+        let quick_filters = &self.quick_filters;
+
+        let mut options = self.filter_index.matches(
+            self.input.content(),
+            &self.options,
+            &self.string_options,
+            Matcher::new(self.filter, self.scorer),
+            &self.filtered_options,
+            |_, opt| {
+                active_quick_filters
+                    .iter()
+                    .all(|&f| (quick_filters[f].predicate)(opt))
+            },
+        );
+
+        if let Some(table) = &self.table {
+            if self.scorer.is_none() || table.is_sorted() {
+                table.sort(&mut options);
+            }
+        }
+
+        options
+    }
+
+    /// Sorts the options by the given column, keeping the cursor on the
+    /// highlighted option.
+    fn sort_by_column(&mut self, column: Option<usize>) -> ActionResult {
+        let table = match &mut self.table {
+            Some(table) => table,
+            None => return ActionResult::Clean,
+        };
+
+        let highlighted = self.rows.get(self.cursor_index).copied();
+        table.sort_by(column);
+        match self.scorer.is_some() && !table.is_sorted() {
+            true => {
+                self.filter_index.invalidate();
+                self.filtered_options = self.filter_options();
+            }
+            false => table.sort(&mut self.filtered_options),
+        }
+        self.compute_rows();
+        self.restore_highlighted_row(highlighted);
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn refresh_filtered_options(&mut self) {
+        self.filtered_options = self.filter_options();
+        self.compute_rows();
+        if self.rows.len() <= self.cursor_index {
+            let _ = self.update_cursor_position(self.rows.len().saturating_sub(1));
+        }
+    }
+
+    fn toggle_quick_filter(&mut self, idx: usize) -> ActionResult {
+        if !self.active_quick_filters.remove(&idx) {
+            self.active_quick_filters.insert(idx);
+        }
+        self.filter_index.invalidate();
+        self.refresh_filtered_options();
+
+        ActionResult::NeedsRedraw
     }
 
     fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
@@ -93,7 +329,7 @@ where
             let after_wrap = qty.saturating_sub(self.cursor_index);
             self.cursor_index
                 .checked_sub(qty)
-                .unwrap_or_else(|| self.filtered_options.len().saturating_sub(after_wrap))
+                .unwrap_or_else(|| self.rows.len().saturating_sub(after_wrap))
         } else {
             self.cursor_index.saturating_sub(qty)
         };
@@ -104,19 +340,42 @@ where
     fn move_cursor_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
         let mut new_position = self.cursor_index.saturating_add(qty);
 
-        if new_position >= self.filtered_options.len() {
-            new_position = if self.filtered_options.is_empty() {
+        if new_position >= self.rows.len() {
+            new_position = if self.rows.is_empty() {
                 0
             } else if wrap {
-                new_position % self.filtered_options.len()
+                new_position % self.rows.len()
             } else {
-                self.filtered_options.len().saturating_sub(1)
+                self.rows.len().saturating_sub(1)
             }
         }
 
         self.update_cursor_position(new_position)
     }
 
+    /// Paginates the displayed options, scrolling the page rendered in the
+    /// previous frame only as needed when a scroll margin is set.
+    fn paginate<'c, D>(&self, page_size: usize, choices: &'c [D]) -> Page<'c, D> {
+        let margin = match self.config.scroll_margin {
+            Some(margin) => margin,
+            None => return paginate(page_size, choices, Some(self.cursor_index)),
+        };
+
+        let page = paginate_with_margin(
+            page_size,
+            choices,
+            self.cursor_index,
+            self.page_start.get(),
+            margin,
+        );
+        let start = page
+            .cursor
+            .map_or(0, |cursor| self.cursor_index.saturating_sub(cursor));
+        self.page_start.set(start);
+
+        page
+    }
+
     fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
         if new_position != self.cursor_index {
             self.cursor_index = new_position;
@@ -127,15 +386,24 @@ where
     }
 
     fn toggle_cursor_selection(&mut self) -> ActionResult {
-        let idx = match self.filtered_options.get(self.cursor_index) {
-            Some(val) => val,
+        let members: Vec<usize> = match self.rows.get(self.cursor_index) {
+            Some(Row::Option(idx)) => vec![*idx],
+            Some(Row::Group(_)) => self.group_members(self.cursor_index).collect(),
             None => return ActionResult::Clean,
         };
 
-        if self.checked.contains(idx) {
-            self.checked.remove(idx);
+        // a group is unchecked only when all of its options are checked
+        if members.iter().all(|idx| self.checked.contains(idx)) {
+            for idx in &members {
+                self.checked.remove(idx);
+            }
+            self.checked_order.retain(|i| !members.contains(i));
         } else {
-            self.checked.insert(*idx);
+            for idx in members {
+                if self.checked.insert(idx) {
+                    self.checked_order.push(idx);
+                }
+            }
         }
 
         if !self.config.keep_filter {
@@ -145,16 +413,20 @@ where
         ActionResult::NeedsRedraw
     }
 
+    /// Indices of the checked options, in the order of the answer.
+    fn answer_indices(&self) -> Vec<usize> {
+        match self.config.selection_order_output {
+            true => self.checked_order.clone(),
+            false => self.checked.iter().copied().collect(),
+        }
+    }
+
     fn validate_current_answer(&self) -> InquireResult<Validation> {
         if let Some(validator) = &self.validator {
             let selected_options = self
-                .options
-                .iter()
-                .enumerate()
-                .filter_map(|(idx, opt)| match &self.checked.contains(&idx) {
-                    true => Some(ListOption::new(idx, opt)),
-                    false => None,
-                })
+                .answer_indices()
+                .into_iter()
+                .map(|idx| ListOption::new(idx, &self.options[idx]))
                 .collect::<Vec<_>>();
 
             let res = validator.validate(&selected_options)?;
@@ -164,7 +436,50 @@ where
         }
     }
 
+    fn state(&self) -> PromptState<'_> {
+        let answer = match self.formatter {
+            Some(formatter) => {
+                let refs: Vec<ListOption<&T>> = self
+                    .answer_indices()
+                    .into_iter()
+                    .map(|index| ListOption::new(index, &self.options[index]))
+                    .collect();
+                formatter(&refs)
+            }
+            None => self
+                .answer_indices()
+                .into_iter()
+                .map(|index| (self.display)(&self.options[index]))
+                .collect::<Vec<String>>()
+                .join(", "),
+        };
+
+        PromptState {
+            input: self.input.content(),
+            answer: Some(answer),
+            options: self.filtered_options.len(),
+            selected: self.checked.len(),
+        }
+    }
+
     fn get_final_answer(&mut self) -> Vec<ListOption<T>> {
+        if self.config.selection_order_output {
+            let mut options: Vec<Option<T>> = std::mem::take(&mut self.options)
+                .into_iter()
+                .map(Some)
+                .collect();
+
+            return self
+                .checked_order
+                .iter()
+                .filter_map(|&index| {
+                    options[index]
+                        .take()
+                        .map(|value| ListOption::new(index, value))
+                })
+                .collect();
+        }
+
         let mut answer = vec![];
 
         // by iterating in descending order, we can safely
@@ -186,7 +501,6 @@ impl<'a, B, T> Prompt<B, MultiSelectConfig, MultiSelectPromptAction, Vec<ListOpt
     for MultiSelectPrompt<'a, T>
 where
     B: MultiSelectBackend,
-    T: Display,
 {
     fn message(&self) -> &str {
         self.message
@@ -196,9 +510,58 @@ where
         &self.config
     }
 
+    fn interrupt_behavior(&self) -> InterruptBehavior {
+        self.config.interrupt_behavior
+    }
+
+    fn event_listener(&self) -> Option<EventListener<'_>> {
+        self.event_listener
+    }
+
+    fn prompt_handle(&self) -> Option<&PromptHandle> {
+        self.handle
+    }
+
+    fn header(&self) -> Option<&Block> {
+        self.header.as_ref()
+    }
+
+    fn footer(&self) -> Option<&Block> {
+        self.footer.as_ref()
+    }
+
+    fn timeout(&self) -> Option<Timeout> {
+        self.config.timeout
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        self.config.refresh_interval
+    }
+
+    fn listens_for_updates(&self) -> bool {
+        self.option_receiver.is_some()
+    }
+
+    fn apply_updates(&mut self) -> bool {
+        self.receive_options()
+    }
+
+    fn submit_on_timeout(&mut self) -> InquireResult<Option<Vec<ListOption<T>>>> {
+        Prompt::<B, _, _, _>::submit(self)
+    }
+
     fn format_answer(&self, answer: &Vec<ListOption<T>>) -> String {
-        let refs: Vec<ListOption<&T>> = answer.iter().map(ListOption::as_ref).collect();
-        (self.formatter)(&refs)
+        match self.formatter {
+            Some(formatter) => {
+                let refs: Vec<ListOption<&T>> = answer.iter().map(ListOption::as_ref).collect();
+                formatter(&refs)
+            }
+            None => answer
+                .iter()
+                .map(|option| (self.display)(&option.value))
+                .collect::<Vec<String>>()
+                .join(", "),
+        }
     }
 
     fn submit(&mut self) -> InquireResult<Option<Vec<ListOption<T>>>> {
@@ -215,8 +578,8 @@ where
 
     fn handle(&mut self, action: MultiSelectPromptAction) -> InquireResult<ActionResult> {
         let result = match action {
-            MultiSelectPromptAction::MoveUp => self.move_cursor_up(1, true),
-            MultiSelectPromptAction::MoveDown => self.move_cursor_down(1, true),
+            MultiSelectPromptAction::MoveUp => self.move_cursor_up(1, self.config.wrap_around),
+            MultiSelectPromptAction::MoveDown => self.move_cursor_down(1, self.config.wrap_around),
             MultiSelectPromptAction::PageUp => self.move_cursor_up(self.config.page_size, false),
             MultiSelectPromptAction::PageDown => {
                 self.move_cursor_down(self.config.page_size, false)
@@ -226,8 +589,10 @@ where
             MultiSelectPromptAction::ToggleCurrentOption => self.toggle_cursor_selection(),
             MultiSelectPromptAction::SelectAll => {
                 self.checked.clear();
+                self.checked_order.clear();
                 for idx in &self.filtered_options {
                     self.checked.insert(*idx);
+                    self.checked_order.push(*idx);
                 }
 
                 if !self.config.keep_filter {
@@ -238,6 +603,7 @@ where
             }
             MultiSelectPromptAction::ClearSelections => {
                 self.checked.clear();
+                self.checked_order.clear();
 
                 if !self.config.keep_filter {
                     self.input.clear();
@@ -249,16 +615,14 @@ where
                 let result = self.input.handle(input_action);
 
                 if let InputActionResult::ContentChanged = result {
-                    let options = self.filter_options();
-                    self.filtered_options = options;
-                    if self.filtered_options.len() <= self.cursor_index {
-                        let _ = self
-                            .update_cursor_position(self.filtered_options.len().saturating_sub(1));
-                    }
+                    self.refresh_filtered_options();
                 }
 
                 result.into()
             }
+            MultiSelectPromptAction::ToggleQuickFilter(idx) => self.toggle_quick_filter(idx),
+            MultiSelectPromptAction::SortByColumn(column) => self.sort_by_column(Some(column)),
+            MultiSelectPromptAction::ClearSort => self.sort_by_column(None),
         };
 
         Ok(result)
@@ -273,21 +637,79 @@ where
 
         backend.render_multiselect_prompt(prompt, &self.input)?;
 
+        if !self.quick_filters.is_empty() {
+            let chips = self
+                .quick_filters
+                .iter()
+                .enumerate()
+                .map(|(i, f)| (f.label, self.active_quick_filters.contains(&i)))
+                .collect::<Vec<_>>();
+            backend.render_quick_filters(&chips)?;
+        }
+
+        if let Some(table) = self.table.as_ref().filter(|_| self.config.table_header) {
+            backend.render_table_header(&table.header(), self.options.len())?;
+        }
+
         let choices = self
-            .filtered_options
+            .rows
             .iter()
-            .cloned()
-            .map(|i| ListOption::new(i, self.options.get(i).unwrap()))
-            .collect::<Vec<ListOption<&T>>>();
+            .map(|row| {
+                let value: &dyn Display = match (*row, &self.table) {
+                    (Row::Group(i), _) => &self.groups[i],
+                    (Row::Option(i), Some(table)) => table.row(i),
+                    (Row::Option(i), None) => &self.string_options[i],
+                };
+                match *row {
+                    Row::Group(i) | Row::Option(i) => ListOption::new(i, value),
+                }
+            })
+            .collect::<Vec<ListOption<&dyn Display>>>();
+
+        let page_size = backend.fit_page_size(self.config.page_size);
+        let page = self.paginate(page_size, &choices);
+
+        let grouped = self.group_by.is_some();
+        let first = self.cursor_index - page.cursor.unwrap_or(0);
+        let checkboxes = (first..first + page.content.len())
+            .map(|position| CheckboxRow {
+                state: self.check_state(position),
+                depth: match self.rows[position] {
+                    Row::Option(_) if grouped => 1,
+                    _ => 0,
+                },
+                parent: matches!(self.rows[position], Row::Group(_)),
+                expanded: None,
+            })
+            .collect::<Vec<_>>();
 
-        let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
+        backend.render_options(page, &checkboxes)?;
+
+        if let Some(message) = self.no_matches_message {
+            if choices.is_empty() && !self.options.is_empty() {
+                backend.render_no_matches_message(
+                    &message.replace("{filter}", self.input.content()),
+                )?;
+            }
+        }
 
-        backend.render_options(page, &self.checked)?;
+        if self.config.position_indicator && !choices.is_empty() {
+            backend.render_position_indicator(self.cursor_index + 1, choices.len())?;
+        }
 
-        if let Some(help_message) = self.help_message {
-            backend.render_help_message(help_message)?;
+        if self.config.selection_summary && !self.checked.is_empty() {
+            let selected = self
+                .answer_indices()
+                .into_iter()
+                .map(|index| (self.display)(&self.options[index]))
+                .collect::<Vec<_>>();
+            backend.render_selection_summary(&selected)?;
         }
 
+        render_help_message(backend, self.help_message, self.help_message_fn, || {
+            self.state()
+        })?;
+
         Ok(())
     }
 }
@@ -1,11 +1,11 @@
 use crate::{
     formatter::MultiOptionFormatter,
     list_option::ListOption,
-    terminal::crossterm::CrosstermTerminal,
-    ui::{Backend, RenderConfig},
+    terminal::{crossterm::CrosstermTerminal, TestBackend},
+    ui::{self, Backend, Key, RenderConfig},
     MultiSelect,
 };
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 #[test]
 /// Tests that a closure that actually closes on a variable can be used
@@ -92,3 +92,258 @@ fn list_option_indexes_are_relative_to_input_vec() {
 
     assert_eq!(vec![ListOption::new(1, 2), ListOption::new(2, 3)], ans);
 }
+
+#[test]
+fn default_values_are_matched_by_value() {
+    let read: Vec<KeyEvent> = vec![KeyCode::Enter]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let options = vec!["a", "b", "c", "d"];
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = MultiSelect::new("Question", options)
+        .with_default_values(&["d", "b", "z"])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(1, "b"), ListOption::new(3, "d")], ans);
+}
+
+#[test]
+fn default_by_predicate_selects_matching_options() {
+    let read: Vec<KeyEvent> = vec![KeyCode::Enter]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let options = vec![1, 2, 3, 4, 5];
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = MultiSelect::new("Question", options)
+        .with_default_by(|n| n % 2 == 0)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(1, 2), ListOption::new(3, 4)], ans);
+}
+
+#[test]
+fn quick_filters_constrain_visible_options() {
+    let read: Vec<KeyEvent> = vec![
+        KeyCode::Char('1'),
+        KeyCode::Right,
+        KeyCode::Char('1'),
+        KeyCode::Char('2'),
+        KeyCode::Enter,
+    ]
+    .into_iter()
+    .map(KeyEvent::from)
+    .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = MultiSelect::new("Question", vec![1, 2, 3, 4, 5])
+        .with_quick_filter("even", &|n| n % 2 == 0)
+        .with_quick_filter("large", &|n| *n > 3)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    // the second quick filter is active when submitting, but only affects
+    // the displayed options, while '2' is not typed into the filter input
+    assert_eq!(vec![ListOption::new(1, 2), ListOption::new(3, 4)], ans);
+}
+
+#[test]
+fn table_rows_are_sorted_by_column() {
+    let read: Vec<KeyEvent> = vec![
+        KeyEvent::new(KeyCode::Char('1'), KeyModifiers::ALT),
+        KeyEvent::from(KeyCode::Home),
+        KeyEvent::from(KeyCode::Char(' ')),
+        KeyEvent::new(KeyCode::Char('1'), KeyModifiers::ALT),
+        KeyEvent::from(KeyCode::Home),
+        KeyEvent::from(KeyCode::Char(' ')),
+        KeyEvent::from(KeyCode::Enter),
+    ];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+    let options = vec!["b 10", "a 9", "c 100"];
+    let ans = MultiSelect::new("Question", options)
+        .with_columns(vec!["Name", "Size"], &|opt| {
+            opt.split(' ').map(String::from).collect()
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    // the first and last options by name, ascending then descending
+    assert_eq!(
+        vec![ListOption::new(1, "a 9"), ListOption::new(2, "c 100")],
+        ans
+    );
+    drop(backend);
+
+    let output = String::from_utf8(write).unwrap();
+    let sorted = output.find("      \u{1b}[1mName ▼  Size\u{1b}[0m").unwrap();
+    assert!(output[sorted..].contains("> [x] c       100"));
+}
+
+#[test]
+fn options_without_display_are_rendered_by_the_display_function() {
+    let options = vec![(1, "one"), (2, "two"), (3, "three")];
+
+    let mut terminal = TestBackend::new(vec![]).with_text(" t \n");
+    let ans =
+        MultiSelect::new_with_display("Numbers:", options, &|(n, name)| format!("{name} = {n}"))
+            .with_keep_filter(false)
+            .without_help_message()
+            .with_render_config(RenderConfig::empty())
+            .prompt_with_terminal(&mut terminal)
+            .unwrap();
+
+    assert_eq!(vec![(1, "one"), (2, "two")], ans);
+    assert_eq!(
+        "? Numbers: one = 1, two = 2\n",
+        terminal.frames().last().unwrap()
+    );
+}
+
+#[test]
+fn map_entries_display_the_value_and_return_the_keys() {
+    let options = vec![("date", "Date prompts"), ("editor", "Editor prompt")];
+
+    let mut terminal =
+        TestBackend::new(vec![ui::Key::Right(ui::KeyModifiers::NONE), ui::Key::Enter]);
+    let ans = MultiSelect::from_map("Features:", options)
+        .without_help_message()
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(
+        vec![("date", "Date prompts"), ("editor", "Editor prompt")],
+        ans
+    );
+    assert_eq!(
+        "? Features: Date prompts, Editor prompt\n",
+        terminal.frames().last().unwrap()
+    );
+}
+
+#[test]
+fn selection_order_output_returns_the_options_in_the_order_they_were_checked() {
+    let down = ui::Key::Down(ui::KeyModifiers::NONE);
+    let up = ui::Key::Up(ui::KeyModifiers::NONE);
+    let space = ui::Key::Char(' ', ui::KeyModifiers::NONE);
+    let mut terminal = TestBackend::new(vec![down, down, space, up, space, up, space]);
+    terminal.push_key(ui::Key::Enter);
+
+    let ans = MultiSelect::new("Steps:", vec!["build", "test", "lint", "deploy"])
+        .with_default(&[3, 1])
+        .with_selection_order_output(true)
+        .without_help_message()
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(vec!["deploy", "lint", "build"], ans);
+    assert_eq!(
+        "? Steps: deploy, lint, build\n",
+        terminal.frames().last().unwrap()
+    );
+}
+
+#[test]
+fn selection_summary_is_collapsed_to_the_terminal_width() {
+    let mut terminal = TestBackend::new(vec![ui::Key::Right(ui::KeyModifiers::NONE)])
+        .with_size(24, 20)
+        .with_text("\n");
+
+    let ans = MultiSelect::new("Regions:", vec!["us-east-1", "eu-west-1", "ap-south-1"])
+        .with_selection_summary(true)
+        .without_help_message()
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(3, ans.len());
+    assert!(!terminal.frames()[0].contains("selected"));
+    assert!(terminal.frames()[1].ends_with("\n3 selected: us-east-1, …\n"));
+}
+
+#[test]
+fn group_headers_toggle_the_options_of_their_group() {
+    let up = ui::Key::Up(ui::KeyModifiers::NONE);
+    let space = ui::Key::Char(' ', ui::KeyModifiers::NONE);
+    let mut terminal = TestBackend::new(vec![space, up, space, space, space]);
+    terminal.push_key(ui::Key::Enter);
+
+    let ans = MultiSelect::new(
+        "Food:",
+        vec!["fruits/apple", "fruits/kiwi", "vegetables/leek"],
+    )
+    .with_group_by(&|option| option.split('/').next().unwrap_or_default().to_string())
+    .without_help_message()
+    .with_render_config(RenderConfig::empty())
+    .prompt_with_terminal(&mut terminal)
+    .unwrap();
+
+    assert_eq!(vec!["fruits/apple", "fruits/kiwi"], ans);
+    assert!(terminal.frames()[1]
+        .contains("\n  [~] fruits\n>   [x] fruits/apple\n    [ ] fruits/kiwi\n"));
+    assert!(terminal.frames()[3]
+        .contains("\n> [x] fruits\n    [x] fruits/apple\n    [x] fruits/kiwi\n  [ ] vegetables\n"));
+    assert!(terminal.frames()[4]
+        .contains("\n> [ ] fruits\n    [ ] fruits/apple\n    [ ] fruits/kiwi\n"));
+}
+
+#[test]
+fn grouped_options_can_not_be_laid_out_as_a_table() {
+    let ans = MultiSelect::new("Food:", vec!["fruits/apple"])
+        .with_group_by(&|option| option.split('/').next().unwrap_or_default().to_string())
+        .with_columns(vec!["Group", "Name"], &|option| {
+            option.split('/').map(String::from).collect()
+        })
+        .prompt_with_terminal(&mut TestBackend::new(vec![]));
+
+    assert!(matches!(
+        ans,
+        Err(crate::InquireError::InvalidConfiguration(_))
+    ));
+}
+
+#[test]
+fn rejected_answers_ring_the_bell() {
+    let space = Key::Char(' ', ui::KeyModifiers::NONE);
+    let down = Key::Down(ui::KeyModifiers::NONE);
+    let mut terminal = TestBackend::new(vec![space, down, space, Key::Enter, space, Key::Enter]);
+
+    let ans = MultiSelect::new("Regions:", vec!["us-east-1", "eu-west-1"])
+        .with_render_config(RenderConfig::empty().with_error_feedback(ui::Feedback::Bell))
+        .with_validator(|selected: &[ListOption<&&str>]| {
+            Ok(match selected.len() {
+                0 | 1 => crate::validator::Validation::Valid,
+                _ => crate::validator::Validation::Invalid("Pick a single region".into()),
+            })
+        })
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(vec!["us-east-1"], ans);
+    assert_eq!(1, terminal.bells());
+}
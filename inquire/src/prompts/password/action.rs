@@ -10,7 +10,7 @@ use super::config::PasswordConfig;
 pub enum PasswordPromptAction {
     /// Action on the value text input handler.
     ValueInput(InputAction),
-    /// Toggles the display mode between plain text and the initial one.
+    /// Cycles the display mode through hidden, masked and plain text.
     ToggleDisplayMode,
 }
 
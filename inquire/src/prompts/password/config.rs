@@ -1,12 +1,20 @@
-use crate::{Password, PasswordDisplayMode};
+use std::time::Duration;
+
+use crate::{config::InterruptBehavior, prompts::prompt::Timeout, Password, PasswordDisplayMode};
 
 /// Configuration settings used in the execution of a PasswordPrompt.
 #[derive(Copy, Clone, Debug)]
 pub struct PasswordConfig {
-    /// Whether to allow the user to toggle the display mode of the password.
+    /// Whether to allow the user to cycle the display mode of the password with Ctrl+R.
     pub enable_display_toggle: bool,
     /// The initial display mode of the password.
     pub display_mode: PasswordDisplayMode,
+    /// What happens when the user presses Ctrl+C.
+    pub interrupt_behavior: InterruptBehavior,
+    /// Timeout applied until the first key press.
+    pub timeout: Option<Timeout>,
+    /// Interval at which the prompt is re-rendered without key presses.
+    pub refresh_interval: Option<Duration>,
 }
 
 impl From<&Password<'_>> for PasswordConfig {
@@ -14,6 +22,9 @@ impl From<&Password<'_>> for PasswordConfig {
         Self {
             enable_display_toggle: value.enable_display_toggle,
             display_mode: value.display_mode,
+            interrupt_behavior: value.interrupt_behavior,
+            timeout: Timeout::new(value.timeout, value.timeout_countdown),
+            refresh_interval: value.refresh_interval,
         }
     }
 }
@@ -7,13 +7,21 @@ mod test;
 
 pub use action::*;
 
+use std::time::Duration;
+
 use crate::{
-    config::get_configuration,
+    config::{
+        get_configuration, get_event_listener, get_global_options, get_interrupt_behavior,
+        InterruptBehavior,
+    },
     error::{InquireError, InquireResult},
     formatter::StringFormatter,
+    handle::PromptHandle,
     prompts::prompt::Prompt,
-    terminal::get_default_terminal,
-    ui::{Backend, PasswordBackend, RenderConfig},
+    report::PromptReport,
+    terminal::{get_default_terminal, Terminal},
+    type_aliases::{EventListener, HelpMessageFn},
+    ui::{Backend, Block, FinalRender, PasswordBackend, Redaction, RenderConfig},
     validator::StringValidator,
 };
 
@@ -42,17 +50,16 @@ pub enum PasswordDisplayMode {
 /// However, you can still customize these and other behaviors if you wish:
 /// - **Standard display mode**: Set the display mode of the text input among hidden, masked and full via the `PasswordDisplayMode` enum.
 ///   - Hidden: default behavior, no UI indicators.
-///   - Masked: behaves like a normal text input, except that all characters of the input are masked to a special character, which is `'*'` by default but can be customized via `RenderConfig`.
+///   - Masked: behaves like a normal text input, except that all characters of the input are masked to a special character, which is `'*'` by default but can be customized via `RenderConfig` or per prompt with `with_mask()`. A function computing the displayed text, e.g. to reveal only the last characters, can be set with `with_mask_formatter()`.
 ///   - Full: behaves like a normal text input, no modifications.
-/// - **Toggle display mode**: When enabling this feature by calling the `with_display_toggle_enabled()` method, you allow the user to toggle between the standard display mode set and the full display mode.
-///   - If you have set the standard display mode to hidden (which is also the default) or masked, the user can press `Ctrl+R` to change the display mode to `Full`, and `Ctrl+R` again to change it back to the standard one.
-///   - Obviously, if you have set the standard display mode to `Full`, pressing `Ctrl+R` won't cause any changes.
+/// - **Toggle display mode**: When enabling this feature by calling the `with_display_toggle_enabled()` method, you allow the user to cycle through the hidden, masked and full display modes by pressing Ctrl+R, starting from the standard display mode set.
 /// - **Confirmation**: By default, the password will have a confirmation flow where the user will be asked for the input twice and the two responses will be compared. If they differ, an error message is shown and the user is prompted again.
 ///   - By default, a "Confirmation:" message is shown for the confirmation prompts, but this can be modified by setting a custom confirmation message only shown the second time, using the `with_custom_confirmation_message()` method.
 ///   - If confirmation is not desired, it can be turned off using the `without_confirmation()` method.
 /// - **Help message**: Message displayed at the line below the prompt.
 /// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
 ///   - By default, it prints eight asterisk characters: `********`.
+/// - **Max length**: Maximum number of characters the user can type, optionally rendering a `12/80` counter after the input in the masked and full display modes.
 /// - **Validators**: Custom validators to make sure a given submitted input pass the specified requirements, e.g. not allowing empty inputs or requiring special characters.
 ///   - No validators are on by default.
 ///
@@ -98,18 +105,49 @@ pub struct Password<'a> {
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
+    /// Function computing the help message from the current state of the
+    /// prompt, replacing [`help_message`](Self::help_message) when set.
+    ///
+    /// See [`with_help_message_fn`](Self::with_help_message_fn) for more details.
+    pub help_message_fn: Option<HelpMessageFn<'a>>,
+
+    /// Content rendered above the prompt.
+    pub header: Option<Block>,
+
+    /// Content rendered below the prompt.
+    pub footer: Option<Block>,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: StringFormatter<'a>,
 
     /// How the password input is displayed to the user.
     pub display_mode: PasswordDisplayMode,
 
-    /// Whether to allow the user to toggle the display of the current password input by pressing the Ctrl+R hotkey.
+    /// Character repeated in place of the input in the masked display mode,
+    /// overriding the [`password_mask`](crate::ui::RenderConfig::password_mask)
+    /// of the render config.
+    pub mask: Option<char>,
+
+    /// Function that computes the text displayed in place of the input in the
+    /// masked display mode, instead of repeating the mask character.
+    pub mask_formatter: Option<StringFormatter<'a>>,
+
+    /// Whether to allow the user to cycle the display mode of the current password input through
+    /// hidden, masked and full by pressing the Ctrl+R hotkey.
     pub enable_display_toggle: bool,
 
     /// Whether to ask for input twice to see if the provided passwords are the same.
     pub enable_confirmation: bool,
 
+    /// Maximum number of characters the user can type.
+    ///
+    /// See [`with_max_length`](Self::with_max_length) for more details.
+    pub max_length: Option<usize>,
+
+    /// Whether to render a `12/80` counter after the input when there is a
+    /// [`max_length`](Self::max_length).
+    pub length_counter: bool,
+
     /// Collection of validators to apply to the user input.
     ///
     /// Validators are executed in the order they are stored, stopping at and displaying to the user
@@ -118,6 +156,33 @@ pub struct Password<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub validators: Vec<Box<dyn StringValidator>>,
 
+    /// What happens when the user presses Ctrl+C.
+    pub interrupt_behavior: InterruptBehavior,
+
+    /// Listener notified of the events of the prompt, such as key presses and
+    /// submissions.
+    ///
+    /// See [`with_event_listener`](Self::with_event_listener) for more details.
+    pub event_listener: Option<EventListener<'a>>,
+
+    /// Handle aborting the prompt from outside of it, if any.
+    ///
+    /// See [`with_handle`](Self::with_handle) for more details.
+    pub handle: Option<&'a PromptHandle>,
+
+    /// Time to wait for the user to start interacting with the prompt.
+    ///
+    /// See [`with_timeout`](Self::with_timeout) for more details.
+    pub timeout: Option<Duration>,
+
+    /// Whether to render the time left before the timeout expires.
+    pub timeout_countdown: bool,
+
+    /// Interval at which the prompt is re-rendered while no key is pressed.
+    ///
+    /// See [`with_refresh_interval`](Self::with_refresh_interval) for more details.
+    pub refresh_interval: Option<Duration>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -150,6 +215,8 @@ impl<'a> Password<'a> {
 
     /// Creates a [Password] with the provided message and default options.
     pub fn new(message: &'a str) -> Self {
+        let global = get_global_options();
+
         Self {
             message,
             custom_confirmation_message: None,
@@ -157,9 +224,22 @@ impl<'a> Password<'a> {
             enable_confirmation: Self::DEFAULT_ENABLE_CONFIRMATION,
             enable_display_toggle: Self::DEFAULT_ENABLE_DISPLAY_TOGGLE,
             display_mode: Self::DEFAULT_DISPLAY_MODE,
-            help_message: Self::DEFAULT_HELP_MESSAGE,
+            mask: None,
+            mask_formatter: None,
+            max_length: None,
+            length_counter: false,
+            help_message: global.help_message(Self::DEFAULT_HELP_MESSAGE),
+            help_message_fn: None,
+            header: None,
+            footer: None,
             formatter: Self::DEFAULT_FORMATTER,
             validators: Self::DEFAULT_VALIDATORS,
+            interrupt_behavior: get_interrupt_behavior(),
+            event_listener: get_event_listener(),
+            handle: None,
+            timeout: None,
+            timeout_countdown: false,
+            refresh_interval: None,
             render_config: get_configuration(),
         }
     }
@@ -170,7 +250,32 @@ impl<'a> Password<'a> {
         self
     }
 
-    /// Sets the flag to enable display toggling.
+    /// Sets a function computing the help message from the current state of the
+    /// prompt, called whenever it is rendered. It takes precedence over the
+    /// static help message, and returning `None` hides the help line.
+    ///
+    /// See [`PromptState`](crate::PromptState) for the state given to the function.
+    pub fn with_help_message_fn(mut self, help_message_fn: HelpMessageFn<'a>) -> Self {
+        self.help_message_fn = Some(help_message_fn);
+        self
+    }
+
+    /// Sets the content rendered above the prompt, such as the current git
+    /// branch. It is redrawn and cleared along with the prompt, see [`Block`].
+    pub fn with_header<H: Into<Block>>(mut self, header: H) -> Self {
+        self.header = Some(header.into());
+        self
+    }
+
+    /// Sets the content rendered below the prompt. It is redrawn and cleared
+    /// along with the prompt, see [`Block`].
+    pub fn with_footer<F: Into<Block>>(mut self, footer: F) -> Self {
+        self.footer = Some(footer.into());
+        self
+    }
+
+    /// Sets the flag to enable display toggling, letting the user cycle through
+    /// the hidden, masked and full display modes by pressing Ctrl+R.
     pub fn with_display_toggle_enabled(mut self) -> Self {
         self.enable_display_toggle = true;
         self
@@ -200,6 +305,57 @@ impl<'a> Password<'a> {
         self
     }
 
+    /// Sets the character repeated in place of the input in the masked display
+    /// mode, overriding the [`password_mask`](crate::ui::RenderConfig::password_mask)
+    /// of the render config for this prompt only.
+    pub fn with_mask(mut self, mask: char) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    /// Sets the function that computes the text displayed in place of the input
+    /// in the masked display mode, e.g. to reveal only its last characters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inquire::{Password, PasswordDisplayMode};
+    ///
+    /// let prompt = Password::new("Card number:")
+    ///     .with_display_mode(PasswordDisplayMode::Masked)
+    ///     .with_mask_formatter(&|input| {
+    ///         let visible = input.chars().count().saturating_sub(4);
+    ///         input
+    ///             .chars()
+    ///             .enumerate()
+    ///             .map(|(i, c)| if i < visible { '•' } else { c })
+    ///             .collect()
+    ///     });
+    /// ```
+    pub fn with_mask_formatter(mut self, mask_formatter: StringFormatter<'a>) -> Self {
+        self.mask_formatter = Some(mask_formatter);
+        self
+    }
+
+    /// Sets the maximum number of characters the user can type, further key
+    /// presses being ignored once the input reaches it.
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Sets whether to render a `12/80` counter of the input length after the
+    /// input, styled with the [`length_counter`](crate::ui::RenderConfig::length_counter)
+    /// style sheet of the render config. The counter is only rendered when a
+    /// maximum length is set.
+    ///
+    /// The counter is not rendered in the hidden display mode, where the
+    /// length of the input is kept secret.
+    pub fn with_length_counter(mut self, length_counter: bool) -> Self {
+        self.length_counter = length_counter;
+        self
+    }
+
     /// Sets the formatter.
     pub fn with_formatter(mut self, formatter: StringFormatter<'a>) -> Self {
         self.formatter = formatter;
@@ -236,12 +392,66 @@ impl<'a> Password<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub fn with_validators(mut self, validators: &[Box<dyn StringValidator>]) -> Self {
         for validator in validators {
-            #[allow(clippy::clone_double_ref)]
             self.validators.push(validator.clone());
         }
         self
     }
 
+    /// Sets what happens when the user presses Ctrl+C, overriding the global
+    /// setting.
+    pub fn with_interrupt_behavior(mut self, interrupt_behavior: InterruptBehavior) -> Self {
+        self.interrupt_behavior = interrupt_behavior;
+        self
+    }
+
+    /// Sets a listener notified of the events of the prompt, such as renders,
+    /// key presses and submissions, overriding the global listener.
+    ///
+    /// See the [`events`](crate::events) module for more details.
+    pub fn with_event_listener(mut self, listener: EventListener<'a>) -> Self {
+        self.event_listener = Some(listener);
+        self
+    }
+
+    /// Sets a handle aborting the prompt from outside of it, e.g. from another
+    /// thread or a Ctrl+C handler, canceling it as if the user pressed ESC.
+    ///
+    /// See the [`handle`](crate::handle) module for more details.
+    pub fn with_handle(mut self, handle: &'a PromptHandle) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets a timeout for the user to start interacting with the prompt.
+    ///
+    /// If no key is pressed before it expires, [`InquireError::Timeout`] is returned.
+    /// Once a key is pressed, the prompt waits for the user indefinitely.
+    ///
+    /// Timeouts are supported by the `crossterm` and `termion` backends, the
+    /// `console` backend always waits for the first key press.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether to render the time left before the timeout expires below the prompt.
+    pub fn with_timeout_countdown(mut self, countdown: bool) -> Self {
+        self.timeout_countdown = countdown;
+        self
+    }
+
+    /// Sets an interval at which the prompt is re-rendered even when no key is
+    /// pressed, e.g. for content computed at render time, such as a dynamic
+    /// help message showing the current time, to stay up to date.
+    ///
+    /// Requires a terminal able to wait for keys with a timeout, such as the
+    /// crossterm or termion ones. Other terminals only re-render the prompt
+    /// after key presses.
+    pub fn with_refresh_interval(mut self, interval: Duration) -> Self {
+        self.refresh_interval = Some(interval);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -255,6 +465,35 @@ impl<'a> Password<'a> {
         self
     }
 
+    /// Sets how the prompt is rendered once answered, e.g. [`FinalRender::None`]
+    /// for the prompt to disappear. This is a shorthand for setting the
+    /// [`final_render`](RenderConfig::final_render) of the render config, so it
+    /// must be called after [`with_render_config`](Self::with_render_config).
+    pub fn with_final_render(mut self, final_render: FinalRender) -> Self {
+        self.render_config.final_render = final_render;
+        self
+    }
+
+    /// Sets how the answer is redacted once the prompt is answered, e.g.
+    /// [`Redaction::Masked`] for secrets to stay out of the scrollback. This is
+    /// a shorthand for setting the [`answer_redaction`](RenderConfig::answer_redaction)
+    /// of the render config, so it must be called after
+    /// [`with_render_config`](Self::with_render_config).
+    pub fn with_answer_redaction(mut self, answer_redaction: Redaction) -> Self {
+        self.render_config.answer_redaction = answer_redaction;
+        self
+    }
+
+    /// Sets whether the prompt is rendered in fullscreen, on the alternate
+    /// screen buffer, leaving only its final rendering in the original screen.
+    /// This is a shorthand for setting the
+    /// [`fullscreen`](RenderConfig::fullscreen) flag of the render config, so
+    /// it must be called after [`with_render_config`](Self::with_render_config).
+    pub fn with_fullscreen(mut self, fullscreen: bool) -> Self {
+        self.render_config.fullscreen = fullscreen;
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -275,11 +514,33 @@ impl<'a> Password<'a> {
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     pub fn prompt(self) -> InquireResult<String> {
-        let terminal = get_default_terminal()?;
+        self.prompt_with_terminal(get_default_terminal()?)
+    }
+
+    /// Prompts the user through the given terminal instead of the default
+    /// terminal of the enabled backend feature, see [`Terminal`].
+    pub fn prompt_with_terminal<Term: Terminal>(self, terminal: Term) -> InquireResult<String> {
         let mut backend = Backend::new(terminal, self.render_config)?;
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Same as [`prompt`](Self::prompt), also returning a [`PromptReport`]
+    /// describing how the user interacted with the prompt.
+    pub fn prompt_with_report(self) -> InquireResult<(String, PromptReport)> {
+        self.prompt_with_report_and_terminal(get_default_terminal()?)
+    }
+
+    /// Same as [`prompt_with_terminal`](Self::prompt_with_terminal), also
+    /// returning a [`PromptReport`] describing how the user interacted with
+    /// the prompt.
+    pub fn prompt_with_report_and_terminal<Term: Terminal>(
+        self,
+        terminal: Term,
+    ) -> InquireResult<(String, PromptReport)> {
+        let mut backend = Backend::new(terminal, self.render_config)?;
+        PasswordPrompt::from(self).prompt_with_report(&mut backend)
+    }
+
     pub(crate) fn prompt_with_backend<B: PasswordBackend>(
         self,
         backend: &mut B,
@@ -1,9 +1,17 @@
+use std::time::Duration;
+
 use crate::{
+    config::{translate, InterruptBehavior},
     error::InquireResult,
     formatter::StringFormatter,
+    handle::PromptHandle,
     input::Input,
-    prompts::prompt::{ActionResult, Prompt},
-    ui::PasswordBackend,
+    prompts::{
+        prompt::{ActionResult, Prompt, Timeout},
+        state::{render_help_message, PromptState},
+    },
+    type_aliases::{EventListener, HelpMessageFn},
+    ui::{Block, PasswordBackend},
     validator::{ErrorMessage, StringValidator, Validation},
     InquireError, Password, PasswordDisplayMode,
 };
@@ -25,12 +33,19 @@ struct PasswordConfirmation<'a> {
 pub struct PasswordPrompt<'a> {
     message: &'a str,
     config: PasswordConfig,
+    event_listener: Option<EventListener<'a>>,
+    handle: Option<&'a PromptHandle>,
     help_message: Option<&'a str>,
+    help_message_fn: Option<HelpMessageFn<'a>>,
+    header: Option<Block>,
+    footer: Option<Block>,
     input: Input,
     current_mode: PasswordDisplayMode,
     confirmation: Option<PasswordConfirmation<'a>>, // if `None`, confirmation is disabled, `Some(_)` confirmation is enabled
     confirmation_stage: bool,
     formatter: StringFormatter<'a>,
+    mask: Option<char>,
+    mask_formatter: Option<StringFormatter<'a>>,
     validators: Vec<Box<dyn StringValidator>>,
     error: Option<ErrorMessage>,
 }
@@ -39,11 +54,15 @@ impl<'a> From<Password<'a>> for PasswordPrompt<'a> {
     fn from(so: Password<'a>) -> Self {
         let confirmation = match so.enable_confirmation {
             true => Some(PasswordConfirmation {
-                message: so.custom_confirmation_message.unwrap_or("Confirmation:"),
+                message: so
+                    .custom_confirmation_message
+                    .unwrap_or(translate("Confirmation:")),
                 error_message: so
                     .custom_confirmation_error_message
-                    .unwrap_or("The answers don't match."),
-                input: Input::new(),
+                    .unwrap_or(translate("The answers don't match.")),
+                input: Input::new()
+                    .with_max_length(so.max_length)
+                    .with_length_counter(so.length_counter),
             }),
             false => None,
         };
@@ -51,13 +70,22 @@ impl<'a> From<Password<'a>> for PasswordPrompt<'a> {
         Self {
             message: so.message,
             config: (&so).into(),
+            event_listener: so.event_listener,
+            handle: so.handle,
             help_message: so.help_message,
+            help_message_fn: so.help_message_fn,
+            header: so.header,
+            footer: so.footer,
             current_mode: so.display_mode,
             confirmation,
             confirmation_stage: false,
             formatter: so.formatter,
+            mask: so.mask,
+            mask_formatter: so.mask_formatter,
             validators: so.validators,
-            input: Input::new(),
+            input: Input::new()
+                .with_max_length(so.max_length)
+                .with_length_counter(so.length_counter),
             error: None,
         }
     }
@@ -85,18 +113,42 @@ impl<'a> PasswordPrompt<'a> {
     }
 
     fn toggle_display_mode(&mut self) -> ActionResult {
-        let new_mode = match self.current_mode {
-            PasswordDisplayMode::Hidden => PasswordDisplayMode::Full,
+        self.current_mode = match self.current_mode {
+            PasswordDisplayMode::Hidden => PasswordDisplayMode::Masked,
             PasswordDisplayMode::Masked => PasswordDisplayMode::Full,
-            PasswordDisplayMode::Full => self.config.display_mode,
+            PasswordDisplayMode::Full => PasswordDisplayMode::Hidden,
         };
 
-        if new_mode != self.current_mode {
-            self.current_mode = new_mode;
-            ActionResult::NeedsRedraw
-        } else {
-            ActionResult::Clean
+        ActionResult::NeedsRedraw
+    }
+
+    fn render_masked_input<B: PasswordBackend>(
+        &self,
+        backend: &mut B,
+        message: &str,
+        input: &Input,
+    ) -> InquireResult<()> {
+        match (self.mask_formatter, self.mask) {
+            (Some(mask_formatter), _) => {
+                let masked = Input::new_with(mask_formatter(input.content())).with_limits_of(input);
+                // the cursor keeps its distance to the end, as formatters usually
+                // only reveal the last characters, if any
+                let after_cursor = input.length() - input.cursor();
+                let cursor = masked.length().saturating_sub(after_cursor);
+                let masked = masked.with_cursor(cursor);
+                backend.render_prompt_with_full_input(message, &masked)?;
+            }
+            (None, Some(mask)) => {
+                let masked: String = (0..input.length()).map(|_| mask).collect();
+                let masked = Input::new_with(masked)
+                    .with_cursor(input.cursor())
+                    .with_limits_of(input);
+                backend.render_prompt_with_full_input(message, &masked)?;
+            }
+            (None, None) => backend.render_prompt_with_masked_input(message, input)?,
         }
+
+        Ok(())
     }
 
     fn confirm_current_answer(&mut self) -> Option<String> {
@@ -156,6 +208,34 @@ where
         &self.config
     }
 
+    fn interrupt_behavior(&self) -> InterruptBehavior {
+        self.config.interrupt_behavior
+    }
+
+    fn event_listener(&self) -> Option<EventListener<'_>> {
+        self.event_listener
+    }
+
+    fn prompt_handle(&self) -> Option<&PromptHandle> {
+        self.handle
+    }
+
+    fn header(&self) -> Option<&Block> {
+        self.header.as_ref()
+    }
+
+    fn footer(&self) -> Option<&Block> {
+        self.footer.as_ref()
+    }
+
+    fn timeout(&self) -> Option<Timeout> {
+        self.config.timeout
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        self.config.refresh_interval
+    }
+
     fn format_answer(&self, answer: &String) -> String {
         (self.formatter)(answer)
     }
@@ -215,11 +295,12 @@ where
                 }
             }
             PasswordDisplayMode::Masked => {
-                backend.render_prompt_with_masked_input(self.message, &self.input)?;
+                self.render_masked_input(backend, self.message, &self.input)?;
 
                 match &self.confirmation {
                     Some(confirmation) if self.confirmation_stage => {
-                        backend.render_prompt_with_masked_input(
+                        self.render_masked_input(
+                            backend,
                             confirmation.message,
                             &confirmation.input,
                         )?;
@@ -242,9 +323,12 @@ where
             }
         }
 
-        if let Some(message) = self.help_message {
-            backend.render_help_message(message)?;
-        }
+        render_help_message(backend, self.help_message, self.help_message_fn, || {
+            PromptState {
+                input: self.active_input().content(),
+                ..PromptState::default()
+            }
+        })?;
 
         Ok(())
     }
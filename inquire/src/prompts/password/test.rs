@@ -1,7 +1,7 @@
-use super::Password;
+use super::{Password, PasswordDisplayMode};
 use crate::{
-    terminal::crossterm::CrosstermTerminal,
-    ui::{Backend, RenderConfig},
+    terminal::{crossterm::CrosstermTerminal, TestBackend},
+    ui::{Backend, Key, KeyModifiers, RenderConfig},
     validator::{ErrorMessage, Validation},
 };
 use crossterm::event::{KeyCode, KeyEvent};
@@ -151,3 +151,131 @@ password_test!(
     "",
     Password::new("")
 );
+
+#[test]
+fn display_toggle_cycles_through_display_modes() {
+    let ctrl_r = Key::Char('r', KeyModifiers::CONTROL);
+    let mut terminal = TestBackend::new(vec![]).with_text("ab");
+    for _ in 0..3 {
+        terminal.push_key(ctrl_r);
+    }
+    terminal.push_key(Key::Enter);
+
+    let ans = Password::new("Secret:")
+        .without_confirmation()
+        .with_display_toggle_enabled()
+        .with_help_message("")
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+    assert_eq!("ab", ans);
+
+    let frames = terminal.frames();
+    let lines: Vec<&str> = frames[3..6]
+        .iter()
+        .map(|frame| frame.lines().next().unwrap())
+        .collect();
+    assert_eq!(vec!["? Secret: ** ", "? Secret: ab ", "? Secret:"], lines);
+}
+
+#[test]
+fn masked_input_uses_the_mask_of_the_render_config() {
+    let mut terminal = TestBackend::new(vec![]).with_text("abc\n");
+
+    let ans = Password::new("Secret:")
+        .without_confirmation()
+        .with_display_mode(PasswordDisplayMode::Masked)
+        .with_render_config(RenderConfig::empty().with_password_mask('•'))
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+    assert_eq!("abc", ans);
+
+    let frames = terminal.frames();
+    assert!(frames[3].starts_with("? Secret: ••• \n"));
+    assert_eq!("? Secret: ********\n", frames[4]);
+}
+
+#[test]
+fn mask_can_be_set_per_prompt() {
+    let mut terminal = TestBackend::new(vec![]).with_text("abc\n");
+
+    Password::new("Secret:")
+        .without_confirmation()
+        .with_display_mode(PasswordDisplayMode::Masked)
+        .with_render_config(RenderConfig::empty())
+        .with_mask('#')
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert!(terminal.frames()[3].starts_with("? Secret: ### \n"));
+}
+
+#[test]
+fn mask_formatter_replaces_the_mask() {
+    let mut terminal = TestBackend::new(vec![]).with_text("12345\n");
+
+    Password::new("PIN:")
+        .without_confirmation()
+        .with_display_mode(PasswordDisplayMode::Masked)
+        .with_render_config(RenderConfig::empty())
+        .with_mask_formatter(&|input| format!("{} digits", input.len()))
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert!(terminal.frames()[5].starts_with("? PIN: 5 digits \n"));
+}
+
+#[test]
+fn max_length_blocks_typing_and_renders_a_counter() {
+    let mut terminal = TestBackend::new(vec![]).with_text("abcd");
+    terminal.push_key(Key::Left(KeyModifiers::NONE));
+    terminal.push_key(Key::Enter);
+
+    let ans = Password::new("PIN:")
+        .without_confirmation()
+        .with_display_mode(PasswordDisplayMode::Masked)
+        .with_max_length(3)
+        .with_length_counter(true)
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+    assert_eq!("abc", ans);
+
+    let frames = terminal.frames();
+    assert!(frames[2].starts_with("? PIN: **  2/3\n"));
+    assert!(frames[3].starts_with("? PIN: ***  3/3\n"));
+    assert!(frames[4].starts_with("? PIN: ***  3/3\n"));
+}
+
+#[test]
+fn mask_formatter_keeps_the_cursor_position() {
+    let read: Vec<KeyEvent> = text_to_events!("abc")
+        .chain([KeyCode::Left])
+        .chain(text_to_events!("d"))
+        .chain([KeyCode::Enter])
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+    let ans = Password::new("PIN:")
+        .without_confirmation()
+        .with_display_mode(PasswordDisplayMode::Masked)
+        .with_mask_formatter(&|input| {
+            let hidden = input.chars().count().saturating_sub(1);
+            format!(
+                "{}{}",
+                "*".repeat(hidden),
+                input.chars().last().unwrap_or(' ')
+            )
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    assert_eq!("abdc", ans);
+    drop(backend);
+
+    // the cursor stays before the last character after typing "d"
+    let output = String::from_utf8(write).unwrap();
+    assert!(output.contains("? PIN: ***c\u{1b}[K\r\n\u{1b}[K\u{1b}[1A\u{1b}[11G"));
+}
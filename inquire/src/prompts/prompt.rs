@@ -1,6 +1,18 @@
 //! Definitions of common behavior shared amongst all different prompt types.
 
-use crate::{error::InquireResult, input::InputActionResult, ui::CommonBackend, InquireError};
+use std::time::{Duration, Instant};
+
+use crate::{
+    config::InterruptBehavior,
+    error::InquireResult,
+    events::{PromptEvent, PromptEventKind},
+    handle::PromptHandle,
+    input::InputActionResult,
+    report::PromptReport,
+    type_aliases::EventListener,
+    ui::{Block, CommonBackend, Event, Key},
+    InquireError,
+};
 
 use super::action::{Action, InnerAction};
 
@@ -14,6 +26,10 @@ pub enum ActionResult {
     /// The action either didn't result in a state change or the state
     /// change does not require a redraw.
     Clean,
+
+    /// The action completed the answer, which is submitted just like when the
+    /// user presses enter.
+    Submit,
 }
 
 impl From<InputActionResult> for ActionResult {
@@ -26,6 +42,32 @@ impl From<InputActionResult> for ActionResult {
     }
 }
 
+/// Interval in which busy prompts are re-rendered while waiting on background work.
+const BUSY_REFRESH_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Interval in which prompts listening for updates or with a handle check for
+/// them while no key is pressed.
+const UPDATE_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Time a prompt waits for the user to start interacting with it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Timeout {
+    /// Time to wait for the first key press.
+    pub duration: Duration,
+
+    /// Whether to render the remaining time below the prompt.
+    pub countdown: bool,
+}
+
+impl Timeout {
+    pub fn new(duration: Option<Duration>, countdown: bool) -> Option<Self> {
+        duration.map(|duration| Self {
+            duration,
+            countdown,
+        })
+    }
+}
+
 /// Shared behavior among all different prompt types.
 pub trait Prompt<Backend, Config, IAction, ReturnType>
 where
@@ -65,6 +107,97 @@ where
         Ok(true)
     }
 
+    /// What happens when the user presses Ctrl+C.
+    fn interrupt_behavior(&self) -> InterruptBehavior {
+        InterruptBehavior::default()
+    }
+
+    /// Timeout applied until the user presses the first key, if any.
+    fn timeout(&self) -> Option<Timeout> {
+        None
+    }
+
+    /// Hook called when the timeout of the prompt expires before any user interaction.
+    ///
+    /// Returns the answer to be automatically submitted, or `None` when the
+    /// prompt has no default answer, in which case [`InquireError::Timeout`]
+    /// is returned to the caller.
+    fn submit_on_timeout(&mut self) -> InquireResult<Option<ReturnType>> {
+        Ok(None)
+    }
+
+    /// Whether the prompt is waiting on background work started on submission,
+    /// such as a validation running on a worker thread.
+    ///
+    /// While busy, the prompt is periodically re-rendered and only reacts to
+    /// cancellations and interruptions.
+    fn is_busy(&self) -> bool {
+        false
+    }
+
+    /// Interval at which the prompt is re-rendered while no key is pressed, if
+    /// any.
+    fn refresh_interval(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Whether the prompt can be updated while open, such as a list prompt
+    /// receiving new options, in which case keys are polled so that updates are
+    /// applied while the user is idle.
+    fn listens_for_updates(&self) -> bool {
+        false
+    }
+
+    /// Applies the updates received since the last call, returning whether the
+    /// prompt must be re-rendered.
+    fn apply_updates(&mut self) -> bool {
+        false
+    }
+
+    /// Handle aborting the prompt from outside of it, if any.
+    fn prompt_handle(&self) -> Option<&PromptHandle> {
+        None
+    }
+
+    /// Listener notified of the events of the prompt, if any.
+    fn event_listener(&self) -> Option<EventListener<'_>> {
+        None
+    }
+
+    /// Content rendered above the prompt, if any.
+    fn header(&self) -> Option<&Block> {
+        None
+    }
+
+    /// Content rendered below the prompt, if any.
+    fn footer(&self) -> Option<&Block> {
+        None
+    }
+
+    /// Notifies the event listener of the prompt, if any.
+    fn emit(&self, kind: PromptEventKind<'_>) {
+        if let Some(listener) = self.event_listener() {
+            listener(&PromptEvent {
+                message: self.message(),
+                kind,
+            });
+        }
+    }
+
+    /// Whether submitting now would answer with the default answer of the
+    /// prompt, as reported in [`PromptReport::default_accepted`].
+    fn is_default_answer(&self) -> bool {
+        false
+    }
+
+    /// Waits up to `timeout` for the background work of a busy prompt.
+    ///
+    /// Returns `Some(ReturnType)` when the work finished and accepted the answer.
+    fn poll_background(&mut self, timeout: Duration) -> InquireResult<Option<ReturnType>> {
+        let _ = timeout;
+        Ok(None)
+    }
+
     /// Hook called when the user submits the answer to the prompt.
     ///
     /// On success, it should return `Some(ReturnType)` when the user
@@ -98,28 +231,200 @@ where
     ///
     /// This should not be reimplemented by types that implement this trait,
     /// unless the situation really warrants it.
-    fn prompt(mut self, backend: &mut Backend) -> InquireResult<ReturnType> {
+    fn prompt(self, backend: &mut Backend) -> InquireResult<ReturnType> {
+        self.prompt_with_report(backend).map(|(answer, _)| answer)
+    }
+
+    /// Same as [`prompt`](Self::prompt), also reporting how the user
+    /// interacted with the prompt.
+    fn prompt_with_report(
+        mut self,
+        backend: &mut Backend,
+    ) -> InquireResult<(ReturnType, PromptReport)> {
+        let started = Instant::now();
+        let mut report = PromptReport::default();
+
+        let answer = self.run(backend, &mut report)?;
+        report.elapsed = started.elapsed();
+
+        Ok((answer, report))
+    }
+
+    /// Loop reading keys and rendering the prompt until it is answered.
+    fn run(
+        &mut self,
+        backend: &mut Backend,
+        report: &mut PromptReport,
+    ) -> InquireResult<ReturnType> {
         self.setup()?;
 
+        let mut timeout = self
+            .timeout()
+            .map(|timeout| (Instant::now() + timeout.duration, timeout.countdown));
+
+        // terminals unable to wait for keys with a timeout would block anyway
+        let can_poll_keys = backend.can_poll_keys();
+        let refresh = self.refresh_interval().filter(|_| can_poll_keys);
+        let update_poll =
+            match (self.listens_for_updates() || self.prompt_handle().is_some()) && can_poll_keys {
+                true => Some(UPDATE_POLL_INTERVAL),
+                false => None,
+            };
+        let wake_up = match (refresh, update_poll) {
+            (Some(refresh), Some(update_poll)) => Some(refresh.min(update_poll)),
+            (refresh, update_poll) => refresh.or(update_poll),
+        };
+
         let mut last_handle = ActionResult::NeedsRedraw;
         let final_answer = loop {
+            let remaining = timeout.map(|(deadline, countdown)| {
+                (
+                    deadline.saturating_duration_since(Instant::now()),
+                    countdown,
+                )
+            });
+
+            if matches!(self.prompt_handle(), Some(handle) if handle.is_aborted()) {
+                backend.frame_setup()?;
+                backend.render_canceled_prompt(self.message())?;
+                backend.frame_finish()?;
+                self.emit(PromptEventKind::Canceled);
+                return Err(InquireError::OperationCanceled);
+            }
+
+            if self.apply_updates() {
+                last_handle = ActionResult::NeedsRedraw;
+            }
+
             if let ActionResult::NeedsRedraw = last_handle {
                 backend.frame_setup()?;
+                if let Some(header) = self.header() {
+                    backend.render_block(header)?;
+                }
                 self.render(backend)?;
+                if let Some((remaining, true)) = remaining {
+                    backend.render_help_message(&format!(
+                        "{}s left to answer",
+                        ceil_secs(remaining)
+                    ))?;
+                }
+                if let Some(footer) = self.footer() {
+                    backend.render_block(footer)?;
+                }
                 backend.frame_finish()?;
+                self.emit(PromptEventKind::Rendered);
                 last_handle = ActionResult::Clean;
             }
 
-            let key = backend.read_key()?;
-            let action = Action::from_key(key, self.config());
+            let event = if self.is_busy() {
+                if let Some(answer) = self.poll_background(BUSY_REFRESH_INTERVAL)? {
+                    break answer;
+                }
+                if !self.is_busy() {
+                    report.validation_failures += 1;
+                    backend.render_error_feedback()?;
+                    self.emit(PromptEventKind::ValidationFailed);
+                }
+                last_handle = ActionResult::NeedsRedraw;
+
+                // once the work is done, go back to waiting for keys as usual
+                if !self.is_busy() || !backend.can_poll_keys() {
+                    continue;
+                }
+                match backend.poll_event(Duration::ZERO)? {
+                    Some(event) => event,
+                    None => continue,
+                }
+            } else {
+                match remaining {
+                    Some((remaining, _)) if remaining == Duration::ZERO => {
+                        if let Some(answer) = self.submit_on_timeout()? {
+                            report.default_accepted = true;
+                            break answer;
+                        }
+
+                        backend.frame_setup()?;
+                        backend.render_canceled_prompt(self.message())?;
+                        backend.frame_finish()?;
+                        self.emit(PromptEventKind::TimedOut);
+                        return Err(InquireError::Timeout);
+                    }
+                    Some((remaining, countdown)) => {
+                        // with a countdown, wake up whenever the displayed seconds change
+                        let wait = match countdown {
+                            true => remaining - Duration::from_secs(ceil_secs(remaining) - 1),
+                            false => remaining,
+                        };
+                        let wait = wake_up.map_or(wait, |wake_up| wait.min(wake_up));
+
+                        match backend.poll_event(wait)? {
+                            Some(event) => event,
+                            None => {
+                                if countdown || refresh.is_some() {
+                                    last_handle = ActionResult::NeedsRedraw;
+                                }
+                                continue;
+                            }
+                        }
+                    }
+                    None => match wake_up {
+                        Some(wake_up) => match backend.poll_event(wake_up)? {
+                            Some(event) => event,
+                            None => {
+                                if refresh.is_some() {
+                                    last_handle = ActionResult::NeedsRedraw;
+                                }
+                                continue;
+                            }
+                        },
+                        None => backend.read_event()?,
+                    },
+                }
+            };
+
+            let key = match event {
+                Event::Key(key) if backend.is_input_right_to_left() => mirror_arrow_keys(key),
+                Event::Key(key) => key,
+                // the backend adapts its layout to the new size on the next frame
+                Event::Resize => {
+                    last_handle = ActionResult::NeedsRedraw;
+                    continue;
+                }
+            };
+            report.keystrokes += 1;
+            self.emit(PromptEventKind::KeyPressed(key));
+            // the first key press stops the timeout
+            timeout = None;
+
+            let action = match Action::from_key(key, self.config()) {
+                Some(Action::Interrupt)
+                    if self.interrupt_behavior() == InterruptBehavior::Cancel =>
+                {
+                    Some(Action::Cancel)
+                }
+                action => action,
+            };
+
+            if self.is_busy() && !matches!(action, Some(Action::Cancel) | Some(Action::Interrupt)) {
+                continue;
+            }
 
             if let Some(action) = action {
                 last_handle = match action {
                     Action::Submit => {
+                        report.default_accepted = self.is_default_answer();
                         if let Some(answer) = self.submit()? {
                             break answer;
                         }
-                        ActionResult::Clean
+                        match self.is_busy() {
+                            true => ActionResult::NeedsRedraw,
+                            false => {
+                                report.validation_failures += 1;
+                                backend.render_error_feedback()?;
+                                self.emit(PromptEventKind::ValidationFailed);
+                                ActionResult::Clean
+                            }
+                        }
                     }
                     Action::Cancel => {
                         let pre_cancel_result = self.pre_cancel()?;
@@ -128,14 +433,54 @@ where
                             backend.frame_setup()?;
                             backend.render_canceled_prompt(self.message())?;
                             backend.frame_finish()?;
+                            self.emit(PromptEventKind::Canceled);
                             return Err(InquireError::OperationCanceled);
                         }
 
                         ActionResult::NeedsRedraw
                     }
-                    Action::Interrupt => return Err(InquireError::OperationInterrupted),
-                    Action::Inner(inner_action) => self.handle(inner_action)?,
+                    Action::Interrupt => {
+                        #[cfg(unix)]
+                        if self.interrupt_behavior() == InterruptBehavior::RaiseSignal {
+                            backend.frame_setup()?;
+                            backend.render_canceled_prompt(self.message())?;
+                            backend.frame_finish()?;
+
+                            crate::terminal::restore_terminal();
+                            crate::terminal::raise_interrupt_signal();
+                        }
+
+                        self.emit(PromptEventKind::Interrupted);
+                        return Err(InquireError::OperationInterrupted);
+                    }
+                    #[cfg(all(unix, feature = "suspend"))]
+                    Action::Suspend => {
+                        backend.suspend()?;
+                        ActionResult::NeedsRedraw
+                    }
+                    Action::Inner(inner_action) => match self.handle(inner_action)? {
+                        ActionResult::Submit => {
+                            report.default_accepted = self.is_default_answer();
+                            if let Some(answer) = self.submit()? {
+                                break answer;
+                            }
+                            if !self.is_busy() {
+                                report.validation_failures += 1;
+                                backend.render_error_feedback()?;
+                                self.emit(PromptEventKind::ValidationFailed);
+                            }
+                            ActionResult::NeedsRedraw
+                        }
+                        result => result,
+                    },
                 };
+            } else {
+                backend.render_error_feedback()?;
+            }
+
+            // the first key press stops the timeout, so the countdown must be cleared
+            if remaining.is_some() && timeout.is_none() {
+                last_handle = ActionResult::NeedsRedraw;
             }
         };
 
@@ -144,7 +489,22 @@ where
         backend.frame_setup()?;
         backend.render_prompt_with_answer(self.message(), &formatted)?;
         backend.frame_finish()?;
+        self.emit(PromptEventKind::Submitted(&formatted));
 
         Ok(final_answer)
     }
 }
+
+/// Swaps the left and right arrow keys, so that they move the cursor of
+/// right-to-left input in the direction of the pressed key.
+fn mirror_arrow_keys(key: Key) -> Key {
+    match key {
+        Key::Left(modifiers) => Key::Right(modifiers),
+        Key::Right(modifiers) => Key::Left(modifiers),
+        key => key,
+    }
+}
+
+fn ceil_secs(duration: Duration) -> u64 {
+    duration.as_secs() + u64::from(duration.subsec_nanos() > 0)
+}
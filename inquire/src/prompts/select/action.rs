@@ -22,6 +22,11 @@ pub enum SelectPromptAction {
     MoveToStart,
     /// Moves the cursor to the end of the list.
     MoveToEnd,
+    /// Sorts the options by the column at the given index, or reverses the
+    /// order if they are already sorted by it. In table mode only.
+    SortByColumn(usize),
+    /// Restores the original order of the options. In table mode only.
+    ClearSort,
 }
 
 impl InnerAction<SelectConfig> for SelectPromptAction {
@@ -40,13 +45,20 @@ impl InnerAction<SelectConfig> for SelectPromptAction {
 
         let action = match key {
             Key::Up(KeyModifiers::NONE) => Self::MoveUp,
-            Key::PageUp => Self::PageUp,
+            Key::PageUp(_) => Self::PageUp,
             Key::Home => Self::MoveToStart,
 
             Key::Down(KeyModifiers::NONE) => Self::MoveDown,
-            Key::PageDown => Self::PageDown,
+            Key::PageDown(_) => Self::PageDown,
             Key::End => Self::MoveToEnd,
 
+            Key::Char('0', KeyModifiers::ALT) if config.table_columns > 0 => Self::ClearSort,
+            Key::Char(c @ '1'..='9', KeyModifiers::ALT)
+                if (c as usize - '1' as usize) < config.table_columns =>
+            {
+                Self::SortByColumn(c as usize - '1' as usize)
+            }
+
             key => match InputAction::from_key(key, &()) {
                 Some(action) => Self::FilterInput(action),
                 None => return None,
@@ -1,4 +1,6 @@
-use crate::Select;
+use std::time::Duration;
+
+use crate::{config::InterruptBehavior, prompts::prompt::Timeout, ui::IndexPrefix, Select};
 
 /// Configuration settings used in the execution of a SelectPrompt.
 #[derive(Copy, Clone, Debug)]
@@ -7,6 +9,32 @@ pub struct SelectConfig {
     pub vim_mode: bool,
     /// Page size of the list of options.
     pub page_size: usize,
+    /// Number of options kept visible around the cursor, if any.
+    pub scroll_margin: Option<usize>,
+    /// Whether the cursor wraps around the ends of the list.
+    pub wrap_around: bool,
+    /// Whether the position of the cursor is rendered below the options.
+    pub position_indicator: bool,
+    /// Whether options are paged section by section.
+    pub section_paging: bool,
+    /// Minimum filter input length for options to be displayed, if the amount
+    /// of options puts the prompt in the search-only state.
+    pub search_min_input_length: Option<usize>,
+    /// Whether typing an index highlights the option instead of filtering.
+    pub index_selection: bool,
+    /// Index prefix rendered before the options in place of the one of the
+    /// render config, set for index selection when the render config has none.
+    pub option_index_prefix: Option<IndexPrefix>,
+    /// Number of columns the options are laid out in, 0 when not in table mode.
+    pub table_columns: usize,
+    /// Whether the header row of the table is rendered above the options.
+    pub table_header: bool,
+    /// What happens when the user presses Ctrl+C.
+    pub interrupt_behavior: InterruptBehavior,
+    /// Timeout applied until the first key press.
+    pub timeout: Option<Timeout>,
+    /// Interval at which the prompt is re-rendered without key presses.
+    pub refresh_interval: Option<Duration>,
 }
 
 impl<T> From<&Select<'_, T>> for SelectConfig {
@@ -14,6 +42,24 @@ impl<T> From<&Select<'_, T>> for SelectConfig {
         Self {
             vim_mode: value.vim_mode,
             page_size: value.page_size,
+            scroll_margin: value.scroll_margin,
+            wrap_around: value.wrap_around,
+            position_indicator: value.position_indicator,
+            section_paging: value.section_paging && value.group_by.is_some(),
+            search_min_input_length: value
+                .search_only_threshold
+                .filter(|threshold| value.options.len() > *threshold)
+                .map(|_| value.search_min_input_length),
+            index_selection: value.index_selection,
+            option_index_prefix: match value.render_config.option_index_prefix {
+                IndexPrefix::None if value.index_selection => Some(IndexPrefix::Simple),
+                _ => None,
+            },
+            table_columns: value.columns.as_ref().map_or(0, |c| c.headers.len()),
+            table_header: value.table_header,
+            interrupt_behavior: value.interrupt_behavior,
+            timeout: Timeout::new(value.timeout, value.timeout_countdown),
+            refresh_interval: value.refresh_interval,
         }
     }
 }
@@ -7,17 +7,24 @@ mod test;
 
 pub use action::*;
 
-use std::fmt::Display;
+use std::{fmt::Display, sync::mpsc::Receiver, time::Duration};
 
 use crate::{
-    config::get_configuration,
+    config::{
+        get_configuration, get_event_listener, get_global_options, get_interrupt_behavior,
+        InterruptBehavior,
+    },
     error::{InquireError, InquireResult},
     formatter::OptionFormatter,
-    list_option::ListOption,
-    prompts::prompt::Prompt,
-    terminal::get_default_terminal,
-    type_aliases::Filter,
-    ui::{Backend, RenderConfig, SelectBackend},
+    handle::PromptHandle,
+    list_option::{Choice, ListOption},
+    prompts::{prompt::Prompt, TableColumns},
+    report::PromptReport,
+    terminal::{get_default_terminal, Terminal},
+    type_aliases::{
+        Badger, Displayer, EventListener, Filter, FreeFormAnswer, Grouper, HelpMessageFn, Scorer,
+    },
+    ui::{Backend, Block, FinalRender, RenderConfig, SelectBackend},
 };
 
 use self::prompt::SelectPrompt;
@@ -26,11 +33,13 @@ use self::prompt::SelectPrompt;
 ///
 /// The user can select and submit the current highlighted option by pressing enter.
 ///
-/// This prompt requires a prompt message and a **non-empty** `Vec` of options to be displayed to the user. The options can be of any type as long as they implement the `Display` trait. It is required that the `Vec` is moved to the prompt, as the prompt will return the selected option (`Vec` element) after the user submits.
+/// This prompt requires a prompt message and a **non-empty** `Vec` of options to be displayed to the user. The options can be of any type as long as they implement the `Display` trait, or are rendered by a function given to [`new_with_display`](Select::new_with_display). It is required that the `Vec` is moved to the prompt, as the prompt will return the selected option (`Vec` element) after the user submits.
 /// - If the list is empty, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
 ///
 /// This prompt does not support custom validators because of its nature. A submission always selects exactly one of the options. If this option was not supposed to be selected or is invalid in some way, it probably should not be included in the options list.
 ///
+/// It is also the table selection prompt: with [`with_columns`](Select::with_columns), options made of several values, such as deployments or pods, are laid out as aligned columns below a header row, and the selected record is returned as is.
+///
 /// The options are paginated in order to provide a smooth experience to the user, with the default page size being 7. The user can move from the options and the pages will be updated accordingly, including moving from the last to the first options (or vice-versa).
 ///
 /// Like all others, this prompt also allows you to customize several aspects of it:
@@ -44,6 +53,12 @@ use self::prompt::SelectPrompt;
 /// - **Page size**: Number of options displayed at once, 7 by default.
 /// - **Display option indexes**: On long lists, it might be helpful to display the indexes of the options to the user. Via the `RenderConfig`, you can set the display mode of the indexes as a prefix of an option. The default configuration is `None`, to not render any index when displaying the options.
 /// - **Filter function**: Function that defines if an option is displayed or not based on the current filter input.
+/// - **Grouping function**: Function that assigns options to named groups, used to page through the list section by section.
+/// - **Search-only threshold**: Number of options above which no options are displayed until the user types a minimum amount of characters to filter them.
+/// - **Badges**: Function that computes a status badge for each option, rendered in a column before the label and colored according to the palette of the render config, see [`Badge`](crate::ui::Badge).
+/// - **Hotkeys**: Keys that immediately select and submit an option when pressed, rendered in brackets before the options. Set with [`Select::new_with_keys`].
+/// - **Index selection**: Whether the user can highlight an option by typing its index, displayed before each option, instead of filtering the options.
+/// - **Table columns**: Columns in which the options are laid out as a table, with an optional header row. The user can sort the rows by a column at runtime, see [`TableColumns`]. Can not be combined with a grouping function.
 ///
 /// # Example
 ///
@@ -74,9 +89,35 @@ pub struct Select<'a, T> {
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
+    /// Function computing the help message from the current state of the
+    /// prompt, replacing [`help_message`](Self::help_message) when set.
+    ///
+    /// See [`with_help_message_fn`](Self::with_help_message_fn) for more details.
+    pub help_message_fn: Option<HelpMessageFn<'a>>,
+
+    /// Content rendered above the prompt.
+    pub header: Option<Block>,
+
+    /// Content rendered below the prompt.
+    pub footer: Option<Block>,
+
     /// Page size of the options displayed to the user.
     pub page_size: usize,
 
+    /// Number of options kept visible between the highlighted option and the
+    /// edges of the page, if any.
+    ///
+    /// See [`with_scroll_margin`](Self::with_scroll_margin) for more details.
+    pub scroll_margin: Option<usize>,
+
+    /// Whether moving past the last option highlights the first one, and
+    /// vice-versa.
+    pub wrap_around: bool,
+
+    /// Whether the position of the highlighted option, e.g. `3/120`, is
+    /// rendered below the options.
+    pub position_indicator: bool,
+
     /// Whether vim mode is enabled. When enabled, the user can
     /// navigate through the options using hjkl.
     pub vim_mode: bool,
@@ -84,12 +125,134 @@ pub struct Select<'a, T> {
     /// Starting cursor index of the selection.
     pub starting_cursor: usize,
 
+    /// Function that renders each option as the text displayed to the user,
+    /// also matched by the filter.
+    pub display: Displayer<'a, T>,
+
     /// Function called with the current user input to filter the provided
     /// options.
     pub filter: Filter<'a, T>,
 
+    /// Function called with the current user input to filter the provided
+    /// options and order them by relevance, used instead of the filter when set.
+    ///
+    /// See [`with_scorer`](Self::with_scorer) for more details.
+    pub scorer: Option<Scorer<'a, T>>,
+
+    /// Whether the options matching the previous input are the only ones
+    /// matched again when the user appends characters to it.
+    ///
+    /// See [`with_incremental_filtering`](Self::with_incremental_filtering) for more details.
+    pub incremental_filtering: bool,
+
+    /// Whether the filter is given the lowercased input and string values of
+    /// the options, the latter being computed once when the prompt starts.
+    ///
+    /// See [`with_lowercase_cache`](Self::with_lowercase_cache) for more details.
+    pub lowercase_cache: bool,
+
+    /// Message rendered in place of the options when the filter input matches
+    /// none of them, if any.
+    ///
+    /// See [`with_no_matches_message`](Self::with_no_matches_message) for more details.
+    pub no_matches_message: Option<&'a str>,
+
+    /// Function turning the filter input into the answer when it matches no
+    /// option, if any.
+    ///
+    /// See [`with_free_form_answer`](Self::with_free_form_answer) for more details.
+    pub free_form_answer: Option<FreeFormAnswer<'a, T>>,
+
+    /// Whether an entry creating the answer from the filter input with the
+    /// [free-form answer function](Self::free_form_answer) is rendered after
+    /// the matching options.
+    ///
+    /// See [`with_allow_custom`](Self::with_allow_custom) for more details.
+    pub allow_custom: bool,
+
+    /// Label of the entry creating a custom answer, in which `{filter}` is
+    /// replaced by the filter input.
+    pub custom_entry_label: &'a str,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
-    pub formatter: OptionFormatter<'a, T>,
+    ///
+    /// When not set, the selected option is printed as it is displayed in the list.
+    pub formatter: Option<OptionFormatter<'a, T>>,
+
+    /// Function that assigns each option to a group. Consecutive options
+    /// in the same group form a section.
+    pub group_by: Option<Grouper<'a, T>>,
+
+    /// Whether the options are paged section by section instead of by a fixed
+    /// amount of options. Only has an effect when a grouping function is set.
+    ///
+    /// When enabled, PageUp and PageDown jump between sections.
+    pub section_paging: bool,
+
+    /// Number of options above which the prompt starts in a search-only state,
+    /// displaying no options until the filter input has at least
+    /// [`search_min_input_length`](Self::search_min_input_length) characters.
+    pub search_only_threshold: Option<usize>,
+
+    /// Minimum length of the filter input for options to be displayed in the
+    /// search-only state.
+    pub search_min_input_length: usize,
+
+    /// Keys that immediately select and submit the option at the same index
+    /// when pressed, empty if the options have no hotkeys.
+    ///
+    /// See [`new_with_keys`](Self::new_with_keys) for more details.
+    pub hotkeys: Vec<char>,
+
+    /// Whether typing the index of an option highlights it instead of
+    /// filtering the options.
+    ///
+    /// See [`with_index_selection`](Self::with_index_selection) for more details.
+    pub index_selection: bool,
+
+    /// Function that computes the badge rendered before the label of each
+    /// option, if any.
+    pub badges: Option<Badger<'a, T>>,
+
+    /// Columns in which the options are laid out as a table, if any.
+    pub columns: Option<TableColumns<'a, T>>,
+
+    /// Whether the header row of the table is rendered above the options, when
+    /// laid out as a table.
+    pub table_header: bool,
+
+    /// Channel through which options are added while the prompt is open, if
+    /// any.
+    ///
+    /// See [`with_option_receiver`](Self::with_option_receiver) for more details.
+    pub option_receiver: Option<&'a Receiver<T>>,
+
+    /// What happens when the user presses Ctrl+C.
+    pub interrupt_behavior: InterruptBehavior,
+
+    /// Listener notified of the events of the prompt, such as key presses and
+    /// submissions.
+    ///
+    /// See [`with_event_listener`](Self::with_event_listener) for more details.
+    pub event_listener: Option<EventListener<'a>>,
+
+    /// Handle aborting the prompt from outside of it, if any.
+    ///
+    /// See [`with_handle`](Self::with_handle) for more details.
+    pub handle: Option<&'a PromptHandle>,
+
+    /// Time to wait for the user to start interacting with the prompt.
+    ///
+    /// See [`with_timeout`](Self::with_timeout) for more details.
+    pub timeout: Option<Duration>,
+
+    /// Whether to render the time left before the timeout expires.
+    pub timeout_countdown: bool,
+
+    /// Interval at which the prompt is re-rendered while no key is pressed.
+    ///
+    /// See [`with_refresh_interval`](Self::with_refresh_interval) for more details.
+    pub refresh_interval: Option<Duration>,
 
     /// RenderConfig to apply to the rendered interface.
     ///
@@ -121,6 +284,45 @@ where
     /// ```
     pub const DEFAULT_FORMATTER: OptionFormatter<'a, T> = &|ans| ans.to_string();
 
+    /// Function used by default to render the options, which simply prints
+    /// their string value.
+    pub const DEFAULT_DISPLAY: Displayer<'a, T> = &|option| option.to_string();
+
+    /// Creates a [Select] with the provided message and options, along with default configuration values.
+    pub fn new(message: &'a str, options: Vec<T>) -> Self {
+        Self::new_with_display(message, options, Self::DEFAULT_DISPLAY)
+    }
+
+    /// Creates a [Select] whose options are each assigned a hotkey, like classic
+    /// terminal menus: pressing the key of an option immediately selects and
+    /// submits it. Hotkeys are rendered in brackets before the options.
+    ///
+    /// Hotkeys take precedence over filtering, the options can still be
+    /// filtered by typing other characters.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// let action = Select::new_with_keys(
+    ///     "What now?",
+    ///     vec![('d', "Deploy"), ('r', "Rollback"), ('q', "Quit")],
+    /// )
+    /// .prompt()?;
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn new_with_keys(message: &'a str, options: Vec<(char, T)>) -> Self {
+        let (hotkeys, options) = options.into_iter().unzip();
+
+        Self {
+            hotkeys,
+            ..Self::new(message, options)
+        }
+    }
+}
+
+impl<'a, T> Select<'a, T> {
     /// Default filter function, which checks if the current filter value is a substring of the option value.
     /// If it is, the option is displayed.
     ///
@@ -145,11 +347,17 @@ where
     /// assert_eq!(true,  filter("sa", &"San Jose",      "San Jose",     12));
     /// ```
     pub const DEFAULT_FILTER: Filter<'a, T> = &|filter, _, string_value, _| -> bool {
-        let filter = filter.to_lowercase();
+        let filter = crate::utils::to_lowercase(filter);
 
-        string_value.to_lowercase().contains(&filter)
+        crate::utils::to_lowercase(string_value).contains(filter.as_ref())
     };
 
+    /// Default value of incremental filtering.
+    pub const DEFAULT_INCREMENTAL_FILTERING: bool = false;
+
+    /// Default value of the lowercase cache.
+    pub const DEFAULT_LOWERCASE_CACHE: bool = false;
+
     /// Default page size.
     pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
 
@@ -159,21 +367,93 @@ where
     /// Default starting cursor index.
     pub const DEFAULT_STARTING_CURSOR: usize = 0;
 
+    /// Default value of wrap around.
+    pub const DEFAULT_WRAP_AROUND: bool = true;
+
+    /// Default value of the position indicator.
+    pub const DEFAULT_POSITION_INDICATOR: bool = false;
+
+    /// Default value of the table header.
+    pub const DEFAULT_TABLE_HEADER: bool = true;
+
+    /// Default value of section paging.
+    pub const DEFAULT_SECTION_PAGING: bool = false;
+
+    /// Default value of allowing custom answers.
+    pub const DEFAULT_ALLOW_CUSTOM: bool = false;
+
+    /// Default label of the entry creating a custom answer.
+    pub const DEFAULT_CUSTOM_ENTRY_LABEL: &'a str = "Create '{filter}'";
+
+    /// Default minimum length of the filter input in the search-only state.
+    pub const DEFAULT_SEARCH_MIN_INPUT_LENGTH: usize = 1;
+
     /// Default help message.
     pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
         Some("↑↓ to move, enter to select, type to filter");
 
-    /// Creates a [Select] with the provided message and options, along with default configuration values.
-    pub fn new(message: &'a str, options: Vec<T>) -> Self {
+    /// Creates a [Select] over options that do not implement [`Display`], each
+    /// option being rendered by the given function instead.
+    ///
+    /// The rendered text is also the one matched by the filter and, unless a
+    /// formatter is set, printed as the final answer.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::PathBuf;
+    /// use inquire::Select;
+    ///
+    /// let paths = vec![PathBuf::from("Cargo.toml"), PathBuf::from("src/lib.rs")];
+    ///
+    /// let path = Select::new_with_display("Open file:", paths, &|path| {
+    ///     path.display().to_string()
+    /// })
+    /// .prompt()?;
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn new_with_display(message: &'a str, options: Vec<T>, display: Displayer<'a, T>) -> Self {
+        let global = get_global_options();
+
         Self {
             message,
             options,
-            help_message: Self::DEFAULT_HELP_MESSAGE,
-            page_size: Self::DEFAULT_PAGE_SIZE,
-            vim_mode: Self::DEFAULT_VIM_MODE,
+            help_message: global.help_message(Self::DEFAULT_HELP_MESSAGE),
+            help_message_fn: None,
+            header: None,
+            footer: None,
+            page_size: global.page_size,
+            scroll_margin: None,
+            wrap_around: Self::DEFAULT_WRAP_AROUND,
+            position_indicator: Self::DEFAULT_POSITION_INDICATOR,
+            vim_mode: global.vim_mode,
             starting_cursor: Self::DEFAULT_STARTING_CURSOR,
+            display,
             filter: Self::DEFAULT_FILTER,
-            formatter: Self::DEFAULT_FORMATTER,
+            scorer: None,
+            incremental_filtering: Self::DEFAULT_INCREMENTAL_FILTERING,
+            lowercase_cache: Self::DEFAULT_LOWERCASE_CACHE,
+            no_matches_message: None,
+            free_form_answer: None,
+            allow_custom: Self::DEFAULT_ALLOW_CUSTOM,
+            custom_entry_label: Self::DEFAULT_CUSTOM_ENTRY_LABEL,
+            formatter: None,
+            group_by: None,
+            section_paging: Self::DEFAULT_SECTION_PAGING,
+            search_only_threshold: None,
+            search_min_input_length: Self::DEFAULT_SEARCH_MIN_INPUT_LENGTH,
+            hotkeys: vec![],
+            index_selection: false,
+            badges: None,
+            columns: None,
+            table_header: Self::DEFAULT_TABLE_HEADER,
+            option_receiver: None,
+            interrupt_behavior: get_interrupt_behavior(),
+            event_listener: get_event_listener(),
+            handle: None,
+            timeout: None,
+            timeout_countdown: false,
+            refresh_interval: None,
             render_config: get_configuration(),
         }
     }
@@ -184,6 +464,30 @@ where
         self
     }
 
+    /// Sets a function computing the help message from the current state of the
+    /// prompt, called whenever it is rendered. It takes precedence over the
+    /// static help message, and returning `None` hides the help line.
+    ///
+    /// See [`PromptState`](crate::PromptState) for the state given to the function.
+    pub fn with_help_message_fn(mut self, help_message_fn: HelpMessageFn<'a>) -> Self {
+        self.help_message_fn = Some(help_message_fn);
+        self
+    }
+
+    /// Sets the content rendered above the prompt, such as the current git
+    /// branch. It is redrawn and cleared along with the prompt, see [`Block`].
+    pub fn with_header<H: Into<Block>>(mut self, header: H) -> Self {
+        self.header = Some(header.into());
+        self
+    }
+
+    /// Sets the content rendered below the prompt. It is redrawn and cleared
+    /// along with the prompt, see [`Block`].
+    pub fn with_footer<F: Into<Block>>(mut self, footer: F) -> Self {
+        self.footer = Some(footer.into());
+        self
+    }
+
     /// Removes the set help message.
     pub fn without_help_message(mut self) -> Self {
         self.help_message = None;
@@ -196,6 +500,30 @@ where
         self
     }
 
+    /// Sets the number of options kept visible between the highlighted option
+    /// and the edges of the page: the page only scrolls once the cursor gets
+    /// closer than that to them. The margin is capped to half of the page.
+    ///
+    /// When not set, the highlighted option is kept in the middle of the page.
+    pub fn with_scroll_margin(mut self, scroll_margin: usize) -> Self {
+        self.scroll_margin = Some(scroll_margin);
+        self
+    }
+
+    /// Sets whether moving past the last option highlights the first one, and
+    /// vice-versa. When disabled, the cursor stops at the ends of the list.
+    pub fn with_wrap_around(mut self, wrap_around: bool) -> Self {
+        self.wrap_around = wrap_around;
+        self
+    }
+
+    /// Sets whether the position of the highlighted option among the displayed
+    /// ones, e.g. `3/120`, is rendered below the options.
+    pub fn with_position_indicator(mut self, position_indicator: bool) -> Self {
+        self.position_indicator = position_indicator;
+        self
+    }
+
     /// Enables or disables vim_mode.
     pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
         self.vim_mode = vim_mode;
@@ -208,9 +536,293 @@ where
         self
     }
 
+    /// Sets the scoring function, filtering the options like a filter function
+    /// and displaying the matching ones by descending score, see [`Scorer`].
+    /// It takes precedence over the filter function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inquire::{terminal::TestBackend, Select};
+    ///
+    /// let mut terminal = TestBackend::new(vec![]).with_text("de\n");
+    /// let ans = Select::new("Command:", vec!["undeploy", "describe", "deploy"])
+    ///     .with_scorer(&|input, _, value, _| {
+    ///         // Prefix matches first, shorter commands first.
+    ///         let position = value.find(input)? as i64;
+    ///         Some(-position * 100 - value.len() as i64)
+    ///     })
+    ///     .prompt_with_terminal(&mut terminal)?;
+    ///
+    /// assert_eq!("deploy", ans);
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn with_scorer(mut self, scorer: Scorer<'a, T>) -> Self {
+        self.scorer = Some(scorer);
+        self
+    }
+
+    /// Enables or disables incremental filtering, which speeds up filtering
+    /// tens of thousands of options.
+    ///
+    /// When enabled, typing characters at the end of the filter input only
+    /// matches the options that matched the input before them, instead of all
+    /// the options. Any other change to the input, such as a deletion, matches
+    /// all the options again.
+    ///
+    /// This is only correct for filters that never match an option an input
+    /// does not match when given a longer input starting with it, which is the
+    /// case of the [default filter](Self::DEFAULT_FILTER) and of most
+    /// substring or prefix matching filters.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// let words: Vec<String> = (0..50_000).map(|i| format!("word-{}", i)).collect();
+    ///
+    /// let word = Select::new("Word:", words)
+    ///     .with_incremental_filtering(true)
+    ///     .with_lowercase_cache(true)
+    ///     .prompt()?;
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn with_incremental_filtering(mut self, incremental_filtering: bool) -> Self {
+        self.incremental_filtering = incremental_filtering;
+        self
+    }
+
+    /// Enables or disables the lowercase cache, computing the lowercased
+    /// string values of the options once when the prompt starts instead of
+    /// lowercasing them each time the filter input changes.
+    ///
+    /// When enabled, the filter is given the lowercased filter input and string
+    /// value of each option, so it can match them as they are. Options still
+    /// keep their original case when displayed.
+    pub fn with_lowercase_cache(mut self, lowercase_cache: bool) -> Self {
+        self.lowercase_cache = lowercase_cache;
+        self
+    }
+
+    /// Sets the message rendered in place of the options when the filter input
+    /// matches none of them, instead of rendering nothing. Occurrences of
+    /// `{filter}` in the message are replaced by the filter input.
+    ///
+    /// The message is styled with
+    /// [`RenderConfig::no_matches_message`](crate::ui::RenderConfig::no_matches_message).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inquire::{terminal::TestBackend, ui::RenderConfig, Select};
+    ///
+    /// let mut terminal = TestBackend::new(vec![]).with_text("qa");
+    /// let ans = Select::new("Environment:", vec!["staging", "production"])
+    ///     .with_no_matches_message("No environments match '{filter}'")
+    ///     .without_help_message()
+    ///     .with_render_config(RenderConfig::empty())
+    ///     .prompt_with_terminal(&mut terminal);
+    ///
+    /// assert_eq!(
+    ///     "? Environment: qa \nNo environments match 'qa'\n",
+    ///     terminal.last_frame().unwrap()
+    /// );
+    /// # let _ = ans;
+    /// ```
+    pub fn with_no_matches_message(mut self, message: &'a str) -> Self {
+        self.no_matches_message = Some(message);
+        self
+    }
+
+    /// Sets the function turning the filter input into the answer when the
+    /// user submits a filter input matching no option, allowing free-form
+    /// answers. The returned [`ListOption`](crate::list_option::ListOption) of
+    /// such an answer has an index equal to the number of options.
+    ///
+    /// Pair it with a [no matches message](Self::with_no_matches_message) to
+    /// tell the user that the input can be submitted as is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inquire::{terminal::TestBackend, Select};
+    ///
+    /// let mut terminal = TestBackend::new(vec![]).with_text("qa\n");
+    /// let environment = Select::new("Environment:", vec!["staging".to_string()])
+    ///     .with_no_matches_message("Press enter to use '{filter}'")
+    ///     .with_free_form_answer(&|input| input.to_string())
+    ///     .prompt_with_terminal(&mut terminal)?;
+    ///
+    /// assert_eq!("qa", environment);
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn with_free_form_answer(mut self, free_form_answer: FreeFormAnswer<'a, T>) -> Self {
+        self.free_form_answer = Some(free_form_answer);
+        self
+    }
+
+    /// Sets whether an entry creating the answer from the filter input, e.g.
+    /// `Create 'qa'`, is rendered as the last entry while the filter input is
+    /// not empty and is not the exact text of a matching option. Selecting it
+    /// submits the answer returned by the
+    /// [free-form answer function](Self::with_free_form_answer), which must be
+    /// set.
+    ///
+    /// See [`new_or_create`](Self::new_or_create) to get a [`Choice`] telling
+    /// existing options from created ones.
+    pub fn with_allow_custom(mut self, allow_custom: bool) -> Self {
+        self.allow_custom = allow_custom;
+        self
+    }
+
+    /// Sets the label of the entry creating a custom answer, in which
+    /// `{filter}` is replaced by the filter input.
+    pub fn with_custom_entry_label(mut self, label: &'a str) -> Self {
+        self.custom_entry_label = label;
+        self
+    }
+
     /// Sets the formatter.
     pub fn with_formatter(mut self, formatter: OptionFormatter<'a, T>) -> Self {
-        self.formatter = formatter;
+        self.formatter = Some(formatter);
+        self
+    }
+
+    /// Sets the function used to group options into sections.
+    pub fn with_group_by(mut self, group_by: Grouper<'a, T>) -> Self {
+        self.group_by = Some(group_by);
+        self
+    }
+
+    /// Enables or disables paging section by section. Requires a grouping
+    /// function to be set, see [`with_group_by`](Self::with_group_by).
+    pub fn with_section_paging(mut self, section_paging: bool) -> Self {
+        self.section_paging = section_paging;
+        self
+    }
+
+    /// Sets the number of options above which the prompt starts in a search-only
+    /// state, avoiding to render a first page that is of little use among thousands
+    /// of options. Options are only displayed once the user typed at least
+    /// [`search_min_input_length`](Self::with_search_min_input_length) characters.
+    pub fn with_search_only_threshold(mut self, threshold: usize) -> Self {
+        self.search_only_threshold = Some(threshold);
+        self
+    }
+
+    /// Sets the minimum length of the filter input for options to be displayed
+    /// in the search-only state.
+    pub fn with_search_min_input_length(mut self, length: usize) -> Self {
+        self.search_min_input_length = length;
+        self
+    }
+
+    /// Sets whether the user can highlight an option by typing its index, so
+    /// that typing `3` and pressing enter selects the third option. Handy for
+    /// numeric menus over connections where arrow keys are unreliable.
+    ///
+    /// Input made only of digits is read as an index instead of filtering the
+    /// options, any other input filters them as usual. The indices are
+    /// displayed before the options, with
+    /// [`IndexPrefix::Simple`](crate::ui::IndexPrefix::Simple) unless the
+    /// render config already sets an index prefix.
+    pub fn with_index_selection(mut self, index_selection: bool) -> Self {
+        self.index_selection = index_selection;
+        self
+    }
+
+    /// Sets the function that computes the badge of each option, such as `✔`
+    /// for an installed package, rendered in a column before its label.
+    ///
+    /// The function is called once for each option, and the column is as wide as
+    /// the widest badge among all options, so it does not shift while paging.
+    pub fn with_badges(mut self, badges: Badger<'a, T>) -> Self {
+        self.badges = Some(badges);
+        self
+    }
+
+    /// Lays out the options as a table with the given column titles, each option
+    /// split into one cell per column by the `cells` function, turning the
+    /// prompt into a table selection prompt.
+    ///
+    /// The user can sort the rows by the n-th column by pressing Alt+n, and
+    /// restore the original order by pressing Alt+0. See [`TableColumns`].
+    ///
+    /// The filter input is matched against the cells of each option, separated
+    /// by spaces, instead of its string value.
+    pub fn with_columns(
+        mut self,
+        headers: Vec<&'a str>,
+        cells: &'a dyn Fn(&T) -> Vec<String>,
+    ) -> Self {
+        self.columns = Some(TableColumns { headers, cells });
+        self
+    }
+
+    /// Sets whether the header row of the table, with the column titles and
+    /// the sort indicator, is rendered above the options when they are laid
+    /// out as a table. The rows can still be sorted without it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inquire::{terminal::TestBackend, ui::RenderConfig, Select};
+    ///
+    /// let pods = vec![("api-7d9f", "Running", 3), ("worker-5c2a", "Pending", 0)];
+    ///
+    /// let mut terminal = TestBackend::new(vec![]).with_text("\n");
+    /// Select::new_with_display("Pod:", pods, &|(name, _, _)| name.to_string())
+    ///     .with_columns(vec!["NAME", "STATUS", "RESTARTS"], &|(name, status, restarts)| {
+    ///         vec![name.to_string(), status.to_string(), restarts.to_string()]
+    ///     })
+    ///     .with_table_header(false)
+    ///     .without_help_message()
+    ///     .with_render_config(RenderConfig::empty())
+    ///     .raw_prompt_with_terminal(&mut terminal)?;
+    ///
+    /// assert!(!terminal.frames()[0].contains("NAME"));
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn with_table_header(mut self, table_header: bool) -> Self {
+        self.table_header = table_header;
+        self
+    }
+
+    /// Sets a channel through which options are added while the prompt is
+    /// open, such as results streaming in from a network scan. Received options
+    /// are appended to the list, keeping the filter input and the highlighted
+    /// option.
+    ///
+    /// The list of options can then start empty. Options can not be received
+    /// when laid out as a table or when they have hotkeys.
+    ///
+    /// Options are received after each key press, and while the user is idle
+    /// with terminals able to wait for keys with a timeout, such as the
+    /// crossterm or termion ones.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::{sync::mpsc, thread};
+    ///
+    /// use inquire::Select;
+    ///
+    /// let (sender, receiver) = mpsc::channel();
+    /// thread::spawn(move || {
+    ///     for host in ["10.0.0.2", "10.0.0.7", "10.0.0.12"] {
+    ///         // scanning takes a while...
+    ///         sender.send(host.to_string()).unwrap();
+    ///     }
+    /// });
+    ///
+    /// let host = Select::new("Host:", vec![])
+    ///     .with_option_receiver(&receiver)
+    ///     .prompt()?;
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn with_option_receiver(mut self, receiver: &'a Receiver<T>) -> Self {
+        self.option_receiver = Some(receiver);
         self
     }
 
@@ -220,6 +832,61 @@ where
         self
     }
 
+    /// Sets what happens when the user presses Ctrl+C, overriding the global
+    /// setting.
+    pub fn with_interrupt_behavior(mut self, interrupt_behavior: InterruptBehavior) -> Self {
+        self.interrupt_behavior = interrupt_behavior;
+        self
+    }
+
+    /// Sets a listener notified of the events of the prompt, such as renders,
+    /// key presses and submissions, overriding the global listener.
+    ///
+    /// See the [`events`](crate::events) module for more details.
+    pub fn with_event_listener(mut self, listener: EventListener<'a>) -> Self {
+        self.event_listener = Some(listener);
+        self
+    }
+
+    /// Sets a handle aborting the prompt from outside of it, e.g. from another
+    /// thread or a Ctrl+C handler, canceling it as if the user pressed ESC.
+    ///
+    /// See the [`handle`](crate::handle) module for more details.
+    pub fn with_handle(mut self, handle: &'a PromptHandle) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets a timeout for the user to start interacting with the prompt.
+    ///
+    /// If no key is pressed before it expires, the highlighted option is submitted.
+    /// Once a key is pressed, the prompt waits for the user indefinitely.
+    ///
+    /// Timeouts are supported by the `crossterm` and `termion` backends, the
+    /// `console` backend always waits for the first key press.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether to render the time left before the timeout expires below the prompt.
+    pub fn with_timeout_countdown(mut self, countdown: bool) -> Self {
+        self.timeout_countdown = countdown;
+        self
+    }
+
+    /// Sets an interval at which the prompt is re-rendered even when no key is
+    /// pressed, e.g. for content computed at render time, such as a dynamic
+    /// help message showing the current time, to stay up to date.
+    ///
+    /// Requires a terminal able to wait for keys with a timeout, such as the
+    /// crossterm or termion ones. Other terminals only re-render the prompt
+    /// after key presses.
+    pub fn with_refresh_interval(mut self, interval: Duration) -> Self {
+        self.refresh_interval = Some(interval);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -233,6 +900,36 @@ where
         self
     }
 
+    /// Sets how the prompt is rendered once answered, e.g. [`FinalRender::None`]
+    /// for the prompt to disappear. This is a shorthand for setting the
+    /// [`final_render`](RenderConfig::final_render) of the render config, so it
+    /// must be called after [`with_render_config`](Self::with_render_config).
+    pub fn with_final_render(mut self, final_render: FinalRender) -> Self {
+        self.render_config.final_render = final_render;
+        self
+    }
+
+    /// Sets whether the prompt is rendered in fullscreen, on the alternate
+    /// screen buffer, leaving only its final rendering in the original screen.
+    /// This is a shorthand for setting the
+    /// [`fullscreen`](RenderConfig::fullscreen) flag of the render config, so
+    /// it must be called after [`with_render_config`](Self::with_render_config).
+    pub fn with_fullscreen(mut self, fullscreen: bool) -> Self {
+        self.render_config.fullscreen = fullscreen;
+        self
+    }
+
+    /// Sets whether the string values of the options may contain ANSI escape
+    /// codes, e.g. colored with `owo-colors`. The codes are passed through to
+    /// the terminal, while left out when measuring and filtering the options.
+    /// This is a shorthand for setting the
+    /// [`ansi_labels`](RenderConfig::ansi_labels) flag of the render config,
+    /// so it must be called after [`with_render_config`](Self::with_render_config).
+    pub fn with_ansi_labels(mut self, ansi_labels: bool) -> Self {
+        self.render_config.ansi_labels = ansi_labels;
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -241,6 +938,12 @@ where
         self.raw_prompt().map(|op| op.value)
     }
 
+    /// Prompts the user through the given terminal instead of the default
+    /// terminal of the enabled backend feature, see [`Terminal`].
+    pub fn prompt_with_terminal<Term: Terminal>(self, terminal: Term) -> InquireResult<T> {
+        self.raw_prompt_with_terminal(terminal).map(|op| op.value)
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -264,11 +967,46 @@ where
     /// Returns a [`ListOption`](crate::list_option::ListOption) containing
     /// the index of the selection and the owned object selected by the user.
     pub fn raw_prompt(self) -> InquireResult<ListOption<T>> {
-        let terminal = get_default_terminal()?;
+        self.raw_prompt_with_terminal(get_default_terminal()?)
+    }
+
+    /// Prompts the user through the given terminal instead of the default
+    /// terminal of the enabled backend feature, see [`Terminal`].
+    ///
+    /// Returns the same as [`raw_prompt`](Self::raw_prompt).
+    pub fn raw_prompt_with_terminal<Term: Terminal>(
+        self,
+        terminal: Term,
+    ) -> InquireResult<ListOption<T>> {
         let mut backend = Backend::new(terminal, self.render_config)?;
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Same as [`prompt`](Self::prompt), also returning a [`PromptReport`]
+    /// describing how the user interacted with the prompt.
+    pub fn prompt_with_report(self) -> InquireResult<(T, PromptReport)> {
+        self.prompt_with_report_and_terminal(get_default_terminal()?)
+    }
+
+    /// Same as [`prompt_with_terminal`](Self::prompt_with_terminal), also
+    /// returning a [`PromptReport`] describing how the user interacted with
+    /// the prompt.
+    pub fn prompt_with_report_and_terminal<Term: Terminal>(
+        self,
+        terminal: Term,
+    ) -> InquireResult<(T, PromptReport)> {
+        self.raw_prompt_with_report(terminal)
+            .map(|(answer, report)| (answer.value, report))
+    }
+
+    fn raw_prompt_with_report<Term: Terminal>(
+        self,
+        terminal: Term,
+    ) -> InquireResult<(ListOption<T>, PromptReport)> {
+        let mut backend = Backend::new(terminal, self.render_config)?;
+        SelectPrompt::new(self)?.prompt_with_report(&mut backend)
+    }
+
     pub(crate) fn prompt_with_backend<B: SelectBackend>(
         self,
         backend: &mut B,
@@ -276,3 +1014,85 @@ where
         SelectPrompt::new(self)?.prompt(backend)
     }
 }
+
+impl<'a, T> Select<'a, Choice<T>>
+where
+    T: Display,
+{
+    const CREATE_ANSWER: FreeFormAnswer<'a, Choice<T>> = &|input| Choice::New(input.to_string());
+
+    /// Creates a [Select] in which the user can either select one of the
+    /// options or create a new one from the filter input, by selecting the
+    /// `Create '<filter>'` entry rendered after the matching options. The
+    /// answer tells both cases apart, see [`Choice`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inquire::{list_option::Choice, terminal::TestBackend, Select};
+    ///
+    /// let mut terminal = TestBackend::new(vec![]).with_text("qa\n");
+    /// let environment = Select::new_or_create("Environment:", vec!["staging", "production"])
+    ///     .prompt_with_terminal(&mut terminal)?;
+    ///
+    /// assert_eq!(Choice::New("qa".to_string()), environment);
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn new_or_create(message: &'a str, options: Vec<T>) -> Self {
+        Self {
+            free_form_answer: Some(Self::CREATE_ANSWER),
+            allow_custom: true,
+            ..Self::new(message, options.into_iter().map(Choice::Existing).collect())
+        }
+    }
+}
+
+impl<'a, K, V> Select<'a, (K, V)>
+where
+    V: Display,
+{
+    const MAP_DISPLAY: Displayer<'a, (K, V)> = &|(_, value)| value.to_string();
+
+    /// Creates a [Select] over the entries of a map, such as a `BTreeMap` or an
+    /// `IndexMap`, or any other sequence of key-value pairs. The values are
+    /// displayed to the user, in iteration order, and the selected entry is
+    /// returned, its key alone with [`prompt_key`](Self::prompt_key).
+    ///
+    /// This avoids zipping the labels with the values they stand for and
+    /// looking the answer back up.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::collections::BTreeMap;
+    /// use inquire::Select;
+    ///
+    /// let regions = BTreeMap::from([
+    ///     ("eu-west-1", "Europe (Ireland)"),
+    ///     ("us-east-1", "US East (N. Virginia)"),
+    /// ]);
+    ///
+    /// let region: &str = Select::from_map("Region:", regions).prompt_key()?;
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn from_map<M>(message: &'a str, map: M) -> Self
+    where
+        M: IntoIterator<Item = (K, V)>,
+    {
+        Self::new_with_display(message, map.into_iter().collect(), Self::MAP_DISPLAY)
+    }
+}
+
+impl<'a, K, V> Select<'a, (K, V)> {
+    /// Prompts the user to select an entry, returning its key.
+    pub fn prompt_key(self) -> InquireResult<K> {
+        self.prompt().map(|(key, _)| key)
+    }
+
+    /// Prompts the user to select an entry, returning its key, or `None` if
+    /// the user cancels the prompt. See [`prompt_skippable`](Self::prompt_skippable).
+    pub fn prompt_key_skippable(self) -> InquireResult<Option<K>> {
+        self.prompt_skippable()
+            .map(|answer| answer.map(|(key, _)| key))
+    }
+}
@@ -1,15 +1,25 @@
-use std::fmt::Display;
+use std::{cell::Cell, fmt::Display, sync::mpsc::Receiver, time::Duration};
 
 use crate::{
+    config::InterruptBehavior,
     error::InquireResult,
     formatter::OptionFormatter,
+    handle::PromptHandle,
     input::{Input, InputActionResult},
     list_option::ListOption,
-    prompts::prompt::{ActionResult, Prompt},
-    type_aliases::Filter,
-    ui::SelectBackend,
-    utils::paginate,
-    InquireError, Select,
+    prompts::{
+        filter_index::{FilterIndex, Matcher},
+        prompt::{ActionResult, Prompt, Timeout},
+        state::{render_help_message, PromptState},
+        table::Table,
+    },
+    text::text_width,
+    type_aliases::{
+        Badger, Displayer, EventListener, Filter, FreeFormAnswer, Grouper, HelpMessageFn, Scorer,
+    },
+    ui::{badge_column_width, Badge, Block, SelectBackend},
+    utils::{paginate, paginate_with_margin, Page},
+    InputAction, InquireError, Select,
 };
 
 use super::{action::SelectPromptAction, config::SelectConfig};
@@ -17,28 +27,46 @@ use super::{action::SelectPromptAction, config::SelectConfig};
 pub struct SelectPrompt<'a, T> {
     message: &'a str,
     config: SelectConfig,
+    event_listener: Option<EventListener<'a>>,
+    handle: Option<&'a PromptHandle>,
     options: Vec<T>,
     string_options: Vec<String>,
     filtered_options: Vec<usize>,
+    sections: Vec<usize>,
+    group_by: Option<Grouper<'a, T>>,
+    option_receiver: Option<&'a Receiver<T>>,
+    table: Option<Table<'a>>,
+    hotkeys: Vec<char>,
+    hotkey_labels: Vec<String>,
     help_message: Option<&'a str>,
+    help_message_fn: Option<HelpMessageFn<'a>>,
+    header: Option<Block>,
+    footer: Option<Block>,
     cursor_index: usize,
+    page_start: Cell<usize>,
     input: Input,
+    display: Displayer<'a, T>,
     filter: Filter<'a, T>,
-    formatter: OptionFormatter<'a, T>,
+    scorer: Option<Scorer<'a, T>>,
+    no_matches_message: Option<&'a str>,
+    free_form_answer: Option<FreeFormAnswer<'a, T>>,
+    custom_entry_label: Option<&'a str>,
+    filter_index: FilterIndex,
+    formatter: Option<OptionFormatter<'a, T>>,
+    badger: Option<Badger<'a, T>>,
+    badges: Vec<Option<Badge>>,
+    badge_width: usize,
 }
 
-impl<'a, T> SelectPrompt<'a, T>
-where
-    T: Display,
-{
+impl<'a, T> SelectPrompt<'a, T> {
     pub fn new(so: Select<'a, T>) -> InquireResult<Self> {
-        if so.options.is_empty() {
+        if so.options.is_empty() && so.option_receiver.is_none() {
             return Err(InquireError::InvalidConfiguration(
                 "Available options can not be empty".into(),
             ));
         }
 
-        if so.starting_cursor >= so.options.len() {
+        if !so.options.is_empty() && so.starting_cursor >= so.options.len() {
             return Err(InquireError::InvalidConfiguration(format!(
                 "Starting cursor index {} is out-of-bounds for length {} of options",
                 so.starting_cursor,
@@ -46,33 +74,300 @@ where
             )));
         }
 
-        let string_options = so.options.iter().map(T::to_string).collect();
-        let filtered_options = (0..so.options.len()).collect();
+        if so.columns.is_some() && so.group_by.is_some() {
+            return Err(InquireError::InvalidConfiguration(
+                "Options laid out as a table can not be grouped".into(),
+            ));
+        }
+
+        if so.option_receiver.is_some() && so.columns.is_some() {
+            return Err(InquireError::InvalidConfiguration(
+                "Options laid out as a table can not be received while the prompt is open".into(),
+            ));
+        }
+
+        if so.option_receiver.is_some() && !so.hotkeys.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Options with hotkeys can not be received while the prompt is open".into(),
+            ));
+        }
+
+        if so.allow_custom && so.free_form_answer.is_none() {
+            return Err(InquireError::InvalidConfiguration(
+                "Custom answers require a free-form answer function".into(),
+            ));
+        }
+
+        if !so.hotkeys.is_empty() && so.hotkeys.len() != so.options.len() {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Got {} hotkeys for {} options",
+                so.hotkeys.len(),
+                so.options.len()
+            )));
+        }
+
+        let config: SelectConfig = (&so).into();
+        let table = match &so.columns {
+            Some(columns) => Some(Table::new(columns, &so.options)?),
+            None => None,
+        };
+        let string_options: Vec<String> = match &table {
+            Some(table) => (0..so.options.len())
+                .map(|i| table.search_text(i))
+                .collect(),
+            None => so.options.iter().map(so.display).collect(),
+        };
+        let hotkey_labels = so
+            .hotkeys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| match &table {
+                Some(table) => format!("[{}] {}", key, table.row(i)),
+                None => format!("[{}] {}", key, (so.display)(&so.options[i])),
+            })
+            .collect();
+        let filtered_options = match config.search_min_input_length {
+            Some(min) if min > 0 => vec![],
+            _ => (0..so.options.len()).collect(),
+        };
+        let filter_index = FilterIndex::new(
+            so.incremental_filtering,
+            so.lowercase_cache,
+            so.render_config.ansi_labels,
+            &string_options,
+        );
+        let sections = match so.group_by {
+            Some(group_by) => Self::compute_sections(&so.options, group_by),
+            None => vec![],
+        };
+        let badges: Vec<Option<Badge>> = match so.badges {
+            Some(badger) => so.options.iter().map(badger).collect(),
+            None => vec![],
+        };
+        let badge_width = badge_column_width(&badges);
 
         Ok(Self {
             message: so.message,
-            config: (&so).into(),
+            config,
+            event_listener: so.event_listener,
+            handle: so.handle,
             options: so.options,
             string_options,
             filtered_options,
+            sections,
+            group_by: so.group_by,
+            option_receiver: so.option_receiver,
+            table,
+            hotkeys: so.hotkeys,
+            hotkey_labels,
             help_message: so.help_message,
+            help_message_fn: so.help_message_fn,
+            header: so.header,
+            footer: so.footer,
             cursor_index: so.starting_cursor,
+            page_start: Cell::new(0),
             input: Input::new(),
+            display: so.display,
             filter: so.filter,
+            scorer: so.scorer,
+            no_matches_message: so.no_matches_message,
+            free_form_answer: so.free_form_answer,
+            custom_entry_label: match so.allow_custom {
+                true => Some(so.custom_entry_label),
+                false => None,
+            },
+            filter_index,
             formatter: so.formatter,
+            badger: so.badges,
+            badges,
+            badge_width,
         })
     }
 
-    fn filter_options(&self) -> Vec<usize> {
-        self.options
-            .iter()
-            .enumerate()
-            .filter_map(|(i, opt)| match self.input.content() {
-                val if val.is_empty() => Some(i),
-                val if (self.filter)(val, opt, self.string_options.get(i).unwrap(), i) => Some(i),
-                _ => None,
-            })
-            .collect()
+    /// Assigns a section id to each option, incrementing it every time
+    /// the group changes between consecutive options.
+    fn compute_sections(options: &[T], group_by: Grouper<'a, T>) -> Vec<usize> {
+        let mut sections = Vec::with_capacity(options.len());
+        let mut last_group = None;
+        let mut section = 0;
+
+        for option in options {
+            let group = group_by(option);
+            if matches!(&last_group, Some(last) if last != &group) {
+                section += 1;
+            }
+            sections.push(section);
+            last_group = Some(group);
+        }
+
+        sections
+    }
+
+    /// Returns the range, relative to the filtered options, of the section
+    /// containing the given cursor position.
+    fn section_bounds(&self, cursor: usize) -> (usize, usize) {
+        let section_of = |i: usize| self.sections[self.filtered_options[i]];
+
+        // the custom entry is a section of its own
+        if cursor >= self.filtered_options.len() {
+            let len = self.filtered_options.len();
+            return (len, self.entry_count().max(len));
+        }
+
+        let section = section_of(cursor);
+        let mut start = cursor;
+        while start > 0 && section_of(start - 1) == section {
+            start -= 1;
+        }
+        let mut end = cursor + 1;
+        while end < self.filtered_options.len() && section_of(end) == section {
+            end += 1;
+        }
+
+        (start, end)
+    }
+
+    fn move_to_previous_section(&mut self) -> ActionResult {
+        let (start, _) = self.section_bounds(self.cursor_index);
+        let new_position = match start {
+            0 => 0,
+            start => self.section_bounds(start - 1).0,
+        };
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn move_to_next_section(&mut self) -> ActionResult {
+        let (_, end) = self.section_bounds(self.cursor_index);
+        let new_position = if end >= self.entry_count() {
+            self.entry_count().saturating_sub(1)
+        } else {
+            end
+        };
+
+        self.update_cursor_position(new_position)
+    }
+
+    /// Whether the prompt is in the search-only state and the filter input is
+    /// still too short to display options.
+    fn awaiting_search_input(&self) -> Option<usize> {
+        self.config
+            .search_min_input_length
+            .filter(|min| self.input.content().chars().count() < *min)
+    }
+
+    /// Index typed by the user, 0-based, if index selection is enabled and
+    /// the input is only made of digits.
+    fn typed_index(&self) -> Option<usize> {
+        let input = self.input.content();
+        if !self.config.index_selection
+            || input.is_empty()
+            || !input.chars().all(|c| c.is_ascii_digit())
+        {
+            return None;
+        }
+
+        // indices are rendered from 1, so a typed 0 does not refer to any option
+        input
+            .parse::<usize>()
+            .ok()
+            .and_then(|index| index.checked_sub(1))
+    }
+
+    /// Appends the options received since the last call, keeping the
+    /// highlighted option. Returns whether any option was received.
+    fn receive_options(&mut self) -> bool {
+        let received: Vec<T> = match self.option_receiver {
+            Some(receiver) => receiver.try_iter().collect(),
+            None => return false,
+        };
+
+        if received.is_empty() {
+            return false;
+        }
+
+        let highlighted = self.filtered_options.get(self.cursor_index).copied();
+
+        for option in received {
+            if let Some(badger) = self.badger {
+                self.badges.push(badger(&option));
+            }
+            let string_option = (self.display)(&option);
+            self.filter_index.push(&string_option);
+            self.string_options.push(string_option);
+            self.options.push(option);
+        }
+
+        if let Some(group_by) = self.group_by {
+            self.sections = Self::compute_sections(&self.options, group_by);
+        }
+
+        self.badge_width = badge_column_width(&self.badges);
+        self.filtered_options = self.filter_options();
+
+        if let Some(position) =
+            highlighted.and_then(|index| self.filtered_options.iter().position(|i| *i == index))
+        {
+            self.cursor_index = position;
+        }
+
+        true
+    }
+
+    fn filter_options(&mut self) -> Vec<usize> {
+        if self.awaiting_search_input().is_some() {
+            self.filter_index.invalidate();
+            return vec![];
+        }
+
+        let filter = match self.typed_index() {
+            Some(_) => "",
+            None => self.input.content(),
+        };
+
+        let mut options = self.filter_index.matches(
+            filter,
+            &self.options,
+            &self.string_options,
+            Matcher::new(self.filter, self.scorer),
+            &self.filtered_options,
+            |_, _| true,
+        );
+
+        if let Some(table) = &self.table {
+            if self.scorer.is_none() || table.is_sorted() {
+                table.sort(&mut options);
+            }
+        }
+
+        options
+    }
+
+    /// Sorts the options by the given column, keeping the cursor on the
+    /// highlighted option.
+    fn sort_by_column(&mut self, column: Option<usize>) -> ActionResult {
+        let table = match &mut self.table {
+            Some(table) => table,
+            None => return ActionResult::Clean,
+        };
+
+        let highlighted = self.filtered_options.get(self.cursor_index).copied();
+        table.sort_by(column);
+        match self.scorer.is_some() && !table.is_sorted() {
+            true => {
+                self.filter_index.invalidate();
+                self.filtered_options = self.filter_options();
+            }
+            false => table.sort(&mut self.filtered_options),
+        }
+
+        if let Some(position) =
+            highlighted.and_then(|index| self.filtered_options.iter().position(|i| *i == index))
+        {
+            self.cursor_index = position;
+        }
+
+        ActionResult::NeedsRedraw
     }
 
     fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
@@ -80,7 +375,7 @@ where
             let after_wrap = qty.saturating_sub(self.cursor_index);
             self.cursor_index
                 .checked_sub(qty)
-                .unwrap_or_else(|| self.filtered_options.len().saturating_sub(after_wrap))
+                .unwrap_or_else(|| self.entry_count().saturating_sub(after_wrap))
         } else {
             self.cursor_index.saturating_sub(qty)
         };
@@ -90,20 +385,44 @@ where
 
     fn move_cursor_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
         let mut new_position = self.cursor_index.saturating_add(qty);
+        let entry_count = self.entry_count();
 
-        if new_position >= self.filtered_options.len() {
-            new_position = if self.filtered_options.is_empty() {
+        if new_position >= entry_count {
+            new_position = if entry_count == 0 {
                 0
             } else if wrap {
-                new_position % self.filtered_options.len()
+                new_position % entry_count
             } else {
-                self.filtered_options.len().saturating_sub(1)
+                entry_count - 1
             }
         }
 
         self.update_cursor_position(new_position)
     }
 
+    /// Paginates the displayed options, scrolling the page rendered in the
+    /// previous frame only as needed when a scroll margin is set.
+    fn paginate<'c, D>(&self, page_size: usize, choices: &'c [D]) -> Page<'c, D> {
+        let margin = match self.config.scroll_margin {
+            Some(margin) => margin,
+            None => return paginate(page_size, choices, Some(self.cursor_index)),
+        };
+
+        let page = paginate_with_margin(
+            page_size,
+            choices,
+            self.cursor_index,
+            self.page_start.get(),
+            margin,
+        );
+        let start = page
+            .cursor
+            .map_or(0, |cursor| self.cursor_index.saturating_sub(cursor));
+        self.page_start.set(start);
+
+        page
+    }
+
     fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
         if new_position != self.cursor_index {
             self.cursor_index = new_position;
@@ -114,13 +433,79 @@ where
     }
 
     fn has_answer_highlighted(&mut self) -> bool {
-        self.filtered_options.get(self.cursor_index).is_some()
+        self.filtered_options.get(self.cursor_index).is_some() || self.is_custom_entry_highlighted()
+    }
+
+    /// Whether the entry creating a custom answer from the filter input is
+    /// rendered after the matching options, which is the case while the input
+    /// is not the exact text of one of them.
+    fn has_custom_entry(&self) -> bool {
+        let input = self.input.content();
+
+        self.custom_entry_label.is_some()
+            && !input.is_empty()
+            && self.awaiting_search_input().is_none()
+            && !self
+                .filtered_options
+                .iter()
+                .any(|&i| self.string_options[i] == input)
+    }
+
+    fn is_custom_entry_highlighted(&self) -> bool {
+        self.cursor_index == self.filtered_options.len() && self.has_custom_entry()
+    }
+
+    /// Number of entries the cursor can move through, the matching options
+    /// and the custom entry if any.
+    fn entry_count(&self) -> usize {
+        self.filtered_options.len() + usize::from(self.has_custom_entry())
+    }
+
+    /// Answer created from the filter input with the free-form answer function.
+    fn free_form_answer(&self) -> Option<ListOption<T>> {
+        self.free_form_answer.map(|free_form_answer| {
+            ListOption::new(self.options.len(), free_form_answer(self.input.content()))
+        })
+    }
+
+    /// Whether the filter input matches none of the options, which are not
+    /// hidden until more characters are typed.
+    fn has_no_matches(&self) -> bool {
+        self.filtered_options.is_empty()
+            && !self.options.is_empty()
+            && !self.input.content().is_empty()
+            && self.awaiting_search_input().is_none()
+    }
+
+    fn state(&self) -> PromptState<'_> {
+        let format = |answer: ListOption<&T>| match self.formatter {
+            Some(formatter) => formatter(answer),
+            None => (self.display)(answer.value),
+        };
+        let answer = match self.filtered_options.get(self.cursor_index) {
+            Some(&index) => Some(format(ListOption::new(index, &self.options[index]))),
+            None if self.is_custom_entry_highlighted() => self
+                .free_form_answer()
+                .map(|answer| format(answer.as_ref())),
+            None => None,
+        };
+
+        PromptState {
+            input: self.input.content(),
+            answer,
+            options: self.filtered_options.len(),
+            selected: 0,
+        }
     }
 
     fn get_final_answer(&mut self) -> ListOption<T> {
         // should only be called after current cursor index is validated
         // on has_answer_highlighted
 
+        if self.is_custom_entry_highlighted() {
+            return self.free_form_answer().unwrap();
+        }
+
         let index = *self.filtered_options.get(self.cursor_index).unwrap();
         let value = self.options.swap_remove(index);
 
@@ -131,7 +516,6 @@ where
 impl<'a, B, T> Prompt<B, SelectConfig, SelectPromptAction, ListOption<T>> for SelectPrompt<'a, T>
 where
     B: SelectBackend,
-    T: Display,
 {
     fn message(&self) -> &str {
         self.message
@@ -141,13 +525,57 @@ where
         &self.config
     }
 
+    fn interrupt_behavior(&self) -> InterruptBehavior {
+        self.config.interrupt_behavior
+    }
+
+    fn event_listener(&self) -> Option<EventListener<'_>> {
+        self.event_listener
+    }
+
+    fn prompt_handle(&self) -> Option<&PromptHandle> {
+        self.handle
+    }
+
+    fn header(&self) -> Option<&Block> {
+        self.header.as_ref()
+    }
+
+    fn footer(&self) -> Option<&Block> {
+        self.footer.as_ref()
+    }
+
+    fn timeout(&self) -> Option<Timeout> {
+        self.config.timeout
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        self.config.refresh_interval
+    }
+
+    fn listens_for_updates(&self) -> bool {
+        self.option_receiver.is_some()
+    }
+
+    fn apply_updates(&mut self) -> bool {
+        self.receive_options()
+    }
+
+    fn submit_on_timeout(&mut self) -> InquireResult<Option<ListOption<T>>> {
+        Prompt::<B, _, _, _>::submit(self)
+    }
+
     fn format_answer(&self, answer: &ListOption<T>) -> String {
-        (self.formatter)(answer.as_ref())
+        match self.formatter {
+            Some(formatter) => formatter(answer.as_ref()),
+            None => (self.display)(&answer.value),
+        }
     }
 
     fn submit(&mut self) -> InquireResult<Option<ListOption<T>>> {
         let answer = match self.has_answer_highlighted() {
             true => Some(self.get_final_answer()),
+            false if self.has_no_matches() => self.free_form_answer(),
             false => None,
         };
 
@@ -156,21 +584,46 @@ where
 
     fn handle(&mut self, action: SelectPromptAction) -> InquireResult<ActionResult> {
         let result = match action {
-            SelectPromptAction::MoveUp => self.move_cursor_up(1, true),
-            SelectPromptAction::MoveDown => self.move_cursor_down(1, true),
+            SelectPromptAction::MoveUp => self.move_cursor_up(1, self.config.wrap_around),
+            SelectPromptAction::MoveDown => self.move_cursor_down(1, self.config.wrap_around),
+            SelectPromptAction::PageUp if self.config.section_paging => {
+                self.move_to_previous_section()
+            }
+            SelectPromptAction::PageDown if self.config.section_paging => {
+                self.move_to_next_section()
+            }
             SelectPromptAction::PageUp => self.move_cursor_up(self.config.page_size, false),
             SelectPromptAction::PageDown => self.move_cursor_down(self.config.page_size, false),
             SelectPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
             SelectPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
+            SelectPromptAction::SortByColumn(column) => self.sort_by_column(Some(column)),
+            SelectPromptAction::ClearSort => self.sort_by_column(None),
+            SelectPromptAction::FilterInput(InputAction::Write(c)) if self.hotkeys.contains(&c) => {
+                let index = self.hotkeys.iter().position(|key| *key == c).unwrap();
+
+                match self.filtered_options.iter().position(|i| *i == index) {
+                    Some(position) => {
+                        let _ = self.update_cursor_position(position);
+                        ActionResult::Submit
+                    }
+                    None => ActionResult::Clean,
+                }
+            }
             SelectPromptAction::FilterInput(input_action) => {
                 let result = self.input.handle(input_action);
 
                 if let InputActionResult::ContentChanged = result {
                     let options = self.filter_options();
                     self.filtered_options = options;
-                    if self.filtered_options.len() <= self.cursor_index {
-                        let _ = self
-                            .update_cursor_position(self.filtered_options.len().saturating_sub(1));
+                    if self.entry_count() <= self.cursor_index {
+                        let _ = self.update_cursor_position(self.entry_count().saturating_sub(1));
+                    }
+
+                    if let Some(position) = self
+                        .typed_index()
+                        .and_then(|index| self.filtered_options.iter().position(|i| *i == index))
+                    {
+                        let _ = self.update_cursor_position(position);
                     }
                 }
 
@@ -184,23 +637,106 @@ where
     fn render(&self, backend: &mut B) -> InquireResult<()> {
         let prompt = &self.message;
 
+        if let Some(prefix) = self.config.option_index_prefix {
+            backend.set_option_index_prefix(prefix);
+        }
+
         backend.render_select_prompt(prompt, &self.input)?;
 
-        let choices = self
+        let custom_entry = match self.custom_entry_label {
+            Some(label) if self.has_custom_entry() => {
+                Some(label.replace("{filter}", self.input.content()))
+            }
+            _ => None,
+        };
+
+        let mut choices = self
             .filtered_options
             .iter()
             .cloned()
-            .map(|i| ListOption::new(i, self.options.get(i).unwrap()))
-            .collect::<Vec<ListOption<&T>>>();
+            .map(|i| {
+                let value: &dyn Display = match (self.hotkey_labels.get(i), &self.table) {
+                    (Some(label), _) => label,
+                    (None, Some(table)) => table.row(i),
+                    (None, None) => &self.string_options[i],
+                };
+                ListOption::new(i, value)
+            })
+            .collect::<Vec<ListOption<&dyn Display>>>();
+
+        if let Some(label) = &custom_entry {
+            choices.push(ListOption::new(self.options.len(), label));
+        }
+
+        let page_size = backend.fit_page_size(self.config.page_size);
+        let page = if self.config.section_paging {
+            let (start, end) = self.section_bounds(self.cursor_index);
+            let mut page = paginate(
+                page_size,
+                &choices[start..end],
+                Some(self.cursor_index - start),
+            );
+            page.first &= start == 0;
+            page.last &= end == choices.len();
+            page.total = choices.len();
+            page
+        } else {
+            self.paginate(page_size, &choices)
+        };
+
+        let badges: Vec<Option<&Badge>> = match self.badges.is_empty() {
+            true => vec![],
+            false => page
+                .content
+                .iter()
+                .map(|option| self.badges.get(option.index).and_then(Option::as_ref))
+                .collect(),
+        };
+
+        if let Some(table) = self.table.as_ref().filter(|_| self.config.table_header) {
+            // keeps the header aligned with the rows prefixed by their hotkey
+            let header = match self.hotkeys.first() {
+                Some(key) => format!(
+                    "{}{}",
+                    " ".repeat(text_width(&format!("[{}] ", key))),
+                    table.header()
+                ),
+                None => table.header(),
+            };
+            backend.render_table_header(&header, self.options.len(), self.badge_width)?;
+        }
 
-        let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
+        backend.render_options(page, &badges, self.badge_width)?;
 
-        backend.render_options(page)?;
+        if let Some(message) = self
+            .no_matches_message
+            .filter(|_| self.has_no_matches() && custom_entry.is_none())
+        {
+            backend
+                .render_no_matches_message(&message.replace("{filter}", self.input.content()))?;
+        }
 
-        if let Some(help_message) = self.help_message {
-            backend.render_help_message(help_message)?;
+        if self.config.position_indicator && !choices.is_empty() {
+            backend.render_position_indicator(self.cursor_index + 1, choices.len())?;
         }
 
+        if let Some(min) = self.awaiting_search_input() {
+            let unit = match min {
+                1 => "character",
+                _ => "characters",
+            };
+            backend.render_help_message(&format!(
+                "type at least {} {} to search among {} options",
+                min,
+                unit,
+                self.options.len()
+            ))?;
+        }
+
+        render_help_message(backend, self.help_message, self.help_message_fn, || {
+            self.state()
+        })?;
+
         Ok(())
     }
 }
@@ -1,11 +1,13 @@
+use std::time::Duration;
+
 use crate::{
     formatter::OptionFormatter,
-    list_option::ListOption,
-    terminal::crossterm::CrosstermTerminal,
-    ui::{Backend, RenderConfig},
-    Select,
+    list_option::{Choice, ListOption},
+    terminal::{crossterm::CrosstermTerminal, TestBackend},
+    ui::{Backend, Badge, Key, RenderConfig},
+    InquireError, Select,
 };
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 #[test]
 /// Tests that a closure that actually closes on a variable can be used
@@ -93,3 +95,745 @@ fn down_arrow_on_empty_list_does_not_panic() {
 
     assert_eq!(ListOption::new(0, 1), ans);
 }
+
+#[test]
+fn section_paging_jumps_between_groups() {
+    let read: Vec<KeyEvent> = vec![
+        KeyCode::PageDown,
+        KeyCode::PageDown,
+        KeyCode::PageUp,
+        KeyCode::Enter,
+    ]
+    .into_iter()
+    .map(KeyEvent::from)
+    .collect();
+    let mut read = read.iter();
+
+    let options = vec!["a1", "a2", "b1", "b2", "b3", "c1"];
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Question", options)
+        .with_group_by(&|opt| opt[..1].to_string())
+        .with_section_paging(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "b1"), ans);
+}
+
+#[test]
+fn section_paging_is_ignored_without_groups() {
+    let read: Vec<KeyEvent> = vec![KeyCode::PageDown, KeyCode::Enter]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let options = vec!["a1", "a2", "b1", "b2", "b3", "c1"];
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Question", options)
+        .with_page_size(2)
+        .with_section_paging(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "b1"), ans);
+}
+
+#[test]
+fn timeout_submits_highlighted_option() {
+    let read: Vec<KeyEvent> = vec![];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Question", vec![1, 2, 3])
+        .with_starting_cursor(1)
+        .with_timeout(Duration::ZERO)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, 2), ans);
+}
+
+#[test]
+fn long_options_are_truncated_unless_wrapping() {
+    let long_option = "x".repeat(5000);
+
+    for wrap in [false, true] {
+        let read: Vec<KeyEvent> = vec![KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty().with_wrap(wrap)).unwrap();
+
+        let ans = Select::new("Question", vec![long_option.as_str()])
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+        assert_eq!(long_option, ans.value);
+        drop(backend);
+
+        let output = String::from_utf8(write).unwrap();
+        assert_eq!(!wrap, output.contains('…'));
+    }
+}
+
+#[test]
+fn long_messages_and_answers_are_truncated_unless_wrapping() {
+    let mut terminal = TestBackend::new(vec![Key::Enter]).with_size(14, 20);
+    Select::new("Which environment?", vec!["production", "dev"])
+        .with_help_message("pick one of them")
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(
+        "? Which envir…  \n> production\n  dev\n[pick one of…]\n",
+        terminal.frames()[0]
+    );
+    assert_eq!("? Which… prod…\n", terminal.last_frame().unwrap());
+
+    let mut terminal = TestBackend::new(vec![Key::Enter]).with_size(14, 20);
+    Select::new("Which environment?", vec!["production", "dev"])
+        .with_render_config(RenderConfig::empty().with_wrap(true))
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(
+        "? Which environment? production\n",
+        terminal.last_frame().unwrap()
+    );
+}
+
+#[test]
+fn search_only_state_hides_options_until_enough_input() {
+    let read: Vec<KeyEvent> = vec![
+        KeyCode::Enter,
+        KeyCode::Char('4'),
+        KeyCode::Enter,
+        KeyCode::Char('2'),
+        KeyCode::Enter,
+    ]
+    .into_iter()
+    .map(KeyEvent::from)
+    .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let options = (0..100).collect();
+    let ans = Select::new("Question", options)
+        .with_search_only_threshold(10)
+        .with_search_min_input_length(2)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(42, 42), ans);
+}
+
+#[test]
+fn table_rows_are_sorted_by_column_keeping_the_cursor() {
+    let read: Vec<KeyEvent> = vec![
+        KeyEvent::new(KeyCode::Char('2'), KeyModifiers::ALT),
+        KeyEvent::from(KeyCode::Down),
+        KeyEvent::from(KeyCode::Enter),
+    ];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+    let options = vec!["b 10", "a 9", "c 100"];
+    let ans = Select::new("Question", options)
+        .with_columns(vec!["Name", "Size"], &|opt| {
+            opt.split(' ').map(String::from).collect()
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    // the cursor follows "b" to the middle of the sorted rows
+    assert_eq!(ListOption::new(2, "c 100"), ans);
+    drop(backend);
+
+    let output = String::from_utf8(write).unwrap();
+    assert!(output.contains(
+        "  \u{1b}[1mName    Size ▲\u{1b}[0m\u{1b}[K\r\n  a       9\u{1b}[K\r\n> b       10"
+    ));
+}
+
+#[test]
+fn table_columns_can_not_be_grouped() {
+    let mut read = [].iter();
+
+    let mut write: Vec<u8> = Vec::new();
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Question", vec!["a1", "b1"])
+        .with_columns(vec!["Name"], &|opt| vec![opt.to_string()])
+        .with_group_by(&|opt| opt[..1].to_string())
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn badges_are_computed_once_and_keep_their_column_width() {
+    let computed = std::cell::Cell::new(0);
+    let badger = |opt: &&str| {
+        computed.set(computed.get() + 1);
+        match *opt {
+            "serde" => Some(Badge::success("✔")),
+            "tokio" => Some(Badge::accent("⬆⬆")),
+            _ => None,
+        }
+    };
+
+    let page_down = Key::PageDown(KeyModifiers::NONE.into());
+    let mut terminal = TestBackend::new(vec![page_down, page_down, Key::Enter]);
+    let ans = Select::new("Crate", vec!["serde", "tokio", "rand", "regex", "clap"])
+        .with_page_size(2)
+        .with_badges(&badger)
+        .without_help_message()
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("clap", ans);
+    assert_eq!("? Crate  \n> ✔  serde\nv ⬆⬆ tokio\n", terminal.frames()[0]);
+    assert_eq!("? Crate  \n^    regex\n>    clap\n", terminal.frames()[2]);
+    assert_eq!(5, computed.get());
+}
+
+#[test]
+fn typed_index_highlights_the_option() {
+    let mut terminal = TestBackend::new(vec![]).with_text("3\n");
+    let ans = Select::new("Question", vec!["a", "b", "c", "d"])
+        .with_index_selection(true)
+        .without_help_message()
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("c", ans);
+    assert_eq!(
+        "? Question 3 \n  1) a\n  2) b\n> 3) c\n  4) d\n",
+        terminal.frames()[1]
+    );
+}
+
+#[test]
+fn typed_zero_does_not_select_the_first_option() {
+    let mut terminal = TestBackend::new(vec![]).with_text("0\n");
+    terminal.push_key(Key::Backspace(crate::ui::KeyModifiers::NONE));
+    terminal.push_text("2\n");
+    let ans = Select::new("Question", vec!["a", "b", "c"])
+        .with_index_selection(true)
+        .without_help_message()
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("b", ans);
+}
+
+#[test]
+fn index_selection_renders_indices_with_any_backend() {
+    let mut terminal = TestBackend::new(vec![]).with_text("\n");
+    let mut backend = Backend::new(&mut terminal, RenderConfig::empty()).unwrap();
+    Select::new("Question", vec!["a", "b"])
+        .with_index_selection(true)
+        .without_help_message()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    drop(backend);
+
+    assert_eq!("? Question  \n> 1) a\n  2) b\n", terminal.frames()[0]);
+}
+
+#[test]
+fn non_numeric_input_filters_with_index_selection() {
+    let mut terminal = TestBackend::new(vec![]).with_text("d\n");
+    let ans = Select::new("Question", vec!["a", "b", "c", "d"])
+        .with_index_selection(true)
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("d", ans);
+}
+
+#[test]
+fn hotkey_selects_and_submits_the_option() {
+    let mut terminal = TestBackend::new(vec![]).with_text("r");
+    let ans = Select::new_with_keys(
+        "What now?",
+        vec![('d', "Deploy"), ('r', "Rollback"), ('q', "Quit")],
+    )
+    .without_help_message()
+    .prompt_with_terminal(&mut terminal)
+    .unwrap();
+
+    assert_eq!("Rollback", ans);
+    assert_eq!(
+        vec![
+            "? What now?  \n> [d] Deploy\n  [r] Rollback\n  [q] Quit\n",
+            "> What now? Rollback\n",
+        ],
+        terminal.frames()
+    );
+}
+
+#[test]
+fn other_keys_filter_options_with_hotkeys() {
+    let mut terminal = TestBackend::new(vec![]).with_text("u\n");
+    let ans = Select::new_with_keys("What now?", vec![('d', "Deploy"), ('q', "Quit")])
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("Quit", ans);
+}
+
+#[test]
+fn hotkeys_must_match_the_options() {
+    let mut terminal = TestBackend::new(vec![]);
+    let mut select = Select::new_with_keys("What now?", vec![('d', "Deploy"), ('q', "Quit")]);
+    select.hotkeys.pop();
+
+    let ans = select.prompt_with_terminal(&mut terminal);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn options_without_display_are_rendered_by_the_display_function() {
+    #[derive(Debug, PartialEq)]
+    struct Region {
+        code: &'static str,
+        name: &'static str,
+    }
+
+    let regions = vec![
+        Region {
+            code: "us-east-1",
+            name: "N. Virginia",
+        },
+        Region {
+            code: "eu-west-1",
+            name: "Ireland",
+        },
+    ];
+
+    let mut terminal = TestBackend::new(vec![]).with_text("ire\n");
+    let ans = Select::new_with_display("Region:", regions, &|r| format!("{} ({})", r.name, r.code))
+        .without_help_message()
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("eu-west-1", ans.code);
+    assert_eq!(
+        "? Region:  \n> N. Virginia (us-east-1)\n  Ireland (eu-west-1)\n",
+        terminal.frames()[0]
+    );
+    assert_eq!(
+        "? Region: Ireland (eu-west-1)\n",
+        terminal.frames().last().unwrap()
+    );
+}
+
+#[test]
+fn map_entries_display_the_value_and_return_the_key() {
+    let regions = std::collections::BTreeMap::from([
+        ("us-east-1", "US East (N. Virginia)"),
+        ("eu-west-1", "Europe (Ireland)"),
+    ]);
+
+    let mut terminal = TestBackend::new(vec![]).with_text("us\n");
+    let ans = Select::from_map("Region:", regions)
+        .without_help_message()
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(("us-east-1", "US East (N. Virginia)"), ans);
+    assert_eq!(
+        "? Region:  \n> Europe (Ireland)\n  US East (N. Virginia)\n",
+        terminal.frames()[0]
+    );
+}
+
+#[test]
+fn received_options_are_appended_to_an_empty_list() {
+    let (sender, receiver) = std::sync::mpsc::channel();
+    sender.send("Deploy").unwrap();
+    sender.send("Rollback").unwrap();
+
+    let mut terminal =
+        TestBackend::new(vec![Key::Down(crate::ui::KeyModifiers::NONE)]).with_text("\n");
+    let ans = Select::new("What now?", vec![])
+        .with_option_receiver(&receiver)
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("Rollback", ans);
+}
+
+#[test]
+fn received_options_are_filtered_by_the_current_input() {
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let listener = |event: &crate::events::PromptEvent<'_>| {
+        if let crate::events::PromptEventKind::KeyPressed(Key::Char('m', _)) = event.kind {
+            sender.send("gamma ray").unwrap();
+        }
+    };
+
+    let mut terminal = TestBackend::new(vec![]).with_text("gam");
+    terminal.push_key(Key::Down(crate::ui::KeyModifiers::NONE));
+    terminal.push_key(Key::Enter);
+    let ans = Select::new("Burst:", vec!["alpha", "gamma"])
+        .with_option_receiver(&receiver)
+        .with_event_listener(&listener)
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("gamma ray", ans);
+    assert!(terminal.frames()[3].contains("> gamma\n  gamma ray\n"));
+}
+
+#[test]
+fn option_receiver_can_not_be_used_with_hotkeys() {
+    let (_sender, receiver) = std::sync::mpsc::channel();
+    let mut terminal = TestBackend::new(vec![]);
+    let ans = Select::new_with_keys("What now?", vec![('d', "Deploy"), ('q', "Quit")])
+        .with_option_receiver(&receiver)
+        .prompt_with_terminal(&mut terminal);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn cursor_stops_at_the_ends_without_wrap_around() {
+    let mut terminal =
+        TestBackend::new(vec![Key::Up(crate::ui::KeyModifiers::NONE)]).with_text("\n");
+    let ans = Select::new("Letter:", vec!["a", "b", "c"])
+        .with_wrap_around(false)
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("a", ans);
+}
+
+#[test]
+fn scroll_margin_keeps_the_page_until_the_cursor_nears_its_edge() {
+    let down = Key::Down(crate::ui::KeyModifiers::NONE);
+    let mut terminal = TestBackend::new(vec![down, down, down, down]).with_text("\n");
+    let ans = Select::new("Number:", (1..=10).collect())
+        .with_page_size(5)
+        .with_scroll_margin(1)
+        .with_position_indicator(true)
+        .without_help_message()
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(5, ans);
+    assert_eq!(
+        "? Number:  \n  1\n  2\n  3\n> 4\nv 5\n4/10\n",
+        terminal.frames()[3]
+    );
+    assert_eq!(
+        "? Number:  \n^ 2\n  3\n  4\n> 5\nv 6\n5/10\n",
+        terminal.frames()[4]
+    );
+}
+
+#[test]
+fn incremental_filtering_narrows_the_previous_matches_until_a_deletion() {
+    let calls = std::cell::Cell::new(0);
+    let filter = |input: &str, _: &&str, value: &str, _: usize| {
+        calls.set(calls.get() + 1);
+        value.contains(input)
+    };
+
+    let mut terminal = TestBackend::new(vec![]).with_text("et");
+    terminal.push_key(Key::Backspace(crate::ui::KeyModifiers::NONE));
+    terminal.push_key(Key::Enter);
+    let ans = Select::new("Letter:", vec!["alpha", "beta", "gamma", "delta"])
+        .with_filter(&filter)
+        .with_incremental_filtering(true)
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("beta", ans);
+    assert_eq!(4 + 2 + 4, calls.get());
+}
+
+#[test]
+fn lowercase_cache_gives_lowercased_values_to_the_filter() {
+    let mut terminal = TestBackend::new(vec![]).with_text("BE\n");
+    let ans = Select::new("Letter:", vec!["Alpha", "Beta"])
+        .with_filter(&|input, _, value, _| value.starts_with(input))
+        .with_lowercase_cache(true)
+        .without_help_message()
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("Beta", ans);
+    assert_eq!("? Letter: BE \n> Beta\n", terminal.frames()[2]);
+}
+
+#[test]
+fn scorer_orders_the_matching_options_by_descending_score() {
+    let mut terminal = TestBackend::new(vec![]).with_text("a\n");
+    let ans = Select::new("Word:", vec!["banana", "kiwi", "avocado", "papaya"])
+        .with_scorer(&|input, _, value, _| match value.matches(input).count() {
+            0 => None,
+            count => Some(count as i64),
+        })
+        .without_help_message()
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("banana", ans);
+    assert_eq!(
+        "? Word: a \n> banana\n  papaya\n  avocado\n",
+        terminal.frames()[1]
+    );
+}
+
+#[test]
+fn free_form_answer_is_only_submitted_when_nothing_matches() {
+    let mut terminal = TestBackend::new(vec![]).with_text("sta\n");
+    let ans = Select::new(
+        "Environment:",
+        vec!["staging".to_string(), "prod".to_string()],
+    )
+    .with_free_form_answer(&|input| input.to_uppercase())
+    .raw_prompt_with_terminal(&mut terminal)
+    .unwrap();
+
+    assert_eq!(ListOption::new(0, "staging".to_string()), ans);
+
+    let mut terminal = TestBackend::new(vec![]).with_text("qa\n");
+    let ans = Select::new(
+        "Environment:",
+        vec!["staging".to_string(), "prod".to_string()],
+    )
+    .with_free_form_answer(&|input| input.to_uppercase())
+    .raw_prompt_with_terminal(&mut terminal)
+    .unwrap();
+
+    assert_eq!(ListOption::new(2, "QA".to_string()), ans);
+}
+
+#[test]
+fn custom_entry_is_rendered_after_the_matching_options() {
+    let mut terminal = TestBackend::new(vec![]).with_text("sta");
+    terminal.push_key(Key::Up(crate::ui::KeyModifiers::NONE));
+    terminal.push_key(Key::Enter);
+    let ans = Select::new_or_create("Environment:", vec!["staging", "prod"])
+        .without_help_message()
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(Choice::New("sta".to_string()), ans);
+    assert_eq!(
+        "? Environment: sta \n> staging\n  Create 'sta'\n",
+        terminal.frames()[3]
+    );
+    assert_eq!(
+        "? Environment: sta \n  staging\n> Create 'sta'\n",
+        terminal.frames()[4]
+    );
+}
+
+#[test]
+fn custom_entry_is_hidden_when_the_input_is_an_option() {
+    let mut terminal = TestBackend::new(vec![]).with_text("prod");
+    terminal.push_key(Key::Down(crate::ui::KeyModifiers::NONE));
+    terminal.push_key(Key::Enter);
+    let ans = Select::new_or_create("Environment:", vec!["staging", "prod"])
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(Choice::Existing("prod"), ans);
+}
+
+#[test]
+fn custom_answers_require_a_free_form_answer_function() {
+    let mut terminal = TestBackend::new(vec![]);
+    let ans = Select::new("Environment:", vec!["staging", "prod"])
+        .with_allow_custom(true)
+        .prompt_with_terminal(&mut terminal);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn ansi_labels_are_filtered_and_truncated_without_their_escape_codes() {
+    let mut terminal = TestBackend::new(vec![]).with_size(12, 20).with_text("3");
+    terminal.push_key(Key::Backspace(crate::ui::KeyModifiers::NONE));
+    terminal.push_text("g\n");
+
+    let ans = Select::new(
+        "Color:",
+        vec!["\x1b[31mred\x1b[0m", "\x1b[32mgreen-yellow\x1b[0m"],
+    )
+    .without_help_message()
+    .with_render_config(RenderConfig::empty())
+    .with_ansi_labels(true)
+    .prompt_with_terminal(&mut terminal)
+    .unwrap();
+
+    assert_eq!("\x1b[32mgreen-yellow\x1b[0m", ans);
+    assert_eq!(
+        "? Color:  \n> \x1b[31mred\x1b[0m\n  \x1b[32mgreen-yel…\x1b[0m\n",
+        terminal.frames()[0]
+    );
+    assert!(!terminal.frames()[1].contains("red"));
+    assert_eq!(
+        "? Color: g \n> \x1b[32mgreen-yel…\x1b[0m\n",
+        terminal.frames()[3]
+    );
+}
+
+#[test]
+fn hyperlinks_are_filtered_by_their_text() {
+    let link = |text, url| crate::ui::Hyperlink::new(text, url).with_enabled(true);
+    let mut terminal = TestBackend::new(vec![]).with_text("docs\n");
+
+    let ans = Select::new(
+        "Docs:",
+        vec![
+            link("docs.rs", "https://docs.rs/inquire"),
+            link(
+                "example",
+                "https://docs.rs/crate/inquire/latest/source/examples",
+            ),
+        ],
+    )
+    .without_help_message()
+    .with_render_config(RenderConfig::empty())
+    .with_ansi_labels(true)
+    .prompt_with_terminal(&mut terminal)
+    .unwrap();
+
+    assert_eq!("docs.rs", ans.text);
+    assert!(terminal.frames()[0]
+        .contains("\n> \x1b]8;;https://docs.rs/inquire\x1b\\docs.rs\x1b]8;;\x1b\\\n"));
+    assert!(!terminal.frames()[4].contains("example"));
+    assert_eq!(
+        "? Docs: \x1b]8;;https://docs.rs/inquire\x1b\\docs.rs\x1b]8;;\x1b\\\n",
+        terminal.last_frame().unwrap()
+    );
+}
+
+#[test]
+fn selected_option_inherits_the_style_of_options() {
+    use crate::ui::{Attributes, Color, StyleSheet};
+
+    let mut terminal = TestBackend::new(vec![Key::Enter]);
+    let theme = RenderConfig::empty()
+        .with_option(StyleSheet::new().with_attr(Attributes::BOLD))
+        .with_selected_option(Some(StyleSheet::new().with_bg(Color::DarkBlue)));
+
+    Select::new("Region:", vec!["eu", "us"])
+        .without_help_message()
+        .with_render_config(RenderConfig::empty().merge(theme))
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(
+        "? Region:  \n> <bg=DarkBlue bold>eu</>\n  <bold>us</>\n",
+        terminal.styled_frames()[0].annotated()
+    );
+}
+
+#[test]
+fn only_ascii_glyphs_are_rendered_in_ascii_mode() {
+    let mut terminal = TestBackend::new(vec![Key::Enter]).with_size(10, 20);
+
+    Select::new("Env:", vec!["production", "dev"])
+        .with_render_config(RenderConfig::empty().with_ascii_only(true))
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(
+        "? Env:  \n> product.\n  dev\n[^v to m.]\n",
+        terminal.frames()[0]
+    );
+}
+
+#[test]
+fn truncated_options_end_with_the_configured_ellipsis() {
+    let mut terminal = TestBackend::new(vec![Key::Enter]).with_size(10, 20);
+
+    Select::new("Env:", vec!["production", "dev"])
+        .without_help_message()
+        .with_render_config(RenderConfig::empty().with_ellipsis("..."))
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("? Env:  \n> produ...\n  dev\n", terminal.frames()[0]);
+}
+
+#[test]
+fn unbound_keys_give_the_configured_feedback() {
+    let mut terminal = TestBackend::new(vec![
+        Key::Up(crate::ui::KeyModifiers::SHIFT),
+        Key::Down(crate::ui::KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Select::new("Env:", vec!["production", "dev"])
+        .with_render_config(RenderConfig::empty().with_error_feedback(crate::ui::Feedback::Flash))
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("dev", ans);
+    assert_eq!(1, terminal.flashes());
+    assert_eq!(0, terminal.bells());
+}
+
+#[test]
+fn table_header_is_aligned_with_the_columns() {
+    let pods = vec![("api-7d9f", "Running", 3), ("worker-5c2a", "Pending", 12)];
+
+    let mut terminal = TestBackend::new(vec![]).with_text("\n");
+    let ans = Select::new_with_display("Pod:", pods, &|(name, _, _)| name.to_string())
+        .with_columns(vec!["NAME", "STATUS", "RESTARTS"], &|(
+            name,
+            status,
+            restarts,
+        )| {
+            vec![name.to_string(), status.to_string(), restarts.to_string()]
+        })
+        .without_help_message()
+        .with_render_config(RenderConfig::empty())
+        .raw_prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, ("api-7d9f", "Running", 3)), ans);
+    assert_eq!(
+        "? Pod:  \n  NAME         STATUS    RESTARTS\n> api-7d9f     Running   3\n  worker-5c2a  Pending   12\n",
+        terminal.frames()[0]
+    );
+}
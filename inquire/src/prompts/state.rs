@@ -0,0 +1,72 @@
+use std::borrow::Cow;
+
+use crate::{type_aliases::HelpMessageFn, ui::CommonBackend};
+
+/// Current state of a prompt, given to the functions computing its help
+/// message, see for example [`Text::with_help_message_fn`].
+///
+/// Fields that do not apply to a prompt are left empty, e.g. `input` for a
+/// [`Toggle`] or `selected` for a [`Select`].
+///
+/// # Example
+///
+/// ```
+/// use inquire::{terminal::TestBackend, MultiSelect};
+///
+/// let mut terminal = TestBackend::new(vec![]).with_text(" \n");
+/// let toppings = MultiSelect::new("Toppings:", vec!["Cheese", "Ham", "Olives"])
+///     .with_help_message_fn(&|state| Some(format!("{} of 2 toppings left", 2 - state.selected.min(2))))
+///     .prompt_with_terminal(&mut terminal)?;
+///
+/// assert_eq!(vec!["Cheese"], toppings);
+/// assert!(terminal.frames()[0].contains("[2 of 2 toppings left]"));
+/// assert!(terminal.frames()[1].contains("[1 of 2 toppings left]"));
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+///
+/// [`Text::with_help_message_fn`]: crate::Text::with_help_message_fn
+/// [`Toggle`]: crate::Toggle
+/// [`Select`]: crate::Select
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct PromptState<'a> {
+    /// Text typed by the user, or the filter of list prompts.
+    pub input: &'a str,
+
+    /// Current answer, formatted as it would be rendered once submitted, when
+    /// the prompt has one. For example the parsed value of a [`CustomType`] or
+    /// the highlighted option of a [`Select`].
+    ///
+    /// Always `None` for [`Password`] prompts.
+    ///
+    /// [`CustomType`]: crate::CustomType
+    /// [`Select`]: crate::Select
+    /// [`Password`]: crate::Password
+    pub answer: Option<String>,
+
+    /// Number of options displayed, after filtering, in list prompts, or of
+    /// suggestions in a [`Text`](crate::Text) prompt.
+    pub options: usize,
+
+    /// Number of options checked in a [`MultiSelect`](crate::MultiSelect).
+    pub selected: usize,
+}
+
+/// Renders the help message computed by `help_message_fn` from the state of the
+/// prompt when set, or the static `help_message` otherwise.
+pub(crate) fn render_help_message<'s, B: CommonBackend>(
+    backend: &mut B,
+    help_message: Option<&str>,
+    help_message_fn: Option<HelpMessageFn<'_>>,
+    state: impl FnOnce() -> PromptState<'s>,
+) -> std::io::Result<()> {
+    let message = match help_message_fn {
+        Some(help_message_fn) => help_message_fn(&state()).map(Cow::Owned),
+        None => help_message.map(Cow::Borrowed),
+    };
+
+    match message {
+        Some(message) => backend.render_help_message(&message),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,249 @@
+use std::cmp::Ordering;
+
+use crate::{
+    error::{InquireError, InquireResult},
+    text::{pad_to_width, text_width},
+};
+
+/// Columns in which the options of a list prompt are laid out as a table.
+///
+/// Each option is rendered as a row with one cell per column, and a header row
+/// with the column titles is rendered above the options. The user can sort the
+/// rows by the n-th column by pressing Alt+n, pressing it again reverses the
+/// order, and restore the original order by pressing Alt+0. Only the first 9
+/// columns can be sorted.
+///
+/// Cells are compared as numbers when both can be parsed as such, and as
+/// case-insensitive text otherwise.
+pub struct TableColumns<'a, T> {
+    /// Titles of the columns, rendered in the header row.
+    pub headers: Vec<&'a str>,
+
+    /// Function that returns the cells of the row of an option, one per column.
+    pub cells: &'a dyn Fn(&T) -> Vec<String>,
+}
+
+impl<'a, T> Clone for TableColumns<'a, T> {
+    fn clone(&self) -> Self {
+        Self {
+            headers: self.headers.clone(),
+            cells: self.cells,
+        }
+    }
+}
+
+/// Column the rows are sorted by.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct TableSort {
+    pub column: usize,
+    pub descending: bool,
+}
+
+/// Rows of a list prompt in table mode, along with the active sort.
+pub(crate) struct Table<'a> {
+    headers: Vec<&'a str>,
+    cells: Vec<Vec<String>>,
+    widths: Vec<usize>,
+    rows: Vec<String>,
+    sort: Option<TableSort>,
+}
+
+const COLUMN_SEPARATOR: &str = "  ";
+const ASCENDING_INDICATOR: &str = "▲";
+const DESCENDING_INDICATOR: &str = "▼";
+
+impl<'a> Table<'a> {
+    pub fn new<T>(columns: &TableColumns<'a, T>, options: &[T]) -> InquireResult<Self> {
+        if columns.headers.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Table columns can not be empty".into(),
+            ));
+        }
+
+        let cells: Vec<Vec<String>> = options.iter().map(columns.cells).collect();
+        if let Some(index) = cells
+            .iter()
+            .position(|row| row.len() != columns.headers.len())
+        {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Option {} has {} cells, expected one for each of the {} columns",
+                index,
+                cells[index].len(),
+                columns.headers.len()
+            )));
+        }
+
+        // reserve room for the sort indicator, so that the layout does not
+        // shift when the user sorts by another column
+        let mut widths: Vec<usize> = columns
+            .headers
+            .iter()
+            .map(|header| text_width(header) + 1 + text_width(ASCENDING_INDICATOR))
+            .collect();
+        for row in &cells {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(text_width(cell));
+            }
+        }
+
+        let rows = cells.iter().map(|row| layout_row(row, &widths)).collect();
+
+        Ok(Self {
+            headers: columns.headers.clone(),
+            cells,
+            widths,
+            rows,
+            sort: None,
+        })
+    }
+
+    /// Row of the option at the given index, with its cells aligned to the columns.
+    pub fn row(&self, index: usize) -> &String {
+        &self.rows[index]
+    }
+
+    /// Row with the column titles, the sorted column marked by an indicator.
+    pub fn header(&self) -> String {
+        let titles: Vec<String> = self
+            .headers
+            .iter()
+            .enumerate()
+            .map(|(column, header)| match self.sort {
+                Some(sort) if sort.column == column => {
+                    let indicator = match sort.descending {
+                        true => DESCENDING_INDICATOR,
+                        false => ASCENDING_INDICATOR,
+                    };
+                    format!("{} {}", header, indicator)
+                }
+                _ => header.to_string(),
+            })
+            .collect();
+
+        layout_row(&titles, &self.widths)
+    }
+
+    /// Cells of the option at the given index separated by spaces, used to
+    /// filter the options.
+    pub fn search_text(&self, index: usize) -> String {
+        self.cells[index].join(" ")
+    }
+
+    /// Sorts by the given column in ascending order, or reverses the order if
+    /// the rows are already sorted by it. `None` restores the original order.
+    pub fn sort_by(&mut self, column: Option<usize>) {
+        self.sort = match (column, self.sort) {
+            (Some(column), Some(sort)) if sort.column == column => Some(TableSort {
+                column,
+                descending: !sort.descending,
+            }),
+            (Some(column), _) => Some(TableSort {
+                column,
+                descending: false,
+            }),
+            (None, _) => None,
+        };
+    }
+
+    /// Whether the rows are sorted by a column.
+    pub fn is_sorted(&self) -> bool {
+        self.sort.is_some()
+    }
+
+    /// Orders the given option indices according to the active sort.
+    pub fn sort(&self, indices: &mut [usize]) {
+        match self.sort {
+            Some(sort) => indices.sort_by(|a, b| {
+                let ordering =
+                    compare_cells(&self.cells[*a][sort.column], &self.cells[*b][sort.column]);
+                match sort.descending {
+                    true => ordering.reverse(),
+                    false => ordering,
+                }
+            }),
+            None => indices.sort_unstable(),
+        }
+    }
+}
+
+fn layout_row<S: AsRef<str>>(cells: &[S], widths: &[usize]) -> String {
+    let mut row = String::new();
+
+    for (column, (cell, width)) in cells.iter().zip(widths).enumerate() {
+        let cell = cell.as_ref();
+        if column > 0 {
+            row.push_str(COLUMN_SEPARATOR);
+        }
+        match column + 1 < widths.len() {
+            true => row.push_str(&pad_to_width(cell, *width)),
+            false => row.push_str(cell),
+        }
+    }
+
+    row
+}
+
+fn compare_cells(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        _ => a.to_lowercase().cmp(&b.to_lowercase()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Table, TableColumns};
+
+    fn table(options: &[(&'static str, u32)]) -> Table<'static> {
+        let columns = TableColumns {
+            headers: vec!["Name", "Size"],
+            cells: &|(name, size): &(&str, u32)| vec![name.to_string(), size.to_string()],
+        };
+
+        Table::new(&columns, options).unwrap()
+    }
+
+    #[test]
+    fn cells_are_aligned_to_the_widest_value() {
+        let table = table(&[("README.md", 9), ("src", 120)]);
+
+        assert_eq!("Name       Size", table.header());
+        assert_eq!("README.md  9", table.row(0));
+        assert_eq!("src        120", table.row(1));
+    }
+
+    #[test]
+    fn sorting_twice_reverses_the_order() {
+        let mut table = table(&[("b", 10), ("a", 9), ("c", 100)]);
+        let mut indices = vec![0, 1, 2];
+
+        table.sort_by(Some(1));
+        table.sort(&mut indices);
+        assert_eq!(vec![1, 0, 2], indices);
+        assert_eq!("Name    Size ▲", table.header());
+
+        table.sort_by(Some(1));
+        table.sort(&mut indices);
+        assert_eq!(vec![2, 0, 1], indices);
+        assert_eq!("Name    Size ▼", table.header());
+
+        table.sort_by(Some(0));
+        table.sort(&mut indices);
+        assert_eq!(vec![1, 0, 2], indices);
+        assert_eq!("Name ▲  Size", table.header());
+
+        table.sort_by(None);
+        table.sort(&mut indices);
+        assert_eq!(vec![0, 1, 2], indices);
+    }
+
+    #[test]
+    fn rows_must_have_a_cell_per_column() {
+        let columns = TableColumns {
+            headers: vec!["Name", "Size"],
+            cells: &|name: &&str| vec![name.to_string()],
+        };
+
+        assert!(Table::new(&columns, &["a"]).is_err());
+    }
+}
@@ -21,17 +21,25 @@ pub enum TextPromptAction {
     MoveToSuggestionPageDown,
     /// When a suggestion list exists, autocompletes the text input with the current suggestion.
     UseCurrentSuggestion,
+    /// When a suggestion list exists, highlights the next suggestion and
+    /// replaces the text input with it, wrapping around at the end of the list.
+    CycleToNextSuggestion,
+    /// When a suggestion list exists, highlights the previous suggestion and
+    /// replaces the text input with it, wrapping around at the start of the list.
+    CycleToPreviousSuggestion,
 }
 
 impl InnerAction<TextConfig> for TextPromptAction {
-    fn from_key(key: Key, _config: &TextConfig) -> Option<Self> {
+    fn from_key(key: Key, config: &TextConfig) -> Option<Self> {
         let action = match key {
             Key::Up(KeyModifiers::NONE) => Self::MoveToSuggestionAbove,
-            Key::PageUp => Self::MoveToSuggestionPageUp,
+            Key::PageUp(_) => Self::MoveToSuggestionPageUp,
 
             Key::Down(KeyModifiers::NONE) => Self::MoveToSuggestionBelow,
-            Key::PageDown => Self::MoveToSuggestionPageDown,
+            Key::PageDown(_) => Self::MoveToSuggestionPageDown,
 
+            Key::Tab if config.tab_cycles => Self::CycleToNextSuggestion,
+            Key::BackTab if config.tab_cycles => Self::CycleToPreviousSuggestion,
             Key::Tab => Self::UseCurrentSuggestion,
 
             key => match InputAction::from_key(key, &()) {
@@ -1,16 +1,33 @@
-use crate::Text;
+use std::time::Duration;
+
+use crate::{config::InterruptBehavior, prompts::prompt::Timeout, Text};
 
 /// Configuration settings used in the execution of a TextPrompt.
 #[derive(Copy, Clone, Debug)]
 pub struct TextConfig {
     /// Page size of the suggestion list, if it exists.
     pub page_size: usize,
+    /// Whether the rest of the top suggestion is rendered as ghost text.
+    pub ghost_text: bool,
+    /// Whether Tab and Shift+Tab cycle through the suggestions in place.
+    pub tab_cycles: bool,
+    /// What happens when the user presses Ctrl+C.
+    pub interrupt_behavior: InterruptBehavior,
+    /// Timeout applied until the first key press.
+    pub timeout: Option<Timeout>,
+    /// Interval at which the prompt is re-rendered without key presses.
+    pub refresh_interval: Option<Duration>,
 }
 
 impl From<&Text<'_>> for TextConfig {
     fn from(value: &Text<'_>) -> Self {
         Self {
             page_size: value.page_size,
+            ghost_text: value.ghost_text,
+            tab_cycles: value.tab_cycles,
+            interrupt_behavior: value.interrupt_behavior,
+            timeout: Timeout::new(value.timeout, value.timeout_countdown),
+            refresh_interval: value.refresh_interval,
         }
     }
 }
@@ -7,15 +7,23 @@ mod test;
 
 pub use action::*;
 
+use std::{sync::Arc, time::Duration};
+
 use crate::{
     autocompletion::Autocomplete,
-    config::get_configuration,
+    config::{
+        get_configuration, get_event_listener, get_global_options, get_interrupt_behavior,
+        InterruptBehavior,
+    },
     error::{InquireError, InquireResult},
     formatter::{StringFormatter, DEFAULT_STRING_FORMATTER},
+    handle::PromptHandle,
     prompts::prompt::Prompt,
-    terminal::get_default_terminal,
-    ui::{Backend, RenderConfig, TextBackend},
-    validator::StringValidator,
+    report::PromptReport,
+    terminal::{get_default_terminal, Terminal},
+    type_aliases::{EventListener, HelpMessageFn},
+    ui::{Backend, Block, FinalRender, Redaction, RenderConfig, TextBackend},
+    validator::{BackgroundStringValidator, StringValidator},
 };
 
 use self::prompt::TextPrompt;
@@ -34,9 +42,15 @@ const DEFAULT_HELP_MESSAGE_WITH_AC: &str = "↑↓ to move, tab to autocomplete,
 /// - **Default value**: Default value returned when the user submits an empty response.
 /// - **Initial value**: Initial value of the prompt's text input, in case you want to display the prompt with something already filled in.
 /// - **Placeholder**: Short hint that describes the expected value of the input.
+/// - **Input filter**: Function deciding which characters the user can type, rejecting the others as they are typed.
+/// - **Input mask**: Template such as `"####-##-##"` restricting the characters accepted at each position and inserting separators automatically.
+/// - **Max length**: Maximum number of characters the user can type, optionally rendering a `12/80` counter after the input.
 /// - **Validators**: Custom validators to the user's input, displaying an error message if the input does not pass the requirements.
+/// - **Background validators**: Slow validators run on a worker thread after submission, rendering a spinner while they run.
 /// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
 /// - **Suggester**: Custom function that returns a list of input suggestions based on the current text input. See more on "Autocomplete" below.
+/// - **Tab cycling**: Whether repeated Tab presses cycle through the suggestions in place, Shift+Tab cycling backwards.
+/// - **Ghost text**: Whether the rest of the top suggestion is rendered dimmed after the input, fish-shell style, accepted with the right arrow or End.
 ///
 /// ## Default behaviors
 ///
@@ -54,8 +68,8 @@ const DEFAULT_HELP_MESSAGE_WITH_AC: &str = "↑↓ to move, tab to autocomplete,
 ///
 /// You can call `with_autocomplete()` and provide a value that implements the `Autocomplete` trait. The `Autocomplete` trait has two provided methods: `get_suggestions` and `get_completion`.
 ///
-/// - `get_suggestions` is called whenever the user's text input is modified, e.g. a new letter is typed, returning a `Vec<String>`. The `Vec<String>` is the list of suggestions that the prompt displays to the user according to their text input. The user can then navigate through the list and if they submit while highlighting one of these suggestions, the suggestion is treated as the final answer.
-/// - `get_completion` is called whenever the user presses the autocompletion hotkey (`tab` by default), with the current text input and the text of the currently highlighted suggestion, if any, as parameters. This method should return whether any text replacement (an autocompletion) should be made. If the prompt receives a replacement to be made, it substitutes the current text input for the string received from the `get_completion` call.
+/// - `get_suggestions` is called whenever the user's text input is modified, e.g. a new letter is typed, returning a `Vec<Suggestion>`. The `Vec<Suggestion>` is the list of suggestions that the prompt displays to the user according to their text input. A suggestion may have a description, rendered dimmed next to it, and a replacement text inserted or submitted instead of the displayed value. The user can then navigate through the list and if they submit while highlighting one of these suggestions, the suggestion is treated as the final answer.
+/// - `get_completion` is called whenever the user presses the autocompletion hotkey (`tab` by default), with the current text input and the replacement text of the currently highlighted suggestion, if any, as parameters. This method should return whether any text replacement (an autocompletion) should be made. If the prompt receives a replacement to be made, it substitutes the current text input for the string received from the `get_completion` call.
 ///
 /// For example, in the `complex_autocompletion.rs` example file, the `FilePathCompleter` scans the file system based on the current text input, storing a list of paths that match the current text input.
 ///
@@ -94,9 +108,40 @@ pub struct Text<'a> {
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
+    /// Function computing the help message from the current state of the
+    /// prompt, replacing [`help_message`](Self::help_message) when set.
+    ///
+    /// See [`with_help_message_fn`](Self::with_help_message_fn) for more details.
+    pub help_message_fn: Option<HelpMessageFn<'a>>,
+
+    /// Content rendered above the prompt.
+    pub header: Option<Block>,
+
+    /// Content rendered below the prompt.
+    pub footer: Option<Block>,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: StringFormatter<'a>,
 
+    /// Function deciding whether a typed character is accepted in the input.
+    ///
+    /// See [`with_input_filter`](Self::with_input_filter) for more details.
+    pub input_filter: Option<fn(char) -> bool>,
+
+    /// Template restricting the characters accepted at each position of the input.
+    ///
+    /// See [`with_input_mask`](Self::with_input_mask) for more details.
+    pub input_mask: Option<&'a str>,
+
+    /// Maximum number of characters the user can type.
+    ///
+    /// See [`with_max_length`](Self::with_max_length) for more details.
+    pub max_length: Option<usize>,
+
+    /// Whether to render a `12/80` counter after the input when there is a
+    /// [`max_length`](Self::max_length).
+    pub length_counter: bool,
+
     /// Autocompleter responsible for handling suggestions and input completions.
     pub autocompleter: Option<Box<dyn Autocomplete>>,
 
@@ -108,9 +153,53 @@ pub struct Text<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub validators: Vec<Box<dyn StringValidator>>,
 
+    /// Collection of validators to run on a worker thread once the input passes
+    /// all [`validators`](Self::validators).
+    ///
+    /// See [`with_background_validator`](Self::with_background_validator) for more details.
+    pub background_validators: Vec<Arc<dyn BackgroundStringValidator>>,
+
     /// Page size of the suggestions displayed to the user, when applicable.
     pub page_size: usize,
 
+    /// Whether the rest of the top suggestion is rendered as ghost text after
+    /// the input.
+    ///
+    /// See [`with_ghost_text`](Self::with_ghost_text) for more details.
+    pub ghost_text: bool,
+
+    /// Whether Tab and Shift+Tab cycle through the suggestions in place.
+    ///
+    /// See [`with_tab_cycles`](Self::with_tab_cycles) for more details.
+    pub tab_cycles: bool,
+
+    /// What happens when the user presses Ctrl+C.
+    pub interrupt_behavior: InterruptBehavior,
+
+    /// Listener notified of the events of the prompt, such as key presses and
+    /// submissions.
+    ///
+    /// See [`with_event_listener`](Self::with_event_listener) for more details.
+    pub event_listener: Option<EventListener<'a>>,
+
+    /// Handle aborting the prompt from outside of it, if any.
+    ///
+    /// See [`with_handle`](Self::with_handle) for more details.
+    pub handle: Option<&'a PromptHandle>,
+
+    /// Time to wait for the user to start interacting with the prompt.
+    ///
+    /// See [`with_timeout`](Self::with_timeout) for more details.
+    pub timeout: Option<Duration>,
+
+    /// Whether to render the time left before the timeout expires.
+    pub timeout_countdown: bool,
+
+    /// Interval at which the prompt is re-rendered while no key is pressed.
+    ///
+    /// See [`with_refresh_interval`](Self::with_refresh_interval) for more details.
+    pub refresh_interval: Option<Duration>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -137,16 +226,34 @@ impl<'a> Text<'a> {
 
     /// Creates a [Text] with the provided message and default options.
     pub fn new(message: &'a str) -> Self {
+        let global = get_global_options();
+
         Self {
             message,
             placeholder: None,
             initial_value: None,
             default: None,
-            help_message: Self::DEFAULT_HELP_MESSAGE,
+            help_message: global.help_message(Self::DEFAULT_HELP_MESSAGE),
+            help_message_fn: None,
+            header: None,
+            footer: None,
             validators: Self::DEFAULT_VALIDATORS,
+            background_validators: vec![],
             formatter: Self::DEFAULT_FORMATTER,
-            page_size: Self::DEFAULT_PAGE_SIZE,
+            page_size: global.page_size,
+            ghost_text: false,
+            tab_cycles: false,
+            input_filter: None,
+            input_mask: None,
+            max_length: None,
+            length_counter: false,
             autocompleter: None,
+            interrupt_behavior: get_interrupt_behavior(),
+            event_listener: get_event_listener(),
+            handle: None,
+            timeout: None,
+            timeout_countdown: false,
+            refresh_interval: None,
             render_config: get_configuration(),
         }
     }
@@ -157,6 +264,30 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Sets a function computing the help message from the current state of the
+    /// prompt, called whenever it is rendered. It takes precedence over the
+    /// static help message, and returning `None` hides the help line.
+    ///
+    /// See [`PromptState`](crate::PromptState) for the state given to the function.
+    pub fn with_help_message_fn(mut self, help_message_fn: HelpMessageFn<'a>) -> Self {
+        self.help_message_fn = Some(help_message_fn);
+        self
+    }
+
+    /// Sets the content rendered above the prompt, such as the current git
+    /// branch. It is redrawn and cleared along with the prompt, see [`Block`].
+    pub fn with_header<H: Into<Block>>(mut self, header: H) -> Self {
+        self.header = Some(header.into());
+        self
+    }
+
+    /// Sets the content rendered below the prompt. It is redrawn and cleared
+    /// along with the prompt, see [`Block`].
+    pub fn with_footer<F: Into<Block>>(mut self, footer: F) -> Self {
+        self.footer = Some(footer.into());
+        self
+    }
+
     /// Sets the initial value of the prompt's text input.
     ///
     /// If you want to set a default value for the prompt, returned when the user's submission is empty, see [`with_default`].
@@ -200,6 +331,85 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Sets whether the rest of the top suggestion is rendered as dimmed ghost
+    /// text after the input, like the autosuggestions of the fish shell, when
+    /// the suggestion starts with the input and the cursor is at its end.
+    ///
+    /// Pressing the right arrow or End accepts the ghost text, replacing the
+    /// input by the [replacement text](crate::autocompletion::Suggestion::replacement_text)
+    /// of the suggestion. The suggestion list is still displayed below the input.
+    ///
+    /// Ghost text is rendered with the `placeholder` style sheet of the render config.
+    pub fn with_ghost_text(mut self, ghost_text: bool) -> Self {
+        self.ghost_text = ghost_text;
+        self
+    }
+
+    /// Sets whether repeated Tab presses cycle through the suggestions in place,
+    /// like shell completion menus, instead of autocompleting the input with
+    /// [`Autocomplete::get_completion`].
+    ///
+    /// Each Tab press highlights the next suggestion, wrapping around at the end
+    /// of the list, and replaces the text input with its replacement text,
+    /// while Shift+Tab cycles backwards. The suggestion list is only updated
+    /// once the user edits the input again. When there are no suggestions, Tab
+    /// falls back to [`Autocomplete::get_completion`].
+    pub fn with_tab_cycles(mut self, tab_cycles: bool) -> Self {
+        self.tab_cycles = tab_cycles;
+        self
+    }
+
+    /// Sets a function deciding whether a typed character is accepted in the
+    /// input, e.g. `char::is_numeric`. Rejected characters are simply not
+    /// written, instead of failing validation on submission.
+    ///
+    /// The filter does not apply to the initial value nor to suggestions.
+    pub fn with_input_filter(mut self, filter: fn(char) -> bool) -> Self {
+        self.input_filter = Some(filter);
+        self
+    }
+
+    /// Sets a template restricting the characters accepted at each position of
+    /// the input, great for phone numbers, dates or colors.
+    ///
+    /// In the template, `#` accepts a digit, `A` a letter, `H` a hexadecimal
+    /// digit and `*` any character. Every other character is a separator,
+    /// inserted automatically when the user types the character that follows
+    /// it. A backslash turns the next character into a separator, e.g.
+    /// `"\\#HHHHHH"` for hex colors.
+    ///
+    /// Characters that do not fit the template are rejected as they are typed,
+    /// and a non-empty input is only submitted once it fills the whole template.
+    ///
+    /// ```no_run
+    /// use inquire::Text;
+    ///
+    /// let date = Text::new("Date:").with_input_mask("####-##-##").prompt();
+    /// ```
+    pub fn with_input_mask(mut self, mask: &'a str) -> Self {
+        self.input_mask = Some(mask);
+        self
+    }
+
+    /// Sets the maximum number of characters the user can type, further key
+    /// presses being ignored once the input reaches it.
+    ///
+    /// Accepting a suggestion or its ghost text may still make the input
+    /// longer, in which case the counter is rendered in the error style.
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Sets whether to render a `12/80` counter of the input length after the
+    /// input, styled with the [`length_counter`](crate::ui::RenderConfig::length_counter)
+    /// style sheet of the render config. The counter is only rendered when a
+    /// maximum length is set.
+    pub fn with_length_counter(mut self, length_counter: bool) -> Self {
+        self.length_counter = length_counter;
+        self
+    }
+
     /// Adds a validator to the collection of validators. You might want to use this feature
     /// in case you need to require certain features from the user's answer, such as
     /// defining a limit of characters.
@@ -232,12 +442,87 @@ impl<'a> Text<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub fn with_validators(mut self, validators: &[Box<dyn StringValidator>]) -> Self {
         for validator in validators {
-            #[allow(clippy::clone_double_ref)]
             self.validators.push(validator.clone());
         }
         self
     }
 
+    /// Adds a validator that runs on a worker thread, meant for checks that take
+    /// noticeable time, such as looking up a value over the network.
+    ///
+    /// Background validators run in the order they are added, once the input passes
+    /// all regular validators. While they run, a spinner with the elapsed time is
+    /// rendered and the user can press ESC to cancel the validation and go back to
+    /// editing the input. The [`CancellationToken`](crate::validator::CancellationToken)
+    /// passed to the validator is set when that happens.
+    ///
+    /// Background validation needs a terminal backend able to poll for key presses,
+    /// which the `crossterm` and `termion` backends support. On the `console`
+    /// backend the spinner is still rendered, but the validation can not be canceled.
+    pub fn with_background_validator<V>(mut self, validator: V) -> Self
+    where
+        V: BackgroundStringValidator + 'static,
+    {
+        self.background_validators.push(Arc::new(validator));
+        self
+    }
+
+    /// Sets what happens when the user presses Ctrl+C, overriding the global
+    /// setting.
+    pub fn with_interrupt_behavior(mut self, interrupt_behavior: InterruptBehavior) -> Self {
+        self.interrupt_behavior = interrupt_behavior;
+        self
+    }
+
+    /// Sets a listener notified of the events of the prompt, such as renders,
+    /// key presses and submissions, overriding the global listener.
+    ///
+    /// See the [`events`](crate::events) module for more details.
+    pub fn with_event_listener(mut self, listener: EventListener<'a>) -> Self {
+        self.event_listener = Some(listener);
+        self
+    }
+
+    /// Sets a handle aborting the prompt from outside of it, e.g. from another
+    /// thread or a Ctrl+C handler, canceling it as if the user pressed ESC.
+    ///
+    /// See the [`handle`](crate::handle) module for more details.
+    pub fn with_handle(mut self, handle: &'a PromptHandle) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets a timeout for the user to start interacting with the prompt.
+    ///
+    /// If no key is pressed before it expires, the default value is submitted, or
+    /// [`InquireError::Timeout`] is returned when there is none.
+    /// Once a key is pressed, the prompt waits for the user indefinitely.
+    ///
+    /// Timeouts are supported by the `crossterm` and `termion` backends, the
+    /// `console` backend always waits for the first key press.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether to render the time left before the timeout expires below the prompt.
+    pub fn with_timeout_countdown(mut self, countdown: bool) -> Self {
+        self.timeout_countdown = countdown;
+        self
+    }
+
+    /// Sets an interval at which the prompt is re-rendered even when no key is
+    /// pressed, e.g. for content computed at render time, such as a dynamic
+    /// help message showing the current time, to stay up to date.
+    ///
+    /// Requires a terminal able to wait for keys with a timeout, such as the
+    /// crossterm or termion ones. Other terminals only re-render the prompt
+    /// after key presses.
+    pub fn with_refresh_interval(mut self, interval: Duration) -> Self {
+        self.refresh_interval = Some(interval);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -251,6 +536,35 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Sets how the prompt is rendered once answered, e.g. [`FinalRender::None`]
+    /// for the prompt to disappear. This is a shorthand for setting the
+    /// [`final_render`](RenderConfig::final_render) of the render config, so it
+    /// must be called after [`with_render_config`](Self::with_render_config).
+    pub fn with_final_render(mut self, final_render: FinalRender) -> Self {
+        self.render_config.final_render = final_render;
+        self
+    }
+
+    /// Sets how the answer is redacted once the prompt is answered, e.g.
+    /// [`Redaction::Masked`] for secrets to stay out of the scrollback. This is
+    /// a shorthand for setting the [`answer_redaction`](RenderConfig::answer_redaction)
+    /// of the render config, so it must be called after
+    /// [`with_render_config`](Self::with_render_config).
+    pub fn with_answer_redaction(mut self, answer_redaction: Redaction) -> Self {
+        self.render_config.answer_redaction = answer_redaction;
+        self
+    }
+
+    /// Sets whether the prompt is rendered in fullscreen, on the alternate
+    /// screen buffer, leaving only its final rendering in the original screen.
+    /// This is a shorthand for setting the
+    /// [`fullscreen`](RenderConfig::fullscreen) flag of the render config, so
+    /// it must be called after [`with_render_config`](Self::with_render_config).
+    pub fn with_fullscreen(mut self, fullscreen: bool) -> Self {
+        self.render_config.fullscreen = fullscreen;
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -271,11 +585,33 @@ impl<'a> Text<'a> {
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     pub fn prompt(self) -> InquireResult<String> {
-        let terminal = get_default_terminal()?;
+        self.prompt_with_terminal(get_default_terminal()?)
+    }
+
+    /// Prompts the user through the given terminal instead of the default
+    /// terminal of the enabled backend feature, see [`Terminal`].
+    pub fn prompt_with_terminal<Term: Terminal>(self, terminal: Term) -> InquireResult<String> {
         let mut backend = Backend::new(terminal, self.render_config)?;
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Same as [`prompt`](Self::prompt), also returning a [`PromptReport`]
+    /// describing how the user interacted with the prompt.
+    pub fn prompt_with_report(self) -> InquireResult<(String, PromptReport)> {
+        self.prompt_with_report_and_terminal(get_default_terminal()?)
+    }
+
+    /// Same as [`prompt_with_terminal`](Self::prompt_with_terminal), also
+    /// returning a [`PromptReport`] describing how the user interacted with
+    /// the prompt.
+    pub fn prompt_with_report_and_terminal<Term: Terminal>(
+        self,
+        terminal: Term,
+    ) -> InquireResult<(String, PromptReport)> {
+        let mut backend = Backend::new(terminal, self.render_config)?;
+        TextPrompt::from(self).prompt_with_report(&mut backend)
+    }
+
     pub(crate) fn prompt_with_backend<B: TextBackend>(
         self,
         backend: &mut B,
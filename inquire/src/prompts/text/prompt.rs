@@ -1,16 +1,23 @@
-use std::cmp::min;
+use std::{cmp::min, sync::Arc, time::Duration};
 
 use crate::{
-    autocompletion::{NoAutoCompletion, Replacement},
+    autocompletion::{NoAutoCompletion, Replacement, Suggestion},
+    config::{translate, InterruptBehavior},
     error::InquireResult,
     formatter::StringFormatter,
-    input::{Input, InputActionResult},
+    handle::PromptHandle,
+    input::{Input, InputActionResult, InputMask, LineDirection, Magnitude},
     list_option::ListOption,
-    prompts::prompt::{ActionResult, Prompt},
-    ui::TextBackend,
+    prompts::{
+        background::PendingValidation,
+        prompt::{ActionResult, Prompt, Timeout},
+        state::{render_help_message, PromptState},
+    },
+    type_aliases::{EventListener, HelpMessageFn},
+    ui::{Block, TextBackend},
     utils::paginate,
-    validator::{ErrorMessage, StringValidator, Validation},
-    Autocomplete, InquireError, Text,
+    validator::{BackgroundStringValidator, ErrorMessage, StringValidator, Validation},
+    Autocomplete, InputAction, InquireError, Text,
 };
 
 use super::{action::TextPromptAction, config::TextConfig, DEFAULT_HELP_MESSAGE_WITH_AC};
@@ -18,20 +25,31 @@ use super::{action::TextPromptAction, config::TextConfig, DEFAULT_HELP_MESSAGE_W
 pub struct TextPrompt<'a> {
     message: &'a str,
     config: TextConfig,
+    event_listener: Option<EventListener<'a>>,
+    handle: Option<&'a PromptHandle>,
     default: Option<&'a str>,
     help_message: Option<&'a str>,
+    help_message_fn: Option<HelpMessageFn<'a>>,
+    header: Option<Block>,
+    footer: Option<Block>,
     input: Input,
+    input_filter: Option<fn(char) -> bool>,
+    input_mask: Option<InputMask>,
     formatter: StringFormatter<'a>,
     validators: Vec<Box<dyn StringValidator>>,
+    background_validators: Vec<Arc<dyn BackgroundStringValidator>>,
+    pending_validation: Option<PendingValidation<String>>,
     error: Option<ErrorMessage>,
     autocompleter: Box<dyn Autocomplete>,
-    suggested_options: Vec<String>,
+    suggested_options: Vec<Suggestion>,
     suggestion_cursor_index: Option<usize>,
 }
 
 impl<'a> From<Text<'a>> for TextPrompt<'a> {
     fn from(so: Text<'a>) -> Self {
-        let input = Input::new_with(so.initial_value.unwrap_or_default());
+        let input = Input::new_with(so.initial_value.unwrap_or_default())
+            .with_max_length(so.max_length)
+            .with_length_counter(so.length_counter);
         let input = if let Some(placeholder) = so.placeholder {
             input.with_placeholder(placeholder)
         } else {
@@ -41,17 +59,26 @@ impl<'a> From<Text<'a>> for TextPrompt<'a> {
         Self {
             message: so.message,
             config: (&so).into(),
+            event_listener: so.event_listener,
+            handle: so.handle,
             default: so.default,
             help_message: so.help_message,
+            help_message_fn: so.help_message_fn,
+            header: so.header,
+            footer: so.footer,
             formatter: so.formatter,
             autocompleter: so
                 .autocompleter
                 .unwrap_or_else(|| Box::<NoAutoCompletion>::default()),
             input,
+            input_filter: so.input_filter,
+            input_mask: so.input_mask.map(InputMask::new),
             error: None,
             suggestion_cursor_index: None,
             suggested_options: vec![],
             validators: so.validators,
+            background_validators: so.background_validators,
+            pending_validation: None,
         }
     }
 }
@@ -72,13 +99,39 @@ impl<'a> TextPrompt<'a> {
 
     fn get_highlighted_suggestion(&self) -> Option<&str> {
         if let Some(cursor) = self.suggestion_cursor_index {
-            let suggestion = self.suggested_options.get(cursor).unwrap().as_ref();
-            Some(suggestion)
+            let suggestion = self.suggested_options.get(cursor).unwrap();
+            Some(suggestion.replacement_text())
         } else {
             None
         }
     }
 
+    /// Rest of the top suggestion after the input, when ghost text is enabled,
+    /// the suggestion starts with the input and the cursor is at its end.
+    fn ghost_text(&self) -> Option<&str> {
+        let content = self.input.content();
+        if !self.config.ghost_text
+            || content.is_empty()
+            || self.input.cursor() < self.input.length()
+        {
+            return None;
+        }
+
+        let suggestion = self.suggested_options.first()?.replacement_text();
+        match suggestion.strip_prefix(content) {
+            Some(rest) if !rest.is_empty() => Some(rest),
+            _ => None,
+        }
+    }
+
+    fn accept_ghost_text(&mut self) -> InquireResult<ActionResult> {
+        let suggestion = self.suggested_options[0].replacement_text().to_owned();
+        self.input.set_content(suggestion);
+        self.update_suggestions()?;
+
+        Ok(ActionResult::NeedsRedraw)
+    }
+
     fn move_cursor_up(&mut self, qty: usize) -> ActionResult {
         let new_cursor_index = match self.suggestion_cursor_index {
             None => None,
@@ -124,18 +177,42 @@ impl<'a> TextPrompt<'a> {
             .get_completion(self.input.content(), suggestion)?
         {
             Replacement::Some(value) => {
-                self.input = Input::new_with(value);
+                self.input.set_content(value);
                 Ok(ActionResult::NeedsRedraw)
             }
             Replacement::None => Ok(ActionResult::Clean),
         }
     }
 
+    fn cycle_suggestions(&mut self, forward: bool) -> InquireResult<ActionResult> {
+        let len = self.suggested_options.len();
+        if len == 0 {
+            return match forward {
+                true => self.use_current_suggestion(),
+                false => Ok(ActionResult::Clean),
+            };
+        }
+
+        let index = match (self.suggestion_cursor_index, forward) {
+            (None, true) => 0,
+            (None, false) => len - 1,
+            (Some(index), true) => (index + 1) % len,
+            (Some(index), false) => (index + len - 1) % len,
+        };
+        self.suggestion_cursor_index = Some(index);
+
+        // the suggestions are kept until the user edits the input again
+        let suggestion = self.suggested_options[index].replacement_text();
+        self.input.set_content(suggestion);
+
+        Ok(ActionResult::NeedsRedraw)
+    }
+
     fn get_current_answer(&self) -> &str {
         // If there is a highlighted suggestion, assume user wanted it as
         // the answer.
         if let Some(suggestion) = self.get_highlighted_suggestion() {
-            return suggestion;
+            return self.input.truncate_to_max_length(suggestion);
         }
 
         // Empty input with default values override any validators.
@@ -148,7 +225,28 @@ impl<'a> TextPrompt<'a> {
         self.input.content()
     }
 
+    fn state(&self) -> PromptState<'_> {
+        PromptState {
+            input: self.input.content(),
+            answer: Some((self.formatter)(self.get_current_answer())),
+            options: self.suggested_options.len(),
+            selected: 0,
+        }
+    }
+
     fn validate_current_answer(&self) -> InquireResult<Validation> {
+        if let Some(mask) = &self.input_mask {
+            let content = self.input.content();
+            if self.suggestion_cursor_index.is_none()
+                && !content.is_empty()
+                && !mask.is_complete(content)
+            {
+                return Ok(Validation::Invalid(ErrorMessage::Custom(
+                    translate("The input is incomplete").into(),
+                )));
+            }
+        }
+
         for validator in &self.validators {
             match validator.validate(self.get_current_answer()) {
                 Ok(Validation::Valid) => {}
@@ -173,6 +271,41 @@ where
         &self.config
     }
 
+    fn interrupt_behavior(&self) -> InterruptBehavior {
+        self.config.interrupt_behavior
+    }
+
+    fn event_listener(&self) -> Option<EventListener<'_>> {
+        self.event_listener
+    }
+
+    fn prompt_handle(&self) -> Option<&PromptHandle> {
+        self.handle
+    }
+
+    fn header(&self) -> Option<&Block> {
+        self.header.as_ref()
+    }
+
+    fn footer(&self) -> Option<&Block> {
+        self.footer.as_ref()
+    }
+
+    fn timeout(&self) -> Option<Timeout> {
+        self.config.timeout
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        self.config.refresh_interval
+    }
+
+    fn submit_on_timeout(&mut self) -> InquireResult<Option<String>> {
+        match self.default {
+            Some(_) => Prompt::<B, _, _, _>::submit(self),
+            None => Ok(None),
+        }
+    }
+
     fn format_answer(&self, answer: &String) -> String {
         (self.formatter)(answer)
     }
@@ -181,8 +314,53 @@ where
         self.update_suggestions()
     }
 
+    fn is_busy(&self) -> bool {
+        self.pending_validation.is_some()
+    }
+
+    fn poll_background(&mut self, timeout: Duration) -> InquireResult<Option<String>> {
+        let validation = match self
+            .pending_validation
+            .as_ref()
+            .and_then(|p| p.poll(timeout))
+        {
+            Some(validation) => validation,
+            None => return Ok(None),
+        };
+
+        let pending = self.pending_validation.take().unwrap();
+        match validation? {
+            Validation::Valid => Ok(Some(pending.answer().clone())),
+            Validation::Invalid(msg) => {
+                self.error = Some(msg);
+                Ok(None)
+            }
+        }
+    }
+
+    fn pre_cancel(&mut self) -> InquireResult<bool> {
+        // Canceling a background validation goes back to editing the answer.
+        Ok(self.pending_validation.take().is_none())
+    }
+
+    fn is_default_answer(&self) -> bool {
+        self.default.is_some()
+            && self.input.content().is_empty()
+            && self.get_highlighted_suggestion().is_none()
+    }
+
     fn submit(&mut self) -> InquireResult<Option<String>> {
         let result = match self.validate_current_answer()? {
+            Validation::Valid if !self.background_validators.is_empty() => {
+                self.error = None;
+                let answer = self.get_current_answer().to_owned();
+                self.pending_validation = Some(PendingValidation::start(
+                    &self.background_validators,
+                    &answer,
+                    answer.clone(),
+                ));
+                None
+            }
             Validation::Valid => Some(self.get_current_answer().to_owned()),
             Validation::Invalid(msg) => {
                 self.error = Some(msg);
@@ -195,8 +373,18 @@ where
 
     fn handle(&mut self, action: TextPromptAction) -> InquireResult<ActionResult> {
         let result = match action {
+            TextPromptAction::ValueInput(InputAction::MoveCursor(
+                Magnitude::Char | Magnitude::Line,
+                LineDirection::Right,
+            )) if self.ghost_text().is_some() => self.accept_ghost_text()?,
+            TextPromptAction::ValueInput(InputAction::Write(c)) if matches!(self.input_filter, Some(filter) if !filter(c)) => {
+                ActionResult::Clean
+            }
             TextPromptAction::ValueInput(input_action) => {
-                let result = self.input.handle(input_action);
+                let result = match (&self.input_mask, input_action) {
+                    (Some(mask), InputAction::Write(c)) => mask.write(&mut self.input, c),
+                    _ => self.input.handle(input_action),
+                };
 
                 if let InputActionResult::ContentChanged = result {
                     self.update_suggestions()?;
@@ -211,6 +399,8 @@ where
                 self.move_cursor_down(self.config.page_size)
             }
             TextPromptAction::UseCurrentSuggestion => self.use_current_suggestion()?,
+            TextPromptAction::CycleToNextSuggestion => self.cycle_suggestions(true)?,
+            TextPromptAction::CycleToPreviousSuggestion => self.cycle_suggestions(false)?,
         };
 
         Ok(result)
@@ -223,27 +413,31 @@ where
             backend.render_error_message(err)?;
         }
 
-        backend.render_prompt(prompt, self.default, &self.input)?;
+        backend.render_prompt(prompt, self.default, &self.input, self.ghost_text())?;
 
         let choices = self
             .suggested_options
             .iter()
             .enumerate()
-            .map(|(i, val)| ListOption::new(i, val.as_ref()))
-            .collect::<Vec<ListOption<&str>>>();
+            .map(|(i, val)| ListOption::new(i, val))
+            .collect::<Vec<ListOption<&Suggestion>>>();
 
         let page = paginate(
-            self.config.page_size,
+            backend.fit_page_size(self.config.page_size),
             &choices,
             self.suggestion_cursor_index,
         );
 
         backend.render_suggestions(page)?;
 
-        if let Some(message) = self.help_message {
-            backend.render_help_message(message)?;
+        if let Some(pending) = &self.pending_validation {
+            backend.render_help_message(&pending.status_message())?;
+        } else if self.help_message.is_some() || self.help_message_fn.is_some() {
+            render_help_message(backend, self.help_message, self.help_message_fn, || {
+                self.state()
+            })?;
         } else if !choices.is_empty() {
-            backend.render_help_message(DEFAULT_HELP_MESSAGE_WITH_AC)?;
+            backend.render_help_message(translate(DEFAULT_HELP_MESSAGE_WITH_AC))?;
         }
 
         Ok(())
@@ -1,10 +1,14 @@
+use std::time::Duration;
+
 use super::Text;
 use crate::{
-    terminal::crossterm::CrosstermTerminal,
-    ui::{Backend, RenderConfig},
-    validator::{ErrorMessage, Validation},
+    autocompletion::{Replacement, Suggestion},
+    terminal::{crossterm::CrosstermTerminal, TestBackend},
+    ui::{Backend, FinalRender, Key, RenderConfig, TextDirection},
+    validator::{CancellationToken, ErrorMessage, Validation},
+    Autocomplete, CustomUserError, InquireError, InterruptBehavior, PromptHandle,
 };
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 fn default<'a>() -> Text<'a> {
     Text::new("Question?")
@@ -114,3 +118,767 @@ text_test!(
         _ => Ok(Validation::Invalid(ErrorMessage::Default)),
     })
 );
+
+text_test!(
+    cjk_input_with_placeholder,
+    text_to_events!("山田太郎\n"),
+    "山田太郎",
+    Text::new("名前:").with_placeholder("名前を入力")
+);
+
+text_test!(
+    cjk_placeholder_is_not_submitted,
+    vec![KeyCode::Enter],
+    "",
+    Text::new("名前:").with_placeholder("名前を入力")
+);
+
+text_test!(
+    cjk_input_edited_in_the_middle,
+    {
+        let mut events = vec![];
+        events.append(&mut text_to_events!("東京都").collect());
+        events.push(KeyCode::Left);
+        events.push(KeyCode::Backspace);
+        events.append(&mut text_to_events!("大阪").collect());
+        events.push(KeyCode::Enter);
+        events
+    },
+    "東大阪都",
+    Text::new("住所:").with_placeholder("東京都")
+);
+
+text_test!(
+    emoji_sequences_are_deleted_as_a_whole,
+    {
+        let mut events = vec![];
+        events.append(&mut text_to_events!("家族👨‍👩‍👧").collect());
+        events.push(KeyCode::Backspace);
+        events.append(&mut text_to_events!("❤️").collect());
+        events.push(KeyCode::Enter);
+        events
+    },
+    "家族❤️",
+    Text::new("名前:").with_placeholder("👤")
+);
+
+text_test!(
+    timeout_submits_default,
+    Vec::<KeyCode>::new(),
+    "default",
+    Text::new("")
+        .with_default("default")
+        .with_timeout(Duration::ZERO)
+);
+
+text_test!(
+    key_press_stops_timeout,
+    text_to_events!("typed\n"),
+    "typed",
+    Text::new("")
+        .with_default("default")
+        .with_timeout(Duration::from_secs(60))
+        .with_timeout_countdown(true)
+);
+
+#[test]
+fn timeout_without_default_fails() {
+    let read: Vec<KeyEvent> = vec![];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Text::new("")
+        .with_timeout(Duration::from_millis(10))
+        .with_timeout_countdown(true)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::Timeout)));
+}
+
+#[test]
+fn ctrl_c_behavior_is_configurable() {
+    let cases = [
+        (InterruptBehavior::ReturnError, "interrupted"),
+        (InterruptBehavior::Cancel, "canceled"),
+    ];
+
+    for (behavior, expected) in cases {
+        let read = [KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = Text::new("")
+            .with_interrupt_behavior(behavior)
+            .prompt_with_backend(&mut backend);
+
+        let outcome = match ans {
+            Err(InquireError::OperationInterrupted) => "interrupted",
+            Err(InquireError::OperationCanceled) => "canceled",
+            _ => "other",
+        };
+        assert_eq!(expected, outcome);
+    }
+}
+
+#[test]
+fn background_validation_rejects_answer() {
+    let read: Vec<KeyEvent> = text_to_events!("taken")
+        .chain(std::iter::once(KeyCode::Enter))
+        .chain(text_to_events!("2"))
+        .chain(std::iter::once(KeyCode::Enter))
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Text::new("")
+        .with_background_validator(|input: &str, _: &CancellationToken| match input {
+            "taken" => Ok(Validation::Invalid("Already taken".into())),
+            _ => Ok(Validation::Valid),
+        })
+        .prompt_with_backend(&mut backend);
+
+    assert_eq!("taken2", ans.unwrap());
+}
+
+#[test]
+fn esc_cancels_background_validation() {
+    let read: Vec<KeyEvent> = text_to_events!("slow")
+        .chain([KeyCode::Enter, KeyCode::Esc])
+        .chain(text_to_events!("er"))
+        .chain(std::iter::once(KeyCode::Enter))
+        .map(KeyEvent::from)
+        .collect();
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let ans = Text::new("")
+        .with_background_validator(|input: &str, cancellation: &CancellationToken| {
+            while input == "slow" && !cancellation.is_canceled() {
+                std::thread::sleep(Duration::from_millis(1));
+            }
+            Ok(Validation::Valid)
+        })
+        .prompt_with_backend(&mut backend);
+
+    assert_eq!("slower", ans.unwrap());
+}
+
+#[test]
+#[cfg(all(unix, feature = "suspend"))]
+fn ctrl_z_suspends_and_renders_the_prompt_again() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static SUSPENSIONS: AtomicUsize = AtomicUsize::new(0);
+
+    extern "C" fn count_suspension(_: libc::c_int) {
+        SUSPENSIONS.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Restores the previous SIGTSTP handler once the test ends, even if it fails.
+    struct RestoreHandler(libc::sighandler_t);
+
+    impl Drop for RestoreHandler {
+        fn drop(&mut self) {
+            // SAFETY: the handler was installed before by `signal`.
+            unsafe {
+                libc::signal(libc::SIGTSTP, self.0);
+            }
+        }
+    }
+
+    // without a shell to continue it, a stopped test process would hang, so
+    // count the signals instead of stopping
+    // SAFETY: the handler only touches an atomic, which is async-signal-safe.
+    let _restore = RestoreHandler(unsafe {
+        libc::signal(
+            libc::SIGTSTP,
+            count_suspension as extern "C" fn(libc::c_int) as libc::sighandler_t,
+        )
+    });
+
+    let mut terminal = TestBackend::new(vec![]).with_text("ab");
+    terminal.push_key(Key::Char('z', crate::ui::KeyModifiers::CONTROL));
+    terminal.push_text("c\n");
+
+    let ans = Text::new("Name:")
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("abc", ans);
+    assert_eq!(1, SUSPENSIONS.load(Ordering::SeqCst));
+    let frames = terminal.frames();
+    assert_eq!("? Name: ab \n", frames[2]);
+    assert_eq!(frames[2], frames[3]);
+    assert_eq!("? Name: abc \n", frames[4]);
+}
+
+#[test]
+fn arrow_keys_are_swapped_for_right_to_left_input() {
+    let mut events: Vec<KeyCode> = text_to_events!("שלום").collect();
+    // moves towards the start of the input, displayed on the right
+    events.push(KeyCode::Right);
+    events.push(KeyCode::Backspace);
+    events.push(KeyCode::Enter);
+
+    let read: Vec<KeyEvent> = events.into_iter().map(KeyEvent::from).collect();
+    let mut read = read.iter();
+    let mut write: Vec<u8> = Vec::new();
+
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let render_config = RenderConfig::default().with_text_direction(TextDirection::RightToLeft);
+    let mut backend = Backend::new(terminal, render_config).unwrap();
+
+    let ans = Text::new("שם:").prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!("שלם", ans);
+}
+
+text_test!(
+    initial_value_is_edited_from_the_end,
+    vec![KeyCode::Backspace, KeyCode::Char('p'), KeyCode::Enter],
+    "hellp",
+    Text::new("Question?").with_initial_value("hello")
+);
+
+text_test!(
+    initial_value_is_submitted_instead_of_default,
+    vec![KeyCode::Enter],
+    "current",
+    Text::new("Question?")
+        .with_initial_value("current")
+        .with_default("default")
+);
+
+text_test!(
+    cleared_initial_value_falls_back_to_default,
+    [vec![KeyCode::Backspace; 7], vec![KeyCode::Enter]].concat(),
+    "default",
+    Text::new("Question?")
+        .with_initial_value("current")
+        .with_default("default")
+);
+
+#[derive(Clone)]
+struct CountryCompleter;
+
+impl Autocomplete for CountryCompleter {
+    fn get_suggestions(&mut self, _: &str) -> Result<Vec<Suggestion>, CustomUserError> {
+        Ok(vec![
+            Suggestion::new("Brazil")
+                .with_description("South America")
+                .with_replacement("BR"),
+            Suggestion::new("Portugal"),
+        ])
+    }
+
+    fn get_completion(
+        &mut self,
+        _: &str,
+        highlighted_suggestion: Option<String>,
+    ) -> Result<Replacement, CustomUserError> {
+        Ok(highlighted_suggestion)
+    }
+}
+
+#[test]
+fn suggestions_render_descriptions_and_complete_replacements() {
+    let mut terminal = TestBackend::new(vec![
+        Key::Down(crate::ui::KeyModifiers::NONE),
+        Key::Tab,
+        Key::Enter,
+    ]);
+
+    let ans = Text::new("Country:")
+        .with_autocomplete(CountryCompleter)
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("BR", ans);
+    assert!(terminal.frames()[1].starts_with("? Country:  \n> Brazil  South America\n  Portugal\n"));
+    assert!(terminal.frames()[2].starts_with("? Country: BR \n"));
+}
+
+#[test]
+fn ghost_text_is_accepted_with_the_right_arrow() {
+    let suggester = |input: &str| -> Result<Vec<String>, CustomUserError> {
+        Ok(["Brazil", "Bulgaria"]
+            .iter()
+            .filter(|country| country.starts_with(input))
+            .map(|country| country.to_string())
+            .collect())
+    };
+    let mut terminal = TestBackend::new(vec![]).with_text("Br");
+    terminal.push_key(Key::Right(crate::ui::KeyModifiers::NONE));
+    terminal.push_key(Key::Enter);
+
+    let ans = Text::new("Country:")
+        .with_autocomplete(suggester)
+        .with_ghost_text(true)
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("Brazil", ans);
+    assert!(terminal.frames()[2].starts_with("? Country: Brazil\n  Brazil\n"));
+    assert!(terminal.frames()[3].starts_with("? Country: Brazil \n"));
+}
+
+#[test]
+fn tab_cycles_through_suggestions_in_place() {
+    let suggester = |input: &str| -> Result<Vec<String>, CustomUserError> {
+        Ok(["banana", "blueberry", "cherry"]
+            .iter()
+            .filter(|fruit| fruit.starts_with(input))
+            .map(|fruit| fruit.to_string())
+            .collect())
+    };
+    let mut terminal = TestBackend::new(vec![]).with_text("b");
+    terminal.push_key(Key::Tab);
+    terminal.push_key(Key::Tab);
+    terminal.push_key(Key::Tab);
+    terminal.push_key(Key::BackTab);
+    terminal.push_key(Key::Enter);
+
+    let ans = Text::new("Fruit:")
+        .with_autocomplete(suggester)
+        .with_tab_cycles(true)
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("blueberry", ans);
+    assert!(terminal.frames()[2].starts_with("? Fruit: banana \n> banana\n  blueberry\n"));
+    assert!(terminal.frames()[4].starts_with("? Fruit: banana \n> banana\n  blueberry\n"));
+    assert!(terminal.frames()[5].starts_with("? Fruit: blueberry \n  banana\n> blueberry\n"));
+}
+
+#[test]
+fn undo_and_redo_revert_edits() {
+    let mut terminal = TestBackend::new(vec![]).with_text("hello world");
+    terminal.push_key(Key::Char('w', KeyModifiers::CONTROL.into()));
+    terminal.push_key(Key::Char('_', KeyModifiers::CONTROL.into()));
+    terminal.push_key(Key::Char('_', KeyModifiers::CONTROL.into()));
+    terminal.push_key(Key::Char('y', KeyModifiers::CONTROL.into()));
+    terminal.push_key(Key::Enter);
+
+    let ans = Text::new("Greeting:")
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("hello world", ans);
+    assert_eq!(
+        Some("? Greeting: hello  \n"),
+        terminal.frames().get(12).map(String::as_str)
+    );
+}
+
+#[test]
+fn input_filter_rejects_characters_as_they_are_typed() {
+    let mut terminal = TestBackend::new(vec![]).with_text("4a2b\n");
+
+    let ans = Text::new("Age:")
+        .with_input_filter(|c| c.is_ascii_digit())
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("42", ans);
+}
+
+#[test]
+fn input_mask_inserts_separators_and_requires_a_complete_input() {
+    let mut terminal = TestBackend::new(vec![]).with_text("555x123456\n");
+    terminal.push_text("7\n");
+
+    let ans = Text::new("Phone:")
+        .with_input_mask("(###) ###-####")
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("(555) 123-4567", ans);
+    assert!(terminal
+        .frames()
+        .iter()
+        .any(|frame| frame.starts_with("# The input is incomplete\n")));
+}
+
+#[test]
+fn length_counter_is_rendered_after_the_input() {
+    let mut terminal = TestBackend::new(vec![]).with_text("ab");
+    terminal.push_key(Key::Enter);
+
+    let ans = Text::new("Name:")
+        .with_initial_value("abc")
+        .with_max_length(4)
+        .with_length_counter(true)
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("abca", ans);
+    assert_eq!("? Name: abc  3/4\n", terminal.frames()[0]);
+    assert_eq!("? Name: abca  4/4\n", terminal.frames()[1]);
+}
+
+#[test]
+fn accepted_suggestions_are_truncated_to_the_max_length() {
+    let mut terminal = TestBackend::new(vec![
+        Key::Down(crate::ui::KeyModifiers::NONE),
+        Key::Down(crate::ui::KeyModifiers::NONE),
+        Key::Tab,
+        Key::Enter,
+    ]);
+
+    let ans = Text::new("Country:")
+        .with_autocomplete(CountryCompleter)
+        .with_max_length(4)
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("Port", ans);
+
+    let mut terminal = TestBackend::new(vec![
+        Key::Down(crate::ui::KeyModifiers::NONE),
+        Key::Down(crate::ui::KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Text::new("Country:")
+        .with_autocomplete(CountryCompleter)
+        .with_max_length(4)
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("Port", ans);
+}
+
+#[test]
+fn report_counts_keystrokes_and_rejected_submissions() {
+    let mut terminal = TestBackend::new(vec![]).with_text("a\nbc\n");
+
+    let (ans, report) = Text::new("Name:")
+        .with_validator(|input: &str| match input.len() {
+            1 => Ok(Validation::Invalid("Too short".into())),
+            _ => Ok(Validation::Valid),
+        })
+        .prompt_with_report_and_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("abc", ans);
+    assert_eq!(5, report.keystrokes);
+    assert_eq!(1, report.validation_failures);
+    assert!(!report.default_accepted);
+}
+
+#[test]
+fn report_tells_whether_the_default_was_accepted() {
+    let mut terminal = TestBackend::new(vec![]).with_text("\n");
+    let (_, report) = Text::new("Name:")
+        .with_default("Ferris")
+        .prompt_with_report_and_terminal(&mut terminal)
+        .unwrap();
+
+    assert!(report.default_accepted);
+
+    let mut terminal = TestBackend::new(vec![]).with_text("Jane\n");
+    let (_, report) = Text::new("Name:")
+        .with_default("Ferris")
+        .prompt_with_report_and_terminal(&mut terminal)
+        .unwrap();
+
+    assert!(!report.default_accepted);
+}
+
+#[test]
+fn event_listener_is_notified_of_interactions() {
+    let events = std::cell::RefCell::new(vec![]);
+    let listener = |event: &crate::events::PromptEvent<'_>| {
+        events.borrow_mut().push(event.kind.name());
+    };
+
+    let mut terminal = TestBackend::new(vec![]).with_text("a\n");
+    terminal.push_key(Key::Escape);
+
+    let ans = Text::new("Name:")
+        .with_validator(|input: &str| match input.len() {
+            1 => Ok(Validation::Invalid("Too short".into())),
+            _ => Ok(Validation::Valid),
+        })
+        .with_event_listener(&listener)
+        .prompt_with_terminal(&mut terminal);
+
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+    assert_eq!(
+        vec![
+            "rendered",
+            "key_pressed",
+            "rendered",
+            "key_pressed",
+            "validation_failed",
+            "key_pressed",
+            "canceled",
+        ],
+        events.into_inner()
+    );
+}
+
+#[test]
+fn final_render_can_be_compact_or_suppressed() {
+    let mut terminal = TestBackend::new(vec![]).with_text("Ferris\n");
+    Text::new("Name:")
+        .with_render_config(RenderConfig::empty())
+        .with_final_render(FinalRender::Compact)
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("Ferris\n", terminal.last_frame().unwrap());
+
+    let mut terminal = TestBackend::new(vec![]).with_text("Ferris\n");
+    Text::new("Name:")
+        .with_render_config(RenderConfig::empty())
+        .with_final_render(FinalRender::None)
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    // the empty final frame is not recorded
+    assert_eq!("? Name: Ferris \n", terminal.last_frame().unwrap());
+}
+
+#[test]
+fn answer_is_redacted_in_the_final_render() {
+    use crate::ui::Redaction;
+
+    let render = |redaction, final_render| {
+        let mut terminal = TestBackend::new(vec![]).with_text("s3cr3t-42\n");
+        let ans = Text::new("Token:")
+            .with_render_config(RenderConfig::empty())
+            .with_final_render(final_render)
+            .with_answer_redaction(redaction)
+            .prompt_with_terminal(&mut terminal)
+            .unwrap();
+
+        assert_eq!("s3cr3t-42", ans);
+        terminal.last_frame().unwrap()
+    };
+
+    assert_eq!(
+        "? Token: s3cr3t-42\n",
+        render(Redaction::None, FinalRender::Full)
+    );
+    assert_eq!("? Token: \n", render(Redaction::Hidden, FinalRender::Full));
+    assert_eq!(
+        "********\n",
+        render(Redaction::Masked, FinalRender::Compact)
+    );
+    assert_eq!(
+        "? Token: …-42\n",
+        render(
+            Redaction::Custom(|answer| format!("…{}", &answer[answer.len() - 3..])),
+            FinalRender::Full
+        )
+    );
+}
+
+#[test]
+fn canceled_final_render_is_configurable() {
+    let render = |final_render| {
+        let mut terminal = TestBackend::new(vec![]).with_text("Fe");
+        terminal.push_key(Key::Escape);
+        let ans = Text::new("Name:")
+            .with_render_config(RenderConfig::empty().with_canceled_final_render(final_render))
+            .prompt_with_terminal(&mut terminal);
+
+        assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+        terminal.last_frame().unwrap()
+    };
+
+    assert_eq!("? Name: <canceled>\n", render(FinalRender::Full));
+    assert_eq!("<canceled>\n", render(FinalRender::Compact));
+    assert_eq!("? Name: Fe \n", render(FinalRender::None));
+    assert_eq!(
+        "Skipped Name:\n",
+        render(FinalRender::Custom(|prompt, _| format!("Skipped {prompt}")))
+    );
+}
+
+#[test]
+fn refresh_interval_renders_without_key_presses() {
+    let read: Vec<KeyEvent> = vec![];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let renders = std::cell::Cell::new(0);
+    let listener = |event: &crate::events::PromptEvent<'_>| {
+        if event.kind == crate::events::PromptEventKind::Rendered {
+            renders.set(renders.get() + 1);
+        }
+    };
+
+    let ans = Text::new("")
+        .with_default("default")
+        .with_timeout(Duration::from_millis(50))
+        .with_refresh_interval(Duration::from_millis(5))
+        .with_event_listener(&listener)
+        .prompt_with_backend(&mut backend);
+
+    assert_eq!("default", ans.unwrap());
+    assert!(renders.get() > 1);
+}
+
+#[test]
+fn aborted_handle_cancels_the_prompt() {
+    let handle = PromptHandle::new();
+    handle.abort();
+
+    let mut terminal = TestBackend::new(vec![]).with_text("Ferris\n");
+    let ans = Text::new("Name:")
+        .with_handle(&handle)
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal);
+
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+    assert_eq!(vec!["? Name: <canceled>\n"], terminal.frames());
+}
+
+#[test]
+fn handle_aborts_the_prompt_from_another_thread() {
+    let read: Vec<KeyEvent> = vec![];
+    let mut read = read.iter();
+
+    let mut write: Vec<u8> = Vec::new();
+
+    let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+    let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+    let handle = PromptHandle::new();
+    let shutdown = handle.clone();
+    let abort = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(10));
+        shutdown.abort();
+    });
+
+    let ans = Text::new("Name:")
+        .with_handle(&handle)
+        .prompt_with_backend(&mut backend);
+    abort.join().unwrap();
+
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+}
+
+#[test]
+fn markup_of_messages_is_rendered_as_styles() {
+    let mut terminal = TestBackend::new(vec![]).with_text("v2\n");
+
+    let ans = Text::new("Deploy **staging** with `make`?")
+        .with_help_message("_tags like v1_2 are allowed_")
+        .with_render_config(
+            RenderConfig::empty()
+                .with_markup(true)
+                .with_markup_code(crate::ui::StyleSheet::new().with_fg(crate::ui::Color::DarkRed)),
+        )
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!("v2", ans);
+    assert_eq!(
+        "? Deploy <bold>staging</> with <fg=DarkRed>make</>?  \n[<dim>tags like v1_2 are allowed</>]\n",
+        terminal.styled_frames()[0].annotated()
+    );
+    assert_eq!(
+        "? Deploy staging with make? v2\n",
+        terminal.last_frame().unwrap()
+    );
+}
+
+#[test]
+fn colors_are_downgraded_to_the_supported_ones() {
+    use crate::ui::{Color, ColorSupport, StyleSheet};
+
+    let render = |support| {
+        let mut terminal = TestBackend::new(vec![]).with_text("\n");
+        Text::new("Name:")
+            .with_render_config(
+                RenderConfig::empty()
+                    .with_color_support(support)
+                    .with_help_message(StyleSheet::new().with_fg(Color::rgb(255, 135, 0))),
+            )
+            .with_help_message("required")
+            .prompt_with_terminal(&mut terminal)
+            .unwrap();
+        terminal.styled_frames()[0].annotated()
+    };
+
+    assert_eq!(
+        "? Name:  \n<fg=Rgb { r: 255, g: 135, b: 0 }>[required]</>\n",
+        render(ColorSupport::TrueColor)
+    );
+    assert_eq!(
+        "? Name:  \n<fg=AnsiValue(208)>[required]</>\n",
+        render(ColorSupport::Ansi256)
+    );
+    assert_eq!(
+        "? Name:  \n<fg=DarkYellow>[required]</>\n",
+        render(ColorSupport::Basic)
+    );
+}
+
+#[test]
+fn prompt_prefix_follows_the_state_of_the_prompt() {
+    use crate::ui::Styled;
+
+    let config = RenderConfig::empty()
+        .with_answered_prompt_prefix(Styled::new("✔"))
+        .with_error_prompt_prefix(Styled::new("!"))
+        .with_canceled_prompt_prefix(Styled::new("✖"));
+    let validator = |input: &str| match input.is_empty() {
+        true => Ok(Validation::Invalid("Required".into())),
+        false => Ok(Validation::Valid),
+    };
+
+    let mut terminal = TestBackend::new(vec![]).with_text("\na\n");
+    Text::new("Name:")
+        .with_validator(validator)
+        .with_render_config(config)
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    let frames = terminal.frames();
+    assert_eq!("? Name:  \n", frames[0]);
+    assert_eq!("# Required\n! Name: a \n", frames[1]);
+    assert_eq!("✔ Name: a\n", frames[2]);
+
+    let mut terminal = TestBackend::new(vec![Key::Escape]);
+    let ans = Text::new("Name:")
+        .with_render_config(config)
+        .prompt_with_terminal(&mut terminal);
+
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+    assert_eq!("✖ Name: <canceled>\n", terminal.last_frame().unwrap());
+}
@@ -0,0 +1,58 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction,
+};
+
+use super::config::TimeSelectConfig;
+
+/// Set of actions for a TimeSelectPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TimeSelectPromptAction {
+    /// Move the cursor to the field on the left.
+    MoveToPrevField,
+    /// Move the cursor to the field on the right.
+    MoveToNextField,
+    /// Increment the value of the current field.
+    Increment,
+    /// Decrement the value of the current field.
+    Decrement,
+    /// Type a digit of the value of the current field.
+    TypeDigit(u32),
+    /// Set the time to before noon, in the 12-hour clock.
+    SetAm,
+    /// Set the time to after noon, in the 12-hour clock.
+    SetPm,
+}
+
+impl InnerAction<TimeSelectConfig> for TimeSelectPromptAction {
+    fn from_key(key: Key, config: &TimeSelectConfig) -> Option<Self> {
+        if config.vim_mode {
+            let action = match key {
+                Key::Char('k', KeyModifiers::NONE) => Some(Self::Increment),
+                Key::Char('j', KeyModifiers::NONE) => Some(Self::Decrement),
+                Key::Char('h', KeyModifiers::NONE) => Some(Self::MoveToPrevField),
+                Key::Char('l', KeyModifiers::NONE) => Some(Self::MoveToNextField),
+                _ => None,
+            };
+
+            if action.is_some() {
+                return action;
+            }
+        }
+
+        let action = match key {
+            Key::Left(KeyModifiers::NONE) | Key::BackTab => Self::MoveToPrevField,
+            Key::Right(KeyModifiers::NONE) | Key::Tab | Key::Char(':', KeyModifiers::NONE) => {
+                Self::MoveToNextField
+            }
+            Key::Up(KeyModifiers::NONE) => Self::Increment,
+            Key::Down(KeyModifiers::NONE) => Self::Decrement,
+            Key::Char('a' | 'A', _) if config.twelve_hour => Self::SetAm,
+            Key::Char('p' | 'P', _) if config.twelve_hour => Self::SetPm,
+            Key::Char(c, KeyModifiers::NONE) => Self::TypeDigit(c.to_digit(10)?),
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
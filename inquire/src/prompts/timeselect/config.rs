@@ -0,0 +1,37 @@
+use std::time::Duration;
+
+use crate::{config::InterruptBehavior, prompts::prompt::Timeout, TimeSelect};
+
+/// Configuration settings used in the execution of a TimeSelectPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct TimeSelectConfig {
+    /// Whether to use vim-style keybindings.
+    pub vim_mode: bool,
+
+    /// Whether the seconds can be selected.
+    pub seconds: bool,
+
+    /// Whether hours are displayed in the 12-hour clock.
+    pub twelve_hour: bool,
+
+    /// What happens when the user presses Ctrl+C.
+    pub interrupt_behavior: InterruptBehavior,
+
+    /// Timeout applied until the first key press.
+    pub timeout: Option<Timeout>,
+    /// Interval at which the prompt is re-rendered without key presses.
+    pub refresh_interval: Option<Duration>,
+}
+
+impl From<&TimeSelect<'_>> for TimeSelectConfig {
+    fn from(value: &TimeSelect<'_>) -> Self {
+        Self {
+            vim_mode: value.vim_mode,
+            seconds: value.seconds,
+            twelve_hour: value.twelve_hour,
+            interrupt_behavior: value.interrupt_behavior,
+            timeout: Timeout::new(value.timeout, value.timeout_countdown),
+            refresh_interval: value.refresh_interval,
+        }
+    }
+}
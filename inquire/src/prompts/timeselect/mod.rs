@@ -0,0 +1,394 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+
+use std::time::Duration;
+
+use chrono::{NaiveTime, Timelike};
+
+use crate::{
+    config::{
+        get_configuration, get_event_listener, get_global_options, get_interrupt_behavior,
+        InterruptBehavior,
+    },
+    error::{InquireError, InquireResult},
+    formatter::TimeFormatter,
+    handle::PromptHandle,
+    prompts::prompt::Prompt,
+    report::PromptReport,
+    terminal::{get_default_terminal, Terminal},
+    type_aliases::{EventListener, HelpMessageFn},
+    ui::{date::TimeSelectBackend, Backend, Block, FinalRender, RenderConfig},
+};
+
+use self::prompt::TimeSelectPrompt;
+
+/// Prompt that allows user to select a time of the day, the clock half of
+/// [`DateSelect`](crate::DateSelect). Available via the `date` feature.
+///
+/// The time is rendered as `HH:MM`, along with the seconds and the `AM`/`PM`
+/// marker when enabled. The user moves between these fields with the left and
+/// right arrows, or with tab, and adjusts the highlighted one with the up and
+/// down arrows. Digits can also be typed directly, moving on to the next field
+/// once it is complete, and `a` or `p` switch between `AM` and `PM` in the
+/// 12-hour clock.
+///
+/// Finally, the user selects the time by pressing the enter key.
+///
+/// `TimeSelect` prompts provide several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Default value**: Time displayed when the prompt is first rendered. Current time, without seconds, by default.
+/// - **Seconds**: Whether the seconds can be selected as well, disabled by default.
+/// - **12-hour clock**: Whether hours are displayed from 1 to 12 along with `AM`/`PM` instead of from 0 to 23, disabled by default.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Formats the time as it is displayed in the prompt by default, e.g. "09:30" or "09:30 AM".
+///
+/// # Example
+///
+/// ```no_run
+/// use chrono::NaiveTime;
+/// use inquire::TimeSelect;
+///
+/// let time = TimeSelect::new("Meeting time:")
+///     .with_default(NaiveTime::from_hms_opt(9, 30, 0).unwrap())
+///     .with_twelve_hour_clock(true)
+///     .prompt()?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Clone)]
+pub struct TimeSelect<'a> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Time displayed when the prompt is first rendered.
+    pub starting_time: NaiveTime,
+
+    /// Whether the seconds can be selected. When disabled, the seconds of the
+    /// answer are always zero.
+    pub seconds: bool,
+
+    /// Whether hours are displayed in the 12-hour clock, along with `AM`/`PM`.
+    pub twelve_hour: bool,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function computing the help message from the current state of the
+    /// prompt, replacing [`help_message`](Self::help_message) when set.
+    ///
+    /// See [`with_help_message_fn`](Self::with_help_message_fn) for more details.
+    pub help_message_fn: Option<HelpMessageFn<'a>>,
+
+    /// Content rendered above the prompt.
+    pub header: Option<Block>,
+
+    /// Content rendered below the prompt.
+    pub footer: Option<Block>,
+
+    /// Whether vim mode is enabled. When enabled, the user can
+    /// move between the fields using h and l, and adjust them using k and j.
+    pub vim_mode: bool,
+
+    /// Function that formats the answer and presents it to the user as the
+    /// final rendering of the prompt, the time as displayed when not set.
+    pub formatter: Option<TimeFormatter<'a>>,
+
+    /// What happens when the user presses Ctrl+C.
+    pub interrupt_behavior: InterruptBehavior,
+
+    /// Listener notified of the events of the prompt, such as key presses and
+    /// submissions.
+    ///
+    /// See [`with_event_listener`](Self::with_event_listener) for more details.
+    pub event_listener: Option<EventListener<'a>>,
+
+    /// Handle aborting the prompt from outside of it, if any.
+    ///
+    /// See [`with_handle`](Self::with_handle) for more details.
+    pub handle: Option<&'a PromptHandle>,
+
+    /// Time to wait for the user to start interacting with the prompt.
+    ///
+    /// See [`with_timeout`](Self::with_timeout) for more details.
+    pub timeout: Option<Duration>,
+
+    /// Whether to render the time left before the timeout expires.
+    pub timeout_countdown: bool,
+
+    /// Interval at which the prompt is re-rendered while no key is pressed.
+    ///
+    /// See [`with_refresh_interval`](Self::with_refresh_interval) for more details.
+    pub refresh_interval: Option<Duration>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still suport NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a> TimeSelect<'a> {
+    /// Default value of vim mode. It is true because there is no typing
+    /// functionality to be lost here.
+    pub const DEFAULT_VIM_MODE: bool = true;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("←→ to move, ↑↓ to change, type to set, enter to select");
+
+    /// Creates a [TimeSelect] with the provided message, along with default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        let global = get_global_options();
+        let now = chrono::Local::now().time();
+
+        Self {
+            message,
+            starting_time: now.with_second(0).unwrap_or(now),
+            seconds: false,
+            twelve_hour: false,
+            help_message: global.help_message(Self::DEFAULT_HELP_MESSAGE),
+            help_message_fn: None,
+            header: None,
+            footer: None,
+            vim_mode: Self::DEFAULT_VIM_MODE,
+            formatter: None,
+            interrupt_behavior: get_interrupt_behavior(),
+            event_listener: get_event_listener(),
+            handle: None,
+            timeout: None,
+            timeout_countdown: false,
+            refresh_interval: None,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Sets a function computing the help message from the current state of the
+    /// prompt, called whenever it is rendered. It takes precedence over the
+    /// static help message, and returning `None` hides the help line.
+    ///
+    /// See [`PromptState`](crate::PromptState) for the state given to the function.
+    pub fn with_help_message_fn(mut self, help_message_fn: HelpMessageFn<'a>) -> Self {
+        self.help_message_fn = Some(help_message_fn);
+        self
+    }
+
+    /// Sets the content rendered above the prompt, such as the current git
+    /// branch. It is redrawn and cleared along with the prompt, see [`Block`].
+    pub fn with_header<H: Into<Block>>(mut self, header: H) -> Self {
+        self.header = Some(header.into());
+        self
+    }
+
+    /// Sets the content rendered below the prompt. It is redrawn and cleared
+    /// along with the prompt, see [`Block`].
+    pub fn with_footer<F: Into<Block>>(mut self, footer: F) -> Self {
+        self.footer = Some(footer.into());
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the default time of the prompt. Equivalent to [TimeSelect::with_starting_time](TimeSelect::with_starting_time).
+    pub fn with_default(self, default: NaiveTime) -> Self {
+        self.with_starting_time(default)
+    }
+
+    /// Sets the starting time. Equivalent to [TimeSelect::with_default](TimeSelect::with_default).
+    pub fn with_starting_time(mut self, starting_time: NaiveTime) -> Self {
+        self.starting_time = starting_time;
+        self
+    }
+
+    /// Sets whether the seconds can be selected.
+    pub fn with_seconds(mut self, seconds: bool) -> Self {
+        self.seconds = seconds;
+        self
+    }
+
+    /// Sets whether hours are displayed in the 12-hour clock, from 1 to 12
+    /// along with `AM`/`PM`, instead of from 0 to 23.
+    pub fn with_twelve_hour_clock(mut self, twelve_hour: bool) -> Self {
+        self.twelve_hour = twelve_hour;
+        self
+    }
+
+    /// Enables or disables vim_mode.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: TimeFormatter<'a>) -> Self {
+        self.formatter = Some(formatter);
+        self
+    }
+
+    /// Sets what happens when the user presses Ctrl+C, overriding the global
+    /// setting.
+    pub fn with_interrupt_behavior(mut self, interrupt_behavior: InterruptBehavior) -> Self {
+        self.interrupt_behavior = interrupt_behavior;
+        self
+    }
+
+    /// Sets a listener notified of the events of the prompt, such as renders,
+    /// key presses and submissions, overriding the global listener.
+    ///
+    /// See the [`events`](crate::events) module for more details.
+    pub fn with_event_listener(mut self, listener: EventListener<'a>) -> Self {
+        self.event_listener = Some(listener);
+        self
+    }
+
+    /// Sets a handle aborting the prompt from outside of it, e.g. from another
+    /// thread or a Ctrl+C handler, canceling it as if the user pressed ESC.
+    ///
+    /// See the [`handle`](crate::handle) module for more details.
+    pub fn with_handle(mut self, handle: &'a PromptHandle) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets a timeout for the user to start interacting with the prompt.
+    ///
+    /// If no key is pressed before it expires, the starting time is submitted.
+    /// Once a key is pressed, the prompt waits for the user indefinitely.
+    ///
+    /// Timeouts are supported by the `crossterm` and `termion` backends, the
+    /// `console` backend always waits for the first key press.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether to render the time left before the timeout expires below the prompt.
+    pub fn with_timeout_countdown(mut self, countdown: bool) -> Self {
+        self.timeout_countdown = countdown;
+        self
+    }
+
+    /// Sets an interval at which the prompt is re-rendered even when no key is
+    /// pressed, e.g. for content computed at render time, such as a dynamic
+    /// help message showing the current time, to stay up to date.
+    ///
+    /// Requires a terminal able to wait for keys with a timeout, such as the
+    /// crossterm or termion ones. Other terminals only re-render the prompt
+    /// after key presses.
+    pub fn with_refresh_interval(mut self, interval: Duration) -> Self {
+        self.refresh_interval = Some(interval);
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still suport NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Sets how the prompt is rendered once answered, e.g. [`FinalRender::None`]
+    /// for the prompt to disappear. This is a shorthand for setting the
+    /// [`final_render`](RenderConfig::final_render) of the render config, so it
+    /// must be called after [`with_render_config`](Self::with_render_config).
+    pub fn with_final_render(mut self, final_render: FinalRender) -> Self {
+        self.render_config.final_render = final_render;
+        self
+    }
+
+    /// Sets whether the prompt is rendered in fullscreen, on the alternate
+    /// screen buffer, leaving only its final rendering in the original screen.
+    /// This is a shorthand for setting the
+    /// [`fullscreen`](RenderConfig::fullscreen) flag of the render config, so
+    /// it must be called after [`with_render_config`](Self::with_render_config).
+    pub fn with_fullscreen(mut self, fullscreen: bool) -> Self {
+        self.render_config.fullscreen = fullscreen;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<NaiveTime>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<NaiveTime> {
+        self.prompt_with_terminal(get_default_terminal()?)
+    }
+
+    /// Prompts the user through the given terminal instead of the default
+    /// terminal of the enabled backend feature, see [`Terminal`].
+    pub fn prompt_with_terminal<Term: Terminal>(self, terminal: Term) -> InquireResult<NaiveTime> {
+        let mut backend = Backend::new(terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    /// Same as [`prompt`](Self::prompt), also returning a [`PromptReport`]
+    /// describing how the user interacted with the prompt.
+    pub fn prompt_with_report(self) -> InquireResult<(NaiveTime, PromptReport)> {
+        self.prompt_with_report_and_terminal(get_default_terminal()?)
+    }
+
+    /// Same as [`prompt_with_terminal`](Self::prompt_with_terminal), also
+    /// returning a [`PromptReport`] describing how the user interacted with
+    /// the prompt.
+    pub fn prompt_with_report_and_terminal<Term: Terminal>(
+        self,
+        terminal: Term,
+    ) -> InquireResult<(NaiveTime, PromptReport)> {
+        let mut backend = Backend::new(terminal, self.render_config)?;
+        TimeSelectPrompt::from(self).prompt_with_report(&mut backend)
+    }
+
+    pub(crate) fn prompt_with_backend<B: TimeSelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<NaiveTime> {
+        TimeSelectPrompt::from(self).prompt(backend)
+    }
+}
+
+impl<'a> From<&'a str> for TimeSelect<'a> {
+    fn from(val: &'a str) -> Self {
+        TimeSelect::new(val)
+    }
+}
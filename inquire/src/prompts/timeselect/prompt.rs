@@ -0,0 +1,310 @@
+use std::time::Duration;
+
+use chrono::{NaiveTime, Timelike};
+
+use crate::{
+    config::InterruptBehavior,
+    error::InquireResult,
+    formatter::TimeFormatter,
+    handle::PromptHandle,
+    prompts::{
+        prompt::{ActionResult, Prompt, Timeout},
+        state::{render_help_message, PromptState},
+    },
+    type_aliases::{EventListener, HelpMessageFn},
+    ui::{date::TimeSelectBackend, Block},
+    TimeSelect,
+};
+
+use super::{action::TimeSelectPromptAction, config::TimeSelectConfig};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Field {
+    Hour,
+    Minute,
+    Second,
+    Meridiem,
+}
+
+pub struct TimeSelectPrompt<'a> {
+    message: &'a str,
+    config: TimeSelectConfig,
+    event_listener: Option<EventListener<'a>>,
+    handle: Option<&'a PromptHandle>,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    fields: Vec<Field>,
+    cursor: usize,
+    typed_digit: Option<u32>,
+    help_message: Option<&'a str>,
+    help_message_fn: Option<HelpMessageFn<'a>>,
+    header: Option<Block>,
+    footer: Option<Block>,
+    formatter: Option<TimeFormatter<'a>>,
+}
+
+impl<'a> From<TimeSelect<'a>> for TimeSelectPrompt<'a> {
+    fn from(ts: TimeSelect<'a>) -> Self {
+        let config: TimeSelectConfig = (&ts).into();
+
+        let mut fields = vec![Field::Hour, Field::Minute];
+        if config.seconds {
+            fields.push(Field::Second);
+        }
+        if config.twelve_hour {
+            fields.push(Field::Meridiem);
+        }
+
+        Self {
+            message: ts.message,
+            config,
+            event_listener: ts.event_listener,
+            handle: ts.handle,
+            hour: ts.starting_time.hour(),
+            minute: ts.starting_time.minute(),
+            second: if config.seconds {
+                ts.starting_time.second()
+            } else {
+                0
+            },
+            fields,
+            cursor: 0,
+            typed_digit: None,
+            help_message: ts.help_message,
+            help_message_fn: ts.help_message_fn,
+            header: ts.header,
+            footer: ts.footer,
+            formatter: ts.formatter,
+        }
+    }
+}
+
+impl<'a> TimeSelectPrompt<'a> {
+    fn cur_answer(&self) -> NaiveTime {
+        NaiveTime::from_hms_opt(self.hour, self.minute, self.second).unwrap_or(NaiveTime::MIN)
+    }
+
+    fn cur_field(&self) -> Field {
+        self.fields[self.cursor]
+    }
+
+    fn move_cursor(&mut self, next: bool) -> ActionResult {
+        self.typed_digit = None;
+
+        let cursor = match next {
+            true => self.cursor + 1,
+            false => self.cursor.wrapping_sub(1),
+        };
+        if cursor >= self.fields.len() {
+            return ActionResult::Clean;
+        }
+
+        self.cursor = cursor;
+        ActionResult::NeedsRedraw
+    }
+
+    /// Largest value the current field can be typed with.
+    fn max_typed_value(&self) -> u32 {
+        match self.cur_field() {
+            Field::Hour if self.config.twelve_hour => 12,
+            Field::Hour => 23,
+            Field::Minute | Field::Second => 59,
+            Field::Meridiem => 0,
+        }
+    }
+
+    fn set_field(&mut self, value: u32) {
+        match self.cur_field() {
+            Field::Hour if self.config.twelve_hour => {
+                self.hour = value % 12 + self.hour / 12 * 12;
+            }
+            Field::Hour => self.hour = value,
+            Field::Minute => self.minute = value,
+            Field::Second => self.second = value,
+            Field::Meridiem => {}
+        }
+    }
+
+    fn shift_field(&mut self, forward: bool) -> ActionResult {
+        self.typed_digit = None;
+
+        let shift = |value: u32, modulo: u32| match forward {
+            true => (value + 1) % modulo,
+            false => (value + modulo - 1) % modulo,
+        };
+
+        match self.cur_field() {
+            // the hour cycles through the twelve hours of the same half of the day
+            Field::Hour if self.config.twelve_hour => {
+                self.hour = shift(self.hour % 12, 12) + self.hour / 12 * 12;
+            }
+            Field::Hour => self.hour = shift(self.hour, 24),
+            Field::Minute => self.minute = shift(self.minute, 60),
+            Field::Second => self.second = shift(self.second, 60),
+            Field::Meridiem => self.hour = (self.hour + 12) % 24,
+        }
+
+        ActionResult::NeedsRedraw
+    }
+
+    /// Types a digit of the current field, moving on to the next field once no
+    /// other digit can follow.
+    fn type_digit(&mut self, digit: u32) -> ActionResult {
+        let max = self.max_typed_value();
+        if max == 0 {
+            return ActionResult::Clean;
+        }
+
+        let (value, complete) = match self.typed_digit {
+            Some(first) if first * 10 + digit <= max => (first * 10 + digit, true),
+            _ => (digit, digit * 10 > max),
+        };
+
+        let twelve_hour_zero =
+            self.cur_field() == Field::Hour && self.config.twelve_hour && value == 0;
+        if !twelve_hour_zero {
+            self.set_field(value);
+        }
+
+        match complete {
+            true => {
+                self.move_cursor(true);
+            }
+            false => self.typed_digit = Some(value),
+        }
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn set_meridiem(&mut self, pm: bool) -> ActionResult {
+        self.typed_digit = None;
+
+        let hour = self.hour % 12 + if pm { 12 } else { 0 };
+        if self.hour == hour {
+            return ActionResult::Clean;
+        }
+
+        self.hour = hour;
+        ActionResult::NeedsRedraw
+    }
+
+    fn rendered_fields(&self) -> Vec<(&'static str, String)> {
+        self.fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                let separator = match (i, field) {
+                    (0, _) => "",
+                    (_, Field::Meridiem) => " ",
+                    _ => ":",
+                };
+                let value = match field {
+                    Field::Hour if self.config.twelve_hour => {
+                        format!("{:02}", (self.hour + 11) % 12 + 1)
+                    }
+                    Field::Hour => format!("{:02}", self.hour),
+                    Field::Minute => format!("{:02}", self.minute),
+                    Field::Second => format!("{:02}", self.second),
+                    Field::Meridiem if self.hour < 12 => String::from("AM"),
+                    Field::Meridiem => String::from("PM"),
+                };
+
+                (separator, value)
+            })
+            .collect()
+    }
+}
+
+impl<'a, B> Prompt<B, TimeSelectConfig, TimeSelectPromptAction, NaiveTime> for TimeSelectPrompt<'a>
+where
+    B: TimeSelectBackend,
+{
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &TimeSelectConfig {
+        &self.config
+    }
+
+    fn interrupt_behavior(&self) -> InterruptBehavior {
+        self.config.interrupt_behavior
+    }
+
+    fn event_listener(&self) -> Option<EventListener<'_>> {
+        self.event_listener
+    }
+
+    fn prompt_handle(&self) -> Option<&PromptHandle> {
+        self.handle
+    }
+
+    fn header(&self) -> Option<&Block> {
+        self.header.as_ref()
+    }
+
+    fn footer(&self) -> Option<&Block> {
+        self.footer.as_ref()
+    }
+
+    fn timeout(&self) -> Option<Timeout> {
+        self.config.timeout
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        self.config.refresh_interval
+    }
+
+    fn submit_on_timeout(&mut self) -> InquireResult<Option<NaiveTime>> {
+        Ok(Some(self.cur_answer()))
+    }
+
+    fn format_answer(&self, answer: &NaiveTime) -> String {
+        if let Some(formatter) = self.formatter {
+            return formatter(*answer);
+        }
+
+        let format = match (self.config.seconds, self.config.twelve_hour) {
+            (false, false) => "%H:%M",
+            (true, false) => "%H:%M:%S",
+            (false, true) => "%I:%M %p",
+            (true, true) => "%I:%M:%S %p",
+        };
+        answer.format(format).to_string()
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<NaiveTime>> {
+        Ok(Some(self.cur_answer()))
+    }
+
+    fn handle(&mut self, action: TimeSelectPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            TimeSelectPromptAction::MoveToPrevField => self.move_cursor(false),
+            TimeSelectPromptAction::MoveToNextField => self.move_cursor(true),
+            TimeSelectPromptAction::Increment => self.shift_field(true),
+            TimeSelectPromptAction::Decrement => self.shift_field(false),
+            TimeSelectPromptAction::TypeDigit(digit) => self.type_digit(digit),
+            TimeSelectPromptAction::SetAm => self.set_meridiem(false),
+            TimeSelectPromptAction::SetPm => self.set_meridiem(true),
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut B) -> InquireResult<()> {
+        backend.render_time_prompt(self.message, &self.rendered_fields(), self.cursor)?;
+
+        render_help_message(backend, self.help_message, self.help_message_fn, || {
+            PromptState {
+                answer: Some(Prompt::<B, _, _, _>::format_answer(
+                    self,
+                    &self.cur_answer(),
+                )),
+                ..PromptState::default()
+            }
+        })?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+use chrono::NaiveTime;
+
+use crate::{
+    terminal::TestBackend,
+    ui::{Key, KeyModifiers, RenderConfig},
+    TimeSelect,
+};
+
+fn time(hour: u32, minute: u32, second: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, minute, second).unwrap()
+}
+
+#[test]
+fn arrows_move_between_and_adjust_the_fields() {
+    let mut terminal = TestBackend::new(vec![
+        Key::Up(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = TimeSelect::new("Time:")
+        .with_default(time(23, 1, 0))
+        .without_help_message()
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(time(0, 59, 0), ans);
+    assert_eq!(
+        vec![
+            "? Time: 23:01\n",
+            "? Time: 00:01\n",
+            "? Time: 00:01\n",
+            "? Time: 00:00\n",
+            "? Time: 00:59\n",
+            "? Time: 00:59\n",
+        ],
+        terminal.frames()
+    );
+}
+
+#[test]
+fn typed_digits_fill_the_fields() {
+    let mut terminal = TestBackend::new(vec![]).with_text("950\n");
+
+    let ans = TimeSelect::new("Time:")
+        .with_default(time(12, 0, 0))
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(time(9, 50, 0), ans);
+}
+
+#[test]
+fn seconds_are_selected_when_enabled() {
+    let mut terminal = TestBackend::new(vec![]).with_text("174512\n");
+
+    let ans = TimeSelect::new("Time:")
+        .with_default(time(12, 0, 30))
+        .with_seconds(true)
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(time(17, 45, 12), ans);
+}
+
+#[test]
+fn twelve_hour_clock_displays_the_meridiem() {
+    let mut terminal = TestBackend::new(vec![]).with_text("p");
+    terminal.push_key(Key::Up(KeyModifiers::NONE));
+    terminal.push_key(Key::Up(KeyModifiers::NONE));
+    terminal.push_key(Key::Up(KeyModifiers::NONE));
+    terminal.push_key(Key::Enter);
+
+    let ans = TimeSelect::new("Time:")
+        .with_default(time(9, 30, 15))
+        .with_twelve_hour_clock(true)
+        .without_help_message()
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(time(12, 30, 0), ans);
+    assert_eq!(
+        vec![
+            "? Time: 09:30 AM\n",
+            "? Time: 09:30 PM\n",
+            "? Time: 10:30 PM\n",
+            "? Time: 11:30 PM\n",
+            "? Time: 12:30 PM\n",
+            "? Time: 12:30 PM\n",
+        ],
+        terminal.frames()
+    );
+}
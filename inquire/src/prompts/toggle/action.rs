@@ -0,0 +1,42 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction,
+};
+
+use super::config::ToggleConfig;
+
+/// Set of actions for a TogglePrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TogglePromptAction {
+    /// Selects the option on the left, the active one.
+    SelectActive,
+    /// Selects the option on the right, the inactive one.
+    SelectInactive,
+    /// Switches to the other option.
+    Switch,
+}
+
+impl InnerAction<ToggleConfig> for TogglePromptAction {
+    fn from_key(key: Key, config: &ToggleConfig) -> Option<Self> {
+        if config.vim_mode {
+            let action = match key {
+                Key::Char('h', KeyModifiers::NONE) => Some(Self::SelectActive),
+                Key::Char('l', KeyModifiers::NONE) => Some(Self::SelectInactive),
+                _ => None,
+            };
+
+            if action.is_some() {
+                return action;
+            }
+        }
+
+        let action = match key {
+            Key::Left(KeyModifiers::NONE) | Key::Home => Self::SelectActive,
+            Key::Right(KeyModifiers::NONE) | Key::End => Self::SelectInactive,
+            Key::Tab | Key::Char(' ', KeyModifiers::NONE) => Self::Switch,
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
@@ -0,0 +1,27 @@
+use std::time::Duration;
+
+use crate::{config::InterruptBehavior, prompts::prompt::Timeout, Toggle};
+
+/// Configuration settings used in the execution of a TogglePrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct ToggleConfig {
+    /// Whether to use vim-style keybindings.
+    pub vim_mode: bool,
+    /// What happens when the user presses Ctrl+C.
+    pub interrupt_behavior: InterruptBehavior,
+    /// Timeout applied until the first key press.
+    pub timeout: Option<Timeout>,
+    /// Interval at which the prompt is re-rendered without key presses.
+    pub refresh_interval: Option<Duration>,
+}
+
+impl From<&Toggle<'_>> for ToggleConfig {
+    fn from(value: &Toggle<'_>) -> Self {
+        Self {
+            vim_mode: value.vim_mode,
+            interrupt_behavior: value.interrupt_behavior,
+            timeout: Timeout::new(value.timeout, value.timeout_countdown),
+            refresh_interval: value.refresh_interval,
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use std::time::Duration;
+
+use crate::{
+    config::InterruptBehavior,
+    error::InquireResult,
+    formatter::BoolFormatter,
+    handle::PromptHandle,
+    prompts::{
+        prompt::{ActionResult, Prompt, Timeout},
+        state::{render_help_message, PromptState},
+    },
+    type_aliases::{EventListener, HelpMessageFn},
+    ui::{Block, ToggleBackend},
+    Toggle,
+};
+
+use super::{action::TogglePromptAction, config::ToggleConfig};
+
+pub struct TogglePrompt<'a> {
+    message: &'a str,
+    config: ToggleConfig,
+    event_listener: Option<EventListener<'a>>,
+    handle: Option<&'a PromptHandle>,
+    active: &'a str,
+    inactive: &'a str,
+    selected: bool,
+    default: bool,
+    help_message: Option<&'a str>,
+    help_message_fn: Option<HelpMessageFn<'a>>,
+    header: Option<Block>,
+    footer: Option<Block>,
+    formatter: Option<BoolFormatter<'a>>,
+}
+
+impl<'a> From<Toggle<'a>> for TogglePrompt<'a> {
+    fn from(to: Toggle<'a>) -> Self {
+        Self {
+            message: to.message,
+            config: (&to).into(),
+            event_listener: to.event_listener,
+            handle: to.handle,
+            active: to.active,
+            inactive: to.inactive,
+            selected: to.default,
+            default: to.default,
+            help_message: to.help_message,
+            help_message_fn: to.help_message_fn,
+            header: to.header,
+            footer: to.footer,
+            formatter: to.formatter,
+        }
+    }
+}
+
+impl<'a> TogglePrompt<'a> {
+    fn select(&mut self, selected: bool) -> ActionResult {
+        if self.selected == selected {
+            return ActionResult::Clean;
+        }
+
+        self.selected = selected;
+        ActionResult::NeedsRedraw
+    }
+}
+
+impl<'a, B> Prompt<B, ToggleConfig, TogglePromptAction, bool> for TogglePrompt<'a>
+where
+    B: ToggleBackend,
+{
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &ToggleConfig {
+        &self.config
+    }
+
+    fn interrupt_behavior(&self) -> InterruptBehavior {
+        self.config.interrupt_behavior
+    }
+
+    fn event_listener(&self) -> Option<EventListener<'_>> {
+        self.event_listener
+    }
+
+    fn prompt_handle(&self) -> Option<&PromptHandle> {
+        self.handle
+    }
+
+    fn header(&self) -> Option<&Block> {
+        self.header.as_ref()
+    }
+
+    fn footer(&self) -> Option<&Block> {
+        self.footer.as_ref()
+    }
+
+    fn timeout(&self) -> Option<Timeout> {
+        self.config.timeout
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        self.config.refresh_interval
+    }
+
+    fn submit_on_timeout(&mut self) -> InquireResult<Option<bool>> {
+        Ok(Some(self.selected))
+    }
+
+    fn format_answer(&self, answer: &bool) -> String {
+        match (self.formatter, answer) {
+            (Some(formatter), answer) => formatter(*answer),
+            (None, true) => self.active.to_string(),
+            (None, false) => self.inactive.to_string(),
+        }
+    }
+
+    fn is_default_answer(&self) -> bool {
+        self.selected == self.default
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<bool>> {
+        Ok(Some(self.selected))
+    }
+
+    fn handle(&mut self, action: TogglePromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            TogglePromptAction::SelectActive => self.select(true),
+            TogglePromptAction::SelectInactive => self.select(false),
+            TogglePromptAction::Switch => self.select(!self.selected),
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut B) -> InquireResult<()> {
+        backend.render_toggle_prompt(self.message, [self.active, self.inactive], self.selected)?;
+
+        render_help_message(backend, self.help_message, self.help_message_fn, || {
+            PromptState {
+                answer: Some(Prompt::<B, _, _, _>::format_answer(self, &self.selected)),
+                ..PromptState::default()
+            }
+        })?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{
+    terminal::TestBackend,
+    ui::{Key, KeyModifiers, RenderConfig},
+    Toggle,
+};
+
+#[test]
+fn arrows_switch_between_the_options() {
+    let mut terminal = TestBackend::new(vec![
+        Key::Left(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Left(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Toggle::new("Continue?")
+        .without_help_message()
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert!(ans);
+    assert_eq!(
+        vec![
+            "? Continue?  Yes / >No\n",
+            "? Continue? >Yes /  No\n",
+            "? Continue?  Yes / >No\n",
+            "? Continue? >Yes /  No\n",
+            "? Continue? Yes\n",
+        ],
+        terminal.frames()
+    );
+}
+
+#[test]
+fn tab_switches_to_the_other_option() {
+    let mut terminal = TestBackend::new(vec![Key::Tab, Key::Tab, Key::Tab, Key::Enter]);
+
+    let ans = Toggle::new("Continue?")
+        .with_default(true)
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert!(!ans);
+}
+
+#[test]
+fn label_of_the_answer_is_rendered() {
+    let mut terminal = TestBackend::new(vec![Key::Enter]);
+
+    let ans = Toggle::new("Environment:")
+        .with_labels("Staging", "Production")
+        .with_render_config(RenderConfig::empty())
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert!(!ans);
+    assert_eq!(
+        "? Environment: Production\n",
+        terminal.last_frame().unwrap()
+    );
+}
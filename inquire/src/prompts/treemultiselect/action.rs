@@ -0,0 +1,66 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction,
+};
+
+use super::config::TreeMultiSelectConfig;
+
+/// Set of actions for a TreeMultiSelectPrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TreeMultiSelectPromptAction {
+    /// Moves the cursor to the node above.
+    MoveUp,
+    /// Moves the cursor to the node below.
+    MoveDown,
+    /// Moves the cursor to the page above.
+    PageUp,
+    /// Moves the cursor to the page below.
+    PageDown,
+    /// Moves the cursor to the start of the tree.
+    MoveToStart,
+    /// Moves the cursor to the end of the tree.
+    MoveToEnd,
+    /// Expands the current node, or moves the cursor to its first child if it
+    /// is already expanded.
+    Expand,
+    /// Collapses the current node, or moves the cursor to its parent if it is
+    /// already collapsed or a leaf.
+    Collapse,
+    /// Toggles the current node, along with all the leaves below it.
+    ToggleCurrentNode,
+}
+
+impl InnerAction<TreeMultiSelectConfig> for TreeMultiSelectPromptAction {
+    fn from_key(key: Key, config: &TreeMultiSelectConfig) -> Option<Self> {
+        if config.vim_mode {
+            let action = match key {
+                Key::Char('k', KeyModifiers::NONE) => Some(Self::MoveUp),
+                Key::Char('j', KeyModifiers::NONE) => Some(Self::MoveDown),
+                Key::Char('h', KeyModifiers::NONE) => Some(Self::Collapse),
+                Key::Char('l', KeyModifiers::NONE) => Some(Self::Expand),
+                _ => None,
+            };
+
+            if action.is_some() {
+                return action;
+            }
+        }
+
+        let action = match key {
+            Key::Up(KeyModifiers::NONE) => Self::MoveUp,
+            Key::PageUp(_) => Self::PageUp,
+            Key::Home => Self::MoveToStart,
+
+            Key::Down(KeyModifiers::NONE) => Self::MoveDown,
+            Key::PageDown(_) => Self::PageDown,
+            Key::End => Self::MoveToEnd,
+
+            Key::Right(KeyModifiers::NONE) => Self::Expand,
+            Key::Left(KeyModifiers::NONE) => Self::Collapse,
+            Key::Char(' ', KeyModifiers::NONE) => Self::ToggleCurrentNode,
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
@@ -0,0 +1,30 @@
+use std::time::Duration;
+
+use crate::{config::InterruptBehavior, prompts::prompt::Timeout, TreeMultiSelect};
+
+/// Configuration settings used in the execution of a TreeMultiSelectPrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct TreeMultiSelectConfig {
+    /// Whether to use vim-style keybindings.
+    pub vim_mode: bool,
+    /// Page size of the tree of options.
+    pub page_size: usize,
+    /// What happens when the user presses Ctrl+C.
+    pub interrupt_behavior: InterruptBehavior,
+    /// Timeout applied until the first key press.
+    pub timeout: Option<Timeout>,
+    /// Interval at which the prompt is re-rendered without key presses.
+    pub refresh_interval: Option<Duration>,
+}
+
+impl<T> From<&TreeMultiSelect<'_, T>> for TreeMultiSelectConfig {
+    fn from(value: &TreeMultiSelect<'_, T>) -> Self {
+        Self {
+            vim_mode: value.vim_mode,
+            page_size: value.page_size,
+            interrupt_behavior: value.interrupt_behavior,
+            timeout: Timeout::new(value.timeout, value.timeout_countdown),
+            refresh_interval: value.refresh_interval,
+        }
+    }
+}
@@ -0,0 +1,484 @@
+mod action;
+mod config;
+mod prompt;
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test;
+
+pub use action::*;
+
+use std::{fmt::Display, time::Duration};
+
+use crate::{
+    config::{
+        get_configuration, get_event_listener, get_global_options, get_interrupt_behavior,
+        InterruptBehavior,
+    },
+    error::{InquireError, InquireResult},
+    formatter::MultiOptionFormatter,
+    handle::PromptHandle,
+    list_option::ListOption,
+    prompts::prompt::Prompt,
+    report::PromptReport,
+    terminal::{get_default_terminal, Terminal},
+    type_aliases::{EventListener, HelpMessageFn},
+    ui::{Backend, Block, FinalRender, MultiSelectBackend, RenderConfig},
+    validator::MultiOptionValidator,
+};
+
+use self::prompt::TreeMultiSelectPrompt;
+
+/// Node of the tree of options of a [`TreeMultiSelect`], such as a directory
+/// and the files in it.
+///
+/// Nodes without children are the leaves of the tree, the only options part of
+/// the answer of the prompt.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeNode<T> {
+    /// Value of the node.
+    pub value: T,
+
+    /// Children of the node, displayed below it when it is expanded.
+    pub children: Vec<TreeNode<T>>,
+}
+
+impl<T> TreeNode<T> {
+    /// Creates a node without children.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            children: vec![],
+        }
+    }
+
+    /// Sets the children of the node.
+    pub fn with_children(mut self, children: Vec<TreeNode<T>>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+/// Prompt to check leaves of a tree of options, e.g. files in a directory tree
+/// or permissions grouped by resource.
+///
+/// Parent nodes can be expanded and collapsed, and checking one checks all of
+/// the leaves below it, or unchecks them if they are all checked already. The
+/// checkbox of a parent is rendered as
+/// [partially selected](crate::ui::RenderConfig::partially_selected_checkbox)
+/// when only some of the leaves below it are checked.
+///
+/// The answer is the list of the values of the checked leaves, in the order of
+/// the tree. The index of each [`ListOption`] returned by
+/// [`raw_prompt`](Self::raw_prompt) is the position of the node in the tree
+/// walked depth-first, parents before their children.
+///
+/// This prompt provides several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Options tree**: Root nodes of the tree of options. Must be **non-empty**.
+/// - **Expanded**: Whether all parent nodes are expanded from the start. Disabled by default.
+/// - **Default selection**: Positions of the nodes checked by default, checking all the leaves below parents.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the checked leaves string values, joined using a comma as the separator, by default.
+/// - **Validator**: Custom validator to make sure a given submitted input pass the specified requirements, e.g. not allowing 0 checked leaves.
+///   - No validators are on by default.
+/// - **Page size**: Number of nodes displayed at once, 7 by default.
+/// - **Vim mode**: Enables the j and k keys to move the cursor, and h and l to collapse and expand nodes.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{TreeMultiSelect, TreeNode};
+///
+/// let files = TreeMultiSelect::new(
+///     "Files to commit:",
+///     vec![
+///         TreeNode::new("src").with_children(vec![
+///             TreeNode::new("lib.rs"),
+///             TreeNode::new("main.rs"),
+///         ]),
+///         TreeNode::new("README.md"),
+///     ],
+/// )
+/// .prompt()?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Clone)]
+pub struct TreeMultiSelect<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Root nodes of the tree of options.
+    pub roots: Vec<TreeNode<T>>,
+
+    /// Whether all parent nodes are expanded from the start.
+    pub expanded: bool,
+
+    /// Positions of the nodes checked by default, in the tree walked
+    /// depth-first.
+    pub default: Option<Vec<usize>>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function computing the help message from the current state of the
+    /// prompt, replacing [`help_message`](Self::help_message) when set.
+    ///
+    /// See [`with_help_message_fn`](Self::with_help_message_fn) for more details.
+    pub help_message_fn: Option<HelpMessageFn<'a>>,
+
+    /// Content rendered above the prompt.
+    pub header: Option<Block>,
+
+    /// Content rendered below the prompt.
+    pub footer: Option<Block>,
+
+    /// Page size of the tree of options.
+    pub page_size: usize,
+
+    /// Whether vim mode is enabled. When enabled, the user can
+    /// navigate through the nodes using hjkl.
+    pub vim_mode: bool,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: MultiOptionFormatter<'a, T>,
+
+    /// Validator to apply to the user input.
+    ///
+    /// In case of error, the message is displayed one line above the prompt.
+    pub validator: Option<Box<dyn MultiOptionValidator<T>>>,
+
+    /// What happens when the user presses Ctrl+C.
+    pub interrupt_behavior: InterruptBehavior,
+
+    /// Listener notified of the events of the prompt, such as key presses and
+    /// submissions.
+    ///
+    /// See [`with_event_listener`](Self::with_event_listener) for more details.
+    pub event_listener: Option<EventListener<'a>>,
+
+    /// Handle aborting the prompt from outside of it, if any.
+    ///
+    /// See [`with_handle`](Self::with_handle) for more details.
+    pub handle: Option<&'a PromptHandle>,
+
+    /// Time to wait for the user to start interacting with the prompt.
+    ///
+    /// See [`with_timeout`](Self::with_timeout) for more details.
+    pub timeout: Option<Duration>,
+
+    /// Whether to render the time left before the timeout expires.
+    pub timeout_countdown: bool,
+
+    /// Interval at which the prompt is re-rendered while no key is pressed.
+    ///
+    /// See [`with_refresh_interval`](Self::with_refresh_interval) for more details.
+    pub refresh_interval: Option<Duration>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still suport NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> TreeMultiSelect<'a, T>
+where
+    T: Display,
+{
+    /// String formatter used by default in [TreeMultiSelect](crate::TreeMultiSelect) prompts.
+    /// Prints the string value of all checked leaves, separated by a comma.
+    pub const DEFAULT_FORMATTER: MultiOptionFormatter<'a, T> = &|ans| {
+        ans.iter()
+            .map(|opt| opt.to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
+    };
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to move, → to expand, ← to collapse, space to select");
+
+    /// Creates a [TreeMultiSelect] with the provided message and root nodes,
+    /// along with default configuration values.
+    pub fn new(message: &'a str, roots: Vec<TreeNode<T>>) -> Self {
+        let global = get_global_options();
+
+        Self {
+            message,
+            roots,
+            expanded: false,
+            default: None,
+            help_message: global.help_message(Self::DEFAULT_HELP_MESSAGE),
+            help_message_fn: None,
+            header: None,
+            footer: None,
+            page_size: global.page_size,
+            vim_mode: global.vim_mode,
+            formatter: Self::DEFAULT_FORMATTER,
+            validator: None,
+            interrupt_behavior: get_interrupt_behavior(),
+            event_listener: get_event_listener(),
+            handle: None,
+            timeout: None,
+            timeout_countdown: false,
+            refresh_interval: None,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets whether all parent nodes are expanded from the start.
+    pub fn with_expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    /// Sets the positions of the nodes checked by default, in the tree walked
+    /// depth-first, parents before their children. Checking a parent checks
+    /// all the leaves below it.
+    pub fn with_default(mut self, default: &[usize]) -> Self {
+        self.default = Some(default.to_vec());
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Sets a function computing the help message from the current state of the
+    /// prompt, called whenever it is rendered. It takes precedence over the
+    /// static help message, and returning `None` hides the help line.
+    ///
+    /// See [`PromptState`](crate::PromptState) for the state given to the function.
+    pub fn with_help_message_fn(mut self, help_message_fn: HelpMessageFn<'a>) -> Self {
+        self.help_message_fn = Some(help_message_fn);
+        self
+    }
+
+    /// Sets the content rendered above the prompt, such as the current git
+    /// branch. It is redrawn and cleared along with the prompt, see [`Block`].
+    pub fn with_header<H: Into<Block>>(mut self, header: H) -> Self {
+        self.header = Some(header.into());
+        self
+    }
+
+    /// Sets the content rendered below the prompt. It is redrawn and cleared
+    /// along with the prompt, see [`Block`].
+    pub fn with_footer<F: Into<Block>>(mut self, footer: F) -> Self {
+        self.footer = Some(footer.into());
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Enables or disables vim_mode.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: MultiOptionFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the validator to apply to the user input. You might want to use this feature
+    /// in case you need to limit the user to specific choices, such as limiting the number
+    /// of checked leaves.
+    ///
+    /// In case of error, the message is displayed one line above the prompt.
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: MultiOptionValidator<T> + 'static,
+    {
+        self.validator = Some(Box::new(validator));
+        self
+    }
+
+    /// Sets what happens when the user presses Ctrl+C, overriding the global
+    /// setting.
+    pub fn with_interrupt_behavior(mut self, interrupt_behavior: InterruptBehavior) -> Self {
+        self.interrupt_behavior = interrupt_behavior;
+        self
+    }
+
+    /// Sets a listener notified of the events of the prompt, such as renders,
+    /// key presses and submissions, overriding the global listener.
+    ///
+    /// See the [`events`](crate::events) module for more details.
+    pub fn with_event_listener(mut self, listener: EventListener<'a>) -> Self {
+        self.event_listener = Some(listener);
+        self
+    }
+
+    /// Sets a handle aborting the prompt from outside of it, e.g. from another
+    /// thread or a Ctrl+C handler, canceling it as if the user pressed ESC.
+    ///
+    /// See the [`handle`](crate::handle) module for more details.
+    pub fn with_handle(mut self, handle: &'a PromptHandle) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets a timeout for the user to start interacting with the prompt.
+    ///
+    /// If no key is pressed before it expires, [`InquireError::Timeout`] is returned.
+    /// Once a key is pressed, the prompt waits for the user indefinitely.
+    ///
+    /// Timeouts are supported by the `crossterm` and `termion` backends, the
+    /// `console` backend always waits for the first key press.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether to render the time left before the timeout expires below the prompt.
+    pub fn with_timeout_countdown(mut self, countdown: bool) -> Self {
+        self.timeout_countdown = countdown;
+        self
+    }
+
+    /// Sets an interval at which the prompt is re-rendered even when no key is
+    /// pressed, e.g. for content computed at render time, such as a dynamic
+    /// help message showing the current time, to stay up to date.
+    ///
+    /// Requires a terminal able to wait for keys with a timeout, such as the
+    /// crossterm or termion ones. Other terminals only re-render the prompt
+    /// after key presses.
+    pub fn with_refresh_interval(mut self, interval: Duration) -> Self {
+        self.refresh_interval = Some(interval);
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still suport NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Sets how the prompt is rendered once answered, e.g. [`FinalRender::None`]
+    /// for the prompt to disappear. This is a shorthand for setting the
+    /// [`final_render`](RenderConfig::final_render) of the render config, so it
+    /// must be called after [`with_render_config`](Self::with_render_config).
+    pub fn with_final_render(mut self, final_render: FinalRender) -> Self {
+        self.render_config.final_render = final_render;
+        self
+    }
+
+    /// Sets whether the prompt is rendered in fullscreen, on the alternate
+    /// screen buffer, leaving only its final rendering in the original screen.
+    /// This is a shorthand for setting the
+    /// [`fullscreen`](RenderConfig::fullscreen) flag of the render config, so
+    /// it must be called after [`with_render_config`](Self::with_render_config).
+    pub fn with_fullscreen(mut self, fullscreen: bool) -> Self {
+        self.render_config.fullscreen = fullscreen;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the owned values of the checked leaves.
+    pub fn prompt(self) -> InquireResult<Vec<T>> {
+        self.raw_prompt()
+            .map(|op| op.into_iter().map(|o| o.value).collect())
+    }
+
+    /// Prompts the user through the given terminal instead of the default
+    /// terminal of the enabled backend feature, see [`Terminal`].
+    pub fn prompt_with_terminal<Term: Terminal>(self, terminal: Term) -> InquireResult<Vec<T>> {
+        self.raw_prompt_with_terminal(terminal)
+            .map(|op| op.into_iter().map(|o| o.value).collect())
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<Vec<T>>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns a vector of [`ListOption`](crate::list_option::ListOption)s
+    /// containing the position of each checked leaf in the tree and its
+    /// owned value.
+    pub fn raw_prompt(self) -> InquireResult<Vec<ListOption<T>>> {
+        self.raw_prompt_with_terminal(get_default_terminal()?)
+    }
+
+    /// Prompts the user through the given terminal instead of the default
+    /// terminal of the enabled backend feature, see [`Terminal`].
+    ///
+    /// Returns the same as [`raw_prompt`](Self::raw_prompt).
+    pub fn raw_prompt_with_terminal<Term: Terminal>(
+        self,
+        terminal: Term,
+    ) -> InquireResult<Vec<ListOption<T>>> {
+        let mut backend = Backend::new(terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    /// Same as [`prompt`](Self::prompt), also returning a [`PromptReport`]
+    /// describing how the user interacted with the prompt.
+    pub fn prompt_with_report(self) -> InquireResult<(Vec<T>, PromptReport)> {
+        self.prompt_with_report_and_terminal(get_default_terminal()?)
+    }
+
+    /// Same as [`prompt_with_terminal`](Self::prompt_with_terminal), also
+    /// returning a [`PromptReport`] describing how the user interacted with
+    /// the prompt.
+    pub fn prompt_with_report_and_terminal<Term: Terminal>(
+        self,
+        terminal: Term,
+    ) -> InquireResult<(Vec<T>, PromptReport)> {
+        let mut backend = Backend::new(terminal, self.render_config)?;
+        let (answer, report) =
+            TreeMultiSelectPrompt::new(self)?.prompt_with_report(&mut backend)?;
+
+        Ok((answer.into_iter().map(|o| o.value).collect(), report))
+    }
+
+    pub(crate) fn prompt_with_backend<B: MultiSelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<Vec<ListOption<T>>> {
+        TreeMultiSelectPrompt::new(self)?.prompt(backend)
+    }
+}
@@ -0,0 +1,426 @@
+use std::{collections::BTreeSet, fmt::Display, time::Duration};
+
+use crate::{
+    config::InterruptBehavior,
+    error::InquireResult,
+    formatter::MultiOptionFormatter,
+    handle::PromptHandle,
+    input::Input,
+    list_option::ListOption,
+    prompts::{
+        prompt::{ActionResult, Prompt, Timeout},
+        state::{render_help_message, PromptState},
+    },
+    type_aliases::{EventListener, HelpMessageFn},
+    ui::{Block, CheckState, CheckboxRow, MultiSelectBackend},
+    utils::paginate,
+    validator::{ErrorMessage, MultiOptionValidator, Validation},
+    InquireError, TreeMultiSelect, TreeNode,
+};
+
+use super::{action::TreeMultiSelectPromptAction, config::TreeMultiSelectConfig};
+
+/// Tree of options flattened depth-first, parents before their children, so
+/// that the descendants of a node are the nodes right after it.
+struct FlatTree<T> {
+    values: Vec<T>,
+    labels: Vec<String>,
+    depths: Vec<usize>,
+    parents: Vec<Option<usize>>,
+    /// End of the descendants of each node, exclusive.
+    ends: Vec<usize>,
+}
+
+impl<T> FlatTree<T>
+where
+    T: Display,
+{
+    fn new(roots: Vec<TreeNode<T>>) -> Self {
+        let mut tree = Self {
+            values: vec![],
+            labels: vec![],
+            depths: vec![],
+            parents: vec![],
+            ends: vec![],
+        };
+        tree.push(roots, 0, None);
+        tree
+    }
+
+    fn push(&mut self, nodes: Vec<TreeNode<T>>, depth: usize, parent: Option<usize>) {
+        for node in nodes {
+            let index = self.values.len();
+            self.labels.push(node.value.to_string());
+            self.values.push(node.value);
+            self.depths.push(depth);
+            self.parents.push(parent);
+            self.ends.push(index + 1);
+
+            self.push(node.children, depth + 1, Some(index));
+            self.ends[index] = self.values.len();
+        }
+    }
+}
+
+impl<T> FlatTree<T> {
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn is_leaf(&self, index: usize) -> bool {
+        self.ends[index] == index + 1
+    }
+
+    /// Leaves at or below the given node.
+    fn leaves(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        (index..self.ends[index]).filter(move |&i| self.is_leaf(i))
+    }
+}
+
+pub struct TreeMultiSelectPrompt<'a, T> {
+    message: &'a str,
+    config: TreeMultiSelectConfig,
+    event_listener: Option<EventListener<'a>>,
+    handle: Option<&'a PromptHandle>,
+    tree: FlatTree<T>,
+    expanded: Vec<bool>,
+    /// Nodes whose ancestors are all expanded, in the order of the tree.
+    visible: Vec<usize>,
+    cursor_index: usize,
+    /// Checked leaves, parents being checked through them.
+    checked: BTreeSet<usize>,
+    input: Input,
+    help_message: Option<&'a str>,
+    help_message_fn: Option<HelpMessageFn<'a>>,
+    header: Option<Block>,
+    footer: Option<Block>,
+    formatter: MultiOptionFormatter<'a, T>,
+    validator: Option<Box<dyn MultiOptionValidator<T>>>,
+    error: Option<ErrorMessage>,
+}
+
+impl<'a, T> TreeMultiSelectPrompt<'a, T>
+where
+    T: Display,
+{
+    pub fn new(tmso: TreeMultiSelect<'a, T>) -> InquireResult<Self> {
+        if tmso.roots.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Available options can not be empty".into(),
+            ));
+        }
+
+        let config = (&tmso).into();
+        let tree = FlatTree::new(tmso.roots);
+
+        let mut checked = BTreeSet::new();
+        for &index in tmso.default.iter().flatten() {
+            if index >= tree.len() {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Index {} is out-of-bounds for a tree of {} nodes",
+                    index,
+                    tree.len()
+                )));
+            }
+            checked.extend(tree.leaves(index));
+        }
+
+        let mut prompt = Self {
+            message: tmso.message,
+            config,
+            event_listener: tmso.event_listener,
+            handle: tmso.handle,
+            expanded: vec![tmso.expanded; tree.len()],
+            tree,
+            visible: vec![],
+            cursor_index: 0,
+            checked,
+            input: Input::new(),
+            help_message: tmso.help_message,
+            help_message_fn: tmso.help_message_fn,
+            header: tmso.header,
+            footer: tmso.footer,
+            formatter: tmso.formatter,
+            validator: tmso.validator,
+            error: None,
+        };
+        prompt.compute_visible();
+
+        Ok(prompt)
+    }
+}
+
+impl<'a, T> TreeMultiSelectPrompt<'a, T> {
+    fn compute_visible(&mut self) {
+        self.visible.clear();
+
+        let mut index = 0;
+        while index < self.tree.len() {
+            self.visible.push(index);
+            index = match self.expanded[index] {
+                true => index + 1,
+                false => self.tree.ends[index],
+            };
+        }
+    }
+
+    fn current_node(&self) -> usize {
+        self.visible[self.cursor_index]
+    }
+
+    fn move_cursor_to_node(&mut self, node: usize) {
+        if let Some(position) = self.visible.iter().position(|i| *i == node) {
+            self.cursor_index = position;
+        }
+    }
+
+    fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let new_position = if wrap {
+            let len = self.visible.len();
+            (self.cursor_index + len - qty % len) % len
+        } else {
+            self.cursor_index.saturating_sub(qty)
+        };
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn move_cursor_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
+        let new_position = if wrap {
+            (self.cursor_index + qty) % self.visible.len()
+        } else {
+            self.cursor_index
+                .saturating_add(qty)
+                .min(self.visible.len() - 1)
+        };
+
+        self.update_cursor_position(new_position)
+    }
+
+    fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
+        if new_position != self.cursor_index {
+            self.cursor_index = new_position;
+            ActionResult::NeedsRedraw
+        } else {
+            ActionResult::Clean
+        }
+    }
+
+    fn expand_current_node(&mut self) -> ActionResult {
+        let node = self.current_node();
+        if self.tree.is_leaf(node) {
+            return ActionResult::Clean;
+        }
+
+        if self.expanded[node] {
+            return self.move_cursor_down(1, false);
+        }
+
+        self.expanded[node] = true;
+        self.compute_visible();
+        ActionResult::NeedsRedraw
+    }
+
+    fn collapse_current_node(&mut self) -> ActionResult {
+        let node = self.current_node();
+
+        if !self.tree.is_leaf(node) && self.expanded[node] {
+            self.expanded[node] = false;
+            self.compute_visible();
+            self.move_cursor_to_node(node);
+            return ActionResult::NeedsRedraw;
+        }
+
+        match self.tree.parents[node] {
+            Some(parent) => {
+                self.move_cursor_to_node(parent);
+                ActionResult::NeedsRedraw
+            }
+            None => ActionResult::Clean,
+        }
+    }
+
+    fn toggle_current_node(&mut self) -> ActionResult {
+        let leaves: Vec<usize> = self.tree.leaves(self.current_node()).collect();
+
+        // a parent is unchecked only when all of its leaves are checked
+        if leaves.iter().all(|i| self.checked.contains(i)) {
+            for i in &leaves {
+                self.checked.remove(i);
+            }
+        } else {
+            self.checked.extend(leaves);
+        }
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn check_state(&self, node: usize) -> CheckState {
+        let (checked, total) = self.tree.leaves(node).fold((0, 0), |(checked, total), i| {
+            (checked + usize::from(self.checked.contains(&i)), total + 1)
+        });
+
+        match checked {
+            0 => CheckState::Unchecked,
+            checked if checked == total => CheckState::Checked,
+            _ => CheckState::Partial,
+        }
+    }
+
+    fn checked_options(&self) -> Vec<ListOption<&T>> {
+        self.checked
+            .iter()
+            .map(|&i| ListOption::new(i, &self.tree.values[i]))
+            .collect()
+    }
+
+    fn validate_current_answer(&self) -> InquireResult<Validation> {
+        match &self.validator {
+            Some(validator) => Ok(validator.validate(&self.checked_options())?),
+            None => Ok(Validation::Valid),
+        }
+    }
+
+    fn state(&self) -> PromptState<'_> {
+        PromptState {
+            answer: Some((self.formatter)(&self.checked_options())),
+            options: self.visible.len(),
+            selected: self.checked.len(),
+            ..PromptState::default()
+        }
+    }
+
+    fn get_final_answer(&mut self) -> Vec<ListOption<T>> {
+        let checked = &self.checked;
+
+        std::mem::take(&mut self.tree.values)
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| checked.contains(i))
+            .map(|(i, value)| ListOption::new(i, value))
+            .collect()
+    }
+}
+
+impl<'a, B, T> Prompt<B, TreeMultiSelectConfig, TreeMultiSelectPromptAction, Vec<ListOption<T>>>
+    for TreeMultiSelectPrompt<'a, T>
+where
+    B: MultiSelectBackend,
+    T: Display,
+{
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &TreeMultiSelectConfig {
+        &self.config
+    }
+
+    fn interrupt_behavior(&self) -> InterruptBehavior {
+        self.config.interrupt_behavior
+    }
+
+    fn event_listener(&self) -> Option<EventListener<'_>> {
+        self.event_listener
+    }
+
+    fn prompt_handle(&self) -> Option<&PromptHandle> {
+        self.handle
+    }
+
+    fn header(&self) -> Option<&Block> {
+        self.header.as_ref()
+    }
+
+    fn footer(&self) -> Option<&Block> {
+        self.footer.as_ref()
+    }
+
+    fn timeout(&self) -> Option<Timeout> {
+        self.config.timeout
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        self.config.refresh_interval
+    }
+
+    fn format_answer(&self, answer: &Vec<ListOption<T>>) -> String {
+        let refs: Vec<ListOption<&T>> = answer.iter().map(ListOption::as_ref).collect();
+        (self.formatter)(&refs)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<Vec<ListOption<T>>>> {
+        let answer = match self.validate_current_answer()? {
+            Validation::Valid => Some(self.get_final_answer()),
+            Validation::Invalid(msg) => {
+                self.error = Some(msg);
+                None
+            }
+        };
+
+        Ok(answer)
+    }
+
+    fn handle(&mut self, action: TreeMultiSelectPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            TreeMultiSelectPromptAction::MoveUp => self.move_cursor_up(1, true),
+            TreeMultiSelectPromptAction::MoveDown => self.move_cursor_down(1, true),
+            TreeMultiSelectPromptAction::PageUp => {
+                self.move_cursor_up(self.config.page_size, false)
+            }
+            TreeMultiSelectPromptAction::PageDown => {
+                self.move_cursor_down(self.config.page_size, false)
+            }
+            TreeMultiSelectPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
+            TreeMultiSelectPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
+            TreeMultiSelectPromptAction::Expand => self.expand_current_node(),
+            TreeMultiSelectPromptAction::Collapse => self.collapse_current_node(),
+            TreeMultiSelectPromptAction::ToggleCurrentNode => self.toggle_current_node(),
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut B) -> InquireResult<()> {
+        if let Some(err) = &self.error {
+            backend.render_error_message(err)?;
+        }
+
+        backend.render_multiselect_prompt(self.message, &self.input)?;
+
+        let choices = self
+            .visible
+            .iter()
+            .map(|&i| ListOption::new(i, &self.tree.labels[i]))
+            .collect::<Vec<ListOption<&String>>>();
+
+        let page_size = backend.fit_page_size(self.config.page_size);
+        let page = paginate(page_size, &choices, Some(self.cursor_index));
+
+        let checkboxes = page
+            .content
+            .iter()
+            .map(|option| {
+                let node = option.index;
+                let leaf = self.tree.is_leaf(node);
+
+                CheckboxRow {
+                    state: self.check_state(node),
+                    // leaves are indented by the width of the expansion
+                    // prefix of parents, aligning the checkboxes of siblings
+                    depth: self.tree.depths[node] + usize::from(leaf),
+                    parent: !leaf,
+                    expanded: (!leaf).then(|| self.expanded[node]),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        backend.render_options(page, &checkboxes)?;
+
+        render_help_message(backend, self.help_message, self.help_message_fn, || {
+            self.state()
+        })?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{
+    terminal::TestBackend,
+    ui::{Key, KeyModifiers, RenderConfig},
+    InquireError, TreeMultiSelect, TreeNode,
+};
+
+fn files_prompt<'a>() -> TreeMultiSelect<'a, &'a str> {
+    TreeMultiSelect::new(
+        "Files:",
+        vec![
+            TreeNode::new("src").with_children(vec![
+                TreeNode::new("bin").with_children(vec![TreeNode::new("cli.rs")]),
+                TreeNode::new("lib.rs"),
+            ]),
+            TreeNode::new("README.md"),
+        ],
+    )
+    .without_help_message()
+    .with_render_config(RenderConfig::empty())
+}
+
+#[test]
+fn parents_are_expanded_and_collapsed() {
+    let right = Key::Right(KeyModifiers::NONE);
+    let left = Key::Left(KeyModifiers::NONE);
+    let mut terminal = TestBackend::new(vec![right, right, right, left, left, Key::Enter]);
+
+    let ans = files_prompt().prompt_with_terminal(&mut terminal).unwrap();
+
+    assert!(ans.is_empty());
+    let frames = terminal.frames();
+    assert_eq!("? Files:  \n> + [ ] src\n    [ ] README.md\n", frames[0]);
+    assert_eq!(
+        "? Files:  \n> - [ ] src\n    + [ ] bin\n      [ ] lib.rs\n    [ ] README.md\n",
+        frames[1]
+    );
+    assert_eq!(
+        "? Files:  \n  - [ ] src\n>   + [ ] bin\n      [ ] lib.rs\n    [ ] README.md\n",
+        frames[2]
+    );
+    assert_eq!(
+        "? Files:  \n  - [ ] src\n>   - [ ] bin\n        [ ] cli.rs\n      [ ] lib.rs\n    [ ] README.md\n",
+        frames[3]
+    );
+    assert_eq!(frames[2], frames[4]);
+    assert_eq!(
+        "? Files:  \n> - [ ] src\n    + [ ] bin\n      [ ] lib.rs\n    [ ] README.md\n",
+        frames[5]
+    );
+}
+
+#[test]
+fn checking_a_parent_checks_the_leaves_below_it() {
+    let down = Key::Down(KeyModifiers::NONE);
+    let space = Key::Char(' ', KeyModifiers::NONE);
+    let mut terminal = TestBackend::new(vec![down, down, space, Key::Up(KeyModifiers::NONE)]);
+    terminal.push_key(Key::Up(KeyModifiers::NONE));
+    terminal.push_key(space);
+    terminal.push_key(Key::Enter);
+
+    let ans = files_prompt()
+        .with_expanded(true)
+        .raw_prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert_eq!(vec![2, 3], ans.iter().map(|o| o.index).collect::<Vec<_>>());
+    assert_eq!(
+        vec!["cli.rs", "lib.rs"],
+        ans.into_iter().map(|o| o.value).collect::<Vec<_>>()
+    );
+    assert!(terminal.frames()[3].contains("\n  - [~] src\n    - [x] bin\n>       [x] cli.rs\n"));
+    assert!(terminal.frames()[6].contains(
+        "\n> - [x] src\n    - [x] bin\n        [x] cli.rs\n      [x] lib.rs\n    [ ] README.md\n"
+    ));
+    assert_eq!("? Files: cli.rs, lib.rs\n", terminal.last_frame().unwrap());
+}
+
+#[test]
+fn checked_parents_are_unchecked_with_all_their_leaves() {
+    let space = Key::Char(' ', KeyModifiers::NONE);
+    let mut terminal = TestBackend::new(vec![space, space, Key::Enter]);
+
+    let ans = files_prompt()
+        .with_default(&[2])
+        .prompt_with_terminal(&mut terminal)
+        .unwrap();
+
+    assert!(ans.is_empty());
+    assert!(terminal.frames()[0].contains("> + [~] src\n"));
+    assert!(terminal.frames()[1].contains("> + [x] src\n"));
+    assert!(terminal.frames()[2].contains("> + [ ] src\n"));
+}
+
+#[test]
+fn empty_trees_are_rejected() {
+    let ans = TreeMultiSelect::<&str>::new("Files:", vec![])
+        .prompt_with_terminal(&mut TestBackend::new(vec![]));
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
@@ -0,0 +1,501 @@
+//! Recording and replay of the keys pressed during prompts.
+//!
+//! A [`Recorder`] wraps the terminal of a real prompt run and records every key
+//! pressed by the user along with the time elapsed since the previous one. The
+//! resulting [`Recording`] can be saved as text and replayed later by a
+//! [`Player`], at the original pace for demos or instantly to reproduce a
+//! regression. A recording can also script a
+//! [`TestBackend`](crate::terminal::TestBackend) through [`Recording::keys`].
+//!
+//! ```
+//! use inquire::{
+//!     record::{Player, Recorder, Recording},
+//!     terminal::TestBackend,
+//!     Text,
+//! };
+//!
+//! // run a prompt while recording the keys, here scripted instead of typed
+//! let mut recorder = Recorder::new(TestBackend::new(vec![]).with_text("Ferris\n"));
+//! let name = Text::new("Name:").prompt_with_terminal(&mut recorder)?;
+//! assert_eq!("Ferris", name);
+//!
+//! let saved = recorder.into_recording().to_string();
+//!
+//! // replay the saved keys, without waiting between them
+//! let recording: Recording = saved.parse().unwrap();
+//! let player = Player::new(TestBackend::new(vec![]), recording).with_speed(0.0);
+//! let name = Text::new("Name:").prompt_with_terminal(player)?;
+//! assert_eq!("Ferris", name);
+//! # Ok::<(), inquire::InquireError>(())
+//! ```
+
+use std::{
+    collections::VecDeque,
+    fmt::{self, Display},
+    io::{Error, ErrorKind, Result},
+    str::FromStr,
+    thread,
+    time::{Duration, Instant},
+};
+
+use thiserror::Error;
+
+use crate::{
+    terminal::{Terminal, TerminalSize},
+    ui::{Event, Key, KeyModifiers, Styled},
+};
+
+/// Key pressed during a recorded session.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RecordedKey {
+    /// Time elapsed since the previous key, or since the start of the
+    /// recording for the first one.
+    pub delay: Duration,
+
+    /// Key pressed by the user.
+    pub key: Key,
+}
+
+/// Keys pressed during a recorded session, in order.
+///
+/// Recordings are saved as text with one key per line, the delay in
+/// milliseconds followed by the key, such as `350 ctrl+char:c` or `80 enter`,
+/// through their [`Display`] and [`FromStr`] implementations.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Recording {
+    /// Keys pressed during the session.
+    pub keys: Vec<RecordedKey>,
+}
+
+impl Recording {
+    /// Creates an empty recording.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keys of the recording, without their delays.
+    pub fn keys(&self) -> impl Iterator<Item = Key> + '_ {
+        self.keys.iter().map(|recorded| recorded.key)
+    }
+
+    /// Total duration of the recording.
+    pub fn duration(&self) -> Duration {
+        self.keys.iter().map(|recorded| recorded.delay).sum()
+    }
+}
+
+impl Display for Recording {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for recorded in &self.keys {
+            write!(f, "{} ", recorded.delay.as_millis())?;
+            write_key(f, recorded.key)?;
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Error returned when parsing an invalid recording.
+#[derive(Error, Clone, Debug, PartialEq, Eq)]
+#[error("Invalid recorded key at line {line}")]
+pub struct ParseRecordingError {
+    /// Line of the invalid key, starting at 1.
+    pub line: usize,
+}
+
+impl FromStr for Recording {
+    type Err = ParseRecordingError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut keys = vec![];
+
+        for (index, line) in s.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let error = ParseRecordingError { line: index + 1 };
+            let mut parts = line.splitn(2, ' ');
+            let delay = parts
+                .next()
+                .and_then(|millis| millis.parse().ok())
+                .map(Duration::from_millis)
+                .ok_or_else(|| error.clone())?;
+            let key = parts.next().and_then(parse_key).ok_or(error)?;
+
+            keys.push(RecordedKey { delay, key });
+        }
+
+        Ok(Self { keys })
+    }
+}
+
+fn write_key(f: &mut fmt::Formatter<'_>, key: Key) -> fmt::Result {
+    let (name, modifiers) = match key {
+        Key::Escape => ("esc", KeyModifiers::NONE),
+        Key::Enter => ("enter", KeyModifiers::NONE),
+        Key::Backspace(m) => ("backspace", m),
+        Key::Tab => ("tab", KeyModifiers::NONE),
+        Key::BackTab => ("backtab", KeyModifiers::NONE),
+        Key::Delete(m) => ("delete", m),
+        Key::Home => ("home", KeyModifiers::NONE),
+        Key::End => ("end", KeyModifiers::NONE),
+        Key::PageUp(m) => ("pageup", m),
+        Key::PageDown(m) => ("pagedown", m),
+        Key::Up(m) => ("up", m),
+        Key::Down(m) => ("down", m),
+        Key::Left(m) => ("left", m),
+        Key::Right(m) => ("right", m),
+        Key::Char(_, m) => ("char:", m),
+        #[allow(deprecated)]
+        Key::Any => ("any", KeyModifiers::NONE),
+    };
+
+    for (modifier, prefix) in MODIFIER_PREFIXES {
+        if modifiers.contains(*modifier) {
+            f.write_str(prefix)?;
+        }
+    }
+    f.write_str(name)?;
+
+    match key {
+        Key::Char(c, _) => write!(f, "{}", c),
+        _ => Ok(()),
+    }
+}
+
+const MODIFIER_PREFIXES: &[(KeyModifiers, &str)] = &[
+    (KeyModifiers::CONTROL, "ctrl+"),
+    (KeyModifiers::ALT, "alt+"),
+    (KeyModifiers::SHIFT, "shift+"),
+];
+
+fn parse_key(mut key: &str) -> Option<Key> {
+    let mut modifiers = KeyModifiers::NONE;
+    for (modifier, prefix) in MODIFIER_PREFIXES {
+        if key.starts_with(prefix) {
+            modifiers |= *modifier;
+            key = &key[prefix.len()..];
+        }
+    }
+
+    if let Some(c) = key.strip_prefix("char:") {
+        let mut chars = c.chars();
+        return match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(Key::Char(c, modifiers)),
+            _ => None,
+        };
+    }
+
+    let key = match key {
+        "esc" => Key::Escape,
+        "enter" => Key::Enter,
+        "backspace" => Key::Backspace(modifiers),
+        "tab" => Key::Tab,
+        "backtab" => Key::BackTab,
+        "delete" => Key::Delete(modifiers),
+        "home" => Key::Home,
+        "end" => Key::End,
+        "pageup" => Key::PageUp(modifiers),
+        "pagedown" => Key::PageDown(modifiers),
+        "up" => Key::Up(modifiers),
+        "down" => Key::Down(modifiers),
+        "left" => Key::Left(modifiers),
+        "right" => Key::Right(modifiers),
+        #[allow(deprecated)]
+        "any" => Key::Any,
+        _ => return None,
+    };
+
+    Some(key)
+}
+
+/// Terminal recording the keys read from the terminal it wraps.
+///
+/// Pass a mutable reference to the prompts so that the recording can be
+/// retrieved afterwards. Several prompts can be recorded in a row.
+pub struct Recorder<T: Terminal> {
+    terminal: T,
+    last_key: Instant,
+    recording: Recording,
+}
+
+impl<T: Terminal> Recorder<T> {
+    /// Starts recording the keys read from the given terminal.
+    pub fn new(terminal: T) -> Self {
+        Self {
+            terminal,
+            last_key: Instant::now(),
+            recording: Recording::new(),
+        }
+    }
+
+    /// Keys recorded so far.
+    pub fn recording(&self) -> &Recording {
+        &self.recording
+    }
+
+    /// Stops recording, returning the recorded keys.
+    pub fn into_recording(self) -> Recording {
+        self.recording
+    }
+
+    fn record(&mut self, event: &Event) {
+        if let Event::Key(key) = event {
+            let now = Instant::now();
+            self.recording.keys.push(RecordedKey {
+                delay: now.duration_since(self.last_key),
+                key: *key,
+            });
+            self.last_key = now;
+        }
+    }
+}
+
+/// Terminal replaying the keys of a [`Recording`], rendering to the terminal
+/// it wraps.
+///
+/// Keys are read after their recorded delay, scaled by the speed of the
+/// player. Once all keys are replayed, reading another one fails with an
+/// [`ErrorKind::UnexpectedEof`] error.
+pub struct Player<T: Terminal> {
+    terminal: T,
+    keys: VecDeque<RecordedKey>,
+    speed: f64,
+    pending: Option<Duration>,
+}
+
+impl<T: Terminal> Player<T> {
+    /// Creates a player replaying the recording at its original pace.
+    pub fn new(terminal: T, recording: Recording) -> Self {
+        Self {
+            terminal,
+            keys: recording.keys.into(),
+            speed: 1.0,
+            pending: None,
+        }
+    }
+
+    /// Sets the factor the recorded delays are multiplied by: 0.5 replays the
+    /// keys twice as fast, and 0 without any delay.
+    pub fn with_speed(mut self, speed: f64) -> Self {
+        self.speed = speed.max(0.0);
+        self
+    }
+
+    /// Keys that were not replayed yet.
+    pub fn remaining_keys(&self) -> impl Iterator<Item = &RecordedKey> {
+        self.keys.iter()
+    }
+
+    /// Time left to wait before the next key.
+    fn next_delay(&mut self) -> Result<Duration> {
+        let speed = self.speed;
+        match (self.pending, self.keys.front()) {
+            (_, None) => Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                "No recorded keys left",
+            )),
+            (Some(pending), _) => Ok(pending),
+            (None, Some(next)) => Ok(next.delay.mul_f64(speed)),
+        }
+    }
+
+    fn next_key(&mut self) -> Result<Event> {
+        self.pending = None;
+        self.keys
+            .pop_front()
+            .map(|recorded| Event::Key(recorded.key))
+            .ok_or_else(|| Error::new(ErrorKind::UnexpectedEof, "No recorded keys left"))
+    }
+}
+
+macro_rules! forward_output {
+    () => {
+        fn cursor_up(&mut self, cnt: u16) -> Result<()> {
+            self.terminal.cursor_up(cnt)
+        }
+
+        fn cursor_down(&mut self, cnt: u16) -> Result<()> {
+            self.terminal.cursor_down(cnt)
+        }
+
+        fn cursor_move_to_column(&mut self, idx: u16) -> Result<()> {
+            self.terminal.cursor_move_to_column(idx)
+        }
+
+        fn flush(&mut self) -> Result<()> {
+            self.terminal.flush()
+        }
+
+        #[cfg(all(unix, feature = "suspend"))]
+        fn suspend_raw_mode(&mut self) -> Result<()> {
+            self.terminal.suspend_raw_mode()
+        }
+
+        #[cfg(all(unix, feature = "suspend"))]
+        fn resume_raw_mode(&mut self) -> Result<()> {
+            self.terminal.resume_raw_mode()
+        }
+
+        fn get_size(&self) -> Result<TerminalSize> {
+            self.terminal.get_size()
+        }
+
+        fn get_in_memory_content(&self) -> &str {
+            self.terminal.get_in_memory_content()
+        }
+
+        fn clear_in_memory_content(&mut self) {
+            self.terminal.clear_in_memory_content()
+        }
+
+        fn write<V: Display>(&mut self, val: V) -> Result<()> {
+            self.terminal.write(val)
+        }
+
+        fn write_styled<V: Display>(&mut self, val: &Styled<V>) -> Result<()> {
+            self.terminal.write_styled(val)
+        }
+
+        fn clear_current_line(&mut self) -> Result<()> {
+            self.terminal.clear_current_line()
+        }
+
+        fn clear_until_new_line(&mut self) -> Result<()> {
+            self.terminal.clear_until_new_line()
+        }
+
+        fn keeps_rendered_content(&self) -> bool {
+            self.terminal.keeps_rendered_content()
+        }
+
+        fn cursor_hide(&mut self) -> Result<()> {
+            self.terminal.cursor_hide()
+        }
+
+        fn cursor_show(&mut self) -> Result<()> {
+            self.terminal.cursor_show()
+        }
+
+        fn disable_line_wrap(&mut self) -> Result<()> {
+            self.terminal.disable_line_wrap()
+        }
+
+        fn enable_line_wrap(&mut self) -> Result<()> {
+            self.terminal.enable_line_wrap()
+        }
+
+        fn enter_alternate_screen(&mut self) -> Result<()> {
+            self.terminal.enter_alternate_screen()
+        }
+
+        fn leave_alternate_screen(&mut self) -> Result<()> {
+            self.terminal.leave_alternate_screen()
+        }
+
+        fn scroll_down(&mut self, cnt: u16) -> Result<()> {
+            self.terminal.scroll_down(cnt)
+        }
+    };
+}
+
+impl<T: Terminal> Terminal for Recorder<T> {
+    fn read_event(&mut self) -> Result<Event> {
+        let event = self.terminal.read_event()?;
+        self.record(&event);
+        Ok(event)
+    }
+
+    fn can_poll_keys(&self) -> bool {
+        self.terminal.can_poll_keys()
+    }
+
+    fn poll_event(&mut self, timeout: Duration) -> Result<Option<Event>> {
+        let event = self.terminal.poll_event(timeout)?;
+        if let Some(event) = &event {
+            self.record(event);
+        }
+        Ok(event)
+    }
+
+    forward_output!();
+}
+
+impl<T: Terminal> Terminal for Player<T> {
+    fn read_event(&mut self) -> Result<Event> {
+        let delay = self.next_delay()?;
+        thread::sleep(delay);
+        self.next_key()
+    }
+
+    fn can_poll_keys(&self) -> bool {
+        true
+    }
+
+    fn poll_event(&mut self, timeout: Duration) -> Result<Option<Event>> {
+        let delay = self.next_delay()?;
+        if delay > timeout {
+            thread::sleep(timeout);
+            self.pending = Some(delay - timeout);
+            return Ok(None);
+        }
+
+        thread::sleep(delay);
+        self.next_key().map(Some)
+    }
+
+    forward_output!();
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{ParseRecordingError, RecordedKey, Recording};
+    use crate::ui::{Key, KeyModifiers};
+
+    #[test]
+    fn recordings_round_trip_through_text() {
+        let recording = Recording {
+            keys: vec![
+                RecordedKey {
+                    delay: Duration::from_millis(350),
+                    key: Key::Char('c', KeyModifiers::CONTROL),
+                },
+                RecordedKey {
+                    delay: Duration::from_millis(5),
+                    key: Key::Char(' ', KeyModifiers::NONE),
+                },
+                RecordedKey {
+                    delay: Duration::from_millis(80),
+                    key: Key::Up(KeyModifiers::ALT | KeyModifiers::SHIFT),
+                },
+                RecordedKey {
+                    delay: Duration::from_millis(0),
+                    key: Key::Enter,
+                },
+            ],
+        };
+
+        let text = recording.to_string();
+        assert_eq!(
+            "350 ctrl+char:c\n5 char: \n80 alt+shift+up\n0 enter\n",
+            text
+        );
+        assert_eq!(Ok(recording), text.parse());
+    }
+
+    #[test]
+    fn invalid_lines_are_reported() {
+        assert_eq!(
+            Err(ParseRecordingError { line: 2 }),
+            "10 enter\n20 f1\n".parse::<Recording>()
+        );
+        assert_eq!(
+            Err(ParseRecordingError { line: 1 }),
+            "enter".parse::<Recording>()
+        );
+    }
+}
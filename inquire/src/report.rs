@@ -0,0 +1,48 @@
+//! Metadata on how the user interacted with a prompt, for applications that
+//! want to analyze the usability of their flows.
+//!
+//! Every prompt has a `prompt_with_report` method, returning a
+//! [`PromptReport`] along with the answer. When the prompt is not answered,
+//! the returned error tells why, e.g. [`InquireError::OperationCanceled`]
+//! when the user pressed ESC or [`InquireError::Timeout`] when the time ran
+//! out.
+//!
+//! ```
+//! use inquire::{terminal::TestBackend, Text};
+//!
+//! let mut terminal = TestBackend::new(vec![]).with_text("\n");
+//! let (name, report) = Text::new("Name:")
+//!     .with_default("Ferris")
+//!     .prompt_with_report_and_terminal(&mut terminal)?;
+//!
+//! assert_eq!("Ferris", name);
+//! assert_eq!(1, report.keystrokes);
+//! assert!(report.default_accepted);
+//! # Ok::<(), inquire::InquireError>(())
+//! ```
+//!
+//! [`InquireError::OperationCanceled`]: crate::InquireError::OperationCanceled
+//! [`InquireError::Timeout`]: crate::InquireError::Timeout
+
+use std::time::Duration;
+
+/// How the user interacted with an answered prompt.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PromptReport {
+    /// Time between the start of the prompt and its answer.
+    pub elapsed: Duration,
+
+    /// Number of keys pressed by the user, including the one submitting the
+    /// answer.
+    pub keystrokes: usize,
+
+    /// Number of submissions rejected before the answer was accepted, e.g. by
+    /// a validator or because the input could not be parsed.
+    pub validation_failures: usize,
+
+    /// Whether the answer is the default one of the prompt, submitted without
+    /// typing anything or automatically once its timeout expired.
+    ///
+    /// Always `false` for prompts without a default answer.
+    pub default_accepted: bool,
+}
@@ -0,0 +1,194 @@
+//! Terminal title and progress reporting for multi-step flows.
+//!
+//! Long interactive flows, such as a setup wizard, can reflect their progress in
+//! the terminal chrome: the window title is set to the current step, e.g.
+//! `Setup 3/7 — Networking`, and terminals supporting the ConEmu `OSC 9;4`
+//! sequence (Windows Terminal, ConEmu, WezTerm and others) show a progress
+//! indicator in the tab or taskbar.
+//!
+//! Reporting is disabled by default and enabled globally with
+//! [`set_global_status_reporting`](crate::set_global_status_reporting). The
+//! title and progress are cleared once the [`FlowStatus`] is dropped. Nothing
+//! is written when stderr is not a terminal.
+//!
+//! ```no_run
+//! use inquire::{set_global_status_reporting, status::{FlowStatus, StatusReporting}, Text};
+//!
+//! set_global_status_reporting(StatusReporting::all());
+//!
+//! let status = FlowStatus::new("Setup", 2);
+//! status.step(1, "Account");
+//! let name = Text::new("Username:").prompt()?;
+//! status.step(2, "Networking");
+//! let host = Text::new("Host:").prompt()?;
+//! # Ok::<(), inquire::InquireError>(())
+//! ```
+//!
+//! [`Form`](crate::form::Form)s report their progress automatically when given
+//! a name with [`Form::with_status`](crate::form::Form::with_status).
+
+use std::io::{stderr, Write};
+
+use crate::config::get_status_reporting;
+
+/// Which parts of the terminal chrome are updated by a [`FlowStatus`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct StatusReporting {
+    /// Whether the terminal title is set to the current step. The previous
+    /// title is restored afterwards on terminals supporting the xterm title stack.
+    pub title: bool,
+
+    /// Whether the progress is reported with the ConEmu `OSC 9;4` sequence.
+    pub progress: bool,
+}
+
+impl StatusReporting {
+    /// Reports nothing, the default.
+    pub fn disabled() -> Self {
+        Self::default()
+    }
+
+    /// Reports both the title and the progress.
+    pub fn all() -> Self {
+        Self {
+            title: true,
+            progress: true,
+        }
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.title || self.progress
+    }
+}
+
+/// Progress of a multi-step flow reflected in the terminal title and progress
+/// indicator, cleared when dropped.
+///
+/// What is reported is decided by the global [`StatusReporting`] at creation.
+#[derive(Debug)]
+pub struct FlowStatus {
+    name: String,
+    total: usize,
+    reporting: StatusReporting,
+}
+
+impl FlowStatus {
+    /// Starts reporting the flow with the given name and number of steps.
+    pub fn new(name: impl Into<String>, total: usize) -> Self {
+        let mut reporting = get_status_reporting();
+        if !stderr_is_terminal() {
+            reporting = StatusReporting::disabled();
+        }
+
+        if reporting.title {
+            // save the current title on the xterm title stack
+            emit("\x1b[22;0t");
+        }
+
+        Self {
+            name: name.into(),
+            total,
+            reporting,
+        }
+    }
+
+    /// Reports that the flow is at the given 1-based step, described by `label`.
+    pub fn step(&self, step: usize, label: &str) {
+        if self.reporting.is_enabled() {
+            emit(&self.step_sequence(step, label));
+        }
+    }
+
+    fn step_sequence(&self, step: usize, label: &str) -> String {
+        let mut sequence = String::new();
+        if self.reporting.title {
+            sequence.push_str(&format!(
+                "\x1b]0;{} {}/{} — {}\x07",
+                self.name, step, self.total, label
+            ));
+        }
+        if self.reporting.progress {
+            let percent = match self.total {
+                0 => 100,
+                total => step.min(total) * 100 / total,
+            };
+            sequence.push_str(&format!("\x1b]9;4;1;{}\x07", percent));
+        }
+
+        sequence
+    }
+}
+
+impl Drop for FlowStatus {
+    fn drop(&mut self) {
+        if self.reporting.progress {
+            emit("\x1b]9;4;0;0\x07");
+        }
+        if self.reporting.title {
+            // restore the title saved on creation
+            emit("\x1b[23;0t");
+        }
+    }
+}
+
+/// Writes the escape sequence to stderr, ignoring errors as the terminal
+/// chrome is purely cosmetic.
+fn emit(sequence: &str) {
+    let mut stderr = stderr();
+    let _ = stderr.write_all(sequence.as_bytes());
+    let _ = stderr.flush();
+}
+
+#[cfg(unix)]
+fn stderr_is_terminal() -> bool {
+    // SAFETY: `isatty` only inspects the given file descriptor.
+    unsafe { libc::isatty(libc::STDERR_FILENO) == 1 }
+}
+
+#[cfg(not(unix))]
+fn stderr_is_terminal() -> bool {
+    true
+}
+
+#[cfg(test)]
+mod test {
+    use std::mem::ManuallyDrop;
+
+    use super::{FlowStatus, StatusReporting};
+
+    // not dropped, so that no clearing sequences are written to the test output
+    fn status(reporting: StatusReporting, total: usize) -> ManuallyDrop<FlowStatus> {
+        ManuallyDrop::new(FlowStatus {
+            name: String::from("Setup"),
+            total,
+            reporting,
+        })
+    }
+
+    #[test]
+    fn step_sets_title_and_progress() {
+        let status = status(StatusReporting::all(), 7);
+
+        assert_eq!(
+            "\x1b]0;Setup 3/7 — Networking\x07\x1b]9;4;1;42\x07",
+            status.step_sequence(3, "Networking")
+        );
+    }
+
+    #[test]
+    fn step_only_reports_enabled_parts() {
+        let progress = StatusReporting {
+            title: false,
+            progress: true,
+        };
+
+        assert_eq!(
+            "\x1b]9;4;1;100\x07",
+            status(progress, 2).step_sequence(5, "Done")
+        );
+        assert_eq!(
+            "\x1b]9;4;1;100\x07",
+            status(progress, 0).step_sequence(1, "Done")
+        );
+    }
+}
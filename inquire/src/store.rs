@@ -0,0 +1,351 @@
+//! Storage used by features that persist values across prompt executions.
+//!
+//! Features such as input history or remembering the last selected option
+//! need to save small values between runs of an application. Instead of each
+//! of them managing its own files, they all go through a [`PromptStore`],
+//! which applications can point to their own configuration directories or
+//! replace with a custom implementation entirely.
+//!
+//! Values are plain strings, grouped by namespaces so that different features
+//! and prompts do not clash with each other.
+//!
+//! # Example
+//!
+//! ```
+//! use inquire::store::{MemoryStore, PromptStore};
+//!
+//! let store = MemoryStore::new();
+//! store.set("history", "name", "Jane")?;
+//!
+//! assert_eq!(Some(String::from("Jane")), store.get("history", "name")?);
+//! assert_eq!(None, store.get("frecency", "name")?);
+//! # Ok::<(), inquire::InquireError>(())
+//! ```
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use crate::error::{InquireError, InquireResult};
+
+/// Storage of namespaced string values.
+///
+/// Implementations are expected to use interior mutability, as stores are
+/// usually shared between several prompts.
+pub trait PromptStore {
+    /// Returns the value stored under the given key of a namespace, if any.
+    fn get(&self, namespace: &str, key: &str) -> InquireResult<Option<String>>;
+
+    /// Stores a value under the given key of a namespace, replacing any previous value.
+    fn set(&self, namespace: &str, key: &str, value: &str) -> InquireResult<()>;
+
+    /// Removes the value stored under the given key of a namespace, if any.
+    fn remove(&self, namespace: &str, key: &str) -> InquireResult<()>;
+}
+
+/// [`PromptStore`] that keeps values in memory, losing them once dropped.
+///
+/// Useful for tests, or to share state between prompts of a single run.
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    values: Mutex<HashMap<String, HashMap<String, String>>>,
+}
+
+impl MemoryStore {
+    /// Creates an empty [MemoryStore].
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl PromptStore for MemoryStore {
+    fn get(&self, namespace: &str, key: &str) -> InquireResult<Option<String>> {
+        let values = self.values.lock().unwrap();
+
+        Ok(values.get(namespace).and_then(|ns| ns.get(key)).cloned())
+    }
+
+    fn set(&self, namespace: &str, key: &str, value: &str) -> InquireResult<()> {
+        let mut values = self.values.lock().unwrap();
+        values
+            .entry(namespace.to_owned())
+            .or_default()
+            .insert(key.to_owned(), value.to_owned());
+
+        Ok(())
+    }
+
+    fn remove(&self, namespace: &str, key: &str) -> InquireResult<()> {
+        let mut values = self.values.lock().unwrap();
+        if let Some(ns) = values.get_mut(namespace) {
+            ns.remove(key);
+        }
+
+        Ok(())
+    }
+}
+
+/// [`PromptStore`] that persists values in a directory, using one file per namespace.
+///
+/// The directory is created when the first value is stored. Each file contains
+/// one `key=value` entry per line, with backslashes, line breaks and `=` signs
+/// escaped.
+///
+/// Namespaces can not be empty, as they would not name any file. Accessing an
+/// empty namespace returns [`InquireError::InvalidConfiguration`].
+#[derive(Debug)]
+pub struct FileStore {
+    directory: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl FileStore {
+    /// Creates a [FileStore] persisting values in the given directory.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// Directory where values are persisted.
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    fn namespace_path(&self, namespace: &str) -> InquireResult<PathBuf> {
+        if namespace.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "FileStore namespaces can not be empty".into(),
+            ));
+        }
+
+        let mut file_name = String::with_capacity(namespace.len());
+        for byte in namespace.bytes() {
+            match byte {
+                b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'_' => {
+                    file_name.push(byte as char)
+                }
+                byte => file_name.push_str(&format!("%{byte:02X}")),
+            }
+        }
+
+        Ok(self.directory.join(file_name))
+    }
+
+    fn read(&self, namespace: &str) -> InquireResult<BTreeMap<String, String>> {
+        let content = match fs::read_to_string(self.namespace_path(namespace)?) {
+            Ok(content) => content,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(BTreeMap::new()),
+            Err(err) => return Err(err.into()),
+        };
+
+        let entries = content
+            .lines()
+            .filter_map(|line| {
+                let (key, value) = split_entry(line)?;
+                Some((unescape(key), unescape(value)))
+            })
+            .collect();
+
+        Ok(entries)
+    }
+
+    fn write(&self, namespace: &str, entries: &BTreeMap<String, String>) -> InquireResult<()> {
+        let path = self.namespace_path(namespace)?;
+
+        if entries.is_empty() {
+            return match fs::remove_file(path) {
+                Err(err) if err.kind() != ErrorKind::NotFound => Err(err.into()),
+                _ => Ok(()),
+            };
+        }
+
+        let mut content = String::new();
+        for (key, value) in entries {
+            content.push_str(&escape(key));
+            content.push('=');
+            content.push_str(&escape(value));
+            content.push('\n');
+        }
+
+        fs::create_dir_all(&self.directory)?;
+        fs::write(path, content)?;
+
+        Ok(())
+    }
+}
+
+impl PromptStore for FileStore {
+    fn get(&self, namespace: &str, key: &str) -> InquireResult<Option<String>> {
+        let _guard = self.lock.lock().unwrap();
+
+        Ok(self.read(namespace)?.remove(key))
+    }
+
+    fn set(&self, namespace: &str, key: &str, value: &str) -> InquireResult<()> {
+        let _guard = self.lock.lock().unwrap();
+
+        let mut entries = self.read(namespace)?;
+        entries.insert(key.to_owned(), value.to_owned());
+        self.write(namespace, &entries)
+    }
+
+    fn remove(&self, namespace: &str, key: &str) -> InquireResult<()> {
+        let _guard = self.lock.lock().unwrap();
+
+        let mut entries = self.read(namespace)?;
+        if entries.remove(key).is_some() {
+            self.write(namespace, &entries)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '=' => escaped.push_str("\\="),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn unescape(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some(c) => unescaped.push(c),
+            None => unescaped.push('\\'),
+        }
+    }
+
+    unescaped
+}
+
+/// Splits a line on the first `=` that is not escaped.
+fn split_entry(line: &str) -> Option<(&str, &str)> {
+    let mut escaped = false;
+    for (idx, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '=' => return Some((&line[..idx], &line[idx + 1..])),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        fs,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use super::{escape, split_entry, unescape, FileStore, MemoryStore, PromptStore};
+    use crate::InquireError;
+
+    fn temp_store() -> FileStore {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let directory = std::env::temp_dir().join(format!(
+            "inquire-store-test-{}-{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::SeqCst)
+        ));
+        let _ = fs::remove_dir_all(&directory);
+
+        FileStore::new(directory)
+    }
+
+    #[test]
+    fn memory_store_separates_namespaces() {
+        let store = MemoryStore::new();
+        store.set("a", "key", "1").unwrap();
+        store.set("b", "key", "2").unwrap();
+        store.remove("b", "key").unwrap();
+
+        assert_eq!(Some(String::from("1")), store.get("a", "key").unwrap());
+        assert_eq!(None, store.get("b", "key").unwrap());
+    }
+
+    #[test]
+    fn file_store_persists_values() {
+        let store = temp_store();
+        assert_eq!(None, store.get("history", "name").unwrap());
+
+        store.set("history", "name", "Jane\nDoe").unwrap();
+        store.set("history", "a=b", "c=d\\").unwrap();
+        store.set("../escape", "key", "value").unwrap();
+
+        let reopened = FileStore::new(store.directory());
+        assert_eq!(
+            Some(String::from("Jane\nDoe")),
+            reopened.get("history", "name").unwrap()
+        );
+        assert_eq!(
+            Some(String::from("c=d\\")),
+            reopened.get("history", "a=b").unwrap()
+        );
+        assert_eq!(
+            Some(String::from("value")),
+            reopened.get("../escape", "key").unwrap()
+        );
+        assert!(store.directory().join("%2E%2E%2Fescape").exists());
+
+        store.remove("history", "name").unwrap();
+        store.remove("history", "a=b").unwrap();
+        assert_eq!(None, reopened.get("history", "name").unwrap());
+        assert!(!store.directory().join("history").exists());
+
+        fs::remove_dir_all(store.directory()).unwrap();
+    }
+
+    #[test]
+    fn file_store_rejects_empty_namespaces() {
+        let store = temp_store();
+
+        assert!(matches!(
+            store.set("", "key", "value"),
+            Err(InquireError::InvalidConfiguration(_))
+        ));
+        assert!(matches!(
+            store.get("", "key"),
+            Err(InquireError::InvalidConfiguration(_))
+        ));
+        assert!(!store.directory().exists());
+    }
+
+    #[test]
+    fn escaping_roundtrips() {
+        for value in ["", "plain", "a=b", "back\\slash", "line\nbreak\r", "=\\="] {
+            let line = format!("{}={}", escape(value), escape(value));
+            let (key, val) = split_entry(&line).unwrap();
+
+            assert_eq!(value, unescape(key));
+            assert_eq!(value, unescape(val));
+        }
+    }
+}
@@ -2,7 +2,11 @@ use std::io::{Result, Write};
 
 use console::{Attribute, Color, Key, Style, Term};
 
-use crate::ui::{Attributes, StyleSheet, Styled};
+use crate::{
+    diagnostics::{self, CleanupStep},
+    error::{InquireError, InquireResult},
+    ui::{Attributes, Event, StyleSheet, Styled},
+};
 
 use super::{Terminal, INITIAL_IN_MEMORY_CAPACITY};
 
@@ -12,15 +16,33 @@ pub struct ConsoleTerminal {
 }
 
 impl ConsoleTerminal {
+    /// # Errors
+    ///
+    /// Returns [`InquireError::NotTTY`] when stderr is not a terminal, as keys
+    /// could not be read from it.
     #[allow(unused)]
-    pub fn new() -> Self {
-        Self {
-            term: Term::stderr(),
-            in_memory_content: String::with_capacity(INITIAL_IN_MEMORY_CAPACITY),
+    pub fn new() -> InquireResult<Self> {
+        // output is buffered until flushed, so that each frame is rendered
+        // with a single write
+        let term = Term::buffered_stderr();
+        if !term.is_term() {
+            return Err(InquireError::NotTTY);
         }
+
+        Ok(Self {
+            term,
+            in_memory_content: String::with_capacity(INITIAL_IN_MEMORY_CAPACITY),
+        })
     }
 }
 
+#[allow(unused)]
+pub fn restore_terminal() {
+    let term = Term::stderr();
+    let _ = term.show_cursor();
+    let _ = term.write_str("\x1b[?7h");
+}
+
 impl Terminal for ConsoleTerminal {
     fn cursor_up(&mut self, cnt: u16) -> Result<()> {
         self.term.move_cursor_up(cnt as usize)
@@ -38,8 +60,10 @@ impl Terminal for ConsoleTerminal {
         Ok(())
     }
 
-    fn read_key(&mut self) -> Result<crate::ui::Key> {
-        self.term.read_key().map(|k| k.into())
+    fn read_event(&mut self) -> Result<Event> {
+        // reading raw keys reports Ctrl+C as a key instead of raising SIGINT,
+        // so that it is handled like in the other backends
+        self.term.read_key_raw().map(|k| Event::Key(k.into()))
     }
 
     fn flush(&mut self) -> Result<()> {
@@ -53,20 +77,13 @@ impl Terminal for ConsoleTerminal {
     }
 
     fn write<T: std::fmt::Display>(&mut self, val: T) -> Result<()> {
-        let formatted = format!("{}", val);
-        let converted = newline_converter::unix2dos(&formatted);
-
-        self.in_memory_content.push_str(converted.as_ref());
-        write!(self.term, "{}", converted)
+        let content = super::push_content(&mut self.in_memory_content, val);
+        self.term.write_str(content)
     }
 
     fn write_styled<T: std::fmt::Display>(&mut self, val: &Styled<T>) -> Result<()> {
-        let formatted = format!("{}", val.content);
-        let converted = newline_converter::unix2dos(&formatted);
-
-        self.in_memory_content.push_str(converted.as_ref());
-
-        let styled_object = Style::from(val.style).apply_to(converted);
+        let content = super::push_content(&mut self.in_memory_content, &val.content);
+        let styled_object = Style::from(val.style).apply_to(content);
 
         write!(self.term, "{}", styled_object)
     }
@@ -75,6 +92,14 @@ impl Terminal for ConsoleTerminal {
         self.term.clear_line()
     }
 
+    fn clear_until_new_line(&mut self) -> Result<()> {
+        self.term.write_str("\x1b[K")
+    }
+
+    fn keeps_rendered_content(&self) -> bool {
+        true
+    }
+
     fn cursor_hide(&mut self) -> Result<()> {
         self.term.hide_cursor()
     }
@@ -83,6 +108,39 @@ impl Terminal for ConsoleTerminal {
         self.term.show_cursor()
     }
 
+    fn disable_line_wrap(&mut self) -> Result<()> {
+        // console has no built-in method to toggle line wrapping either
+        self.term.write_str("\x1b[?7l")
+    }
+
+    fn enable_line_wrap(&mut self) -> Result<()> {
+        self.term.write_str("\x1b[?7h")
+    }
+
+    fn enter_alternate_screen(&mut self) -> Result<()> {
+        self.term.write_str("\x1b[?1049h")
+    }
+
+    fn leave_alternate_screen(&mut self) -> Result<()> {
+        self.term.write_str("\x1b[?1049l")
+    }
+
+    fn scroll_down(&mut self, cnt: u16) -> Result<()> {
+        self.term.write_str(&format!("\x1b[{}T", cnt))
+    }
+
+    fn bell(&mut self) -> Result<()> {
+        self.term.write_str("\x07")
+    }
+
+    fn flash(&mut self) -> Result<()> {
+        self.term.write_str("\x1b[?5h")?;
+        self.flush()?;
+        std::thread::sleep(super::FLASH_DURATION);
+        self.term.write_str("\x1b[?5l")?;
+        self.flush()
+    }
+
     fn get_in_memory_content(&self) -> &str {
         self.in_memory_content.as_ref()
     }
@@ -94,7 +152,8 @@ impl Terminal for ConsoleTerminal {
 
 impl Drop for ConsoleTerminal {
     fn drop(&mut self) {
-        let _ = self.flush();
+        let result = self.flush();
+        diagnostics::record_cleanup(CleanupStep::Flush, result);
     }
 }
 
@@ -120,6 +179,10 @@ impl From<StyleSheet> for Style {
             style = style.attr(Attribute::Italic);
         }
 
+        if from.att.contains(Attributes::DIM) {
+            style = style.attr(Attribute::Dim);
+        }
+
         style
     }
 }
@@ -150,19 +213,61 @@ impl From<Key> for crate::ui::Key {
             Key::Escape => Self::Escape,
             Key::Char('\n') | Key::Char('\r') | Key::Enter => Self::Enter,
             Key::Char('\t') | Key::Tab => Self::Tab,
-            Key::Backspace => Self::Backspace,
+            Key::BackTab => Self::BackTab,
+            Key::Backspace => Self::Backspace(KeyModifiers::empty()),
             Key::Del => Self::Delete(KeyModifiers::empty()),
             Key::Home => Self::Home,
             Key::End => Self::End,
-            Key::PageUp => Self::PageUp,
-            Key::PageDown => Self::PageDown,
+            Key::PageUp => Self::PageUp(KeyModifiers::empty()),
+            Key::PageDown => Self::PageDown(KeyModifiers::empty()),
             Key::ArrowUp => Self::Up(KeyModifiers::empty()),
             Key::ArrowDown => Self::Down(KeyModifiers::empty()),
             Key::ArrowLeft => Self::Left(KeyModifiers::empty()),
             Key::ArrowRight => Self::Right(KeyModifiers::empty()),
+            Key::CtrlC => Self::Char('c', KeyModifiers::CONTROL),
+            // raw control characters, such as Ctrl+W, are not translated by console
+            Key::Char(c @ '\u{1}'..='\u{1a}') => {
+                let letter = char::from(b'a' + (c as u8 - 1));
+                Self::Char(letter, KeyModifiers::CONTROL)
+            }
+            Key::Char('\u{1f}') => Self::Char('_', KeyModifiers::CONTROL),
             Key::Char(c) => Self::Char(c, KeyModifiers::empty()),
+            // Alt+key is sent as Esc followed by the key
+            Key::UnknownEscSeq(seq) if seq == ['\u{7f}'] => Self::Backspace(KeyModifiers::ALT),
+            Key::UnknownEscSeq(seq) if seq.len() == 1 && seq[0] != '[' => {
+                Self::Char(seq[0], KeyModifiers::ALT)
+            }
             #[allow(deprecated)]
             _ => Self::Any,
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use console::Key;
+
+    use crate::ui::{Key as InquireKey, KeyModifiers};
+
+    #[test]
+    fn control_characters_are_mapped_to_modified_keys() {
+        assert_eq!(
+            InquireKey::Char('c', KeyModifiers::CONTROL),
+            Key::CtrlC.into()
+        );
+        assert_eq!(
+            InquireKey::Char('w', KeyModifiers::CONTROL),
+            Key::Char('\u{17}').into()
+        );
+        assert_eq!(
+            InquireKey::Char('a', KeyModifiers::CONTROL),
+            Key::Char('\u{1}').into()
+        );
+        assert_eq!(InquireKey::Tab, Key::Char('\t').into());
+        assert_eq!(InquireKey::Enter, Key::Char('\r').into());
+        assert_eq!(
+            InquireKey::Char('2', KeyModifiers::ALT),
+            Key::UnknownEscSeq(vec!['2']).into()
+        );
+    }
+}
@@ -1,20 +1,24 @@
-use std::io::{stderr, Result, Stderr, Write};
+use std::{
+    io::{stderr, Result, Stderr, Write},
+    time::{Duration, Instant},
+};
 
 use crossterm::{
     cursor,
     event::{self, KeyCode, KeyEvent, KeyModifiers},
-    queue,
-    style::{Attribute, Color, Print, SetAttribute, SetBackgroundColor, SetForegroundColor},
+    execute, queue,
+    style::{Attribute, Color, SetAttribute, SetBackgroundColor, SetForegroundColor},
     terminal::{self, enable_raw_mode, ClearType},
     Command,
 };
 
 use crate::{
+    diagnostics::{self, CleanupStep},
     error::{InquireError, InquireResult},
-    ui::{Attributes, Key, Styled},
+    ui::{Attributes, Event, Key, Styled},
 };
 
-use super::{Terminal, INITIAL_IN_MEMORY_CAPACITY};
+use super::{Terminal, INITIAL_IN_MEMORY_CAPACITY, INITIAL_OUTPUT_CAPACITY};
 
 enum IO<'a> {
     Std {
@@ -29,6 +33,7 @@ enum IO<'a> {
 
 pub struct CrosstermTerminal<'a> {
     io: IO<'a>,
+    output: Vec<u8>,
     in_memory_content: String,
 }
 
@@ -41,6 +46,7 @@ impl<'a> CrosstermTerminal<'a> {
 
         Ok(Self {
             io: IO::Std { w: stderr() },
+            output: Vec::with_capacity(INITIAL_OUTPUT_CAPACITY),
             in_memory_content: String::with_capacity(INITIAL_IN_MEMORY_CAPACITY),
         })
     }
@@ -58,19 +64,21 @@ impl<'a> CrosstermTerminal<'a> {
                 r: reader,
                 w: writer,
             },
+            output: Vec::with_capacity(INITIAL_OUTPUT_CAPACITY),
             in_memory_content: String::with_capacity(INITIAL_IN_MEMORY_CAPACITY),
         }
     }
 
-    fn get_writer(&mut self) -> &mut dyn Write {
-        match &mut self.io {
-            IO::Std { w } => w,
-            IO::Custom { r: _, w } => w,
+    fn write_command<C: Command>(&mut self, command: C) -> Result<()> {
+        // legacy Windows consoles run commands through the WinAPI right away,
+        // so the output queued before them has to be written first
+        #[cfg(windows)]
+        if !command.is_ansi_code_supported() {
+            self.flush()?;
+            return command.execute_winapi();
         }
-    }
 
-    fn write_command<C: Command>(&mut self, command: C) -> Result<()> {
-        queue!(&mut self.get_writer(), command)
+        queue!(&mut self.output, command)
     }
 
     fn set_attributes(&mut self, attributes: Attributes) -> Result<()> {
@@ -80,6 +88,9 @@ impl<'a> CrosstermTerminal<'a> {
         if attributes.contains(Attributes::ITALIC) {
             self.write_command(SetAttribute(Attribute::Italic))?;
         }
+        if attributes.contains(Attributes::DIM) {
+            self.write_command(SetAttribute(Attribute::Dim))?;
+        }
 
         Ok(())
     }
@@ -105,6 +116,20 @@ impl<'a> CrosstermTerminal<'a> {
     }
 }
 
+/// Converts the crossterm events prompts react to, ignoring the others.
+fn convert_event(event: event::Event) -> Option<Event> {
+    match event {
+        event::Event::Key(key_event) => Some(Event::Key(key_event.into())),
+        event::Event::Resize(_, _) => Some(Event::Resize),
+        _ => None,
+    }
+}
+
+pub fn restore_terminal() {
+    let _ = terminal::disable_raw_mode();
+    let _ = execute!(stderr(), cursor::Show, terminal::EnableLineWrap);
+}
+
 impl<'a> Terminal for CrosstermTerminal<'a> {
     fn cursor_up(&mut self, cnt: u16) -> Result<()> {
         self.write_command(cursor::MoveUp(cnt))
@@ -118,24 +143,75 @@ impl<'a> Terminal for CrosstermTerminal<'a> {
         self.write_command(cursor::MoveToColumn(idx))
     }
 
-    fn read_key(&mut self) -> Result<Key> {
+    fn read_event(&mut self) -> Result<Event> {
         loop {
             match &mut self.io {
                 IO::Std { w: _ } => {
-                    if let event::Event::Key(key_event) = event::read()? {
-                        return Ok(key_event.into());
+                    if let Some(event) = convert_event(event::read()?) {
+                        return Ok(event);
                     }
                 }
                 IO::Custom { r, w: _ } => {
                     let key = r.next().expect("Custom stream of characters has ended");
-                    return Ok((*key).into());
+                    return Ok(Event::Key((*key).into()));
+                }
+            }
+        }
+    }
+
+    fn can_poll_keys(&self) -> bool {
+        true
+    }
+
+    fn poll_event(&mut self, timeout: Duration) -> Result<Option<Event>> {
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            match &mut self.io {
+                IO::Std { w: _ } => {
+                    let remaining = deadline.saturating_duration_since(Instant::now());
+                    if !event::poll(remaining)? {
+                        return Ok(None);
+                    }
+                    if let Some(event) = convert_event(event::read()?) {
+                        return Ok(Some(event));
+                    }
                 }
+                // An exhausted custom stream behaves as a user that stopped typing.
+                IO::Custom { r, w: _ } => return Ok(r.next().map(|key| Event::Key((*key).into()))),
             }
         }
     }
 
     fn flush(&mut self) -> Result<()> {
-        self.get_writer().flush()
+        let w: &mut dyn Write = match &mut self.io {
+            IO::Std { w } => w,
+            IO::Custom { r: _, w } => w,
+        };
+
+        // the queued output is dropped even if it could not be written, so
+        // that the next frame does not repeat a partial one
+        let result = w.write_all(&self.output);
+        self.output.clear();
+        result?;
+
+        w.flush()
+    }
+
+    #[cfg(all(unix, feature = "suspend"))]
+    fn suspend_raw_mode(&mut self) -> Result<()> {
+        match &self.io {
+            IO::Std { w: _ } => terminal::disable_raw_mode(),
+            IO::Custom { r: _, w: _ } => Ok(()),
+        }
+    }
+
+    #[cfg(all(unix, feature = "suspend"))]
+    fn resume_raw_mode(&mut self) -> Result<()> {
+        match &self.io {
+            IO::Std { w: _ } => enable_raw_mode(),
+            IO::Custom { r: _, w: _ } => Ok(()),
+        }
     }
 
     fn get_size(&self) -> Result<super::TerminalSize> {
@@ -143,11 +219,10 @@ impl<'a> Terminal for CrosstermTerminal<'a> {
     }
 
     fn write<T: std::fmt::Display>(&mut self, val: T) -> Result<()> {
-        let formatted = format!("{val}");
-        let converted = newline_converter::unix2dos(&formatted);
-
-        self.in_memory_content.push_str(converted.as_ref());
-        self.write_command(Print(converted))
+        // printing text is the same with and without ANSI support
+        let content = super::push_content(&mut self.in_memory_content, val);
+        self.output.extend_from_slice(content.as_bytes());
+        Ok(())
     }
 
     fn write_styled<T: std::fmt::Display>(&mut self, val: &Styled<T>) -> Result<()> {
@@ -180,6 +255,14 @@ impl<'a> Terminal for CrosstermTerminal<'a> {
         self.write_command(terminal::Clear(ClearType::CurrentLine))
     }
 
+    fn clear_until_new_line(&mut self) -> Result<()> {
+        self.write_command(terminal::Clear(ClearType::UntilNewLine))
+    }
+
+    fn keeps_rendered_content(&self) -> bool {
+        true
+    }
+
     fn cursor_hide(&mut self) -> Result<()> {
         self.write_command(cursor::Hide)
     }
@@ -188,6 +271,45 @@ impl<'a> Terminal for CrosstermTerminal<'a> {
         self.write_command(cursor::Show)
     }
 
+    fn disable_line_wrap(&mut self) -> Result<()> {
+        self.write_command(terminal::DisableLineWrap)
+    }
+
+    fn enable_line_wrap(&mut self) -> Result<()> {
+        self.write_command(terminal::EnableLineWrap)
+    }
+
+    fn enter_alternate_screen(&mut self) -> Result<()> {
+        self.write_command(terminal::EnterAlternateScreen)
+    }
+
+    fn leave_alternate_screen(&mut self) -> Result<()> {
+        self.write_command(terminal::LeaveAlternateScreen)
+    }
+
+    fn scroll_down(&mut self, cnt: u16) -> Result<()> {
+        self.write_command(terminal::ScrollDown(cnt))
+    }
+
+    fn bell(&mut self) -> Result<()> {
+        // legacy Windows consoles ring the bell character as well
+        self.output.push(b'\x07');
+        Ok(())
+    }
+
+    fn flash(&mut self) -> Result<()> {
+        // reverse video has no WinAPI equivalent
+        if super::is_legacy_console() {
+            return Ok(());
+        }
+
+        self.output.extend_from_slice(b"\x1b[?5h");
+        self.flush()?;
+        std::thread::sleep(super::FLASH_DURATION);
+        self.output.extend_from_slice(b"\x1b[?5l");
+        self.flush()
+    }
+
     fn get_in_memory_content(&self) -> &str {
         self.in_memory_content.as_ref()
     }
@@ -199,11 +321,14 @@ impl<'a> Terminal for CrosstermTerminal<'a> {
 
 impl<'a> Drop for CrosstermTerminal<'a> {
     fn drop(&mut self) {
-        let _ = self.flush();
-        let _ = match self.io {
+        let result = self.flush();
+        diagnostics::record_cleanup(CleanupStep::Flush, result);
+
+        let result = match self.io {
             IO::Std { w: _ } => terminal::disable_raw_mode(),
             IO::Custom { r: _, w: _ } => Ok(()),
         };
+        diagnostics::record_cleanup(CleanupStep::DisableRawMode, result);
     }
 }
 
@@ -279,10 +404,15 @@ impl From<KeyEvent> for Key {
                 code: KeyCode::Char('\t'),
                 ..
             } => Self::Tab,
+            KeyEvent {
+                code: KeyCode::BackTab,
+                ..
+            } => Self::BackTab,
             KeyEvent {
                 code: KeyCode::Backspace,
+                modifiers: m,
                 ..
-            } => Self::Backspace,
+            } => Self::Backspace(m.into()),
             KeyEvent {
                 code: KeyCode::Delete,
                 modifiers: m,
@@ -297,12 +427,14 @@ impl From<KeyEvent> for Key {
             } => Self::End,
             KeyEvent {
                 code: KeyCode::PageUp,
+                modifiers: m,
                 ..
-            } => Self::PageUp,
+            } => Self::PageUp(m.into()),
             KeyEvent {
                 code: KeyCode::PageDown,
+                modifiers: m,
                 ..
-            } => Self::PageDown,
+            } => Self::PageDown(m.into()),
             KeyEvent {
                 code: KeyCode::Up,
                 modifiers: m,
@@ -361,6 +493,39 @@ mod test {
         assert_eq!("testing writing wow", std::str::from_utf8(&write).unwrap());
     }
 
+    /// Writer keeping each chunk written to it apart.
+    #[derive(Default)]
+    struct Chunks(Vec<Vec<u8>>);
+
+    impl std::io::Write for Chunks {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.push(buf.to_vec());
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn output_is_written_at_once_when_flushed() {
+        let mut write = Chunks::default();
+        let read = Vec::new();
+        let mut read = read.iter();
+
+        {
+            let mut terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+
+            terminal.write("testing ").unwrap();
+            terminal.cursor_up(1).unwrap();
+            terminal.write("wow").unwrap();
+            terminal.flush().unwrap();
+        }
+
+        assert_eq!(vec![b"testing \x1b[1Awow".to_vec()], write.0);
+    }
+
     #[test]
     fn style_management() {
         let mut write: Vec<u8> = Vec::new();
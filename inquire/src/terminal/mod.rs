@@ -1,51 +1,446 @@
-use std::{fmt::Display, io::Result};
+//! Terminal abstraction that prompts are rendered to and read keys from.
+//!
+//! Prompts use the terminal of the enabled backend feature by default, such as
+//! `crossterm`. Applications that run prompts somewhere else, such as over an
+//! SSH session or in a web terminal, can implement [`Terminal`] and pass it to
+//! the `prompt_with_terminal` method of a prompt. [`TestBackend`] is a
+//! headless implementation answering prompts with scripted keys, to test prompt
+//! flows without a real TTY.
+//!
+//! ```
+//! use std::{fmt::Display, io::Result, vec::IntoIter};
+//!
+//! use inquire::{
+//!     terminal::{Terminal, TerminalSize},
+//!     ui::{Event, Key, KeyModifiers, Styled},
+//!     Confirm,
+//! };
+//!
+//! /// Terminal answering prompts with scripted keys, ignoring styles.
+//! struct ScriptedTerminal {
+//!     keys: IntoIter<Key>,
+//!     content: String,
+//! }
+//!
+//! impl Terminal for ScriptedTerminal {
+//!     fn cursor_up(&mut self, _: u16) -> Result<()> { Ok(()) }
+//!     fn cursor_down(&mut self, _: u16) -> Result<()> { Ok(()) }
+//!     fn cursor_move_to_column(&mut self, _: u16) -> Result<()> { Ok(()) }
+//!     fn read_event(&mut self) -> Result<Event> {
+//!         Ok(Event::Key(self.keys.next().unwrap_or(Key::Escape)))
+//!     }
+//!     fn flush(&mut self) -> Result<()> { Ok(()) }
+//!     fn get_size(&self) -> Result<TerminalSize> {
+//!         Ok(TerminalSize { width: 80, height: 24 })
+//!     }
+//!     fn get_in_memory_content(&self) -> &str { &self.content }
+//!     fn clear_in_memory_content(&mut self) { self.content.clear() }
+//!     fn write<T: Display>(&mut self, val: T) -> Result<()> {
+//!         self.content.push_str(&val.to_string());
+//!         Ok(())
+//!     }
+//!     fn write_styled<T: Display>(&mut self, val: &Styled<T>) -> Result<()> {
+//!         self.write(&val.content)
+//!     }
+//!     fn clear_current_line(&mut self) -> Result<()> { Ok(()) }
+//!     fn cursor_hide(&mut self) -> Result<()> { Ok(()) }
+//!     fn cursor_show(&mut self) -> Result<()> { Ok(()) }
+//! }
+//!
+//! let terminal = ScriptedTerminal {
+//!     keys: vec![Key::Char('y', KeyModifiers::NONE), Key::Enter].into_iter(),
+//!     content: String::new(),
+//! };
+//!
+//! let answer = Confirm::new("Continue?").prompt_with_terminal(terminal)?;
+//! assert!(answer);
+//! # Ok::<(), inquire::InquireError>(())
+//! ```
+
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    io::{Result, Write},
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
 
 use crate::{
     error::InquireResult,
-    ui::{Key, Styled},
+    ui::{Event, Styled},
 };
 
 const INITIAL_IN_MEMORY_CAPACITY: usize = 2048;
 
+/// Initial capacity of the buffers where terminals queue the output of a frame
+/// until it is flushed.
+#[cfg(any(feature = "crossterm", feature = "termion"))]
+const INITIAL_OUTPUT_CAPACITY: usize = 8192;
+
+/// Time the screen stays in reverse video when flashed.
+#[cfg(any(feature = "crossterm", feature = "termion", feature = "console"))]
+const FLASH_DURATION: Duration = Duration::from_millis(100);
+
+/// Appends the value to the in-memory content of a terminal with CRLF line
+/// endings, returning the appended text. Only values with line feeds to
+/// convert are formatted into a temporary buffer.
+fn push_content<T: Display>(content: &mut String, val: T) -> &str {
+    let start = content.len();
+    let _ = fmt::Write::write_fmt(content, format_args!("{val}"));
+
+    if let Cow::Owned(converted) = newline_converter::unix2dos(&content[start..]) {
+        content.truncate(start);
+        content.push_str(&converted);
+    }
+
+    &content[start..]
+}
+
+/// Whether a prompt rendered in fullscreen is currently on the alternate
+/// screen, which [`restore_terminal`] then switches back from.
+pub(crate) static ALTERNATE_SCREEN: AtomicBool = AtomicBool::new(false);
+
 #[cfg(feature = "crossterm")]
-#[cfg_attr(docsrs, doc(cfg(feature = "crossterm")))]
-pub mod crossterm;
+pub(crate) mod crossterm;
 
 #[cfg(feature = "termion")]
-#[cfg_attr(docsrs, doc(cfg(feature = "termion")))]
-pub mod termion;
+pub(crate) mod termion;
 
 #[cfg(feature = "console")]
-#[cfg_attr(docsrs, doc(cfg(feature = "console")))]
-pub mod console;
+pub(crate) mod console;
 
+mod test_backend;
+pub use test_backend::{Frame, TestBackend};
+
+/// Size of a terminal, in columns and rows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct TerminalSize {
+    /// Number of columns.
     pub width: u16,
+    /// Number of rows.
     pub height: u16,
 }
 
+/// Terminal that prompts are rendered to and read keys from.
+///
+/// Prompts expect the terminal to be in raw mode while they run: keys are
+/// reported as soon as they are pressed, without being echoed. Implementations
+/// should enable it when created and restore the terminal when dropped.
+///
+/// Besides writing to the terminal, implementations keep a copy of the content
+/// written since the last call to
+/// [`clear_in_memory_content`](Self::clear_in_memory_content), without styles,
+/// which prompts use to compute the position of the cursor.
 pub trait Terminal: Sized {
+    /// Moves the cursor up by the given number of rows.
     fn cursor_up(&mut self, cnt: u16) -> Result<()>;
+
+    /// Moves the cursor down by the given number of rows.
     fn cursor_down(&mut self, cnt: u16) -> Result<()>;
+
+    /// Moves the cursor to the given column of the current row, starting at 0.
     fn cursor_move_to_column(&mut self, idx: u16) -> Result<()>;
-    fn read_key(&mut self) -> Result<Key>;
+
+    /// Waits for the next input event.
+    fn read_event(&mut self) -> Result<Event>;
+
+    /// Whether [`poll_event`](Self::poll_event) returns once its timeout expires.
+    fn can_poll_keys(&self) -> bool {
+        false
+    }
+
+    /// Waits up to `timeout` for an event, returning `None` if none happened.
+    ///
+    /// Terminals that can not wait for input with a timeout block until a key
+    /// is pressed.
+    fn poll_event(&mut self, timeout: Duration) -> Result<Option<Event>> {
+        let _ = timeout;
+        self.read_event().map(Some)
+    }
+
+    /// Writes any buffered content to the terminal.
+    ///
+    /// Prompts flush the terminal once per frame, so implementations buffering
+    /// their output until then render each frame with a single write, without
+    /// ever showing partially drawn frames.
     fn flush(&mut self) -> Result<()>;
 
+    /// Gives the terminal back to the shell, disabling raw mode if enabled.
+    #[cfg(all(unix, feature = "suspend"))]
+    fn suspend_raw_mode(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Takes the terminal back after [`suspend_raw_mode`](Self::suspend_raw_mode).
+    #[cfg(all(unix, feature = "suspend"))]
+    fn resume_raw_mode(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Current size of the terminal.
     fn get_size(&self) -> Result<TerminalSize>;
 
+    /// Content written since the last call to
+    /// [`clear_in_memory_content`](Self::clear_in_memory_content), without styles.
     fn get_in_memory_content(&self) -> &str;
+
+    /// Clears the copy of the written content, called when a prompt is
+    /// rendered again.
     fn clear_in_memory_content(&mut self);
 
+    /// Writes the value at the cursor position.
+    ///
+    /// Line feeds move the cursor to the start of the next row.
     fn write<T: Display>(&mut self, val: T) -> Result<()>;
+
+    /// Writes the value at the cursor position, applying its style.
     fn write_styled<T: Display>(&mut self, val: &Styled<T>) -> Result<()>;
 
+    /// Clears the row of the cursor.
     fn clear_current_line(&mut self) -> Result<()>;
 
+    /// Clears the row of the cursor from the cursor position to its end.
+    ///
+    /// Only used by terminals [keeping their rendered
+    /// content](Self::keeps_rendered_content).
+    fn clear_until_new_line(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Whether the content written to the terminal stays on screen, so that
+    /// prompts only rewrite the lines that changed since the previous frame
+    /// instead of rendering every frame from scratch.
+    ///
+    /// Terminals returning `true` must also implement
+    /// [`clear_until_new_line`](Self::clear_until_new_line).
+    fn keeps_rendered_content(&self) -> bool {
+        false
+    }
+
+    /// Hides the cursor.
     fn cursor_hide(&mut self) -> Result<()>;
+
+    /// Shows the cursor.
     fn cursor_show(&mut self) -> Result<()>;
+
+    /// Stops wrapping lines longer than the terminal width, clipping them at the
+    /// last column instead.
+    fn disable_line_wrap(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Wraps lines longer than the terminal width again.
+    fn enable_line_wrap(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Switches to the alternate screen buffer, keeping the content of the
+    /// main screen aside.
+    fn enter_alternate_screen(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Switches back to the main screen buffer, as it was before
+    /// [`enter_alternate_screen`](Self::enter_alternate_screen).
+    fn leave_alternate_screen(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Scrolls the content of the screen down by the given number of rows,
+    /// inserting empty rows at the top, without moving the cursor.
+    fn scroll_down(&mut self, cnt: u16) -> Result<()> {
+        let _ = cnt;
+        Ok(())
+    }
+
+    /// Rings the bell of the terminal.
+    fn bell(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Briefly flashes the screen, flushing the content written so far.
+    fn flash(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Forwards to the referenced terminal, so that it can still be inspected after
+/// a prompt consumed the reference.
+impl<T: Terminal> Terminal for &mut T {
+    fn cursor_up(&mut self, cnt: u16) -> Result<()> {
+        (**self).cursor_up(cnt)
+    }
+
+    fn cursor_down(&mut self, cnt: u16) -> Result<()> {
+        (**self).cursor_down(cnt)
+    }
+
+    fn cursor_move_to_column(&mut self, idx: u16) -> Result<()> {
+        (**self).cursor_move_to_column(idx)
+    }
+
+    fn read_event(&mut self) -> Result<Event> {
+        (**self).read_event()
+    }
+
+    fn can_poll_keys(&self) -> bool {
+        (**self).can_poll_keys()
+    }
+
+    fn poll_event(&mut self, timeout: Duration) -> Result<Option<Event>> {
+        (**self).poll_event(timeout)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        (**self).flush()
+    }
+
+    #[cfg(all(unix, feature = "suspend"))]
+    fn suspend_raw_mode(&mut self) -> Result<()> {
+        (**self).suspend_raw_mode()
+    }
+
+    #[cfg(all(unix, feature = "suspend"))]
+    fn resume_raw_mode(&mut self) -> Result<()> {
+        (**self).resume_raw_mode()
+    }
+
+    fn get_size(&self) -> Result<TerminalSize> {
+        (**self).get_size()
+    }
+
+    fn get_in_memory_content(&self) -> &str {
+        (**self).get_in_memory_content()
+    }
+
+    fn clear_in_memory_content(&mut self) {
+        (**self).clear_in_memory_content()
+    }
+
+    fn write<V: Display>(&mut self, val: V) -> Result<()> {
+        (**self).write(val)
+    }
+
+    fn write_styled<V: Display>(&mut self, val: &Styled<V>) -> Result<()> {
+        (**self).write_styled(val)
+    }
+
+    fn clear_current_line(&mut self) -> Result<()> {
+        (**self).clear_current_line()
+    }
+
+    fn clear_until_new_line(&mut self) -> Result<()> {
+        (**self).clear_until_new_line()
+    }
+
+    fn keeps_rendered_content(&self) -> bool {
+        (**self).keeps_rendered_content()
+    }
+
+    fn cursor_hide(&mut self) -> Result<()> {
+        (**self).cursor_hide()
+    }
+
+    fn cursor_show(&mut self) -> Result<()> {
+        (**self).cursor_show()
+    }
+
+    fn disable_line_wrap(&mut self) -> Result<()> {
+        (**self).disable_line_wrap()
+    }
+
+    fn enable_line_wrap(&mut self) -> Result<()> {
+        (**self).enable_line_wrap()
+    }
+
+    fn enter_alternate_screen(&mut self) -> Result<()> {
+        (**self).enter_alternate_screen()
+    }
+
+    fn leave_alternate_screen(&mut self) -> Result<()> {
+        (**self).leave_alternate_screen()
+    }
+
+    fn scroll_down(&mut self, cnt: u16) -> Result<()> {
+        (**self).scroll_down(cnt)
+    }
+
+    fn bell(&mut self) -> Result<()> {
+        (**self).bell()
+    }
+
+    fn flash(&mut self) -> Result<()> {
+        (**self).flash()
+    }
+}
+
+/// Restores the terminal to a usable state without access to the terminal in
+/// use by the running prompt, e.g. from a panic hook or before raising a signal.
+pub(crate) fn restore_terminal() {
+    if ALTERNATE_SCREEN.swap(false, Ordering::SeqCst) {
+        let mut w = std::io::stderr();
+        let _ = write!(w, "\x1b[?1049l");
+        let _ = w.flush();
+    }
+
+    #[cfg(feature = "crossterm")]
+    crossterm::restore_terminal();
+
+    #[cfg(all(feature = "termion", not(feature = "crossterm")))]
+    termion::restore_terminal();
+
+    #[cfg(all(
+        feature = "console",
+        not(feature = "termion"),
+        not(feature = "crossterm")
+    ))]
+    console::restore_terminal();
+}
+
+/// Whether the program runs in a legacy Windows console without support for
+/// ANSI escape codes, such as the conhost of Windows versions older than 10,
+/// where the crossterm terminal falls back to WinAPI calls.
+pub(crate) fn is_legacy_console() -> bool {
+    #[cfg(all(windows, feature = "crossterm"))]
+    return !::crossterm::ansi_support::supports_ansi();
+
+    #[cfg(not(all(windows, feature = "crossterm")))]
+    false
+}
+
+/// Raises SIGINT for the current process.
+#[cfg(unix)]
+pub(crate) fn raise_interrupt_signal() {
+    // SAFETY: raising a signal has no memory safety requirements, whatever the
+    // installed handler does is up to the application.
+    unsafe {
+        libc::raise(libc::SIGINT);
+    }
+}
+
+/// Kills the current process with SIGINT, restoring its default handler first
+/// so that the process does not survive it.
+#[cfg(unix)]
+pub(crate) fn reraise_interrupt_signal() {
+    // SAFETY: resetting the handler and raising a signal have no memory safety
+    // requirements.
+    unsafe {
+        libc::signal(libc::SIGINT, libc::SIG_DFL);
+        libc::raise(libc::SIGINT);
+    }
+}
+
+/// Stops the current process with SIGTSTP, returning once it is continued.
+#[cfg(all(unix, feature = "suspend"))]
+pub(crate) fn raise_suspend_signal() {
+    // SAFETY: raising a signal has no memory safety requirements. With the
+    // default handler, the call only returns after the process receives SIGCONT.
+    unsafe {
+        libc::raise(libc::SIGTSTP);
+    }
 }
 
-pub fn get_default_terminal() -> InquireResult<impl Terminal> {
+pub(crate) fn get_default_terminal() -> InquireResult<impl Terminal> {
     #[cfg(feature = "crossterm")]
     return crossterm::CrosstermTerminal::new();
 
@@ -57,7 +452,7 @@ pub fn get_default_terminal() -> InquireResult<impl Terminal> {
         not(feature = "termion"),
         not(feature = "crossterm")
     ))]
-    return Ok(console::ConsoleTerminal::new());
+    return console::ConsoleTerminal::new();
 
     #[cfg(all(
         not(feature = "crossterm"),
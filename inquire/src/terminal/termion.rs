@@ -1,5 +1,14 @@
 use core::fmt;
-use std::io::{stderr, stdin, Result, Stderr, Stdin, Write};
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    io::{self, stderr, Read, Result, Stderr, Write},
+    rc::Rc,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use lazy_static::lazy_static;
 
 use termion::{
     color::{self, Color},
@@ -11,16 +20,133 @@ use termion::{
 };
 
 use crate::{
+    diagnostics::{self, CleanupStep},
     error::{InquireError, InquireResult},
-    ui::{Attributes, Styled},
+    ui::{Attributes, Event, Styled},
 };
 
-use super::{Terminal, INITIAL_IN_MEMORY_CAPACITY};
+use super::{Terminal, INITIAL_IN_MEMORY_CAPACITY, INITIAL_OUTPUT_CAPACITY};
+
+lazy_static! {
+    /// Terminal settings from before raw mode was enabled, used to restore them
+    /// when the `RawTerminal` that would do so is out of reach.
+    static ref ORIGINAL_TERMIOS: Mutex<Option<libc::termios>> = Mutex::new(None);
+}
+
+/// Unbuffered reader of stdin, so that polling its file descriptor reliably
+/// tells whether keys are pending.
+///
+/// termion reads two bytes at a time to tell a lone Esc from an escape
+/// sequence, and keeps the second one to itself when it starts another key,
+/// out of reach of `poll`. The bytes read from the file descriptor are kept in
+/// a buffer shared with [`StdinKeys`] instead, and only handed out two at a
+/// time when they belong to the same key.
+struct RawStdin {
+    fd: libc::c_int,
+    pending: Rc<RefCell<VecDeque<u8>>>,
+}
+
+impl Read for RawStdin {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let mut pending = self.pending.borrow_mut();
+
+        if pending.is_empty() && !buf.is_empty() {
+            let mut chunk = [0u8; 1024];
+            // SAFETY: `chunk` is valid for writes of `chunk.len()` bytes.
+            let read = unsafe { libc::read(self.fd, chunk.as_mut_ptr().cast(), chunk.len()) };
+
+            match read {
+                -1 => return Err(io::Error::last_os_error()),
+                read => pending.extend(&chunk[..read as usize]),
+            }
+        }
+
+        // an escape sequence, or Alt with a key, always consumes the byte after Esc
+        let len = match pending.front() {
+            Some(b'\x1B') => 2,
+            _ => 1,
+        };
+        let len = len.min(buf.len()).min(pending.len());
+
+        for (slot, byte) in buf.iter_mut().zip(pending.drain(..len)) {
+            *slot = byte;
+        }
+
+        Ok(len)
+    }
+}
+
+/// Keys read from stdin, which can be polled without losing the ones
+/// received in the same read.
+struct StdinKeys {
+    fd: libc::c_int,
+    pending: Rc<RefCell<VecDeque<u8>>>,
+    keys: Keys<RawStdin>,
+}
+
+impl StdinKeys {
+    fn new(fd: libc::c_int) -> Self {
+        let pending = Rc::new(RefCell::new(VecDeque::new()));
+        let keys = RawStdin {
+            fd,
+            pending: pending.clone(),
+        }
+        .keys();
+
+        Self { fd, pending, keys }
+    }
+
+    fn next_key(&mut self) -> Option<Result<Key>> {
+        self.keys.next()
+    }
+
+    /// Waits up to `timeout` for a key, starting with the already read ones.
+    fn poll_key(&mut self, timeout: Duration) -> Result<Option<Key>> {
+        let deadline = Instant::now() + timeout;
+
+        while self.pending.borrow().is_empty() {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if poll_fd(self.fd, remaining)? {
+                break;
+            }
+            if remaining == Duration::ZERO {
+                return Ok(None);
+            }
+        }
+
+        self.next_key().transpose()
+    }
+}
+
+/// Waits up to `timeout` for the file descriptor to be readable.
+fn poll_fd(fd: libc::c_int, timeout: Duration) -> Result<bool> {
+    let mut fd = libc::pollfd {
+        fd,
+        events: libc::POLLIN,
+        revents: 0,
+    };
+    // rounded up, so that the deadline is not missed by less than a millisecond
+    let mut millis = timeout.as_millis();
+    if Duration::from_millis(millis as u64) < timeout {
+        millis += 1;
+    }
+    let millis = millis.min(libc::c_int::MAX as u128) as libc::c_int;
+
+    // SAFETY: `fd` is a single valid `pollfd`.
+    match unsafe { libc::poll(&mut fd, 1, millis) } {
+        -1 => match io::Error::last_os_error() {
+            // interrupted by a signal, e.g. a resize, the caller polls again
+            err if err.kind() == io::ErrorKind::Interrupted => Ok(false),
+            err => Err(err),
+        },
+        ready => Ok(ready > 0),
+    }
+}
 
 enum IO<'a> {
     #[allow(unused)]
     Std {
-        r: Keys<Stdin>,
+        r: StdinKeys,
         w: RawTerminal<Stderr>,
     },
     #[allow(unused)]
@@ -32,12 +158,21 @@ enum IO<'a> {
 
 pub struct TermionTerminal<'a> {
     io: IO<'a>,
+    output: Vec<u8>,
     in_memory_content: String,
 }
 
 impl<'a> TermionTerminal<'a> {
     #[allow(unused)]
     pub fn new() -> InquireResult<Self> {
+        // SAFETY: `termios` is a plain C struct, fully written by `tcgetattr` on success.
+        unsafe {
+            let mut termios = std::mem::zeroed();
+            if libc::tcgetattr(libc::STDERR_FILENO, &mut termios) == 0 {
+                *ORIGINAL_TERMIOS.lock().unwrap() = Some(termios);
+            }
+        }
+
         let raw_mode = stderr()
             .into_raw_mode()
             .map_err(|e| match e.raw_os_error() {
@@ -47,9 +182,10 @@ impl<'a> TermionTerminal<'a> {
 
         Ok(Self {
             io: IO::Std {
-                r: stdin().keys(),
+                r: StdinKeys::new(libc::STDIN_FILENO),
                 w: raw_mode?,
             },
+            output: Vec::with_capacity(INITIAL_OUTPUT_CAPACITY),
             in_memory_content: String::with_capacity(INITIAL_IN_MEMORY_CAPACITY),
         })
     }
@@ -67,15 +203,14 @@ impl<'a> TermionTerminal<'a> {
                 r: reader,
                 w: writer,
             },
+            output: Vec::with_capacity(INITIAL_OUTPUT_CAPACITY),
             in_memory_content: String::with_capacity(INITIAL_IN_MEMORY_CAPACITY),
         }
     }
 
+    /// Output queued until the terminal is flushed.
     fn get_writer(&mut self) -> &mut dyn Write {
-        match &mut self.io {
-            IO::Std { r: _, w } => w,
-            IO::Custom { r: _, w } => w,
-        }
+        &mut self.output
     }
 
     fn set_attributes(&mut self, attributes: Attributes) -> Result<()> {
@@ -85,6 +220,9 @@ impl<'a> TermionTerminal<'a> {
         if attributes.contains(Attributes::ITALIC) {
             write!(self.get_writer(), "{}", termion::style::Italic)?;
         }
+        if attributes.contains(Attributes::DIM) {
+            write!(self.get_writer(), "{}", termion::style::Faint)?;
+        }
 
         Ok(())
     }
@@ -110,6 +248,20 @@ impl<'a> TermionTerminal<'a> {
     }
 }
 
+#[allow(unused)]
+pub fn restore_terminal() {
+    if let Some(termios) = *ORIGINAL_TERMIOS.lock().unwrap() {
+        // SAFETY: the settings were read by `tcgetattr` from the same file descriptor.
+        unsafe {
+            libc::tcsetattr(libc::STDERR_FILENO, libc::TCSANOW, &termios);
+        }
+    }
+
+    let mut w = stderr();
+    let _ = write!(w, "{}\x1b[?7h", cursor::Show);
+    let _ = w.flush();
+}
+
 impl<'a> Terminal for TermionTerminal<'a> {
     fn cursor_up(&mut self, cnt: u16) -> Result<()> {
         write!(self.get_writer(), "{}", cursor::Up(cnt))
@@ -123,24 +275,63 @@ impl<'a> Terminal for TermionTerminal<'a> {
         write!(self.get_writer(), "\x1b[{}G", idx.saturating_add(1))
     }
 
-    fn read_key(&mut self) -> Result<crate::ui::Key> {
+    fn read_event(&mut self) -> Result<Event> {
         loop {
             match &mut self.io {
                 IO::Std { r, w: _ } => {
-                    if let Some(key) = r.next() {
-                        return key.map(|k| k.into());
+                    if let Some(key) = r.next_key() {
+                        return key.map(|k| Event::Key(k.into()));
                     }
                 }
                 IO::Custom { r, w: _ } => {
                     let key = r.next().expect("Custom stream of characters has ended");
-                    return Ok((*key).into());
+                    return Ok(Event::Key((*key).into()));
                 }
             }
         }
     }
 
+    fn can_poll_keys(&self) -> bool {
+        true
+    }
+
+    fn poll_event(&mut self, timeout: Duration) -> Result<Option<Event>> {
+        match &mut self.io {
+            IO::Std { r, w: _ } => Ok(r.poll_key(timeout)?.map(|k| Event::Key(k.into()))),
+            // An exhausted custom stream behaves as a user that stopped typing.
+            IO::Custom { r, w: _ } => Ok(r.next().map(|key| Event::Key((*key).into()))),
+        }
+    }
+
     fn flush(&mut self) -> Result<()> {
-        self.get_writer().flush()
+        let w: &mut dyn Write = match &mut self.io {
+            IO::Std { r: _, w } => w,
+            IO::Custom { r: _, w } => w,
+        };
+
+        // the queued output is dropped even if it could not be written, so
+        // that the next frame does not repeat a partial one
+        let result = w.write_all(&self.output);
+        self.output.clear();
+        result?;
+
+        w.flush()
+    }
+
+    #[cfg(all(unix, feature = "suspend"))]
+    fn suspend_raw_mode(&mut self) -> Result<()> {
+        match &self.io {
+            IO::Std { r: _, w } => w.suspend_raw_mode(),
+            IO::Custom { r: _, w: _ } => Ok(()),
+        }
+    }
+
+    #[cfg(all(unix, feature = "suspend"))]
+    fn resume_raw_mode(&mut self) -> Result<()> {
+        match &self.io {
+            IO::Std { r: _, w } => w.activate_raw_mode(),
+            IO::Custom { r: _, w: _ } => Ok(()),
+        }
     }
 
     fn get_size(&self) -> Result<super::TerminalSize> {
@@ -148,11 +339,9 @@ impl<'a> Terminal for TermionTerminal<'a> {
     }
 
     fn write<T: std::fmt::Display>(&mut self, val: T) -> Result<()> {
-        let formatted = format!("{}", val);
-        let converted = newline_converter::unix2dos(&formatted);
-
-        self.in_memory_content.push_str(converted.as_ref());
-        write!(self.get_writer(), "{}", converted)
+        let content = super::push_content(&mut self.in_memory_content, val);
+        self.output.extend_from_slice(content.as_bytes());
+        Ok(())
     }
 
     fn write_styled<T: std::fmt::Display>(&mut self, val: &Styled<T>) -> Result<()> {
@@ -185,6 +374,14 @@ impl<'a> Terminal for TermionTerminal<'a> {
         write!(self.get_writer(), "{}", termion::clear::CurrentLine)
     }
 
+    fn clear_until_new_line(&mut self) -> Result<()> {
+        write!(self.get_writer(), "{}", termion::clear::UntilNewline)
+    }
+
+    fn keeps_rendered_content(&self) -> bool {
+        true
+    }
+
     fn cursor_hide(&mut self) -> Result<()> {
         write!(self.get_writer(), "{}", termion::cursor::Hide)
     }
@@ -193,6 +390,38 @@ impl<'a> Terminal for TermionTerminal<'a> {
         write!(self.get_writer(), "{}", termion::cursor::Show)
     }
 
+    fn disable_line_wrap(&mut self) -> Result<()> {
+        write!(self.get_writer(), "\x1b[?7l")
+    }
+
+    fn enable_line_wrap(&mut self) -> Result<()> {
+        write!(self.get_writer(), "\x1b[?7h")
+    }
+
+    fn enter_alternate_screen(&mut self) -> Result<()> {
+        write!(self.get_writer(), "{}", termion::screen::ToAlternateScreen)
+    }
+
+    fn leave_alternate_screen(&mut self) -> Result<()> {
+        write!(self.get_writer(), "{}", termion::screen::ToMainScreen)
+    }
+
+    fn scroll_down(&mut self, cnt: u16) -> Result<()> {
+        write!(self.get_writer(), "{}", termion::scroll::Down(cnt))
+    }
+
+    fn bell(&mut self) -> Result<()> {
+        write!(self.get_writer(), "\x07")
+    }
+
+    fn flash(&mut self) -> Result<()> {
+        write!(self.get_writer(), "\x1b[?5h")?;
+        self.flush()?;
+        std::thread::sleep(super::FLASH_DURATION);
+        write!(self.get_writer(), "\x1b[?5l")?;
+        self.flush()
+    }
+
     fn get_in_memory_content(&self) -> &str {
         self.in_memory_content.as_ref()
     }
@@ -204,7 +433,8 @@ impl<'a> Terminal for TermionTerminal<'a> {
 
 impl<'a> Drop for TermionTerminal<'a> {
     fn drop(&mut self) {
-        let _ = self.flush();
+        let result = self.flush();
+        diagnostics::record_cleanup(CleanupStep::Flush, result);
     }
 }
 
@@ -252,18 +482,20 @@ impl From<Key> for crate::ui::Key {
             Key::Esc => Self::Escape,
             Key::Char('\n') | Key::Char('\r') => Self::Enter,
             Key::Char('\t') => Self::Tab,
-            Key::Backspace => Self::Backspace,
+            Key::BackTab => Self::BackTab,
+            Key::Backspace => Self::Backspace(KeyModifiers::empty()),
             Key::Delete => Self::Delete(KeyModifiers::empty()),
             Key::Home => Self::Home,
             Key::End => Self::End,
-            Key::PageUp => Self::PageUp,
-            Key::PageDown => Self::PageDown,
+            Key::PageUp => Self::PageUp(KeyModifiers::empty()),
+            Key::PageDown => Self::PageDown(KeyModifiers::empty()),
             Key::Up => Self::Up(KeyModifiers::empty()),
             Key::Down => Self::Down(KeyModifiers::empty()),
             Key::Left => Self::Left(KeyModifiers::empty()),
             Key::Right => Self::Right(KeyModifiers::empty()),
             Key::Char(c) => Self::Char(c, KeyModifiers::empty()),
             Key::Ctrl(c) => Self::Char(c, KeyModifiers::CONTROL),
+            Key::Alt('\u{7f}') => Self::Backspace(KeyModifiers::ALT),
             Key::Alt(c) => Self::Char(c, KeyModifiers::ALT),
             #[allow(deprecated)]
             _ => Self::Any,
@@ -273,11 +505,59 @@ impl From<Key> for crate::ui::Key {
 
 #[cfg(test)]
 mod test {
+    use std::time::Duration;
+
+    use termion::event::Key;
+
     use crate::terminal::Terminal;
-    use crate::ui::Color;
+    use crate::ui::{Color, Event};
 
     use super::Attributes;
-    use super::TermionTerminal;
+    use super::{StdinKeys, TermionTerminal};
+
+    #[test]
+    fn poll_event_returns_pending_keys() {
+        let mut write: Vec<u8> = Vec::new();
+        let read = [Key::Char('a')];
+        let mut read = read.iter();
+
+        let mut terminal = TermionTerminal::new_with_io(&mut write, &mut read);
+
+        assert!(terminal.can_poll_keys());
+        assert_eq!(
+            Some(Event::Key(crate::ui::Key::Char(
+                'a',
+                crate::ui::KeyModifiers::empty()
+            ))),
+            terminal.poll_event(Duration::ZERO).unwrap()
+        );
+        assert_eq!(None, terminal.poll_event(Duration::ZERO).unwrap());
+    }
+
+    #[test]
+    fn poll_key_returns_keys_received_in_the_same_read() {
+        let mut fds = [0; 2];
+        // SAFETY: `fds` is valid for writes of two file descriptors.
+        assert_eq!(0, unsafe { libc::pipe(fds.as_mut_ptr()) });
+        let [read_fd, write_fd] = fds;
+
+        let input = b"\x1B[Aab";
+        // SAFETY: `input` is valid for reads of `input.len()` bytes.
+        let written = unsafe { libc::write(write_fd, input.as_ptr().cast(), input.len()) };
+        assert_eq!(input.len() as isize, written);
+
+        let mut keys = StdinKeys::new(read_fd);
+        assert_eq!(Some(Key::Up), keys.poll_key(Duration::ZERO).unwrap());
+        assert_eq!(Some(Key::Char('a')), keys.poll_key(Duration::ZERO).unwrap());
+        assert_eq!(Some(Key::Char('b')), keys.poll_key(Duration::ZERO).unwrap());
+        assert_eq!(None, keys.poll_key(Duration::ZERO).unwrap());
+
+        // SAFETY: both file descriptors were opened by `pipe` above.
+        unsafe {
+            libc::close(read_fd);
+            libc::close(write_fd);
+        }
+    }
 
     #[test]
     fn writer() {
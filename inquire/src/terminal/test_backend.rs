@@ -0,0 +1,393 @@
+use std::{
+    collections::VecDeque,
+    fmt::{self, Display, Write},
+    io::{Error, ErrorKind, Result},
+};
+
+use crate::ui::{Attributes, Event, Key, KeyModifiers, StyleSheet, Styled};
+
+use super::{Terminal, TerminalSize, INITIAL_IN_MEMORY_CAPACITY};
+
+/// Headless terminal that answers prompts with a scripted sequence of keys and
+/// records every rendered frame, to test prompt flows without a real TTY.
+///
+/// Frames are recorded with `\n` line endings, either as plain text with
+/// [`frames`](Self::frames) or along with their styles with
+/// [`styled_frames`](Self::styled_frames), see [`Frame`].
+/// Once all scripted keys are consumed, reading another key fails with an
+/// [`ErrorKind::UnexpectedEof`] error, which the prompt returns as
+/// [`InquireError::IO`](crate::InquireError::IO).
+///
+/// Pass a mutable reference to the prompt so that the frames can be inspected
+/// afterwards:
+///
+/// ```
+/// use inquire::{terminal::TestBackend, ui::Key, Text};
+///
+/// let mut terminal = TestBackend::new(vec![Key::Enter]).with_text("Ferris");
+/// terminal.push_key(Key::Enter);
+///
+/// // the first Enter submits the empty input, which is rejected
+/// let name = Text::new("Name:")
+///     .with_validator(inquire::required!())
+///     .prompt_with_terminal(&mut terminal)?;
+///
+/// assert_eq!("Ferris", name);
+/// assert_eq!("> Name: Ferris\n", terminal.last_frame().unwrap());
+/// assert!(terminal.frames().iter().any(|frame| frame.contains("A response is required.")));
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct TestBackend {
+    keys: VecDeque<Key>,
+    size: TerminalSize,
+    frames: Vec<Frame>,
+    current_frame: Frame,
+    in_memory_content: String,
+    bells: usize,
+    flashes: usize,
+}
+
+impl TestBackend {
+    /// Creates a terminal of 80 columns and 24 rows that answers prompts with
+    /// the given keys.
+    pub fn new<I>(keys: I) -> Self
+    where
+        I: IntoIterator<Item = Key>,
+    {
+        Self {
+            keys: keys.into_iter().collect(),
+            size: TerminalSize {
+                width: 80,
+                height: 24,
+            },
+            frames: vec![],
+            current_frame: Frame::default(),
+            in_memory_content: String::with_capacity(INITIAL_IN_MEMORY_CAPACITY),
+            bells: 0,
+            flashes: 0,
+        }
+    }
+
+    /// Sets the size of the terminal reported to prompts.
+    pub fn with_size(mut self, width: u16, height: u16) -> Self {
+        self.size = TerminalSize { width, height };
+        self
+    }
+
+    /// Appends a key for each character of the text to the scripted keys, with
+    /// line feeds mapped to [`Key::Enter`].
+    pub fn with_text(mut self, text: &str) -> Self {
+        self.push_text(text);
+        self
+    }
+
+    /// Appends the key to the scripted keys.
+    pub fn push_key(&mut self, key: Key) {
+        self.keys.push_back(key);
+    }
+
+    /// Appends a key for each character of the text to the scripted keys, with
+    /// line feeds mapped to [`Key::Enter`].
+    pub fn push_text(&mut self, text: &str) {
+        self.keys.extend(text.chars().map(|c| match c {
+            '\n' => Key::Enter,
+            c => Key::Char(c, KeyModifiers::NONE),
+        }));
+    }
+
+    /// Scripted keys that were not read by a prompt yet.
+    pub fn remaining_keys(&self) -> impl Iterator<Item = &Key> {
+        self.keys.iter()
+    }
+
+    /// Frames rendered so far as plain text, oldest first.
+    pub fn frames(&self) -> Vec<String> {
+        self.styled_frames().iter().map(Frame::text).collect()
+    }
+
+    /// Last frame rendered as plain text, usually the prompt with the submitted
+    /// answer.
+    pub fn last_frame(&self) -> Option<String> {
+        self.frames().pop()
+    }
+
+    /// Number of times the bell was rung, see
+    /// [`Feedback::Bell`](crate::ui::Feedback::Bell).
+    pub fn bells(&self) -> usize {
+        self.bells
+    }
+
+    /// Number of times the screen was flashed, see
+    /// [`Feedback::Flash`](crate::ui::Feedback::Flash).
+    pub fn flashes(&self) -> usize {
+        self.flashes
+    }
+
+    /// Frames rendered so far along with their styles, oldest first.
+    pub fn styled_frames(&self) -> Vec<Frame> {
+        let mut frames = self.frames.clone();
+        if !self.current_frame.spans.is_empty() {
+            frames.push(self.current_frame.clone());
+        }
+        frames
+    }
+}
+
+/// Frame rendered by a prompt, recorded by [`TestBackend`].
+///
+/// Besides the plain text, a frame can be exported with [`annotated`](Self::annotated)
+/// as text in which styled spans are enclosed in tags describing their style,
+/// which makes colors and attributes visible in snapshot tests, e.g. with
+/// `insta`:
+///
+/// ```
+/// use inquire::{terminal::TestBackend, ui::RenderConfig, Confirm};
+///
+/// let mut terminal = TestBackend::new(vec![]).with_text("y\n");
+/// Confirm::new("Continue?")
+///     .with_render_config(RenderConfig::default_colored())
+///     .prompt_with_terminal(&mut terminal)?;
+///
+/// let frame = terminal.styled_frames().pop().unwrap();
+/// assert_eq!("> Continue? Yes\n", frame.text());
+/// assert_eq!(
+///     "<fg=LightGreen>></> Continue? <fg=LightCyan>Yes</>\n",
+///     frame.annotated()
+/// );
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Frame {
+    spans: Vec<Styled<String>>,
+}
+
+impl Frame {
+    /// Spans of text the frame is made of, each with the style it was
+    /// rendered with. Consecutive spans have different styles.
+    pub fn spans(&self) -> &[Styled<String>] {
+        &self.spans
+    }
+
+    /// Content of the frame without styles.
+    pub fn text(&self) -> String {
+        self.spans
+            .iter()
+            .map(|span| span.content.as_str())
+            .collect()
+    }
+
+    /// Content of the frame where each styled span is enclosed in a
+    /// `<style>` tag and a `</>` closing tag, the style listing the foreground
+    /// color as `fg=Color`, the background color as `bg=Color` and the
+    /// attributes, such as `bold`, separated by spaces.
+    pub fn annotated(&self) -> String {
+        let mut annotated = String::new();
+
+        for span in &self.spans {
+            match span.style.is_empty() {
+                true => annotated.push_str(&span.content),
+                false => {
+                    let _ = write!(annotated, "<{}>{}</>", StyleTag(span.style), span.content);
+                }
+            }
+        }
+
+        annotated
+    }
+
+    fn push<T: Display>(&mut self, content: T, style: StyleSheet) {
+        let content = content.to_string().replace("\r\n", "\n");
+        if content.is_empty() {
+            return;
+        }
+
+        match self.spans.last_mut() {
+            Some(last) if last.style == style => last.content.push_str(&content),
+            _ => self
+                .spans
+                .push(Styled::new(content).with_style_sheet(style)),
+        }
+    }
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.annotated())
+    }
+}
+
+struct StyleTag(StyleSheet);
+
+impl Display for StyleTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = vec![];
+        if let Some(fg) = self.0.fg {
+            parts.push(format!("fg={:?}", fg));
+        }
+        if let Some(bg) = self.0.bg {
+            parts.push(format!("bg={:?}", bg));
+        }
+        if self.0.att.contains(Attributes::BOLD) {
+            parts.push("bold".into());
+        }
+        if self.0.att.contains(Attributes::ITALIC) {
+            parts.push("italic".into());
+        }
+        if self.0.att.contains(Attributes::DIM) {
+            parts.push("dim".into());
+        }
+
+        f.write_str(&parts.join(" "))
+    }
+}
+
+impl Terminal for TestBackend {
+    fn cursor_up(&mut self, _cnt: u16) -> Result<()> {
+        Ok(())
+    }
+
+    fn cursor_down(&mut self, _cnt: u16) -> Result<()> {
+        Ok(())
+    }
+
+    fn cursor_move_to_column(&mut self, _idx: u16) -> Result<()> {
+        Ok(())
+    }
+
+    fn read_event(&mut self) -> Result<Event> {
+        self.keys
+            .pop_front()
+            .map(Event::Key)
+            .ok_or_else(|| Error::new(ErrorKind::UnexpectedEof, "No scripted keys left"))
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn get_size(&self) -> Result<TerminalSize> {
+        Ok(self.size)
+    }
+
+    fn get_in_memory_content(&self) -> &str {
+        &self.in_memory_content
+    }
+
+    fn clear_in_memory_content(&mut self) {
+        let frame = std::mem::take(&mut self.current_frame);
+        if !frame.spans.is_empty() {
+            self.frames.push(frame);
+        }
+        self.in_memory_content.clear();
+    }
+
+    fn write<T: Display>(&mut self, val: T) -> Result<()> {
+        let content = val.to_string();
+        self.in_memory_content.push_str(&content);
+        self.current_frame.push(content, StyleSheet::empty());
+        Ok(())
+    }
+
+    fn write_styled<T: Display>(&mut self, val: &Styled<T>) -> Result<()> {
+        let content = val.content.to_string();
+        self.in_memory_content.push_str(&content);
+        self.current_frame.push(content, val.style);
+        Ok(())
+    }
+
+    fn clear_current_line(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn cursor_hide(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn cursor_show(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn bell(&mut self) -> Result<()> {
+        self.bells += 1;
+        Ok(())
+    }
+
+    fn flash(&mut self) -> Result<()> {
+        self.flashes += 1;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::TestBackend;
+    use crate::{
+        error::InquireError,
+        terminal::Terminal,
+        ui::{Attributes, Color, Key, KeyModifiers, StyleSheet, Styled},
+        Confirm, Select,
+    };
+
+    #[test]
+    fn frames_are_recorded_per_render() {
+        let mut terminal = TestBackend::new(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+        let answer = Select::new("Fruit:", vec!["Apple", "Banana"])
+            .without_help_message()
+            .prompt_with_terminal(&mut terminal)
+            .unwrap();
+
+        assert_eq!("Banana", answer);
+        assert_eq!(
+            vec![
+                "? Fruit:  \n> Apple\n  Banana\n",
+                "? Fruit:  \n  Apple\n> Banana\n",
+                "> Fruit: Banana\n",
+            ],
+            terminal.frames()
+        );
+        assert_eq!(0, terminal.remaining_keys().count());
+    }
+
+    #[test]
+    fn running_out_of_keys_is_an_error() {
+        let mut terminal = TestBackend::new(vec![]).with_text("y");
+
+        let result = Confirm::new("Continue?").prompt_with_terminal(&mut terminal);
+
+        match result {
+            Err(InquireError::IO(err)) => {
+                assert_eq!(std::io::ErrorKind::UnexpectedEof, err.kind())
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn spans_with_the_same_style_are_merged() {
+        let mut terminal = TestBackend::new(vec![]);
+        let bold = StyleSheet::new()
+            .with_fg(Color::rgb(1, 2, 3))
+            .with_attr(Attributes::BOLD);
+
+        terminal.write("plain ").unwrap();
+        terminal.write("text\r\n").unwrap();
+        terminal
+            .write_styled(&Styled::new("bo").with_style_sheet(bold))
+            .unwrap();
+        terminal
+            .write_styled(&Styled::new("ld").with_style_sheet(bold))
+            .unwrap();
+        terminal.clear_in_memory_content();
+
+        let frames = terminal.styled_frames();
+        assert_eq!(1, frames.len());
+        assert_eq!(2, frames[0].spans().len());
+        assert_eq!("plain text\nbold", frames[0].text());
+        assert_eq!(
+            "plain text\n<fg=Rgb { r: 1, g: 2, b: 3 } bold>bold</>",
+            frames[0].annotated()
+        );
+    }
+}
@@ -0,0 +1,215 @@
+//! Measuring, truncating and padding text by the number of terminal columns it
+//! takes, as done when rendering prompts.
+//!
+//! Widths are computed per grapheme cluster, so that double-width characters
+//! such as `日` take two columns, combining accents none and emoji sequences
+//! such as `👨‍👩‍👧` a single double-width glyph. Custom formatters and preview
+//! callbacks can use these functions to align their output with the rest of
+//! the prompt.
+//!
+//! ```
+//! use inquire::text::{column_widths, fit_to_width, text_width, truncate_to_width};
+//!
+//! assert_eq!(4, text_width("日本"));
+//! assert_eq!("deplo…", truncate_to_width("deployment", 6));
+//!
+//! let rows = vec![vec!["api", "Running"], vec!["worker-日本", "Pending"]];
+//! let widths = column_widths(&rows);
+//! assert_eq!(vec![11, 7], widths);
+//!
+//! let table: Vec<String> = rows
+//!     .iter()
+//!     .map(|row| {
+//!         row.iter()
+//!             .zip(&widths)
+//!             .map(|(cell, width)| fit_to_width(cell, *width))
+//!             .collect::<Vec<_>>()
+//!             .join("  ")
+//!     })
+//!     .collect();
+//! assert_eq!("api          Running", table[0]);
+//! assert_eq!("worker-日本  Pending", table[1]);
+//! ```
+//!
+//! ANSI escape codes are measured as regular characters, they should be
+//! stripped from the text beforehand.
+
+use std::borrow::Cow;
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Number of columns a grapheme cluster takes when rendered.
+///
+/// Emoji sequences, such as a family joined by zero-width joiners or a symbol
+/// followed by the emoji presentation selector, render as a single double-width
+/// glyph regardless of the widths of the characters they are made of.
+pub fn grapheme_width(grapheme: &str) -> usize {
+    let width = grapheme.width();
+
+    if width > 0 && grapheme.contains(['\u{200d}', '\u{fe0f}'].as_ref()) {
+        2
+    } else {
+        width
+    }
+}
+
+/// Number of columns the text takes when rendered, see [`grapheme_width`].
+pub fn text_width(text: &str) -> usize {
+    text.graphemes(true).map(grapheme_width).sum()
+}
+
+/// Truncates the text to at most `width` columns, replacing the end of the
+/// text with an ellipsis when it does not fit.
+pub fn truncate_to_width(text: &str, width: usize) -> Cow<'_, str> {
+    truncate_with_ellipsis(text, width, "…")
+}
+
+/// Truncates the text like [`truncate_to_width`], ending it with the given
+/// ellipsis instead, e.g. `...` for terminals rendering only ASCII. The
+/// ellipsis is left out when it does not fit in `width` columns itself.
+///
+/// ```
+/// use inquire::text::truncate_with_ellipsis;
+///
+/// assert_eq!("deplo...", truncate_with_ellipsis("deployment", 8, "..."));
+/// assert_eq!("de", truncate_with_ellipsis("deployment", 2, "..."));
+/// ```
+pub fn truncate_with_ellipsis<'t>(text: &'t str, width: usize, ellipsis: &str) -> Cow<'t, str> {
+    if text_width(text) <= width {
+        return Cow::Borrowed(text);
+    }
+
+    let ellipsis = match text_width(ellipsis) <= width {
+        true => ellipsis,
+        false => "",
+    };
+    let available = width - text_width(ellipsis);
+    let mut truncated = String::with_capacity(text.len());
+    let mut used = 0;
+
+    for grapheme in text.graphemes(true) {
+        let len = grapheme_width(grapheme);
+        if used + len > available {
+            break;
+        }
+        used += len;
+        truncated.push_str(grapheme);
+    }
+
+    truncated.push_str(ellipsis);
+
+    Cow::Owned(truncated)
+}
+
+/// Pads the text with spaces on its right up to `width` columns. Text already
+/// as wide is returned as is, even when wider.
+pub fn pad_to_width(text: &str, width: usize) -> Cow<'_, str> {
+    let padding = width.saturating_sub(text_width(text));
+
+    match padding {
+        0 => Cow::Borrowed(text),
+        padding => Cow::Owned(format!("{}{}", text, " ".repeat(padding))),
+    }
+}
+
+/// Truncates or pads the text so that it takes exactly `width` columns, e.g.
+/// for a cell of a table column.
+///
+/// A double-width character that does not fit whole in the last column is
+/// replaced by a space along with the ellipsis.
+pub fn fit_to_width(text: &str, width: usize) -> Cow<'_, str> {
+    match truncate_to_width(text, width) {
+        Cow::Borrowed(text) => pad_to_width(text, width),
+        Cow::Owned(truncated) => Cow::Owned(pad_to_width(&truncated, width).into_owned()),
+    }
+}
+
+/// Width of each column of a table, which is the width of its widest cell.
+///
+/// Rows can have different numbers of cells, the result has as many columns as
+/// the longest row.
+pub fn column_widths<R, S>(rows: &[R]) -> Vec<usize>
+where
+    R: AsRef<[S]>,
+    S: AsRef<str>,
+{
+    let mut widths = vec![];
+
+    for row in rows {
+        for (column, cell) in row.as_ref().iter().enumerate() {
+            let width = text_width(cell.as_ref());
+            match widths.get_mut(column) {
+                Some(max) => *max = width.max(*max),
+                None => widths.push(width),
+            }
+        }
+    }
+
+    widths
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow;
+
+    use super::{
+        column_widths, fit_to_width, grapheme_width, pad_to_width, text_width, truncate_to_width,
+        truncate_with_ellipsis,
+    };
+
+    #[test]
+    fn truncate_to_width_works() {
+        assert_eq!("short", truncate_to_width("short", 5));
+        assert_eq!("shor…", truncate_to_width("shorter", 5));
+        assert_eq!("…", truncate_to_width("shorter", 1));
+        assert_eq!("", truncate_to_width("shorter", 0));
+        // double-width characters never get split in half
+        assert_eq!("日本…", truncate_to_width("日本語です", 6));
+        assert_eq!("日…", truncate_to_width("日本語です", 4));
+        // neither are grapheme clusters
+        assert_eq!("👨‍👩‍👧…", truncate_to_width("👨‍👩‍👧👨‍👩‍👧", 3));
+        assert_eq!(
+            "e\u{301}…",
+            truncate_to_width("e\u{301}e\u{301}e\u{301}", 2)
+        );
+    }
+
+    #[test]
+    fn truncate_with_ellipsis_works() {
+        assert_eq!("short", truncate_with_ellipsis("short", 5, "..."));
+        assert_eq!("sh...", truncate_with_ellipsis("shorter", 5, "..."));
+        assert_eq!("...", truncate_with_ellipsis("shorter", 3, "..."));
+        assert_eq!("sh", truncate_with_ellipsis("shorter", 2, "..."));
+        assert_eq!("shorte", truncate_with_ellipsis("shorter", 6, ""));
+        assert_eq!("日 ~", truncate_with_ellipsis("日本語", 4, " ~"));
+    }
+
+    #[test]
+    fn text_width_counts_columns_per_grapheme() {
+        assert_eq!(0, text_width(""));
+        assert_eq!(5, text_width("hello"));
+        assert_eq!(10, text_width("名前を入力"));
+        assert_eq!(1, text_width("e\u{301}"));
+        assert_eq!(2, grapheme_width("🌍"));
+        assert_eq!(2, grapheme_width("👨‍👩‍👧"));
+        assert_eq!(2, grapheme_width("🧘🏻‍♂️"));
+        assert_eq!(2, grapheme_width("❤️"));
+        assert_eq!(2, grapheme_width("🇯🇵"));
+        assert_eq!(0, grapheme_width("\r\n"));
+    }
+
+    #[test]
+    fn cells_are_fitted_to_the_width_of_their_column() {
+        assert!(matches!(pad_to_width("wide", 3), Cow::Borrowed("wide")));
+        assert_eq!("日本  ", pad_to_width("日本", 6));
+        assert_eq!("日本  ", fit_to_width("日本", 6));
+        assert_eq!("日… ", fit_to_width("日本語", 4));
+        assert_eq!("", fit_to_width("日本語", 0));
+
+        let rows: Vec<Vec<String>> =
+            vec![vec!["a".into(), "bc".into()], vec!["日本".into()], vec![]];
+        assert_eq!(vec![4, 2], column_widths(&rows));
+        assert!(column_widths::<Vec<&str>, &str>(&[]).is_empty());
+    }
+}
@@ -1,6 +1,6 @@
 //! General type aliases.
 
-use crate::error::CustomUserError;
+use crate::{error::CustomUserError, events::PromptEvent, ui::Badge, PromptState};
 
 /// Type alias to represent the function used to filter options.
 ///
@@ -38,6 +38,105 @@ use crate::error::CustomUserError;
 /// ```
 pub type Filter<'a, T> = &'a dyn Fn(&str, &T, &str, usize) -> bool;
 
+/// Type alias to represent the function used to filter and rank options.
+///
+/// The function receives the same arguments as a [`Filter`], and returns the
+/// score of the current option, or `None` if it should not be displayed to the
+/// user. Matching options are displayed by descending score, options with the
+/// same score keeping their original order.
+///
+/// This allows ordering the options by relevance, e.g. with the scores of a
+/// fuzzy matching library.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::Scorer;
+///
+/// // Prefix matches first, then substring matches.
+/// let scorer: Scorer<str> = &|filter, _, string_value, _| -> Option<i64> {
+///     let filter = filter.to_lowercase();
+///     let string_value = string_value.to_lowercase();
+///
+///     match string_value.find(&filter) {
+///         Some(0) => Some(2),
+///         Some(_) => Some(1),
+///         None => None,
+///     }
+/// };
+/// assert_eq!(Some(2), scorer("san", "San Diego",   "San Diego",   0));
+/// assert_eq!(Some(1), scorer("san", "Pleasanton",  "Pleasanton",  1));
+/// assert_eq!(None,    scorer("san", "Los Angeles", "Los Angeles", 2));
+/// ```
+pub type Scorer<'a, T> = &'a dyn Fn(&str, &T, &str, usize) -> Option<i64>;
+
+/// Type alias to represent the function used to group options into sections.
+///
+/// The function receives an option and should return the name of the group it
+/// belongs to. Consecutive options with the same group name are part of the same
+/// section, so options are expected to be already sorted by group.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::Grouper;
+///
+/// let grouper: Grouper<str> = &|option| option.split('/').next().unwrap_or_default().to_string();
+/// assert_eq!("fruits", grouper("fruits/apple"));
+/// assert_eq!("vegetables", grouper("vegetables/carrot"));
+/// ```
+pub type Grouper<'a, T> = &'a dyn Fn(&T) -> String;
+
+/// Type alias to represent the function used to render an option as the text
+/// displayed to the user in list prompts.
+///
+/// It allows prompting over options that do not implement
+/// [`Display`](std::fmt::Display), such as types from other crates, without
+/// wrapping them in a newtype.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::Displayer;
+///
+/// let displayer: Displayer<(u32, &str)> = &|(id, name)| format!("#{id} {name}");
+/// assert_eq!("#7 Ferris", displayer(&(7, "Ferris")));
+/// ```
+pub type Displayer<'a, T> = &'a dyn Fn(&T) -> String;
+
+/// Type alias to represent the function used to turn the filter input into an
+/// answer when it matches no option.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::FreeFormAnswer;
+///
+/// let answer: FreeFormAnswer<String> = &|input| input.trim().to_string();
+/// assert_eq!("staging", answer(" staging "));
+/// ```
+pub type FreeFormAnswer<'a, T> = &'a dyn Fn(&str) -> T;
+
+/// Type alias to represent the function used to compute the badge of an option.
+///
+/// The function receives an option and returns the badge rendered before its
+/// label, if any. It is only called for the options on the current page.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{type_aliases::Badger, ui::Badge};
+///
+/// let badger: Badger<str> = &|package| match package {
+///     "serde" => Some(Badge::success("✔")),
+///     "tokio" => Some(Badge::accent("⬆")),
+///     _ => None,
+/// };
+/// assert_eq!(Some(Badge::success("✔")), badger("serde"));
+/// assert_eq!(None, badger("rand"));
+/// ```
+pub type Badger<'a, T> = &'a dyn Fn(&T) -> Option<Badge>;
+
 /// Type alias to represent the function used to retrieve text input suggestions.
 /// The function receives the current input and should return a collection of strings
 /// containing the suggestions to be made to the user.
@@ -47,3 +146,24 @@ pub type Suggester<'a> = &'a dyn Fn(&str) -> Result<Vec<String>, CustomUserError
 /// The function receives the current input and should return the suggestion (if any)
 /// that will replace the current input.
 pub type Completer<'a> = &'a dyn Fn(&str) -> Result<Option<String>, CustomUserError>;
+
+/// Type alias for the listeners notified of the events of a prompt, see the
+/// [`events`](crate::events) module.
+pub type EventListener<'a> = &'a dyn Fn(&PromptEvent<'_>);
+
+/// Type alias to represent the function computing the help message of a prompt
+/// from its current state, called whenever the prompt is rendered.
+///
+/// Returning `None` hides the help message.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::HelpMessageFn;
+///
+/// let help_message: HelpMessageFn = &|state| match state.answer.as_deref() {
+///     Some(answer) => Some(format!("Will be saved as {answer}")),
+///     None => None,
+/// };
+/// ```
+pub type HelpMessageFn<'a> = &'a dyn Fn(&PromptState<'_>) -> Option<String>;
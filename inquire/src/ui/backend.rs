@@ -1,23 +1,62 @@
-use crate::ansi::AnsiStrippable;
-use std::{collections::BTreeSet, fmt::Display, io::Result};
+use crate::ansi::{ansi_stripped, ansi_truncate_to_width, AnsiStrippable};
+use std::{
+    borrow::Cow,
+    fmt::{Display, Write as _},
+    io::Result,
+    sync::atomic::Ordering,
+    time::Duration,
+};
 
-use unicode_width::UnicodeWidthChar;
+use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{
-    error::InquireResult,
+    autocompletion::Suggestion,
+    diagnostics::{self, CleanupStep},
     input::Input,
     list_option::ListOption,
-    terminal::{Terminal, TerminalSize},
-    ui::{IndexPrefix, Key, RenderConfig, Styled},
+    terminal::{self, Terminal, TerminalSize},
+    text::{grapheme_width, text_width, truncate_with_ellipsis},
+    ui::{
+        bidi,
+        frame_buffer::{FrameBuffer, Line},
+        markup::{self, Markup},
+        Attributes, Badge, Block, Event, Feedback, FinalRender, IndexPrefix, RenderConfig,
+        StyleSheet, Styled,
+    },
     utils::{int_log10, Page},
     validator::ErrorMessage,
-    {Action, InnerAction},
 };
 
-use super::InputReader;
+/// ASCII replacement of the glyphs rendered by prompts, taking as many
+/// columns, for consoles rendering [only ASCII](RenderConfig::ascii_only).
+fn ascii_glyph(c: char) -> char {
+    match c {
+        '…' => '.',
+        '↑' => '^',
+        '↓' => 'v',
+        '←' => '<',
+        '→' => '>',
+        c => c,
+    }
+}
 
 pub trait CommonBackend {
-    fn read_key(&mut self) -> Result<Key>;
+    fn read_event(&mut self) -> Result<Event>;
+    fn poll_event(&mut self, timeout: Duration) -> Result<Option<Event>>;
+    fn can_poll_keys(&self) -> bool;
+
+    /// Largest page size, up to `page_size`, whose options fit in the terminal
+    /// height alongside the rest of the prompt.
+    fn fit_page_size(&self, page_size: usize) -> usize;
+
+    /// Whether the input rendered in the last frame was laid out from right to
+    /// left, in which case the left and right arrow keys are swapped.
+    fn is_input_right_to_left(&self) -> bool;
+
+    /// Clears the prompt and suspends the process, returning once it is
+    /// continued. The prompt must be rendered again afterwards.
+    #[cfg(all(unix, feature = "suspend"))]
+    fn suspend(&mut self) -> Result<()>;
 
     fn frame_setup(&mut self) -> Result<()>;
     fn frame_finish(&mut self) -> Result<()>;
@@ -27,6 +66,13 @@ pub trait CommonBackend {
 
     fn render_error_message(&mut self, error: &ErrorMessage) -> Result<()>;
     fn render_help_message(&mut self, help: &str) -> Result<()>;
+    fn render_block(&mut self, block: &Block) -> Result<()>;
+    fn render_position_indicator(&mut self, position: usize, total: usize) -> Result<()>;
+    fn render_no_matches_message(&mut self, message: &str) -> Result<()>;
+
+    /// Gives the configured [`error_feedback`](RenderConfig::error_feedback),
+    /// right away and outside of any frame.
+    fn render_error_feedback(&mut self) -> Result<()>;
 }
 
 pub trait TextBackend: CommonBackend {
@@ -35,8 +81,9 @@ pub trait TextBackend: CommonBackend {
         prompt: &str,
         default: Option<&str>,
         cur_input: &Input,
+        ghost_text: Option<&str>,
     ) -> Result<()>;
-    fn render_suggestions<D: Display>(&mut self, page: Page<ListOption<D>>) -> Result<()>;
+    fn render_suggestions(&mut self, page: Page<ListOption<&Suggestion>>) -> Result<()>;
 }
 
 #[cfg(feature = "editor")]
@@ -46,15 +93,42 @@ pub trait EditorBackend: CommonBackend {
 
 pub trait SelectBackend: CommonBackend {
     fn render_select_prompt(&mut self, prompt: &str, cur_input: &Input) -> Result<()>;
-    fn render_options<D: Display>(&mut self, page: Page<ListOption<D>>) -> Result<()>;
+    fn render_options<D: Display>(
+        &mut self,
+        page: Page<ListOption<D>>,
+        badges: &[Option<&Badge>],
+        badge_width: usize,
+    ) -> Result<()>;
+    fn render_table_header(&mut self, header: &str, total: usize, badge_width: usize)
+        -> Result<()>;
+
+    /// Overrides the index prefix of the render config for the options.
+    fn set_option_index_prefix(&mut self, prefix: IndexPrefix);
 }
 
 pub trait MultiSelectBackend: CommonBackend {
     fn render_multiselect_prompt(&mut self, prompt: &str, cur_input: &Input) -> Result<()>;
+    fn render_quick_filters(&mut self, filters: &[(&str, bool)]) -> Result<()>;
+    fn render_table_header(&mut self, header: &str, total: usize) -> Result<()>;
     fn render_options<D: Display>(
         &mut self,
         page: Page<ListOption<D>>,
-        checked: &BTreeSet<usize>,
+        checkboxes: &[CheckboxRow],
+    ) -> Result<()>;
+    fn render_selection_summary(&mut self, selected: &[String]) -> Result<()>;
+}
+
+pub trait ExpandBackend: CommonBackend {
+    fn render_expand_prompt(&mut self, prompt: &str, keys: &str) -> Result<()>;
+    fn render_expanded_options<D: Display>(&mut self, page: Page<ListOption<D>>) -> Result<()>;
+}
+
+pub trait ToggleBackend: CommonBackend {
+    fn render_toggle_prompt(
+        &mut self,
+        prompt: &str,
+        labels: [&str; 2],
+        active_selected: bool,
     ) -> Result<()>;
 }
 
@@ -73,7 +147,35 @@ pub trait PasswordBackend: CommonBackend {
     fn render_prompt_with_full_input(&mut self, prompt: &str, cur_input: &Input) -> Result<()>;
 }
 
-#[derive(Clone, Copy, Debug, Default)]
+/// State of the checkbox of a row rendered by multi-selection prompts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CheckState {
+    Checked,
+    Unchecked,
+    /// Only some of the children of the row are checked.
+    Partial,
+}
+
+/// Checkbox of a row rendered by multi-selection prompts, either an option or
+/// a parent of options such as a group header.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CheckboxRow {
+    pub state: CheckState,
+    /// Indentation level of the row.
+    pub depth: usize,
+    /// Whether the row is a parent of options, rendered without index prefix.
+    pub parent: bool,
+    /// Whether the children of a tree node are displayed, rendering the
+    /// matching prefix before its checkbox. `None` for rows without children
+    /// to expand.
+    pub expanded: Option<bool>,
+}
+
+/// Rows kept free for the prompt line, the error and help messages and any
+/// other content rendered around a page of options.
+const RESERVED_ROWS: usize = 4;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct Position {
     pub row: u16,
     pub col: u16,
@@ -87,7 +189,18 @@ where
     prompt_end_position: Position,
     prompt_cursor_offset: Option<usize>,
     prompt_cursor_position: Option<Position>,
+    alternate_screen: bool,
+    screen_offset: u16,
+    content: String,
+    buffering: bool,
+    frame: FrameBuffer,
+    previous_frame: FrameBuffer,
+    previous_width: u16,
+    stale_rows: u16,
+    scratch: String,
     show_cursor: bool,
+    rtl_input: bool,
+    error_shown: bool,
     terminal: T,
     terminal_size: TerminalSize,
     render_config: RenderConfig<'a>,
@@ -108,53 +221,101 @@ where
             prompt_end_position: Position::default(),
             prompt_cursor_offset: None,
             prompt_cursor_position: None,
+            alternate_screen: false,
+            screen_offset: 0,
+            content: String::new(),
+            buffering: false,
+            frame: FrameBuffer::new(),
+            previous_frame: FrameBuffer::new(),
+            previous_width: 0,
+            stale_rows: 0,
+            scratch: String::new(),
             show_cursor: false,
+            rtl_input: false,
+            error_shown: false,
             terminal,
             render_config,
             terminal_size,
         };
 
+        diagnostics::prompt_started();
         backend.terminal.cursor_hide()?;
 
+        if backend.render_config.fullscreen {
+            backend.terminal.enter_alternate_screen()?;
+            backend.alternate_screen = true;
+            terminal::ALTERNATE_SCREEN.store(true, Ordering::SeqCst);
+        }
+
+        if backend.render_config.strict_inline {
+            // the terminal clips long lines instead of wrapping them
+            backend.render_config.wrap = false;
+            backend.terminal.disable_line_wrap()?;
+        }
+
         Ok(backend)
     }
 
     fn update_position_info(&mut self) {
-        let input = self.terminal.get_in_memory_content();
+        let input = ansi_stripped(&self.content);
         let term_width = self.terminal_size.width;
+        let clip_lines = self.render_config.strict_inline;
 
         let mut cur_pos = Position::default();
+        let mut char_idx = 0;
 
-        for (idx, c) in input.ansi_stripped_chars().enumerate() {
-            let len = UnicodeWidthChar::width(c).unwrap_or(0) as u16;
+        for grapheme in input.graphemes(true) {
+            let len = grapheme_width(grapheme) as u16;
+            let chars = grapheme.chars().count();
 
-            if c == '\n' {
+            if grapheme.ends_with('\n') {
                 cur_pos.row = cur_pos.row.saturating_add(1);
                 cur_pos.col = 0;
             } else {
-                let left = term_width - cur_pos.col;
+                let left = term_width.saturating_sub(cur_pos.col);
 
                 if left >= len {
                     cur_pos.col = cur_pos.col.saturating_add(len);
+                } else if clip_lines {
+                    cur_pos.col = term_width;
                 } else {
                     cur_pos.row = cur_pos.row.saturating_add(1);
                     cur_pos.col = len;
                 }
             }
 
+            // the cursor offset is counted in chars, so it might point to any
+            // char of the grapheme under the cursor
             if let Some(prompt_cursor_offset) = self.prompt_cursor_offset {
-                if prompt_cursor_offset == idx {
+                if (char_idx..char_idx + chars).contains(&prompt_cursor_offset) {
                     let mut cursor_position = cur_pos;
                     cursor_position.col = cursor_position.col.saturating_sub(len);
                     self.prompt_cursor_position = Some(cursor_position);
                 }
             }
+
+            char_idx += chars;
         }
 
         self.prompt_current_position = cur_pos;
         self.prompt_end_position = cur_pos;
     }
 
+    /// Recomputes the layout of the rendered prompt when the terminal was resized,
+    /// as the terminal reflows its lines, so that the next frame clears them.
+    fn refresh_terminal_size(&mut self) {
+        let terminal_size = match self.terminal.get_size() {
+            Ok(size) if size != self.terminal_size => size,
+            _ => return,
+        };
+        self.terminal_size = terminal_size;
+
+        self.update_position_info();
+        if let Some(prompt_cursor_position) = self.prompt_cursor_position {
+            self.prompt_current_position = prompt_cursor_position;
+        }
+    }
+
     fn move_cursor_to_end_position(&mut self) -> Result<()> {
         if self.prompt_current_position.row != self.prompt_end_position.row {
             let diff = self
@@ -177,22 +338,88 @@ where
     }
 
     fn mark_prompt_cursor_position(&mut self, offset: usize) {
-        let current = self.terminal.get_in_memory_content();
-        let position = current.chars().count();
+        let position = self.content.as_str().ansi_stripped_chars().count();
         let position = position.saturating_add(offset);
 
         self.prompt_cursor_offset = Some(position);
     }
 
+    /// Switches back to the main screen, where the prompt is rendered inline
+    /// from then on, e.g. for its final rendering.
+    fn leave_alternate_screen(&mut self) -> Result<()> {
+        if !self.alternate_screen {
+            return Ok(());
+        }
+
+        self.terminal.leave_alternate_screen()?;
+        self.alternate_screen = false;
+        terminal::ALTERNATE_SCREEN.store(false, Ordering::SeqCst);
+
+        self.terminal.clear_in_memory_content();
+        self.content.clear();
+        self.prompt_current_position = Position::default();
+        self.prompt_end_position = Position::default();
+        self.screen_offset = 0;
+
+        Ok(())
+    }
+
+    /// Vertically centers the rendered frame on the alternate screen by
+    /// inserting empty rows above it.
+    fn center_frame(&mut self) -> Result<()> {
+        let frame_height = self.prompt_end_position.row;
+        let offset = self.terminal_size.height.saturating_sub(frame_height) / 2;
+
+        if offset > 0 {
+            self.terminal.scroll_down(offset)?;
+            self.terminal.cursor_down(offset)?;
+        }
+        self.screen_offset = offset;
+
+        Ok(())
+    }
+
     fn reset_prompt(&mut self) -> Result<()> {
         self.move_cursor_to_end_position()?;
 
-        for _ in 0..self.prompt_end_position.row {
+        let rows = self
+            .prompt_end_position
+            .row
+            .saturating_add(self.screen_offset);
+        self.screen_offset = 0;
+
+        for _ in 0..rows {
             self.terminal.cursor_up(1)?;
             self.terminal.clear_current_line()?;
         }
+        self.previous_frame.clear();
 
+        self.reset_prompt_state()
+    }
+
+    /// Moves the cursor back to the start of the rendered prompt without
+    /// clearing it, buffering the next frame so that
+    /// [`emit_frame`](Self::emit_frame) only rewrites the lines that changed.
+    fn rewind_prompt(&mut self) -> Result<()> {
+        self.move_cursor_to_end_position()?;
+
+        // nothing was rendered yet on the first frame, which starts at the
+        // current column
+        self.stale_rows = self.prompt_end_position.row;
+        if self.stale_rows > 0 {
+            self.terminal.cursor_up(self.stale_rows)?;
+            self.terminal.cursor_move_to_column(0)?;
+        }
+
+        self.buffering = true;
+        self.frame.clear();
+
+        self.reset_prompt_state()
+    }
+
+    fn reset_prompt_state(&mut self) -> Result<()> {
         self.terminal.clear_in_memory_content();
+        self.content.clear();
 
         self.prompt_current_position = Position::default();
         self.prompt_end_position = Position::default();
@@ -202,6 +429,8 @@ where
         // let's default to false to catch any previous
         // default behaviors we didn't account for
         self.show_cursor = false;
+        self.rtl_input = false;
+        self.error_shown = false;
         self.terminal.cursor_hide()?;
 
         Ok(())
@@ -224,7 +453,22 @@ where
             empty_prefix
         };
 
-        self.terminal.write_styled(&x)
+        self.write_styled(&x)
+    }
+
+    /// Truncates the label of an option, keeping its ANSI escape codes whole
+    /// when [`ansi_labels`](RenderConfig::ansi_labels) is set.
+    fn truncate_label<'t>(&self, label: &'t str, width: usize) -> Cow<'t, str> {
+        match self.render_config.ansi_labels {
+            true => ansi_truncate_to_width(label, width, self.render_config.ellipsis),
+            false => self.truncate(label, width),
+        }
+    }
+
+    /// Truncates the text, ending it with the configured
+    /// [`ellipsis`](RenderConfig::ellipsis).
+    fn truncate<'t>(&self, text: &'t str, width: usize) -> Cow<'t, str> {
+        truncate_with_ellipsis(text, width, self.render_config.ellipsis)
     }
 
     fn print_option_value<D: Display>(
@@ -235,63 +479,251 @@ where
     ) -> Result<()> {
         let stylesheet = if let Some(selected_option_style) = self.render_config.selected_option {
             match page.cursor {
-                Some(cursor) if cursor == option_relative_index => selected_option_style,
+                Some(cursor) if cursor == option_relative_index => {
+                    selected_option_style.inherit(self.render_config.option)
+                }
                 _ => self.render_config.option,
             }
         } else {
             self.render_config.option
         };
 
-        self.terminal
-            .write_styled(&Styled::new(&option.value).with_style_sheet(stylesheet))
+        if self.render_config.wrap {
+            return self.write_styled(&Styled::new(&option.value).with_style_sheet(stylesheet));
+        }
+
+        // formatted into a buffer reused across options
+        let mut value = std::mem::take(&mut self.scratch);
+        value.clear();
+        let _ = write!(value, "{}", option.value);
+
+        let available = self.available_width();
+        let result = self.write_styled(
+            &Styled::new(self.truncate_label(&value, available)).with_style_sheet(stylesheet),
+        );
+
+        self.scratch = value;
+        result
+    }
+
+    fn print_suggestion_description(&mut self, description: &str) -> Result<()> {
+        let style = self.render_config.suggestion_description;
+
+        if self.render_config.wrap {
+            self.write_styled(&Styled::new("  ").with_style_sheet(style))?;
+            return self.write_styled(&Styled::new(description).with_style_sheet(style));
+        }
+
+        let available = self.available_width();
+        if available <= 2 {
+            return Ok(());
+        }
+        let description = self.truncate(description, available - 2);
+
+        self.write_styled(&Styled::new("  ").with_style_sheet(style))?;
+        self.write_styled(&Styled::new(description).with_style_sheet(style))
+    }
+
+    /// Column where the next character written to the current line lands.
+    fn current_column(&self) -> usize {
+        let line = self.content.rsplit('\n').next().unwrap_or_default();
+        let width = text_width(&ansi_stripped(line));
+
+        match usize::from(self.terminal_size.width) {
+            0 => width,
+            term_width if self.render_config.strict_inline => width.min(term_width),
+            term_width => width % term_width,
+        }
     }
 
     fn print_option_index_prefix(&mut self, index: usize, max_index: usize) -> Option<Result<()>> {
+        let style = self.render_config.option;
         let index = index.saturating_add(1);
+        let width = int_log10(max_index.saturating_add(1));
 
-        let content = match self.render_config.option_index_prefix {
-            IndexPrefix::None => None,
-            IndexPrefix::Simple => Some(format!("{index})")),
-            IndexPrefix::SpacePadded => {
-                let width = int_log10(max_index.saturating_add(1));
-                Some(format!("{index:width$})"))
+        // formatted straight into the rendered content, without allocating
+        let result = match self.render_config.option_index_prefix {
+            IndexPrefix::None => return None,
+            IndexPrefix::Simple => {
+                self.write_styled(&Styled::new(format_args!("{index})")).with_style_sheet(style))
             }
-            IndexPrefix::ZeroPadded => {
-                let width = int_log10(max_index.saturating_add(1));
-                Some(format!("{index:0width$})"))
+            IndexPrefix::SpacePadded => self.write_styled(
+                &Styled::new(format_args!("{index:width$})")).with_style_sheet(style),
+            ),
+            IndexPrefix::ZeroPadded => self.write_styled(
+                &Styled::new(format_args!("{index:0width$})")).with_style_sheet(style),
+            ),
+        };
+
+        Some(result)
+    }
+
+    /// Width of the index prefix of an option, if the render config has one.
+    fn option_index_prefix_width(&self, index: usize, max_index: usize) -> Option<usize> {
+        let digits = int_log10(index.saturating_add(1));
+        let width = int_log10(max_index.saturating_add(1));
+
+        match self.render_config.option_index_prefix {
+            IndexPrefix::None => None,
+            IndexPrefix::Simple => Some(digits + 1),
+            IndexPrefix::SpacePadded | IndexPrefix::ZeroPadded => Some(digits.max(width) + 1),
+        }
+    }
+
+    /// Prints the badge of an option padded to the width of the badge column,
+    /// followed by a separator. Nothing is printed when the column is empty.
+    fn print_option_badge(&mut self, badge: Option<&Badge>, width: usize) -> Result<()> {
+        if width == 0 {
+            return Ok(());
+        }
+
+        let icon = badge.map(|badge| badge.icon.as_str()).unwrap_or_default();
+        let padding = width.saturating_sub(text_width(icon));
+
+        if let Some(badge) = badge {
+            let style = badge.style_sheet(&self.render_config.palette);
+            self.write_styled(&Styled::new(icon).with_style_sheet(style))?;
+        }
+
+        self.write(format_args!("{:1$}", "", padding + 1))
+    }
+
+    /// Prints the header row of options laid out as a table, aligned with the
+    /// option values, past their prefixes, the badge column and the given
+    /// checkbox, if any.
+    fn print_table_header(
+        &mut self,
+        header: &str,
+        total: usize,
+        badge_width: usize,
+        checkbox: Option<Styled<&str>>,
+    ) -> Result<()> {
+        let mut indent = text_width(self.render_config.highlighted_option_prefix.content) + 1;
+        if let Some(width) = self.option_index_prefix_width(total.saturating_sub(1), total) {
+            indent += width + 1;
+        }
+        if badge_width > 0 {
+            indent += badge_width + 1;
+        }
+        if let Some(checkbox) = checkbox {
+            indent += text_width(checkbox.content) + 1;
+        }
+
+        self.write(format_args!("{:1$}", "", indent))?;
+
+        let header = match self.render_config.wrap {
+            true => header.into(),
+            false => {
+                let available = self.available_width();
+                self.truncate(header, available)
             }
         };
+        let style = self.render_config.option.with_attr(Attributes::BOLD);
+        self.write_styled(&Styled::new(header).with_style_sheet(style))?;
 
-        content.map(|prefix| {
-            self.terminal
-                .write_styled(&Styled::new(prefix).with_style_sheet(self.render_config.option))
-        })
+        self.new_line()
     }
 
     fn print_default_value(&mut self, value: &str) -> Result<()> {
         let content = format!("({value})");
         let token = Styled::new(content).with_style_sheet(self.render_config.default_value);
 
-        self.terminal.write_styled(&token)
+        self.write_styled(&token)
     }
 
     fn print_prompt_with_prefix(&mut self, prefix: Styled<&str>, prompt: &str) -> Result<()> {
-        self.terminal.write_styled(&prefix)?;
+        self.write_styled(&prefix)?;
+
+        self.write(" ")?;
+
+        self.write_message(prompt, self.render_config.prompt, 0)?;
+
+        Ok(())
+    }
+
+    /// Writes a prompt or help message, styling its inline markup when
+    /// [`markup`](RenderConfig::markup) is enabled.
+    ///
+    /// Unless [`wrap`](RenderConfig::wrap) is enabled, the message is truncated
+    /// to fit in the current line with `reserved` columns left after it.
+    fn write_message(&mut self, message: &str, style: StyleSheet, reserved: usize) -> Result<()> {
+        let spans = match self.render_config.markup {
+            true => markup::parse(message),
+            false => vec![(message, Markup::Plain)],
+        };
 
-        self.terminal.write(" ")?;
+        let available = self.available_width().saturating_sub(reserved);
+        let ellipsis = match text_width(self.render_config.ellipsis) <= available {
+            true => self.render_config.ellipsis,
+            false => "",
+        };
+        let width: usize = spans.iter().map(|(span, _)| text_width(span)).sum();
+        let mut budget = match self.render_config.wrap || width <= available {
+            true => None,
+            false => Some(available - text_width(ellipsis)),
+        };
 
-        self.terminal
-            .write_styled(&Styled::new(prompt).with_style_sheet(self.render_config.prompt))?;
+        let code = self.render_config.markup_code;
+        for (span, markup) in spans {
+            let span_style = match markup {
+                Markup::Plain => style,
+                Markup::Bold => style.with_attr(style.att | Attributes::BOLD),
+                Markup::Dim => style.with_attr(style.att | Attributes::DIM),
+                Markup::Code => StyleSheet {
+                    fg: code.fg.or(style.fg),
+                    bg: code.bg.or(style.bg),
+                    att: style.att | code.att,
+                },
+            };
+
+            match &mut budget {
+                Some(budget) if text_width(span) > *budget => {
+                    let span = truncate_with_ellipsis(span, *budget, "");
+                    self.write_styled(&Styled::new(span).with_style_sheet(span_style))?;
+                    return self.write_styled(&Styled::new(ellipsis).with_style_sheet(span_style));
+                }
+                Some(budget) => *budget -= text_width(span),
+                None => {}
+            }
+
+            self.write_styled(&Styled::new(span).with_style_sheet(span_style))?;
+        }
 
         Ok(())
     }
 
+    /// Columns left in the current line of the terminal.
+    fn available_width(&self) -> usize {
+        usize::from(self.terminal_size.width).saturating_sub(self.current_column())
+    }
+
+    /// Writes the answer of a prompt, truncated to fit in the current line
+    /// unless [`wrap`](RenderConfig::wrap) is enabled.
+    fn write_answer(&mut self, answer: &str) -> Result<()> {
+        let answer = match self.render_config.wrap {
+            true => answer.into(),
+            false => self.truncate(answer, self.available_width()),
+        };
+
+        self.write_styled(&Styled::new(answer).with_style_sheet(self.render_config.answer))
+    }
+
     fn print_prompt(&mut self, prompt: &str) -> Result<()> {
-        self.print_prompt_with_prefix(self.render_config.prompt_prefix, prompt)
+        self.print_prompt_with_prefix(self.active_prompt_prefix(), prompt)
     }
 
-    fn print_input(&mut self, input: &Input) -> Result<()> {
-        self.terminal.write(" ")?;
+    /// Prefix of the prompt while it is running, which is the
+    /// [`error_prompt_prefix`](RenderConfig::error_prompt_prefix) while an
+    /// error message is shown.
+    fn active_prompt_prefix(&self) -> Styled<&'a str> {
+        match (self.error_shown, self.render_config.error_prompt_prefix) {
+            (true, Some(prefix)) => prefix,
+            _ => self.render_config.prompt_prefix,
+        }
+    }
+
+    fn print_input(&mut self, input: &Input, ghost_text: Option<&str>) -> Result<()> {
+        self.write(" ")?;
 
         let cursor_offset = input.pre_cursor().chars().count();
         self.mark_prompt_cursor_position(cursor_offset);
@@ -300,13 +732,13 @@ where
         if input.is_empty() {
             match input.placeholder() {
                 None => {}
-                Some(p) if p.is_empty() => {}
-                Some(p) => self.terminal.write_styled(
+                Some("") => {}
+                Some(p) => self.write_styled(
                     &Styled::new(p).with_style_sheet(self.render_config.placeholder),
                 )?,
             }
         } else {
-            self.terminal.write_styled(
+            self.write_styled(
                 &Styled::new(input.content()).with_style_sheet(self.render_config.text_input),
             )?;
         }
@@ -315,32 +747,275 @@ where
         // a space, otherwise the cursor will render on the
         // \n character, on the next line.
         if input.cursor() == input.length() {
-            self.terminal.write(' ')?;
+            match ghost_text {
+                Some(ghost_text) => self.write_styled(
+                    &Styled::new(ghost_text).with_style_sheet(self.render_config.placeholder),
+                )?,
+                None => self.write(' ')?,
+            }
+        }
+
+        if let Some((length, max_length)) = input.length_counter() {
+            if input.cursor() < input.length() {
+                self.write(' ')?;
+            }
+
+            let style = match length > max_length {
+                true => self.render_config.error_message.message,
+                false => self.render_config.length_counter,
+            };
+            self.write(' ')?;
+            self.write_styled(
+                &Styled::new(format_args!("{length}/{max_length}")).with_style_sheet(style),
+            )?;
         }
 
         Ok(())
     }
 
+    /// Prints a line made of the given segments laid out from right to left,
+    /// marking the cursor on the grapheme at the `cursor` index, if any.
+    fn print_rtl_line(&mut self, segments: &[Styled<&str>], cursor: Option<usize>) -> Result<()> {
+        let mut graphemes = vec![];
+        let mut styles = vec![];
+        for segment in segments {
+            for grapheme in bidi::graphemes(segment.content) {
+                graphemes.push(grapheme);
+                styles.push(segment.style);
+            }
+        }
+
+        let visual = bidi::visual_order(&graphemes);
+        let width: usize = graphemes.iter().map(|g| grapheme_width(g)).sum();
+        let padding = " ".repeat(usize::from(self.terminal_size.width).saturating_sub(width));
+
+        if let Some(cursor) = cursor {
+            let offset: usize = visual
+                .iter()
+                .take_while(|g| g.index != cursor)
+                .map(|g| g.content.chars().count())
+                .sum();
+            self.mark_prompt_cursor_position(padding.chars().count() + offset);
+            self.show_cursor = true;
+        }
+
+        self.write(padding)?;
+
+        // graphemes are written in chunks of the same style
+        let mut chunk = String::new();
+        let mut chunk_style = StyleSheet::empty();
+        for grapheme in visual {
+            let style = styles[grapheme.index];
+            if style != chunk_style && !chunk.is_empty() {
+                self.write_styled(&Styled::new(&chunk).with_style_sheet(chunk_style))?;
+                chunk.clear();
+            }
+            chunk_style = style;
+            chunk.push_str(grapheme.content);
+        }
+        if !chunk.is_empty() {
+            self.write_styled(&Styled::new(&chunk).with_style_sheet(chunk_style))?;
+        }
+
+        self.new_line()
+    }
+
+    fn print_rtl_prompt_with_input(
+        &mut self,
+        prompt: &str,
+        default: Option<&str>,
+        input: &Input,
+    ) -> Result<()> {
+        let default = default.map(|default| format!("({default})"));
+
+        let mut segments = vec![
+            self.active_prompt_prefix(),
+            Styled::new(" "),
+            Styled::new(prompt).with_style_sheet(self.render_config.prompt),
+        ];
+        if let Some(default) = &default {
+            segments.push(Styled::new(" "));
+            segments.push(
+                Styled::new(default.as_str()).with_style_sheet(self.render_config.default_value),
+            );
+        }
+        segments.push(Styled::new(" "));
+
+        let cursor = segments
+            .iter()
+            .map(|s| bidi::graphemes(s.content).len())
+            .sum::<usize>()
+            + input.cursor();
+
+        match (input.is_empty(), input.placeholder()) {
+            (true, Some(placeholder)) => segments
+                .push(Styled::new(placeholder).with_style_sheet(self.render_config.placeholder)),
+            (true, None) => {}
+            (false, _) => segments
+                .push(Styled::new(input.content()).with_style_sheet(self.render_config.text_input)),
+        }
+        if input.cursor() == input.length() {
+            segments.push(Styled::new(" "));
+        }
+
+        self.rtl_input = true;
+        self.print_rtl_line(&segments, Some(cursor))
+    }
+
+    fn is_right_to_left(&self, segments: &[&str]) -> bool {
+        self.render_config
+            .text_direction
+            .is_right_to_left(&segments.concat())
+    }
+
     fn print_prompt_with_input(
         &mut self,
         prompt: &str,
         default: Option<&str>,
         input: &Input,
     ) -> Result<()> {
+        self.print_prompt_with_ghost_text(prompt, default, input, None)
+    }
+
+    /// Prints the prompt with the input followed by the ghost text, if any.
+    /// Ghost text is not rendered in right-to-left lines.
+    fn print_prompt_with_ghost_text(
+        &mut self,
+        prompt: &str,
+        default: Option<&str>,
+        input: &Input,
+        ghost_text: Option<&str>,
+    ) -> Result<()> {
+        if self.is_right_to_left(&[prompt, input.content()]) {
+            return self.print_rtl_prompt_with_input(prompt, default, input);
+        }
+
         self.print_prompt(prompt)?;
 
         if let Some(default) = default {
-            self.terminal.write(" ")?;
+            self.write(" ")?;
             self.print_default_value(default)?;
         }
 
-        self.print_input(input)?;
+        self.print_input(input, ghost_text)?;
 
         self.new_line()?;
 
         Ok(())
     }
 
+    /// Writes the rendered lines of the buffered frame that differ from the
+    /// previous frame, clearing the rows left over from it.
+    ///
+    /// Unchanged lines are skipped over as long as they are still at the same
+    /// rows, i.e. the lines above them kept their height and the terminal was
+    /// not resized.
+    fn emit_frame(&mut self) -> Result<()> {
+        self.buffering = false;
+
+        let last = self.frame.len() - 1;
+        let mut aligned = self.previous_width == self.terminal_size.width;
+        let mut skipped_rows: u16 = 0;
+
+        for idx in 0..=last {
+            let line = self.frame.line(idx);
+            let terminated = idx < last;
+            let rows = self.line_rows(line);
+            let previous_line = Some(idx)
+                .filter(|idx| idx + 1 < self.previous_frame.len())
+                .map(|idx| self.previous_frame.line(idx));
+
+            if aligned && terminated && previous_line == Some(line) {
+                skipped_rows = skipped_rows.saturating_add(rows);
+                continue;
+            }
+            if previous_line.map(|l| self.line_rows(l)) != Some(rows) {
+                aligned = false;
+            }
+
+            if skipped_rows > 0 {
+                self.terminal.cursor_down(skipped_rows)?;
+                skipped_rows = 0;
+            }
+
+            for segment in line.segments() {
+                self.terminal.write_styled(&segment)?;
+            }
+            if !self.fills_last_row(line) {
+                self.terminal.clear_until_new_line()?;
+            }
+            if terminated {
+                self.terminal.write("\r\n")?;
+            }
+        }
+
+        let stale_rows = self.stale_rows.saturating_sub(self.prompt_end_position.row);
+        for _ in 0..stale_rows {
+            self.terminal.cursor_down(1)?;
+            self.terminal.clear_current_line()?;
+        }
+        if stale_rows > 0 {
+            self.terminal.cursor_up(stale_rows)?;
+        }
+
+        // the buffers of the previous frame are reused for the next one
+        std::mem::swap(&mut self.frame, &mut self.previous_frame);
+        self.previous_width = self.terminal_size.width;
+
+        Ok(())
+    }
+
+    /// Number of rows a rendered line takes in the terminal.
+    fn line_rows(&self, line: Line<'_>) -> u16 {
+        match usize::from(self.terminal_size.width) {
+            0 => 1,
+            _ if self.render_config.strict_inline => 1,
+            term_width => ((line.width.max(1) - 1) / term_width + 1) as u16,
+        }
+    }
+
+    /// Whether the line ends at the last column of its last row, where clearing
+    /// the rest of the row would erase its last character.
+    fn fills_last_row(&self, line: Line<'_>) -> bool {
+        match usize::from(self.terminal_size.width) {
+            0 => false,
+            term_width if self.render_config.strict_inline => line.width >= term_width,
+            term_width => {
+                let end_column = line.width % term_width;
+                line.width > 0 && end_column == 0
+            }
+        }
+    }
+
+    /// Writes the value to the terminal, or to the buffered frame when only the
+    /// lines that changed are rendered.
+    fn write<D: Display>(&mut self, val: D) -> Result<()> {
+        self.write_styled(&Styled::new(val))
+    }
+
+    fn write_styled<D: Display>(&mut self, val: &Styled<D>) -> Result<()> {
+        // formatted straight into the in-memory content, without allocating
+        let start = self.content.len();
+        let _ = write!(self.content, "{}", val.content);
+        if self.render_config.ascii_only && !self.content[start..].is_ascii() {
+            let content: String = self.content[start..].chars().map(ascii_glyph).collect();
+            self.content.truncate(start);
+            self.content.push_str(&content);
+        }
+        let content = &self.content[start..];
+        let style = val.style.downgrade(self.render_config.color_support);
+
+        match self.buffering {
+            true => {
+                self.frame.push(content, style);
+                Ok(())
+            }
+            false => self
+                .terminal
+                .write_styled(&Styled::new(content).with_style_sheet(style)),
+        }
+    }
+
     fn flush(&mut self) -> Result<()> {
         self.terminal.flush()?;
 
@@ -348,7 +1023,7 @@ where
     }
 
     fn new_line(&mut self) -> Result<()> {
-        self.terminal.write("\r\n")?;
+        self.write("\r\n")?;
         Ok(())
     }
 }
@@ -358,15 +1033,34 @@ where
     T: Terminal,
 {
     fn frame_setup(&mut self) -> Result<()> {
+        self.refresh_terminal_size();
+        // the frame is only flushed once finished, along with the clearing
+        // of the previous one
         self.terminal.cursor_hide()?;
-        self.terminal.flush()?;
 
-        self.reset_prompt()
+        // the rows of prompts taller than the terminal scrolled out of reach,
+        // so they can not be compared with the new frame
+        let rewindable = self.terminal.keeps_rendered_content()
+            && !self.alternate_screen
+            && self.prompt_end_position.row < self.terminal_size.height;
+
+        match rewindable {
+            true => self.rewind_prompt(),
+            false => self.reset_prompt(),
+        }
     }
 
     fn frame_finish(&mut self) -> Result<()> {
         self.update_position_info();
 
+        if self.buffering {
+            self.emit_frame()?;
+        }
+
+        if self.alternate_screen {
+            self.center_frame()?;
+        }
+
         if let Some(prompt_cursor_position) = self.prompt_cursor_position {
             let row_diff = self.prompt_current_position.row - prompt_cursor_position.row;
 
@@ -383,12 +1077,34 @@ where
     }
 
     fn render_canceled_prompt(&mut self, prompt: &str) -> Result<()> {
-        self.print_prompt(prompt)?;
+        self.leave_alternate_screen()?;
+
+        let indicator = self.render_config.canceled_prompt_indicator;
+        match self.render_config.canceled_final_render {
+            FinalRender::Full => {}
+            FinalRender::Compact => {
+                self.write_styled(&indicator)?;
+                return self.new_line();
+            }
+            FinalRender::None => return Ok(()),
+            FinalRender::Custom(render) => {
+                for line in render(prompt, indicator.content).lines() {
+                    self.write(line)?;
+                    self.new_line()?;
+                }
+                return Ok(());
+            }
+        }
+
+        let prefix = self
+            .render_config
+            .canceled_prompt_prefix
+            .unwrap_or(self.render_config.prompt_prefix);
+        self.print_prompt_with_prefix(prefix, prompt)?;
 
-        self.terminal.write(" ")?;
+        self.write(" ")?;
 
-        self.terminal
-            .write_styled(&self.render_config.canceled_prompt_indicator)?;
+        self.write_styled(&indicator)?;
 
         self.new_line()?;
 
@@ -396,27 +1112,112 @@ where
     }
 
     fn render_prompt_with_answer(&mut self, prompt: &str, answer: &str) -> Result<()> {
-        self.print_prompt_with_prefix(self.render_config.answered_prompt_prefix, prompt)?;
+        self.leave_alternate_screen()?;
+
+        let redacted = self
+            .render_config
+            .answer_redaction
+            .apply(answer, self.render_config.password_mask);
+        let answer = redacted.as_ref();
+
+        match self.render_config.final_render {
+            FinalRender::Full => {}
+            FinalRender::Compact => {
+                self.write_answer(answer)?;
+                return self.new_line();
+            }
+            FinalRender::None => return Ok(()),
+            FinalRender::Custom(render) => {
+                for line in render(prompt, answer).lines() {
+                    self.write(line)?;
+                    self.new_line()?;
+                }
+                return Ok(());
+            }
+        }
+
+        if self.is_right_to_left(&[prompt, answer]) {
+            let segments = [
+                self.render_config.answered_prompt_prefix,
+                Styled::new(" "),
+                Styled::new(prompt).with_style_sheet(self.render_config.prompt),
+                Styled::new(" "),
+                Styled::new(answer).with_style_sheet(self.render_config.answer),
+            ];
+            return self.print_rtl_line(&segments, None);
+        }
+
+        let prefix = self.render_config.answered_prompt_prefix;
+        self.write_styled(&prefix)?;
 
-        self.terminal.write(" ")?;
+        self.write(" ")?;
 
-        let token = Styled::new(answer).with_style_sheet(self.render_config.answer);
-        self.terminal.write_styled(&token)?;
+        // keeps room for the answer, up to half of the line
+        let reserved = (text_width(answer) + 1).min(self.available_width() / 2);
+        self.write_message(prompt, self.render_config.prompt, reserved)?;
+
+        self.write(" ")?;
+
+        self.write_answer(answer)?;
 
         self.new_line()?;
 
         Ok(())
     }
 
-    fn read_key(&mut self) -> Result<Key> {
-        self.terminal.read_key()
+    fn read_event(&mut self) -> Result<Event> {
+        self.terminal.read_event()
+    }
+
+    fn poll_event(&mut self, timeout: Duration) -> Result<Option<Event>> {
+        self.terminal.poll_event(timeout)
+    }
+
+    fn fit_page_size(&self, page_size: usize) -> usize {
+        let available = (self.terminal_size.height as usize).saturating_sub(RESERVED_ROWS);
+
+        page_size.min(available).max(1)
+    }
+
+    fn can_poll_keys(&self) -> bool {
+        self.terminal.can_poll_keys()
+    }
+
+    fn is_input_right_to_left(&self) -> bool {
+        self.rtl_input
+    }
+
+    #[cfg(all(unix, feature = "suspend"))]
+    fn suspend(&mut self) -> Result<()> {
+        self.reset_prompt()?;
+        self.terminal.cursor_show()?;
+        self.terminal.flush()?;
+        self.terminal.suspend_raw_mode()?;
+
+        crate::terminal::raise_suspend_signal();
+
+        self.terminal.resume_raw_mode()?;
+        self.terminal.cursor_hide()?;
+        self.terminal.flush()
+    }
+
+    fn render_error_feedback(&mut self) -> Result<()> {
+        match self.render_config.error_feedback {
+            Feedback::None => return Ok(()),
+            Feedback::Bell => self.terminal.bell()?,
+            Feedback::Flash => self.terminal.flash()?,
+        }
+
+        self.terminal.flush()
     }
 
     fn render_error_message(&mut self, error: &ErrorMessage) -> Result<()> {
-        self.terminal
-            .write_styled(&self.render_config.error_message.prefix)?;
+        self.error_shown = true;
+
+        let prefix = self.render_config.error_message.prefix;
+        self.write_styled(&prefix)?;
 
-        self.terminal.write_styled(
+        self.write_styled(
             &Styled::new(" ").with_style_sheet(self.render_config.error_message.separator),
         )?;
 
@@ -425,7 +1226,7 @@ where
             ErrorMessage::Custom(msg) => msg,
         };
 
-        self.terminal.write_styled(
+        self.write_styled(
             &Styled::new(message).with_style_sheet(self.render_config.error_message.message),
         )?;
 
@@ -435,19 +1236,45 @@ where
     }
 
     fn render_help_message(&mut self, help: &str) -> Result<()> {
-        self.terminal
-            .write_styled(&Styled::new("[").with_style_sheet(self.render_config.help_message))?;
+        self.write_styled(&Styled::new("[").with_style_sheet(self.render_config.help_message))?;
 
-        self.terminal
-            .write_styled(&Styled::new(help).with_style_sheet(self.render_config.help_message))?;
+        // leaves room for the closing bracket
+        self.write_message(help, self.render_config.help_message, 1)?;
 
-        self.terminal
-            .write_styled(&Styled::new("]").with_style_sheet(self.render_config.help_message))?;
+        self.write_styled(&Styled::new("]").with_style_sheet(self.render_config.help_message))?;
 
         self.new_line()?;
 
         Ok(())
     }
+
+    fn render_block(&mut self, block: &Block) -> Result<()> {
+        for line in &block.lines {
+            for segment in line {
+                self.write_styled(segment)?;
+            }
+
+            self.new_line()?;
+        }
+
+        Ok(())
+    }
+
+    fn render_position_indicator(&mut self, position: usize, total: usize) -> Result<()> {
+        let style = self.render_config.length_counter;
+        self.write_styled(
+            &Styled::new(format_args!("{position}/{total}")).with_style_sheet(style),
+        )?;
+
+        self.new_line()
+    }
+
+    fn render_no_matches_message(&mut self, message: &str) -> Result<()> {
+        let style = self.render_config.no_matches_message;
+        self.write_styled(&Styled::new(message).with_style_sheet(style))?;
+
+        self.new_line()
+    }
 }
 
 impl<'a, T> TextBackend for Backend<'a, T>
@@ -459,17 +1286,22 @@ where
         prompt: &str,
         default: Option<&str>,
         cur_input: &Input,
+        ghost_text: Option<&str>,
     ) -> Result<()> {
-        self.print_prompt_with_input(prompt, default, cur_input)
+        self.print_prompt_with_ghost_text(prompt, default, cur_input, ghost_text)
     }
 
-    fn render_suggestions<D: Display>(&mut self, page: Page<ListOption<D>>) -> Result<()> {
+    fn render_suggestions(&mut self, page: Page<ListOption<&Suggestion>>) -> Result<()> {
         for (idx, option) in page.content.iter().enumerate() {
             self.print_option_prefix(idx, &page)?;
 
-            self.terminal.write(" ")?;
+            self.write(" ")?;
             self.print_option_value(idx, option, &page)?;
 
+            if let Some(description) = &option.value.description {
+                self.print_suggestion_description(description)?;
+            }
+
             self.new_line()?;
         }
 
@@ -485,11 +1317,11 @@ where
     fn render_prompt(&mut self, prompt: &str, editor_command: &str) -> Result<()> {
         self.print_prompt(prompt)?;
 
-        self.terminal.write(" ")?;
+        self.write(" ")?;
 
         let message = format!("[(e) to open {}, (enter) to submit]", editor_command);
         let token = Styled::new(message).with_style_sheet(self.render_config.editor_prompt);
-        self.terminal.write_styled(&token)?;
+        self.write_styled(&token)?;
 
         self.new_line()?;
 
@@ -497,6 +1329,16 @@ where
     }
 }
 
+/// Width of the column of the given option badges, 0 when there are none.
+pub(crate) fn badge_column_width(badges: &[Option<Badge>]) -> usize {
+    badges
+        .iter()
+        .flatten()
+        .map(|badge| text_width(&badge.icon))
+        .max()
+        .unwrap_or(0)
+}
+
 impl<'a, T> SelectBackend for Backend<'a, T>
 where
     T: Terminal,
@@ -505,17 +1347,25 @@ where
         self.print_prompt_with_input(prompt, None, cur_input)
     }
 
-    fn render_options<D: Display>(&mut self, page: Page<ListOption<D>>) -> Result<()> {
+    fn render_options<D: Display>(
+        &mut self,
+        page: Page<ListOption<D>>,
+        badges: &[Option<&Badge>],
+        badge_width: usize,
+    ) -> Result<()> {
         for (idx, option) in page.content.iter().enumerate() {
             self.print_option_prefix(idx, &page)?;
 
-            self.terminal.write(" ")?;
+            self.write(" ")?;
 
             if let Some(res) = self.print_option_index_prefix(option.index, page.total) {
                 res?;
-                self.terminal.write(" ")?;
+                self.write(" ")?;
             }
 
+            let badge = badges.get(idx).copied().flatten();
+            self.print_option_badge(badge, badge_width)?;
+
             self.print_option_value(idx, option, &page)?;
 
             self.new_line()?;
@@ -523,6 +1373,19 @@ where
 
         Ok(())
     }
+
+    fn render_table_header(
+        &mut self,
+        header: &str,
+        total: usize,
+        badge_width: usize,
+    ) -> Result<()> {
+        self.print_table_header(header, total, badge_width, None)
+    }
+
+    fn set_option_index_prefix(&mut self, prefix: IndexPrefix) {
+        self.render_config.option_index_prefix = prefix;
+    }
 }
 
 impl<'a, T> MultiSelectBackend for Backend<'a, T>
@@ -533,24 +1396,90 @@ where
         self.print_prompt_with_input(prompt, None, cur_input)
     }
 
+    fn render_quick_filters(&mut self, filters: &[(&str, bool)]) -> Result<()> {
+        for (idx, (label, active)) in filters.iter().enumerate() {
+            if idx > 0 {
+                self.write("  ")?;
+            }
+
+            let checkbox = match active {
+                true => self.render_config.selected_checkbox,
+                false => self.render_config.unselected_checkbox,
+            };
+
+            self.write(idx + 1)?;
+            self.write(" ")?;
+            self.write_styled(&checkbox)?;
+            self.write(" ")?;
+            self.write(label)?;
+        }
+
+        self.new_line()
+    }
+
+    fn render_table_header(&mut self, header: &str, total: usize) -> Result<()> {
+        let checkbox = self.render_config.unselected_checkbox;
+        self.print_table_header(header, total, 0, Some(checkbox))
+    }
+
+    fn render_selection_summary(&mut self, selected: &[String]) -> Result<()> {
+        // collapsed into a single line, truncated when it does not fit
+        let mut summary = std::mem::take(&mut self.scratch);
+        summary.clear();
+        let _ = write!(summary, "{} selected: ", selected.len());
+        for (idx, value) in selected.iter().enumerate() {
+            if idx > 0 {
+                summary.push_str(", ");
+            }
+            summary.push_str(value);
+        }
+
+        let style = self.render_config.selection_summary;
+        let available = self.available_width();
+        let result = self.write_styled(
+            &Styled::new(self.truncate_label(&summary, available)).with_style_sheet(style),
+        );
+
+        self.scratch = summary;
+        result?;
+
+        self.new_line()
+    }
+
     fn render_options<D: Display>(
         &mut self,
         page: Page<ListOption<D>>,
-        checked: &BTreeSet<usize>,
+        checkboxes: &[CheckboxRow],
     ) -> Result<()> {
-        for (idx, option) in page.content.iter().enumerate() {
+        for (idx, (option, row)) in page.content.iter().zip(checkboxes).enumerate() {
             self.print_option_prefix(idx, &page)?;
 
-            self.terminal.write(" ")?;
+            self.write(" ")?;
 
-            if let Some(res) = self.print_option_index_prefix(option.index, page.total) {
-                res?;
-                self.terminal.write(" ")?;
+            for _ in 0..row.depth {
+                self.write("  ")?;
             }
 
-            let mut checkbox = match checked.contains(&option.index) {
-                true => self.render_config.selected_checkbox,
-                false => self.render_config.unselected_checkbox,
+            if let Some(expanded) = row.expanded {
+                let prefix = match expanded {
+                    true => self.render_config.expanded_node_prefix,
+                    false => self.render_config.collapsed_node_prefix,
+                };
+                self.write_styled(&prefix)?;
+                self.write(" ")?;
+            }
+
+            if !row.parent {
+                if let Some(res) = self.print_option_index_prefix(option.index, page.total) {
+                    res?;
+                    self.write(" ")?;
+                }
+            }
+
+            let mut checkbox = match row.state {
+                CheckState::Checked => self.render_config.selected_checkbox,
+                CheckState::Unchecked => self.render_config.unselected_checkbox,
+                CheckState::Partial => self.render_config.partially_selected_checkbox,
             };
 
             match (self.render_config.selected_option, page.cursor) {
@@ -558,9 +1487,9 @@ where
                 _ => {}
             }
 
-            self.terminal.write_styled(&checkbox)?;
+            self.write_styled(&checkbox)?;
 
-            self.terminal.write(" ")?;
+            self.write(" ")?;
 
             self.print_option_value(idx, option, &page)?;
 
@@ -577,12 +1506,17 @@ pub mod date {
 
     use chrono::{Datelike, Duration};
 
-    use crate::{date_utils::get_start_date, terminal::Terminal, ui::Styled};
+    use crate::{
+        date_utils::{get_month_name, get_row_monday, get_start_date, get_weekday_abbreviation},
+        input::Input,
+        terminal::Terminal,
+        ui::Styled,
+    };
 
     use super::{Backend, CommonBackend};
 
     pub trait DateSelectBackend: CommonBackend {
-        fn render_calendar_prompt(&mut self, prompt: &str) -> Result<()>;
+        fn render_calendar_prompt(&mut self, prompt: &str, jump_input: &Input) -> Result<()>;
 
         #[allow(clippy::too_many_arguments)]
         fn render_calendar(
@@ -594,14 +1528,71 @@ pub mod date {
             selected_date: chrono::NaiveDate,
             min_date: Option<chrono::NaiveDate>,
             max_date: Option<chrono::NaiveDate>,
+            disabled_dates: Option<fn(chrono::NaiveDate) -> bool>,
+            week_selection: bool,
         ) -> Result<()>;
     }
 
+    pub trait TimeSelectBackend: CommonBackend {
+        fn render_time_prompt(
+            &mut self,
+            prompt: &str,
+            fields: &[(&str, String)],
+            cursor: usize,
+        ) -> Result<()>;
+    }
+
+    impl<'a, T> TimeSelectBackend for Backend<'a, T>
+    where
+        T: Terminal,
+    {
+        fn render_time_prompt(
+            &mut self,
+            prompt: &str,
+            fields: &[(&str, String)],
+            cursor: usize,
+        ) -> Result<()> {
+            self.print_prompt(prompt)?;
+            self.write(" ")?;
+
+            for (i, (separator, value)) in fields.iter().enumerate() {
+                self.write(separator)?;
+
+                if i != cursor {
+                    let token =
+                        Styled::new(value.as_str()).with_style_sheet(self.render_config.answer);
+                    self.write_styled(&token)?;
+                    continue;
+                }
+
+                match self.render_config.selected_option {
+                    Some(style_sheet) => {
+                        let token = Styled::new(value.as_str()).with_style_sheet(style_sheet);
+                        self.write_styled(&token)?;
+                    }
+                    None => {
+                        self.mark_prompt_cursor_position(0);
+                        self.show_cursor = true;
+                        self.write(value)?;
+                    }
+                }
+            }
+
+            self.new_line()?;
+
+            Ok(())
+        }
+    }
+
     impl<'a, T> DateSelectBackend for Backend<'a, T>
     where
         T: Terminal,
     {
-        fn render_calendar_prompt(&mut self, prompt: &str) -> Result<()> {
+        fn render_calendar_prompt(&mut self, prompt: &str, jump_input: &Input) -> Result<()> {
+            if !jump_input.is_empty() {
+                return self.print_prompt_with_input(prompt, None, jump_input);
+            }
+
             self.print_prompt(prompt)?;
             self.new_line()?;
             Ok(())
@@ -616,23 +1607,30 @@ pub mod date {
             selected_date: chrono::NaiveDate,
             min_date: Option<chrono::NaiveDate>,
             max_date: Option<chrono::NaiveDate>,
+            disabled_dates: Option<fn(chrono::NaiveDate) -> bool>,
+            week_selection: bool,
         ) -> Result<()> {
             macro_rules! write_prefix {
                 () => {{
-                    self.terminal
-                        .write_styled(&self.render_config.calendar.prefix)?;
-                    self.terminal.write(" ")
+                    let prefix = self.render_config.calendar.prefix;
+                    self.write_styled(&prefix)?;
+                    self.write(" ")
                 }};
             }
 
             // print header (month year)
-            let header = format!("{} {}", month.name().to_lowercase(), year);
+            let header = format!("{} {}", get_month_name(month), year);
             let header = format!("{header:^20}");
             let header = Styled::new(header).with_style_sheet(self.render_config.calendar.header);
 
+            let week_numbers = self.render_config.calendar.week_numbers;
+
             write_prefix!()?;
 
-            self.terminal.write_styled(&header)?;
+            if week_numbers {
+                self.write("   ")?;
+            }
+            self.write_styled(&header)?;
 
             self.new_line()?;
 
@@ -640,10 +1638,7 @@ pub mod date {
             let mut current_weekday = week_start;
             let mut week_days: Vec<String> = vec![];
             for _ in 0..7 {
-                let mut formatted = format!("{current_weekday}");
-                formatted.make_ascii_lowercase();
-                formatted.pop();
-                week_days.push(formatted);
+                week_days.push(get_weekday_abbreviation(current_weekday));
 
                 current_weekday = current_weekday.succ();
             }
@@ -653,7 +1648,13 @@ pub mod date {
 
             write_prefix!()?;
 
-            self.terminal.write_styled(&week_days)?;
+            if week_numbers {
+                let label =
+                    Styled::new("wk").with_style_sheet(self.render_config.calendar.week_header);
+                self.write_styled(&label)?;
+                self.write(" ")?;
+            }
+            self.write_styled(&week_days)?;
             self.new_line()?;
 
             // print dates
@@ -673,18 +1674,36 @@ pub mod date {
             for _ in 0..6 {
                 write_prefix!()?;
 
+                let monday = get_row_monday(date_it, week_start);
+                let selected_week =
+                    week_selection && get_row_monday(selected_date, week_start) == monday;
+
+                if week_numbers {
+                    let style = self.render_config.calendar.week_number;
+                    let week = monday.iso_week().week();
+                    self.write_styled(
+                        &Styled::new(format_args!("{week:2}")).with_style_sheet(style),
+                    )?;
+                    self.write(" ")?;
+                }
+
                 for i in 0..7 {
                     if i > 0 {
-                        self.terminal.write(" ")?;
+                        self.write(" ")?;
                     }
 
-                    let date = format!("{:2}", date_it.day());
+                    let day = date_it.day();
 
                     let cursor_offset = if date_it.day() < 10 { 1 } else { 0 };
 
                     let mut style_sheet = crate::ui::StyleSheet::empty();
 
-                    if date_it == selected_date {
+                    if selected_week && date_it != selected_date {
+                        if let Some(custom_style_sheet) = self.render_config.calendar.selected_date
+                        {
+                            style_sheet = custom_style_sheet;
+                        }
+                    } else if date_it == selected_date {
                         self.mark_prompt_cursor_position(cursor_offset);
                         if let Some(custom_style_sheet) = self.render_config.calendar.selected_date
                         {
@@ -710,8 +1729,15 @@ pub mod date {
                         }
                     }
 
-                    let token = Styled::new(date).with_style_sheet(style_sheet);
-                    self.terminal.write_styled(&token)?;
+                    if let Some(disabled_dates) = disabled_dates {
+                        if disabled_dates(date_it) {
+                            style_sheet = self.render_config.calendar.unavailable_date;
+                        }
+                    }
+
+                    self.write_styled(
+                        &Styled::new(format_args!("{day:2}")).with_style_sheet(style_sheet),
+                    )?;
 
                     date_it = date_it.succ_opt().unwrap_or(date_it);
                 }
@@ -724,6 +1750,76 @@ pub mod date {
     }
 }
 
+impl<'a, T> ExpandBackend for Backend<'a, T>
+where
+    T: Terminal,
+{
+    fn render_expand_prompt(&mut self, prompt: &str, keys: &str) -> Result<()> {
+        self.print_prompt(prompt)?;
+
+        self.write(" ")?;
+        self.print_default_value(keys)?;
+
+        self.new_line()?;
+
+        Ok(())
+    }
+
+    fn render_expanded_options<D: Display>(&mut self, page: Page<ListOption<D>>) -> Result<()> {
+        for (idx, option) in page.content.iter().enumerate() {
+            self.print_option_prefix(idx, &page)?;
+
+            self.write(" ")?;
+            self.print_option_value(idx, option, &page)?;
+
+            self.new_line()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, T> ToggleBackend for Backend<'a, T>
+where
+    T: Terminal,
+{
+    fn render_toggle_prompt(
+        &mut self,
+        prompt: &str,
+        labels: [&str; 2],
+        active_selected: bool,
+    ) -> Result<()> {
+        self.print_prompt(prompt)?;
+
+        let prefix = self.render_config.highlighted_option_prefix;
+        let empty_prefix = " ".repeat(text_width(prefix.content));
+        let option_style = self.render_config.option;
+        let selected_style = self
+            .render_config
+            .selected_option
+            .map_or(option_style, |style| style.inherit(option_style));
+
+        for (i, label) in labels.iter().enumerate() {
+            self.write(match i {
+                0 => " ",
+                _ => " / ",
+            })?;
+
+            if (i == 0) == active_selected {
+                self.write_styled(&prefix)?;
+                self.write_styled(&Styled::new(label).with_style_sheet(selected_style))?;
+            } else {
+                self.write(&empty_prefix)?;
+                self.write_styled(&Styled::new(label).with_style_sheet(self.render_config.option))?;
+            }
+        }
+
+        self.new_line()?;
+
+        Ok(())
+    }
+}
+
 impl<'a, T> CustomTypeBackend for Backend<'a, T>
 where
     T: Terminal,
@@ -753,7 +1849,9 @@ where
             .map(|_| self.render_config.password_mask)
             .collect();
 
-        let masked_input = Input::new_with(masked_string).with_cursor(cur_input.cursor());
+        let masked_input = Input::new_with(masked_string)
+            .with_cursor(cur_input.cursor())
+            .with_limits_of(cur_input);
 
         self.print_prompt_with_input(prompt, None, &masked_input)
     }
@@ -768,22 +1866,236 @@ where
     T: Terminal,
 {
     fn drop(&mut self) {
-        let _ = self.move_cursor_to_end_position();
-        let _ = self.terminal.cursor_show();
+        diagnostics::prompt_finished();
+        diagnostics::begin_cleanup();
+
+        let result = self.move_cursor_to_end_position();
+        diagnostics::record_cleanup(CleanupStep::MoveCursor, result);
+
+        if self.alternate_screen {
+            let result = self.leave_alternate_screen();
+            diagnostics::record_cleanup(CleanupStep::LeaveAlternateScreen, result);
+        }
+
+        if self.render_config.strict_inline {
+            let result = self.terminal.enable_line_wrap();
+            diagnostics::record_cleanup(CleanupStep::EnableLineWrap, result);
+        }
+
+        let result = self.terminal.cursor_show();
+        diagnostics::record_cleanup(CleanupStep::ShowCursor, result);
+
+        // terminals given by reference are not dropped along with the backend
+        let result = self.terminal.flush();
+        diagnostics::record_cleanup(CleanupStep::Flush, result);
     }
 }
 
-impl<'a, I, T> InputReader<I> for Backend<'a, T>
-where
-    T: Terminal,
-    I: Copy + Clone + PartialEq + Eq,
-{
-    fn next_action<C>(&mut self, config: &C) -> InquireResult<Option<Action<I>>>
-    where
-        I: InnerAction<C>,
-    {
-        let key = self.read_key()?;
-        let action = Action::from_key(key, config);
-        Ok(action)
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test {
+    use crossterm::event::KeyEvent;
+
+    use super::{Backend, CommonBackend, Position, TextBackend};
+    use crate::{
+        input::Input,
+        terminal::{crossterm::CrosstermTerminal, Terminal, TerminalSize},
+        ui::{Block, RenderConfig, TextDirection},
+    };
+
+    /// Position of the cursor after rendering `? Q: {input}` in a terminal `width` columns wide.
+    fn cursor_position(input: &Input, width: u16) -> Position {
+        render("Q:", input, RenderConfig::empty(), width).1
+    }
+
+    /// Rendered content and cursor position of the prompt with the given input.
+    fn render(
+        prompt: &str,
+        input: &Input,
+        render_config: RenderConfig<'_>,
+        width: u16,
+    ) -> (String, Position) {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+        let mut write: Vec<u8> = Vec::new();
+
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, render_config).unwrap();
+        backend.terminal_size = TerminalSize { width, height: 24 };
+
+        backend.render_prompt(prompt, None, input, None).unwrap();
+        backend.update_position_info();
+
+        (
+            backend.terminal.get_in_memory_content().to_owned(),
+            backend.prompt_cursor_position.unwrap(),
+        )
+    }
+
+    fn at(row: u16, col: u16) -> Position {
+        Position { row, col }
+    }
+
+    #[test]
+    fn cursor_accounts_for_double_width_characters() {
+        let input = Input::new_with("名前を入力");
+
+        assert_eq!(at(0, 5), cursor_position(&input.clone().with_cursor(0), 80));
+        assert_eq!(at(0, 9), cursor_position(&input.clone().with_cursor(2), 80));
+        assert_eq!(at(0, 15), cursor_position(&input, 80));
+    }
+
+    #[test]
+    fn cursor_accounts_for_emoji_sequences() {
+        let input = Input::new_with("a👨‍👩‍👧b❤️c");
+
+        assert_eq!(at(0, 6), cursor_position(&input.clone().with_cursor(1), 80));
+        assert_eq!(at(0, 8), cursor_position(&input.clone().with_cursor(2), 80));
+        assert_eq!(
+            at(0, 11),
+            cursor_position(&input.clone().with_cursor(4), 80)
+        );
+    }
+
+    #[test]
+    fn double_width_characters_wrap_as_a_whole() {
+        // `? Q: 名前を` fills 10 columns, `入` does not fit in the last one
+        let input = Input::new_with("名前を入力").with_cursor(3);
+
+        assert_eq!(at(1, 0), cursor_position(&input, 12));
+    }
+
+    #[test]
+    fn cursor_is_placed_before_cjk_placeholder() {
+        let input = Input::new().with_placeholder("名前を入力");
+
+        assert_eq!(at(0, 5), cursor_position(&input, 80));
+    }
+
+    #[test]
+    fn right_to_left_lines_are_right_aligned_and_reordered() {
+        let config = RenderConfig::empty().with_text_direction(TextDirection::RightToLeft);
+        let input = Input::new_with("דני");
+
+        // `? שם: דני ` with the trailing space for the cursor, reversed
+        let (content, cursor) = render("שם:", &input, config, 20);
+        assert_eq!(format!("{} ינד :םש ?\r\n", " ".repeat(10)), content);
+        assert_eq!(at(0, 10), cursor);
+
+        let (_, cursor) = render("שם:", &input.with_cursor(1), config, 20);
+        assert_eq!(at(0, 12), cursor);
+    }
+
+    #[test]
+    fn auto_direction_detects_right_to_left_prompts() {
+        let config = RenderConfig::empty().with_text_direction(TextDirection::Auto);
+
+        let (content, _) = render("Name:", &Input::new_with("Dani"), config, 20);
+        assert_eq!("? Name: Dani \r\n", content);
+
+        let (content, _) = render("שם:", &Input::new_with("Dani"), config, 20);
+        assert_eq!(format!("{} Dani :םש ?\r\n", " ".repeat(9)), content);
+    }
+
+    #[test]
+    fn blocks_are_part_of_the_prompt_layout() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+        let mut write: Vec<u8> = Vec::new();
+
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+        backend.terminal_size = TerminalSize {
+            width: 80,
+            height: 24,
+        };
+
+        let input = Input::new_with("abc");
+        backend
+            .render_block(&Block::from("branch: main\nenv: staging"))
+            .unwrap();
+        backend.render_prompt("Q:", None, &input, None).unwrap();
+        backend.render_block(&Block::from("footer")).unwrap();
+        backend.update_position_info();
+
+        assert_eq!(
+            "branch: main\r\nenv: staging\r\n? Q: abc \r\nfooter\r\n",
+            backend.terminal.get_in_memory_content()
+        );
+        assert_eq!(Some(at(2, 8)), backend.prompt_cursor_position);
+        assert_eq!(at(4, 0), backend.prompt_end_position);
+    }
+
+    #[test]
+    fn strict_inline_mode_clips_long_lines() {
+        let input = Input::new_with("a".repeat(30));
+
+        let (_, cursor) = render("Q:", &input, RenderConfig::empty(), 20);
+        assert_eq!(at(1, 15), cursor);
+
+        let config = RenderConfig::empty().with_strict_inline(true);
+        let (_, cursor) = render("Q:", &input, config, 20);
+        assert_eq!(at(0, 19), cursor);
+    }
+
+    #[test]
+    fn fullscreen_frames_are_centered_on_the_alternate_screen() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+        let mut write: Vec<u8> = Vec::new();
+
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let config = RenderConfig::empty().with_fullscreen(true);
+        let mut backend = Backend::new(terminal, config).unwrap();
+        backend.terminal_size = TerminalSize {
+            width: 80,
+            height: 24,
+        };
+
+        let input = Input::new_with("abc");
+        backend.render_prompt("Q:", None, &input, None).unwrap();
+        backend.frame_finish().unwrap();
+        assert_eq!(11, backend.screen_offset);
+
+        backend.render_prompt_with_answer("Q:", "abc").unwrap();
+        drop(backend);
+
+        let output = String::from_utf8(write).unwrap();
+        let entered = output.find("\x1b[?1049h").unwrap();
+        let centered = output.find("\x1b[11T").unwrap();
+        let left = output.find("\x1b[?1049l").unwrap();
+        let answered = output.rfind("? Q: abc").unwrap();
+        assert!(entered < centered && centered < left && left < answered);
+    }
+
+    #[test]
+    fn only_changed_lines_are_rewritten() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+        let mut write: Vec<u8> = Vec::new();
+
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+        backend.terminal_size = TerminalSize {
+            width: 80,
+            height: 24,
+        };
+
+        for input in ["a", "ab"] {
+            backend.frame_setup().unwrap();
+            backend.render_block(&Block::from("header")).unwrap();
+            backend
+                .render_prompt("Q:", None, &Input::new_with(input), None)
+                .unwrap();
+            backend.render_help_message("help").unwrap();
+            backend.frame_finish().unwrap();
+        }
+        assert_eq!(at(1, 7), backend.prompt_current_position);
+        drop(backend);
+
+        let output = String::from_utf8(write).unwrap();
+        assert_eq!(1, output.matches("header").count());
+        assert_eq!(1, output.matches("help").count());
+        assert!(output.contains("? Q: ab"));
     }
 }
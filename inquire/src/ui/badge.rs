@@ -0,0 +1,93 @@
+use super::{Palette, StyleSheet};
+
+/// Small status indicator rendered in a column before the label of an option,
+/// such as `✔` for an installed package or `✖` for a broken one.
+///
+/// The color of a badge is picked from the [`Palette`] of the render config
+/// according to its [`BadgeKind`], so that badges follow the theme of the
+/// prompt.
+///
+/// # Example
+///
+/// ```
+/// use inquire::ui::{Badge, BadgeKind};
+///
+/// let badge = Badge::success("✔");
+///
+/// assert_eq!("✔", badge.icon);
+/// assert_eq!(BadgeKind::Success, badge.kind);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Badge {
+    /// Text of the badge, usually a single symbol.
+    pub icon: String,
+
+    /// Role of the badge, defining its color.
+    pub kind: BadgeKind,
+}
+
+/// Role of a [`Badge`], mapped to a color of the [`Palette`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BadgeKind {
+    /// Rendered without colors.
+    Neutral,
+    /// Rendered with the accent color of the palette.
+    Accent,
+    /// Rendered with the success color of the palette.
+    Success,
+    /// Rendered with the danger color of the palette.
+    Danger,
+    /// Rendered with the muted color of the palette.
+    Muted,
+}
+
+impl Badge {
+    /// Creates a badge with the given icon and kind.
+    pub fn new<S: Into<String>>(icon: S, kind: BadgeKind) -> Self {
+        Self {
+            icon: icon.into(),
+            kind,
+        }
+    }
+
+    /// Creates a badge rendered without colors.
+    pub fn neutral<S: Into<String>>(icon: S) -> Self {
+        Self::new(icon, BadgeKind::Neutral)
+    }
+
+    /// Creates a badge rendered with the accent color.
+    pub fn accent<S: Into<String>>(icon: S) -> Self {
+        Self::new(icon, BadgeKind::Accent)
+    }
+
+    /// Creates a badge rendered with the success color.
+    pub fn success<S: Into<String>>(icon: S) -> Self {
+        Self::new(icon, BadgeKind::Success)
+    }
+
+    /// Creates a badge rendered with the danger color.
+    pub fn danger<S: Into<String>>(icon: S) -> Self {
+        Self::new(icon, BadgeKind::Danger)
+    }
+
+    /// Creates a badge rendered with the muted color.
+    pub fn muted<S: Into<String>>(icon: S) -> Self {
+        Self::new(icon, BadgeKind::Muted)
+    }
+
+    /// Style sheet of the badge in the given palette.
+    pub(crate) fn style_sheet(&self, palette: &Palette) -> StyleSheet {
+        let fg = match self.kind {
+            BadgeKind::Neutral => None,
+            BadgeKind::Accent => palette.accent,
+            BadgeKind::Success => palette.success,
+            BadgeKind::Danger => palette.danger,
+            BadgeKind::Muted => palette.muted,
+        };
+
+        StyleSheet {
+            fg,
+            ..StyleSheet::empty()
+        }
+    }
+}
@@ -0,0 +1,206 @@
+//! Minimal bidirectional text support, so that prompts written in right-to-left
+//! scripts such as Arabic or Hebrew are readable on terminals that lay out all
+//! text from left to right.
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Base direction in which prompt lines are laid out.
+///
+/// Most terminals render text strictly from left to right. In right-to-left
+/// mode, lines with a prompt and its input are right-aligned and reordered
+/// for display: right-to-left text is reversed while embedded left-to-right
+/// text, such as latin words and numbers, keeps its order. The left and right
+/// arrow keys are swapped as well, so that the cursor moves in the direction
+/// of the pressed key.
+///
+/// Terminals that implement the bidirectional algorithm themselves should keep
+/// the left-to-right direction, otherwise the text would be reordered twice.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TextDirection {
+    /// Lines are laid out from left to right, as written.
+    LeftToRight,
+
+    /// Lines are right-aligned and laid out from right to left.
+    RightToLeft,
+
+    /// The direction of each line is detected from its first letter, lines
+    /// starting with a right-to-left script are laid out from right to left.
+    Auto,
+}
+
+impl TextDirection {
+    /// Whether a line with the given content is laid out from right to left.
+    pub(crate) fn is_right_to_left(self, line: &str) -> bool {
+        match self {
+            Self::LeftToRight => false,
+            Self::RightToLeft => true,
+            Self::Auto => {
+                line.chars()
+                    .map(class)
+                    .find(|class| *class != Class::Neutral)
+                    == Some(Class::RightToLeft)
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Class {
+    LeftToRight,
+    RightToLeft,
+    Neutral,
+}
+
+fn class(c: char) -> Class {
+    match c {
+        // Hebrew, Arabic, Syriac, Thaana, NKo, Samaritan, Mandaic and their
+        // presentation forms
+        '\u{0590}'..='\u{08ff}'
+        | '\u{fb1d}'..='\u{fdff}'
+        | '\u{fe70}'..='\u{feff}'
+        | '\u{10800}'..='\u{10fff}'
+        | '\u{1e800}'..='\u{1efff}' => Class::RightToLeft,
+        c if c.is_alphanumeric() => Class::LeftToRight,
+        _ => Class::Neutral,
+    }
+}
+
+/// Grapheme of a line in display order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct VisualGrapheme<'s> {
+    /// Index of the grapheme in the logical order of the line.
+    pub index: usize,
+    /// Content to display, with brackets mirrored inside right-to-left text.
+    pub content: &'s str,
+}
+
+/// Reorders the graphemes of a right-to-left line for display on a terminal
+/// that lays out text from left to right.
+///
+/// Neutral characters, such as spaces and punctuation, take the direction of
+/// the surrounding text, or the right-to-left direction of the line when the
+/// text around them has different directions.
+pub(crate) fn visual_order<'s>(graphemes: &[&'s str]) -> Vec<VisualGrapheme<'s>> {
+    let classes: Vec<Class> = graphemes
+        .iter()
+        .map(|g| g.chars().next().map_or(Class::Neutral, class))
+        .collect();
+
+    let strong_before = strong_classes(classes.iter().copied());
+    let mut strong_after = strong_classes(classes.iter().rev().copied());
+    strong_after.reverse();
+
+    let ltr: Vec<bool> = classes
+        .iter()
+        .enumerate()
+        .map(|(index, class)| match class {
+            Class::LeftToRight => true,
+            Class::RightToLeft => false,
+            Class::Neutral => {
+                strong_before[index] == Some(Class::LeftToRight)
+                    && strong_after[index] == Some(Class::LeftToRight)
+            }
+        })
+        .collect();
+
+    // runs are laid out from right to left, and so are the graphemes of
+    // right-to-left runs
+    let mut visual = Vec::with_capacity(graphemes.len());
+    let mut end = graphemes.len();
+    while end > 0 {
+        let run_ltr = ltr[end - 1];
+        let mut start = end - 1;
+        while start > 0 && ltr[start - 1] == run_ltr {
+            start -= 1;
+        }
+
+        if run_ltr {
+            visual.extend((start..end).map(|index| VisualGrapheme {
+                index,
+                content: graphemes[index],
+            }));
+        } else {
+            visual.extend((start..end).rev().map(|index| VisualGrapheme {
+                index,
+                content: mirror(graphemes[index]),
+            }));
+        }
+
+        end = start;
+    }
+
+    visual
+}
+
+/// Splits the text into graphemes, as expected by [`visual_order`].
+pub(crate) fn graphemes(text: &str) -> Vec<&str> {
+    text.graphemes(true).collect()
+}
+
+/// For each position, the closest strong class found before it, in iteration order.
+fn strong_classes(classes: impl Iterator<Item = Class>) -> Vec<Option<Class>> {
+    let mut last = None;
+
+    classes
+        .map(|class| {
+            let before = last;
+            if class != Class::Neutral {
+                last = Some(class);
+            }
+            before
+        })
+        .collect()
+}
+
+fn mirror(grapheme: &str) -> &str {
+    match grapheme {
+        "(" => ")",
+        ")" => "(",
+        "[" => "]",
+        "]" => "[",
+        "{" => "}",
+        "}" => "{",
+        "<" => ">",
+        ">" => "<",
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{graphemes, visual_order, TextDirection};
+
+    fn visual(text: &str) -> String {
+        visual_order(&graphemes(text))
+            .into_iter()
+            .map(|g| g.content)
+            .collect()
+    }
+
+    #[test]
+    fn auto_direction_follows_first_letter() {
+        assert!(TextDirection::Auto.is_right_to_left("? שם: John"));
+        assert!(TextDirection::Auto.is_right_to_left("? ما اسمك؟"));
+        assert!(!TextDirection::Auto.is_right_to_left("? Name: שם"));
+        assert!(!TextDirection::Auto.is_right_to_left("? "));
+        assert!(TextDirection::RightToLeft.is_right_to_left("Name:"));
+        assert!(!TextDirection::LeftToRight.is_right_to_left("שם"));
+    }
+
+    #[test]
+    fn right_to_left_text_is_reversed() {
+        assert_eq!("םולש", visual("שלום"));
+        assert_eq!(" :םש ?", visual("? שם: "));
+    }
+
+    #[test]
+    fn embedded_left_to_right_text_keeps_its_order() {
+        assert_eq!("John Doe :םש", visual("שם: John Doe"));
+        assert_eq!("42 ןב", visual("בן 42"));
+    }
+
+    #[test]
+    fn brackets_are_mirrored_in_right_to_left_text() {
+        assert_eq!("(ןכ) ?ךישמהל", visual("להמשיך? (כן)"));
+    }
+}
@@ -0,0 +1,73 @@
+use super::Styled;
+
+/// Multi-line content rendered above or below a prompt, such as the current
+/// git branch or the selected environment, set with the `with_header` and
+/// `with_footer` methods of the prompts.
+///
+/// Blocks are rendered as part of the prompt, so they are redrawn and cleared
+/// along with it, and are not part of the final rendering of the prompt once
+/// it is answered.
+///
+/// # Example
+///
+/// ```
+/// use inquire::{terminal::TestBackend, ui::{Block, Color, RenderConfig, Styled}, Text};
+///
+/// let header = Block::new()
+///     .with_line("Deploying api")
+///     .with_styled_line(vec![
+///         Styled::new(String::from("branch: ")),
+///         Styled::new(String::from("main")).with_fg(Color::LightGreen),
+///     ]);
+///
+/// let mut terminal = TestBackend::new(vec![]).with_text("v2\n");
+/// let tag = Text::new("Tag:")
+///     .with_header(header)
+///     .with_footer("Tags are immutable")
+///     .with_render_config(RenderConfig::empty())
+///     .prompt_with_terminal(&mut terminal)?;
+///
+/// assert_eq!("v2", tag);
+/// assert!(terminal.frames()[0].starts_with("Deploying api\nbranch: main\n? Tag: "));
+/// assert!(terminal.frames()[0].ends_with("\nTags are immutable\n"));
+/// assert_eq!("? Tag: v2\n", terminal.last_frame().unwrap());
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Block {
+    /// Lines of the block, each made of styled segments.
+    pub lines: Vec<Vec<Styled<String>>>,
+}
+
+impl Block {
+    /// Creates an empty block.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a line without style.
+    pub fn with_line<S: Into<String>>(mut self, line: S) -> Self {
+        self.lines.push(vec![Styled::new(line.into())]);
+        self
+    }
+
+    /// Adds a line made of the given styled segments.
+    pub fn with_styled_line(mut self, segments: Vec<Styled<String>>) -> Self {
+        self.lines.push(segments);
+        self
+    }
+}
+
+impl From<&str> for Block {
+    /// Creates a block without style with a line for each line of the text.
+    fn from(text: &str) -> Self {
+        text.lines().fold(Self::new(), Self::with_line)
+    }
+}
+
+impl From<String> for Block {
+    /// Creates a block without style with a line for each line of the text.
+    fn from(text: String) -> Self {
+        Self::from(text.as_str())
+    }
+}
@@ -1,10 +1,14 @@
+use std::env;
+
 /// Represents a color to be used for text styling purposes.
 ///
 /// Currently a clone of [crossterm::style::Color]. Check their documentation
 /// for detailed documentation.
 ///
 /// In summary, the 16 defined colors are supported by almost all terminals.
-/// The Rgb and AnsiValue variants are supported in more modern ones.
+/// The Rgb and AnsiValue variants are supported in more modern ones, and are
+/// downgraded to the nearest supported color when rendered in other terminals,
+/// see [`ColorSupport`].
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Color {
     /// Black color.
@@ -165,4 +169,217 @@ impl Color {
     pub fn rgb(r: u8, g: u8, b: u8) -> Color {
         Color::Rgb { r, g, b }
     }
+
+    /// Nearest color among the ones supported by the terminal, e.g. the
+    /// nearest of the 16 standard colors for an RGB color in a terminal
+    /// supporting only those.
+    ///
+    /// ```
+    /// # use inquire::ui::{Color, ColorSupport};
+    ///
+    /// let orange = Color::rgb(255, 135, 0);
+    ///
+    /// assert_eq!(orange, orange.downgrade(ColorSupport::TrueColor));
+    /// assert_eq!(Color::AnsiValue(208), orange.downgrade(ColorSupport::Ansi256));
+    /// assert_eq!(Color::DarkYellow, orange.downgrade(ColorSupport::Basic));
+    /// ```
+    pub fn downgrade(self, support: ColorSupport) -> Color {
+        match (self, support) {
+            (_, ColorSupport::TrueColor) => self,
+            (Color::Rgb { r, g, b }, ColorSupport::Ansi256) => {
+                Color::AnsiValue(nearest_ansi256(r, g, b))
+            }
+            (Color::Rgb { r, g, b }, ColorSupport::Basic) => nearest_basic(r, g, b),
+            (Color::AnsiValue(value), ColorSupport::Basic) => match value {
+                0..=15 => BASIC_COLORS[usize::from(value)].0,
+                _ => {
+                    let (r, g, b) = ansi256_to_rgb(value);
+                    nearest_basic(r, g, b)
+                }
+            },
+            _ => self,
+        }
+    }
+}
+
+/// Colors a terminal is able to render.
+///
+/// Colors not supported by the terminal are [downgraded](Color::downgrade) to
+/// the nearest supported one when rendered, according to the
+/// [`color_support`](crate::ui::RenderConfig::color_support) of the render
+/// config.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ColorSupport {
+    /// The 16 standard colors only.
+    Basic,
+
+    /// The 256 colors of the [`AnsiValue`](Color::AnsiValue) palette.
+    Ansi256,
+
+    /// All colors, including [`Rgb`](Color::Rgb) ones.
+    TrueColor,
+}
+
+impl ColorSupport {
+    /// Colors supported by the terminal the program runs in, detected from
+    /// the `COLORTERM` and `TERM` environment variables, as terminfo entries
+    /// are named after the number of colors they support, e.g.
+    /// `xterm-256color`.
+    pub fn detect() -> Self {
+        Self::detect_in(|name| env::var(name).ok())
+    }
+
+    fn detect_in<F>(var: F) -> Self
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        if matches!(var("COLORTERM").as_deref(), Some("truecolor" | "24bit"))
+            || var("WT_SESSION").is_some()
+        {
+            return Self::TrueColor;
+        }
+
+        match var("TERM") {
+            Some(term) if term.ends_with("-direct") || term.contains("truecolor") => {
+                Self::TrueColor
+            }
+            Some(term) if term.contains("256color") => Self::Ansi256,
+            _ => Self::Basic,
+        }
+    }
+}
+
+/// The 16 standard colors along with their usual RGB values, in the order of
+/// their ANSI codes.
+const BASIC_COLORS: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::DarkRed, (205, 0, 0)),
+    (Color::DarkGreen, (0, 205, 0)),
+    (Color::DarkYellow, (205, 205, 0)),
+    (Color::DarkBlue, (0, 0, 238)),
+    (Color::DarkMagenta, (205, 0, 205)),
+    (Color::DarkCyan, (0, 205, 205)),
+    (Color::Grey, (229, 229, 229)),
+    (Color::DarkGrey, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// Intensities of each component in the 6x6x6 color cube of the 256 colors.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let d = |a: u8, b: u8| (i32::from(a) - i32::from(b)).pow(2) as u32;
+    d(r1, r2) + d(g1, g2) + d(b1, b2)
+}
+
+fn nearest_basic(r: u8, g: u8, b: u8) -> Color {
+    BASIC_COLORS
+        .iter()
+        .min_by_key(|(_, rgb)| distance(*rgb, (r, g, b)))
+        .map(|(color, _)| *color)
+        .unwrap_or(Color::White)
+}
+
+/// Nearest color of the 256 colors, among the color cube and the grayscale
+/// ramp, leaving out the 16 standard colors whose values vary across terminals.
+fn nearest_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let level = |c: u8| {
+        (0..CUBE_LEVELS.len())
+            .min_by_key(|idx| (i32::from(CUBE_LEVELS[*idx]) - i32::from(c)).abs())
+            .unwrap_or(0) as u8
+    };
+    let cube = 16 + 36 * level(r) + 6 * level(g) + level(b);
+
+    let average = (u16::from(r) + u16::from(g) + u16::from(b)) / 3;
+    let gray = 232 + (average.saturating_sub(3) / 10).min(23) as u8;
+
+    match distance(ansi256_to_rgb(gray), (r, g, b)) < distance(ansi256_to_rgb(cube), (r, g, b)) {
+        true => gray,
+        false => cube,
+    }
+}
+
+fn ansi256_to_rgb(value: u8) -> (u8, u8, u8) {
+    match value {
+        0..=15 => BASIC_COLORS[usize::from(value)].1,
+        16..=231 => {
+            let idx = value - 16;
+            (
+                CUBE_LEVELS[usize::from(idx / 36)],
+                CUBE_LEVELS[usize::from(idx / 6 % 6)],
+                CUBE_LEVELS[usize::from(idx % 6)],
+            )
+        }
+        _ => {
+            let level = 8 + 10 * (value - 232);
+            (level, level, level)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Color, ColorSupport};
+
+    #[test]
+    fn colors_are_downgraded_to_the_nearest_supported_one() {
+        assert_eq!(
+            Color::AnsiValue(16),
+            Color::rgb(0, 0, 0).downgrade(ColorSupport::Ansi256)
+        );
+        assert_eq!(
+            Color::AnsiValue(231),
+            Color::rgb(255, 255, 255).downgrade(ColorSupport::Ansi256)
+        );
+        assert_eq!(
+            Color::AnsiValue(244),
+            Color::rgb(128, 128, 128).downgrade(ColorSupport::Ansi256)
+        );
+        assert_eq!(
+            Color::DarkCyan,
+            Color::rgb(0, 158, 115).downgrade(ColorSupport::Basic)
+        );
+        assert_eq!(
+            Color::LightBlue,
+            Color::AnsiValue(12).downgrade(ColorSupport::Basic)
+        );
+        assert_eq!(
+            Color::DarkGrey,
+            Color::AnsiValue(244).downgrade(ColorSupport::Basic)
+        );
+        assert_eq!(
+            Color::LightRed,
+            Color::LightRed.downgrade(ColorSupport::Basic)
+        );
+        assert_eq!(
+            Color::AnsiValue(244),
+            Color::AnsiValue(244).downgrade(ColorSupport::Ansi256)
+        );
+    }
+
+    #[test]
+    fn support_is_detected_from_the_environment() {
+        let detect = |vars: &[(&str, &str)]| {
+            ColorSupport::detect_in(|name| {
+                vars.iter()
+                    .find(|(var, _)| *var == name)
+                    .map(|(_, value)| value.to_string())
+            })
+        };
+
+        assert_eq!(ColorSupport::Basic, detect(&[]));
+        assert_eq!(ColorSupport::Basic, detect(&[("TERM", "xterm")]));
+        assert_eq!(ColorSupport::Ansi256, detect(&[("TERM", "xterm-256color")]));
+        assert_eq!(ColorSupport::TrueColor, detect(&[("TERM", "xterm-direct")]));
+        assert_eq!(
+            ColorSupport::TrueColor,
+            detect(&[("TERM", "xterm-256color"), ("COLORTERM", "truecolor")])
+        );
+    }
 }
@@ -0,0 +1,152 @@
+use crate::{ansi::ansi_stripped, text::text_width};
+
+use super::{StyleSheet, Styled};
+
+/// Styled segment of a frame, pointing into its text.
+#[derive(Clone, Copy, Debug)]
+struct Span {
+    start: usize,
+    end: usize,
+    style: StyleSheet,
+}
+
+/// Lines of a frame buffered before being rendered, stored in buffers that
+/// are reused across frames, so that buffering a frame does not allocate once
+/// they grew to the size of the prompt.
+#[derive(Debug)]
+pub(crate) struct FrameBuffer {
+    text: String,
+    spans: Vec<Span>,
+    /// Index of the first span and text offset of each line. The last line is
+    /// the one being written, not terminated by a line feed yet.
+    lines: Vec<(usize, usize)>,
+}
+
+impl FrameBuffer {
+    pub fn new() -> Self {
+        Self {
+            text: String::new(),
+            spans: vec![],
+            lines: vec![(0, 0)],
+        }
+    }
+
+    /// Removes all lines, keeping the allocated buffers.
+    pub fn clear(&mut self) {
+        self.text.clear();
+        self.spans.clear();
+        self.lines.clear();
+        self.lines.push((0, 0));
+    }
+
+    /// Appends styled content, starting a new line after each line feed.
+    /// Carriage returns are dropped.
+    pub fn push(&mut self, content: &str, style: StyleSheet) {
+        for (idx, part) in content.split('\n').enumerate() {
+            if idx > 0 {
+                self.lines.push((self.spans.len(), self.text.len()));
+            }
+
+            let start = self.text.len();
+            match part.contains('\r') {
+                true => self.text.extend(part.chars().filter(|c| *c != '\r')),
+                false => self.text.push_str(part),
+            }
+
+            if self.text.len() > start {
+                self.spans.push(Span {
+                    start,
+                    end: self.text.len(),
+                    style,
+                });
+            }
+        }
+    }
+
+    /// Number of lines, including the last one which is not terminated.
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Line at the given index, which must be lower than [`len`](Self::len).
+    pub fn line(&self, idx: usize) -> Line<'_> {
+        let (first_span, start) = self.lines[idx];
+        let (last_span, end) = match self.lines.get(idx + 1) {
+            Some(next) => *next,
+            None => (self.spans.len(), self.text.len()),
+        };
+
+        Line {
+            text: &self.text,
+            spans: &self.spans[first_span..last_span],
+            width: text_width(&ansi_stripped(&self.text[start..end])),
+        }
+    }
+}
+
+/// Line of a [`FrameBuffer`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Line<'a> {
+    text: &'a str,
+    spans: &'a [Span],
+    /// Width of the line, without styles nor ANSI escape codes.
+    pub width: usize,
+}
+
+impl<'a> Line<'a> {
+    /// Styled segments of the line.
+    pub fn segments(&self) -> impl Iterator<Item = Styled<&'a str>> + 'a {
+        let text = self.text;
+
+        self.spans
+            .iter()
+            .map(move |span| Styled::new(&text[span.start..span.end]).with_style_sheet(span.style))
+    }
+}
+
+impl<'a> PartialEq for Line<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.spans.len() == other.spans.len() && self.segments().eq(other.segments())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::FrameBuffer;
+    use crate::ui::{StyleSheet, Styled};
+
+    #[test]
+    fn content_is_split_in_lines() {
+        let bold = StyleSheet::new().with_attr(crate::ui::Attributes::BOLD);
+
+        let mut frame = FrameBuffer::new();
+        frame.push("? ", StyleSheet::empty());
+        frame.push("Name:\r\n", bold);
+        frame.push("\r\n[help]", StyleSheet::empty());
+
+        assert_eq!(3, frame.len());
+        assert_eq!(
+            vec![
+                Styled::new("? "),
+                Styled::new("Name:").with_style_sheet(bold)
+            ],
+            frame.line(0).segments().collect::<Vec<_>>()
+        );
+        assert_eq!(7, frame.line(0).width);
+        assert_eq!(0, frame.line(1).segments().count());
+        assert_eq!(
+            vec![Styled::new("[help]")],
+            frame.line(2).segments().collect::<Vec<_>>()
+        );
+
+        let mut other = FrameBuffer::new();
+        other.push("\n\n[help]", StyleSheet::empty());
+        assert!(frame.line(0) != other.line(0));
+        assert!(frame.line(1) == other.line(1));
+        assert!(frame.line(2) == other.line(2));
+
+        frame.clear();
+        assert_eq!(1, frame.len());
+        assert_eq!(0, frame.line(0).width);
+    }
+}
@@ -0,0 +1,133 @@
+use std::{env, fmt::Display};
+
+/// Text linking to a URL, clickable in terminals supporting the OSC 8 escape
+/// sequence, and rendered as plain text elsewhere.
+///
+/// It can be used as the value of an option, in which case the prompt must be
+/// told that its labels contain escape codes, e.g. with
+/// [`Select::with_ansi_labels`](crate::Select::with_ansi_labels), as well as
+/// formatted into a prompt or help message.
+///
+/// Whether the link is rendered is decided when creating it, see
+/// [`hyperlinks_supported`].
+///
+/// # Example
+///
+/// ```
+/// use inquire::ui::Hyperlink;
+///
+/// let link = Hyperlink::new("docs", "https://docs.rs/inquire");
+/// assert_eq!(
+///     "\x1b]8;;https://docs.rs/inquire\x1b\\docs\x1b]8;;\x1b\\",
+///     link.clone().with_enabled(true).to_string()
+/// );
+/// assert_eq!("docs", link.with_enabled(false).to_string());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Hyperlink<T> {
+    /// Text displayed in place of the URL.
+    pub text: T,
+
+    /// Target of the link.
+    pub url: String,
+
+    /// Whether the link is rendered, or only its text.
+    pub enabled: bool,
+}
+
+impl<T> Hyperlink<T>
+where
+    T: Display,
+{
+    /// Creates a link to the URL, rendered when the terminal
+    /// [supports hyperlinks](hyperlinks_supported).
+    pub fn new<S: Into<String>>(text: T, url: S) -> Self {
+        Self {
+            text,
+            url: url.into(),
+            enabled: hyperlinks_supported(),
+        }
+    }
+
+    /// Sets whether the link is rendered, or only its text, overriding the
+    /// detection of the terminal support.
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+impl<T> Display for Hyperlink<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.enabled {
+            true => write!(f, "\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", self.url, self.text),
+            false => write!(f, "{}", self.text),
+        }
+    }
+}
+
+/// Whether the terminal the program runs in is known to support hyperlinks,
+/// detected from its environment variables.
+///
+/// The `FORCE_HYPERLINK` environment variable takes precedence: set to `0` it
+/// disables hyperlinks, set to anything else it enables them.
+pub fn hyperlinks_supported() -> bool {
+    supported_in(|name| env::var(name).ok())
+}
+
+fn supported_in<F>(var: F) -> bool
+where
+    F: Fn(&str) -> Option<String>,
+{
+    if let Some(force) = var("FORCE_HYPERLINK") {
+        return force != "0";
+    }
+
+    if var("WT_SESSION").is_some() || var("KONSOLE_VERSION").is_some() || var("DOMTERM").is_some() {
+        return true;
+    }
+
+    // VTE-based terminals, such as GNOME Terminal, since version 0.50
+    if let Some(version) = var("VTE_VERSION").and_then(|v| v.parse::<u32>().ok()) {
+        return version >= 5000;
+    }
+
+    let known_program = matches!(
+        var("TERM_PROGRAM").as_deref(),
+        Some("iTerm.app" | "WezTerm" | "vscode" | "Hyper" | "ghostty")
+    );
+    let known_term = matches!(
+        var("TERM").as_deref(),
+        Some("xterm-kitty" | "alacritty" | "foot" | "xterm-ghostty")
+    );
+
+    known_program || known_term
+}
+
+#[cfg(test)]
+mod test {
+    use super::supported_in;
+
+    fn supported(vars: &[(&str, &str)]) -> bool {
+        supported_in(|name| {
+            vars.iter()
+                .find(|(var, _)| *var == name)
+                .map(|(_, value)| value.to_string())
+        })
+    }
+
+    #[test]
+    fn support_is_detected_from_the_environment() {
+        assert!(!supported(&[]));
+        assert!(!supported(&[("TERM", "xterm-256color")]));
+        assert!(supported(&[("TERM_PROGRAM", "WezTerm")]));
+        assert!(supported(&[("TERM", "xterm-kitty")]));
+        assert!(supported(&[("VTE_VERSION", "6800")]));
+        assert!(!supported(&[("VTE_VERSION", "4200")]));
+        assert!(supported(&[("FORCE_HYPERLINK", "1")]));
+        assert!(!supported(&[("FORCE_HYPERLINK", "0"), ("WT_SESSION", "1")]));
+    }
+}
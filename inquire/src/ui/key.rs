@@ -3,31 +3,68 @@ use bitflags::bitflags;
 // Using the same struct, but without importing, to cut prompts' direct dependencies to crossterm
 // https://github.com/crossterm-rs/crossterm/blob/e1260446e94e9a8f7809fef61dc1369b6f8d6e12/src/event.rs#L376-L385
 bitflags! {
+    /// Modifier keys held while a key was pressed.
     #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
     pub struct KeyModifiers: u8 {
+        /// Shift key.
         const SHIFT = 0b0000_0001;
+        /// Control key.
         const CONTROL = 0b0000_0010;
+        /// Alt key.
         const ALT = 0b0000_0100;
+        /// No modifier.
         const NONE = 0b0000_0000;
     }
 }
 
+/// Input event read from the terminal.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// A key was pressed.
+    Key(Key),
+    /// The terminal was resized, its new size can be read from the terminal.
+    ///
+    /// Only reported by the crossterm backend.
+    Resize,
+}
+
+/// Key pressed by the user.
+///
+/// Control characters are reported as the letter along with the control
+/// modifier, e.g. Ctrl+C is `Key::Char('c', KeyModifiers::CONTROL)`.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Key {
+    /// Escape key.
     Escape,
+    /// Enter key.
     Enter,
-    Backspace,
+    /// Backspace key.
+    Backspace(KeyModifiers),
+    /// Tab key.
     Tab,
+    /// Shift+Tab key.
+    BackTab,
+    /// Delete key.
     Delete(KeyModifiers),
+    /// Home key.
     Home,
+    /// End key.
     End,
-    PageUp,
-    PageDown,
+    /// Page up key.
+    PageUp(KeyModifiers),
+    /// Page down key.
+    PageDown(KeyModifiers),
+    /// Up arrow key.
     Up(KeyModifiers),
+    /// Down arrow key.
     Down(KeyModifiers),
+    /// Left arrow key.
     Left(KeyModifiers),
+    /// Right arrow key.
     Right(KeyModifiers),
+    /// Key of a character.
     Char(char, KeyModifiers),
+    /// Any other key, ignored by prompts.
     #[deprecated(note = "If the key you want isn't mapped, please open a PR.")]
     Any,
 }
@@ -0,0 +1,111 @@
+//! Inline markup of prompt and help messages, styled by the renderer when
+//! [`RenderConfig::markup`](crate::ui::RenderConfig::markup) is enabled.
+
+/// Style of a span of a message.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Markup {
+    Plain,
+    /// Text enclosed in `**`.
+    Bold,
+    /// Text enclosed in `_`.
+    Dim,
+    /// Text enclosed in backticks.
+    Code,
+}
+
+/// Splits the message into spans of `**bold**`, `_dim_` and `` `code` `` text,
+/// leaving the markers out.
+///
+/// Markers without a matching closing one are kept as plain text, and so are
+/// underscores within words, as in `snake_case`. Spans are not nested.
+pub(crate) fn parse(text: &str) -> Vec<(&str, Markup)> {
+    let mut spans = vec![];
+    let mut plain_start = 0;
+    let mut idx = 0;
+
+    while let Some(c) = text[idx..].chars().next() {
+        if let Some((marker, markup)) = opening_marker(text, idx) {
+            let start = idx + marker.len();
+            if let Some(end) = closing_marker(text, start, marker) {
+                if plain_start < idx {
+                    spans.push((&text[plain_start..idx], Markup::Plain));
+                }
+                spans.push((&text[start..end], markup));
+                idx = end + marker.len();
+                plain_start = idx;
+                continue;
+            }
+        }
+
+        idx += c.len_utf8();
+    }
+
+    if plain_start < text.len() {
+        spans.push((&text[plain_start..], Markup::Plain));
+    }
+
+    spans
+}
+
+fn opening_marker(text: &str, idx: usize) -> Option<(&'static str, Markup)> {
+    let rest = &text[idx..];
+
+    if rest.starts_with("**") {
+        Some(("**", Markup::Bold))
+    } else if rest.starts_with('`') {
+        Some(("`", Markup::Code))
+    } else if rest.starts_with('_') && !is_word_char(text[..idx].chars().next_back()) {
+        Some(("_", Markup::Dim))
+    } else {
+        None
+    }
+}
+
+/// Position of the marker closing a non-empty span starting at `start`.
+fn closing_marker(text: &str, start: usize, marker: &str) -> Option<usize> {
+    text[start..]
+        .match_indices(marker)
+        .map(|(offset, _)| start + offset)
+        .filter(|end| *end > start)
+        .find(|end| marker != "_" || !is_word_char(text[end + 1..].chars().next()))
+}
+
+fn is_word_char(c: Option<char>) -> bool {
+    matches!(c, Some(c) if c.is_alphanumeric())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse, Markup};
+
+    #[test]
+    fn markers_are_parsed_into_styled_spans() {
+        assert_eq!(
+            vec![
+                ("Deploy to ", Markup::Plain),
+                ("production", Markup::Bold),
+                ("? ", Markup::Plain),
+                ("run", Markup::Code),
+                (" ", Markup::Plain),
+                ("optional", Markup::Dim),
+            ],
+            parse("Deploy to **production**? `run` _optional_")
+        );
+        assert_eq!(vec![("日本", Markup::Bold)], parse("**日本**"));
+        assert!(parse("").is_empty());
+    }
+
+    #[test]
+    fn unmatched_markers_are_plain_text() {
+        assert_eq!(vec![("2 ** 3", Markup::Plain)], parse("2 ** 3"));
+        assert_eq!(vec![("**** `", Markup::Plain)], parse("**** `"));
+        assert_eq!(
+            vec![("set my_var or _MY_VAR", Markup::Plain)],
+            parse("set my_var or _MY_VAR")
+        );
+        assert_eq!(
+            vec![("a_b ", Markup::Plain), ("c_d", Markup::Dim)],
+            parse("a_b _c_d_")
+        );
+    }
+}
@@ -1,16 +1,26 @@
 //! UI-related definitions for rendered content.
 
 mod backend;
+mod badge;
+mod bidi;
+mod block;
 mod color;
-mod input_reader;
+mod frame_buffer;
+mod hyperlink;
 mod key;
+mod markup;
+mod palette;
 mod render_config;
 mod style;
 
 pub(crate) use backend::*;
-pub(crate) use input_reader::*;
-pub(crate) use key::*;
 
-pub use color::Color;
+pub use badge::{Badge, BadgeKind};
+pub use bidi::TextDirection;
+pub use block::Block;
+pub use color::{Color, ColorSupport};
+pub use hyperlink::{hyperlinks_supported, Hyperlink};
+pub use key::{Event, Key, KeyModifiers};
+pub use palette::Palette;
 pub use render_config::*;
 pub use style::{Attributes, StyleSheet, Styled};
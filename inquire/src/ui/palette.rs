@@ -0,0 +1,104 @@
+use super::Color;
+
+/// Colors assigned to the semantic roles of the rendered interface.
+///
+/// Instead of picking colors for each part of a prompt, a palette defines a
+/// handful of roles that [`RenderConfig::with_palette`](crate::ui::RenderConfig::with_palette)
+/// maps onto the individual style sheets, so the whole interface can be retoned
+/// in one place:
+///
+/// - **Accent**: highlighted options and prefixes, help messages and answers.
+/// - **Success**: prompt prefixes, checked checkboxes and today's date.
+/// - **Danger**: error messages and the canceled prompt indicator.
+/// - **Muted**: placeholders and unavailable dates.
+///
+/// A role set to `None` leaves the related parts uncolored.
+///
+/// # Example
+///
+/// ```
+/// use inquire::ui::{Palette, RenderConfig};
+///
+/// let config = RenderConfig::default_colored().with_palette(Palette::color_blind_safe());
+///
+/// assert_eq!(Palette::color_blind_safe(), config.palette);
+/// assert_eq!(config.palette.danger, config.error_message.message.fg);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Palette {
+    /// Color drawing attention to the element the user interacts with.
+    pub accent: Option<Color>,
+    /// Color of positive states.
+    pub success: Option<Color>,
+    /// Color of errors and negative states.
+    pub danger: Option<Color>,
+    /// Color of secondary and unavailable content.
+    pub muted: Option<Color>,
+}
+
+impl Palette {
+    /// Palette in which no colors are applied.
+    pub fn empty() -> Self {
+        Self {
+            accent: None,
+            success: None,
+            danger: None,
+            muted: None,
+        }
+    }
+
+    /// Palette used by the default colored render config, built from the 16
+    /// standard terminal colors.
+    pub fn standard() -> Self {
+        Self {
+            accent: Some(Color::LightCyan),
+            success: Some(Color::LightGreen),
+            danger: Some(Color::LightRed),
+            muted: Some(Color::DarkGrey),
+        }
+    }
+
+    /// Palette based on the Okabe-Ito colors, which stay distinguishable under
+    /// protanopia, deuteranopia and tritanopia.
+    ///
+    /// Meant for terminals supporting RGB colors: elsewhere, the colors are
+    /// downgraded to the nearest supported ones, which may not stay as
+    /// distinguishable, see [`color_blind_safe_ansi`](Self::color_blind_safe_ansi)
+    /// instead.
+    pub fn color_blind_safe() -> Self {
+        Self {
+            accent: Some(Color::rgb(86, 180, 233)),
+            success: Some(Color::rgb(0, 158, 115)),
+            danger: Some(Color::rgb(213, 94, 0)),
+            muted: Some(Color::DarkGrey),
+        }
+    }
+
+    /// Palette built from the 16 standard terminal colors which avoids telling
+    /// states apart by red and green, the pair confused under protanopia and
+    /// deuteranopia, the most common color-vision deficiencies.
+    pub fn color_blind_safe_ansi() -> Self {
+        Self {
+            accent: Some(Color::LightCyan),
+            success: Some(Color::LightBlue),
+            danger: Some(Color::LightYellow),
+            muted: Some(Color::DarkGrey),
+        }
+    }
+
+    /// Copies the palette, overriding the colors of the roles set in `other`.
+    pub fn merge(self, other: Palette) -> Self {
+        Self {
+            accent: other.accent.or(self.accent),
+            success: other.success.or(self.success),
+            danger: other.danger.or(self.danger),
+            muted: other.muted.or(self.muted),
+        }
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
@@ -1,6 +1,8 @@
-use std::env;
+use std::{borrow::Cow, env};
 
-use super::{Color, StyleSheet, Styled};
+use crate::terminal;
+
+use super::{Color, ColorSupport, Palette, StyleSheet, Styled, TextDirection};
 
 /// Rendering configuration that can be applied to a prompt.
 ///
@@ -8,6 +10,10 @@ use super::{Color, StyleSheet, Styled};
 /// parts of the prompt layout. Additionally, it allows you to set
 /// the content of a few tokens, such as prompt or error message prefixes.
 ///
+/// Colors are organized around the semantic roles of a [`Palette`], which
+/// [`with_palette`](Self::with_palette) applies to all style sheets at once.
+/// Individual style sheets can still be overridden afterwards.
+///
 /// # Example
 ///
 /// ```
@@ -21,6 +27,12 @@ use super::{Color, StyleSheet, Styled};
 /// ```
 #[derive(Copy, Clone, Debug)]
 pub struct RenderConfig<'a> {
+    /// Colors of the semantic roles applied to this configuration.
+    ///
+    /// Note: changing this field does not retone the other style sheets, use
+    /// [`with_palette`](Self::with_palette) for that.
+    pub palette: Palette,
+
     /// Prefix added before prompts.
     ///
     /// Note: a space character will be added to separate the prefix
@@ -33,6 +45,21 @@ pub struct RenderConfig<'a> {
     /// and the prompt message.
     pub answered_prompt_prefix: Styled<&'a str>,
 
+    /// Prefix added before prompts while an error message is shown, e.g. after
+    /// an invalid input was submitted. If the value is None, it will fall back
+    /// to `prompt_prefix`.
+    ///
+    /// Note: a space character will be added to separate the prefix
+    /// and the prompt message.
+    pub error_prompt_prefix: Option<Styled<&'a str>>,
+
+    /// Prefix added before prompts canceled by the user - by pressing ESC. If
+    /// the value is None, it will fall back to `prompt_prefix`.
+    ///
+    /// Note: a space character will be added to separate the prefix
+    /// and the prompt message.
+    pub canceled_prompt_prefix: Option<Styled<&'a str>>,
+
     /// Style of the prompt message, applicable to all prompt types.
     pub prompt: StyleSheet,
 
@@ -55,6 +82,20 @@ pub struct RenderConfig<'a> {
     /// Note: help messages are displayed wrapped in brackets, e.g. [Be careful!].
     pub help_message: StyleSheet,
 
+    /// Whether prompt and help messages are parsed for inline markup: text
+    /// enclosed in `**` is rendered bold, in `_` dimmed and in backticks with
+    /// the [`markup_code`](Self::markup_code) style sheet, without the markers.
+    ///
+    /// Markers without a matching closing one, and underscores within words,
+    /// are rendered as is. Right-to-left lines are rendered without markup.
+    ///
+    /// Defaults to `false`.
+    pub markup: bool,
+
+    /// Style sheet of the `` `code` `` spans of messages, applied over the
+    /// style sheet of the message, when [`markup`](Self::markup) is enabled.
+    pub markup_code: StyleSheet,
+
     /// Character used to mask password text inputs when in mode
     /// [`Masked`](crate::prompts::PasswordDisplayMode).
     ///
@@ -62,6 +103,12 @@ pub struct RenderConfig<'a> {
     /// [`text_input`](crate::ui::RenderConfig::text_input) configuration.
     pub password_mask: char,
 
+    /// Text ending the lines truncated to the width of the terminal, such as
+    /// option labels when [`wrap`](Self::wrap) is disabled.
+    ///
+    /// Defaults to `…`, and to `...` in [`RenderConfig::ascii`].
+    pub ellipsis: &'a str,
+
     /// Style sheet for text inputs.
     ///
     /// Note: a non-styled space character is added before the text input as
@@ -118,6 +165,29 @@ pub struct RenderConfig<'a> {
     /// option value to the right.
     pub unselected_checkbox: Styled<&'a str>,
 
+    /// Checkbox of the group headers in multi-select options, and of the
+    /// parent nodes of tree multi-select options, when only some of the
+    /// options below them are selected.
+    ///
+    /// Note: a space character will be added to separate the checkbox
+    /// from a possible prefix, and to separate the checkbox from the
+    /// option value to the right.
+    pub partially_selected_checkbox: Styled<&'a str>,
+
+    /// Prefix of the parent nodes of tree multi-select options whose children
+    /// are hidden.
+    ///
+    /// Note: a space character will be added to separate the prefix from the
+    /// checkbox to the right.
+    pub collapsed_node_prefix: Styled<&'a str>,
+
+    /// Prefix of the parent nodes of tree multi-select options whose children
+    /// are displayed.
+    ///
+    /// Note: a space character will be added to separate the prefix from the
+    /// checkbox to the right.
+    pub expanded_node_prefix: Styled<&'a str>,
+
     /// Definition of index prefixes in option lists.
     pub option_index_prefix: IndexPrefix,
 
@@ -127,13 +197,119 @@ pub struct RenderConfig<'a> {
     /// a separator from the prefix.
     pub option: StyleSheet,
 
-    /// Style sheet for the option that is currently selected. If the value is
+    /// Style sheet for the option that is currently selected, inheriting the
+    /// colors it does not set and the attributes of `option`. If the value is
     /// None, it will fall back to `option`.
     ///
     /// Note: a non-styled space character is added before the option value as
     /// a separator from the prefix.
     pub selected_option: Option<StyleSheet>,
 
+    /// Style sheet for the descriptions rendered next to autocompletion
+    /// suggestions.
+    pub suggestion_description: StyleSheet,
+
+    /// Style sheet for the `12/80` counter rendered after inputs with a maximum
+    /// length. The error message style is used instead once the input is longer
+    /// than the maximum.
+    ///
+    /// Also applied to the `3/120` position indicator of list prompts.
+    pub length_counter: StyleSheet,
+
+    /// Style sheet for the `3 selected: a, b, c` summary rendered below the
+    /// options of multi-selection prompts, see
+    /// [`MultiSelect::with_selection_summary`](crate::MultiSelect::with_selection_summary).
+    pub selection_summary: StyleSheet,
+
+    /// Style sheet for the message rendered by list prompts in place of the
+    /// options when the filter input matches none of them, see
+    /// [`Select::with_no_matches_message`](crate::Select::with_no_matches_message).
+    pub no_matches_message: StyleSheet,
+
+    /// Whether lines longer than the terminal width, such as long options,
+    /// prompt messages, help messages and answers, are wrapped into several
+    /// lines instead of truncated with an ellipsis.
+    pub wrap: bool,
+
+    /// Direction in which the prompt lines are laid out, see [`TextDirection`].
+    ///
+    /// Defaults to [`TextDirection::LeftToRight`].
+    pub text_direction: TextDirection,
+
+    /// How prompts are rendered once answered, see [`FinalRender`].
+    ///
+    /// Defaults to [`FinalRender::Full`].
+    pub final_render: FinalRender,
+
+    /// How prompts are rendered once canceled by the user - by pressing ESC,
+    /// the [`canceled_prompt_indicator`](Self::canceled_prompt_indicator)
+    /// taking the place of the answer, e.g. `? Name: <canceled>`. Rendering
+    /// nothing keeps skipped prompts out of the transcript of the session.
+    ///
+    /// Defaults to [`FinalRender::Full`].
+    pub canceled_final_render: FinalRender,
+
+    /// How the answer is redacted once the prompt is answered, e.g. for a
+    /// token typed into a text prompt to never end up in the scrollback of
+    /// the terminal or in CI logs, see [`Redaction`].
+    ///
+    /// Defaults to [`Redaction::None`].
+    pub answer_redaction: Redaction,
+
+    /// Feedback given when the user presses a key not bound to any action of
+    /// the prompt or submits an answer rejected by its validators, see
+    /// [`Feedback`].
+    ///
+    /// Defaults to [`Feedback::None`].
+    pub error_feedback: Feedback,
+
+    /// Whether prompts are rendered in strict inline mode, never touching the
+    /// lines printed above them, e.g. logs of the program.
+    ///
+    /// Prompts are redrawn by clearing as many rows as they took, computed from
+    /// the terminal width. When the terminal disagrees, e.g. on the width of
+    /// some emoji or after a resize without reflow, lines above the prompt can
+    /// be cleared along with it. In strict inline mode, the terminal stops
+    /// wrapping lines while the prompt is running, so that each line of the
+    /// prompt takes exactly one row. Lines longer than the terminal width,
+    /// including long inputs, are clipped instead, and [`wrap`](Self::wrap) is
+    /// ignored.
+    ///
+    /// Defaults to `false`.
+    pub strict_inline: bool,
+
+    /// Whether prompts are rendered in fullscreen: on the alternate screen
+    /// buffer, vertically centered, restoring the original screen once they
+    /// are answered. Only the final rendering of the prompt is then left in
+    /// the original screen.
+    ///
+    /// Useful for long lists that do not fit inline. Defaults to `false`.
+    pub fullscreen: bool,
+
+    /// Whether the labels of options may contain ANSI escape codes, e.g. colored
+    /// with `owo-colors`. The codes are passed through to the terminal, and
+    /// left out when measuring and truncating the labels, as well as when
+    /// filtering the options.
+    ///
+    /// Defaults to `false`.
+    pub ansi_labels: bool,
+
+    /// Whether only ASCII glyphs are rendered, for consoles whose fonts lack
+    /// the other ones, such as the legacy Windows console. The arrows of the
+    /// default help messages are then rendered as `^`, `v`, `<` and `>`, and
+    /// truncated text ends with `.` instead of `…`.
+    ///
+    /// Defaults to `false`, see [`RenderConfig::ascii`].
+    pub ascii_only: bool,
+
+    /// Colors the terminal is able to render. [`Rgb`](super::Color::Rgb) and
+    /// [`AnsiValue`](super::Color::AnsiValue) colors of the style sheets are
+    /// downgraded to the nearest supported color when rendered.
+    ///
+    /// Defaults to the [detected](ColorSupport::detect) support of the
+    /// terminal.
+    pub color_support: ColorSupport,
+
     /// Render configuration for calendar
 
     #[cfg(feature = "date")]
@@ -152,25 +328,49 @@ impl<'a> RenderConfig<'a> {
     /// RenderConfig in which no colors or attributes are applied.
     pub fn empty() -> Self {
         Self {
+            palette: Palette::empty(),
             prompt_prefix: Styled::new("?"),
             answered_prompt_prefix: Styled::new("?"),
+            error_prompt_prefix: None,
+            canceled_prompt_prefix: None,
             prompt: StyleSheet::empty(),
             default_value: StyleSheet::empty(),
             placeholder: StyleSheet::empty(),
             help_message: StyleSheet::empty(),
+            markup: false,
+            markup_code: StyleSheet::empty(),
             text_input: StyleSheet::empty(),
             error_message: ErrorMessageRenderConfig::empty(),
             answer: StyleSheet::empty(),
             canceled_prompt_indicator: Styled::new("<canceled>"),
             password_mask: '*',
+            ellipsis: "…",
             highlighted_option_prefix: Styled::new(">"),
             scroll_up_prefix: Styled::new("^"),
             scroll_down_prefix: Styled::new("v"),
             selected_checkbox: Styled::new("[x]"),
             unselected_checkbox: Styled::new("[ ]"),
+            partially_selected_checkbox: Styled::new("[~]"),
+            collapsed_node_prefix: Styled::new("+"),
+            expanded_node_prefix: Styled::new("-"),
             option_index_prefix: IndexPrefix::None,
             option: StyleSheet::empty(),
             selected_option: None,
+            suggestion_description: StyleSheet::empty(),
+            length_counter: StyleSheet::empty(),
+            selection_summary: StyleSheet::empty(),
+            no_matches_message: StyleSheet::empty(),
+            wrap: false,
+            text_direction: TextDirection::LeftToRight,
+            final_render: FinalRender::Full,
+            canceled_final_render: FinalRender::Full,
+            answer_redaction: Redaction::None,
+            error_feedback: Feedback::None,
+            strict_inline: false,
+            fullscreen: false,
+            ansi_labels: false,
+            ascii_only: false,
+            color_support: ColorSupport::detect(),
 
             #[cfg(feature = "date")]
             calendar: calendar::CalendarRenderConfig::empty(),
@@ -182,41 +382,315 @@ impl<'a> RenderConfig<'a> {
 
     /// RenderConfig where default colors and attributes are applied.
     pub fn default_colored() -> Self {
+        let mut config = Self {
+            answered_prompt_prefix: Styled::new(">"),
+            ..Self::empty()
+        }
+        .with_palette(Palette::standard());
+
+        config.canceled_prompt_indicator.style.fg = Some(Color::DarkRed);
+
+        #[cfg(feature = "editor")]
+        {
+            config.editor_prompt.fg = Some(Color::DarkCyan);
+        }
+
+        config
+    }
+
+    /// RenderConfig for consoles without support for ANSI escape codes, such
+    /// as the legacy Windows console, or for fonts rendering Unicode poorly:
+    /// only ASCII glyphs and the 16 standard colors are rendered.
+    ///
+    /// The decorative glyphs of the default config, i.e. the prompt prefixes,
+    /// option and calendar cursors, scroll indicators, checkboxes and node
+    /// prefixes, are already ASCII. Truncated lines end with `...`.
+    ///
+    /// It is the default render config when such a console is detected, and
+    /// can be set explicitly when the detection fails.
+    pub fn ascii() -> Self {
+        Self::default_colored()
+            .with_ascii_only(true)
+            .with_ellipsis("...")
+            .with_color_support(ColorSupport::Basic)
+    }
+
+    /// Applies the palette, setting the foreground color of each style sheet
+    /// to the color of its role.
+    ///
+    /// - Accent: help messages, code spans of messages, answers, highlighted
+    ///   option prefix, highlighted option and editor hint.
+    /// - Success: prompt prefixes, selected and partially selected checkboxes, calendar prefix
+    ///   and today's date.
+    /// - Danger: canceled prompt indicator, error messages and the error and
+    ///   canceled prompt prefixes, if set.
+    /// - Muted: placeholders, suggestion descriptions, length counters, selection summaries,
+    ///   no matches messages and dates outside of the current month or range.
+    pub fn with_palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+
+        self.prompt_prefix.style.fg = palette.success;
+        self.answered_prompt_prefix.style.fg = palette.success;
+        self.placeholder.fg = palette.muted;
+        self.suggestion_description.fg = palette.muted;
+        self.length_counter.fg = palette.muted;
+        self.selection_summary.fg = palette.muted;
+        self.no_matches_message.fg = palette.muted;
+        self.help_message.fg = palette.accent;
+        self.markup_code.fg = palette.accent;
+        self.answer.fg = palette.accent;
+        self.canceled_prompt_indicator.style.fg = palette.danger;
+        if let Some(prefix) = &mut self.error_prompt_prefix {
+            prefix.style.fg = palette.danger;
+        }
+        if let Some(prefix) = &mut self.canceled_prompt_prefix {
+            prefix.style.fg = palette.danger;
+        }
+        self.error_message = self.error_message.with_palette(palette);
+        self.highlighted_option_prefix.style.fg = palette.accent;
+        self.selected_checkbox.style.fg = palette.success;
+        self.partially_selected_checkbox.style.fg = palette.success;
+        self.selected_option = match (self.selected_option, palette.accent) {
+            (Some(selected_option), fg) => Some(StyleSheet {
+                fg,
+                ..selected_option
+            }),
+            (None, Some(accent)) => Some(StyleSheet::new().with_fg(accent)),
+            (None, None) => None,
+        };
+
+        #[cfg(feature = "date")]
+        {
+            self.calendar = self.calendar.with_palette(palette);
+        }
+
+        #[cfg(feature = "editor")]
+        {
+            self.editor_prompt.fg = palette.accent;
+        }
+
+        self
+    }
+
+    /// Layers the style sheets and tokens of `other`, such as a partial theme
+    /// built from [`RenderConfig::empty`], on top of this configuration.
+    ///
+    /// Each style sheet of `other` [inherits](StyleSheet::inherit) from the
+    /// one of this configuration: the colors it sets override the ones of this
+    /// configuration, and the attributes of both are combined. The contents of
+    /// the tokens of `other`, such as prefixes and checkboxes, override the
+    /// ones of this configuration when they differ from the contents in
+    /// [`RenderConfig::empty`]. The behavioral settings, such as
+    /// [`wrap`](Self::wrap) or [`final_render`](Self::final_render), are the
+    /// ones of this configuration.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inquire::ui::{Attributes, Color, RenderConfig, StyleSheet, Styled};
+    ///
+    /// let theme = RenderConfig::empty()
+    ///     .with_prompt_prefix(Styled::new("$"))
+    ///     .with_help_message(StyleSheet::new().with_attr(Attributes::ITALIC));
+    /// let config = RenderConfig::default_colored().merge(theme);
+    ///
+    /// assert_eq!("$", config.prompt_prefix.content);
+    /// assert_eq!(Some(Color::LightGreen), config.prompt_prefix.style.fg);
+    /// assert_eq!(Some(Color::LightCyan), config.help_message.fg);
+    /// assert_eq!(Attributes::ITALIC, config.help_message.att);
+    /// ```
+    pub fn merge(self, other: RenderConfig<'a>) -> Self {
+        let empty = Self::empty();
+
         Self {
-            prompt_prefix: Styled::new("?").with_fg(Color::LightGreen),
-            answered_prompt_prefix: Styled::new(">").with_fg(Color::LightGreen),
-            prompt: StyleSheet::empty(),
-            default_value: StyleSheet::empty(),
-            placeholder: StyleSheet::new().with_fg(Color::DarkGrey),
-            help_message: StyleSheet::empty().with_fg(Color::LightCyan),
-            text_input: StyleSheet::empty(),
-            error_message: ErrorMessageRenderConfig::default_colored(),
-            password_mask: '*',
-            answer: StyleSheet::empty().with_fg(Color::LightCyan),
-            canceled_prompt_indicator: Styled::new("<canceled>").with_fg(Color::DarkRed),
-            highlighted_option_prefix: Styled::new(">").with_fg(Color::LightCyan),
-            scroll_up_prefix: Styled::new("^"),
-            scroll_down_prefix: Styled::new("v"),
-            selected_checkbox: Styled::new("[x]").with_fg(Color::LightGreen),
-            unselected_checkbox: Styled::new("[ ]"),
-            option_index_prefix: IndexPrefix::None,
-            option: StyleSheet::empty(),
-            selected_option: Some(StyleSheet::new().with_fg(Color::LightCyan)),
+            palette: self.palette.merge(other.palette),
+            prompt_prefix: merge_token(
+                self.prompt_prefix,
+                other.prompt_prefix,
+                empty.prompt_prefix,
+            ),
+            answered_prompt_prefix: merge_token(
+                self.answered_prompt_prefix,
+                other.answered_prompt_prefix,
+                empty.answered_prompt_prefix,
+            ),
+            error_prompt_prefix: other.error_prompt_prefix.or(self.error_prompt_prefix),
+            canceled_prompt_prefix: other.canceled_prompt_prefix.or(self.canceled_prompt_prefix),
+            prompt: other.prompt.inherit(self.prompt),
+            default_value: other.default_value.inherit(self.default_value),
+            placeholder: other.placeholder.inherit(self.placeholder),
+            help_message: other.help_message.inherit(self.help_message),
+            markup_code: other.markup_code.inherit(self.markup_code),
+            password_mask: match other.password_mask == empty.password_mask {
+                true => self.password_mask,
+                false => other.password_mask,
+            },
+            ellipsis: match other.ellipsis == empty.ellipsis {
+                true => self.ellipsis,
+                false => other.ellipsis,
+            },
+            text_input: other.text_input.inherit(self.text_input),
+            answer: other.answer.inherit(self.answer),
+            canceled_prompt_indicator: merge_token(
+                self.canceled_prompt_indicator,
+                other.canceled_prompt_indicator,
+                empty.canceled_prompt_indicator,
+            ),
+            error_message: self.error_message.merge(other.error_message),
+            highlighted_option_prefix: merge_token(
+                self.highlighted_option_prefix,
+                other.highlighted_option_prefix,
+                empty.highlighted_option_prefix,
+            ),
+            scroll_up_prefix: merge_token(
+                self.scroll_up_prefix,
+                other.scroll_up_prefix,
+                empty.scroll_up_prefix,
+            ),
+            scroll_down_prefix: merge_token(
+                self.scroll_down_prefix,
+                other.scroll_down_prefix,
+                empty.scroll_down_prefix,
+            ),
+            selected_checkbox: merge_token(
+                self.selected_checkbox,
+                other.selected_checkbox,
+                empty.selected_checkbox,
+            ),
+            unselected_checkbox: merge_token(
+                self.unselected_checkbox,
+                other.unselected_checkbox,
+                empty.unselected_checkbox,
+            ),
+            partially_selected_checkbox: merge_token(
+                self.partially_selected_checkbox,
+                other.partially_selected_checkbox,
+                empty.partially_selected_checkbox,
+            ),
+            collapsed_node_prefix: merge_token(
+                self.collapsed_node_prefix,
+                other.collapsed_node_prefix,
+                empty.collapsed_node_prefix,
+            ),
+            expanded_node_prefix: merge_token(
+                self.expanded_node_prefix,
+                other.expanded_node_prefix,
+                empty.expanded_node_prefix,
+            ),
+            option: other.option.inherit(self.option),
+            selected_option: merge_optional(self.selected_option, other.selected_option),
+            suggestion_description: other
+                .suggestion_description
+                .inherit(self.suggestion_description),
+            length_counter: other.length_counter.inherit(self.length_counter),
+            selection_summary: other.selection_summary.inherit(self.selection_summary),
+            no_matches_message: other.no_matches_message.inherit(self.no_matches_message),
 
             #[cfg(feature = "date")]
-            calendar: calendar::CalendarRenderConfig::default_colored(),
+            calendar: self.calendar.merge(other.calendar),
 
             #[cfg(feature = "editor")]
-            editor_prompt: StyleSheet::new().with_fg(Color::DarkCyan),
+            editor_prompt: other.editor_prompt.inherit(self.editor_prompt),
+
+            ..self
         }
     }
 
+    /// Sets whether lines longer than the terminal width are wrapped instead of
+    /// truncated with an ellipsis.
+    pub fn with_wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Sets the direction in which the prompt lines are laid out.
+    pub fn with_text_direction(mut self, text_direction: TextDirection) -> Self {
+        self.text_direction = text_direction;
+        self
+    }
+
+    /// Sets how prompts are rendered once answered.
+    pub fn with_final_render(mut self, final_render: FinalRender) -> Self {
+        self.final_render = final_render;
+        self
+    }
+
+    /// Sets how the answer is redacted once the prompt is answered.
+    pub fn with_answer_redaction(mut self, answer_redaction: Redaction) -> Self {
+        self.answer_redaction = answer_redaction;
+        self
+    }
+
+    /// Sets the feedback given when a key is not bound to any action or an
+    /// answer fails validation.
+    pub fn with_error_feedback(mut self, error_feedback: Feedback) -> Self {
+        self.error_feedback = error_feedback;
+        self
+    }
+
+    /// Sets how prompts are rendered once canceled.
+    pub fn with_canceled_final_render(mut self, canceled_final_render: FinalRender) -> Self {
+        self.canceled_final_render = canceled_final_render;
+        self
+    }
+
+    /// Sets whether prompts are rendered in strict inline mode, never touching
+    /// the lines printed above them.
+    pub fn with_strict_inline(mut self, strict_inline: bool) -> Self {
+        self.strict_inline = strict_inline;
+        self
+    }
+
+    /// Sets whether prompts are rendered in fullscreen, on the alternate
+    /// screen buffer.
+    pub fn with_fullscreen(mut self, fullscreen: bool) -> Self {
+        self.fullscreen = fullscreen;
+        self
+    }
+
+    /// Sets whether the labels of options may contain ANSI escape codes.
+    pub fn with_ansi_labels(mut self, ansi_labels: bool) -> Self {
+        self.ansi_labels = ansi_labels;
+        self
+    }
+
+    /// Sets whether only ASCII glyphs are rendered.
+    pub fn with_ascii_only(mut self, ascii_only: bool) -> Self {
+        self.ascii_only = ascii_only;
+        self
+    }
+
+    /// Sets the colors the terminal is able to render, overriding the
+    /// detected ones.
+    pub fn with_color_support(mut self, color_support: ColorSupport) -> Self {
+        self.color_support = color_support;
+        self
+    }
+
     /// Sets the prompt prefix and its style sheet.
     pub fn with_prompt_prefix(mut self, prompt_prefix: Styled<&'a str>) -> Self {
         self.prompt_prefix = prompt_prefix;
         self
     }
 
+    /// Sets the prefix of answered prompts and its style sheet.
+    pub fn with_answered_prompt_prefix(mut self, answered_prompt_prefix: Styled<&'a str>) -> Self {
+        self.answered_prompt_prefix = answered_prompt_prefix;
+        self
+    }
+
+    /// Sets the prefix of prompts showing an error message and its style sheet.
+    pub fn with_error_prompt_prefix(mut self, error_prompt_prefix: Styled<&'a str>) -> Self {
+        self.error_prompt_prefix = Some(error_prompt_prefix);
+        self
+    }
+
+    /// Sets the prefix of canceled prompts and its style sheet.
+    pub fn with_canceled_prompt_prefix(mut self, canceled_prompt_prefix: Styled<&'a str>) -> Self {
+        self.canceled_prompt_prefix = Some(canceled_prompt_prefix);
+        self
+    }
+
     /// Sets style for text inputs.
     pub fn with_text_input(mut self, text_input: StyleSheet) -> Self {
         self.text_input = text_input;
@@ -235,6 +709,19 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets whether prompt and help messages are parsed for inline markup,
+    /// such as `**bold**`, `_dim_` and `` `code` `` text.
+    pub fn with_markup(mut self, markup: bool) -> Self {
+        self.markup = markup;
+        self
+    }
+
+    /// Sets the style sheet of the `` `code` `` spans of messages.
+    pub fn with_markup_code(mut self, markup_code: StyleSheet) -> Self {
+        self.markup_code = markup_code;
+        self
+    }
+
     /// Sets the style sheet for answers.
     pub fn with_answer(mut self, answer: StyleSheet) -> Self {
         self.answer = answer;
@@ -256,6 +743,31 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets the style sheet for the descriptions of autocompletion suggestions.
+    pub fn with_suggestion_description(mut self, suggestion_description: StyleSheet) -> Self {
+        self.suggestion_description = suggestion_description;
+        self
+    }
+
+    /// Sets the style sheet for the length counter of inputs with a maximum length.
+    pub fn with_length_counter(mut self, length_counter: StyleSheet) -> Self {
+        self.length_counter = length_counter;
+        self
+    }
+
+    /// Sets the style sheet of the selection summary of multi-selection prompts.
+    pub fn with_selection_summary(mut self, selection_summary: StyleSheet) -> Self {
+        self.selection_summary = selection_summary;
+        self
+    }
+
+    /// Sets the style sheet of the message rendered by list prompts when the
+    /// filter input matches no option.
+    pub fn with_no_matches_message(mut self, no_matches_message: StyleSheet) -> Self {
+        self.no_matches_message = no_matches_message;
+        self
+    }
+
     /// Sets the styled component for prefixes in scroll-up indicators.
     pub fn with_scroll_up_prefix(mut self, scroll_up_prefix: Styled<&'a str>) -> Self {
         self.scroll_up_prefix = scroll_up_prefix;
@@ -280,12 +792,46 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets the styled component for partially selected checkboxes.
+    pub fn with_partially_selected_checkbox(
+        mut self,
+        partially_selected_checkbox: Styled<&'a str>,
+    ) -> Self {
+        self.partially_selected_checkbox = partially_selected_checkbox;
+        self
+    }
+
+    /// Sets the styled component for the prefix of collapsed tree nodes.
+    pub fn with_collapsed_node_prefix(mut self, collapsed_node_prefix: Styled<&'a str>) -> Self {
+        self.collapsed_node_prefix = collapsed_node_prefix;
+        self
+    }
+
+    /// Sets the styled component for the prefix of expanded tree nodes.
+    pub fn with_expanded_node_prefix(mut self, expanded_node_prefix: Styled<&'a str>) -> Self {
+        self.expanded_node_prefix = expanded_node_prefix;
+        self
+    }
+
     /// Sets the index prefix for option lists.
     pub fn with_option_index_prefix(mut self, index_prefix: IndexPrefix) -> Self {
         self.option_index_prefix = index_prefix;
         self
     }
 
+    /// Sets the character used to mask the input of password prompts in the
+    /// masked display mode.
+    pub fn with_password_mask(mut self, password_mask: char) -> Self {
+        self.password_mask = password_mask;
+        self
+    }
+
+    /// Sets the text ending the lines truncated to the width of the terminal.
+    pub fn with_ellipsis(mut self, ellipsis: &'a str) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
+
     /// Sets the style sheet for option values.
     pub fn with_option(mut self, option: StyleSheet) -> Self {
         self.option = option;
@@ -322,11 +868,38 @@ impl<'a> RenderConfig<'a> {
     }
 }
 
+/// Token whose content is the one of `top` when it differs from the default
+/// one, and whose style sheet inherits from the one of `base`.
+fn merge_token<'a>(
+    base: Styled<&'a str>,
+    top: Styled<&'a str>,
+    default: Styled<&'a str>,
+) -> Styled<&'a str> {
+    let content = match top.content == default.content {
+        true => base.content,
+        false => top.content,
+    };
+
+    Styled::new(content).with_style_sheet(top.style.inherit(base.style))
+}
+
+/// Optional style sheet of `top` inheriting from the one of `base`, when both
+/// are set.
+fn merge_optional(base: Option<StyleSheet>, top: Option<StyleSheet>) -> Option<StyleSheet> {
+    match (base, top) {
+        (Some(base), Some(top)) => Some(top.inherit(base)),
+        (base, None) => base,
+        (None, top) => top,
+    }
+}
+
 impl<'a> Default for RenderConfig<'a> {
     fn default() -> Self {
-        match env::var("NO_COLOR") {
-            Ok(_) => Self::empty(),
-            Err(_) => Self::default_colored(),
+        match (env::var("NO_COLOR"), terminal::is_legacy_console()) {
+            (Ok(_), false) => Self::empty(),
+            (Ok(_), true) => Self::empty().with_ascii_only(true),
+            (Err(_), false) => Self::default_colored(),
+            (Err(_), true) => Self::ascii(),
         }
     }
 }
@@ -367,6 +940,128 @@ pub enum IndexPrefix {
     ZeroPadded,
 }
 
+/// How a prompt is rendered once answered, replacing the prompt in the
+/// terminal.
+///
+/// Canceled prompts are rendered according to the
+/// [`canceled_final_render`](RenderConfig::canceled_final_render), with the
+/// [`canceled_prompt_indicator`](RenderConfig::canceled_prompt_indicator) in
+/// place of the answer.
+///
+/// # Example
+///
+/// ```
+/// use inquire::{terminal::TestBackend, ui::{FinalRender, RenderConfig}, Text};
+///
+/// let mut terminal = TestBackend::new(vec![]).with_text("Ferris\n");
+/// Text::new("Name:")
+///     .with_render_config(RenderConfig::empty())
+///     .with_final_render(FinalRender::Custom(|prompt, answer| format!("{prompt} set to {answer}")))
+///     .prompt_with_terminal(&mut terminal)?;
+///
+/// assert_eq!("Name: set to Ferris\n", terminal.last_frame().unwrap());
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub enum FinalRender {
+    /// The prompt message is rendered along with the answer, e.g.
+    /// `? Name: Ferris`.
+    Full,
+
+    /// Only the answer is rendered, e.g. `Ferris`.
+    Compact,
+
+    /// Nothing is rendered, the prompt disappears once answered.
+    None,
+
+    /// The lines returned by the function, given the prompt message and the
+    /// formatted answer, are rendered without style.
+    Custom(fn(&str, &str) -> String),
+}
+
+/// How the answer of a prompt is redacted in its final rendering, along
+/// with the [`FinalRender`] of the prompt.
+///
+/// Only the rendering is redacted: the answer returned by the prompt and the
+/// one given to event listeners are the formatted answer.
+///
+/// # Example
+///
+/// ```
+/// use inquire::{terminal::TestBackend, ui::{RenderConfig, Redaction}, Text};
+///
+/// let mut terminal = TestBackend::new(vec![]).with_text("ghp_4f9a\n");
+/// let token = Text::new("Token:")
+///     .with_render_config(RenderConfig::empty())
+///     .with_answer_redaction(Redaction::Masked)
+///     .prompt_with_terminal(&mut terminal)?;
+///
+/// assert_eq!("ghp_4f9a", token);
+/// assert_eq!("? Token: ********\n", terminal.last_frame().unwrap());
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub enum Redaction {
+    /// The answer is rendered as is.
+    None,
+
+    /// The answer is rendered as eight
+    /// [`password_mask`](RenderConfig::password_mask) characters, not
+    /// revealing its length.
+    Masked,
+
+    /// The answer is not rendered, only the prompt message.
+    Hidden,
+
+    /// The answer is rendered as returned by the function, given the
+    /// formatted answer, e.g. to only reveal its last characters.
+    Custom(fn(&str) -> String),
+}
+
+impl Redaction {
+    /// Redacts the formatted answer.
+    pub(crate) fn apply<'s>(&self, answer: &'s str, mask: char) -> Cow<'s, str> {
+        match self {
+            Self::None => Cow::Borrowed(answer),
+            Self::Masked => Cow::Owned(mask.to_string().repeat(8)),
+            Self::Hidden => Cow::Borrowed(""),
+            Self::Custom(redact) => Cow::Owned(redact(answer)),
+        }
+    }
+}
+
+/// Feedback given by prompts when the user presses a key not bound to any of
+/// their actions, or submits an answer rejected by their validators, such as
+/// selecting more options than a validator allows.
+///
+/// # Example
+///
+/// ```
+/// use inquire::{terminal::TestBackend, ui::{Feedback, RenderConfig}, Text};
+///
+/// let mut terminal = TestBackend::new(vec![]).with_text("\nFerris\n");
+/// Text::new("Name:")
+///     .with_render_config(RenderConfig::empty().with_error_feedback(Feedback::Bell))
+///     .with_validator(inquire::required!())
+///     .prompt_with_terminal(&mut terminal)?;
+///
+/// assert_eq!(1, terminal.bells());
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Feedback {
+    /// No feedback besides the error messages of the prompt.
+    None,
+
+    /// The bell of the terminal is rung, which is audible or visual depending
+    /// on the settings of the terminal.
+    Bell,
+
+    /// The screen is briefly flashed in reverse video, in terminals supporting
+    /// it.
+    Flash,
+}
+
 /// Render configuration for error messages.
 #[derive(Copy, Clone, Debug)]
 pub struct ErrorMessageRenderConfig<'a> {
@@ -399,12 +1094,14 @@ impl<'a> ErrorMessageRenderConfig<'a> {
 
     /// Render configuration where default colors and attributes are applied.
     pub fn default_colored() -> Self {
-        Self {
-            prefix: Styled::new("#").with_fg(Color::LightRed),
-            separator: StyleSheet::empty(),
-            message: StyleSheet::empty().with_fg(Color::LightRed),
-            default_message: "Invalid input.",
-        }
+        Self::empty().with_palette(Palette::standard())
+    }
+
+    /// Sets the color of the prefix and message to the danger color of the palette.
+    pub fn with_palette(mut self, palette: Palette) -> Self {
+        self.prefix.style.fg = palette.danger;
+        self.message.fg = palette.danger;
+        self
     }
 
     /// Sets the prefix.
@@ -427,13 +1124,31 @@ impl<'a> ErrorMessageRenderConfig<'a> {
         self.message = message;
         self
     }
+
+    /// Layers the style sheets and tokens of `other` on top of this
+    /// configuration, see [`RenderConfig::merge`].
+    pub fn merge(self, other: ErrorMessageRenderConfig<'a>) -> Self {
+        let empty = Self::empty();
+
+        Self {
+            prefix: merge_token(self.prefix, other.prefix, empty.prefix),
+            separator: other.separator.inherit(self.separator),
+            message: other.message.inherit(self.message),
+            default_message: match other.default_message == empty.default_message {
+                true => self.default_message,
+                false => other.default_message,
+            },
+        }
+    }
 }
 
 #[cfg(feature = "date")]
 pub mod calendar {
     //! Module containing additional render config for date prompts.
 
-    use super::{Color, StyleSheet, Styled};
+    use crate::ui::{Color, Palette, StyleSheet, Styled};
+
+    use super::{merge_optional, merge_token};
 
     /// Calendar configuration for error messages.
     #[derive(Copy, Clone, Debug)]
@@ -447,6 +1162,13 @@ pub mod calendar {
         /// Style sheet for the calendar week header, e.g. su mo tu we th fr sa.
         pub week_header: StyleSheet,
 
+        /// Whether to render the ISO week number of each row on the left of
+        /// the calendar.
+        pub week_numbers: bool,
+
+        /// Style sheet for the ISO week numbers.
+        pub week_number: StyleSheet,
+
         /// Style sheet for the currently selected date.
         ///
         /// When `None`, no custom style sheet will be applied and the native
@@ -465,7 +1187,7 @@ pub mod calendar {
         pub different_month_date: StyleSheet,
 
         /// Style sheet for dates that can not be selected due to the
-        /// min/max settings.
+        /// min/max settings or the disabled dates.
         pub unavailable_date: StyleSheet,
     }
 
@@ -476,6 +1198,8 @@ pub mod calendar {
                 prefix: Styled::new(">"),
                 header: StyleSheet::empty(),
                 week_header: StyleSheet::empty(),
+                week_numbers: false,
+                week_number: StyleSheet::empty(),
                 selected_date: None,
                 today_date: StyleSheet::empty(),
                 different_month_date: StyleSheet::empty(),
@@ -485,19 +1209,27 @@ pub mod calendar {
 
         /// Render configuration where default colors and attributes are applied.
         pub fn default_colored() -> Self {
+            let selected_date = StyleSheet::empty()
+                .with_fg(Color::Black)
+                .with_bg(Color::Grey);
+
             Self {
-                prefix: Styled::new(">").with_fg(Color::LightGreen),
-                header: StyleSheet::empty(),
-                week_header: StyleSheet::empty(),
-                selected_date: Some(
-                    StyleSheet::empty()
-                        .with_fg(Color::Black)
-                        .with_bg(Color::Grey),
-                ),
-                today_date: StyleSheet::empty().with_fg(Color::LightGreen),
-                different_month_date: StyleSheet::empty().with_fg(Color::DarkGrey),
-                unavailable_date: StyleSheet::empty().with_fg(Color::DarkGrey),
+                selected_date: Some(selected_date),
+                ..Self::empty()
             }
+            .with_palette(Palette::standard())
+        }
+
+        /// Sets the colors of the prefix and today's date to the success color
+        /// of the palette, and of week numbers and dates outside of the current
+        /// month or range to the muted color.
+        pub fn with_palette(mut self, palette: Palette) -> Self {
+            self.prefix.style.fg = palette.success;
+            self.today_date.fg = palette.success;
+            self.week_number.fg = palette.muted;
+            self.different_month_date.fg = palette.muted;
+            self.unavailable_date.fg = palette.muted;
+            self
         }
 
         /// Sets the prefix.
@@ -505,5 +1237,30 @@ pub mod calendar {
             self.prefix = prefix;
             self
         }
+
+        /// Sets whether to render the ISO week number of each row on the left
+        /// of the calendar.
+        pub fn with_week_numbers(mut self, week_numbers: bool) -> Self {
+            self.week_numbers = week_numbers;
+            self
+        }
+
+        /// Layers the style sheets and tokens of `other` on top of this
+        /// configuration, see [`RenderConfig::merge`](crate::ui::RenderConfig::merge).
+        pub fn merge(self, other: CalendarRenderConfig<'a>) -> Self {
+            Self {
+                prefix: merge_token(self.prefix, other.prefix, Self::empty().prefix),
+                header: other.header.inherit(self.header),
+                week_header: other.week_header.inherit(self.week_header),
+                week_number: other.week_number.inherit(self.week_number),
+                selected_date: merge_optional(self.selected_date, other.selected_date),
+                today_date: other.today_date.inherit(self.today_date),
+                different_month_date: other
+                    .different_month_date
+                    .inherit(self.different_month_date),
+                unavailable_date: other.unavailable_date.inherit(self.unavailable_date),
+                ..self
+            }
+        }
     }
 }
@@ -4,7 +4,7 @@ use std::fmt::Display;
 
 use bitflags::bitflags;
 
-use super::Color;
+use super::{Color, ColorSupport};
 
 bitflags! {
     /// Attributes to apply to a text via the [StyleSheet] struct.
@@ -28,6 +28,9 @@ bitflags! {
 
         /// Emphasises the text.
         const ITALIC = 0b10;
+
+        /// Decreases the text intensity.
+        const DIM    = 0b100;
     }
 }
 
@@ -73,6 +76,16 @@ impl StyleSheet {
         }
     }
 
+    /// Copies the StyleSheet with its colors [downgraded](Color::downgrade) to
+    /// the ones supported by the terminal.
+    pub fn downgrade(self, support: ColorSupport) -> Self {
+        Self {
+            fg: self.fg.map(|fg| fg.downgrade(support)),
+            bg: self.bg.map(|bg| bg.downgrade(support)),
+            att: self.att,
+        }
+    }
+
     /// Check if the stylesheet contains no colors and no attributes.
     pub fn is_empty(&self) -> bool {
         self.fg.is_none() && self.bg.is_none() && self.att.is_empty()
@@ -117,6 +130,27 @@ impl StyleSheet {
         self.att = attributes;
         self
     }
+
+    /// Copies the StyleSheet, taking the colors it does not set from the
+    /// parent one and adding the attributes of the parent to its own.
+    ///
+    /// ```
+    /// use inquire::ui::{Attributes, Color, StyleSheet};
+    ///
+    /// let option = StyleSheet::new().with_fg(Color::Grey).with_attr(Attributes::ITALIC);
+    /// let selected_option = StyleSheet::new().with_bg(Color::DarkBlue).inherit(option);
+    ///
+    /// assert_eq!(Some(Color::Grey), selected_option.fg);
+    /// assert_eq!(Some(Color::DarkBlue), selected_option.bg);
+    /// assert_eq!(Attributes::ITALIC, selected_option.att);
+    /// ```
+    pub fn inherit(self, parent: StyleSheet) -> Self {
+        Self {
+            fg: self.fg.or(parent.fg),
+            bg: self.bg.or(parent.bg),
+            att: self.att | parent.att,
+        }
+    }
 }
 
 impl Default for StyleSheet {
@@ -128,7 +162,7 @@ impl Default for StyleSheet {
 
 /// Represents a content that when rendered must have the associated style
 /// applied to it.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Styled<T>
 where
     T: Display,
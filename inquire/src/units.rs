@@ -0,0 +1,314 @@
+//! Parsing and formatting of values typed with human units, such as byte sizes
+//! (`10MiB`), durations (`1h30m`) and percentages (`12.5%`).
+//!
+//! These functions back the [`CustomType::bytesize`](crate::CustomType::bytesize),
+//! [`CustomType::duration`](crate::CustomType::duration) and
+//! [`CustomType::percentage`](crate::CustomType::percentage) constructors, and
+//! can be plugged into any [`CustomType`](crate::CustomType) prompt with
+//! `with_parser` and `with_unit_formatter`.
+//!
+//! Requires the `units` feature.
+//!
+//! ```
+//! use std::time::Duration;
+//! use inquire::units::{format_byte_size, parse_byte_size, parse_duration};
+//!
+//! assert_eq!(Some(10 * 1024 * 1024), parse_byte_size("10MiB"));
+//! assert_eq!(Some(Duration::from_secs(5400)), parse_duration("1h30m"));
+//! assert_eq!("1.5 KiB", format_byte_size(1536));
+//! ```
+
+use std::time::Duration;
+
+const BINARY_UNITS: &[&str] = &["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+const DURATION_UNITS: &[(&str, f64)] = &[
+    ("ms", 0.001),
+    ("s", 1.0),
+    ("sec", 1.0),
+    ("m", 60.0),
+    ("min", 60.0),
+    ("h", 3600.0),
+    ("d", 86400.0),
+];
+
+/// Splits the input into its leading number and the rest, trimming whitespace.
+fn split_number(input: &str) -> Option<(f64, &str)> {
+    let input = input.trim_start();
+    let end = input
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(input.len());
+
+    let number = input[..end].parse::<f64>().ok()?;
+    Some((number, input[end..].trim_start()))
+}
+
+/// Formats a number with at most two decimals, dropping trailing zeros.
+fn format_number(number: f64) -> String {
+    let formatted = format!("{number:.2}");
+    formatted
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_owned()
+}
+
+/// Parses a byte size such as `512`, `1.5 GB` or `10MiB`, case-insensitively.
+///
+/// Decimal units (`kB`, `MB`, `GB`, `TB`, `PB`, `EB`) are multiples of 1000 and
+/// binary units (`KiB`, `MiB`, `GiB`, `TiB`, `PiB`, `EiB`) multiples of 1024. A number
+/// without unit, or followed by `B`, is a number of bytes.
+///
+/// ```
+/// use inquire::units::parse_byte_size;
+///
+/// assert_eq!(Some(512), parse_byte_size("512"));
+/// assert_eq!(Some(1_500_000_000), parse_byte_size("1.5 GB"));
+/// assert_eq!(Some(2048), parse_byte_size("2kib"));
+/// assert_eq!(None, parse_byte_size("2 parsecs"));
+/// ```
+pub fn parse_byte_size(input: &str) -> Option<u64> {
+    let (number, unit) = split_number(input)?;
+    let unit = unit.trim_end().to_lowercase();
+
+    let mut chars = unit.chars();
+    let exponent = match chars.next() {
+        None | Some('b') if unit.len() <= 1 => 0,
+        Some('k') => 1,
+        Some('m') => 2,
+        Some('g') => 3,
+        Some('t') => 4,
+        Some('p') => 5,
+        Some('e') => 6,
+        _ => return None,
+    };
+    let base: f64 = match chars.as_str() {
+        "" | "b" => 1000.0,
+        "i" | "ib" => 1024.0,
+        _ => return None,
+    };
+
+    let bytes = number * base.powi(exponent);
+    // `u64::MAX as f64` rounds up to 2^64, which does not fit in a `u64`.
+    if bytes >= u64::MAX as f64 {
+        return None;
+    }
+
+    Some(bytes.round() as u64)
+}
+
+/// Formats a byte size with the largest binary unit keeping the number above
+/// one, e.g. `1.5 KiB`.
+///
+/// ```
+/// use inquire::units::format_byte_size;
+///
+/// assert_eq!("512 B", format_byte_size(512));
+/// assert_eq!("10 MiB", format_byte_size(10 * 1024 * 1024));
+/// ```
+pub fn format_byte_size(bytes: u64) -> String {
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < BINARY_UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{} {}", format_number(size), BINARY_UNITS[unit])
+}
+
+/// Parses a duration made of one or more components such as `1h30m`,
+/// `1h 30m`, `1.5d` or `250ms`.
+///
+/// The supported units are `ms`, `s` (or `sec`), `m` (or `min`), `h` and `d`.
+///
+/// ```
+/// use std::time::Duration;
+/// use inquire::units::parse_duration;
+///
+/// assert_eq!(Some(Duration::from_secs(5400)), parse_duration("1h 30m"));
+/// assert_eq!(Some(Duration::from_millis(1500)), parse_duration("1.5s"));
+/// assert_eq!(None, parse_duration("90"));
+/// ```
+pub fn parse_duration(input: &str) -> Option<Duration> {
+    let mut rest = input.trim();
+    if rest.is_empty() {
+        return None;
+    }
+
+    let mut seconds = 0.0;
+    while !rest.is_empty() {
+        let (number, tail) = split_number(rest)?;
+        let unit_end = tail
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(tail.len());
+        let unit = tail[..unit_end].to_lowercase();
+        let (_, factor) = DURATION_UNITS.iter().find(|(name, _)| *name == unit)?;
+
+        seconds += number * factor;
+        rest = tail[unit_end..].trim_start();
+    }
+
+    // `u64::MAX as f64` rounds up to 2^64, which does not fit in a duration.
+    if seconds >= u64::MAX as f64 {
+        return None;
+    }
+
+    Some(Duration::from_secs_f64(seconds))
+}
+
+/// Formats a duration as its days, hours, minutes, seconds and milliseconds
+/// components, skipping the empty ones, e.g. `1h 30m`.
+///
+/// ```
+/// use std::time::Duration;
+/// use inquire::units::format_duration;
+///
+/// assert_eq!("1h 30m", format_duration(Duration::from_secs(5400)));
+/// assert_eq!("2d 5s 250ms", format_duration(Duration::from_millis(172_805_250)));
+/// assert_eq!("0s", format_duration(Duration::ZERO));
+/// ```
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let components = [
+        (secs / 86400, "d"),
+        (secs % 86400 / 3600, "h"),
+        (secs % 3600 / 60, "m"),
+        (secs % 60, "s"),
+        (u64::from(duration.subsec_millis()), "ms"),
+    ];
+
+    let formatted: Vec<String> = components
+        .iter()
+        .filter(|(value, _)| *value > 0)
+        .map(|(value, unit)| format!("{value}{unit}"))
+        .collect();
+
+    match formatted.is_empty() {
+        true => String::from("0s"),
+        false => formatted.join(" "),
+    }
+}
+
+/// Parses a percentage such as `50%` or `12.5 %` into a ratio, `0.5` and
+/// `0.125` respectively. The `%` sign is optional.
+///
+/// ```
+/// use inquire::units::parse_percentage;
+///
+/// assert_eq!(Some(0.5), parse_percentage("50%"));
+/// assert_eq!(Some(0.125), parse_percentage("12.5"));
+/// assert_eq!(None, parse_percentage("half"));
+/// ```
+pub fn parse_percentage(input: &str) -> Option<f64> {
+    let input = input.trim();
+    let number = input.strip_suffix('%').unwrap_or(input).trim_end();
+
+    number
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite())
+        .map(|value| value / 100.0)
+}
+
+/// Formats a ratio as a percentage with at most two decimals, e.g. `12.5%`.
+///
+/// ```
+/// use inquire::units::format_percentage;
+///
+/// assert_eq!("12.5%", format_percentage(0.125));
+/// ```
+pub fn format_percentage(ratio: f64) -> String {
+    format!("{}%", format_number(ratio * 100.0))
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{
+        format_byte_size, format_duration, format_percentage, parse_byte_size, parse_duration,
+        parse_percentage,
+    };
+
+    #[test]
+    fn byte_sizes_support_decimal_and_binary_units() {
+        assert_eq!(Some(0), parse_byte_size("0"));
+        assert_eq!(Some(42), parse_byte_size("42 B"));
+        assert_eq!(Some(1000), parse_byte_size("1kB"));
+        assert_eq!(Some(1024), parse_byte_size("1 KiB"));
+        assert_eq!(Some(3_000_000), parse_byte_size("3M"));
+        assert_eq!(Some(5 * 1024u64.pow(4)), parse_byte_size("5TiB"));
+        assert_eq!(None, parse_byte_size("MiB"));
+        assert_eq!(None, parse_byte_size("10 MiBs"));
+        assert_eq!(None, parse_byte_size("-1"));
+        assert_eq!(None, parse_byte_size("20000PB"));
+        assert_eq!(Some(2 * 1024u64.pow(6)), parse_byte_size("2 EiB"));
+        assert_eq!(Some(3 * 1000u64.pow(6)), parse_byte_size("3EB"));
+    }
+
+    #[test]
+    fn byte_sizes_overflowing_u64_are_rejected() {
+        assert_eq!(
+            Some(18_446_744_073_709_549_568),
+            parse_byte_size("18446744073709549568")
+        );
+        assert_eq!(None, parse_byte_size("18446744073709551616B"));
+        assert_eq!(None, parse_byte_size("18446744073709551616"));
+        assert_eq!(None, parse_byte_size("16 EiB"));
+        assert_eq!(None, parse_byte_size(&"9".repeat(400)));
+    }
+
+    #[test]
+    fn byte_sizes_are_formatted_with_binary_units() {
+        assert_eq!("0 B", format_byte_size(0));
+        assert_eq!("1023 B", format_byte_size(1023));
+        assert_eq!("1 KiB", format_byte_size(1024));
+        assert_eq!("1.33 GiB", format_byte_size(1_428_000_000));
+        assert_eq!("2 EiB", format_byte_size(2 * 1024u64.pow(6)));
+        assert_eq!("16 EiB", format_byte_size(u64::MAX));
+    }
+
+    #[test]
+    fn durations_add_their_components() {
+        assert_eq!(Some(Duration::from_secs(90)), parse_duration("90s"));
+        assert_eq!(Some(Duration::from_secs(90)), parse_duration("1m 30sec"));
+        assert_eq!(Some(Duration::from_secs(129_600)), parse_duration("1.5d"));
+        assert_eq!(
+            Some(Duration::from_millis(3_600_250)),
+            parse_duration("1H250ms")
+        );
+        assert_eq!(None, parse_duration(""));
+        assert_eq!(None, parse_duration("1h 30"));
+        assert_eq!(None, parse_duration("1 week"));
+    }
+
+    #[test]
+    fn durations_overflowing_u64_seconds_are_rejected() {
+        assert_eq!(
+            Some(Duration::from_secs(18_446_744_073_709_549_568)),
+            parse_duration("18446744073709549568s")
+        );
+        assert_eq!(None, parse_duration("18446744073709551616s"));
+        assert_eq!(None, parse_duration("18446744073709551615s"));
+        assert_eq!(None, parse_duration(&format!("{}s", "9".repeat(400))));
+    }
+
+    #[test]
+    fn durations_round_trip() {
+        for input in ["1h 30m", "2d 5s", "45m 10s 5ms"] {
+            assert_eq!(input, format_duration(parse_duration(input).unwrap()));
+        }
+    }
+
+    #[test]
+    fn percentages_are_ratios() {
+        assert_eq!(Some(1.0), parse_percentage("100 %"));
+        assert_eq!(Some(-0.05), parse_percentage("-5%"));
+        assert_eq!(None, parse_percentage("%"));
+        assert_eq!(None, parse_percentage("NaN%"));
+        assert_eq!(None, parse_percentage("inf%"));
+        assert_eq!(None, parse_percentage("-infinity"));
+        assert_eq!("50%", format_percentage(0.5));
+        assert_eq!("33.33%", format_percentage(1.0 / 3.0));
+    }
+}
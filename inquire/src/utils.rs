@@ -1,6 +1,6 @@
 // sorry for this file
 
-use std::fmt::Debug;
+use std::{borrow::Cow, fmt::Debug};
 
 pub struct Page<'a, T> {
     /// Whether this is the first page.
@@ -19,7 +19,7 @@ pub struct Page<'a, T> {
     pub total: usize,
 }
 
-pub fn paginate<T>(page_size: usize, choices: &[T], sel: Option<usize>) -> Page<T> {
+pub fn paginate<T>(page_size: usize, choices: &[T], sel: Option<usize>) -> Page<'_, T> {
     // if there is no selection, we default to the first page.
     // in practice, the same as selecting the 0 index.
 
@@ -68,6 +68,44 @@ pub fn paginate<T>(page_size: usize, choices: &[T], sel: Option<usize>) -> Page<
     }
 }
 
+/// Paginates the choices starting from the page that begins at `start`, e.g. the
+/// one rendered in the previous frame, and scrolling it only as much as needed
+/// to keep `margin` choices between the selected one and the edges of the page.
+///
+/// The margin is capped to half of the page, in which case the selected choice
+/// stays in the middle of the page.
+pub fn paginate_with_margin<T>(
+    page_size: usize,
+    choices: &[T],
+    sel: usize,
+    start: usize,
+    margin: usize,
+) -> Page<'_, T> {
+    if choices.len() <= page_size {
+        return paginate(page_size, choices, Some(sel));
+    }
+
+    let sel = sel.min(choices.len() - 1);
+    let margin = margin.min(page_size.saturating_sub(1) / 2);
+
+    let mut start = start;
+    if sel < start + margin {
+        start = sel.saturating_sub(margin);
+    } else if sel + margin >= start + page_size {
+        start = sel + margin + 1 - page_size;
+    }
+    let start = start.min(choices.len() - page_size);
+    let end = start + page_size;
+
+    Page {
+        first: start == 0,
+        last: end == choices.len(),
+        content: &choices[start..end],
+        cursor: Some(sel - start),
+        total: choices.len(),
+    }
+}
+
 pub fn int_log10<T>(mut i: T) -> usize
 where
     T: std::ops::DivAssign + std::cmp::PartialOrd + From<u8> + Copy,
@@ -84,15 +122,50 @@ where
     len
 }
 
+/// Lowercases the text, only allocating when it has uppercase characters.
+pub fn to_lowercase(text: &str) -> Cow<'_, str> {
+    match text
+        .chars()
+        .all(|c| c.to_lowercase().eq(std::iter::once(c)))
+    {
+        true => Cow::Borrowed(text),
+        false => Cow::Owned(text.to_lowercase()),
+    }
+}
+
+impl<'a, T> Debug for Page<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Page")
+            .field("first", &self.first)
+            .field("last", &self.last)
+            .field("content", &format!("({} elements)", &self.content.len()))
+            .field("cursor", &self.cursor)
+            .field("total", &self.total)
+            .finish()
+    }
+}
+
 #[cfg(test)]
 mod test {
     #![allow(clippy::bool_assert_comparison)]
 
+    use std::borrow::Cow;
+
     use crate::{
         list_option::ListOption,
-        utils::{int_log10, paginate},
+        utils::{int_log10, paginate, paginate_with_margin, to_lowercase},
     };
 
+    #[test]
+    fn to_lowercase_only_allocates_for_uppercase_text() {
+        assert!(matches!(
+            to_lowercase("san josé"),
+            Cow::Borrowed("san josé")
+        ));
+        assert_eq!("san josé", to_lowercase("San JOSÉ"));
+        assert_eq!("ὀδυσσεύς", to_lowercase("ὈΔΥΣΣΕΎΣ"));
+    }
+
     #[test]
     fn int_log10_works() {
         for i in 1..10 {
@@ -239,16 +312,40 @@ mod test {
         assert_eq!(false, page.last);
         assert_eq!(6, page.total);
     }
-}
 
-impl<'a, T> Debug for Page<'a, T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("Page")
-            .field("first", &self.first)
-            .field("last", &self.last)
-            .field("content", &format!("({} elements)", &self.content.len()))
-            .field("cursor", &self.cursor)
-            .field("total", &self.total)
-            .finish()
+    #[test]
+    fn paginate_with_margin_scrolls_only_near_the_edges() {
+        let choices = ListOption::from_list((1..=10).collect());
+
+        // moving down within the page keeps it in place
+        let page = paginate_with_margin(5, &choices, 2, 0, 1);
+        assert_eq!(choices[0..5], page.content[..]);
+        assert_eq!(Some(2), page.cursor);
+
+        // the cursor gets closer than the margin to the bottom edge
+        let page = paginate_with_margin(5, &choices, 4, 0, 1);
+        assert_eq!(choices[1..6], page.content[..]);
+        assert_eq!(Some(3), page.cursor);
+
+        // moving back up keeps the scrolled page
+        let page = paginate_with_margin(5, &choices, 3, 1, 1);
+        assert_eq!(choices[1..6], page.content[..]);
+        assert_eq!(Some(2), page.cursor);
+
+        // the margin is not kept past the end of the list
+        let page = paginate_with_margin(5, &choices, 9, 1, 1);
+        assert_eq!(choices[5..10], page.content[..]);
+        assert_eq!(Some(4), page.cursor);
+        assert_eq!(true, page.last);
+    }
+
+    #[test]
+    fn paginate_with_margin_caps_the_margin_to_half_a_page() {
+        let choices = ListOption::from_list((1..=10).collect());
+
+        let page = paginate_with_margin(5, &choices, 5, 0, 10);
+
+        assert_eq!(choices[3..8], page.content[..]);
+        assert_eq!(Some(2), page.cursor);
     }
 }
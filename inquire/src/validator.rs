@@ -12,9 +12,23 @@
 //! This module also provides several macros as shorthands to the struct
 //! constructor functions, exported with the `macros` feature.
 
+use std::{
+    fmt::Display,
+    ops::{Bound, RangeBounds},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
 use dyn_clone::DynClone;
 
-use crate::{error::CustomUserError, list_option::ListOption};
+use crate::{
+    config::{translate, translate_template},
+    error::CustomUserError,
+    form::Answers,
+    list_option::ListOption,
+};
 
 /// Error message that is displayed to the users when their input is considered not
 /// valid by registered validators.
@@ -109,6 +123,87 @@ where
     }
 }
 
+/// Flag set once the result of a [`BackgroundStringValidator`] is no longer
+/// needed, e.g. because the user pressed ESC to go back to editing their answer.
+///
+/// Cancellation is cooperative: validators should check the flag regularly and
+/// return early once it is set, as their result is discarded anyway.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    canceled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Creates a token that is not canceled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the validation was canceled.
+    pub fn is_canceled(&self) -> bool {
+        self.canceled.load(Ordering::SeqCst)
+    }
+
+    /// Cancels the validation.
+    pub fn cancel(&self) {
+        self.canceled.store(true, Ordering::SeqCst);
+    }
+}
+
+/// Validator used in [`Text`](crate::Text) and [`CustomType`](crate::CustomType)
+/// prompts for slow checks, such as network lookups, which runs on a worker thread.
+///
+/// While it runs, the prompt renders a spinner with the elapsed time, and the
+/// user can press ESC to cancel the validation and go back to editing.
+/// Background validators only run once all regular validators accepted the input.
+///
+/// # Examples
+///
+/// ```
+/// use std::{thread, time::Duration};
+/// use inquire::validator::{BackgroundStringValidator, CancellationToken, Validation};
+///
+/// let validator = |input: &str, cancellation: &CancellationToken| {
+///     for _ in 0..10 {
+///         if cancellation.is_canceled() {
+///             break;
+///         }
+///         thread::sleep(Duration::from_millis(1));
+///     }
+///
+///     match input {
+///         "taken" => Ok(Validation::Invalid("This username is already taken".into())),
+///         _ => Ok(Validation::Valid),
+///     }
+/// };
+///
+/// let token = CancellationToken::new();
+/// assert_eq!(Validation::Valid, validator.validate("free", &token)?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+pub trait BackgroundStringValidator: Send + Sync {
+    /// Confirm the given input string is a valid value, returning early
+    /// when the cancellation token is set.
+    fn validate(
+        &self,
+        input: &str,
+        cancellation: &CancellationToken,
+    ) -> Result<Validation, CustomUserError>;
+}
+
+impl<F> BackgroundStringValidator for F
+where
+    F: Fn(&str, &CancellationToken) -> Result<Validation, CustomUserError> + Send + Sync,
+{
+    fn validate(
+        &self,
+        input: &str,
+        cancellation: &CancellationToken,
+    ) -> Result<Validation, CustomUserError> {
+        (self)(input, cancellation)
+    }
+}
+
 /// Validator used in [`DateSelect`](crate::DateSelect) prompts.
 ///
 /// If the input provided by the user is valid, your validator should return `Ok(Validation::Valid)`.
@@ -269,6 +364,49 @@ where
     }
 }
 
+/// Validator used in [`Form`](crate::form::Form)s, called with the whole answer
+/// set once every question of the form has been answered.
+///
+/// If the answers are valid, your validator should return `Ok(Validation::Valid)`.
+///
+/// If the answers are not valid, your validator should return `Ok(Validation::Invalid(ErrorMessage))`,
+/// where the content of `ErrorMessage` is recommended to be a string whose content will be displayed
+/// to the user as an error message. It is also recommended that this value gives a helpful feedback to the user.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::form::Answers;
+/// use inquire::validator::{FormValidator, Validation};
+///
+/// let validator = |answers: &Answers| match answers.get::<String>("name") {
+///     Some(name) if name == "root" => Ok(Validation::Invalid("Reserved name".into())),
+///     _ => Ok(Validation::Valid),
+/// };
+///
+/// assert_eq!(Validation::Valid, validator.validate(&Answers::default())?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+pub trait FormValidator: DynClone {
+    /// Confirm the given answer set is valid.
+    fn validate(&self, answers: &Answers) -> Result<Validation, CustomUserError>;
+}
+
+impl Clone for Box<dyn FormValidator> {
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+impl<F> FormValidator for F
+where
+    F: Fn(&Answers) -> Result<Validation, CustomUserError> + Clone,
+{
+    fn validate(&self, answers: &Answers) -> Result<Validation, CustomUserError> {
+        (self)(answers)
+    }
+}
+
 /// Custom trait to call correct method to retrieve input length.
 ///
 /// The method can vary depending on the type of input.
@@ -332,7 +470,7 @@ impl Default for ValueRequiredValidator {
     /// `A response is required`.
     fn default() -> Self {
         Self {
-            message: "A response is required.".to_owned(),
+            message: translate("A response is required.").to_owned(),
         }
     }
 }
@@ -417,7 +555,7 @@ impl MaxLengthValidator {
     pub fn new(limit: usize) -> Self {
         Self {
             limit,
-            message: format!("The length of the response should be at most {limit}"),
+            message: translate_template("The length of the response should be at most {}", limit),
         }
     }
 
@@ -526,7 +664,7 @@ impl MinLengthValidator {
     pub fn new(limit: usize) -> Self {
         Self {
             limit,
-            message: format!("The length of the response should be at least {limit}"),
+            message: translate_template("The length of the response should be at least {}", limit),
         }
     }
 
@@ -632,7 +770,7 @@ impl ExactLengthValidator {
     pub fn new(length: usize) -> Self {
         Self {
             length,
-            message: format!("The length of the response should be {length}"),
+            message: translate_template("The length of the response should be {}", length),
         }
     }
 
@@ -702,14 +840,648 @@ macro_rules! length {
     };
 }
 
+/// Extension methods to compose validators.
+///
+/// The resulting validators implement every validator trait implemented by
+/// all of the composed validators, so they can be used on any prompt the
+/// original validators could be used on.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{
+///     MaxLengthValidator, StringValidator, Validation, ValidatorExt, ValueRequiredValidator,
+/// };
+///
+/// let validator = ValueRequiredValidator::default().and(MaxLengthValidator::new(5));
+/// assert_eq!(Validation::Valid, validator.validate("Good")?);
+/// assert_eq!(Validation::Invalid("A response is required.".into()), validator.validate("")?);
+/// assert_eq!(
+///     Validation::Invalid("The length of the response should be at most 5".into()),
+///     validator.validate("Terrible")?,
+/// );
+///
+/// let validator = MaxLengthValidator::new(5).not("Too short!");
+/// assert_eq!(Validation::Valid, validator.validate("Terrible")?);
+/// assert_eq!(Validation::Invalid("Too short!".into()), validator.validate("Good")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+pub trait ValidatorExt: Sized {
+    /// Combines this validator with another one, only considering the input
+    /// valid if both validators do.
+    ///
+    /// The second validator is not called if the first one fails, in which
+    /// case the error message of the first validator is returned.
+    fn and<V>(self, other: V) -> And<Self, V> {
+        And {
+            first: self,
+            second: other,
+        }
+    }
+
+    /// Combines this validator with another one, considering the input
+    /// valid if any of the validators do.
+    ///
+    /// The second validator is not called if the first one succeeds. When both
+    /// fail, the error message of the second validator is returned.
+    fn or<V>(self, other: V) -> Or<Self, V> {
+        Or {
+            first: self,
+            second: other,
+        }
+    }
+
+    /// Inverts this validator, considering the input valid only if the
+    /// validator does not, and returning the given error message otherwise.
+    fn not(self, message: impl Into<String>) -> Not<Self> {
+        Not {
+            validator: self,
+            message: message.into(),
+        }
+    }
+
+    /// Maps the error message returned by this validator when the input is
+    /// invalid.
+    fn map_err<F>(self, mapper: F) -> MapErr<Self, F>
+    where
+        F: Fn(ErrorMessage) -> ErrorMessage,
+    {
+        MapErr {
+            validator: self,
+            mapper,
+        }
+    }
+}
+
+impl<V> ValidatorExt for V where V: Clone {}
+
+/// Validator created by [`ValidatorExt::and`].
+#[derive(Clone)]
+pub struct And<A, B> {
+    first: A,
+    second: B,
+}
+
+/// Validator created by [`ValidatorExt::or`].
+#[derive(Clone)]
+pub struct Or<A, B> {
+    first: A,
+    second: B,
+}
+
+/// Validator created by [`ValidatorExt::not`].
+#[derive(Clone)]
+pub struct Not<V> {
+    validator: V,
+    message: String,
+}
+
+/// Validator created by [`ValidatorExt::map_err`].
+#[derive(Clone)]
+pub struct MapErr<V, F> {
+    validator: V,
+    mapper: F,
+}
+
+macro_rules! impl_combinators {
+    ($trait:ident$(<$generic:ident>)?, $input:ty) => {
+        impl<A, B $(, $generic: ?Sized)?> $trait$(<$generic>)? for And<A, B>
+        where
+            A: $trait$(<$generic>)? + Clone,
+            B: $trait$(<$generic>)? + Clone,
+        {
+            fn validate(&self, input: $input) -> Result<Validation, CustomUserError> {
+                match $trait::validate(&self.first, input)? {
+                    Validation::Valid => $trait::validate(&self.second, input),
+                    invalid => Ok(invalid),
+                }
+            }
+        }
+
+        impl<A, B $(, $generic: ?Sized)?> $trait$(<$generic>)? for Or<A, B>
+        where
+            A: $trait$(<$generic>)? + Clone,
+            B: $trait$(<$generic>)? + Clone,
+        {
+            fn validate(&self, input: $input) -> Result<Validation, CustomUserError> {
+                match $trait::validate(&self.first, input)? {
+                    Validation::Valid => Ok(Validation::Valid),
+                    Validation::Invalid(_) => $trait::validate(&self.second, input),
+                }
+            }
+        }
+
+        impl<V $(, $generic: ?Sized)?> $trait$(<$generic>)? for Not<V>
+        where
+            V: $trait$(<$generic>)? + Clone,
+        {
+            fn validate(&self, input: $input) -> Result<Validation, CustomUserError> {
+                Ok(match $trait::validate(&self.validator, input)? {
+                    Validation::Valid => Validation::Invalid(self.message.as_str().into()),
+                    Validation::Invalid(_) => Validation::Valid,
+                })
+            }
+        }
+
+        impl<V, F $(, $generic: ?Sized)?> $trait$(<$generic>)? for MapErr<V, F>
+        where
+            V: $trait$(<$generic>)? + Clone,
+            F: Fn(ErrorMessage) -> ErrorMessage + Clone,
+        {
+            fn validate(&self, input: $input) -> Result<Validation, CustomUserError> {
+                Ok(match $trait::validate(&self.validator, input)? {
+                    Validation::Valid => Validation::Valid,
+                    Validation::Invalid(message) => Validation::Invalid((self.mapper)(message)),
+                })
+            }
+        }
+    };
+}
+
+impl_combinators!(StringValidator, &str);
+#[cfg(feature = "date")]
+impl_combinators!(DateValidator, chrono::NaiveDate);
+impl_combinators!(MultiOptionValidator<T>, &[ListOption<&T>]);
+impl_combinators!(CustomTypeValidator<T>, &T);
+impl_combinators!(FormValidator, &Answers);
+
+/// Built-in validator that checks whether the answer matches the given
+/// regular expression.
+///
+/// Requires the `regex` feature.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{RegexValidator, StringValidator, Validation};
+/// use regex::Regex;
+///
+/// let validator = RegexValidator::new(Regex::new(r"^\d{3}-\d{4}$").unwrap());
+/// assert_eq!(Validation::Valid, validator.validate("555-1234")?);
+/// assert_eq!(
+///     Validation::Invalid("The response does not match the expected format".into()),
+///     validator.validate("5551234")?,
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[cfg(feature = "regex")]
+#[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
+#[derive(Clone)]
+pub struct RegexValidator {
+    regex: regex::Regex,
+    message: String,
+}
+
+#[cfg(feature = "regex")]
+impl RegexValidator {
+    /// Create a new instance of this validator, requiring the answer to match
+    /// the given regular expression, otherwise returning an error with default message.
+    pub fn new(regex: regex::Regex) -> Self {
+        Self {
+            regex,
+            message: "The response does not match the expected format".to_owned(),
+        }
+    }
+
+    /// Define a custom error message returned by the validator.
+    /// Defaults to `The response does not match the expected format`.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+}
+
+#[cfg(feature = "regex")]
+impl StringValidator for RegexValidator {
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        Ok(if self.regex.is_match(input) {
+            Validation::Valid
+        } else {
+            Validation::Invalid(self.message.as_str().into())
+        })
+    }
+}
+
+/// Built-in validator that checks whether a value is within the given range.
+///
+/// Intended for [`CustomType`](crate::CustomType) prompts parsing numbers, but
+/// works with any type implementing `PartialOrd`.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{CustomTypeValidator, RangeValidator, Validation};
+///
+/// let validator = RangeValidator::new(1..=10);
+/// assert_eq!(Validation::Valid, validator.validate(&10)?);
+/// assert_eq!(
+///     Validation::Invalid("The value should be between 1 and 10".into()),
+///     validator.validate(&11)?,
+/// );
+///
+/// let validator = RangeValidator::new(0.0..);
+/// assert_eq!(Validation::Valid, validator.validate(&0.5)?);
+/// assert_eq!(
+///     Validation::Invalid("The value should be at least 0".into()),
+///     validator.validate(&-0.5)?,
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Clone)]
+pub struct RangeValidator<T> {
+    start: Bound<T>,
+    end: Bound<T>,
+    message: String,
+}
+
+impl<T> RangeValidator<T>
+where
+    T: Clone + PartialOrd + Display,
+{
+    /// Create a new instance of this validator, requiring the value to be
+    /// within the given range, otherwise returning an error with default message.
+    pub fn new(range: impl RangeBounds<T>) -> Self {
+        let start = range.start_bound().cloned();
+        let end = range.end_bound().cloned();
+
+        let message = match (&start, &end) {
+            (Bound::Included(min), Bound::Included(max)) => {
+                format!("The value should be between {min} and {max}")
+            }
+            (Bound::Included(min), Bound::Excluded(max)) => {
+                format!("The value should be at least {min} and less than {max}")
+            }
+            (Bound::Excluded(min), Bound::Included(max)) => {
+                format!("The value should be greater than {min} and at most {max}")
+            }
+            (Bound::Excluded(min), Bound::Excluded(max)) => {
+                format!("The value should be greater than {min} and less than {max}")
+            }
+            (Bound::Included(min), Bound::Unbounded) => {
+                format!("The value should be at least {min}")
+            }
+            (Bound::Excluded(min), Bound::Unbounded) => {
+                format!("The value should be greater than {min}")
+            }
+            (Bound::Unbounded, Bound::Included(max)) => {
+                format!("The value should be at most {max}")
+            }
+            (Bound::Unbounded, Bound::Excluded(max)) => {
+                format!("The value should be less than {max}")
+            }
+            (Bound::Unbounded, Bound::Unbounded) => String::new(),
+        };
+
+        Self {
+            start,
+            end,
+            message,
+        }
+    }
+
+    /// Define a custom error message returned by the validator.
+    /// Defaults to a message describing the range, e.g. `The value should be between 1 and 10`.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+}
+
+impl<T> CustomTypeValidator<T> for RangeValidator<T>
+where
+    T: Clone + PartialOrd,
+{
+    fn validate(&self, input: &T) -> Result<Validation, CustomUserError> {
+        // `Bound::as_ref` requires Rust 1.65, above our MSRV.
+        let above_start = match &self.start {
+            Bound::Included(start) => input >= start,
+            Bound::Excluded(start) => input > start,
+            Bound::Unbounded => true,
+        };
+        let below_end = match &self.end {
+            Bound::Included(end) => input <= end,
+            Bound::Excluded(end) => input < end,
+            Bound::Unbounded => true,
+        };
+        let in_range = above_start && below_end;
+
+        Ok(if in_range {
+            Validation::Valid
+        } else {
+            Validation::Invalid(self.message.as_str().into())
+        })
+    }
+}
+
+/// Built-in validator that checks whether the answer looks like an email address.
+///
+/// The check is purely structural: the answer must have a non-empty local part
+/// and a domain containing at least one dot, separated by a single `@`, and no
+/// whitespace. It does not verify that the address actually exists.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{EmailValidator, StringValidator, Validation};
+///
+/// let validator = EmailValidator::default();
+/// assert_eq!(Validation::Valid, validator.validate("jane@example.com")?);
+/// assert_eq!(
+///     Validation::Invalid("The response should be a valid email address".into()),
+///     validator.validate("jane@localhost")?,
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Clone)]
+pub struct EmailValidator {
+    message: String,
+}
+
+impl EmailValidator {
+    /// Create a new instance of this validator with given error message.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+
+    fn is_email(input: &str) -> bool {
+        let (local, domain) = match input.split_once('@') {
+            Some(parts) => parts,
+            None => return false,
+        };
+
+        !local.is_empty()
+            && !domain.contains('@')
+            && !input.chars().any(char::is_whitespace)
+            && domain.contains('.')
+            && domain.split('.').all(|label| !label.is_empty())
+    }
+}
+
+impl Default for EmailValidator {
+    /// Create a new instance of this validator with the default error message
+    /// `The response should be a valid email address`.
+    fn default() -> Self {
+        Self::new("The response should be a valid email address")
+    }
+}
+
+impl StringValidator for EmailValidator {
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        Ok(if Self::is_email(input) {
+            Validation::Valid
+        } else {
+            Validation::Invalid(self.message.as_str().into())
+        })
+    }
+}
+
+/// Built-in validator that checks whether the answer looks like a URL, in the
+/// form of `scheme://rest`.
+///
+/// The accepted schemes can be restricted with [`UrlValidator::with_schemes`].
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{StringValidator, UrlValidator, Validation};
+///
+/// let validator = UrlValidator::default();
+/// assert_eq!(Validation::Valid, validator.validate("ftp://example.com/file")?);
+/// assert_eq!(
+///     Validation::Invalid("The response should be a valid URL".into()),
+///     validator.validate("example.com")?,
+/// );
+///
+/// let validator = UrlValidator::default().with_schemes(&["https"]);
+/// assert_eq!(Validation::Valid, validator.validate("https://example.com")?);
+/// assert_eq!(
+///     Validation::Invalid("The response should be a valid URL".into()),
+///     validator.validate("http://example.com")?,
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Clone)]
+pub struct UrlValidator {
+    schemes: Option<Vec<String>>,
+    message: String,
+}
+
+impl UrlValidator {
+    /// Create a new instance of this validator with given error message.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            schemes: None,
+            message: message.into(),
+        }
+    }
+
+    /// Restricts the accepted URL schemes, compared case-insensitively.
+    pub fn with_schemes(mut self, schemes: &[&str]) -> Self {
+        self.schemes = Some(schemes.iter().map(|s| s.to_lowercase()).collect());
+        self
+    }
+
+    fn is_url(&self, input: &str) -> bool {
+        let (scheme, rest) = match input.split_once("://") {
+            Some(parts) => parts,
+            None => return false,
+        };
+
+        let valid_scheme = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.');
+        let allowed_scheme = match &self.schemes {
+            Some(schemes) => schemes.contains(&scheme.to_lowercase()),
+            None => true,
+        };
+
+        valid_scheme
+            && allowed_scheme
+            && !rest.is_empty()
+            && !rest.starts_with('/')
+            && !rest.chars().any(char::is_whitespace)
+    }
+}
+
+impl Default for UrlValidator {
+    /// Create a new instance of this validator with the default error message
+    /// `The response should be a valid URL`.
+    fn default() -> Self {
+        Self::new("The response should be a valid URL")
+    }
+}
+
+impl StringValidator for UrlValidator {
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        Ok(if self.is_url(input) {
+            Validation::Valid
+        } else {
+            Validation::Invalid(self.message.as_str().into())
+        })
+    }
+}
+
+/// Built-in validator that checks whether the answer is a path that exists
+/// in the file system.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{PathExistsValidator, StringValidator, Validation};
+///
+/// let validator = PathExistsValidator::default();
+/// assert_eq!(Validation::Valid, validator.validate(".")?);
+/// assert_eq!(
+///     Validation::Invalid("The path does not exist".into()),
+///     validator.validate("./this/path/does/not/exist")?,
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Clone)]
+pub struct PathExistsValidator {
+    message: String,
+}
+
+impl PathExistsValidator {
+    /// Create a new instance of this validator with given error message.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl Default for PathExistsValidator {
+    /// Create a new instance of this validator with the default error message
+    /// `The path does not exist`.
+    fn default() -> Self {
+        Self::new("The path does not exist")
+    }
+}
+
+impl StringValidator for PathExistsValidator {
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        Ok(if std::path::Path::new(input).exists() {
+            Validation::Valid
+        } else {
+            Validation::Invalid(self.message.as_str().into())
+        })
+    }
+}
+
+/// Built-in validator that checks whether the answer contains at least one
+/// character that is not whitespace.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{NonWhitespaceValidator, StringValidator, Validation};
+///
+/// let validator = NonWhitespaceValidator::default();
+/// assert_eq!(Validation::Valid, validator.validate(" a ")?);
+/// assert_eq!(
+///     Validation::Invalid("The response can not be blank".into()),
+///     validator.validate(" \t ")?,
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Clone)]
+pub struct NonWhitespaceValidator {
+    message: String,
+}
+
+impl NonWhitespaceValidator {
+    /// Create a new instance of this validator with given error message.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl Default for NonWhitespaceValidator {
+    /// Create a new instance of this validator with the default error message
+    /// `The response can not be blank`.
+    fn default() -> Self {
+        Self::new("The response can not be blank")
+    }
+}
+
+impl StringValidator for NonWhitespaceValidator {
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        Ok(if input.chars().any(|c| !c.is_whitespace()) {
+            Validation::Valid
+        } else {
+            Validation::Invalid(self.message.as_str().into())
+        })
+    }
+}
+
+/// Built-in validator that checks whether the answer size in bytes, when encoded
+/// as UTF-8, is smaller than or equal to the specified threshold.
+///
+/// Unlike [`MaxLengthValidator`], which counts graphemes, this validator is
+/// meant for values stored in fields with a byte-size limit.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{MaxByteLengthValidator, StringValidator, Validation};
+///
+/// let validator = MaxByteLengthValidator::new(4);
+/// assert_eq!(Validation::Valid, validator.validate("Good")?);
+/// assert_eq!(
+///     Validation::Invalid("The response should be at most 4 bytes long".into()),
+///     validator.validate("Göod")?,
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Clone)]
+pub struct MaxByteLengthValidator {
+    limit: usize,
+    message: String,
+}
+
+impl MaxByteLengthValidator {
+    /// Create a new instance of this validator, requiring at most the given amount
+    /// of bytes, otherwise returning an error with default message.
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            message: format!("The response should be at most {limit} bytes long"),
+        }
+    }
+
+    /// Define a custom error message returned by the validator.
+    /// Defaults to `The response should be at most $limit bytes long`.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+}
+
+impl StringValidator for MaxByteLengthValidator {
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        Ok(if input.len() <= self.limit {
+            Validation::Valid
+        } else {
+            Validation::Invalid(self.message.as_str().into())
+        })
+    }
+}
+
 #[cfg(test)]
 mod validators_test {
     use crate::{
         error::CustomUserError,
         list_option::ListOption,
         validator::{
-            ExactLengthValidator, MaxLengthValidator, MinLengthValidator, MultiOptionValidator,
-            StringValidator, Validation,
+            CustomTypeValidator, EmailValidator, ErrorMessage, ExactLengthValidator,
+            MaxLengthValidator, MinLengthValidator, MultiOptionValidator, RangeValidator,
+            StringValidator, UrlValidator, Validation, ValidatorExt,
         },
     };
 
@@ -921,4 +1693,123 @@ mod validators_test {
 
         Ok(())
     }
+
+    #[test]
+    fn combinators_apply_to_multi_option_validators() -> Result<(), CustomUserError> {
+        let validator = MinLengthValidator::new(2).and(MaxLengthValidator::new(3));
+        let validator: &dyn MultiOptionValidator<str> = &validator;
+
+        assert!(matches!(
+            validator.validate(&build_option_vec(1))?,
+            Validation::Invalid(_)
+        ));
+        assert!(matches!(
+            validator.validate(&build_option_vec(3))?,
+            Validation::Valid
+        ));
+        assert!(matches!(
+            validator.validate(&build_option_vec(4))?,
+            Validation::Invalid(_)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn or_returns_error_of_second_validator() -> Result<(), CustomUserError> {
+        let validator = ExactLengthValidator::new(2)
+            .with_message("first")
+            .or(ExactLengthValidator::new(4).with_message("second"));
+
+        assert_eq!(
+            Validation::Valid,
+            StringValidator::validate(&validator, "ab")?
+        );
+        assert_eq!(
+            Validation::Valid,
+            StringValidator::validate(&validator, "abcd")?
+        );
+        assert_eq!(
+            Validation::Invalid("second".into()),
+            StringValidator::validate(&validator, "abc")?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn map_err_changes_only_invalid_messages() -> Result<(), CustomUserError> {
+        let validator = |input: &i32| match input % 2 {
+            0 => Ok(Validation::Valid),
+            _ => Ok(Validation::Invalid("odd".into())),
+        };
+        let validator = validator.map_err(|message| match message {
+            ErrorMessage::Custom(msg) => format!("{msg} number").into(),
+            ErrorMessage::Default => ErrorMessage::Default,
+        });
+
+        assert_eq!(Validation::Valid, validator.validate(&2)?);
+        assert_eq!(
+            Validation::Invalid("odd number".into()),
+            validator.validate(&3)?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn range_validator_respects_bounds() -> Result<(), CustomUserError> {
+        let validator = RangeValidator::new(1..10);
+
+        assert_eq!(Validation::Valid, validator.validate(&1)?);
+        assert_eq!(Validation::Valid, validator.validate(&9)?);
+        assert_eq!(
+            Validation::Invalid("The value should be at least 1 and less than 10".into()),
+            validator.validate(&10)?
+        );
+        assert!(matches!(validator.validate(&0)?, Validation::Invalid(_)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn email_validator_rejects_malformed_addresses() -> Result<(), CustomUserError> {
+        let validator = EmailValidator::default();
+
+        assert_eq!(Validation::Valid, validator.validate("a.b@c.example.org")?);
+        for invalid in ["@example.com", "a@b@c.com", "a b@c.com", "a@.com", "a@com."] {
+            assert!(
+                matches!(validator.validate(invalid)?, Validation::Invalid(_)),
+                "{} should be invalid",
+                invalid
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn url_validator_checks_scheme_and_host() -> Result<(), CustomUserError> {
+        let validator = UrlValidator::default();
+
+        assert_eq!(
+            Validation::Valid,
+            validator.validate("git+ssh://host/repo")?
+        );
+        for invalid in [
+            "://host",
+            "1http://host",
+            "http://",
+            "http:///path",
+            "http://a b",
+        ] {
+            assert!(
+                matches!(validator.validate(invalid)?, Validation::Invalid(_)),
+                "{} should be invalid",
+                invalid
+            );
+        }
+
+        Ok(())
+    }
 }